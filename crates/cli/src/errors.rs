@@ -64,6 +64,10 @@ pub enum CliError {
     /// Missing required files or dependencies
     #[error("Missing dependency: {dependency} - {message}")]
     MissingDependency { dependency: String, message: String },
+
+    /// `rr version --fail-on-no-bump` found no release-worthy commits
+    #[error("No version bump required: none of the {commits} analysed commits is a feature, fix or breaking change")]
+    NoVersionBump { commits: usize },
 }
 
 #[allow(dead_code)] // Allow during foundation phase
@@ -113,3 +113,13 @@ fn test_config_provider_error_conversion() {
         .to_string()
         .contains("Configuration provider error"));
 }
+
+#[test]
+fn test_no_version_bump_error_display() {
+    let error = CliError::NoVersionBump { commits: 3 };
+
+    assert_eq!(
+        error.to_string(),
+        "No version bump required: none of the 3 analysed commits is a feature, fix or breaking change"
+    );
+}
@@ -9,8 +9,12 @@
 //!
 //! * **Production mode** ([`create_production_processor`]) — reads GitHub App
 //!   credentials from environment variables and connects to the real GitHub API.
+//!
+//! Both have a dry-run counterpart ([`create_dry_run_mock_processor`] and
+//! [`create_dry_run_production_processor`]) that wraps the GitHub client in a
+//! [`DryRunGitHubOperations`] so that no mutating call is executed.
 
-use release_regent_core::ReleaseRegentProcessor;
+use release_regent_core::{DryRunGitHubOperations, DryRunLog, ReleaseRegentProcessor};
 use release_regent_testing::mocks::{
     MockConfigurationProvider, MockGitHubOperations, MockVersionCalculator,
 };
@@ -40,6 +44,21 @@ pub type ProductionProcessor = ReleaseRegentProcessor<
     DefaultVersionCalculator,
 >;
 
+/// Type alias for the dry-run processor returned by [`create_dry_run_mock_processor`].
+pub type DryRunMockProcessor = ReleaseRegentProcessor<
+    DryRunGitHubOperations<MockGitHubOperations>,
+    MockConfigurationProvider,
+    MockVersionCalculator,
+>;
+
+/// Type alias for the dry-run processor returned by
+/// [`create_dry_run_production_processor`].
+pub type DryRunProductionProcessor = ReleaseRegentProcessor<
+    DryRunGitHubOperations<release_regent_github_client::GitHubClient>,
+    release_regent_config_provider::FileConfigurationProvider,
+    DefaultVersionCalculator,
+>;
+
 // ──────────────────────────────────────────────────────────────────────────────
 // Factory functions
 // ──────────────────────────────────────────────────────────────────────────────
@@ -82,7 +101,60 @@ pub fn create_mock_processor() -> MockProcessor {
 /// current directory.
 pub async fn create_production_processor() -> CliResult<ProductionProcessor> {
     info!("Creating production processor from environment variables");
+    let (github_client, config_provider, version_calculator) = production_dependencies().await?;
+    Ok(ReleaseRegentProcessor::new(
+        github_client,
+        config_provider,
+        version_calculator,
+    ))
+}
+
+/// Create an all-mock processor whose GitHub mutations are recorded rather than
+/// executed.
+///
+/// Returns the processor together with the [`DryRunLog`] of intercepted
+/// mutations.
+pub fn create_dry_run_mock_processor() -> (DryRunMockProcessor, DryRunLog) {
+    info!("Creating dry-run mock processor");
+    let github_ops = DryRunGitHubOperations::new(MockGitHubOperations::new());
+    let log = github_ops.log();
+    let processor = ReleaseRegentProcessor::new(
+        github_ops,
+        MockConfigurationProvider::new(),
+        MockVersionCalculator::new(),
+    )
+    .with_dry_run_log(log.clone());
+    (processor, log)
+}
+
+/// Create a production processor that reads from the real GitHub API but only
+/// records mutating calls.
+///
+/// Uses the same environment variables as [`create_production_processor`].
+/// Returns the processor together with the [`DryRunLog`] of intercepted
+/// mutations.
+///
+/// # Errors
+///
+/// Returns the same errors as [`create_production_processor`].
+pub async fn create_dry_run_production_processor(
+) -> CliResult<(DryRunProductionProcessor, DryRunLog)> {
+    info!("Creating dry-run production processor from environment variables");
+    let (github_client, config_provider, version_calculator) = production_dependencies().await?;
+    let github_ops = DryRunGitHubOperations::new(github_client);
+    let log = github_ops.log();
+    let processor = ReleaseRegentProcessor::new(github_ops, config_provider, version_calculator)
+        .with_dry_run_log(log.clone());
+    Ok((processor, log))
+}
 
+/// Build the production GitHub client, configuration provider and version
+/// calculator from environment variables.
+async fn production_dependencies() -> CliResult<(
+    release_regent_github_client::GitHubClient,
+    release_regent_config_provider::FileConfigurationProvider,
+    DefaultVersionCalculator,
+)> {
     let app_id: u64 = std::env::var("GITHUB_APP_ID")
         .map_err(|_| CliError::missing_dependency("GITHUB_APP_ID", "environment variable not set"))?
         .parse::<u64>()
@@ -101,6 +173,8 @@ pub async fn create_production_processor() -> CliResult<ProductionProcessor> {
         app_id,
         private_key,
         webhook_secret,
+        jwt_expiration_seconds: release_regent_github_client::DEFAULT_JWT_EXPIRATION_SECS,
+        request_timeout_seconds: release_regent_github_client::DEFAULT_REQUEST_TIMEOUT_SECS,
     };
 
     // The installation ID is extracted per-event from the webhook payload
@@ -120,9 +194,5 @@ pub async fn create_production_processor() -> CliResult<ProductionProcessor> {
 
     let version_calculator = DefaultVersionCalculator::new();
 
-    Ok(ReleaseRegentProcessor::new(
-        github_client,
-        config_provider,
-        version_calculator,
-    ))
+    Ok((github_client, config_provider, version_calculator))
 }
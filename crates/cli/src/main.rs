@@ -2,21 +2,32 @@
 //!
 //! This application provides local testing and configuration tools for Release Regent.
 
-use clap::{Args, Parser, Subcommand};
+use clap::{Args, CommandFactory, Parser, Subcommand};
 use release_regent_core::{
-    traits::event_source::{EventSourceKind, EventType, ProcessingEvent, RepositoryInfo},
-    MergedPullRequestHandler,
+    telemetry::{LogFormat, LOG_FORMAT_ENV},
+    traits::{
+        event_source::{EventSourceKind, EventType, ProcessingEvent},
+        version_calculator::VersionBump,
+    },
+    webhook::{extract_repository, payload::parse_webhook, schema::validate_webhook},
+    CoreError, DryRunAction, DryRunLog, DryRunReport, MergedPullRequestHandler,
 };
 use std::path::PathBuf;
-use tracing::{debug, info, warn};
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use tracing::{debug, info, warn, Subscriber};
+use tracing_subscriber::{
+    fmt::MakeWriter, layer::SubscriberExt, registry::LookupSpan, util::SubscriberInitExt, Layer,
+};
 
 mod errors;
 mod factory;
+mod templates;
 mod version_calculator;
 
 use errors::{CliError, CliResult};
-use factory::{create_mock_processor, create_production_processor};
+use factory::{
+    create_dry_run_mock_processor, create_dry_run_production_processor, create_mock_processor,
+    create_production_processor,
+};
 
 #[cfg(test)]
 #[path = "main_tests.rs"]
@@ -43,6 +54,10 @@ struct Cli {
     #[arg(short, long, global = true)]
     config: Option<PathBuf>,
 
+    /// Log output format: `pretty` or `json`
+    #[arg(long, global = true, env = LOG_FORMAT_ENV, default_value_t = LogFormat::Pretty)]
+    log_format: LogFormat,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -55,6 +70,16 @@ enum Commands {
     Run(RunArgs),
     /// Test parsing and changelog generation from Git history
     Test(TestArgs),
+    /// Preview the changelog for a range of commits
+    Changelog(ChangelogArgs),
+    /// Print the next version calculated from Git history
+    Version(VersionArgs),
+    /// Migrate a configuration file written for an older release
+    Migrate(MigrateArgs),
+    /// Show how a repository's effective configuration differs from the global one
+    Diff(DiffArgs),
+    /// Print a shell completion script to stdout
+    Completions(CompletionsArgs),
     /// Generate test data files for development workflows
     Generate(GenerateArgs),
 }
@@ -65,7 +90,7 @@ struct InitArgs {
     #[arg(short, long, default_value = ".")]
     output_dir: PathBuf,
 
-    /// Configuration template type
+    /// Configuration template: basic, monorepo, or enterprise
     #[arg(short, long, default_value = "basic")]
     template: String,
 
@@ -75,30 +100,49 @@ struct InitArgs {
 }
 
 #[derive(Args, Debug)]
+#[command(group(
+    clap::ArgGroup::new("event_input")
+        .required(true)
+        .args(["event_file", "stdin"]),
+))]
 struct RunArgs {
-    /// Webhook event file (JSON format)
+    /// Webhook event file (JSON format); `-` reads from standard input
     #[arg(short, long)]
-    event_file: PathBuf,
+    event_file: Option<PathBuf>,
+
+    /// Read the webhook event (JSON format) from standard input
+    #[arg(long)]
+    stdin: bool,
 
     /// Internal Release Regent event type. Valid values: `pull_request_merged`,
     /// `release_pr_merged`, `pull_request_comment_received`, `pull_request_opened`,
-    /// `pull_request_updated`.  GitHub native event names (e.g. `pull_request`,
+    /// `pull_request_updated`, `pull_request_reactivated`.  GitHub native event names (e.g. `pull_request`,
     /// `push`) are NOT accepted here — use the mapping documented in the README.
     /// Tip: inspect `action` in the JSON payload to determine the correct value.
     #[arg(long, default_value = "pull_request_merged")]
     event_type: String,
 
-    /// Dry run mode (no actual operations)
+    /// Dry run mode: process the event but only log GitHub changes
     #[arg(short, long)]
     dry_run: bool,
 
+    /// Output format for the dry-run summary: text or json
+    #[arg(long, default_value = "text", requires = "dry_run")]
+    format: String,
+
     /// Use mock dependencies instead of real GitHub API credentials
     #[arg(long)]
     mock: bool,
 
-    /// Configuration file path
-    #[arg(short, long)]
+    /// Configuration file path. There is no short flag: `-c` is the global
+    /// `--config` option.
+    #[arg(long)]
     config_path: Option<PathBuf>,
+
+    /// Check the payload against the JSON Schema of its event kind before
+    /// processing it, reporting every violation
+    #[arg(long, env = "WEBHOOK_VALIDATE_SCHEMA")]
+    validate_schema: bool,
 }
 
 #[derive(Args, Debug)]
@@ -111,6 +155,15 @@ struct TestArgs {
     #[arg(short, long)]
     from: Option<String>,
 
+    /// Analyse every commit after this tag up to HEAD (ignores --commits)
+    #[arg(long, value_name = "TAG", conflicts_with_all = ["from", "auto_last_tag"])]
+    since_tag: Option<String>,
+
+    /// Analyse every commit after the most recent tag up to HEAD, or the whole
+    /// history when there are no tags (ignores --commits)
+    #[arg(long, conflicts_with = "from")]
+    auto_last_tag: bool,
+
     /// Show detailed commit parsing
     #[arg(short, long)]
     verbose: bool,
@@ -120,6 +173,89 @@ struct TestArgs {
     current_version: Option<String>,
 }
 
+#[derive(Args, Debug)]
+struct ChangelogArgs {
+    /// Start of the range, exclusive (defaults to the most recent tag reachable from --to)
+    #[arg(short, long)]
+    from: Option<String>,
+
+    /// End of the range, inclusive
+    #[arg(short, long, default_value = "HEAD")]
+    to: String,
+
+    /// Version the changelog is for; adds a heading to the output
+    #[arg(long)]
+    version: Option<String>,
+
+    /// Output format: markdown or json
+    #[arg(long, default_value = "markdown")]
+    format: String,
+}
+
+#[derive(Args, Debug)]
+struct VersionArgs {
+    /// Number of commits to analyse from current HEAD
+    #[arg(short = 'n', long, default_value = "10")]
+    commits: usize,
+
+    /// Starting commit SHA (defaults to HEAD)
+    #[arg(short, long)]
+    from: Option<String>,
+
+    /// Current version to calculate the next version from (defaults to an initial release)
+    #[arg(long)]
+    current: Option<String>,
+
+    /// Output format: text (the version only) or json
+    #[arg(long, default_value = "text")]
+    format: String,
+
+    /// Exit with an error when no commit requires a version bump
+    #[arg(long)]
+    fail_on_no_bump: bool,
+}
+
+#[derive(Args, Debug)]
+struct MigrateArgs {
+    /// Configuration file to migrate
+    #[arg(long = "in", value_name = "FILE")]
+    input: PathBuf,
+
+    /// Where to write the migrated configuration (defaults to stdout)
+    #[arg(long = "out", value_name = "FILE")]
+    output: Option<PathBuf>,
+
+    /// Overwrite the output file if it exists
+    #[arg(long)]
+    overwrite: bool,
+}
+
+#[derive(Args, Debug)]
+struct DiffArgs {
+    /// Repository owner
+    #[arg(long)]
+    owner: String,
+
+    /// Repository name
+    #[arg(long)]
+    repo: String,
+
+    /// Directory holding the global and `{owner}-{repo}.toml` configuration files
+    #[arg(long, default_value = ".")]
+    config_dir: PathBuf,
+
+    /// Output format: text or json
+    #[arg(long, default_value = "text")]
+    format: String,
+}
+
+#[derive(Args, Debug)]
+struct CompletionsArgs {
+    /// Shell to generate completions for
+    #[arg(value_enum)]
+    shell: clap_complete::Shell,
+}
+
 #[derive(Args, Debug)]
 struct GenerateArgs {
     /// Output directory for generated files
@@ -140,6 +276,8 @@ async fn execute_init(args: InitArgs) -> CliResult<()> {
     info!("Initializing Release Regent configuration");
     debug!("Init args: {:?}", args);
 
+    let template = templates::ConfigTemplate::parse(&args.template)?;
+
     // Create output directory if it doesn't exist
     if !args.output_dir.exists() {
         tokio::fs::create_dir_all(&args.output_dir).await?;
@@ -155,11 +293,14 @@ async fn execute_init(args: InitArgs) -> CliResult<()> {
         ));
     }
 
-    let default_config = release_regent_core::config::ReleaseRegentConfig::default();
-    let config_toml = toml::to_string_pretty(&default_config)?;
+    let config_toml = template.render()?;
 
     tokio::fs::write(&config_path, config_toml).await?;
-    info!("Generated configuration file: {}", config_path.display());
+    info!(
+        "Generated configuration file from the '{}' template: {}",
+        template.name(),
+        config_path.display()
+    );
 
     // Generate sample webhook payload
     let webhook_path = args.output_dir.join("sample-webhook.json");
@@ -185,8 +326,85 @@ async fn execute_init(args: InitArgs) -> CliResult<()> {
     Ok(())
 }
 
+/// Where `rr run` reads the webhook payload from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum EventInput {
+    /// A JSON file on disk
+    File(PathBuf),
+    /// Standard input
+    Stdin,
+}
+
+impl EventInput {
+    /// Select the input from `--event-file` and `--stdin`.
+    ///
+    /// Exactly one of them must be given; `--event-file -` means standard input.
+    #[allow(clippy::result_large_err)] // CliError is intentionally large
+    fn from_args(args: &RunArgs) -> CliResult<Self> {
+        match (&args.event_file, args.stdin) {
+            (Some(path), false) if path.as_os_str() == "-" => Ok(Self::Stdin),
+            (Some(path), false) => Ok(Self::File(path.clone())),
+            (None, true) => Ok(Self::Stdin),
+            (Some(_), true) => Err(CliError::invalid_argument(
+                "--stdin",
+                "Cannot be combined with --event-file",
+            )),
+            (None, false) => Err(CliError::invalid_argument(
+                "--event-file",
+                "Either --event-file or --stdin is required",
+            )),
+        }
+    }
+
+    /// The flag to name in errors about this input.
+    fn argument(&self) -> &'static str {
+        match self {
+            Self::File(_) => "--event-file",
+            Self::Stdin => "--stdin",
+        }
+    }
+
+    /// Report a payload problem found by [`dispatch_event`] against this input.
+    fn attribute(&self, error: CliError) -> CliError {
+        match error {
+            CliError::InvalidArgument { argument, message } if argument == "--event-file" => {
+                CliError::invalid_argument(self.argument(), message)
+            }
+            other => other,
+        }
+    }
+
+    /// Read the raw payload, taking standard input from `stdin`.
+    async fn read(&self, mut stdin: impl std::io::Read) -> CliResult<String> {
+        match self {
+            Self::File(path) => {
+                if !path.exists() {
+                    return Err(CliError::invalid_argument(
+                        "--event-file",
+                        format!("File not found: {}", path.display()),
+                    ));
+                }
+                let json = tokio::fs::read_to_string(path).await?;
+                info!("Loaded webhook event from: {}", path.display());
+                Ok(json)
+            }
+            Self::Stdin => {
+                let mut json = String::new();
+                stdin.read_to_string(&mut json)?;
+                info!("Loaded webhook event from standard input");
+                Ok(json)
+            }
+        }
+    }
+}
+
 /// Execute the run command, routing to mock or production processor via `--mock`.
 async fn execute_run(args: RunArgs) -> CliResult<()> {
+    execute_run_with_input(args, std::io::stdin().lock()).await
+}
+
+/// [`execute_run`] reading `--stdin` payloads from `stdin`.
+async fn execute_run_with_input(args: RunArgs, stdin: impl std::io::Read) -> CliResult<()> {
     info!("Processing webhook event locally");
     debug!("Run args: {:?}", args);
 
@@ -204,30 +422,55 @@ async fn execute_run(args: RunArgs) -> CliResult<()> {
         }
     }
 
-    // Load and parse the webhook JSON file.
-    if !args.event_file.exists() {
-        return Err(CliError::invalid_argument(
-            "--event-file",
-            format!("File not found: {}", args.event_file.display()),
-        ));
-    }
-
-    let event_json = tokio::fs::read_to_string(&args.event_file).await?;
-    info!("Loaded webhook event from: {}", args.event_file.display());
+    // Load and parse the webhook JSON.
+    let input = EventInput::from_args(&args)?;
+    let event_json = input.read(stdin).await?;
 
     let payload: serde_json::Value = serde_json::from_str(&event_json)
-        .map_err(|e| CliError::invalid_argument("--event-file", format!("Invalid JSON: {e}")))?;
+        .map_err(|e| CliError::invalid_argument(input.argument(), format!("Invalid JSON: {e}")))?;
+
+    process_payload(&args, payload)
+        .await
+        .map_err(|e| input.attribute(e))
+}
 
+/// Dispatch a parsed webhook payload to the processor selected by `args`.
+async fn process_payload(args: &RunArgs, payload: serde_json::Value) -> CliResult<()> {
     let event_type = args.event_type.clone();
+    let json = match args.format.as_str() {
+        "text" => false,
+        "json" => true,
+        other => {
+            return Err(CliError::invalid_argument(
+                "--format",
+                format!("Unknown format '{other}'. Expected 'text' or 'json'"),
+            ))
+        }
+    };
 
     info!(
         "Parsed webhook event: type={}, dry_run={}, mock={}",
         event_type, args.dry_run, args.mock
     );
+    if args.validate_schema {
+        validate_payload(&event_type, &payload)?;
+    }
 
     if args.dry_run {
-        info!("Dry-run mode: no operations performed");
-        println!("Dry run completed - no changes made");
+        let (event, log) = if args.mock {
+            info!("Mock mode: using in-process mocks (no GitHub credentials required)");
+            let (processor, log) = create_dry_run_mock_processor();
+            (dispatch_event(processor, &event_type, payload).await?, log)
+        } else {
+            let (processor, log) = create_dry_run_production_processor().await?;
+            (dispatch_event(processor, &event_type, payload).await?, log)
+        };
+        if json {
+            println!("{}", render_dry_run_report(&log.report(&event))?);
+        } else {
+            println!("✅ Event processed successfully");
+            print_dry_run_summary(&log);
+        }
         return Ok(());
     }
 
@@ -235,18 +478,64 @@ async fn execute_run(args: RunArgs) -> CliResult<()> {
         info!("Mock mode: using in-process mocks (no GitHub credentials required)");
         let processor = create_mock_processor();
         dispatch_event(processor, &event_type, payload).await?;
-        return Ok(());
+    } else {
+        // Production mode: requires GitHub App credentials in environment
+        let processor = create_production_processor().await?;
+        dispatch_event(processor, &event_type, payload).await?;
     }
-
-    // Production mode: requires GitHub App credentials in environment
-    let processor = create_production_processor().await?;
-    dispatch_event(processor, &event_type, payload).await?;
+    println!("✅ Event processed successfully");
     Ok(())
 }
 
+/// Render `report` as pretty-printed JSON for `rr run --dry-run --format json`.
+#[allow(clippy::result_large_err)] // CliError is intentionally large
+fn render_dry_run_report(report: &DryRunReport) -> CliResult<String> {
+    serde_json::to_string_pretty(report)
+        .map_err(|e| CliError::command_execution("run", format!("{e}")))
+}
+
+/// Print the GitHub changes that a dry run intercepted.
+///
+/// The title and body of a created or updated pull request are printed in
+/// full because they carry the computed version and changelog.
+fn print_dry_run_summary(log: &DryRunLog) {
+    let actions = log.actions();
+    println!();
+    if let Some(reason) = log.no_release_reason() {
+        println!("No release needed: {reason}");
+    }
+    if actions.is_empty() {
+        println!("Dry run completed - no changes would be made");
+        return;
+    }
+
+    println!("Dry run completed - the following changes were NOT made:");
+    for action in &actions {
+        println!("  • {action}");
+    }
+
+    for action in &actions {
+        let (title, body) = match action {
+            DryRunAction::CreatePullRequest { title, body, .. } => (Some(title), body),
+            DryRunAction::UpdatePullRequest { title, body, .. } => (title.as_ref(), body),
+            DryRunAction::CreateRelease { name, body, .. } => (name.as_ref(), body),
+            DryRunAction::Other { .. } => continue,
+        };
+        println!();
+        if let Some(title) = title {
+            println!("=== {title} ===");
+        }
+        if let Some(body) = body {
+            println!("{body}");
+        }
+    }
+}
+
 /// Construct a [`ProcessingEvent`] from parsed webhook JSON and dispatch it to
 /// the appropriate handler method on `processor`.
 ///
+/// Returns the dispatched event.
+///
 /// # Event type format
 ///
 /// `raw_event_type` must be one of Release Regent's **internal** event type
@@ -258,46 +547,41 @@ async fn execute_run(args: RunArgs) -> CliResult<()> {
 /// | `release_pr_merged`               | Release PR (branch `release/vX.Y.Z`) merged     |
 /// | `pull_request_comment_received`   | Comment posted on any PR                         |
 /// | `pull_request_opened`             | PR opened (action `opened`)                      |
-/// | `pull_request_updated`            | PR updated (action `edited` / `synchronize`)     |
+/// | `pull_request_updated`            | PR edited or synchronized                        |
+/// | `pull_request_reactivated`        | PR reopened or marked ready for review           |
 ///
 /// Any other string routes to the `Unknown` arm and is silently dropped.
 ///
 /// # Repository and installation metadata
 ///
-/// Repository metadata (`owner`, `name`, `default_branch`) is extracted from the
-/// standard GitHub `repository` object embedded in the webhook payload.
-/// The `installation.id` field is extracted when present; missing installation
-/// context defaults to `0`.  A warning is emitted when `installation_id == 0`
-/// and the processor is in production mode, because GitHub App authentication
-/// requires a non-zero installation ID.
+/// The payload is parsed with [`parse_webhook`], the parser the server uses
+/// too, as the GitHub event `raw_event_type` comes from (see
+/// [`github_event_kind`]); repository metadata and the `installation.id` are
+/// taken from the parsed event. Unknown event types are only checked for a
+/// repository with [`extract_repository`]. The event is rejected with
+/// [`CliError::InvalidArgument`] when the payload does not parse.
+/// Missing installation context defaults to `0`.  A warning is emitted when
+/// `installation_id == 0` and the processor is in production mode, because
+/// GitHub App authentication requires a non-zero installation ID.
 async fn dispatch_event<H: MergedPullRequestHandler>(
     processor: H,
     raw_event_type: &str,
     payload: serde_json::Value,
-) -> CliResult<()> {
-    // Extract repository info from the GitHub-standard `repository` object.
-    let owner = payload["repository"]["owner"]["login"]
-        .as_str()
-        .unwrap_or("unknown")
-        .to_string();
-    let repo_name = payload["repository"]["name"]
-        .as_str()
-        .unwrap_or("unknown")
-        .to_string();
-    let default_branch = payload["repository"]["default_branch"]
-        .as_str()
-        .unwrap_or("main")
-        .to_string();
-
-    if owner == "unknown" || repo_name == "unknown" {
-        warn!(
-            "Could not extract owner/repo from webhook payload — \
-             ensure the file contains a GitHub-format 'repository' object"
-        );
-    }
+) -> CliResult<ProcessingEvent> {
+    let event_type = EventType::from(raw_event_type);
+    let body = payload.to_string().into_bytes();
+    let (repository, installation_id) = match github_event_kind(&event_type, &payload) {
+        Some(kind) => {
+            let webhook = parse_webhook(kind, &body).map_err(invalid_payload)?;
+            let repository = webhook.repository().cloned().ok_or_else(|| {
+                CliError::invalid_argument("--event-file", "webhook payload has no repository")
+            })?;
+            (repository, webhook.installation_id().unwrap_or(0))
+        }
+        None => (extract_repository(&body).map_err(invalid_payload)?, 0),
+    };
 
-    let installation_id = payload["installation"]["id"].as_u64().unwrap_or(0);
-    if installation_id == 0 {
+    if installation_id == 0 && !matches!(event_type, EventType::Unknown(_)) {
         // Non-zero installation ID is required for GitHub App authentication.
         // In mock mode this is harmless; in production it will cause an auth
         // failure when attempting to acquire an installation access token.
@@ -312,12 +596,8 @@ async fn dispatch_event<H: MergedPullRequestHandler>(
     let event = ProcessingEvent {
         event_id: uuid::Uuid::new_v4().to_string(),
         correlation_id: uuid::Uuid::new_v4().to_string(),
-        event_type: EventType::from(raw_event_type),
-        repository: RepositoryInfo {
-            owner: owner.clone(),
-            name: repo_name.clone(),
-            default_branch,
-        },
+        event_type,
+        repository,
         payload,
         received_at: chrono::Utc::now(),
         source: EventSourceKind::Webhook,
@@ -325,8 +605,8 @@ async fn dispatch_event<H: MergedPullRequestHandler>(
     };
 
     info!(
-        owner = %owner,
-        repo = %repo_name,
+        owner = %event.repository.owner,
+        repo = %event.repository.name,
         event_type = %event.event_type,
         installation_id,
         "Dispatching event to processor"
@@ -337,7 +617,12 @@ async fn dispatch_event<H: MergedPullRequestHandler>(
             processor
                 .handle_merged_pull_request(&event)
                 .await
-                .map_err(CliError::from)?;
+                .map_err(|e| {
+                    if let Some(failure) = e.stage_summary() {
+                        warn!(%failure, "Merged pull request was not processed");
+                    }
+                    CliError::from(e)
+                })?;
         }
         EventType::ReleasePrMerged => {
             processor
@@ -357,13 +642,60 @@ async fn dispatch_event<H: MergedPullRequestHandler>(
                 .await
                 .map_err(CliError::from)?;
         }
+        EventType::PullRequestReactivated => {
+            processor
+                .handle_pull_request_reactivated(&event)
+                .await
+                .map_err(CliError::from)?;
+        }
         EventType::Unknown(raw) => {
             warn!(event_type = %raw, "Unrecognised event type — dropping");
         }
     }
 
-    println!("✅ Event processed successfully");
-    Ok(())
+    Ok(event)
+}
+
+/// The GitHub event (`X-GitHub-Event`) a payload of `event_type` was
+/// delivered as, or `None` for unknown event types.
+///
+/// PR comments arrive as `issue_comment` events, with an `issue` object, or as
+/// `pull_request_review_comment` events.
+fn github_event_kind(event_type: &EventType, payload: &serde_json::Value) -> Option<&'static str> {
+    match event_type {
+        EventType::PullRequestMerged
+        | EventType::ReleasePrMerged
+        | EventType::PullRequestOpened
+        | EventType::PullRequestUpdated
+        | EventType::PullRequestReactivated => Some("pull_request"),
+        EventType::PullRequestCommentReceived if payload.get("issue").is_some() => {
+            Some("issue_comment")
+        }
+        EventType::PullRequestCommentReceived => Some("pull_request_review_comment"),
+        EventType::Unknown(_) => None,
+    }
+}
+
+/// Check `payload` against the JSON Schema of the GitHub event it was
+/// delivered as. Unknown event types are not validated.
+#[allow(clippy::result_large_err)] // CliError is intentionally large
+fn validate_payload(raw_event_type: &str, payload: &serde_json::Value) -> CliResult<()> {
+    let event_type = EventType::from(raw_event_type);
+    match github_event_kind(&event_type, payload) {
+        Some(kind) => {
+            validate_webhook(kind, payload.to_string().as_bytes()).map_err(invalid_payload)
+        }
+        None => Ok(()),
+    }
+}
+
+/// Report a payload `parse_webhook` rejected against the event file.
+fn invalid_payload(error: CoreError) -> CliError {
+    let message = match error {
+        CoreError::InvalidInput { message, .. } => message,
+        other => other.to_string(),
+    };
+    CliError::invalid_argument("--event-file", message)
 }
 
 /// Execute the generate command — write test data files to the output directory.
@@ -416,7 +748,25 @@ async fn execute_test(args: TestArgs) -> CliResult<()> {
     debug!("Test args: {:?}", args);
 
     // Get commit messages from git log
-    let commits = get_recent_commits(args.commits, args.from.as_deref()).await?;
+    let commits = match TestRange::from_args(&args) {
+        TestRange::Recent { count, from } => get_recent_commits(count, from.as_deref()).await?,
+        TestRange::SinceTag(tag) => {
+            let sha = resolve_tag_commit(&tag).await?;
+            info!("Analysing commits since {tag} ({sha})");
+            get_commits_in_range(Some(&sha), "HEAD").await?
+        }
+        TestRange::SinceLastTag => match find_last_tag("HEAD").await? {
+            Some(tag) => {
+                let sha = resolve_tag_commit(&tag).await?;
+                info!("Analysing commits since {tag} ({sha})");
+                get_commits_in_range(Some(&sha), "HEAD").await?
+            }
+            None => {
+                info!("No tag found; analysing every commit from the root commit");
+                get_commits_in_range(None, "HEAD").await?
+            }
+        },
+    };
 
     if commits.is_empty() {
         println!("No commits found to analyze.");
@@ -465,6 +815,12 @@ async fn execute_test(args: TestArgs) -> CliResult<()> {
                 println!("Current version: (none - initial release)");
             }
             println!("Next version: {next_version}");
+            // `git log` lists the newest commit first.
+            let oldest_first: Vec<_> = parsed_commits.iter().rev().cloned().collect();
+            println!(
+                "Bump reason: {}",
+                VersionCalculator::explain_version_bump(&oldest_first)
+            );
             println!();
         }
         Err(e) => {
@@ -485,6 +841,324 @@ async fn execute_test(args: TestArgs) -> CliResult<()> {
     Ok(())
 }
 
+/// The commits `rr test` analyses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TestRange {
+    /// The last `count` commits up to HEAD, stopping at `from` when given.
+    Recent { count: usize, from: Option<String> },
+    /// Every commit after the tag up to HEAD.
+    SinceTag(String),
+    /// Every commit after the most recent tag up to HEAD.
+    SinceLastTag,
+}
+
+impl TestRange {
+    fn from_args(args: &TestArgs) -> Self {
+        if let Some(tag) = &args.since_tag {
+            Self::SinceTag(tag.clone())
+        } else if args.auto_last_tag {
+            Self::SinceLastTag
+        } else {
+            Self::Recent {
+                count: args.commits,
+                from: args.from.clone(),
+            }
+        }
+    }
+}
+
+/// Execute the changelog command
+async fn execute_changelog(args: ChangelogArgs) -> CliResult<()> {
+    info!("Generating changelog preview");
+    debug!("Changelog args: {:?}", args);
+
+    let format = ChangelogFormat::parse(&args.format)?;
+    let version = match args.version.as_deref() {
+        Some(v) => Some(
+            release_regent_core::versioning::VersionCalculator::parse_version(v)
+                .map_err(|e| CliError::invalid_argument("--version", format!("{e}")))?,
+        ),
+        None => None,
+    };
+
+    let from = match args.from {
+        Some(from) => Some(from),
+        None => find_last_tag(&args.to).await?,
+    };
+    match &from {
+        Some(from) => info!("Collecting commits in {from}..{}", args.to),
+        None => info!("No tag found; collecting all commits up to {}", args.to),
+    }
+
+    let commits = get_commits_in_range(from.as_deref(), &args.to).await?;
+    let output = render_changelog(
+        &commits,
+        version.as_ref(),
+        format,
+        from.as_deref(),
+        &args.to,
+    )?;
+    println!("{output}");
+    Ok(())
+}
+
+/// Output formats supported by `rr changelog`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChangelogFormat {
+    Markdown,
+    Json,
+}
+
+impl ChangelogFormat {
+    #[allow(clippy::result_large_err)] // CliError is intentionally large
+    fn parse(value: &str) -> CliResult<Self> {
+        match value {
+            "markdown" | "md" => Ok(Self::Markdown),
+            "json" => Ok(Self::Json),
+            other => Err(CliError::invalid_argument(
+                "--format",
+                format!("Unknown format '{other}'. Expected 'markdown' or 'json'"),
+            )),
+        }
+    }
+}
+
+/// Render the changelog for `commits` (`(sha, message)` pairs, newest first).
+///
+/// Markdown output is the generated changelog, preceded by a `## <version>`
+/// heading when a version is given. JSON output wraps the same text together
+/// with the range and the parsed commits so that it can be consumed by scripts.
+#[allow(clippy::result_large_err)] // CliError is intentionally large
+fn render_changelog(
+    commits: &[(String, String)],
+    version: Option<&release_regent_core::versioning::SemanticVersion>,
+    format: ChangelogFormat,
+    from: Option<&str>,
+    to: &str,
+) -> CliResult<String> {
+    use release_regent_core::{changelog::ChangelogGenerator, versioning::VersionCalculator};
+
+    let parsed_commits = VersionCalculator::parse_conventional_commits(commits);
+    let changelog = ChangelogGenerator::new().generate_changelog(&parsed_commits)?;
+
+    match format {
+        ChangelogFormat::Markdown => Ok(match version {
+            Some(version) => format!("## {version}\n\n{changelog}"),
+            None => changelog,
+        }),
+        ChangelogFormat::Json => serde_json::to_string_pretty(&serde_json::json!({
+            "version": version.map(ToString::to_string),
+            "from": from,
+            "to": to,
+            "commits": parsed_commits,
+            "changelog": changelog,
+        }))
+        .map_err(|e| CliError::command_execution("changelog", format!("{e}"))),
+    }
+}
+
+/// Execute the version command
+async fn execute_version(args: VersionArgs) -> CliResult<()> {
+    use release_regent_core::versioning::VersionCalculator;
+
+    info!("Calculating next version");
+    debug!("Version args: {:?}", args);
+
+    let json = match args.format.as_str() {
+        "text" => false,
+        "json" => true,
+        other => {
+            return Err(CliError::invalid_argument(
+                "--format",
+                format!("Unknown format '{other}'. Expected 'text' or 'json'"),
+            ))
+        }
+    };
+    let current = match args.current.as_deref() {
+        Some(v) => Some(
+            VersionCalculator::parse_version(v)
+                .map_err(|e| CliError::invalid_argument("--current", format!("{e}")))?,
+        ),
+        None => None,
+    };
+
+    let commits = get_recent_commits(args.commits, args.from.as_deref()).await?;
+    let next = calculate_version(&commits, current.as_ref())?;
+
+    if args.fail_on_no_bump && next.bump == "none" {
+        return Err(CliError::NoVersionBump {
+            commits: commits.len(),
+        });
+    }
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&next)
+                .map_err(|e| CliError::command_execution("version", format!("{e}")))?
+        );
+    } else {
+        println!("{}", next.next);
+    }
+    Ok(())
+}
+
+/// Result of `rr version`, also its JSON output.
+#[derive(Debug, serde::Serialize)]
+struct NextVersion {
+    current: Option<String>,
+    next: String,
+    /// `major`, `minor`, `patch` or `none`.
+    bump: &'static str,
+}
+
+/// Calculate the next version for `commits` (`(sha, message)` pairs).
+#[allow(clippy::result_large_err)] // CliError is intentionally large
+fn calculate_version(
+    commits: &[(String, String)],
+    current: Option<&release_regent_core::versioning::SemanticVersion>,
+) -> CliResult<NextVersion> {
+    use release_regent_core::versioning::VersionCalculator;
+
+    let parsed_commits = VersionCalculator::parse_conventional_commits(commits);
+    let bump = match VersionCalculator::determine_version_bump(&parsed_commits) {
+        VersionBump::Major => "major",
+        VersionBump::Minor => "minor",
+        VersionBump::Patch => "patch",
+        VersionBump::None => "none",
+    };
+    let next = VersionCalculator::new(current.cloned()).calculate_next_version(&parsed_commits)?;
+
+    Ok(NextVersion {
+        current: current.map(ToString::to_string),
+        next: next.to_string(),
+        bump,
+    })
+}
+
+/// Execute the migrate command
+async fn execute_migrate(args: MigrateArgs) -> CliResult<()> {
+    debug!("Migrate args: {:?}", args);
+
+    if let Some(output) = &args.output {
+        if output.exists() && !args.overwrite {
+            return Err(CliError::config_file(format!(
+                "{} already exists. Use --overwrite to replace it.",
+                output.display()
+            )));
+        }
+    }
+
+    let content = tokio::fs::read_to_string(&args.input).await?;
+    let (migrated, report) = migrate_config(&content)?;
+
+    match &args.output {
+        Some(output) => {
+            tokio::fs::write(output, migrated).await?;
+            info!("Wrote migrated configuration to {}", output.display());
+        }
+        None => print!("{migrated}"),
+    }
+    // The report goes to stderr so that stdout can be redirected to a file.
+    eprintln!("{report}");
+
+    Ok(())
+}
+
+/// Migrate configuration file content to the current version, returning the
+/// new file content and a report of the changes.
+#[allow(clippy::result_large_err)] // CliError is intentionally large
+fn migrate_config(
+    content: &str,
+) -> CliResult<(String, release_regent_config_provider::MigrationReport)> {
+    let outcome = release_regent_config_provider::ConfigMigrator::new().migrate(content)?;
+    let migrated = toml::to_string_pretty(&outcome.table)?;
+    Ok((migrated, outcome.report))
+}
+
+/// Execute the diff command
+async fn execute_diff(args: DiffArgs) -> CliResult<()> {
+    debug!("Diff args: {:?}", args);
+
+    let json = match args.format.as_str() {
+        "text" => false,
+        "json" => true,
+        other => {
+            return Err(CliError::invalid_argument(
+                "--format",
+                format!("Unknown format '{other}'. Expected 'text' or 'json'"),
+            ))
+        }
+    };
+
+    let provider =
+        release_regent_config_provider::FileConfigurationProvider::new(&args.config_dir).await?;
+    let diffs =
+        release_regent_config_provider::diff_effective_config(&provider, &args.owner, &args.repo)
+            .await?;
+
+    println!("{}", render_diff(&diffs, &args.owner, &args.repo, json)?);
+    Ok(())
+}
+
+/// Render the differences between the global and the effective
+/// configuration of `owner/repo`.
+#[allow(clippy::result_large_err)] // CliError is intentionally large
+fn render_diff(
+    diffs: &[release_regent_config_provider::FieldDiff],
+    owner: &str,
+    repo: &str,
+    json: bool,
+) -> CliResult<String> {
+    if json {
+        let output = serde_json::json!({
+            "owner": owner,
+            "repo": repo,
+            "differences": diffs,
+        });
+        return serde_json::to_string_pretty(&output)
+            .map_err(|e| CliError::command_execution("diff", format!("{e}")));
+    }
+
+    if diffs.is_empty() {
+        return Ok(format!(
+            "The effective configuration of {owner}/{repo} matches the global configuration"
+        ));
+    }
+
+    let mut output = format!(
+        "The effective configuration of {owner}/{repo} differs from the global configuration in {} field(s):",
+        diffs.len()
+    );
+    for diff in diffs {
+        output.push_str("\n  ");
+        output.push_str(&diff.to_string());
+    }
+    Ok(output)
+}
+
+/// Execute the completions command
+#[allow(clippy::unused_async)] // signature is async to stay consistent with other execute_* functions
+async fn execute_completions(args: CompletionsArgs) -> CliResult<()> {
+    use std::io::Write as _;
+
+    debug!("Completions args: {:?}", args);
+    // Render into a buffer first: clap_complete panics on write errors, while
+    // a closed stdout (e.g. `rr completions bash | head`) should be an error.
+    let mut script = Vec::new();
+    write_completions(args.shell, &mut script);
+    std::io::stdout().write_all(&script)?;
+    Ok(())
+}
+
+/// Write the completion script for `shell`, derived from the clap definitions
+/// of [`Cli`] so that it always matches the real arguments.
+fn write_completions(shell: clap_complete::Shell, out: &mut dyn std::io::Write) {
+    let mut command = Cli::command();
+    let name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, name, out);
+}
+
 /// Generate a sample webhook payload for testing.
 ///
 /// The payload represents a pull request merged event and is structured to pass
@@ -503,6 +1177,9 @@ fn generate_sample_webhook() -> String {
             "body": "This PR adds a new feature to the application.\n\n## Changes\n- Added feature X\n- Updated documentation",
             "merged": true,
             "merge_commit_sha": "abc123def456789",
+            "user": {
+                "login": "contributor"
+            },
             "base": {
                 "ref": "main",
                 "sha": "def456789abc123"
@@ -542,28 +1219,54 @@ fn generate_sample_webhook() -> String {
 }
 
 /// Set up logging based on verbosity level
-fn setup_logging(verbose: bool) {
+fn setup_logging(verbose: bool, format: LogFormat) {
     let filter = if verbose { "debug" } else { "info" };
 
     tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::fmt::layer()
-                .with_target(false)
-                .with_file(false)
-                .with_line_number(false),
-        )
+        .with(log_layer(format, std::io::stderr))
         .with(tracing_subscriber::EnvFilter::new(filter))
         .init();
 }
 
+/// Log layer rendering events as `format` to `writer`.
+///
+/// JSON lines carry the event fields at the top level and the fields of the
+/// enclosing spans under `span` and `spans`.
+fn log_layer<S, W>(format: LogFormat, writer: W) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    let layer = tracing_subscriber::fmt::layer()
+        .with_writer(writer)
+        .with_target(false)
+        .with_file(false)
+        .with_line_number(false);
+    match format {
+        LogFormat::Pretty => layer.boxed(),
+        LogFormat::Json => layer
+            .json()
+            .flatten_event(true)
+            .with_current_span(true)
+            .with_span_list(true)
+            .boxed(),
+    }
+}
+
 /// Main entry point for the CLI application
 #[tokio::main]
 #[allow(clippy::result_large_err)] // CliError is intentionally large
 async fn main() -> CliResult<()> {
     let cli = Cli::parse();
 
+    // Completion scripts are written to stdout and sourced by the shell, so
+    // they must not be mixed with log output.
+    if let Commands::Completions(args) = cli.command {
+        return execute_completions(args).await;
+    }
+
     // Initialize logging
-    setup_logging(cli.verbose);
+    setup_logging(cli.verbose, cli.log_format);
 
     info!("Starting Release Regent CLI");
     debug!("Parsed CLI arguments: {:?}", cli);
@@ -573,23 +1276,65 @@ async fn main() -> CliResult<()> {
         Commands::Init(args) => execute_init(args).await,
         Commands::Run(args) => execute_run(args).await,
         Commands::Test(args) => execute_test(args).await,
+        Commands::Changelog(args) => execute_changelog(args).await,
+        Commands::Version(args) => execute_version(args).await,
+        Commands::Migrate(args) => execute_migrate(args).await,
+        Commands::Diff(args) => execute_diff(args).await,
+        Commands::Completions(_) => unreachable!("handled before logging is set up"),
         Commands::Generate(args) => execute_generate(args).await,
     }
 }
 
-/// Get recent commits from git log
+/// Get the full messages of the last `count` commits up to HEAD (stopping at
+/// `from` when given), newest first.
 #[allow(clippy::unused_async)] // signature is async to stay consistent with other execute_* functions
 async fn get_recent_commits(count: usize, from: Option<&str>) -> CliResult<Vec<(String, String)>> {
     use std::process::Command;
 
-    let mut cmd = Command::new("git");
-    cmd.arg("log").arg("--oneline").arg(format!("-{count}"));
+    let output = Command::new("git")
+        .args(recent_commits_log_args(count, from))
+        .output()
+        .map_err(|e| CliError::command_execution(
+            "git",
+            format!("Failed to execute git command. Make sure git is installed and you're in a git repository. Error: {e}"),
+        ))?;
+
+    if !output.status.success() {
+        let error_msg = String::from_utf8_lossy(&output.stderr);
+        return Err(CliError::command_execution(
+            "git",
+            format!("Git command failed: {error_msg}"),
+        ));
+    }
+
+    Ok(parse_git_log_records(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
 
+/// `git log` arguments listing the last `count` commits with their full
+/// messages, in the record format read by [`parse_git_log_records`].
+fn recent_commits_log_args(count: usize, from: Option<&str>) -> Vec<String> {
+    let mut args = vec![
+        "log".to_string(),
+        GIT_LOG_RECORD_FORMAT.to_string(),
+        format!("-{count}"),
+    ];
     if let Some(from_sha) = from {
-        cmd.arg(format!("{from_sha}..HEAD"));
+        args.push(log_range(Some(from_sha), "HEAD"));
     }
+    args
+}
+
+/// Find the most recent tag reachable from `to`, or `None` when the history
+/// contains no tags.
+#[allow(clippy::unused_async)] // signature is async to stay consistent with other git helpers
+async fn find_last_tag(to: &str) -> CliResult<Option<String>> {
+    use std::process::Command;
 
-    let output = cmd.output()
+    let output = Command::new("git")
+        .args(["describe", "--tags", "--abbrev=0", to])
+        .output()
         .map_err(|e| CliError::command_execution(
             "git",
             format!("Failed to execute git command. Make sure git is installed and you're in a git repository. Error: {e}"),
@@ -597,24 +1342,92 @@ async fn get_recent_commits(count: usize, from: Option<&str>) -> CliResult<Vec<(
 
     if !output.status.success() {
         let error_msg = String::from_utf8_lossy(&output.stderr);
+        // `git describe` fails with "No names found" when there are no tags;
+        // any other failure (bad ref, not a repository) is a real error.
+        if error_msg.contains("No names found") || error_msg.contains("No tags can describe") {
+            return Ok(None);
+        }
         return Err(CliError::command_execution(
             "git",
             format!("Git command failed: {error_msg}"),
         ));
     }
 
-    let output_str = String::from_utf8_lossy(&output.stdout);
-    let commits: Vec<(String, String)> = output_str
-        .lines()
-        .filter_map(|line| {
-            let parts: Vec<&str> = line.splitn(2, ' ').collect();
-            if parts.len() == 2 {
-                Some((parts[0].to_string(), parts[1].to_string()))
-            } else {
-                None
-            }
-        })
-        .collect();
+    let tag = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok((!tag.is_empty()).then_some(tag))
+}
+
+/// Get the full commit messages in `from..to` (or everything up to `to` when
+/// `from` is `None`), newest first.
+#[allow(clippy::unused_async)] // signature is async to stay consistent with other git helpers
+async fn get_commits_in_range(from: Option<&str>, to: &str) -> CliResult<Vec<(String, String)>> {
+    use std::process::Command;
+
+    let range = log_range(from, to);
+    let output = Command::new("git")
+        .args(["log", GIT_LOG_RECORD_FORMAT, &range])
+        .output()
+        .map_err(|e| CliError::command_execution(
+            "git",
+            format!("Failed to execute git command. Make sure git is installed and you're in a git repository. Error: {e}"),
+        ))?;
+
+    if !output.status.success() {
+        let error_msg = String::from_utf8_lossy(&output.stderr);
+        return Err(CliError::command_execution(
+            "git",
+            format!("Git command failed: {error_msg}"),
+        ));
+    }
+
+    Ok(parse_git_log_records(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// The `git log` revision range for the commits after `from` up to `to`; all
+/// of history up to `to` when `from` is `None`.
+fn log_range(from: Option<&str>, to: &str) -> String {
+    from.map_or_else(|| to.to_string(), |from| format!("{from}..{to}"))
+}
+
+/// Resolve `tag` to the SHA of the commit it points to.
+#[allow(clippy::unused_async)] // signature is async to stay consistent with other git helpers
+async fn resolve_tag_commit(tag: &str) -> CliResult<String> {
+    use std::process::Command;
+
+    let output = Command::new("git")
+        .args(["rev-parse", "--verify", "--quiet", &format!("{tag}^{{commit}}")])
+        .output()
+        .map_err(|e| CliError::command_execution(
+            "git",
+            format!("Failed to execute git command. Make sure git is installed and you're in a git repository. Error: {e}"),
+        ))?;
 
-    Ok(commits)
+    let sha = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if !output.status.success() || sha.is_empty() {
+        return Err(CliError::invalid_argument(
+            "--since-tag",
+            format!("Tag '{tag}' does not resolve to a commit"),
+        ));
+    }
+    Ok(sha)
+}
+
+/// `git log` format emitting one record per commit: the SHA and the full
+/// message (subject, body and footers), separated by a unit separator and
+/// terminated by a record separator, neither of which appears in messages.
+const GIT_LOG_RECORD_FORMAT: &str = "--format=%H%x1f%B%x1e";
+
+/// Split [`GIT_LOG_RECORD_FORMAT`] output into `(sha, message)` pairs.
+fn parse_git_log_records(output: &str) -> Vec<(String, String)> {
+    output
+        .split('\x1e')
+        .filter_map(|record| {
+            let (sha, message) = record.split_once('\x1f')?;
+            let sha = sha.trim();
+            let message = message.trim();
+            (!sha.is_empty() && !message.is_empty()).then(|| (sha.to_string(), message.to_string()))
+        })
+        .collect()
 }
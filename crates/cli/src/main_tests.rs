@@ -79,10 +79,43 @@ impl release_regent_core::MergedPullRequestHandler for EventSpyHandler {
         self.events.lock().unwrap().push(event.clone());
         Ok(())
     }
+
+    async fn handle_pr_comment(&self, event: &ProcessingEvent) -> CoreResult<()> {
+        self.events.lock().unwrap().push(event.clone());
+        Ok(())
+    }
 }
 
+/// A `pull_request` webhook payload for a merged PR in `owner/repo`.
 fn sample_payload(owner: &str, repo: &str) -> serde_json::Value {
     serde_json::json!({
+        "action": "closed",
+        "pull_request": {
+            "number": 42,
+            "title": "feat: add widget",
+            "merged": true,
+            "head": { "ref": "feature/widget", "sha": "abc123" },
+            "base": { "ref": "main", "sha": "def456" },
+            "user": { "login": "contributor" }
+        },
+        "repository": {
+            "name": repo,
+            "owner": { "login": owner },
+            "default_branch": "main"
+        },
+        "installation": { "id": 12_345_678 }
+    })
+}
+
+/// An `issue_comment` webhook payload for a comment on PR #42 of `owner/repo`.
+fn sample_comment_payload(owner: &str, repo: &str) -> serde_json::Value {
+    serde_json::json!({
+        "action": "created",
+        "issue": {
+            "number": 42,
+            "pull_request": { "url": "https://api.github.com/repos/owner/repo/pulls/42" }
+        },
+        "comment": { "id": 1, "body": "!release minor", "user": { "login": "maintainer" } },
         "repository": {
             "name": repo,
             "owner": { "login": owner },
@@ -155,7 +188,7 @@ async fn test_dispatch_event_release_pr_merged() {
 async fn test_dispatch_event_pr_comment() {
     let handler = SpyHandler::new();
     let cloned = handler.clone();
-    let payload = sample_payload("owner", "repo");
+    let payload = sample_comment_payload("owner", "repo");
 
     dispatch_event(cloned, "pull_request_comment_received", payload)
         .await
@@ -193,6 +226,22 @@ async fn test_dispatch_event_pull_request_updated() {
     assert_eq!(handler.received_event_types(), vec!["pull_request_updated"]);
 }
 
+#[tokio::test]
+async fn test_dispatch_event_pull_request_reactivated() {
+    let handler = SpyHandler::new();
+    let cloned = handler.clone();
+    let payload = sample_payload("owner", "repo");
+
+    dispatch_event(cloned, "pull_request_reactivated", payload)
+        .await
+        .expect("dispatch should succeed");
+
+    assert_eq!(
+        handler.received_event_types(),
+        vec!["pull_request_reactivated"]
+    );
+}
+
 /// An unrecognised event type (including GitHub native names like "pull_request")
 /// must be silently dropped — the handler should never be invoked.
 #[tokio::test]
@@ -219,14 +268,9 @@ async fn test_dispatch_event_unknown_is_dropped() {
 async fn test_dispatch_event_extracts_repository_metadata() {
     let handler = EventSpyHandler::new();
     let cloned = handler.clone();
-    let payload = serde_json::json!({
-        "repository": {
-            "name": "my-repo",
-            "owner": { "login": "my-org" },
-            "default_branch": "develop"
-        },
-        "installation": { "id": 99 }
-    });
+    let mut payload = sample_payload("my-org", "my-repo");
+    payload["repository"]["default_branch"] = serde_json::json!("develop");
+    payload["installation"]["id"] = serde_json::json!(99);
 
     dispatch_event(cloned, "pull_request_merged", payload)
         .await
@@ -238,3 +282,950 @@ async fn test_dispatch_event_extracts_repository_metadata() {
     assert_eq!(events[0].repository.default_branch, "develop");
     assert_eq!(events[0].installation_id, 99);
 }
+
+/// PR comments sent as `pull_request_review_comment` deliveries are parsed too.
+#[tokio::test]
+async fn test_dispatch_event_pr_review_comment() {
+    let handler = EventSpyHandler::new();
+    let payload = serde_json::json!({
+        "action": "created",
+        "pull_request": { "number": 42 },
+        "comment": { "id": 1, "body": "!release minor", "user": { "login": "maintainer" } },
+        "repository": {
+            "name": "repo",
+            "owner": { "login": "owner" },
+            "default_branch": "main"
+        },
+        "installation": { "id": 7 }
+    });
+
+    dispatch_event(handler.clone(), "pull_request_comment_received", payload)
+        .await
+        .unwrap();
+
+    let events = handler.events.lock().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].installation_id, 7);
+}
+
+/// A payload missing a field its event needs is rejected before dispatch.
+#[tokio::test]
+async fn test_dispatch_event_rejects_incomplete_pull_request() {
+    let handler = SpyHandler::new();
+    let mut payload = sample_payload("owner", "repo");
+    payload["pull_request"]
+        .as_object_mut()
+        .unwrap()
+        .remove("head");
+
+    let result = dispatch_event(handler.clone(), "pull_request_merged", payload).await;
+
+    match result {
+        Err(CliError::InvalidArgument { argument, message }) => {
+            assert_eq!(argument, "--event-file");
+            assert!(message.contains("head"), "unexpected message: {message}");
+        }
+        other => panic!("expected InvalidArgument, got {other:?}"),
+    }
+    assert_eq!(handler.received_count(), 0);
+}
+
+/// A payload that is not a JSON object is rejected before dispatch.
+#[tokio::test]
+async fn test_dispatch_event_rejects_non_object_payload() {
+    let handler = SpyHandler::new();
+
+    let result = dispatch_event(
+        handler.clone(),
+        "pull_request_merged",
+        serde_json::json!([1]),
+    )
+    .await;
+
+    assert!(matches!(result, Err(CliError::InvalidArgument { .. })));
+    assert_eq!(handler.received_count(), 0);
+}
+
+/// A payload without repository owner/name is rejected before dispatch.
+#[tokio::test]
+async fn test_dispatch_event_rejects_missing_repository() {
+    let handler = SpyHandler::new();
+    let mut payload = sample_payload("owner", "repo");
+    payload.as_object_mut().unwrap().remove("repository");
+
+    let result = dispatch_event(handler.clone(), "pull_request_merged", payload).await;
+
+    match result {
+        Err(CliError::InvalidArgument { message, .. }) => {
+            assert!(
+                message.contains("repository"),
+                "unexpected message: {message}"
+            );
+        }
+        other => panic!("expected InvalidArgument, got {other:?}"),
+    }
+    assert_eq!(handler.received_count(), 0);
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// execute_run tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn run_args(event_file: PathBuf, dry_run: bool, mock: bool) -> RunArgs {
+    RunArgs {
+        event_file: Some(event_file),
+        stdin: false,
+        event_type: "pull_request_merged".to_string(),
+        dry_run,
+        format: "text".to_string(),
+        mock,
+        config_path: None,
+        validate_schema: false,
+    }
+}
+
+fn write_event_file(contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("rr-run-{}.json", uuid::Uuid::new_v4()));
+    std::fs::write(&path, contents).expect("event file should be writable");
+    path
+}
+
+/// The bundled sample webhook is processed end to end in dry-run mode.
+#[tokio::test]
+async fn test_execute_run_dry_run_processes_sample_webhook() {
+    let path = write_event_file(&generate_sample_webhook());
+
+    let result = execute_run(run_args(path.clone(), true, true)).await;
+    let _ = std::fs::remove_file(&path);
+
+    result.expect("dry run of the sample webhook should succeed");
+}
+
+/// Malformed JSON produces an `InvalidArgument` error naming the event file.
+#[tokio::test]
+async fn test_execute_run_rejects_malformed_json() {
+    let path = write_event_file("{ not json");
+
+    let result = execute_run(run_args(path.clone(), true, true)).await;
+    let _ = std::fs::remove_file(&path);
+
+    match result {
+        Err(CliError::InvalidArgument { argument, .. }) => assert_eq!(argument, "--event-file"),
+        other => panic!("expected InvalidArgument, got {other:?}"),
+    }
+}
+
+fn stdin_run_args() -> RunArgs {
+    RunArgs {
+        event_file: None,
+        stdin: true,
+        ..run_args(PathBuf::new(), true, true)
+    }
+}
+
+/// A payload piped in with `--stdin` is processed like one read from a file.
+#[tokio::test]
+async fn test_execute_run_reads_payload_from_stdin() {
+    let payload = generate_sample_webhook();
+
+    execute_run_with_input(stdin_run_args(), std::io::Cursor::new(payload))
+        .await
+        .expect("dry run of the piped sample webhook should succeed");
+}
+
+/// `--event-file -` is shorthand for `--stdin`.
+#[tokio::test]
+async fn test_execute_run_dash_event_file_reads_stdin() {
+    let args = run_args(PathBuf::from("-"), true, true);
+    assert_eq!(EventInput::from_args(&args).unwrap(), EventInput::Stdin);
+
+    execute_run_with_input(args, std::io::Cursor::new(generate_sample_webhook()))
+        .await
+        .expect("dry run of the piped sample webhook should succeed");
+}
+
+/// Malformed JSON on standard input is reported against `--stdin`.
+#[tokio::test]
+async fn test_execute_run_rejects_malformed_stdin_json() {
+    let result = execute_run_with_input(stdin_run_args(), std::io::Cursor::new("{ not json")).await;
+
+    match result {
+        Err(CliError::InvalidArgument { argument, .. }) => assert_eq!(argument, "--stdin"),
+        other => panic!("expected InvalidArgument, got {other:?}"),
+    }
+}
+
+/// Payload problems found while dispatching name `--stdin` too.
+#[tokio::test]
+async fn test_execute_run_reports_invalid_stdin_payload_against_stdin() {
+    let mut payload = sample_payload("owner", "repo");
+    payload["repository"]
+        .as_object_mut()
+        .unwrap()
+        .remove("owner");
+    let result =
+        execute_run_with_input(stdin_run_args(), std::io::Cursor::new(payload.to_string())).await;
+
+    match result {
+        Err(CliError::InvalidArgument { argument, message }) => {
+            assert_eq!(argument, "--stdin");
+            assert!(message.contains("repository.owner.login"), "{message}");
+        }
+        other => panic!("expected InvalidArgument, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_event_input_requires_exactly_one_source() {
+    let neither = RunArgs {
+        event_file: None,
+        ..run_args(PathBuf::new(), true, true)
+    };
+    let both = RunArgs {
+        stdin: true,
+        ..run_args(PathBuf::from("event.json"), true, true)
+    };
+
+    match EventInput::from_args(&neither) {
+        Err(CliError::InvalidArgument { argument, .. }) => assert_eq!(argument, "--event-file"),
+        other => panic!("expected InvalidArgument, got {other:?}"),
+    }
+    match EventInput::from_args(&both) {
+        Err(CliError::InvalidArgument { argument, .. }) => assert_eq!(argument, "--stdin"),
+        other => panic!("expected InvalidArgument, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_run_command_line_requires_exactly_one_event_source() {
+    let missing = Cli::try_parse_from(["rr", "run"]).unwrap_err();
+    assert_eq!(
+        missing.kind(),
+        clap::error::ErrorKind::MissingRequiredArgument
+    );
+
+    let both = Cli::try_parse_from(["rr", "run", "--stdin", "--event-file", "e.json"]).unwrap_err();
+    assert_eq!(both.kind(), clap::error::ErrorKind::ArgumentConflict);
+
+    assert!(Cli::try_parse_from(["rr", "run", "--stdin"]).is_ok());
+}
+
+/// The dry-run report of the sample webhook carries the planned version and
+/// the intercepted GitHub mutations, and serializes to JSON.
+#[tokio::test]
+async fn test_dry_run_report_for_sample_webhook_renders_as_json() {
+    let payload: serde_json::Value = serde_json::from_str(&generate_sample_webhook()).unwrap();
+    let (processor, log) = create_dry_run_mock_processor();
+
+    let event = dispatch_event(processor, "pull_request_merged", payload)
+        .await
+        .expect("dry run of the sample webhook should succeed");
+    let json: serde_json::Value =
+        serde_json::from_str(&render_dry_run_report(&log.report(&event)).unwrap()).unwrap();
+
+    assert_eq!(json["event_type"], "pull_request_merged");
+    assert!(json["calculation"]["next_version"].is_string(), "{json}");
+    assert!(json["actions"].is_array(), "{json}");
+}
+
+#[test]
+fn test_run_format_requires_dry_run() {
+    let err = Cli::try_parse_from(["rr", "run", "--stdin", "--format", "json"]).unwrap_err();
+    assert_eq!(err.kind(), clap::error::ErrorKind::MissingRequiredArgument);
+
+    assert!(Cli::try_parse_from(["rr", "run", "--stdin", "--dry-run", "--format", "json"]).is_ok());
+}
+
+/// With `--validate-schema` a payload that breaks the schema is rejected
+/// with every violation listed, and the bundled sample still passes.
+#[tokio::test]
+async fn test_execute_run_validate_schema_lists_violations() {
+    let mut payload: serde_json::Value = serde_json::from_str(&generate_sample_webhook()).unwrap();
+    payload.as_object_mut().unwrap().remove("repository");
+    payload["pull_request"]["number"] = serde_json::json!("one");
+    let args = RunArgs {
+        validate_schema: true,
+        ..stdin_run_args()
+    };
+
+    let result = execute_run_with_input(args, std::io::Cursor::new(payload.to_string())).await;
+
+    match result {
+        Err(CliError::InvalidArgument { argument, message }) => {
+            assert_eq!(argument, "--stdin");
+            assert!(message.contains("does not match its schema"), "{message}");
+            assert!(message.contains("repository"), "{message}");
+            assert!(message.contains("/pull_request/number"), "{message}");
+        }
+        other => panic!("expected InvalidArgument, got {other:?}"),
+    }
+
+    let args = RunArgs {
+        validate_schema: true,
+        ..stdin_run_args()
+    };
+    execute_run_with_input(args, std::io::Cursor::new(generate_sample_webhook()))
+        .await
+        .expect("the sample webhook matches its schema");
+}
+
+/// An unknown `--format` is rejected before the event is dispatched.
+#[tokio::test]
+async fn test_execute_run_rejects_unknown_format() {
+    let args = RunArgs {
+        format: "yaml".to_string(),
+        ..stdin_run_args()
+    };
+
+    let result =
+        execute_run_with_input(args, std::io::Cursor::new(generate_sample_webhook())).await;
+
+    match result {
+        Err(CliError::InvalidArgument { argument, .. }) => assert_eq!(argument, "--format"),
+        other => panic!("expected InvalidArgument, got {other:?}"),
+    }
+}
+
+// ── rr changelog ─────────────────────────────────────────────────────────────
+
+fn changelog_commits() -> Vec<(String, String)> {
+    vec![
+        (
+            "bbb222".to_string(),
+            "fix(api): handle empty body".to_string(),
+        ),
+        ("aaa111".to_string(), "feat: add login".to_string()),
+    ]
+}
+
+#[test]
+fn test_changelog_format_parse() {
+    assert_eq!(
+        ChangelogFormat::parse("markdown").unwrap(),
+        ChangelogFormat::Markdown
+    );
+    assert_eq!(
+        ChangelogFormat::parse("md").unwrap(),
+        ChangelogFormat::Markdown
+    );
+    assert_eq!(
+        ChangelogFormat::parse("json").unwrap(),
+        ChangelogFormat::Json
+    );
+    match ChangelogFormat::parse("html") {
+        Err(CliError::InvalidArgument { argument, .. }) => assert_eq!(argument, "--format"),
+        other => panic!("expected InvalidArgument, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_render_changelog_markdown_with_version_heading() {
+    let version = release_regent_core::versioning::VersionCalculator::parse_version("1.4.0")
+        .expect("valid version");
+
+    let output = render_changelog(
+        &changelog_commits(),
+        Some(&version),
+        ChangelogFormat::Markdown,
+        Some("v1.3.0"),
+        "HEAD",
+    )
+    .unwrap();
+
+    assert!(output.starts_with("## 1.4.0\n\n"), "output: {output}");
+    assert!(output.contains("add login"));
+    assert!(output.contains("handle empty body"));
+}
+
+#[test]
+fn test_render_changelog_markdown_without_version_has_no_heading() {
+    let output = render_changelog(
+        &changelog_commits(),
+        None,
+        ChangelogFormat::Markdown,
+        None,
+        "HEAD",
+    )
+    .unwrap();
+
+    assert!(!output.starts_with("## "), "output: {output}");
+    assert!(output.contains("add login"));
+}
+
+#[test]
+fn test_render_changelog_json_includes_range_and_commits() {
+    let output = render_changelog(
+        &changelog_commits(),
+        None,
+        ChangelogFormat::Json,
+        Some("v1.3.0"),
+        "main",
+    )
+    .unwrap();
+
+    let value: serde_json::Value = serde_json::from_str(&output).expect("valid JSON");
+    assert_eq!(value["from"], "v1.3.0");
+    assert_eq!(value["to"], "main");
+    assert!(value["version"].is_null());
+    assert_eq!(value["commits"].as_array().unwrap().len(), 2);
+    assert_eq!(value["commits"][0]["commit_type"], "fix");
+    assert_eq!(value["commits"][0]["scope"], "api");
+    assert!(value["changelog"]
+        .as_str()
+        .unwrap()
+        .contains("handle empty body"));
+}
+
+#[test]
+fn test_render_changelog_empty_range() {
+    let output = render_changelog(&[], None, ChangelogFormat::Markdown, None, "HEAD").unwrap();
+
+    assert_eq!(output, "No changes in this release.");
+}
+
+#[test]
+fn test_parse_git_log_records_keeps_full_messages() {
+    let output = "aaa111\x1ffeat: add login\n\nBREAKING CHANGE: sessions reset\n\x1e\nbbb222\x1ffix: typo\n\x1e\n";
+
+    let records = parse_git_log_records(output);
+
+    assert_eq!(
+        records,
+        vec![
+            (
+                "aaa111".to_string(),
+                "feat: add login\n\nBREAKING CHANGE: sessions reset".to_string()
+            ),
+            ("bbb222".to_string(), "fix: typo".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_recent_commits_log_args_request_full_messages() {
+    assert_eq!(
+        recent_commits_log_args(5, None),
+        vec!["log", "--format=%H%x1f%B%x1e", "-5"]
+    );
+    assert_eq!(
+        recent_commits_log_args(5, Some("abc123")),
+        vec!["log", "--format=%H%x1f%B%x1e", "-5", "abc123..HEAD"]
+    );
+}
+
+#[test]
+fn test_breaking_change_footer_survives_git_log_parsing() {
+    use release_regent_core::versioning::VersionCalculator;
+
+    let output = "aaa111\x1ffeat(auth): rotate sessions\n\nSessions are now stored server-side.\n\nBREAKING CHANGE: existing sessions are invalidated\n\x1e\n";
+
+    let parsed = VersionCalculator::parse_conventional_commits(&parse_git_log_records(output));
+
+    assert_eq!(parsed.len(), 1);
+    assert!(parsed[0].breaking_change, "{:?}", parsed[0]);
+    assert_eq!(parsed[0].description, "rotate sessions");
+}
+
+#[tokio::test]
+async fn test_execute_changelog_rejects_invalid_version() {
+    let result = execute_changelog(ChangelogArgs {
+        from: Some("HEAD".to_string()),
+        to: "HEAD".to_string(),
+        version: Some("not-a-version".to_string()),
+        format: "markdown".to_string(),
+    })
+    .await;
+
+    match result {
+        Err(CliError::InvalidArgument { argument, .. }) => assert_eq!(argument, "--version"),
+        other => panic!("expected InvalidArgument, got {other:?}"),
+    }
+}
+
+// ── rr completions ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_completions_are_generated_for_every_shell() {
+    use clap::ValueEnum;
+
+    for shell in clap_complete::Shell::value_variants() {
+        let mut out = Vec::new();
+        write_completions(*shell, &mut out);
+        let script = String::from_utf8(out).expect("completion script should be UTF-8");
+
+        assert!(!script.is_empty(), "{shell} script is empty");
+        assert!(script.contains("rr"), "{shell} script does not mention rr");
+        assert!(
+            script.contains("changelog"),
+            "{shell} script is missing the changelog subcommand"
+        );
+    }
+}
+
+#[test]
+fn test_completions_parses_known_shell() {
+    let cli = Cli::try_parse_from(["rr", "completions", "zsh"]).expect("zsh is supported");
+
+    match cli.command {
+        Commands::Completions(args) => assert_eq!(args.shell, clap_complete::Shell::Zsh),
+        other => panic!("expected Completions, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_completions_rejects_unknown_shell() {
+    let err = Cli::try_parse_from(["rr", "completions", "tcsh"]).unwrap_err();
+
+    assert_eq!(err.kind(), clap::error::ErrorKind::InvalidValue);
+    let message = err.to_string();
+    assert!(message.contains("tcsh"), "message: {message}");
+    assert!(message.contains("bash"), "message: {message}");
+}
+
+/// clap only validates the full command tree (e.g. duplicate short flags
+/// between global and subcommand options) when all of it is built, which
+/// completion generation does.
+#[test]
+fn test_cli_definition_is_valid() {
+    Cli::command().debug_assert();
+}
+
+// ── rr version ───────────────────────────────────────────────────────────────
+
+fn commits(messages: &[&str]) -> Vec<(String, String)> {
+    messages
+        .iter()
+        .enumerate()
+        .map(|(i, m)| (format!("sha{i}"), (*m).to_string()))
+        .collect()
+}
+
+#[test]
+fn test_calculate_version_minor_bump_from_current() {
+    let current = release_regent_core::versioning::VersionCalculator::parse_version("1.2.3")
+        .expect("valid version");
+
+    let next =
+        calculate_version(&commits(&["feat: add login", "fix: typo"]), Some(&current)).unwrap();
+
+    assert_eq!(next.current.as_deref(), Some("1.2.3"));
+    assert_eq!(next.next, "1.3.0");
+    assert_eq!(next.bump, "minor");
+}
+
+#[test]
+fn test_calculate_version_breaking_change_is_major() {
+    let current = release_regent_core::versioning::VersionCalculator::parse_version("1.2.3")
+        .expect("valid version");
+
+    let next = calculate_version(&commits(&["feat!: drop v1 API"]), Some(&current)).unwrap();
+
+    assert_eq!(next.next, "2.0.0");
+    assert_eq!(next.bump, "major");
+}
+
+#[test]
+fn test_calculate_version_without_current_starts_initial_release() {
+    let next = calculate_version(&commits(&["fix: typo"]), None).unwrap();
+
+    assert!(next.current.is_none());
+    assert_eq!(next.bump, "patch");
+}
+
+#[test]
+fn test_calculate_version_no_bump_keeps_current() {
+    let current = release_regent_core::versioning::VersionCalculator::parse_version("1.2.3")
+        .expect("valid version");
+
+    let next = calculate_version(&commits(&["chore: tidy", "docs: typo"]), Some(&current)).unwrap();
+
+    assert_eq!(next.next, "1.2.3");
+    assert_eq!(next.bump, "none");
+}
+
+#[test]
+fn test_next_version_json_shape() {
+    let next = calculate_version(&commits(&["feat: add login"]), None).unwrap();
+
+    let value = serde_json::to_value(&next).unwrap();
+
+    assert!(value["current"].is_null());
+    assert_eq!(value["bump"], "minor");
+    assert!(value["next"].is_string());
+}
+
+#[tokio::test]
+async fn test_execute_version_rejects_unknown_format() {
+    let result = execute_version(VersionArgs {
+        commits: 1,
+        from: None,
+        current: None,
+        format: "yaml".to_string(),
+        fail_on_no_bump: false,
+    })
+    .await;
+
+    match result {
+        Err(CliError::InvalidArgument { argument, .. }) => assert_eq!(argument, "--format"),
+        other => panic!("expected InvalidArgument, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_execute_version_rejects_invalid_current() {
+    let result = execute_version(VersionArgs {
+        commits: 1,
+        from: None,
+        current: Some("one.two".to_string()),
+        format: "text".to_string(),
+        fail_on_no_bump: false,
+    })
+    .await;
+
+    match result {
+        Err(CliError::InvalidArgument { argument, .. }) => assert_eq!(argument, "--current"),
+        other => panic!("expected InvalidArgument, got {other:?}"),
+    }
+}
+
+// ── rr test ranges ───────────────────────────────────────────────────────────
+
+fn test_range(argv: &[&str]) -> TestRange {
+    let cli = Cli::try_parse_from(argv).expect("test arguments should parse");
+    match cli.command {
+        Commands::Test(args) => TestRange::from_args(&args),
+        other => panic!("expected Test, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_test_range_defaults_to_recent_commits() {
+    assert_eq!(
+        test_range(&["rr", "test"]),
+        TestRange::Recent {
+            count: 10,
+            from: None
+        }
+    );
+    assert_eq!(
+        test_range(&["rr", "test", "-n", "3", "--from", "abc123"]),
+        TestRange::Recent {
+            count: 3,
+            from: Some("abc123".to_string())
+        }
+    );
+}
+
+#[test]
+fn test_test_range_since_tag() {
+    assert_eq!(
+        test_range(&["rr", "test", "--since-tag", "v1.2.0", "-n", "3"]),
+        TestRange::SinceTag("v1.2.0".to_string())
+    );
+}
+
+#[test]
+fn test_test_range_auto_last_tag() {
+    assert_eq!(
+        test_range(&["rr", "test", "--auto-last-tag"]),
+        TestRange::SinceLastTag
+    );
+}
+
+#[test]
+fn test_test_range_options_conflict_with_from() {
+    for argv in [
+        ["rr", "test", "--since-tag", "v1.2.0", "--from", "abc123"].as_slice(),
+        ["rr", "test", "--auto-last-tag", "--from", "abc123"].as_slice(),
+        ["rr", "test", "--since-tag", "v1.2.0", "--auto-last-tag"].as_slice(),
+    ] {
+        let err = Cli::try_parse_from(argv).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            clap::error::ErrorKind::ArgumentConflict,
+            "{argv:?}"
+        );
+    }
+}
+
+#[test]
+fn test_log_range_from_tag_or_root() {
+    assert_eq!(log_range(Some("abc123"), "HEAD"), "abc123..HEAD");
+    assert_eq!(log_range(None, "HEAD"), "HEAD");
+}
+
+// ── rr migrate ───────────────────────────────────────────────────────────────
+
+const V1_CONFIG: &str = r#"
+[versioning]
+prefix = "rel-"
+
+[repository]
+main_branch = "trunk"
+"#;
+
+#[test]
+fn test_migrate_parses_in_and_out() {
+    let cli = Cli::try_parse_from(["rr", "migrate", "--in", "old.toml", "--out", "new.toml"])
+        .expect("migrate should parse");
+
+    match cli.command {
+        Commands::Migrate(args) => {
+            assert_eq!(args.input, PathBuf::from("old.toml"));
+            assert_eq!(args.output, Some(PathBuf::from("new.toml")));
+        }
+        other => panic!("expected Migrate, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_migrate_config_rewrites_v1_layout() {
+    let (migrated, report) = migrate_config(V1_CONFIG).unwrap();
+
+    assert_eq!(report.from_version, 1);
+    let config: release_regent_core::config::ReleaseRegentConfig =
+        toml::from_str(&migrated).expect("migrated output should parse");
+    assert_eq!(config.core.version_prefix, "rel-");
+    assert_eq!(config.core.branches.main, "trunk");
+    assert!(!migrated.contains("[repository]"), "output: {migrated}");
+}
+
+#[test]
+fn test_migrate_config_rejects_newer_version() {
+    let result = migrate_config("config_version = 99\n");
+
+    assert!(matches!(
+        result,
+        Err(CliError::ConfigProvider {
+            source: release_regent_config_provider::ConfigProviderError::UnsupportedConfigVersion { .. }
+        })
+    ));
+}
+
+#[tokio::test]
+async fn test_execute_migrate_writes_output_file() {
+    let dir = std::env::temp_dir().join(format!("rr-migrate-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let input = dir.join("old.toml");
+    let output = dir.join("new.toml");
+    std::fs::write(&input, V1_CONFIG).unwrap();
+
+    let result = execute_migrate(MigrateArgs {
+        input,
+        output: Some(output.clone()),
+        overwrite: false,
+    })
+    .await;
+    let written = std::fs::read_to_string(&output);
+    let _ = std::fs::remove_dir_all(&dir);
+
+    result.unwrap();
+    assert!(written.unwrap().contains("config_version = 2"));
+}
+
+#[tokio::test]
+async fn test_execute_migrate_refuses_to_overwrite() {
+    let dir = std::env::temp_dir().join(format!("rr-migrate-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let input = dir.join("old.toml");
+    std::fs::write(&input, V1_CONFIG).unwrap();
+
+    let result = execute_migrate(MigrateArgs {
+        input: input.clone(),
+        output: Some(input.clone()),
+        overwrite: false,
+    })
+    .await;
+    let unchanged = std::fs::read_to_string(&input).unwrap();
+    let _ = std::fs::remove_dir_all(&dir);
+
+    assert!(matches!(result, Err(CliError::ConfigFile { .. })));
+    assert_eq!(unchanged, V1_CONFIG);
+}
+
+// ── rr diff ──────────────────────────────────────────────────────────────────
+
+fn changed_prefix() -> release_regent_config_provider::FieldDiff {
+    release_regent_config_provider::FieldDiff::Changed {
+        path: "core.version_prefix".to_string(),
+        from: toml::Value::String("v".to_string()),
+        to: toml::Value::String("widgets-v".to_string()),
+    }
+}
+
+#[test]
+fn test_diff_parses_owner_and_repo() {
+    let cli = Cli::try_parse_from([
+        "rr", "diff", "--owner", "acme", "--repo", "widgets", "--format", "json",
+    ])
+    .expect("diff should parse");
+
+    match cli.command {
+        Commands::Diff(args) => {
+            assert_eq!(args.owner, "acme");
+            assert_eq!(args.repo, "widgets");
+            assert_eq!(args.config_dir, PathBuf::from("."));
+            assert_eq!(args.format, "json");
+        }
+        other => panic!("expected Diff, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_render_diff_text_shows_both_values() {
+    let output = render_diff(&[changed_prefix()], "acme", "widgets", false).unwrap();
+
+    assert!(output.contains("acme/widgets differs from the global configuration in 1 field(s)"));
+    assert!(output.contains("~ core.version_prefix: \"v\" -> \"widgets-v\""));
+}
+
+#[test]
+fn test_render_diff_text_without_differences() {
+    let output = render_diff(&[], "acme", "widgets", false).unwrap();
+
+    assert_eq!(
+        output,
+        "The effective configuration of acme/widgets matches the global configuration"
+    );
+}
+
+#[test]
+fn test_render_diff_json_marks_changed_fields() {
+    let output = render_diff(&[changed_prefix()], "acme", "widgets", true).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    assert_eq!(value["owner"], "acme");
+    assert_eq!(value["repo"], "widgets");
+    assert_eq!(
+        value["differences"][0],
+        serde_json::json!({
+            "kind": "changed",
+            "path": "core.version_prefix",
+            "from": "v",
+            "to": "widgets-v",
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_execute_diff_rejects_unknown_format() {
+    let result = execute_diff(DiffArgs {
+        owner: "acme".to_string(),
+        repo: "widgets".to_string(),
+        config_dir: PathBuf::from("."),
+        format: "yaml".to_string(),
+    })
+    .await;
+
+    assert!(matches!(result, Err(CliError::InvalidArgument { .. })));
+}
+
+// ── rr init ──────────────────────────────────────────────────────────────────
+
+/// `rr init` writes TOML, the only configuration format, and the file loads
+/// back through the file provider as exactly the default configuration.
+#[tokio::test]
+async fn test_execute_init_output_round_trips_to_default_config() {
+    use release_regent_core::traits::{configuration_provider::LoadOptions, ConfigurationProvider};
+
+    let dir = std::env::temp_dir().join(format!("rr-init-{}", uuid::Uuid::new_v4()));
+    let result = execute_init(InitArgs {
+        output_dir: dir.clone(),
+        template: "basic".to_string(),
+        overwrite: false,
+    })
+    .await;
+    let config_path = dir.join(".release-regent.toml");
+    let loaded = async {
+        let mut provider = release_regent_config_provider::FileConfigurationProvider::new(&dir)
+            .await
+            .unwrap();
+        provider.set_global_config_path(&config_path);
+        provider.load_global_config(LoadOptions::default()).await
+    }
+    .await;
+    let _ = std::fs::remove_dir_all(&dir);
+
+    result.unwrap();
+    let loaded = loaded.expect("init output should load");
+    let diffs = release_regent_config_provider::diff_configs(
+        &release_regent_core::config::ReleaseRegentConfig::default(),
+        &loaded,
+    )
+    .unwrap();
+    assert!(
+        diffs.is_empty(),
+        "init output differs from defaults: {diffs:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_execute_init_refuses_to_overwrite() {
+    let dir = std::env::temp_dir().join(format!("rr-init-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let config_path = dir.join(".release-regent.toml");
+    std::fs::write(&config_path, "# keep me\n").unwrap();
+
+    let result = execute_init(InitArgs {
+        output_dir: dir.clone(),
+        template: "basic".to_string(),
+        overwrite: false,
+    })
+    .await;
+    let unchanged = std::fs::read_to_string(&config_path).unwrap();
+    let _ = std::fs::remove_dir_all(&dir);
+
+    assert!(matches!(result, Err(CliError::ConfigFile { .. })));
+    assert_eq!(unchanged, "# keep me\n");
+}
+
+// ── logging ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_log_format_flag_defaults_to_pretty_and_accepts_json() {
+    let default = Cli::try_parse_from(["rr", "init"]).unwrap();
+    let json = Cli::try_parse_from(["rr", "--log-format", "json", "init"]).unwrap();
+    let after_subcommand = Cli::try_parse_from(["rr", "init", "--log-format", "JSON"]).unwrap();
+
+    if std::env::var_os(LOG_FORMAT_ENV).is_none() {
+        assert_eq!(default.log_format, LogFormat::Pretty);
+    }
+    assert_eq!(json.log_format, LogFormat::Json);
+    assert_eq!(after_subcommand.log_format, LogFormat::Json);
+    assert!(Cli::try_parse_from(["rr", "--log-format", "xml", "init"]).is_err());
+}
+
+#[test]
+fn test_log_layer_json_emits_json_lines_with_span_fields() {
+    let buffer = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&buffer);
+    let writer = move || SharedBuffer(Arc::clone(&sink));
+    let subscriber = tracing_subscriber::registry().with(log_layer(LogFormat::Json, writer));
+
+    tracing::subscriber::with_default(subscriber, || {
+        let span = tracing::info_span!("run", correlation_id = "corr-7");
+        let _entered = span.enter();
+        info!(dry_run = true, "Processing webhook event locally");
+    });
+
+    let output = String::from_utf8(buffer.lock().unwrap().clone()).unwrap();
+    let line: serde_json::Value =
+        serde_json::from_str(output.trim()).expect("output should be one JSON object");
+    assert_eq!(line["message"], "Processing webhook event locally");
+    assert_eq!(line["dry_run"], true);
+    assert_eq!(line["span"]["correlation_id"], "corr-7");
+}
+
+/// `io::Write` sink shared between the log layer and the test.
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl std::io::Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
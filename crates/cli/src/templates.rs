@@ -0,0 +1,122 @@
+//! Starter configuration templates for `rr init`.
+//!
+//! Each [`ConfigTemplate`] produces a complete [`ReleaseRegentConfig`] tuned
+//! for a common kind of project. The rendered TOML starts with a comment
+//! naming the template so that users can tell where a file came from.
+
+use release_regent_core::{
+    config::{GitHubIssueConfig, NotificationStrategy, ReleaseRegentConfig},
+    manifest::{ManifestFileConfig, ManifestFormat},
+};
+
+use crate::errors::{CliError, CliResult};
+
+#[cfg(test)]
+#[path = "templates_tests.rs"]
+mod tests;
+
+/// A named starter configuration for `rr init --template`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigTemplate {
+    /// Library defaults; suitable for a single-package repository.
+    Basic,
+    /// Several packages in one repository whose manifests are bumped together.
+    Monorepo,
+    /// Organisation-managed repository: draft releases for review, no
+    /// version overrides from PR comments, bot PRs ignored and failures
+    /// reported as issues.
+    Enterprise,
+}
+
+impl ConfigTemplate {
+    /// Every template, in the order they are listed to users.
+    pub const ALL: [Self; 3] = [Self::Basic, Self::Monorepo, Self::Enterprise];
+
+    /// Look up a template by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CliError::InvalidArgument`] listing the valid names when
+    /// `name` is not a known template.
+    #[allow(clippy::result_large_err)] // CliError is intentionally large
+    pub fn parse(name: &str) -> CliResult<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|template| template.name() == name)
+            .ok_or_else(|| {
+                let valid: Vec<&str> = Self::ALL.iter().map(|t| t.name()).collect();
+                CliError::invalid_argument(
+                    "--template",
+                    format!(
+                        "Unknown template '{name}'. Valid templates: {}",
+                        valid.join(", ")
+                    ),
+                )
+            })
+    }
+
+    /// The name used on the command line.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Basic => "basic",
+            Self::Monorepo => "monorepo",
+            Self::Enterprise => "enterprise",
+        }
+    }
+
+    /// The configuration this template produces.
+    pub fn config(self) -> ReleaseRegentConfig {
+        let mut config = ReleaseRegentConfig::default();
+
+        match self {
+            Self::Basic => {}
+            Self::Monorepo => {
+                // List the package manifests explicitly; auto-detection only
+                // looks at the repository root.
+                config.release_pr.auto_detect_manifests = false;
+                config.release_pr.manifest_files = vec![
+                    ManifestFileConfig {
+                        path: "Cargo.toml".to_string(),
+                        format: ManifestFormat::Toml,
+                        version_key: "workspace.package.version".to_string(),
+                    },
+                    ManifestFileConfig {
+                        path: "packages/web/package.json".to_string(),
+                        format: ManifestFormat::Json,
+                        version_key: "version".to_string(),
+                    },
+                ];
+            }
+            Self::Enterprise => {
+                config.releases.draft = true;
+                config.versioning.allow_override = false;
+                config.versioning.excluded_pr_authors =
+                    vec!["dependabot[bot]".to_string(), "renovate[bot]".to_string()];
+                config.notifications.strategy = NotificationStrategy::GitHubIssue;
+                config.notifications.github_issue = Some(GitHubIssueConfig {
+                    labels: vec!["release-regent".to_string(), "release-failure".to_string()],
+                    assignees: Vec::new(),
+                });
+                config.error_handling.max_retries = 8;
+                config.error_handling.initial_delay_ms = 2000;
+            }
+        }
+
+        config
+    }
+
+    /// Render the template as the contents of a `.release-regent.toml` file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CliError::TomlSerializing`] if the configuration cannot be
+    /// serialized.
+    #[allow(clippy::result_large_err)] // CliError is intentionally large
+    pub fn render(self) -> CliResult<String> {
+        let body = toml::to_string_pretty(&self.config())?;
+        Ok(format!(
+            "# Release Regent configuration generated by `rr init --template {}`\n\n{body}",
+            self.name()
+        ))
+    }
+}
@@ -0,0 +1,127 @@
+use super::*;
+use release_regent_config_provider::FileConfigurationProvider;
+use release_regent_core::{
+    config::VersioningStrategy,
+    traits::{configuration_provider::LoadOptions, ConfigurationProvider},
+};
+use std::path::PathBuf;
+
+/// Write `template` to a fresh directory and load it back through the file
+/// provider, which runs the same validation as the server.
+async fn load_through_file_provider(template: ConfigTemplate) -> ReleaseRegentConfig {
+    let dir: PathBuf = std::env::temp_dir().join(format!("rr-template-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).expect("temp dir should be creatable");
+    let path = dir.join(".release-regent.toml");
+    std::fs::write(&path, template.render().expect("template should render"))
+        .expect("template should be writable");
+
+    let mut provider = FileConfigurationProvider::new(&dir)
+        .await
+        .expect("provider should be created");
+    provider.set_global_config_path(&path);
+    let result = provider.load_global_config(LoadOptions::default()).await;
+    let _ = std::fs::remove_dir_all(&dir);
+
+    result.unwrap_or_else(|e| panic!("{} template should load: {e}", template.name()))
+}
+
+#[test]
+fn test_parse_known_templates() {
+    for template in ConfigTemplate::ALL {
+        assert_eq!(ConfigTemplate::parse(template.name()).unwrap(), template);
+    }
+}
+
+#[test]
+fn test_parse_unknown_template_lists_valid_choices() {
+    match ConfigTemplate::parse("comprehensive") {
+        Err(CliError::InvalidArgument { argument, message }) => {
+            assert_eq!(argument, "--template");
+            assert!(message.contains("comprehensive"), "message: {message}");
+            for template in ConfigTemplate::ALL {
+                assert!(message.contains(template.name()), "message: {message}");
+            }
+        }
+        other => panic!("expected InvalidArgument, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_every_template_passes_core_validation() {
+    for template in ConfigTemplate::ALL {
+        template
+            .config()
+            .validate()
+            .unwrap_or_else(|e| panic!("{} template is invalid: {e}", template.name()));
+    }
+}
+
+#[test]
+fn test_render_starts_with_template_comment() {
+    let rendered = ConfigTemplate::Monorepo.render().unwrap();
+
+    assert!(rendered
+        .starts_with("# Release Regent configuration generated by `rr init --template monorepo`"));
+}
+
+#[test]
+fn test_templates_produce_different_configs() {
+    let rendered: Vec<String> = ConfigTemplate::ALL
+        .iter()
+        .map(|t| t.render().unwrap())
+        .collect();
+
+    assert_ne!(rendered[0], rendered[1]);
+    assert_ne!(rendered[0], rendered[2]);
+    assert_ne!(rendered[1], rendered[2]);
+}
+
+#[tokio::test]
+async fn test_basic_template_loads_with_defaults() {
+    let config = load_through_file_provider(ConfigTemplate::Basic).await;
+
+    assert_eq!(config.core.version_prefix, "v");
+    assert_eq!(config.core.branches.main, "main");
+    assert!(config.release_pr.auto_detect_manifests);
+    assert!(config.release_pr.manifest_files.is_empty());
+    assert_eq!(config.versioning.strategy, VersioningStrategy::Conventional);
+}
+
+#[tokio::test]
+async fn test_monorepo_template_loads_with_explicit_manifests() {
+    let config = load_through_file_provider(ConfigTemplate::Monorepo).await;
+
+    assert!(!config.release_pr.auto_detect_manifests);
+    let paths: Vec<&str> = config
+        .release_pr
+        .manifest_files
+        .iter()
+        .map(|m| m.path.as_str())
+        .collect();
+    assert_eq!(paths, vec!["Cargo.toml", "packages/web/package.json"]);
+    assert_eq!(
+        config.release_pr.manifest_files[0].format,
+        ManifestFormat::Toml
+    );
+    assert_eq!(
+        config.release_pr.manifest_files[1].format,
+        ManifestFormat::Json
+    );
+}
+
+#[tokio::test]
+async fn test_enterprise_template_loads_with_review_settings() {
+    let config = load_through_file_provider(ConfigTemplate::Enterprise).await;
+
+    assert!(config.releases.draft);
+    assert!(!config.versioning.allow_override);
+    assert!(config
+        .versioning
+        .excluded_pr_authors
+        .contains(&"dependabot[bot]".to_string()));
+    assert_eq!(config.error_handling.max_retries, 8);
+    assert_eq!(
+        config.notifications.github_issue.unwrap().labels,
+        vec!["release-regent", "release-failure"]
+    );
+}
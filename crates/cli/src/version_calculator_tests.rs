@@ -1,8 +1,5 @@
 use super::*;
-use release_regent_core::{
-    traits::version_calculator::{VersionBump, VersioningStrategy},
-    VersionCalculator,
-};
+use release_regent_core::{traits::version_calculator::VersioningStrategy, VersionCalculator};
 
 // ──────────────────────────────────────────────────────────────
 // DefaultVersionCalculator::new
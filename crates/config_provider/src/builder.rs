@@ -3,6 +3,7 @@
 use crate::errors::{ConfigProviderError, ConfigProviderResult};
 use crate::file_provider::FileConfigurationProvider;
 use crate::formats::is_toml_path;
+use crate::remote::{RemoteConfigSource, RemoteRetryPolicy, DEFAULT_REMOTE_TIMEOUT};
 use crate::validation::{ConfigValidator, ValidationRule};
 use release_regent_core::{
     config::ReleaseRegentConfig,
@@ -10,6 +11,7 @@ use release_regent_core::{
 };
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 /// Builder for creating complex configuration setups
 pub struct ConfigurationBuilder {
@@ -17,6 +19,20 @@ pub struct ConfigurationBuilder {
     global_config_path: Option<PathBuf>,
     /// Repository configuration file path
     repository_config_path: Option<PathBuf>,
+    /// Global configuration URL
+    global_config_url: Option<String>,
+    /// Repository configuration URL
+    repository_config_url: Option<String>,
+    /// Inline global configuration
+    global_config: Option<ReleaseRegentConfig>,
+    /// Inline repository configurations keyed by `(owner, repo)`
+    repository_configs: HashMap<(String, String), ReleaseRegentConfig>,
+    /// Timeout for fetching configuration URLs
+    remote_timeout: Duration,
+    /// Backoff for transient failures fetching configuration URLs
+    remote_retry_policy: RemoteRetryPolicy,
+    /// Whether plain `http` configuration URLs are allowed
+    allow_insecure: bool,
     /// Configuration search directories
     search_directories: Vec<PathBuf>,
     /// Custom configuration values to override
@@ -27,6 +43,8 @@ pub struct ConfigurationBuilder {
     strict_validation: bool,
     /// Whether to create missing configuration files
     create_missing: bool,
+    /// Whether `build` checks that every configured source can be loaded
+    validate_on_build: bool,
 }
 
 impl ConfigurationBuilder {
@@ -36,11 +54,19 @@ impl ConfigurationBuilder {
         Self {
             global_config_path: None,
             repository_config_path: None,
+            global_config_url: None,
+            repository_config_url: None,
+            global_config: None,
+            repository_configs: HashMap::new(),
+            remote_timeout: DEFAULT_REMOTE_TIMEOUT,
+            remote_retry_policy: RemoteRetryPolicy::default(),
+            allow_insecure: false,
             search_directories: Vec::new(),
             overrides: HashMap::new(),
             validator: None,
             strict_validation: false,
             create_missing: false,
+            validate_on_build: true,
         }
     }
 
@@ -58,6 +84,77 @@ impl ConfigurationBuilder {
         self
     }
 
+    /// Fetch the global configuration from a URL instead of local files
+    #[must_use]
+    pub fn with_global_config_url<U: Into<String>>(mut self, url: U) -> Self {
+        self.global_config_url = Some(url.into());
+        self
+    }
+
+    /// Fetch the configuration of repositories without a local
+    /// `{owner}-{repo}.toml` file from a URL
+    #[must_use]
+    pub fn with_repository_config_url<U: Into<String>>(mut self, url: U) -> Self {
+        self.repository_config_url = Some(url.into());
+        self
+    }
+
+    /// Use an already-constructed global configuration
+    ///
+    /// Takes precedence over global configuration files and
+    /// [`with_global_config_url`](Self::with_global_config_url). Overrides and
+    /// validation still apply.
+    #[must_use]
+    pub fn with_global_config(mut self, config: ReleaseRegentConfig) -> Self {
+        self.global_config = Some(config);
+        self
+    }
+
+    /// Use an already-constructed configuration for `owner/repo`
+    ///
+    /// Takes precedence over that repository's configuration file and
+    /// [`with_repository_config_url`](Self::with_repository_config_url), and
+    /// is merged over the global configuration like a repository file would
+    /// be. Overrides and validation still apply.
+    #[must_use]
+    pub fn with_repository_config<O: Into<String>, R: Into<String>>(
+        mut self,
+        owner: O,
+        repo: R,
+        config: ReleaseRegentConfig,
+    ) -> Self {
+        self.repository_configs
+            .insert((owner.into(), repo.into()), config);
+        self
+    }
+
+    /// Set the timeout for fetching configuration URLs (default 30 seconds)
+    #[must_use]
+    pub fn with_remote_timeout(mut self, timeout: Duration) -> Self {
+        self.remote_timeout = timeout;
+        self
+    }
+
+    /// Set how often and how quickly transient failures fetching
+    /// configuration URLs are retried (default: 2 retries starting at 200 ms)
+    ///
+    /// Use [`RemoteRetryPolicy::none`] to fail on the first error.
+    #[must_use]
+    pub fn with_remote_retry_policy(mut self, policy: RemoteRetryPolicy) -> Self {
+        self.remote_retry_policy = policy;
+        self
+    }
+
+    /// Allow configuration URLs that use plain `http`
+    ///
+    /// Configuration fetched without TLS can be tampered with in transit;
+    /// only use this for local development and tests.
+    #[must_use]
+    pub fn with_allow_insecure(mut self) -> Self {
+        self.allow_insecure = true;
+        self
+    }
+
     /// Add a directory to search for configuration files
     #[must_use]
     pub fn with_search_directory<P: AsRef<Path>>(mut self, directory: P) -> Self {
@@ -126,13 +223,33 @@ impl ConfigurationBuilder {
         self
     }
 
+    /// Check every configured source when building (default `true`)
+    ///
+    /// When enabled, [`build`](Self::build) reads, fetches, parses and
+    /// validates the configuration files, URLs and inline configurations, so
+    /// a broken setup fails at startup rather than on the first event. See
+    /// [`FileConfigurationProvider::validate_sources`].
+    #[must_use]
+    pub fn validate_on_build(mut self, enabled: bool) -> Self {
+        self.validate_on_build = enabled;
+        self
+    }
+
     /// Build the configuration provider
     ///
     /// # Errors
     /// - `ConfigProviderError::Builder` — the base directory cannot be determined
     /// - `ConfigProviderError::Io` — file-system access failed
+    /// - `ConfigProviderError::InvalidUrl` — a configuration URL is malformed
+    /// - `ConfigProviderError::InsecureUrl` — a configuration URL uses `http`
+    ///   without [`with_allow_insecure`](Self::with_allow_insecure)
+    /// - `ConfigProviderError::InvalidSources` — with
+    ///   [`validate_on_build`](Self::validate_on_build), one or more sources
+    ///   are missing, cannot be fetched, cannot be parsed or fail validation
     #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
     pub async fn build(self) -> ConfigProviderResult<FileConfigurationProvider> {
+        let validate_on_build = self.validate_on_build;
+
         // Determine the base directory for the configuration provider
         let base_dir = self.determine_base_directory()?;
 
@@ -166,11 +283,42 @@ impl ConfigurationBuilder {
             provider.set_repository_config_path(repo_path);
         }
 
+        // Configure remote sources
+        if let Some(url) = &self.global_config_url {
+            provider.set_global_config_source(
+                RemoteConfigSource::new(url, self.allow_insecure)?
+                    .with_timeout(self.remote_timeout)
+                    .with_retry_policy(self.remote_retry_policy.clone()),
+            );
+        }
+
+        if let Some(url) = &self.repository_config_url {
+            provider.set_repository_config_source(
+                RemoteConfigSource::new(url, self.allow_insecure)?
+                    .with_timeout(self.remote_timeout)
+                    .with_retry_policy(self.remote_retry_policy.clone()),
+            );
+        }
+
+        // Inline configurations take precedence over files and remote sources
+        if let Some(config) = self.global_config {
+            provider.set_global_config(config);
+        }
+
+        for ((owner, repo), config) in self.repository_configs {
+            provider.set_repository_config(owner, repo, config);
+        }
+
         // Enable creation of missing files if requested
         if self.create_missing {
             provider.enable_create_missing();
         }
 
+        // Fail fast on sources that cannot be loaded
+        if validate_on_build {
+            provider.validate_sources().await?;
+        }
+
         Ok(provider)
     }
 
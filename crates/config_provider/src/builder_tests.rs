@@ -89,3 +89,352 @@ async fn test_determine_base_directory() {
     let base_dir = builder.determine_base_directory().unwrap();
     assert_eq!(base_dir, temp_dir.path());
 }
+
+const REMOTE_CONFIG: &str = r#"
+[core]
+version_prefix = "remote-"
+
+[core.branches]
+main = "main"
+"#;
+
+async fn serve_config(route: &str) -> wiremock::MockServer {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(route))
+        .respond_with(ResponseTemplate::new(200).set_body_string(REMOTE_CONFIG))
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn test_build_rejects_insecure_url() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let result = ConfigurationBuilder::new()
+        .with_search_directory(temp_dir.path())
+        .with_global_config_url("http://config.example.com/release-regent.toml")
+        .build()
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ConfigProviderError::InsecureUrl { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_build_and_load_global_from_url() {
+    let server = serve_config("/release-regent.toml").await;
+    let temp_dir = TempDir::new().unwrap();
+
+    let config = ConfigurationBuilder::new()
+        .with_search_directory(temp_dir.path())
+        .with_global_config_url(format!("{}/release-regent.toml", server.uri()))
+        .with_remote_timeout(Duration::from_secs(5))
+        .with_allow_insecure()
+        .build_and_load_global()
+        .await
+        .unwrap();
+
+    assert_eq!(config.core.version_prefix, "remote-");
+}
+
+#[tokio::test]
+async fn test_build_and_load_global_retries_transient_url_failures() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/release-regent.toml"))
+        .respond_with(ResponseTemplate::new(502))
+        .up_to_n_times(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/release-regent.toml"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(REMOTE_CONFIG))
+        .mount(&server)
+        .await;
+    let temp_dir = TempDir::new().unwrap();
+
+    let config = ConfigurationBuilder::new()
+        .with_search_directory(temp_dir.path())
+        .with_global_config_url(format!("{}/release-regent.toml", server.uri()))
+        .with_remote_retry_policy(RemoteRetryPolicy {
+            max_retries: 2,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(10),
+        })
+        .with_allow_insecure()
+        .build_and_load_global()
+        .await
+        .unwrap();
+
+    assert_eq!(config.core.version_prefix, "remote-");
+}
+
+#[tokio::test]
+async fn test_repository_url_is_used_without_local_file() {
+    let server = serve_config("/repos.toml").await;
+    let temp_dir = TempDir::new().unwrap();
+
+    let provider = ConfigurationBuilder::new()
+        .with_search_directory(temp_dir.path())
+        .with_repository_config_url(format!("{}/repos.toml", server.uri()))
+        .with_allow_insecure()
+        .build()
+        .await
+        .unwrap();
+    let repo_config = provider
+        .load_repository_config("acme", "widgets", LoadOptions::default())
+        .await
+        .unwrap()
+        .expect("remote repository config should be used");
+
+    assert_eq!(repo_config.config.core.version_prefix, "remote-");
+    let source = provider
+        .get_config_source(Some("acme"), Some("widgets"))
+        .await
+        .unwrap();
+    assert_eq!(source.source_type, "remote");
+}
+
+fn config_with_prefix(prefix: &str) -> ReleaseRegentConfig {
+    let mut config = ReleaseRegentConfig::default();
+    config.core.version_prefix = prefix.to_string();
+    config
+}
+
+#[tokio::test]
+async fn test_provider_built_from_inline_values_loads_them_through_trait() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut repo_config = config_with_prefix("repo-");
+    repo_config.releases.draft = true;
+
+    let provider = ConfigurationBuilder::new()
+        .with_search_directory(temp_dir.path())
+        .with_global_config(config_with_prefix("global-"))
+        .with_repository_config("acme", "widgets", repo_config)
+        .build()
+        .await
+        .unwrap();
+
+    let global = provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(global.core.version_prefix, "global-");
+
+    let repo = provider
+        .load_repository_config("acme", "widgets", LoadOptions::default())
+        .await
+        .unwrap()
+        .expect("inline repository config should be returned");
+    assert_eq!(repo.owner, "acme");
+    assert_eq!(repo.name, "widgets");
+    assert_eq!(repo.config.core.version_prefix, "repo-");
+
+    let merged = provider
+        .get_merged_config("acme", "widgets", LoadOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(merged.core.version_prefix, "repo-");
+    assert!(merged.releases.draft);
+
+    let other = provider
+        .get_merged_config("acme", "gadgets", LoadOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(other.core.version_prefix, "global-");
+
+    assert!(provider
+        .config_exists(Some("acme"), Some("widgets"))
+        .await
+        .unwrap());
+    let source = provider.get_config_source(None, None).await.unwrap();
+    assert_eq!(source.source_type, "inline");
+    let listed = provider
+        .list_repository_configs(LoadOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].name, "widgets");
+}
+
+#[tokio::test]
+async fn test_inline_global_config_takes_precedence_over_file() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("release-regent.toml"),
+        crate::formats::serialize_config(&config_with_prefix("file-")).unwrap(),
+    )
+    .unwrap();
+
+    let config = ConfigurationBuilder::new()
+        .with_search_directory(temp_dir.path())
+        .with_global_config(config_with_prefix("inline-"))
+        .build_and_load_global()
+        .await
+        .unwrap();
+
+    assert_eq!(config.core.version_prefix, "inline-");
+}
+
+#[tokio::test]
+async fn test_inline_repository_config_is_merged_over_global_file() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("release-regent.toml"),
+        crate::formats::serialize_config(&config_with_prefix("file-")).unwrap(),
+    )
+    .unwrap();
+
+    let config = ConfigurationBuilder::new()
+        .with_search_directory(temp_dir.path())
+        .with_repository_config("acme", "widgets", config_with_prefix("inline-"))
+        .build_and_load(Some("acme"), Some("widgets"))
+        .await
+        .unwrap();
+
+    assert_eq!(config.core.version_prefix, "inline-");
+}
+
+#[tokio::test]
+async fn test_overrides_apply_to_inline_configs() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let config = ConfigurationBuilder::new()
+        .with_search_directory(temp_dir.path())
+        .with_global_config(ReleaseRegentConfig::default())
+        .with_override("branches.main_branch", "trunk")
+        .build_and_load_global()
+        .await
+        .unwrap();
+
+    assert_eq!(config.core.branches.main, "trunk");
+}
+
+fn invalid_sources(
+    result: ConfigProviderResult<FileConfigurationProvider>,
+) -> Vec<ConfigProviderError> {
+    match result {
+        Err(ConfigProviderError::InvalidSources { errors }) => errors,
+        Err(other) => panic!("expected InvalidSources, got {other:?}"),
+        Ok(_) => panic!("expected InvalidSources, got a provider"),
+    }
+}
+
+#[tokio::test]
+async fn test_build_validates_configured_paths() {
+    let temp_dir = TempDir::new().unwrap();
+    let global_path = temp_dir.path().join("release-regent.toml");
+    std::fs::write(
+        &global_path,
+        crate::formats::serialize_config(&config_with_prefix("file-")).unwrap(),
+    )
+    .unwrap();
+
+    let result = ConfigurationBuilder::new()
+        .with_global_config_path(&global_path)
+        .with_repository_config_path(temp_dir.path().join("missing.toml"))
+        .build()
+        .await;
+
+    let errors = invalid_sources(result);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(
+        matches!(&errors[0], ConfigProviderError::ConfigFileNotFound { path } if path.ends_with("missing.toml")),
+        "{errors:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_build_reports_every_invalid_source() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos.toml"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+    let temp_dir = TempDir::new().unwrap();
+    let global_path = temp_dir.path().join("release-regent.toml");
+    std::fs::write(&global_path, "[core\nversion_prefix = ").unwrap();
+    let mut invalid_repo = ReleaseRegentConfig::default();
+    invalid_repo.core.branches.main = String::new();
+
+    let result = ConfigurationBuilder::new()
+        .with_global_config_path(&global_path)
+        .with_repository_config_url(format!("{}/repos.toml", server.uri()))
+        .with_repository_config("acme", "widgets", invalid_repo)
+        .with_remote_retry_policy(RemoteRetryPolicy::none())
+        .with_allow_insecure()
+        .build()
+        .await;
+
+    let errors = invalid_sources(result);
+    assert_eq!(errors.len(), 3, "{errors:?}");
+    assert!(
+        matches!(&errors[0], ConfigProviderError::ParseError { path, .. } if *path == global_path),
+        "{errors:?}"
+    );
+    assert!(
+        matches!(
+            &errors[1],
+            ConfigProviderError::RemoteStatus { status: 404, .. }
+        ),
+        "{errors:?}"
+    );
+    assert!(
+        matches!(&errors[2], ConfigProviderError::ValidationError { path, errors }
+            if path == Path::new("<inline acme/widgets>")
+                && errors.iter().any(|e| e.contains("Main branch"))),
+        "{errors:?}"
+    );
+    let message = ConfigProviderError::InvalidSources { errors }.to_string();
+    assert!(
+        message.starts_with("3 configuration source(s) are invalid"),
+        "{message}"
+    );
+}
+
+#[tokio::test]
+async fn test_build_without_validation_defers_errors_to_load() {
+    let temp_dir = TempDir::new().unwrap();
+    let missing = temp_dir.path().join("release-regent.toml");
+
+    let provider = ConfigurationBuilder::new()
+        .with_global_config_path(&missing)
+        .validate_on_build(false)
+        .build()
+        .await
+        .unwrap();
+
+    assert!(provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_build_does_not_require_files_that_are_created_on_demand() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let result = ConfigurationBuilder::new()
+        .with_global_config_path(temp_dir.path().join("release-regent.toml"))
+        .with_repository_config_path(temp_dir.path().join("repo.toml"))
+        .with_create_missing()
+        .build()
+        .await;
+
+    assert!(result.is_ok(), "{:?}", result.err());
+    assert!(!temp_dir.path().join("repo.toml").exists());
+}
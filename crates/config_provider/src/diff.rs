@@ -0,0 +1,159 @@
+//! Field-level comparison of configurations.
+//!
+//! [`diff_configs`] compares two configurations key by key and reports every
+//! leaf value that was added, changed or removed, using dotted paths such as
+//! `core.branches.main`. Tables are compared recursively; lists are compared
+//! as a whole because merging replaces a list rather than combining its
+//! entries. [`diff_effective_config`] applies this to the global
+//! configuration and the merged configuration of one repository.
+
+use crate::errors::{ConfigProviderError, ConfigProviderResult};
+use release_regent_core::{
+    config::ReleaseRegentConfig,
+    traits::{configuration_provider::LoadOptions, ConfigurationProvider},
+};
+use serde::Serialize;
+use std::fmt;
+use toml::{Table, Value};
+
+#[cfg(test)]
+#[path = "diff_tests.rs"]
+mod tests;
+
+/// One difference between two configurations.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FieldDiff {
+    /// The field is only set in the second configuration
+    Added {
+        /// Dotted path of the field
+        path: String,
+        /// Value in the second configuration
+        value: Value,
+    },
+    /// The field has a different value in each configuration
+    Changed {
+        /// Dotted path of the field
+        path: String,
+        /// Value in the first configuration
+        from: Value,
+        /// Value in the second configuration
+        to: Value,
+    },
+    /// The field is only set in the first configuration
+    Removed {
+        /// Dotted path of the field
+        path: String,
+        /// Value in the first configuration
+        value: Value,
+    },
+}
+
+impl FieldDiff {
+    /// Dotted path of the field that differs.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Added { path, .. } | Self::Changed { path, .. } | Self::Removed { path, .. } => {
+                path
+            }
+        }
+    }
+}
+
+impl fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added { path, value } => write!(f, "+ {path} = {value}"),
+            Self::Changed { path, from, to } => write!(f, "~ {path}: {from} -> {to}"),
+            Self::Removed { path, value } => write!(f, "- {path} = {value}"),
+        }
+    }
+}
+
+/// Compare two configurations field by field.
+///
+/// Differences are returned in path order.
+///
+/// # Errors
+/// - `ConfigProviderError::SerializeError` — a configuration could not be converted for comparison
+#[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+pub fn diff_configs(
+    base: &ReleaseRegentConfig,
+    other: &ReleaseRegentConfig,
+) -> ConfigProviderResult<Vec<FieldDiff>> {
+    let base = to_table(base)?;
+    let other = to_table(other)?;
+
+    let mut diffs = Vec::new();
+    diff_tables("", &base, &other, &mut diffs);
+    Ok(diffs)
+}
+
+/// Compare the global configuration with the effective configuration of
+/// `owner/repo`, as returned by
+/// [`get_merged_config`](ConfigurationProvider::get_merged_config).
+///
+/// # Errors
+/// - `ConfigProviderError::Core` — loading either configuration failed
+/// - `ConfigProviderError::SerializeError` — a configuration could not be converted for comparison
+#[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+pub async fn diff_effective_config<P: ConfigurationProvider + ?Sized>(
+    provider: &P,
+    owner: &str,
+    repo: &str,
+) -> ConfigProviderResult<Vec<FieldDiff>> {
+    let global = provider.load_global_config(LoadOptions::default()).await?;
+    let merged = provider
+        .get_merged_config(owner, repo, LoadOptions::default())
+        .await?;
+    diff_configs(&global, &merged)
+}
+
+#[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+fn to_table(config: &ReleaseRegentConfig) -> ConfigProviderResult<Table> {
+    Table::try_from(config).map_err(|e| {
+        ConfigProviderError::serialize_error_with_source(
+            format!("Failed to convert configuration for comparison: {e}"),
+            e,
+        )
+    })
+}
+
+fn diff_tables(prefix: &str, base: &Table, other: &Table, diffs: &mut Vec<FieldDiff>) {
+    let mut keys: Vec<&String> = base.keys().chain(other.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    for key in keys {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+
+        match (base.get(key), other.get(key)) {
+            (Some(Value::Table(from)), Some(Value::Table(to))) => {
+                diff_tables(&path, from, to, diffs);
+            }
+            // Report a table that exists on one side only leaf by leaf too.
+            (Some(Value::Table(from)), None) => diff_tables(&path, from, &Table::new(), diffs),
+            (None, Some(Value::Table(to))) => diff_tables(&path, &Table::new(), to, diffs),
+            (Some(from), Some(to)) if from != to => diffs.push(FieldDiff::Changed {
+                path,
+                from: from.clone(),
+                to: to.clone(),
+            }),
+            (Some(_), Some(_)) => {}
+            (Some(value), None) => diffs.push(FieldDiff::Removed {
+                path,
+                value: value.clone(),
+            }),
+            (None, Some(value)) => diffs.push(FieldDiff::Added {
+                path,
+                value: value.clone(),
+            }),
+            (None, None) => unreachable!("key comes from one of the tables"),
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! Unit tests for configuration diffing.
+
+use super::*;
+use crate::FileConfigurationProvider;
+use release_regent_core::config::WebhookConfig;
+use std::collections::HashMap;
+use tempfile::TempDir;
+
+fn find<'a>(diffs: &'a [FieldDiff], path: &str) -> &'a FieldDiff {
+    diffs
+        .iter()
+        .find(|d| d.path() == path)
+        .unwrap_or_else(|| panic!("no difference at {path}: {diffs:?}"))
+}
+
+#[test]
+fn test_identical_configs_have_no_differences() {
+    let config = ReleaseRegentConfig::default();
+
+    assert!(diff_configs(&config, &config).unwrap().is_empty());
+}
+
+#[test]
+fn test_overridden_field_is_changed_with_both_values() {
+    let base = ReleaseRegentConfig::default();
+    let mut other = base.clone();
+    other.core.branches.main = "trunk".to_string();
+
+    let diffs = diff_configs(&base, &other).unwrap();
+
+    assert_eq!(
+        diffs,
+        vec![FieldDiff::Changed {
+            path: "core.branches.main".to_string(),
+            from: Value::String("main".to_string()),
+            to: Value::String("trunk".to_string()),
+        }]
+    );
+}
+
+#[test]
+fn test_optional_sections_are_added_and_removed() {
+    let base = ReleaseRegentConfig::default();
+    let mut other = base.clone();
+    other.group = Some("platform".to_string());
+    other.notifications.webhook = Some(WebhookConfig {
+        url: "https://hooks.example.com".to_string(),
+        headers: HashMap::from([("X-Team".to_string(), "release".into())]),
+    });
+
+    let added = diff_configs(&base, &other).unwrap();
+    assert!(matches!(find(&added, "group"), FieldDiff::Added { .. }));
+    // Nested tables are reported leaf by leaf.
+    assert!(matches!(
+        find(&added, "notifications.webhook.headers.X-Team"),
+        FieldDiff::Added { value, .. } if value.as_str() == Some("release")
+    ));
+    assert!(matches!(
+        find(&added, "notifications.webhook.url"),
+        FieldDiff::Added { .. }
+    ));
+
+    let removed = diff_configs(&other, &base).unwrap();
+    assert!(matches!(find(&removed, "group"), FieldDiff::Removed { .. }));
+}
+
+#[test]
+fn test_lists_are_compared_as_a_whole() {
+    let mut base = ReleaseRegentConfig::default();
+    base.versioning.excluded_pr_authors = vec!["dependabot[bot]".to_string()];
+    let mut other = base.clone();
+    other
+        .versioning
+        .excluded_pr_authors
+        .push("renovate[bot]".to_string());
+
+    let diffs = diff_configs(&base, &other).unwrap();
+
+    assert_eq!(diffs.len(), 1);
+    match &diffs[0] {
+        FieldDiff::Changed { path, from, to } => {
+            assert_eq!(path, "versioning.excluded_pr_authors");
+            assert_eq!(from.as_array().unwrap().len(), 1);
+            assert_eq!(to.as_array().unwrap().len(), 2);
+        }
+        other => panic!("expected Changed, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_display_marks_each_kind() {
+    let changed = FieldDiff::Changed {
+        path: "core.version_prefix".to_string(),
+        from: Value::String("v".to_string()),
+        to: Value::String("release-".to_string()),
+    };
+
+    assert_eq!(
+        changed.to_string(),
+        "~ core.version_prefix: \"v\" -> \"release-\""
+    );
+}
+
+#[tokio::test]
+async fn test_diff_effective_config_reports_repository_override() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join("release-regent.toml"),
+        "[core]\nversion_prefix = \"v\"\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("acme-widgets.toml"),
+        "[core]\nversion_prefix = \"widgets-v\"\n",
+    )
+    .unwrap();
+    let provider = FileConfigurationProvider::new(dir.path()).await.unwrap();
+
+    let diffs = diff_effective_config(&provider, "acme", "widgets")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        diffs,
+        vec![FieldDiff::Changed {
+            path: "core.version_prefix".to_string(),
+            from: Value::String("v".to_string()),
+            to: Value::String("widgets-v".to_string()),
+        }]
+    );
+}
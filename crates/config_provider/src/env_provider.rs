@@ -0,0 +1,306 @@
+//! Environment-variable configuration provider.
+//!
+//! [`EnvironmentConfigurationProvider`] reads configuration values from
+//! variables named `{PREFIX}__{SECTION}__{KEY}`, with `__` separating the
+//! levels of the dotted configuration path. With the default prefix,
+//! `RELEASE_REGENT__CORE__VERSION_PREFIX=release-` sets
+//! `core.version_prefix` and
+//! `RELEASE_REGENT__NOTIFICATIONS__SLACK__WEBHOOK_URL=...` sets
+//! `notifications.slack.webhook_url`.
+//!
+//! Values are read as TOML values where possible, so `true`, `3` and
+//! `["a", "b"]` become a boolean, an integer and a list; anything else is
+//! taken as a string. Quote a value (`'"1.0"'`) to force a string.
+//!
+//! The environment only supplies global configuration. It is meant to be
+//! combined with file-based configuration through
+//! [`LayeredConfigurationProvider`](crate::LayeredConfigurationProvider), so
+//! that deployments can keep secrets out of configuration files.
+
+use crate::validation::ConfigValidator;
+use async_trait::async_trait;
+use release_regent_core::{
+    config::ReleaseRegentConfig,
+    errors::CoreError,
+    secret::describe_toml_error,
+    traits::{
+        configuration_provider::{
+            ConfigurationSource, LoadOptions, RepositoryConfig, ValidationResult,
+        },
+        ConfigurationProvider,
+    },
+    CoreResult,
+};
+use std::collections::BTreeMap;
+use tokio::sync::RwLock;
+use toml::{Table, Value};
+use tracing::debug;
+
+#[cfg(test)]
+#[path = "env_provider_tests.rs"]
+mod tests;
+
+/// Prefix used by [`EnvironmentConfigurationProvider::new`].
+pub const DEFAULT_ENV_PREFIX: &str = "RELEASE_REGENT";
+
+/// Separator between the levels of a configuration path in a variable name.
+const PATH_SEPARATOR: &str = "__";
+
+/// Configuration provider backed by environment variables.
+pub struct EnvironmentConfigurationProvider {
+    /// Variable name prefix, without the trailing separator
+    prefix: String,
+    /// Matching variables, keyed by name
+    variables: RwLock<BTreeMap<String, String>>,
+    /// Whether [`reload_config`](ConfigurationProvider::reload_config) re-reads
+    /// the process environment
+    from_process: bool,
+    /// Configuration validator
+    validator: ConfigValidator,
+}
+
+impl EnvironmentConfigurationProvider {
+    /// Read variables starting with [`DEFAULT_ENV_PREFIX`] from the process
+    /// environment.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_prefix(DEFAULT_ENV_PREFIX)
+    }
+
+    /// Read variables starting with `prefix` from the process environment.
+    #[must_use]
+    pub fn with_prefix(prefix: impl Into<String>) -> Self {
+        let prefix = prefix.into();
+        let variables = Self::matching(&prefix, std::env::vars());
+        Self {
+            prefix,
+            variables: RwLock::new(variables),
+            from_process: true,
+            validator: ConfigValidator::new(),
+        }
+    }
+
+    /// Use a fixed set of variables instead of the process environment.
+    #[must_use]
+    pub fn from_vars<I, K, V>(prefix: impl Into<String>, vars: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let prefix = prefix.into();
+        let variables =
+            Self::matching(&prefix, vars.into_iter().map(|(k, v)| (k.into(), v.into())));
+        Self {
+            prefix,
+            variables: RwLock::new(variables),
+            from_process: false,
+            validator: ConfigValidator::new(),
+        }
+    }
+
+    fn matching(
+        prefix: &str,
+        vars: impl IntoIterator<Item = (String, String)>,
+    ) -> BTreeMap<String, String> {
+        let start = format!("{prefix}{PATH_SEPARATOR}");
+        vars.into_iter()
+            .filter(|(name, _)| name.starts_with(&start))
+            .collect()
+    }
+
+    /// Dotted configuration path for a variable name, e.g.
+    /// `core.version_prefix` for `RELEASE_REGENT__CORE__VERSION_PREFIX`.
+    fn config_path(&self, name: &str) -> Option<Vec<String>> {
+        let rest = name
+            .strip_prefix(&self.prefix)?
+            .strip_prefix(PATH_SEPARATOR)?;
+        let path: Vec<String> = rest
+            .split(PATH_SEPARATOR)
+            .map(str::to_ascii_lowercase)
+            .collect();
+        if path.iter().any(String::is_empty) {
+            return None;
+        }
+        Some(path)
+    }
+
+    fn parse_value(raw: &str) -> Value {
+        toml::from_str::<Table>(&format!("value = {raw}"))
+            .ok()
+            .and_then(|mut table| table.remove("value"))
+            .unwrap_or_else(|| Value::String(raw.to_string()))
+    }
+
+    /// Build the configuration: the defaults with every variable applied.
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large; established pattern
+    fn build_config(
+        &self,
+        variables: &BTreeMap<String, String>,
+    ) -> CoreResult<ReleaseRegentConfig> {
+        let mut table = Table::try_from(ReleaseRegentConfig::default()).map_err(|e| {
+            CoreError::config(format!("Failed to convert default configuration: {e}"))
+        })?;
+        self.insert_variables(&mut table, variables)?;
+
+        Value::Table(table)
+            .try_into()
+            .map_err(|e: toml::de::Error| {
+                CoreError::config(format!(
+                    "Invalid configuration in {}{PATH_SEPARATOR}* variables: {}",
+                    self.prefix,
+                    describe_toml_error(&e, None)
+                ))
+            })
+    }
+
+    /// Set the value of every variable at its configuration path in `table`.
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large; established pattern
+    fn insert_variables(
+        &self,
+        table: &mut Table,
+        variables: &BTreeMap<String, String>,
+    ) -> CoreResult<()> {
+        for (name, raw) in variables {
+            let Some(path) = self.config_path(name) else {
+                debug!(variable = %name, "Ignoring malformed configuration variable name");
+                continue;
+            };
+            let Some((key, sections)) = path.split_last() else {
+                return Err(CoreError::config(format!(
+                    "{name}: no configuration key in variable name"
+                )));
+            };
+            let mut target = &mut *table;
+            for section in sections {
+                let entry = target
+                    .entry(section.clone())
+                    .or_insert_with(|| Value::Table(Table::new()));
+                target = match entry {
+                    Value::Table(nested) => nested,
+                    _ => {
+                        return Err(CoreError::config(format!(
+                            "{name}: {section} is not a configuration section"
+                        )))
+                    }
+                };
+            }
+            target.insert(key.clone(), Self::parse_value(raw));
+        }
+        Ok(())
+    }
+}
+
+impl Default for EnvironmentConfigurationProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ConfigurationProvider for EnvironmentConfigurationProvider {
+    async fn load_global_config(&self, _options: LoadOptions) -> CoreResult<ReleaseRegentConfig> {
+        let variables = self.variables.read().await;
+        self.build_config(&variables)
+    }
+
+    async fn load_repository_config(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _options: LoadOptions,
+    ) -> CoreResult<Option<RepositoryConfig>> {
+        Ok(None)
+    }
+
+    async fn get_merged_config(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        options: LoadOptions,
+    ) -> CoreResult<ReleaseRegentConfig> {
+        self.load_global_config(options).await
+    }
+
+    async fn validate_config(&self, config: &ReleaseRegentConfig) -> CoreResult<ValidationResult> {
+        let result = self
+            .validator
+            .validate(config)
+            .map_err(|e| CoreError::config(e.to_string()))?;
+        Ok(ValidationResult {
+            is_valid: result.is_valid,
+            errors: result.errors,
+            warnings: result.warnings,
+        })
+    }
+
+    async fn save_config(
+        &self,
+        _config: &ReleaseRegentConfig,
+        _owner: Option<&str>,
+        _repo: Option<&str>,
+        _global: bool,
+    ) -> CoreResult<()> {
+        Err(CoreError::not_supported(
+            "save_config",
+            "environment configuration is read-only",
+        ))
+    }
+
+    async fn list_repository_configs(
+        &self,
+        _options: LoadOptions,
+    ) -> CoreResult<Vec<RepositoryConfig>> {
+        Ok(Vec::new())
+    }
+
+    async fn get_config_source(
+        &self,
+        _owner: Option<&str>,
+        _repo: Option<&str>,
+    ) -> CoreResult<ConfigurationSource> {
+        Ok(ConfigurationSource {
+            format: "env".to_string(),
+            loaded_at: chrono::Utc::now(),
+            location: format!("{}{PATH_SEPARATOR}*", self.prefix),
+            source_type: "environment".to_string(),
+        })
+    }
+
+    async fn reload_config(&self, _owner: Option<&str>, _repo: Option<&str>) -> CoreResult<()> {
+        if self.from_process {
+            *self.variables.write().await = Self::matching(&self.prefix, std::env::vars());
+        }
+        Ok(())
+    }
+
+    async fn config_exists(&self, owner: Option<&str>, repo: Option<&str>) -> CoreResult<bool> {
+        if owner.is_some() && repo.is_some() {
+            return Ok(false);
+        }
+        Ok(!self.variables.read().await.is_empty())
+    }
+
+    fn supported_formats(&self) -> Vec<String> {
+        vec!["env".to_string()]
+    }
+
+    async fn get_default_config(&self) -> CoreResult<ReleaseRegentConfig> {
+        Ok(ReleaseRegentConfig::default())
+    }
+
+    /// Only the paths named by a variable, at any scope.
+    async fn load_explicit_values(
+        &self,
+        _owner: Option<&str>,
+        _repo: Option<&str>,
+        _options: LoadOptions,
+    ) -> CoreResult<Option<Table>> {
+        let variables = self.variables.read().await;
+        // Reject values the full configuration would reject, with the same error.
+        self.build_config(&variables)?;
+        let mut table = Table::new();
+        self.insert_variables(&mut table, &variables)?;
+        Ok(Some(table))
+    }
+}
@@ -0,0 +1,142 @@
+//! Tests for the environment-variable configuration provider.
+
+use super::*;
+
+fn provider(vars: &[(&str, &str)]) -> EnvironmentConfigurationProvider {
+    EnvironmentConfigurationProvider::from_vars(DEFAULT_ENV_PREFIX, vars.iter().copied())
+}
+
+#[tokio::test]
+async fn test_variables_map_to_nested_config_paths() {
+    let provider = provider(&[
+        ("RELEASE_REGENT__CORE__VERSION_PREFIX", "release-"),
+        ("RELEASE_REGENT__CORE__BRANCHES__MAIN", "trunk"),
+        (
+            "RELEASE_REGENT__NOTIFICATIONS__SLACK__WEBHOOK_URL",
+            "https://hooks.slack.com/services/T000/B000/secret",
+        ),
+    ]);
+
+    let config = provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(config.core.version_prefix, "release-");
+    assert_eq!(config.core.branches.main, "trunk");
+    assert_eq!(
+        config.notifications.slack.unwrap().webhook_url.expose(),
+        "https://hooks.slack.com/services/T000/B000/secret"
+    );
+}
+
+#[tokio::test]
+async fn test_values_are_read_as_toml_where_possible() {
+    let provider = provider(&[
+        ("RELEASE_REGENT__RELEASES__DRAFT", "true"),
+        ("RELEASE_REGENT__ERROR_HANDLING__MAX_RETRIES", "7"),
+        (
+            "RELEASE_REGENT__VERSIONING__EXCLUDED_PR_AUTHORS",
+            r#"["renovate[bot]"]"#,
+        ),
+    ]);
+
+    let config = provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+
+    assert!(config.releases.draft);
+    assert_eq!(config.error_handling.max_retries, 7);
+    assert_eq!(config.versioning.excluded_pr_authors, vec!["renovate[bot]"]);
+}
+
+#[tokio::test]
+async fn test_explicit_values_hold_only_the_variables() {
+    let provider = provider(&[
+        ("RELEASE_REGENT__RELEASES__DRAFT", "false"),
+        ("RELEASE_REGENT__CORE__BRANCHES__MAIN", "trunk"),
+    ]);
+
+    let values = provider
+        .load_explicit_values(None, None, LoadOptions::default())
+        .await
+        .unwrap()
+        .expect("explicit values");
+
+    let expected: Table = toml::from_str(
+        r#"
+        releases = { draft = false }
+        core = { branches = { main = "trunk" } }
+        "#,
+    )
+    .unwrap();
+    assert_eq!(values, expected);
+}
+
+#[tokio::test]
+async fn test_explicit_values_reject_a_value_of_wrong_type() {
+    let provider = provider(&[("RELEASE_REGENT__RELEASES__DRAFT", "sometimes")]);
+
+    let result = provider
+        .load_explicit_values(None, None, LoadOptions::default())
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Config { .. })));
+}
+
+#[tokio::test]
+async fn test_unrelated_and_malformed_variables_are_ignored() {
+    let provider = provider(&[
+        ("PATH", "/usr/bin"),
+        ("RELEASE_REGENT_CORE__VERSION_PREFIX", "ignored-"),
+        ("RELEASE_REGENT____", "ignored"),
+    ]);
+
+    let config = provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(config.core.version_prefix, "v");
+}
+
+#[tokio::test]
+async fn test_value_of_wrong_type_is_a_config_error() {
+    let provider = provider(&[("RELEASE_REGENT__ERROR_HANDLING__MAX_RETRIES", "many")]);
+
+    let err = provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, CoreError::Config { .. }), "got: {err:?}");
+    assert!(err.to_string().contains("RELEASE_REGENT__*"), "got: {err}");
+}
+
+#[tokio::test]
+async fn test_config_exists_only_for_global_scope_with_variables() {
+    let empty = provider(&[]);
+    assert!(!empty.config_exists(None, None).await.unwrap());
+
+    let set = provider(&[("RELEASE_REGENT__CORE__VERSION_PREFIX", "v")]);
+    assert!(set.config_exists(None, None).await.unwrap());
+    assert!(!set
+        .config_exists(Some("owner"), Some("repo"))
+        .await
+        .unwrap());
+    assert!(set
+        .load_repository_config("owner", "repo", LoadOptions::default())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_save_config_is_not_supported() {
+    let result = provider(&[])
+        .save_config(&ReleaseRegentConfig::default(), None, None, true)
+        .await;
+
+    assert!(matches!(result, Err(CoreError::NotSupported { .. })));
+}
@@ -1,6 +1,6 @@
 //! Error types and handling for the configuration provider crate.
 
-use release_regent_core::errors::CoreError;
+use release_regent_core::{errors::CoreError, secret::describe_toml_error};
 use std::path::PathBuf;
 use thiserror::Error;
 
@@ -71,10 +71,47 @@ pub enum ConfigProviderError {
     #[error("Schema validation error: {message}")]
     SchemaValidation { message: String },
 
+    /// Configuration file version this release cannot read or migrate
+    #[error("Unsupported configuration version {version}; this release supports versions 1 to {supported}")]
+    UnsupportedConfigVersion { version: i64, supported: u32 },
+
+    /// Remote configuration URL could not be parsed
+    #[error("Invalid configuration URL '{url}': {reason}")]
+    InvalidUrl { url: String, reason: String },
+
+    /// Remote configuration URL does not use `https` and insecure URLs are not allowed
+    #[error("Refusing to load configuration from insecure URL '{url}'; use https or explicitly allow insecure URLs")]
+    InsecureUrl { url: String },
+
+    /// Remote configuration server answered with a non-success status
+    #[error("Configuration request to {url} failed with HTTP status {status}")]
+    RemoteStatus { url: String, status: u16 },
+
+    /// Remote configuration request did not complete in time
+    #[error("Timed out after {timeout:?} fetching configuration from {url}")]
+    RemoteTimeout {
+        url: String,
+        timeout: std::time::Duration,
+    },
+
+    /// Remote configuration request failed (connection, TLS or body errors)
+    #[error("Failed to fetch configuration from {url}: {message}")]
+    RemoteRequest {
+        url: String,
+        message: String,
+        #[source]
+        source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
+
     /// Builder configuration errors
     #[error("Configuration builder error: {message}")]
     Builder { message: String },
 
+    /// One or more configuration sources are unusable; every problem found
+    /// is listed rather than only the first
+    #[error("{} configuration source(s) are invalid: {}", .errors.len(), join_errors(.errors))]
+    InvalidSources { errors: Vec<ConfigProviderError> },
+
     /// Core error passthrough
     #[error("Core error: {0}")]
     Core(#[from] CoreError),
@@ -153,6 +190,32 @@ impl ConfigProviderError {
             message: message.into(),
         }
     }
+
+    /// Returns `true` when fetching a remote configuration failed in a way
+    /// that may succeed on a later attempt.
+    ///
+    /// Timeouts, connection and body-read failures, `408`, `429` and `5xx`
+    /// responses are retryable. Other statuses (such as `404`), unsupported
+    /// formats and parse errors are not, and neither is any non-remote error.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::RemoteTimeout { .. } | Self::RemoteRequest { .. } => true,
+            Self::RemoteStatus { status, .. } => {
+                matches!(status, 408 | 429) || (500..600).contains(status)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Render `errors` as a single `; `-separated line.
+fn join_errors(errors: &[ConfigProviderError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
 }
 
 impl From<std::io::Error> for ConfigProviderError {
@@ -162,12 +225,14 @@ impl From<std::io::Error> for ConfigProviderError {
 }
 
 impl From<toml::de::Error> for ConfigProviderError {
+    // The TOML error quotes the parsed content, which may hold secrets, so
+    // only its message is kept.
     fn from(error: toml::de::Error) -> Self {
-        let message = format!("TOML parsing error: {error}");
+        let message = format!("TOML parsing error: {}", describe_toml_error(&error, None));
         Self::ParseError {
             path: PathBuf::new(), // Will be set by caller if available
             reason: message,
-            source: Some(Box::new(error)),
+            source: None,
         }
     }
 }
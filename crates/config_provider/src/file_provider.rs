@@ -2,10 +2,12 @@
 
 use crate::errors::{ConfigProviderError, ConfigProviderResult};
 use crate::formats::{is_toml_path, parse_config, serialize_config, validate_toml_path};
+use crate::remote::RemoteConfigSource;
 use crate::validation::ConfigValidator;
+use crate::watch::{ConfigChange, ConfigWatchGuard, WatchContext, GLOBAL_CONFIG_FILE_NAMES};
 use async_trait::async_trait;
 use release_regent_core::{
-    config::{ReleaseRegentConfig, VersioningStrategy},
+    config::{ReleaseRegentConfig, TagSigningConfig, VersioningStrategy},
     errors::CoreError,
     traits::{
         configuration_provider::{
@@ -16,36 +18,60 @@ use release_regent_core::{
 };
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::fs;
 use tracing::{debug, info, warn};
 
+#[cfg(test)]
+#[path = "file_provider_tests.rs"]
+mod tests;
+
 /// File-based configuration provider with TOML support
+///
+/// Parsed configurations are cached in memory, keyed by the file they were
+/// loaded from and that file's modification time, so a file is only re-read
+/// and re-parsed after it changes. By default an entry stays valid until its
+/// file is modified; [`with_cache`](Self::with_cache) additionally bounds how
+/// long an entry may be served.
 pub struct FileConfigurationProvider {
     /// Base directory for configuration files
     base_directory: PathBuf,
     /// Additional directories to search for configuration files
     search_directories: Vec<PathBuf>,
     /// Configuration validator
-    validator: ConfigValidator,
+    validator: Arc<ConfigValidator>,
     /// Configuration overrides
     overrides: HashMap<String, String>,
     /// Specific global configuration file path
     global_config_path: Option<PathBuf>,
     /// Specific repository configuration file path
     repository_config_path: Option<PathBuf>,
+    /// Remote global configuration, used instead of local files when set
+    global_config_source: Option<RemoteConfigSource>,
+    /// Remote configuration for repositories without a file of their own
+    repository_config_source: Option<RemoteConfigSource>,
+    /// Inline global configuration, used instead of files and remote sources
+    global_config: Option<ReleaseRegentConfig>,
+    /// Inline repository configurations keyed by `(owner, repo)`, used
+    /// instead of files and remote sources
+    repository_configs: HashMap<(String, String), ReleaseRegentConfig>,
     /// Whether to create missing configuration files
     create_missing: bool,
     /// Cached configurations
     config_cache: tokio::sync::RwLock<HashMap<String, CachedConfig>>,
+    /// Maximum age of a cache entry; `None` keeps entries until the file changes
+    cache_ttl: Option<Duration>,
 }
 
 /// Cached configuration entry
 #[derive(Clone)]
 struct CachedConfig {
     config: ReleaseRegentConfig,
+    /// Modification time of `file_path` when it was parsed
     last_modified: std::time::SystemTime,
-    #[allow(dead_code)] // retained for future cache-invalidation comparisons
     file_path: PathBuf,
+    cached_at: Instant,
 }
 
 impl FileConfigurationProvider {
@@ -72,15 +98,78 @@ impl FileConfigurationProvider {
         Ok(Self {
             base_directory: base_dir,
             search_directories: Vec::new(),
-            validator: ConfigValidator::new(),
+            validator: Arc::new(ConfigValidator::new()),
             overrides: HashMap::new(),
             global_config_path: None,
             repository_config_path: None,
+            global_config_source: None,
+            repository_config_source: None,
+            global_config: None,
+            repository_configs: HashMap::new(),
             create_missing: false,
             config_cache: tokio::sync::RwLock::new(HashMap::new()),
+            cache_ttl: None,
         })
     }
 
+    /// Serve cached configurations for at most `ttl` after they were loaded.
+    ///
+    /// Entries are still invalidated as soon as their file's modification
+    /// time changes, so edits are picked up immediately; the TTL additionally
+    /// forces a periodic re-read, e.g. for files replaced with an older
+    /// modification time. A zero `ttl` disables caching.
+    #[must_use]
+    pub fn with_cache(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Watch the configuration files and call `callback` whenever one of them
+    /// changes.
+    ///
+    /// The base directory, the search directories and the directories of the
+    /// explicitly set global and repository files are watched. Each change is
+    /// parsed, has overrides applied and is validated before `callback` is
+    /// called; an edit that fails is logged and skipped, so the last
+    /// configuration delivered stays in effect. Cached entries for changed
+    /// files are invalidated by their new modification time, so later loads
+    /// see the edit too.
+    ///
+    /// `callback` runs on the watcher's thread. Watching stops when the
+    /// returned guard is dropped.
+    ///
+    /// # Errors
+    /// - `ConfigProviderError::FileSystem` — the watcher could not be created
+    ///   or a directory could not be watched
+    #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+    pub fn watch<F>(&self, callback: F) -> ConfigProviderResult<ConfigWatchGuard>
+    where
+        F: Fn(ConfigChange) + Send + 'static,
+    {
+        let mut directories = vec![self.base_directory.clone()];
+        directories.extend(self.search_directories.iter().cloned());
+        for file in [&self.global_config_path, &self.repository_config_path]
+            .into_iter()
+            .flatten()
+        {
+            if let Some(parent) = file.parent().filter(|p| !p.as_os_str().is_empty()) {
+                directories.push(parent.to_path_buf());
+            }
+        }
+        directories.sort();
+        directories.dedup();
+        directories.retain(|d| d.is_dir());
+
+        WatchContext {
+            directories,
+            global_config_path: self.global_config_path.clone(),
+            repository_config_path: self.repository_config_path.clone(),
+            validator: Arc::clone(&self.validator),
+            overrides: self.overrides.clone(),
+        }
+        .start(callback)
+    }
+
     /// Add a search directory for configuration files
     pub fn add_search_directory<P: AsRef<Path>>(&mut self, directory: P) {
         self.search_directories
@@ -89,7 +178,7 @@ impl FileConfigurationProvider {
 
     /// Set the configuration validator
     pub fn set_validator(&mut self, validator: ConfigValidator) {
-        self.validator = validator;
+        self.validator = Arc::new(validator);
     }
 
     /// Set configuration overrides
@@ -107,11 +196,142 @@ impl FileConfigurationProvider {
         self.repository_config_path = Some(path.as_ref().to_path_buf());
     }
 
+    /// Load the global configuration from a remote source instead of local
+    /// files.
+    pub fn set_global_config_source(&mut self, source: RemoteConfigSource) {
+        self.global_config_source = Some(source);
+    }
+
+    /// Load the configuration of repositories that have no
+    /// `{owner}-{repo}.toml` file from a remote source. Takes precedence over
+    /// [`set_repository_config_path`](Self::set_repository_config_path).
+    pub fn set_repository_config_source(&mut self, source: RemoteConfigSource) {
+        self.repository_config_source = Some(source);
+    }
+
+    /// Use `config` as the global configuration instead of loading it from
+    /// files or a remote source.
+    pub fn set_global_config(&mut self, config: ReleaseRegentConfig) {
+        self.global_config = Some(config);
+    }
+
+    /// Use `config` as the configuration of `owner/repo` instead of loading
+    /// it from files or a remote source.
+    pub fn set_repository_config(
+        &mut self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        config: ReleaseRegentConfig,
+    ) {
+        self.repository_configs
+            .insert((owner.into(), repo.into()), config);
+    }
+
+    /// The inline configuration of `owner/repo`, if one was set.
+    fn inline_repository_config(&self, owner: &str, repo: &str) -> Option<&ReleaseRegentConfig> {
+        self.repository_configs
+            .get(&(owner.to_string(), repo.to_string()))
+    }
+
     /// Enable creation of missing configuration files
     pub fn enable_create_missing(&mut self) {
         self.create_missing = true;
     }
 
+    /// Check that every configured source can be loaded
+    ///
+    /// Reads, parses and validates the sources a load would use: the global
+    /// configuration (inline, URL, configured path or a file found in the
+    /// search directories), the repository configuration path or URL, and
+    /// the inline repository configurations. All problems are collected
+    /// instead of stopping at the first one. A configured path that does not
+    /// exist is a problem unless missing files are created on demand; no
+    /// global file in the search directories is not.
+    ///
+    /// # Errors
+    /// - `ConfigProviderError::InvalidSources` — one or more sources are
+    ///   missing, cannot be fetched, cannot be parsed or fail validation
+    #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+    pub async fn validate_sources(&self) -> ConfigProviderResult<()> {
+        let mut errors = Vec::new();
+
+        if let Some(config) = &self.global_config {
+            errors.extend(
+                self.validate_unloaded(config.clone(), PathBuf::from("<inline global>"))
+                    .err(),
+            );
+        } else if let Some(source) = &self.global_config_source {
+            errors.extend(self.validate_remote(source).await.err());
+        } else if let Some(path) = self.find_config_file("global") {
+            errors.extend(self.validate_file(&path).await.err());
+        } else if let Some(path) = &self.global_config_path {
+            errors.extend(self.validate_file(path).await.err());
+        }
+
+        if let Some(source) = &self.repository_config_source {
+            errors.extend(self.validate_remote(source).await.err());
+        } else if let Some(path) = &self.repository_config_path {
+            errors.extend(self.validate_file(path).await.err());
+        }
+
+        let mut inline: Vec<_> = self.repository_configs.iter().collect();
+        inline.sort_by(|a, b| a.0.cmp(b.0));
+        for ((owner, repo), config) in inline {
+            let path = PathBuf::from(format!("<inline {owner}/{repo}>"));
+            errors.extend(self.validate_unloaded(config.clone(), path).err());
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigProviderError::InvalidSources { errors })
+        }
+    }
+
+    /// Load and validate the configuration file at `path`
+    #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+    async fn validate_file(&self, path: &Path) -> ConfigProviderResult<()> {
+        if !path.exists() && self.create_missing {
+            return Ok(());
+        }
+        let config = self.load_config_from_file(path).await?;
+        self.validate_loaded(&config, path.to_path_buf())
+    }
+
+    /// Fetch and validate a remote configuration
+    #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+    async fn validate_remote(&self, source: &RemoteConfigSource) -> ConfigProviderResult<()> {
+        let config = source.fetch().await?;
+        self.validate_unloaded(config, PathBuf::from(source.url()))
+    }
+
+    /// Apply overrides to a configuration that was not read from a file and
+    /// validate it, reporting failures against `path`
+    #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+    fn validate_unloaded(
+        &self,
+        mut config: ReleaseRegentConfig,
+        path: PathBuf,
+    ) -> ConfigProviderResult<()> {
+        apply_overrides(&self.overrides, &mut config);
+        self.validate_loaded(&config, path)
+    }
+
+    /// Validate a configuration, reporting failures against `path`
+    #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+    fn validate_loaded(
+        &self,
+        config: &ReleaseRegentConfig,
+        path: PathBuf,
+    ) -> ConfigProviderResult<()> {
+        let result = self.validator.validate(config)?;
+        if result.is_valid {
+            Ok(())
+        } else {
+            Err(ConfigProviderError::validation_error(path, result.errors))
+        }
+    }
+
     /// Find configuration file in search directories
     fn find_config_file(&self, filename: &str) -> Option<PathBuf> {
         // Check specific paths first
@@ -129,11 +349,10 @@ impl FileConfigurationProvider {
 
         // Common configuration file variations
         let variations = if filename == "global" {
-            vec![
-                "release-regent.toml".to_string(),
-                "release_regent.toml".to_string(),
-                "config.toml".to_string(),
-            ]
+            GLOBAL_CONFIG_FILE_NAMES
+                .iter()
+                .map(ToString::to_string)
+                .collect()
         } else {
             vec![format!("{}.toml", filename)]
         };
@@ -196,13 +415,70 @@ impl FileConfigurationProvider {
             e
         })?;
 
+        // Report misspelt and deprecated keys, which serde ignores
+        let key_validation = self.validator.validate_keys(&content)?;
+        for warning in &key_validation.warnings {
+            warn!("{:?}: {}", path, warning);
+        }
+        if !key_validation.is_valid {
+            return Err(ConfigProviderError::validation_error(
+                path.to_path_buf(),
+                key_validation.errors,
+            ));
+        }
+
         // Apply overrides
-        self.apply_overrides(&mut config);
+        apply_overrides(&self.overrides, &mut config);
 
         info!("Successfully loaded configuration from: {:?}", path);
         Ok(config)
     }
 
+    /// Fetch a remote configuration, apply overrides and validate it
+    ///
+    /// Remote configurations are not cached; every call fetches them again.
+    ///
+    /// # Errors
+    /// - `CoreError::Config` — fetching, parsing or validation failed
+    async fn load_remote_config(
+        &self,
+        source: &RemoteConfigSource,
+        kind: &str,
+    ) -> Result<ReleaseRegentConfig, CoreError> {
+        let config = source
+            .fetch()
+            .await
+            .map_err(|e| CoreError::config(e.to_string()))?;
+        self.apply_overrides_and_validate(config, kind)
+    }
+
+    /// Apply overrides to a configuration that was not read from a file and
+    /// validate it
+    ///
+    /// # Errors
+    /// - `CoreError::Config` — validation failed
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large
+    fn apply_overrides_and_validate(
+        &self,
+        mut config: ReleaseRegentConfig,
+        kind: &str,
+    ) -> Result<ReleaseRegentConfig, CoreError> {
+        apply_overrides(&self.overrides, &mut config);
+
+        let validation_result = self
+            .validator
+            .validate(&config)
+            .map_err(|e| CoreError::config(e.to_string()))?;
+        if !validation_result.is_valid {
+            return Err(CoreError::config(format!(
+                "{kind} configuration validation failed: {:?}",
+                validation_result.errors
+            )));
+        }
+
+        Ok(config)
+    }
+
     /// Create a default configuration file
     ///
     /// # Errors
@@ -234,54 +510,6 @@ impl FileConfigurationProvider {
         Ok(default_config)
     }
 
-    /// Apply configuration overrides
-    fn apply_overrides(&self, config: &mut ReleaseRegentConfig) {
-        for (key, value) in &self.overrides {
-            match key.as_str() {
-                "versioning.strategy" => {
-                    // Convert string to VersioningStrategy enum
-                    match value.as_str() {
-                        "conventional" => {
-                            config.versioning.strategy = VersioningStrategy::Conventional;
-                        }
-                        "external" => {
-                            // Cannot set external strategy via simple string override;
-                            // full configuration (command, env_vars) must be provided in
-                            // the config file itself.
-                            warn!(
-                                "Override 'versioning.strategy=external' ignored: \
-                                 external strategy requires a command field and \
-                                 must be configured in the config file"
-                            );
-                        }
-                        _ => {
-                            // Keep existing if unknown
-                        }
-                    }
-                }
-                "branches.main_branch" => {
-                    config.core.branches.main.clone_from(value);
-                }
-                "webhook.url" => {
-                    // WebhookConfig only has 'url' and 'headers' fields
-                    if config.notifications.webhook.is_none() {
-                        config.notifications.webhook =
-                            Some(release_regent_core::config::WebhookConfig {
-                                url: value.clone(),
-                                headers: std::collections::HashMap::new(),
-                            });
-                    } else if let Some(webhook) = &mut config.notifications.webhook {
-                        webhook.url.clone_from(value);
-                    }
-                }
-                // Add more override patterns as needed
-                _ => {
-                    warn!("Unknown configuration override key: {}", key);
-                }
-            }
-        }
-    }
-
     /// Get cached configuration if still valid
     async fn get_cached_config(
         &self,
@@ -289,20 +517,31 @@ impl FileConfigurationProvider {
         file_path: &Path,
     ) -> Option<ReleaseRegentConfig> {
         let cache = self.config_cache.read().await;
+        let cached = cache.get(cache_key)?;
 
-        if let Some(cached) = cache.get(cache_key) {
-            // Check if file has been modified since cache
-            if let Ok(metadata) = fs::metadata(file_path).await {
-                if let Ok(modified) = metadata.modified() {
-                    if modified <= cached.last_modified {
-                        debug!("Using cached configuration for: {}", cache_key);
-                        return Some(cached.config.clone());
-                    }
-                }
+        if cached.file_path != file_path {
+            return None;
+        }
+        if let Some(ttl) = self.cache_ttl {
+            if cached.cached_at.elapsed() >= ttl {
+                debug!("Cached configuration for {} has expired", cache_key);
+                return None;
             }
         }
 
-        None
+        // Any change to the modification time, forwards or backwards, means
+        // the file may have different content.
+        let modified = fs::metadata(file_path).await.ok()?.modified().ok()?;
+        if modified != cached.last_modified {
+            debug!(
+                "Configuration file {:?} changed since it was cached",
+                file_path
+            );
+            return None;
+        }
+
+        debug!("Using cached configuration for: {}", cache_key);
+        Some(cached.config.clone())
     }
 
     /// Cache configuration
@@ -318,6 +557,7 @@ impl FileConfigurationProvider {
                     config,
                     last_modified: modified,
                     file_path,
+                    cached_at: Instant::now(),
                 };
 
                 let mut cache = self.config_cache.write().await;
@@ -333,13 +573,17 @@ impl FileConfigurationProvider {
     }
 
     /// Merge two configurations (repository overrides global)
-    fn merge_configurations(
+    pub(crate) fn merge_configurations(
         global: ReleaseRegentConfig,
         repository: ReleaseRegentConfig,
     ) -> ReleaseRegentConfig {
         // For now, implement a simple merge where repository config overrides global config
         // In a real implementation, this would be more sophisticated
 
+        let tag_signing = TagSigningConfig::overlay(
+            global.releases.tag_signing.clone(),
+            repository.releases.tag_signing.as_ref(),
+        );
         let mut merged = global;
 
         // Override with repository-specific settings
@@ -347,6 +591,8 @@ impl FileConfigurationProvider {
         merged.core = repository.core;
         merged.versioning = repository.versioning;
         merged.releases = repository.releases;
+        // A repository may switch tag signing on or off but not name the key.
+        merged.releases.tag_signing = tag_signing;
         merged.notifications = repository.notifications;
         merged.error_handling = repository.error_handling;
         merged.release_pr = repository.release_pr;
@@ -355,12 +601,71 @@ impl FileConfigurationProvider {
     }
 }
 
+/// Apply configuration overrides
+pub(crate) fn apply_overrides(
+    overrides: &HashMap<String, String>,
+    config: &mut ReleaseRegentConfig,
+) {
+    for (key, value) in overrides {
+        match key.as_str() {
+            "versioning.strategy" => {
+                // Convert string to VersioningStrategy enum
+                match value.as_str() {
+                    "conventional" => {
+                        config.versioning.strategy = VersioningStrategy::Conventional;
+                    }
+                    "external" => {
+                        // Cannot set external strategy via simple string override;
+                        // full configuration (command, env_vars) must be provided in
+                        // the config file itself.
+                        warn!(
+                            "Override 'versioning.strategy=external' ignored: \
+                                 external strategy requires a command field and \
+                                 must be configured in the config file"
+                        );
+                    }
+                    _ => {
+                        // Keep existing if unknown
+                    }
+                }
+            }
+            "branches.main_branch" => {
+                config.core.branches.main.clone_from(value);
+            }
+            "webhook.url" => {
+                // WebhookConfig only has 'url' and 'headers' fields
+                if config.notifications.webhook.is_none() {
+                    config.notifications.webhook =
+                        Some(release_regent_core::config::WebhookConfig {
+                            url: value.clone(),
+                            headers: std::collections::HashMap::new(),
+                        });
+                } else if let Some(webhook) = &mut config.notifications.webhook {
+                    webhook.url.clone_from(value);
+                }
+            }
+            // Add more override patterns as needed
+            _ => {
+                warn!("Unknown configuration override key: {}", key);
+            }
+        }
+    }
+}
+
 #[async_trait]
 impl ConfigurationProvider for FileConfigurationProvider {
     async fn load_global_config(
         &self,
         _options: LoadOptions,
     ) -> Result<ReleaseRegentConfig, CoreError> {
+        if let Some(config) = &self.global_config {
+            return self.apply_overrides_and_validate(config.clone(), "Global");
+        }
+
+        if let Some(source) = &self.global_config_source {
+            return self.load_remote_config(source, "Global").await;
+        }
+
         let cache_key = "global".to_string();
 
         // Try to find global configuration file
@@ -412,6 +717,15 @@ impl ConfigurationProvider for FileConfigurationProvider {
         repo: &str,
         _options: LoadOptions,
     ) -> Result<Option<RepositoryConfig>, CoreError> {
+        if let Some(config) = self.inline_repository_config(owner, repo) {
+            let config = self.apply_overrides_and_validate(config.clone(), "Repository")?;
+            return Ok(Some(RepositoryConfig {
+                config,
+                name: repo.to_string(),
+                owner: owner.to_string(),
+            }));
+        }
+
         let cache_key = format!("{owner}_{repo}");
         let filename = format!("{owner}-{repo}");
 
@@ -420,7 +734,14 @@ impl ConfigurationProvider for FileConfigurationProvider {
             Some(path) => path,
             None => {
                 // Try generic repository config
-                if let Some(repo_path) = &self.repository_config_path {
+                if let Some(source) = &self.repository_config_source {
+                    let config = self.load_remote_config(source, "Repository").await?;
+                    return Ok(Some(RepositoryConfig {
+                        config,
+                        name: repo.to_string(),
+                        owner: owner.to_string(),
+                    }));
+                } else if let Some(repo_path) = &self.repository_config_path {
                     repo_path.clone()
                 } else if self.create_missing {
                     self.base_directory.join(format!("{filename}.toml"))
@@ -575,7 +896,16 @@ impl ConfigurationProvider for FileConfigurationProvider {
         &self,
         _options: LoadOptions,
     ) -> Result<Vec<RepositoryConfig>, CoreError> {
-        let mut configs = Vec::new();
+        // Inline configurations come first so that they win the dedup below.
+        let mut configs: Vec<RepositoryConfig> = self
+            .repository_configs
+            .iter()
+            .map(|((owner, repo), config)| RepositoryConfig {
+                config: config.clone(),
+                name: repo.clone(),
+                owner: owner.clone(),
+            })
+            .collect();
 
         // Search in all directories
         let mut search_dirs = vec![self.base_directory.clone()];
@@ -621,11 +951,40 @@ impl ConfigurationProvider for FileConfigurationProvider {
         owner: Option<&str>,
         repo: Option<&str>,
     ) -> Result<ConfigurationSource, CoreError> {
+        let inline = match (owner, repo) {
+            (Some(o), Some(r)) => self.inline_repository_config(o, r).is_some(),
+            _ => self.global_config.is_some(),
+        };
+        if inline {
+            return Ok(ConfigurationSource {
+                location: "inline".to_string(),
+                source_type: "inline".to_string(),
+                format: "toml".to_string(),
+                loaded_at: chrono::Utc::now(),
+            });
+        }
+
         let filename = match (owner, repo) {
             (Some(o), Some(r)) => format!("{o}-{r}"),
             _ => "global".to_string(),
         };
 
+        let remote = if filename == "global" {
+            self.global_config_source.as_ref()
+        } else if self.find_config_file(&filename).is_none() {
+            self.repository_config_source.as_ref()
+        } else {
+            None
+        };
+        if let Some(source) = remote {
+            return Ok(ConfigurationSource {
+                location: source.url().to_string(),
+                source_type: "remote".to_string(),
+                format: "toml".to_string(),
+                loaded_at: chrono::Utc::now(),
+            });
+        }
+
         match self.find_config_file(&filename) {
             Some(path) => Ok(ConfigurationSource {
                 location: path.to_string_lossy().to_string(),
@@ -657,15 +1016,20 @@ impl ConfigurationProvider for FileConfigurationProvider {
         owner: Option<&str>,
         repo: Option<&str>,
     ) -> Result<bool, CoreError> {
-        let filename = match (owner, repo) {
-            (Some(o), Some(r)) => format!("{o}-{r}"),
-            _ => "global".to_string(),
+        let (filename, remote, inline) = match (owner, repo) {
+            (Some(o), Some(r)) => (
+                format!("{o}-{r}"),
+                &self.repository_config_source,
+                self.inline_repository_config(o, r).is_some(),
+            ),
+            _ => (
+                "global".to_string(),
+                &self.global_config_source,
+                self.global_config.is_some(),
+            ),
         };
 
-        match self.find_config_file(&filename) {
-            Some(_) => Ok(true),
-            None => Ok(false),
-        }
+        Ok(inline || remote.is_some() || self.find_config_file(&filename).is_some())
     }
 
     fn supported_formats(&self) -> Vec<String> {
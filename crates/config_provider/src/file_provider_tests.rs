@@ -0,0 +1,214 @@
+//! Unit tests for the file configuration provider cache.
+
+use super::*;
+use tempfile::TempDir;
+
+const GLOBAL_CONFIG: &str = r#"
+[core]
+version_prefix = "v"
+
+[core.branches]
+main = "main"
+"#;
+
+async fn provider_with_global(content: &str) -> (FileConfigurationProvider, TempDir, PathBuf) {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("release-regent.toml");
+    std::fs::write(&path, content).unwrap();
+    let provider = FileConfigurationProvider::new(dir.path()).await.unwrap();
+    (provider, dir, path)
+}
+
+/// Replace the cached global entry's prefix so that a cache hit is visible in
+/// the loaded configuration.
+async fn mark_cached_global(provider: &FileConfigurationProvider) {
+    let mut cache = provider.config_cache.write().await;
+    let entry = cache
+        .get_mut("global")
+        .expect("global config should be cached");
+    entry.config.core.version_prefix = "cached-".to_string();
+}
+
+/// Rewrite `path` so that its modification time differs from the cached one.
+async fn rewrite(path: &Path, content: &str) {
+    let before = std::fs::metadata(path).unwrap().modified().unwrap();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    std::fs::write(path, content).unwrap();
+    assert_ne!(
+        std::fs::metadata(path).unwrap().modified().unwrap(),
+        before,
+        "file system did not record a new modification time"
+    );
+}
+
+#[tokio::test]
+async fn test_second_load_is_served_from_cache() {
+    let (provider, _dir, _path) = provider_with_global(GLOBAL_CONFIG).await;
+
+    let first = provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(first.core.version_prefix, "v");
+
+    mark_cached_global(&provider).await;
+    let second = provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(second.core.version_prefix, "cached-");
+}
+
+#[tokio::test]
+async fn test_modifying_the_file_invalidates_the_cache() {
+    let (provider, _dir, path) = provider_with_global(GLOBAL_CONFIG).await;
+    provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+    mark_cached_global(&provider).await;
+
+    rewrite(&path, &GLOBAL_CONFIG.replace("\"v\"", "\"release-\"")).await;
+    let reloaded = provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(reloaded.core.version_prefix, "release-");
+}
+
+#[tokio::test]
+async fn test_expired_entries_are_reloaded() {
+    let (provider, _dir, _path) = provider_with_global(GLOBAL_CONFIG).await;
+    let provider = provider.with_cache(Duration::ZERO);
+    provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+    mark_cached_global(&provider).await;
+
+    let reloaded = provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(reloaded.core.version_prefix, "v");
+}
+
+#[tokio::test]
+async fn test_entries_within_ttl_are_served_from_cache() {
+    let (provider, _dir, _path) = provider_with_global(GLOBAL_CONFIG).await;
+    let provider = provider.with_cache(Duration::from_secs(300));
+    provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+    mark_cached_global(&provider).await;
+
+    let second = provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(second.core.version_prefix, "cached-");
+}
+
+#[tokio::test]
+async fn test_entry_for_a_different_file_is_not_used() {
+    let (mut provider, dir, _path) = provider_with_global(GLOBAL_CONFIG).await;
+    provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+    mark_cached_global(&provider).await;
+
+    let other = dir.path().join("other.toml");
+    std::fs::write(&other, GLOBAL_CONFIG.replace("\"v\"", "\"other-\"")).unwrap();
+    provider.set_global_config_path(&other);
+    let loaded = provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(loaded.core.version_prefix, "other-");
+}
+
+#[tokio::test]
+async fn test_repository_config_is_cached_and_invalidated() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("acme-widgets.toml");
+    std::fs::write(&path, GLOBAL_CONFIG).unwrap();
+    let provider = FileConfigurationProvider::new(dir.path()).await.unwrap();
+
+    provider
+        .load_repository_config("acme", "widgets", LoadOptions::default())
+        .await
+        .unwrap()
+        .expect("repository config should exist");
+    {
+        let mut cache = provider.config_cache.write().await;
+        let entry = cache.get_mut("acme_widgets").expect("entry should exist");
+        entry.config.core.version_prefix = "cached-".to_string();
+    }
+    let cached = provider
+        .load_repository_config("acme", "widgets", LoadOptions::default())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached.config.core.version_prefix, "cached-");
+
+    rewrite(&path, &GLOBAL_CONFIG.replace("\"v\"", "\"w-\"")).await;
+    let reloaded = provider
+        .load_repository_config("acme", "widgets", LoadOptions::default())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(reloaded.config.core.version_prefix, "w-");
+}
+
+#[tokio::test]
+async fn test_reload_config_clears_the_entry() {
+    let (provider, _dir, _path) = provider_with_global(GLOBAL_CONFIG).await;
+    provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+    mark_cached_global(&provider).await;
+
+    provider.reload_config(None, None).await.unwrap();
+    let reloaded = provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(reloaded.core.version_prefix, "v");
+}
+
+#[tokio::test]
+#[tracing_test::traced_test]
+async fn test_unknown_key_is_logged_and_ignored() {
+    let (provider, _dir, _path) =
+        provider_with_global(&format!("{GLOBAL_CONFIG}\n[releases]\ndraf = true\n")).await;
+
+    let config = provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+
+    assert!(!config.releases.draft);
+    assert!(logs_contain(
+        "Unknown configuration key 'releases.draf' is ignored"
+    ));
+}
+
+#[tokio::test]
+async fn test_unknown_key_is_rejected_by_strict_validator() {
+    let (mut provider, _dir, _path) =
+        provider_with_global(&format!("{GLOBAL_CONFIG}\n[releases]\ndraf = true\n")).await;
+    provider.set_validator(ConfigValidator::strict());
+
+    let result = provider.load_global_config(LoadOptions::default()).await;
+
+    assert!(result.is_err());
+}
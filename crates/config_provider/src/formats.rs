@@ -4,20 +4,25 @@
 //! parsing, serializing, and validating TOML configuration files.
 
 use crate::errors::{ConfigProviderError, ConfigProviderResult};
-use release_regent_core::config::ReleaseRegentConfig;
+use release_regent_core::{config::ReleaseRegentConfig, secret::describe_toml_error};
 use std::path::Path;
 
 /// Parse TOML configuration content into a [`ReleaseRegentConfig`].
 ///
+/// The error describes the problem by line and column without quoting the
+/// content, which may hold secrets.
+///
 /// # Errors
 /// - `ConfigProviderError::ParseError` — content could not be parsed as valid TOML
 #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
 pub fn parse_config(content: &str) -> ConfigProviderResult<ReleaseRegentConfig> {
     toml::from_str(content).map_err(|e| {
-        ConfigProviderError::parse_error_with_source(
+        ConfigProviderError::parse_error(
             std::path::PathBuf::new(),
-            format!("Failed to parse TOML: {e}"),
-            e,
+            format!(
+                "Failed to parse TOML: {}",
+                describe_toml_error(&e, Some(content))
+            ),
         )
     })
 }
@@ -54,3 +54,14 @@ fn test_validate_toml_path_wrong_extension() {
 fn test_validate_toml_path_no_extension() {
     assert!(validate_toml_path(&PathBuf::from("config")).is_err());
 }
+
+#[test]
+fn test_parse_config_error_does_not_quote_content() {
+    let content = "[notifications.slack]\nwebhook_url = \"https://hooks.slack.com/s3cr3t\" x\n";
+
+    let error = parse_config(content).unwrap_err();
+    let rendered = format!("{error} {error:?}");
+
+    assert!(!rendered.contains("s3cr3t"), "content leaked: {rendered}");
+    assert!(rendered.contains("line 2"), "error: {rendered}");
+}
@@ -154,7 +154,8 @@ fn merge_config_with_locks(
     locks: &ConfigLocks,
 ) -> ReleaseRegentConfig {
     use release_regent_core::config::{
-        BranchConfig, CoreConfig, ErrorHandlingConfig, ReleasesConfig, VersioningConfig,
+        BranchConfig, CoreConfig, ErrorHandlingConfig, ReleasesConfig, TagSigningConfig,
+        VersioningConfig,
     };
 
     // ── versioning.strategy ────────────────────────────────────────────────
@@ -311,6 +312,7 @@ fn merge_config_with_locks(
     };
 
     ReleaseRegentConfig {
+        config_version: incoming.config_version,
         core: CoreConfig {
             version_prefix: core_version_prefix,
             branches: BranchConfig {
@@ -328,6 +330,14 @@ fn merge_config_with_locks(
             draft: releases_draft,
             prerelease: releases_prerelease,
             generate_notes: releases_generate_notes,
+            // Not lockable; always take from incoming.
+            target_branch: incoming.releases.target_branch,
+            // Only the app-level config may name a signing key; lower levels
+            // can only switch signing on or off.
+            tag_signing: TagSigningConfig::overlay(
+                base.releases.tag_signing,
+                incoming.releases.tag_signing.as_ref(),
+            ),
         },
         error_handling: ErrorHandlingConfig {
             max_retries: error_max_retries,
@@ -339,6 +349,22 @@ fn merge_config_with_locks(
             allow_override: versioning_allow_override,
             // excluded_pr_authors is not lockable; always from incoming.
             excluded_pr_authors: incoming.versioning.excluded_pr_authors,
+            // exclusions are not lockable; always from incoming.
+            exclusions: incoming.versioning.exclusions,
+            // scopes are not lockable; always from incoming.
+            scopes: incoming.versioning.scopes,
+            // breaking_scopes are not lockable; always from incoming.
+            breaking_scopes: incoming.versioning.breaking_scopes,
+            // use_pr_title is not lockable; always from incoming.
+            use_pr_title: incoming.versioning.use_pr_title,
+            // release_on_no_bump is not lockable; always from incoming.
+            release_on_no_bump: incoming.versioning.release_on_no_bump,
+            // no_conventional_commits is not lockable; always from incoming.
+            no_conventional_commits: incoming.versioning.no_conventional_commits,
+            // minimum_version is not lockable; always from incoming.
+            minimum_version: incoming.versioning.minimum_version,
+            // initial_version is not lockable; always from incoming.
+            initial_version: incoming.versioning.initial_version,
         },
         // changelog is not lockable; always take from incoming.
         changelog: incoming.changelog,
@@ -501,6 +527,7 @@ impl<G: GitHubOperations + Clone + Send + Sync> GitHubConfigurationProvider<G> {
     ///
     /// Returns `Err(CoreError::Config)` when parsing fails or when the parsed
     /// config does not satisfy the validator's rules.
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large
     fn parse_and_validate_content(
         &self,
         content: &str,
@@ -512,16 +539,30 @@ impl<G: GitHubOperations + Clone + Send + Sync> GitHubConfigurationProvider<G> {
             ))
         })?;
 
+        let key_validation = self.validator.validate_keys(content).map_err(|e| {
+            release_regent_core::CoreError::config(format!(
+                "Validation error for {source_desc}: {e}"
+            ))
+        })?;
+        for warning in &key_validation.warnings {
+            warn!("Config at {source_desc}: {warning}");
+        }
+
         let validation = self.validator.validate(&config).map_err(|e| {
             release_regent_core::CoreError::config(format!(
                 "Validation error for {source_desc}: {e}"
             ))
         })?;
 
-        if !validation.is_valid {
+        if !key_validation.is_valid || !validation.is_valid {
+            let errors: Vec<String> = key_validation
+                .errors
+                .into_iter()
+                .chain(validation.errors)
+                .collect();
             return Err(release_regent_core::CoreError::config(format!(
                 "Config at {source_desc} is invalid: {}",
-                validation.errors.join(", ")
+                errors.join(", ")
             )));
         }
 
@@ -900,18 +941,15 @@ where
                 }
 
                 // Level 5: Repo dotfile (reusing the already-fetched result).
-                match repo_result? {
-                    Some(mut rc) => {
-                        if !rc.locked_fields.is_empty() {
-                            tracing::warn!(
-                                repo = %format!("{owner}/{repo}"),
-                                "locked_fields in repository dotfile is ignored"
-                            );
-                            rc.locked_fields.clear();
-                        }
-                        result = merge_config_with_locks(result, rc, &locks);
+                if let Some(mut rc) = repo_result? {
+                    if !rc.locked_fields.is_empty() {
+                        tracing::warn!(
+                            repo = %format!("{owner}/{repo}"),
+                            "locked_fields in repository dotfile is ignored"
+                        );
+                        rc.locked_fields.clear();
                     }
-                    None => {}
+                    result = merge_config_with_locks(result, rc, &locks);
                 }
             }
             None => {
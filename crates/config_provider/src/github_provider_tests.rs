@@ -1,9 +1,9 @@
 use super::{merge_config_with_locks, ConfigLocks, LOCKABLE_FIELDS};
 use release_regent_core::config::{
     BranchConfig, CoreConfig, ErrorHandlingConfig, ReleasesConfig, VersioningConfig,
-    VersioningStrategy,
+    VersioningStrategy, CURRENT_CONFIG_VERSION,
 };
-use release_regent_core::config::{ReleasePrConfig, ReleaseRegentConfig};
+use release_regent_core::config::{ReleasePrConfig, ReleaseRegentConfig, TagSigningConfig};
 use tracing_test::traced_test;
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -31,6 +31,7 @@ fn make_config(
     initial_delay_ms: u64,
 ) -> ReleaseRegentConfig {
     ReleaseRegentConfig {
+        config_version: CURRENT_CONFIG_VERSION,
         core: CoreConfig {
             version_prefix: version_prefix.to_string(),
             branches: BranchConfig {
@@ -41,11 +42,21 @@ fn make_config(
             strategy: versioning_strategy,
             allow_override,
             excluded_pr_authors: Vec::new(),
+            exclusions: Default::default(),
+            scopes: Default::default(),
+            minimum_version: None,
+            breaking_scopes: Vec::new(),
+            use_pr_title: false,
+            release_on_no_bump: false,
+            no_conventional_commits: Default::default(),
+            initial_version: None,
         },
         releases: ReleasesConfig {
             draft,
             prerelease,
             generate_notes,
+            target_branch: None,
+            tag_signing: None,
         },
         error_handling: ErrorHandlingConfig {
             max_retries,
@@ -358,6 +369,30 @@ fn test_merge_config_with_locks_release_pr_always_from_incoming() {
     assert_eq!(result.release_pr.title_template, "custom: ${version}");
 }
 
+#[test]
+fn test_merge_config_with_locks_tag_signing_key_only_from_base() {
+    let mut base = default_config();
+    base.releases.tag_signing = Some(TagSigningConfig {
+        enabled: false,
+        key_env: Some("TAG_SIGNING_KEY".to_string()),
+        ..TagSigningConfig::default()
+    });
+    let mut incoming = default_config();
+    incoming.releases.tag_signing = Some(TagSigningConfig {
+        key_file: Some("/etc/ssh/ssh_host_ed25519_key".into()),
+        ..TagSigningConfig::default()
+    });
+
+    let result = merge_config_with_locks(base, incoming.clone(), &ConfigLocks::default());
+    let signing = result.releases.tag_signing.unwrap();
+    assert!(signing.enabled);
+    assert_eq!(signing.key_env.as_deref(), Some("TAG_SIGNING_KEY"));
+    assert_eq!(signing.key_file, None);
+
+    let result = merge_config_with_locks(default_config(), incoming, &ConfigLocks::default());
+    assert!(result.releases.tag_signing.is_none());
+}
+
 #[test]
 fn test_merge_config_with_locks_group_metadata_always_from_incoming() {
     let mut base = default_config();
@@ -473,21 +508,25 @@ use release_regent_core::{
             GetCommitsOptions, GitCommit, GitOperations, GitRepository, GitTag, ListTagsOptions,
         },
         github_operations::{
-            CollaboratorPermission, CreatePullRequestParams, CreateReleaseParams, FileUpdate,
-            IssueComment, Label, PullRequest, Release, Tag, UpdateReleaseParams,
+            CollaboratorPermission, CreateCommitStatusParams, CreatePullRequestParams,
+            CreateReleaseParams, FileUpdate, IssueComment, Label, LabelMode, PullRequest, Release,
+            Tag, UpdateReleaseParams,
         },
         GitHubOperations,
     },
     CoreError,
 };
 
+/// Key for `get_file_content` responses: (owner, repo, path, branch).
+type FileKey = (String, String, String, String);
+
 /// State shared across clones of `TestGitHub` via `Arc<Mutex>`.
 struct TestGitHubState {
     /// Override for `get_installation_id_for_repo`. `None` → return `Err(GitHub)`.
     install_response: Option<u64>,
     /// Per-key response for `get_file_content`.
     /// Value is `Ok(Some(content))`, `Ok(None)` (absent), or `Err(msg)` (API error).
-    file_responses: GH2HashMap<(String, String, String, String), Result<Option<String>, String>>,
+    file_responses: GH2HashMap<FileKey, Result<Option<String>, String>>,
 }
 
 impl TestGitHubState {
@@ -561,6 +600,16 @@ impl GitOperations for TestGitHub {
         Ok(vec![])
     }
 
+    async fn list_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _head: &str,
+        _options: GetCommitsOptions,
+    ) -> release_regent_core::CoreResult<Vec<GitCommit>> {
+        Ok(vec![])
+    }
+
     async fn get_commit(
         &self,
         _owner: &str,
@@ -679,6 +728,25 @@ impl GitHubOperations for TestGitHub {
         Err(CoreError::not_found("stub"))
     }
 
+    async fn create_signed_tag(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _params: release_regent_core::traits::github_operations::SignedTagParams,
+    ) -> release_regent_core::CoreResult<Tag> {
+        Err(CoreError::not_found("stub"))
+    }
+
+    async fn create_commit_status(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _sha: &str,
+        _params: CreateCommitStatusParams,
+    ) -> release_regent_core::CoreResult<()> {
+        Ok(())
+    }
+
     async fn delete_branch(
         &self,
         _owner: &str,
@@ -698,6 +766,17 @@ impl GitHubOperations for TestGitHub {
         Ok(())
     }
 
+    async fn upsert_branch(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch_name: &str,
+        _from_sha: &str,
+    ) -> release_regent_core::CoreResult<release_regent_core::traits::github_operations::BranchUpsert>
+    {
+        Ok(release_regent_core::traits::github_operations::BranchUpsert::Created)
+    }
+
     async fn get_collaborator_permission(
         &self,
         _owner: &str,
@@ -728,9 +807,9 @@ impl GitHubOperations for TestGitHub {
         &self,
         _owner: &str,
         _repo: &str,
-        tag: &str,
-    ) -> release_regent_core::CoreResult<Release> {
-        Err(CoreError::not_found(format!("release for tag '{tag}'")))
+        _tag: &str,
+    ) -> release_regent_core::CoreResult<Option<Release>> {
+        Ok(None)
     }
 
     async fn list_pr_labels(
@@ -751,6 +830,16 @@ impl GitHubOperations for TestGitHub {
         Ok(vec![])
     }
 
+    async fn list_pull_request_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+    ) -> release_regent_core::CoreResult<Vec<release_regent_core::traits::git_operations::GitCommit>>
+    {
+        Ok(vec![])
+    }
+
     async fn list_pull_requests(
         &self,
         _owner: &str,
@@ -774,6 +863,18 @@ impl GitHubOperations for TestGitHub {
         Ok(vec![])
     }
 
+    async fn upload_release_asset(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _release_id: u64,
+        _name: &str,
+        _content_type: Option<&str>,
+        _data: Vec<u8>,
+    ) -> release_regent_core::CoreResult<String> {
+        Err(CoreError::not_found("stub"))
+    }
+
     async fn remove_label(
         &self,
         _owner: &str,
@@ -784,6 +885,26 @@ impl GitHubOperations for TestGitHub {
         Ok(())
     }
 
+    async fn resolve_ref(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _git_ref: &str,
+    ) -> release_regent_core::CoreResult<String> {
+        Err(CoreError::not_found("stub"))
+    }
+
+    async fn set_pull_request_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+        _labels: &[&str],
+        _mode: LabelMode,
+    ) -> release_regent_core::CoreResult<()> {
+        Ok(())
+    }
+
     async fn search_pull_requests(
         &self,
         _owner: &str,
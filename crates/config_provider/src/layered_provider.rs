@@ -0,0 +1,381 @@
+//! Configuration provider that merges several providers in priority order.
+//!
+//! [`LayeredConfigurationProvider`] starts from the built-in defaults and
+//! applies each layer on top of the previous ones, lowest priority first. A
+//! typical deployment uses a [`FileConfigurationProvider`] for structure and
+//! an [`EnvironmentConfigurationProvider`] for secrets:
+//!
+//! ```rust,no_run
+//! use release_regent_config_provider::{
+//!     EnvironmentConfigurationProvider, FileConfigurationProvider, LayeredConfigurationProvider,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let provider = LayeredConfigurationProvider::new(vec![
+//!     Box::new(FileConfigurationProvider::new("/etc/release-regent").await?),
+//!     Box::new(EnvironmentConfigurationProvider::new()),
+//! ]);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! A layer that reports the values it sets explicitly, through
+//! [`ConfigurationProvider::load_explicit_values`], overrides exactly those
+//! values, including ones it sets back to their default:
+//! `RELEASE_REGENT__RELEASES__DRAFT=false` turns off drafts enabled by a
+//! file. Other layers only return a complete configuration, so for them a
+//! field overrides the lower layers only when its value differs from the
+//! built-in default. Lists replace the lower value as a whole.
+//!
+//! [`FileConfigurationProvider`]: crate::FileConfigurationProvider
+//! [`EnvironmentConfigurationProvider`]: crate::EnvironmentConfigurationProvider
+
+use crate::validation::ConfigValidator;
+use async_trait::async_trait;
+use release_regent_core::{
+    config::ReleaseRegentConfig,
+    errors::CoreError,
+    secret::describe_toml_error,
+    traits::{
+        configuration_provider::{
+            ConfigurationSource, LoadOptions, RepositoryConfig, ValidationResult,
+        },
+        ConfigurationProvider,
+    },
+    CoreResult,
+};
+use std::collections::HashSet;
+use toml::{Table, Value};
+use tracing::debug;
+
+#[cfg(test)]
+#[path = "layered_provider_tests.rs"]
+mod tests;
+
+/// Merges the configuration of several providers, later layers taking
+/// precedence over earlier ones.
+pub struct LayeredConfigurationProvider {
+    /// Layers, lowest priority first
+    layers: Vec<Box<dyn ConfigurationProvider>>,
+    /// Configuration validator
+    validator: ConfigValidator,
+}
+
+impl LayeredConfigurationProvider {
+    /// Create a provider from `layers`, ordered from lowest to highest
+    /// priority. The built-in defaults always sit below the first layer.
+    #[must_use]
+    pub fn new(layers: Vec<Box<dyn ConfigurationProvider>>) -> Self {
+        Self {
+            layers,
+            validator: ConfigValidator::new(),
+        }
+    }
+
+    /// Number of layers, not counting the built-in defaults.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Returns `true` when there are no layers and only the built-in defaults apply.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Whether `layer` has any configuration for `owner/repo`, or any global
+    /// configuration when no repository is given.
+    async fn has_config(
+        layer: &dyn ConfigurationProvider,
+        owner: Option<&str>,
+        repo: Option<&str>,
+    ) -> CoreResult<bool> {
+        if layer.config_exists(None, None).await? {
+            return Ok(true);
+        }
+        match (owner, repo) {
+            (Some(_), Some(_)) => layer.config_exists(owner, repo).await,
+            _ => Ok(false),
+        }
+    }
+}
+
+/// Accumulates layers on top of the built-in defaults.
+struct Merger {
+    defaults: Table,
+    merged: Table,
+}
+
+impl Merger {
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large; established pattern
+    fn new() -> CoreResult<Self> {
+        let defaults = to_table(&ReleaseRegentConfig::default())?;
+        Ok(Self {
+            merged: defaults.clone(),
+            defaults,
+        })
+    }
+
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large; established pattern
+    fn apply(&mut self, layer: &ReleaseRegentConfig) -> CoreResult<()> {
+        overlay(&mut self.merged, &to_table(layer)?, Some(&self.defaults));
+        Ok(())
+    }
+
+    /// Apply values a layer set explicitly, whether or not they are defaults.
+    fn apply_explicit(&mut self, values: &Table) {
+        overlay(&mut self.merged, values, None);
+    }
+
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large; established pattern
+    fn finish(self) -> CoreResult<ReleaseRegentConfig> {
+        Value::Table(self.merged)
+            .try_into()
+            .map_err(|e: toml::de::Error| {
+                CoreError::config(format!(
+                    "Failed to merge configuration layers: {}",
+                    describe_toml_error(&e, None)
+                ))
+            })
+    }
+}
+
+#[allow(clippy::result_large_err)] // CoreError is intentionally large; established pattern
+fn to_table(config: &ReleaseRegentConfig) -> CoreResult<Table> {
+    Table::try_from(config)
+        .map_err(|e| CoreError::config(format!("Failed to convert configuration for merging: {e}")))
+}
+
+/// Copy every value of `layer` that differs from `defaults` into `base`, or
+/// every value when there are no defaults to compare with. Tables are merged
+/// key by key; any other value replaces the one in `base`.
+fn overlay(base: &mut Table, layer: &Table, defaults: Option<&Table>) {
+    for (key, value) in layer {
+        let default = defaults.and_then(|d| d.get(key));
+        match (base.get_mut(key), value) {
+            (Some(Value::Table(base_table)), Value::Table(layer_table)) => {
+                overlay(base_table, layer_table, default.and_then(Value::as_table));
+            }
+            _ if default == Some(value) => {}
+            _ => {
+                base.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ConfigurationProvider for LayeredConfigurationProvider {
+    async fn load_global_config(&self, options: LoadOptions) -> CoreResult<ReleaseRegentConfig> {
+        let mut merger = Merger::new()?;
+        for (index, layer) in self.layers.iter().enumerate() {
+            if !Self::has_config(layer.as_ref(), None, None).await? {
+                debug!(
+                    layer = index,
+                    "Skipping configuration layer without global config"
+                );
+                continue;
+            }
+            match layer
+                .load_explicit_values(None, None, options.clone())
+                .await?
+            {
+                Some(values) => merger.apply_explicit(&values),
+                None => merger.apply(&layer.load_global_config(options.clone()).await?)?,
+            }
+        }
+        merger.finish()
+    }
+
+    async fn load_repository_config(
+        &self,
+        owner: &str,
+        repo: &str,
+        options: LoadOptions,
+    ) -> CoreResult<Option<RepositoryConfig>> {
+        let mut merger = Merger::new()?;
+        let mut found = false;
+        for layer in &self.layers {
+            if let Some(repo_config) = layer
+                .load_repository_config(owner, repo, options.clone())
+                .await?
+            {
+                merger.apply(&repo_config.config)?;
+                found = true;
+            }
+        }
+
+        if !found {
+            return Ok(None);
+        }
+        Ok(Some(RepositoryConfig {
+            config: merger.finish()?,
+            name: repo.to_string(),
+            owner: owner.to_string(),
+        }))
+    }
+
+    async fn get_merged_config(
+        &self,
+        owner: &str,
+        repo: &str,
+        options: LoadOptions,
+    ) -> CoreResult<ReleaseRegentConfig> {
+        let mut merger = Merger::new()?;
+        for (index, layer) in self.layers.iter().enumerate() {
+            if !Self::has_config(layer.as_ref(), Some(owner), Some(repo)).await? {
+                debug!(
+                    layer = index,
+                    "Skipping configuration layer without config for {}/{}", owner, repo
+                );
+                continue;
+            }
+            match layer
+                .load_explicit_values(Some(owner), Some(repo), options.clone())
+                .await?
+            {
+                Some(values) => merger.apply_explicit(&values),
+                None => merger.apply(
+                    &layer
+                        .get_merged_config(owner, repo, options.clone())
+                        .await?,
+                )?,
+            }
+        }
+        merger.finish()
+    }
+
+    async fn validate_config(&self, config: &ReleaseRegentConfig) -> CoreResult<ValidationResult> {
+        let result = self
+            .validator
+            .validate(config)
+            .map_err(|e| CoreError::config(e.to_string()))?;
+        Ok(ValidationResult {
+            is_valid: result.is_valid,
+            errors: result.errors,
+            warnings: result.warnings,
+        })
+    }
+
+    /// Save to the highest-priority layer that supports saving.
+    async fn save_config(
+        &self,
+        config: &ReleaseRegentConfig,
+        owner: Option<&str>,
+        repo: Option<&str>,
+        global: bool,
+    ) -> CoreResult<()> {
+        for layer in self.layers.iter().rev() {
+            match layer.save_config(config, owner, repo, global).await {
+                Err(CoreError::NotSupported { .. }) => continue,
+                result => return result,
+            }
+        }
+        Err(CoreError::not_supported(
+            "save_config",
+            "no configuration layer supports saving",
+        ))
+    }
+
+    /// Repository configurations from every layer, merged per repository.
+    async fn list_repository_configs(
+        &self,
+        options: LoadOptions,
+    ) -> CoreResult<Vec<RepositoryConfig>> {
+        let mut seen = HashSet::new();
+        let mut repositories = Vec::new();
+        for layer in &self.layers {
+            for repo_config in layer.list_repository_configs(options.clone()).await? {
+                if seen.insert((repo_config.owner.clone(), repo_config.name.clone())) {
+                    repositories.push((repo_config.owner, repo_config.name));
+                }
+            }
+        }
+
+        let mut configs = Vec::with_capacity(repositories.len());
+        for (owner, repo) in repositories {
+            if let Some(config) = self
+                .load_repository_config(&owner, &repo, options.clone())
+                .await?
+            {
+                configs.push(config);
+            }
+        }
+        Ok(configs)
+    }
+
+    /// Source of the highest-priority layer that has configuration.
+    async fn get_config_source(
+        &self,
+        owner: Option<&str>,
+        repo: Option<&str>,
+    ) -> CoreResult<ConfigurationSource> {
+        for layer in self.layers.iter().rev() {
+            if Self::has_config(layer.as_ref(), owner, repo).await? {
+                return layer.get_config_source(owner, repo).await;
+            }
+        }
+        Ok(ConfigurationSource {
+            format: "toml".to_string(),
+            loaded_at: chrono::Utc::now(),
+            location: "built-in defaults".to_string(),
+            source_type: "default".to_string(),
+        })
+    }
+
+    async fn reload_config(&self, owner: Option<&str>, repo: Option<&str>) -> CoreResult<()> {
+        for layer in &self.layers {
+            layer.reload_config(owner, repo).await?;
+        }
+        Ok(())
+    }
+
+    async fn config_exists(&self, owner: Option<&str>, repo: Option<&str>) -> CoreResult<bool> {
+        for layer in &self.layers {
+            if layer.config_exists(owner, repo).await? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn supported_formats(&self) -> Vec<String> {
+        let mut formats: Vec<String> = self
+            .layers
+            .iter()
+            .flat_map(|layer| layer.supported_formats())
+            .collect();
+        formats.sort();
+        formats.dedup();
+        formats
+    }
+
+    async fn get_default_config(&self) -> CoreResult<ReleaseRegentConfig> {
+        Ok(ReleaseRegentConfig::default())
+    }
+
+    /// Explicit values of every layer that reports them, merged in priority
+    /// order; `None` when any layer with configuration does not.
+    async fn load_explicit_values(
+        &self,
+        owner: Option<&str>,
+        repo: Option<&str>,
+        options: LoadOptions,
+    ) -> CoreResult<Option<Table>> {
+        let mut merged = Table::new();
+        for layer in &self.layers {
+            if !Self::has_config(layer.as_ref(), owner, repo).await? {
+                continue;
+            }
+            match layer
+                .load_explicit_values(owner, repo, options.clone())
+                .await?
+            {
+                Some(values) => overlay(&mut merged, &values, None),
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(merged))
+    }
+}
@@ -0,0 +1,208 @@
+//! Tests for the layered configuration provider.
+
+use super::*;
+use crate::{EnvironmentConfigurationProvider, FileConfigurationProvider};
+use tempfile::TempDir;
+
+const GLOBAL_CONFIG: &str = r#"
+[core]
+version_prefix = "file-"
+
+[core.branches]
+main = "develop"
+
+[releases]
+draft = true
+"#;
+
+async fn file_layer(files: &[(&str, &str)]) -> (Box<dyn ConfigurationProvider>, TempDir) {
+    let dir = TempDir::new().unwrap();
+    for (name, content) in files {
+        std::fs::write(dir.path().join(name), content).unwrap();
+    }
+    let provider = FileConfigurationProvider::new(dir.path()).await.unwrap();
+    (Box::new(provider), dir)
+}
+
+fn env_layer(vars: &[(&str, &str)]) -> Box<dyn ConfigurationProvider> {
+    Box::new(EnvironmentConfigurationProvider::from_vars(
+        "RELEASE_REGENT",
+        vars.iter().copied(),
+    ))
+}
+
+#[tokio::test]
+async fn test_env_overrides_file_which_overrides_defaults() {
+    let (file, _dir) = file_layer(&[("release-regent.toml", GLOBAL_CONFIG)]).await;
+    let provider = LayeredConfigurationProvider::new(vec![
+        file,
+        env_layer(&[("RELEASE_REGENT__CORE__VERSION_PREFIX", "env-")]),
+    ]);
+
+    let config = provider
+        .get_merged_config("owner", "repo", LoadOptions::default())
+        .await
+        .unwrap();
+
+    // Set by the environment, overriding the file.
+    assert_eq!(config.core.version_prefix, "env-");
+    // Set by the file only; the environment layer's defaults do not reset them.
+    assert_eq!(config.core.branches.main, "develop");
+    assert!(config.releases.draft);
+    // Set by neither layer.
+    assert_eq!(
+        config.error_handling.max_retries,
+        ReleaseRegentConfig::default().error_handling.max_retries
+    );
+}
+
+#[tokio::test]
+async fn test_env_can_set_a_file_value_back_to_its_default() {
+    let (file, _dir) = file_layer(&[("release-regent.toml", GLOBAL_CONFIG)]).await;
+    let provider = LayeredConfigurationProvider::new(vec![
+        file,
+        env_layer(&[("RELEASE_REGENT__RELEASES__DRAFT", "false")]),
+    ]);
+
+    let global = provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+    let merged = provider
+        .get_merged_config("owner", "repo", LoadOptions::default())
+        .await
+        .unwrap();
+
+    // `false` is the default, but the environment sets it explicitly.
+    assert!(!ReleaseRegentConfig::default().releases.draft);
+    assert!(!global.releases.draft);
+    assert!(!merged.releases.draft);
+    assert_eq!(merged.core.version_prefix, "file-");
+}
+
+#[tokio::test]
+async fn test_explicit_values_are_none_when_a_layer_only_has_full_config() {
+    let (file, _dir) = file_layer(&[("release-regent.toml", GLOBAL_CONFIG)]).await;
+    let env_only = LayeredConfigurationProvider::new(vec![env_layer(&[(
+        "RELEASE_REGENT__RELEASES__DRAFT",
+        "false",
+    )])]);
+    let with_file = LayeredConfigurationProvider::new(vec![file]);
+
+    let values = env_only
+        .load_explicit_values(None, None, LoadOptions::default())
+        .await
+        .unwrap()
+        .expect("the environment reports explicit values");
+    assert_eq!(values["releases"]["draft"], Value::Boolean(false));
+    assert_eq!(values.len(), 1);
+    assert!(with_file
+        .load_explicit_values(None, None, LoadOptions::default())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_later_layer_wins_regardless_of_provider_type() {
+    let (file, _dir) = file_layer(&[("release-regent.toml", GLOBAL_CONFIG)]).await;
+    let provider = LayeredConfigurationProvider::new(vec![
+        env_layer(&[("RELEASE_REGENT__CORE__VERSION_PREFIX", "env-")]),
+        file,
+    ]);
+
+    let config = provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(config.core.version_prefix, "file-");
+}
+
+#[tokio::test]
+async fn test_missing_lower_layer_is_skipped() {
+    // No configuration files at all: loading the file layer directly fails.
+    let (file, _dir) = file_layer(&[]).await;
+    assert!(file
+        .get_merged_config("owner", "repo", LoadOptions::default())
+        .await
+        .is_err());
+
+    let provider = LayeredConfigurationProvider::new(vec![
+        file,
+        env_layer(&[("RELEASE_REGENT__CORE__BRANCHES__MAIN", "trunk")]),
+    ]);
+
+    let config = provider
+        .get_merged_config("owner", "repo", LoadOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(config.core.branches.main, "trunk");
+    assert_eq!(config.core.version_prefix, "v");
+}
+
+#[tokio::test]
+async fn test_no_layers_yields_defaults() {
+    let provider = LayeredConfigurationProvider::new(Vec::new());
+
+    let config = provider
+        .get_merged_config("owner", "repo", LoadOptions::default())
+        .await
+        .unwrap();
+
+    assert!(provider.is_empty());
+    assert!(
+        crate::diff_configs(&config, &ReleaseRegentConfig::default())
+            .unwrap()
+            .is_empty()
+    );
+    let source = provider.get_config_source(None, None).await.unwrap();
+    assert_eq!(source.source_type, "default");
+}
+
+#[tokio::test]
+async fn test_repository_config_from_file_layer_is_merged() {
+    let (file, _dir) = file_layer(&[
+        ("release-regent.toml", GLOBAL_CONFIG),
+        ("owner-repo.toml", "[core]\nversion_prefix = \"repo-\"\n"),
+    ])
+    .await;
+    let provider = LayeredConfigurationProvider::new(vec![
+        file,
+        env_layer(&[("RELEASE_REGENT__RELEASES__PRERELEASE", "true")]),
+    ]);
+
+    let repo_config = provider
+        .load_repository_config("owner", "repo", LoadOptions::default())
+        .await
+        .unwrap()
+        .expect("repository config should be found");
+    assert_eq!(repo_config.config.core.version_prefix, "repo-");
+
+    let merged = provider
+        .get_merged_config("owner", "repo", LoadOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(merged.core.version_prefix, "repo-");
+    assert!(merged.releases.prerelease);
+
+    let source = provider.get_config_source(None, None).await.unwrap();
+    assert_eq!(source.source_type, "environment");
+}
+
+#[tokio::test]
+async fn test_save_config_uses_highest_layer_that_supports_it() {
+    let (file, dir) = file_layer(&[("release-regent.toml", GLOBAL_CONFIG)]).await;
+    let provider = LayeredConfigurationProvider::new(vec![file, env_layer(&[])]);
+
+    let mut config = ReleaseRegentConfig::default();
+    config.core.version_prefix = "saved-".to_string();
+    provider
+        .save_config(&config, None, None, true)
+        .await
+        .unwrap();
+
+    let saved = std::fs::read_to_string(dir.path().join("release-regent.toml")).unwrap();
+    assert!(saved.contains("saved-"), "got: {saved}");
+}
@@ -7,6 +7,10 @@
 //! ## Features
 //!
 //! - File-based configuration loading (TOML only)
+//! - Loading configuration from HTTPS URLs
+//! - In-memory configuration for embedders that already hold deserialized values
+//! - Environment variable overrides, layered over files and built-in defaults
+//! - Migration of configuration files written for older releases
 //! - Configuration validation with JSON Schema
 //! - Builder pattern for complex configuration setups
 //! - Comprehensive error handling
@@ -40,18 +44,34 @@
 //! ```
 
 pub mod builder;
+pub mod diff;
+pub mod env_provider;
 pub mod errors;
 pub mod file_provider;
 pub mod formats;
 pub mod github_provider;
+pub mod layered_provider;
+pub mod memory_provider;
+pub mod migration;
+pub mod remote;
 pub mod validation;
+pub mod watch;
 
 pub use builder::ConfigurationBuilder;
+pub use diff::{diff_configs, diff_effective_config, FieldDiff};
+pub use env_provider::EnvironmentConfigurationProvider;
 pub use errors::{ConfigProviderError, ConfigProviderResult};
 pub use file_provider::FileConfigurationProvider;
 pub use formats::{is_toml_path, parse_config, serialize_config, validate_toml_path};
 pub use github_provider::GitHubConfigurationProvider;
-pub use validation::{ConfigValidator, ValidationResult as ConfigValidationResult};
+pub use layered_provider::LayeredConfigurationProvider;
+pub use memory_provider::InMemoryConfigurationProvider;
+pub use migration::{ConfigMigrator, MigrationOutcome, MigrationReport};
+pub use remote::{RemoteConfigSource, RemoteRetryPolicy};
+pub use validation::{
+    ConfigValidator, FindingSeverity, ValidationFinding, ValidationResult as ConfigValidationResult,
+};
+pub use watch::{ConfigChange, ConfigScope, ConfigWatchGuard};
 
 // Re-export core types for convenience
 pub use release_regent_core::{
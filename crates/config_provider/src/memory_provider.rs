@@ -0,0 +1,312 @@
+//! In-memory configuration provider.
+//!
+//! [`InMemoryConfigurationProvider`] serves configuration that the embedding
+//! application already holds as [`ReleaseRegentConfig`] values, for example
+//! configuration read from a database. It stores a global configuration plus
+//! per-repository configurations and merges them exactly as
+//! [`FileConfigurationProvider`](crate::FileConfigurationProvider) does.
+//!
+//! ```rust
+//! use release_regent_config_provider::{InMemoryConfigurationProvider, ReleaseRegentConfig};
+//! use release_regent_core::traits::ConfigurationProvider;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut repo_config = ReleaseRegentConfig::default();
+//! repo_config.core.version_prefix = "release-".to_string();
+//!
+//! let provider = InMemoryConfigurationProvider::new()
+//!     .with_repository_config("owner", "repo", repo_config);
+//!
+//! let merged = provider
+//!     .get_merged_config("owner", "repo", Default::default())
+//!     .await?;
+//! assert_eq!(merged.core.version_prefix, "release-");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Configurations can be replaced at runtime with
+//! [`set_global_config`](InMemoryConfigurationProvider::set_global_config) and
+//! [`set_repository_config`](InMemoryConfigurationProvider::set_repository_config),
+//! or through [`ConfigurationProvider::save_config`].
+
+use crate::{file_provider::FileConfigurationProvider, validation::ConfigValidator};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use release_regent_core::{
+    config::ReleaseRegentConfig,
+    errors::CoreError,
+    traits::{
+        configuration_provider::{
+            ConfigurationSource, LoadOptions, RepositoryConfig, ValidationResult,
+        },
+        ConfigurationProvider,
+    },
+    CoreResult,
+};
+use std::collections::BTreeMap;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+#[cfg(test)]
+#[path = "memory_provider_tests.rs"]
+mod tests;
+
+/// Configuration provider backed by configuration values held in memory.
+pub struct InMemoryConfigurationProvider {
+    /// Global configuration; the built-in defaults when unset
+    global: RwLock<Option<ReleaseRegentConfig>>,
+    /// Repository configurations, keyed by `(owner, repo)`
+    repositories: RwLock<BTreeMap<(String, String), ReleaseRegentConfig>>,
+    /// When the provider was created or last changed
+    updated_at: RwLock<DateTime<Utc>>,
+    /// Configuration validator
+    validator: ConfigValidator,
+}
+
+impl InMemoryConfigurationProvider {
+    /// Create a provider with no configuration; the global configuration is
+    /// the built-in defaults until one is set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            global: RwLock::new(None),
+            repositories: RwLock::new(BTreeMap::new()),
+            updated_at: RwLock::new(Utc::now()),
+            validator: ConfigValidator::new(),
+        }
+    }
+
+    /// Use `config` as the global configuration.
+    #[must_use]
+    pub fn with_global_config(mut self, config: ReleaseRegentConfig) -> Self {
+        *self.global.get_mut() = Some(config);
+        self
+    }
+
+    /// Use `config` as the configuration of `owner/repo`.
+    #[must_use]
+    pub fn with_repository_config(
+        mut self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        config: ReleaseRegentConfig,
+    ) -> Self {
+        self.repositories
+            .get_mut()
+            .insert((owner.into(), repo.into()), config);
+        self
+    }
+
+    /// Replace the global configuration.
+    pub async fn set_global_config(&self, config: ReleaseRegentConfig) {
+        *self.global.write().await = Some(config);
+        self.touch().await;
+    }
+
+    /// Replace the configuration of `owner/repo`.
+    pub async fn set_repository_config(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        config: ReleaseRegentConfig,
+    ) {
+        self.repositories
+            .write()
+            .await
+            .insert((owner.into(), repo.into()), config);
+        self.touch().await;
+    }
+
+    /// Remove the configuration of `owner/repo`, returning it if present.
+    pub async fn remove_repository_config(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Option<ReleaseRegentConfig> {
+        let removed = self
+            .repositories
+            .write()
+            .await
+            .remove(&(owner.to_string(), repo.to_string()));
+        if removed.is_some() {
+            self.touch().await;
+        }
+        removed
+    }
+
+    async fn touch(&self) {
+        *self.updated_at.write().await = Utc::now();
+    }
+
+    /// Reject configuration that fails validation.
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large
+    fn validated(
+        &self,
+        config: ReleaseRegentConfig,
+        kind: &str,
+    ) -> CoreResult<ReleaseRegentConfig> {
+        let result = self
+            .validator
+            .validate(&config)
+            .map_err(|e| CoreError::config(e.to_string()))?;
+        if !result.is_valid {
+            return Err(CoreError::config(format!(
+                "{kind} configuration validation failed: {:?}",
+                result.errors
+            )));
+        }
+        Ok(config)
+    }
+}
+
+impl Default for InMemoryConfigurationProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ConfigurationProvider for InMemoryConfigurationProvider {
+    async fn load_global_config(&self, _options: LoadOptions) -> CoreResult<ReleaseRegentConfig> {
+        let config = self.global.read().await.clone().unwrap_or_default();
+        self.validated(config, "Global")
+    }
+
+    async fn load_repository_config(
+        &self,
+        owner: &str,
+        repo: &str,
+        _options: LoadOptions,
+    ) -> CoreResult<Option<RepositoryConfig>> {
+        let config = self
+            .repositories
+            .read()
+            .await
+            .get(&(owner.to_string(), repo.to_string()))
+            .cloned();
+        let Some(config) = config else {
+            return Ok(None);
+        };
+
+        Ok(Some(RepositoryConfig {
+            config: self.validated(config, "Repository")?,
+            name: repo.to_string(),
+            owner: owner.to_string(),
+        }))
+    }
+
+    async fn get_merged_config(
+        &self,
+        owner: &str,
+        repo: &str,
+        options: LoadOptions,
+    ) -> CoreResult<ReleaseRegentConfig> {
+        let global_config = self.load_global_config(options.clone()).await?;
+
+        if let Some(repo_config) = self.load_repository_config(owner, repo, options).await? {
+            Ok(FileConfigurationProvider::merge_configurations(
+                global_config,
+                repo_config.config,
+            ))
+        } else {
+            debug!(
+                "Using global configuration for {}/{} (no repository-specific config)",
+                owner, repo
+            );
+            Ok(global_config)
+        }
+    }
+
+    async fn validate_config(&self, config: &ReleaseRegentConfig) -> CoreResult<ValidationResult> {
+        let result = self
+            .validator
+            .validate(config)
+            .map_err(|e| CoreError::config(e.to_string()))?;
+        Ok(ValidationResult {
+            is_valid: result.is_valid,
+            errors: result.errors,
+            warnings: result.warnings,
+        })
+    }
+
+    async fn save_config(
+        &self,
+        config: &ReleaseRegentConfig,
+        owner: Option<&str>,
+        repo: Option<&str>,
+        global: bool,
+    ) -> CoreResult<()> {
+        if global {
+            self.set_global_config(config.clone()).await;
+            return Ok(());
+        }
+        match (owner, repo) {
+            (Some(owner), Some(repo)) => {
+                self.set_repository_config(owner, repo, config.clone())
+                    .await;
+                Ok(())
+            }
+            _ => Err(CoreError::config(
+                "Owner and repo must be specified for repository configuration",
+            )),
+        }
+    }
+
+    async fn list_repository_configs(
+        &self,
+        _options: LoadOptions,
+    ) -> CoreResult<Vec<RepositoryConfig>> {
+        Ok(self
+            .repositories
+            .read()
+            .await
+            .iter()
+            .map(|((owner, repo), config)| RepositoryConfig {
+                config: config.clone(),
+                name: repo.clone(),
+                owner: owner.clone(),
+            })
+            .collect())
+    }
+
+    async fn get_config_source(
+        &self,
+        owner: Option<&str>,
+        repo: Option<&str>,
+    ) -> CoreResult<ConfigurationSource> {
+        let location = match (owner, repo) {
+            (Some(owner), Some(repo)) => format!("memory:{owner}/{repo}"),
+            _ => "memory:global".to_string(),
+        };
+        Ok(ConfigurationSource {
+            format: "memory".to_string(),
+            loaded_at: *self.updated_at.read().await,
+            location,
+            source_type: "memory".to_string(),
+        })
+    }
+
+    async fn reload_config(&self, _owner: Option<&str>, _repo: Option<&str>) -> CoreResult<()> {
+        Ok(())
+    }
+
+    async fn config_exists(&self, owner: Option<&str>, repo: Option<&str>) -> CoreResult<bool> {
+        match (owner, repo) {
+            (Some(owner), Some(repo)) => Ok(self
+                .repositories
+                .read()
+                .await
+                .contains_key(&(owner.to_string(), repo.to_string()))),
+            _ => Ok(self.global.read().await.is_some()),
+        }
+    }
+
+    fn supported_formats(&self) -> Vec<String> {
+        vec!["memory".to_string()]
+    }
+
+    async fn get_default_config(&self) -> CoreResult<ReleaseRegentConfig> {
+        Ok(ReleaseRegentConfig::default())
+    }
+}
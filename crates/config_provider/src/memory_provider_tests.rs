@@ -0,0 +1,185 @@
+//! Tests for the in-memory configuration provider.
+
+use super::*;
+use crate::formats::serialize_config;
+use tempfile::TempDir;
+
+fn global_config() -> ReleaseRegentConfig {
+    let mut config = ReleaseRegentConfig::default();
+    config.core.version_prefix = "global-".to_string();
+    config.core.branches.main = "develop".to_string();
+    config.releases.draft = true;
+    config.changelog.include_shas = false;
+    config
+}
+
+fn repo_config() -> ReleaseRegentConfig {
+    let mut config = ReleaseRegentConfig::default();
+    config.core.version_prefix = "repo-".to_string();
+    config.release_pr.draft = true;
+    config.changelog.include_authors = false;
+    config
+}
+
+/// A file provider holding the same configuration as files on disk.
+async fn file_provider_with(
+    global: &ReleaseRegentConfig,
+    repos: &[(&str, &str, &ReleaseRegentConfig)],
+) -> (FileConfigurationProvider, TempDir) {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join("release-regent.toml"),
+        serialize_config(global).unwrap(),
+    )
+    .unwrap();
+    for (owner, repo, config) in repos {
+        std::fs::write(
+            dir.path().join(format!("{owner}-{repo}.toml")),
+            serialize_config(config).unwrap(),
+        )
+        .unwrap();
+    }
+    let provider = FileConfigurationProvider::new(dir.path()).await.unwrap();
+    (provider, dir)
+}
+
+async fn merged_toml(provider: &dyn ConfigurationProvider, owner: &str, repo: &str) -> String {
+    let merged = provider
+        .get_merged_config(owner, repo, LoadOptions::default())
+        .await
+        .unwrap();
+    serialize_config(&merged).unwrap()
+}
+
+#[tokio::test]
+async fn test_merged_config_matches_file_provider() {
+    let memory = InMemoryConfigurationProvider::new()
+        .with_global_config(global_config())
+        .with_repository_config("acme", "widgets", repo_config());
+    let (file, _dir) =
+        file_provider_with(&global_config(), &[("acme", "widgets", &repo_config())]).await;
+
+    assert_eq!(
+        merged_toml(&memory, "acme", "widgets").await,
+        merged_toml(&file, "acme", "widgets").await
+    );
+
+    let merged = memory
+        .get_merged_config("acme", "widgets", LoadOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(merged.core.version_prefix, "repo-");
+    assert!(merged.release_pr.draft);
+}
+
+#[tokio::test]
+async fn test_repository_without_config_uses_global_like_file_provider() {
+    let memory = InMemoryConfigurationProvider::new()
+        .with_global_config(global_config())
+        .with_repository_config("acme", "widgets", repo_config());
+    let (file, _dir) =
+        file_provider_with(&global_config(), &[("acme", "widgets", &repo_config())]).await;
+
+    assert_eq!(
+        merged_toml(&memory, "acme", "gadgets").await,
+        merged_toml(&file, "acme", "gadgets").await
+    );
+    assert_eq!(
+        memory
+            .get_merged_config("acme", "gadgets", LoadOptions::default())
+            .await
+            .unwrap()
+            .core
+            .version_prefix,
+        "global-"
+    );
+}
+
+#[tokio::test]
+async fn test_global_config_defaults_when_unset() {
+    let provider = InMemoryConfigurationProvider::new();
+
+    let global = provider
+        .load_global_config(LoadOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        serialize_config(&global).unwrap(),
+        serialize_config(&ReleaseRegentConfig::default()).unwrap()
+    );
+    assert!(!provider.config_exists(None, None).await.unwrap());
+    assert!(provider
+        .load_repository_config("acme", "widgets", LoadOptions::default())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_set_and_save_config_replace_stored_values() {
+    let provider = InMemoryConfigurationProvider::new();
+
+    provider.set_global_config(global_config()).await;
+    provider
+        .save_config(&repo_config(), Some("acme"), Some("widgets"), false)
+        .await
+        .unwrap();
+
+    assert!(provider.config_exists(None, None).await.unwrap());
+    assert!(provider
+        .config_exists(Some("acme"), Some("widgets"))
+        .await
+        .unwrap());
+    let listed = provider
+        .list_repository_configs(LoadOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(
+        (listed[0].owner.as_str(), listed[0].name.as_str()),
+        ("acme", "widgets")
+    );
+
+    let removed = provider.remove_repository_config("acme", "widgets").await;
+    assert!(removed.is_some());
+    assert!(!provider
+        .config_exists(Some("acme"), Some("widgets"))
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_save_repository_config_requires_owner_and_repo() {
+    let provider = InMemoryConfigurationProvider::new();
+
+    let result = provider
+        .save_config(&repo_config(), Some("acme"), None, false)
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_invalid_stored_config_is_rejected_on_load() {
+    let mut invalid = ReleaseRegentConfig::default();
+    invalid.core.branches.main = String::new();
+    let provider = InMemoryConfigurationProvider::new().with_global_config(invalid);
+
+    let result = provider.load_global_config(LoadOptions::default()).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_config_source_reports_memory() {
+    let provider = InMemoryConfigurationProvider::new();
+
+    let source = provider
+        .get_config_source(Some("acme"), Some("widgets"))
+        .await
+        .unwrap();
+
+    assert_eq!(source.source_type, "memory");
+    assert_eq!(source.location, "memory:acme/widgets");
+}
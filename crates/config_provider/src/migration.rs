@@ -0,0 +1,292 @@
+//! Migration of configuration files written for older releases.
+//!
+//! Every configuration file carries a `config_version`. [`ConfigMigrator`]
+//! reads a file as plain TOML, applies the migration steps between its
+//! version and [`CURRENT_CONFIG_VERSION`] in order, and reports each change
+//! it made. Files without a `config_version` predate the field and are
+//! treated as version 1; the version 1 step only touches keys that no longer
+//! exist, so a file that is already in the current shape comes through
+//! unchanged apart from gaining the version field.
+
+use crate::errors::{ConfigProviderError, ConfigProviderResult};
+use crate::formats::parse_config;
+use crate::validation::ConfigValidator;
+use release_regent_core::{
+    config::{ReleaseRegentConfig, CURRENT_CONFIG_VERSION},
+    secret::describe_toml_error,
+};
+use std::fmt;
+use std::path::PathBuf;
+use toml::{Table, Value};
+use tracing::info;
+
+#[cfg(test)]
+#[path = "migration_tests.rs"]
+mod tests;
+
+/// A migration from one configuration version to the next. Each change made
+/// is described in the second argument.
+type MigrationStep = fn(&mut Table, &mut Vec<String>);
+
+/// Brings configuration files written for older releases up to the current
+/// [`CURRENT_CONFIG_VERSION`].
+pub struct ConfigMigrator {
+    /// Steps keyed by the version they migrate from, in ascending order
+    steps: Vec<(u32, MigrationStep)>,
+}
+
+/// What a migration changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// Version the input was written for
+    pub from_version: u32,
+    /// Version of the output
+    pub to_version: u32,
+    /// Human-readable description of each change, in the order applied
+    pub changes: Vec<String>,
+}
+
+impl MigrationReport {
+    /// Returns `true` when the migration made no changes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for MigrationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "Configuration is already at version {}", self.to_version);
+        }
+
+        write!(
+            f,
+            "Migrated configuration from version {} to {}:",
+            self.from_version, self.to_version
+        )?;
+        for change in &self.changes {
+            write!(f, "\n  - {change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A migrated and validated configuration.
+#[derive(Debug, Clone)]
+pub struct MigrationOutcome {
+    /// The migrated file, holding only the keys the input set; write this
+    /// back rather than `config` to avoid spelling out every default
+    pub table: Table,
+    /// The configuration in the current shape
+    pub config: ReleaseRegentConfig,
+    /// What the migration changed
+    pub report: MigrationReport,
+}
+
+impl ConfigMigrator {
+    /// Create a migrator with every known migration step.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            steps: vec![(1, migrate_v1_to_v2 as MigrationStep)],
+        }
+    }
+
+    /// Read the `config_version` of a configuration file, treating a missing
+    /// field as version 1.
+    ///
+    /// # Errors
+    /// - `ConfigProviderError::UnsupportedConfigVersion` — the version is below 1 or
+    ///   newer than [`CURRENT_CONFIG_VERSION`]
+    /// - `ConfigProviderError::ParseError` — `config_version` is not an integer
+    #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+    pub fn detect_version(table: &Table) -> ConfigProviderResult<u32> {
+        match table.get("config_version") {
+            None => Ok(1),
+            Some(Value::Integer(version)) => u32::try_from(*version)
+                .ok()
+                .filter(|v| (1..=CURRENT_CONFIG_VERSION).contains(v))
+                .ok_or(ConfigProviderError::UnsupportedConfigVersion {
+                    version: *version,
+                    supported: CURRENT_CONFIG_VERSION,
+                }),
+            Some(other) => Err(ConfigProviderError::parse_error(
+                PathBuf::new(),
+                format!(
+                    "config_version must be an integer, found {}",
+                    other.type_str()
+                ),
+            )),
+        }
+    }
+
+    /// Migrate a configuration table to the current version.
+    ///
+    /// # Errors
+    /// - `ConfigProviderError::UnsupportedConfigVersion` — see [`detect_version`](Self::detect_version)
+    /// - `ConfigProviderError::ParseError` — `config_version` is not an integer
+    #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+    pub fn migrate_table(
+        &self,
+        mut table: Table,
+    ) -> ConfigProviderResult<(Table, MigrationReport)> {
+        let from_version = Self::detect_version(&table)?;
+        let mut changes = Vec::new();
+
+        for (version, step) in &self.steps {
+            if *version >= from_version {
+                step(&mut table, &mut changes);
+            }
+        }
+
+        if table.get("config_version") != Some(&Value::Integer(i64::from(CURRENT_CONFIG_VERSION))) {
+            table.insert(
+                "config_version".to_string(),
+                Value::Integer(i64::from(CURRENT_CONFIG_VERSION)),
+            );
+            changes.push(format!("Set config_version to {CURRENT_CONFIG_VERSION}"));
+        }
+
+        Ok((
+            table,
+            MigrationReport {
+                from_version,
+                to_version: CURRENT_CONFIG_VERSION,
+                changes,
+            },
+        ))
+    }
+
+    /// Migrate TOML configuration content to the current version, then parse
+    /// and validate the result.
+    ///
+    /// # Errors
+    /// - `ConfigProviderError::ParseError` — the content, or the migrated
+    ///   result, is not a valid configuration
+    /// - `ConfigProviderError::UnsupportedConfigVersion` — see [`detect_version`](Self::detect_version)
+    /// - `ConfigProviderError::ValidationError` — the migrated configuration fails validation
+    #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+    pub fn migrate(&self, content: &str) -> ConfigProviderResult<MigrationOutcome> {
+        let table: Table = toml::from_str(content).map_err(|e| {
+            ConfigProviderError::parse_error(
+                PathBuf::new(),
+                format!(
+                    "Failed to parse TOML: {}",
+                    describe_toml_error(&e, Some(content))
+                ),
+            )
+        })?;
+
+        let (table, report) = self.migrate_table(table)?;
+        let config = parse_config(&toml::to_string(&table)?)?;
+
+        let validation = ConfigValidator::new().validate(&config)?;
+        if !validation.is_valid {
+            return Err(ConfigProviderError::validation_error(
+                PathBuf::new(),
+                validation.errors,
+            ));
+        }
+
+        info!(
+            "Migrated configuration from version {} to {} ({} changes)",
+            report.from_version,
+            report.to_version,
+            report.changes.len()
+        );
+        Ok(MigrationOutcome {
+            table,
+            config,
+            report,
+        })
+    }
+}
+
+impl Default for ConfigMigrator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Version 1 kept the tag prefix under `[versioning]` and branch settings
+/// under `[repository]`.
+fn migrate_v1_to_v2(table: &mut Table, changes: &mut Vec<String>) {
+    move_key(
+        table,
+        ("versioning", "prefix"),
+        &["core", "version_prefix"],
+        changes,
+    );
+    move_key(
+        table,
+        ("repository", "main_branch"),
+        &["core", "branches", "main"],
+        changes,
+    );
+
+    if take(table, "versioning", "allow_prerelease").is_some() {
+        changes.push(
+            "Removed versioning.allow_prerelease; pre-release versions are always accepted"
+                .to_string(),
+        );
+    }
+    if take(table, "repository", "release_branch_pattern").is_some() {
+        changes.push(
+            "Removed repository.release_branch_pattern; release branches are always named \
+             release/<prefix><version>"
+                .to_string(),
+        );
+    }
+
+    for section in ["repository", "versioning"] {
+        if table
+            .get(section)
+            .and_then(Value::as_table)
+            .is_some_and(Table::is_empty)
+        {
+            table.remove(section);
+        }
+    }
+}
+
+/// Move `from` (a key in a top-level section) to the nested key `to`. An
+/// existing value at `to` wins over the legacy one.
+fn move_key(table: &mut Table, from: (&str, &str), to: &[&str], changes: &mut Vec<String>) {
+    let Some(value) = take(table, from.0, from.1) else {
+        return;
+    };
+    let (key, sections) = to.split_last().expect("destination must not be empty");
+    let from_path = format!("{}.{}", from.0, from.1);
+    let to_path = to.join(".");
+
+    let mut target = &mut *table;
+    for section in sections {
+        let entry = target
+            .entry((*section).to_string())
+            .or_insert_with(|| Value::Table(Table::new()));
+        let Some(nested) = entry.as_table_mut() else {
+            changes.push(format!(
+                "Removed {from_path}; {section} is not a table so it could not be moved to {to_path}"
+            ));
+            return;
+        };
+        target = nested;
+    }
+
+    if target.contains_key(*key) {
+        changes.push(format!(
+            "Removed {from_path}; {to_path} is already set and takes precedence"
+        ));
+    } else {
+        target.insert((*key).to_string(), value);
+        changes.push(format!("Moved {from_path} to {to_path}"));
+    }
+}
+
+fn take(table: &mut Table, section: &str, key: &str) -> Option<Value> {
+    table
+        .get_mut(section)
+        .and_then(Value::as_table_mut)
+        .and_then(|t| t.remove(key))
+}
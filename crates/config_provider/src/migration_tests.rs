@@ -0,0 +1,166 @@
+//! Unit tests for configuration migration.
+
+use super::*;
+
+/// A configuration in the version 1 layout.
+const V1_CONFIG: &str = r#"
+[release_pr]
+title_template = "chore(release): prepare version ${version}"
+
+[versioning]
+prefix = "rel-"
+allow_prerelease = true
+strategy = "conventional"
+
+[repository]
+main_branch = "trunk"
+release_branch_pattern = "release/v{version}"
+"#;
+
+const CURRENT_CONFIG: &str = r#"
+config_version = 2
+
+[core]
+version_prefix = "v"
+
+[core.branches]
+main = "main"
+"#;
+
+fn table(content: &str) -> Table {
+    toml::from_str(content).unwrap()
+}
+
+#[test]
+fn test_migrates_v1_config_to_current_version() {
+    let outcome = ConfigMigrator::new().migrate(V1_CONFIG).unwrap();
+
+    assert_eq!(outcome.report.from_version, 1);
+    assert_eq!(outcome.report.to_version, CURRENT_CONFIG_VERSION);
+    assert_eq!(outcome.config.config_version, CURRENT_CONFIG_VERSION);
+    assert_eq!(outcome.config.core.version_prefix, "rel-");
+    assert_eq!(outcome.config.core.branches.main, "trunk");
+    assert_eq!(
+        outcome.config.release_pr.title_template,
+        "chore(release): prepare version ${version}"
+    );
+    outcome
+        .config
+        .validate()
+        .expect("migrated config should be valid");
+}
+
+#[test]
+fn test_report_lists_each_change() {
+    let outcome = ConfigMigrator::new().migrate(V1_CONFIG).unwrap();
+
+    assert_eq!(
+        outcome.report.changes,
+        vec![
+            "Moved versioning.prefix to core.version_prefix".to_string(),
+            "Moved repository.main_branch to core.branches.main".to_string(),
+            "Removed versioning.allow_prerelease; pre-release versions are always accepted"
+                .to_string(),
+            "Removed repository.release_branch_pattern; release branches are always named \
+             release/<prefix><version>"
+                .to_string(),
+            format!("Set config_version to {CURRENT_CONFIG_VERSION}"),
+        ]
+    );
+    let rendered = outcome.report.to_string();
+    assert!(rendered.starts_with("Migrated configuration from version 1 to 2:"));
+}
+
+#[test]
+fn test_migrated_table_drops_legacy_sections() {
+    let (migrated, _) = ConfigMigrator::new()
+        .migrate_table(table(V1_CONFIG))
+        .unwrap();
+
+    assert!(!migrated.contains_key("repository"));
+    let versioning = migrated["versioning"].as_table().unwrap();
+    assert!(!versioning.contains_key("prefix"));
+    assert_eq!(versioning["strategy"].as_str(), Some("conventional"));
+}
+
+#[test]
+fn test_existing_current_key_takes_precedence() {
+    let content = format!("{V1_CONFIG}\n[core]\nversion_prefix = \"v\"\n");
+
+    let outcome = ConfigMigrator::new().migrate(&content).unwrap();
+
+    assert_eq!(outcome.config.core.version_prefix, "v");
+    assert!(outcome.report.changes.contains(
+        &"Removed versioning.prefix; core.version_prefix is already set and takes precedence"
+            .to_string()
+    ));
+}
+
+#[test]
+fn test_current_config_is_unchanged() {
+    let outcome = ConfigMigrator::new().migrate(CURRENT_CONFIG).unwrap();
+
+    assert!(outcome.report.is_empty());
+    assert_eq!(outcome.report.from_version, CURRENT_CONFIG_VERSION);
+    assert_eq!(
+        outcome.report.to_string(),
+        format!("Configuration is already at version {CURRENT_CONFIG_VERSION}")
+    );
+}
+
+#[test]
+fn test_missing_version_is_treated_as_v1() {
+    let unversioned = CURRENT_CONFIG.replace("config_version = 2", "");
+
+    let outcome = ConfigMigrator::new().migrate(&unversioned).unwrap();
+
+    assert_eq!(outcome.report.from_version, 1);
+    assert_eq!(
+        outcome.report.changes,
+        vec![format!("Set config_version to {CURRENT_CONFIG_VERSION}")]
+    );
+    assert_eq!(outcome.config.core.version_prefix, "v");
+}
+
+#[test]
+fn test_newer_version_is_rejected() {
+    let newer = CURRENT_CONFIG.replace("config_version = 2", "config_version = 99");
+
+    let result = ConfigMigrator::new().migrate(&newer);
+
+    match result {
+        Err(ConfigProviderError::UnsupportedConfigVersion { version, supported }) => {
+            assert_eq!(version, 99);
+            assert_eq!(supported, CURRENT_CONFIG_VERSION);
+        }
+        other => panic!("expected UnsupportedConfigVersion, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_invalid_version_values_are_rejected() {
+    for value in ["0", "-1"] {
+        let content = format!("config_version = {value}\n");
+        assert!(matches!(
+            ConfigMigrator::detect_version(&table(&content)),
+            Err(ConfigProviderError::UnsupportedConfigVersion { .. })
+        ));
+    }
+
+    assert!(matches!(
+        ConfigMigrator::detect_version(&table("config_version = \"2\"\n")),
+        Err(ConfigProviderError::ParseError { .. })
+    ));
+}
+
+#[test]
+fn test_migrated_config_must_pass_validation() {
+    let content = V1_CONFIG.replace("main_branch = \"trunk\"", "main_branch = \"\"");
+
+    let result = ConfigMigrator::new().migrate(&content);
+
+    assert!(matches!(
+        result,
+        Err(ConfigProviderError::ValidationError { .. })
+    ));
+}
@@ -0,0 +1,341 @@
+//! Loading configuration from HTTP(S) URLs.
+//!
+//! A [`RemoteConfigSource`] fetches a configuration file from a central
+//! service and parses it exactly like a local file. Only `https` URLs are
+//! accepted unless insecure URLs are explicitly allowed, and the same rule
+//! applies to every redirect the server answers with.
+//!
+//! Configuration services often carry credentials in the userinfo or query of
+//! the URL, so logs and errors only ever show the URL without them.
+//!
+//! Transient failures — timeouts, connection errors and `5xx` or `429`
+//! responses — are retried with exponential backoff according to a
+//! [`RemoteRetryPolicy`]. Anything else, such as a `404` or a body that does
+//! not parse, fails immediately because asking again would not change it.
+
+use crate::errors::{ConfigProviderError, ConfigProviderResult};
+use crate::formats::{is_toml_path, parse_config};
+use release_regent_core::config::ReleaseRegentConfig;
+use reqwest::{header::CONTENT_TYPE, redirect::Policy, Client, Url};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+#[cfg(test)]
+#[path = "remote_tests.rs"]
+mod tests;
+
+/// Default time allowed for fetching a remote configuration.
+pub const DEFAULT_REMOTE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Redirects followed before a fetch fails, as in reqwest's default policy.
+const MAX_REDIRECTS: usize = 10;
+
+/// Backoff applied when fetching a remote configuration fails transiently.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteRetryPolicy {
+    /// Maximum number of retries after the initial attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled for each further retry.
+    pub base_delay: Duration,
+    /// Upper bound for the delay between two attempts.
+    pub max_delay: Duration,
+}
+
+impl Default for RemoteRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RemoteRetryPolicy {
+    /// A policy that never retries.
+    #[must_use]
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Compute the delay to wait before retry number `attempt`.
+    ///
+    /// `attempt` is zero-based: `0` is the delay before the first retry.
+    #[must_use]
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay)
+    }
+}
+
+/// A configuration file served over HTTP(S).
+#[derive(Debug, Clone)]
+pub struct RemoteConfigSource {
+    url: Url,
+    /// `url` without credentials, for logs and errors.
+    display_url: String,
+    timeout: Duration,
+    retry_policy: RemoteRetryPolicy,
+    client: Client,
+}
+
+impl RemoteConfigSource {
+    /// Create a source for `url`.
+    ///
+    /// Redirects to `http` URLs are refused unless `allow_insecure` is set.
+    ///
+    /// # Errors
+    /// - `ConfigProviderError::InvalidUrl` — `url` cannot be parsed or is not http(s)
+    /// - `ConfigProviderError::InsecureUrl` — `url` uses `http` and `allow_insecure` is false
+    /// - `ConfigProviderError::RemoteRequest` — the HTTP client could not be created
+    #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+    pub fn new(url: &str, allow_insecure: bool) -> ConfigProviderResult<Self> {
+        let parsed = Url::parse(url).map_err(|e| ConfigProviderError::InvalidUrl {
+            url: redact_unparsed_url(url),
+            reason: e.to_string(),
+        })?;
+        let display_url = redact_url(&parsed);
+
+        match parsed.scheme() {
+            "https" => {}
+            "http" if allow_insecure => {}
+            "http" => return Err(ConfigProviderError::InsecureUrl { url: display_url }),
+            scheme => {
+                return Err(ConfigProviderError::InvalidUrl {
+                    url: display_url,
+                    reason: format!("unsupported scheme '{scheme}'"),
+                })
+            }
+        }
+
+        let client =
+            build_client(allow_insecure).map_err(|e| ConfigProviderError::RemoteRequest {
+                url: display_url.clone(),
+                message: format!("failed to create HTTP client: {e}"),
+                source: Some(Box::new(e)),
+            })?;
+
+        Ok(Self {
+            url: parsed,
+            display_url,
+            timeout: DEFAULT_REMOTE_TIMEOUT,
+            retry_policy: RemoteRetryPolicy::default(),
+            client,
+        })
+    }
+
+    /// Set the time allowed for the whole request, including reading the body.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the backoff applied to transient failures.
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: RemoteRetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// The URL the configuration is fetched from, without its userinfo,
+    /// query or fragment, which may hold credentials.
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.display_url
+    }
+
+    /// The request timeout.
+    #[must_use]
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// The backoff applied to transient failures.
+    #[must_use]
+    pub fn retry_policy(&self) -> &RemoteRetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Fetch and parse the configuration.
+    ///
+    /// The response must be TOML: either its `Content-Type` names TOML or the
+    /// URL path ends in `.toml`.
+    ///
+    /// Failures for which [`ConfigProviderError::is_retryable`] holds are
+    /// retried according to the [`retry_policy`](Self::retry_policy); the
+    /// error of the last attempt is returned once the retries are used up.
+    ///
+    /// # Errors
+    /// - `ConfigProviderError::RemoteTimeout` — the request did not complete within the timeout
+    /// - `ConfigProviderError::RemoteRequest` — connecting, the TLS handshake or reading the body failed
+    /// - `ConfigProviderError::RemoteStatus` — the server answered with a non-success status
+    /// - `ConfigProviderError::UnsupportedFormat` — the response is not TOML
+    /// - `ConfigProviderError::ParseError` — the body could not be parsed
+    #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+    pub async fn fetch(&self) -> ConfigProviderResult<ReleaseRegentConfig> {
+        let mut attempt = 0;
+        loop {
+            match self.fetch_once().await {
+                Err(e) if e.is_retryable() && attempt < self.retry_policy.max_retries => {
+                    let delay = self.retry_policy.delay_for(attempt);
+                    attempt += 1;
+                    warn!(
+                        "Fetching configuration from {} failed (attempt {attempt}), retrying in {delay:?}: {e}",
+                        self.display_url
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+    async fn fetch_once(&self) -> ConfigProviderResult<ReleaseRegentConfig> {
+        debug!("Fetching configuration from {}", self.display_url);
+
+        let response = self
+            .client
+            .get(self.url.clone())
+            .timeout(self.timeout)
+            .send()
+            .await
+            .map_err(|e| self.request_error(e))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ConfigProviderError::RemoteStatus {
+                url: self.display_url.clone(),
+                status: status.as_u16(),
+            });
+        }
+
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        self.check_format(content_type.as_deref())?;
+
+        let body = response.text().await.map_err(|e| self.request_error(e))?;
+        let config = parse_config(&body).map_err(|e| match e {
+            ConfigProviderError::ParseError { reason, source, .. } => {
+                ConfigProviderError::ParseError {
+                    path: self.display_path(),
+                    reason,
+                    source,
+                }
+            }
+            other => other,
+        })?;
+
+        info!(
+            "Successfully loaded configuration from: {}",
+            self.display_url
+        );
+        Ok(config)
+    }
+
+    /// Accept TOML content types, or any content type when the URL itself
+    /// names a `.toml` file.
+    #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+    fn check_format(&self, content_type: Option<&str>) -> ConfigProviderResult<()> {
+        let media_type = content_type
+            .and_then(|value| value.split(';').next())
+            .map(|value| value.trim().to_ascii_lowercase());
+
+        if media_type.as_deref().is_some_and(|m| m.ends_with("toml"))
+            || is_toml_path(Path::new(self.url.path()))
+        {
+            return Ok(());
+        }
+
+        Err(ConfigProviderError::UnsupportedFormat {
+            format: media_type.unwrap_or_else(|| "unknown".to_string()),
+            path: self.display_path(),
+        })
+    }
+
+    fn request_error(&self, error: reqwest::Error) -> ConfigProviderError {
+        // reqwest names the full request URL in its message.
+        let error = error.without_url();
+        if error.is_timeout() {
+            return ConfigProviderError::RemoteTimeout {
+                url: self.display_url.clone(),
+                timeout: self.timeout,
+            };
+        }
+
+        // reqwest's own message is generic; the underlying cause (refused
+        // connection, certificate problem, ...) is further down the chain.
+        let mut message = error.to_string();
+        let mut cause = std::error::Error::source(&error);
+        while let Some(inner) = cause {
+            message.push_str(": ");
+            message.push_str(&inner.to_string());
+            cause = inner.source();
+        }
+
+        ConfigProviderError::RemoteRequest {
+            url: self.display_url.clone(),
+            message,
+            source: Some(Box::new(error)),
+        }
+    }
+
+    fn display_path(&self) -> PathBuf {
+        PathBuf::from(&self.display_url)
+    }
+}
+
+/// Build the HTTP client, following redirects only to `https` URLs unless
+/// `allow_insecure` is set.
+fn build_client(allow_insecure: bool) -> reqwest::Result<Client> {
+    Client::builder()
+        .redirect(redirect_policy(allow_insecure))
+        .build()
+}
+
+/// Follow up to [`MAX_REDIRECTS`] redirects, refusing `http` targets unless
+/// `allow_insecure` is set: an `https` source must not be downgraded.
+fn redirect_policy(allow_insecure: bool) -> Policy {
+    if allow_insecure {
+        return Policy::limited(MAX_REDIRECTS);
+    }
+    Policy::custom(|attempt| {
+        if attempt.url().scheme() != "https" {
+            let target = redact_url(attempt.url());
+            attempt.error(format!("refusing redirect to insecure URL {target}"))
+        } else if attempt.previous().len() > MAX_REDIRECTS {
+            attempt.error("too many redirects")
+        } else {
+            attempt.follow()
+        }
+    })
+}
+
+/// `url` without its userinfo, query and fragment.
+fn redact_url(url: &Url) -> String {
+    let mut redacted = url.clone();
+    // Both only fail for URLs that cannot have userinfo in the first place.
+    let _ = redacted.set_username("");
+    let _ = redacted.set_password(None);
+    redacted.set_query(None);
+    redacted.set_fragment(None);
+    redacted.to_string()
+}
+
+/// Best-effort [`redact_url`] for a string that is not a valid URL.
+fn redact_unparsed_url(url: &str) -> String {
+    let url = url.split(['?', '#']).next().unwrap_or_default();
+    match (url.split_once("://"), url.rfind('@')) {
+        (Some((scheme, _)), Some(at)) => format!("{scheme}://{}", &url[at + 1..]),
+        _ => url.to_string(),
+    }
+}
@@ -1,8 +1,57 @@
 //! Configuration validation using JSON Schema and custom rules.
 
-use crate::errors::ConfigProviderResult;
-use release_regent_core::config::{ReleaseRegentConfig, VersioningStrategy};
-use std::collections::HashMap;
+use crate::errors::{ConfigProviderError, ConfigProviderResult};
+use crate::formats::parse_config;
+use release_regent_core::{
+    branch_template::validate_branch_template,
+    commit_filter::CommitFilter,
+    config::{ReleaseRegentConfig, VersioningStrategy},
+    monorepo::PathFilter,
+    secret::describe_toml_error,
+    traits::version_calculator::PackageSpec,
+};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use toml::{Table, Value};
+use toml_edit::{Item, TableLike};
+
+/// Keys read by older releases, with a hint on how to migrate each one.
+///
+/// [`ConfigValidator::validate_keys`] reports these as deprecated rather than
+/// unknown.
+pub const DEPRECATED_KEYS: &[(&str, &str)] = &[
+    ("releases.create_as_draft", "rename it to releases.draft"),
+    (
+        "versioning.prefix",
+        "move it to core.version_prefix, or run `rr migrate`",
+    ),
+    (
+        "repository.main_branch",
+        "move it to core.branches.main, or run `rr migrate`",
+    ),
+    (
+        "versioning.allow_prerelease",
+        "remove it; pre-release versions are always accepted",
+    ),
+    (
+        "releases.tag_signing.key",
+        "store the key in a file or environment variable and set releases.tag_signing.key_file or key_env in the app-level configuration",
+    ),
+    (
+        "repository.release_branch_pattern",
+        "remove it; release branches are always named release/<prefix><version>",
+    ),
+];
+
+/// [`ValidationFinding::rule`] of findings from the structural checks.
+pub const RULE_STRUCTURE: &str = "structure";
+
+/// [`ValidationFinding::rule`] of findings from the Git ref name checks.
+pub const RULE_SEMANTICS: &str = "semantics";
+
+/// [`ValidationFinding::rule`] of unknown and deprecated key findings.
+pub const RULE_KEYS: &str = "keys";
 
 /// Rich configuration validation result — **config-provider layer only**.
 ///
@@ -24,16 +73,61 @@ use std::collections::HashMap;
 /// the config-provider layer needs the richer type for its internal rule pipeline.
 /// `FileConfigProvider::validate_config` converts from this type to the core
 /// `ValidationResult` when crossing the trait boundary.
-#[derive(Debug, Clone)]
+///
+/// Serializes as `is_valid`, `findings` and `metadata`; `errors` and
+/// `warnings` are the messages of the findings and are not repeated.
+#[derive(Debug, Clone, Serialize)]
 pub struct ValidationResult {
     /// Whether the configuration is valid
     pub is_valid: bool,
     /// List of validation errors
+    #[serde(skip)]
     pub errors: Vec<String>,
     /// List of validation warnings
+    #[serde(skip)]
     pub warnings: Vec<String>,
     /// Validation metadata
     pub metadata: HashMap<String, String>,
+    /// Errors and warnings with the key and rule they come from, errors first
+    pub findings: Vec<ValidationFinding>,
+}
+
+/// How serious a [`ValidationFinding`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingSeverity {
+    /// The configuration cannot be used
+    Error,
+    /// The configuration works but is probably not what was intended
+    Warning,
+}
+
+/// A single error or warning reported by validation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ValidationFinding {
+    /// Dotted path of the configuration key the finding is about, e.g.
+    /// `releases.target_branch`; `None` when it is not about a single key
+    pub path: Option<String>,
+    /// Whether the finding is an error or a warning
+    pub severity: FindingSeverity,
+    /// Human-readable description of the problem
+    pub message: String,
+    /// Rule that reported the finding: `structure`, `semantics`, `keys`, or
+    /// the [`ValidationRule::name`] of a custom rule
+    pub rule: Option<String>,
+}
+
+impl ValidationFinding {
+    /// A finding whose key path, if any, is read from a `path: ...` prefix of
+    /// `message`.
+    fn from_message(severity: FindingSeverity, message: String) -> Self {
+        Self {
+            path: message_path(&message).map(str::to_string),
+            severity,
+            message,
+            rule: None,
+        }
+    }
 }
 
 impl ValidationResult {
@@ -45,31 +139,69 @@ impl ValidationResult {
             errors: Vec::new(),
             warnings: Vec::new(),
             metadata: HashMap::new(),
+            findings: Vec::new(),
         }
     }
 
     /// Create a new invalid result with errors
     #[must_use]
     pub fn invalid(errors: Vec<String>) -> Self {
+        Self::from_findings(
+            errors
+                .into_iter()
+                .map(|e| ValidationFinding::from_message(FindingSeverity::Error, e))
+                .collect(),
+        )
+    }
+
+    /// Create a result from `findings`; it is valid when none is an error.
+    ///
+    /// Errors are ordered before warnings; the order within each severity is
+    /// kept.
+    #[must_use]
+    pub fn from_findings(mut findings: Vec<ValidationFinding>) -> Self {
+        findings.sort_by_key(|f| f.severity);
+        let messages = |severity| {
+            findings
+                .iter()
+                .filter(|f| f.severity == severity)
+                .map(|f| f.message.clone())
+                .collect::<Vec<_>>()
+        };
+        let errors = messages(FindingSeverity::Error);
+        let warnings = messages(FindingSeverity::Warning);
         Self {
-            is_valid: false,
+            is_valid: errors.is_empty(),
             errors,
-            warnings: Vec::new(),
+            warnings,
             metadata: HashMap::new(),
+            findings,
         }
     }
 
     /// Add a warning to the result
     #[must_use]
     pub fn with_warning(mut self, warning: String) -> Self {
+        self.findings.push(ValidationFinding::from_message(
+            FindingSeverity::Warning,
+            warning.clone(),
+        ));
         self.warnings.push(warning);
         self
     }
 
     /// Add warnings to the result
     #[must_use]
-    pub fn with_warnings(mut self, warnings: Vec<String>) -> Self {
-        self.warnings.extend(warnings);
+    pub fn with_warnings(self, warnings: Vec<String>) -> Self {
+        warnings.into_iter().fold(self, Self::with_warning)
+    }
+
+    /// Attribute every finding that has no rule yet to `rule`
+    #[must_use]
+    pub fn with_rule(mut self, rule: &str) -> Self {
+        for finding in &mut self.findings {
+            finding.rule.get_or_insert_with(|| rule.to_string());
+        }
         self
     }
 
@@ -85,6 +217,109 @@ impl ValidationResult {
     pub fn has_issues(&self) -> bool {
         !self.errors.is_empty() || !self.warnings.is_empty()
     }
+
+    /// Format the findings as GitHub Actions `::error` and `::warning`
+    /// workflow commands, one per line, so that a CI step can annotate
+    /// `file` inline.
+    ///
+    /// `content` is the TOML the result was produced from; it is used to
+    /// point each finding at the line of its key. Findings whose key cannot
+    /// be found point at the file as a whole.
+    #[must_use]
+    pub fn to_github_annotations(&self, file: &str, content: &str) -> String {
+        let document = toml_edit::Document::parse(content).ok();
+        self.findings
+            .iter()
+            .map(|finding| {
+                let command = match finding.severity {
+                    FindingSeverity::Error => "error",
+                    FindingSeverity::Warning => "warning",
+                };
+                let mut properties = vec![format!("file={}", escape_property(file))];
+                if let Some(line) = document
+                    .as_ref()
+                    .zip(finding.path.as_deref())
+                    .and_then(|(document, path)| key_line(document, path))
+                {
+                    properties.push(format!("line={line}"));
+                }
+                if let Some(rule) = &finding.rule {
+                    properties.push(format!("title={}", escape_property(rule)));
+                }
+                format!(
+                    "::{command} {}::{}\n",
+                    properties.join(","),
+                    escape_data(&finding.message)
+                )
+            })
+            .collect()
+    }
+}
+
+/// The key path at the start of a `path: description` message, e.g.
+/// `versioning.minimum_version`.
+fn message_path(message: &str) -> Option<&str> {
+    let (path, _) = message.split_once(": ")?;
+    let is_path = path.contains(|c: char| c.is_ascii_lowercase())
+        && path
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "_.[]".contains(c));
+    is_path.then_some(path)
+}
+
+/// The 1-based line of the key at the dotted `path` in `document`, e.g.
+/// `versioning.strategy.monorepo.packages[0].tag_prefix`.
+///
+/// When the key itself is missing, the line of its closest present parent
+/// is returned.
+fn key_line(document: &toml_edit::Document<&str>, path: &str) -> Option<usize> {
+    let mut table: &dyn TableLike = document.as_table();
+    let mut offset = None;
+    for segment in path.split('.') {
+        let (name, index) = match segment.split_once('[') {
+            Some((name, index)) => (
+                name,
+                index
+                    .strip_suffix(']')
+                    .and_then(|i| i.parse::<usize>().ok()),
+            ),
+            None => (segment, None),
+        };
+        let Some((key, item)) = table.get_key_value(name) else {
+            break;
+        };
+        offset = key.span().map(|span| span.start).or(offset);
+        let next: Option<&dyn TableLike> = match (item, index) {
+            (Item::ArrayOfTables(tables), Some(index)) => tables.get(index).map(|table| {
+                offset = table.span().map(|span| span.start).or(offset);
+                table as &dyn TableLike
+            }),
+            (Item::Value(toml_edit::Value::Array(values)), Some(index)) => values
+                .get(index)
+                .and_then(toml_edit::Value::as_inline_table)
+                .map(|table| table as &dyn TableLike),
+            (item, None) => item.as_table_like(),
+            _ => None,
+        };
+        let Some(next) = next else {
+            break;
+        };
+        table = next;
+    }
+    offset.map(|offset| document.raw()[..offset].matches('\n').count() + 1)
+}
+
+/// Escape the message of a workflow command.
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a property value of a workflow command.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
 }
 
 /// Configuration validator with JSON Schema support and custom validation rules
@@ -133,34 +368,109 @@ impl ConfigValidator {
 
         // Basic structural validation
         Self::validate_structure(config, &mut errors, &mut warnings);
+        let mut findings = ValidationResult::invalid(std::mem::take(&mut errors))
+            .with_warnings(std::mem::take(&mut warnings))
+            .with_rule(RULE_STRUCTURE)
+            .findings;
+
+        // Semantic validation of values that are structurally valid
+        Self::validate_semantics(config, &mut errors);
+        findings.extend(
+            ValidationResult::invalid(errors)
+                .with_rule(RULE_SEMANTICS)
+                .findings,
+        );
 
         // Custom rules validation
         for rule in &self.custom_rules {
             match rule.validate(config) {
-                Ok(result) => {
-                    errors.extend(result.errors);
-                    warnings.extend(result.warnings);
-                    metadata.extend(result.metadata);
+                Ok(mut result) => {
+                    metadata.extend(std::mem::take(&mut result.metadata));
+                    findings.extend(result.with_rule(rule.name()).findings);
                 }
                 Err(e) => {
-                    errors.push(format!("Custom validation rule failed: {e}"));
+                    findings.extend(
+                        ValidationResult::invalid(vec![format!(
+                            "Custom validation rule failed: {e}"
+                        )])
+                        .with_rule(rule.name())
+                        .findings,
+                    );
                 }
             }
         }
 
         // In strict mode, treat warnings as errors
+        let mut result = ValidationResult::from_findings(findings);
         if self.strict_mode {
-            errors.extend(warnings.clone());
-            warnings.clear();
+            result = ValidationResult::from_findings(
+                result
+                    .findings
+                    .into_iter()
+                    .map(|f| ValidationFinding {
+                        severity: FindingSeverity::Error,
+                        ..f
+                    })
+                    .collect(),
+            );
         }
+        result.metadata = metadata;
+        Ok(result)
+    }
 
-        let is_valid = errors.is_empty();
-        Ok(ValidationResult {
-            is_valid,
-            errors,
-            warnings,
-            metadata,
-        })
+    /// Report keys in TOML configuration `content` that are not read.
+    ///
+    /// Serde ignores keys it does not know, so a misspelt key or one left over
+    /// from an older release silently has no effect. This pass parses
+    /// `content`, serializes the result back, and reports every key of the
+    /// input that did not survive the round trip as a warning naming its
+    /// dotted path, e.g. `releases.draf`. Keys listed in [`DEPRECATED_KEYS`]
+    /// are reported as deprecated, with a migration hint.
+    ///
+    /// In strict mode the warnings are reported as errors.
+    ///
+    /// # Errors
+    /// - `ConfigProviderError::ParseError` — `content` is not a valid configuration
+    /// - `ConfigProviderError::SerializeError` — the parsed configuration could not be serialized
+    #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+    pub fn validate_keys(&self, content: &str) -> ConfigProviderResult<ValidationResult> {
+        let raw: Table = toml::from_str(content).map_err(|e| {
+            ConfigProviderError::parse_error(
+                PathBuf::new(),
+                format!(
+                    "Failed to parse TOML: {}",
+                    describe_toml_error(&e, Some(content))
+                ),
+            )
+        })?;
+        let known = Table::try_from(parse_config(content)?).map_err(|e| {
+            ConfigProviderError::serialize_error_with_source(
+                format!("Failed to serialize to TOML: {e}"),
+                e,
+            )
+        })?;
+
+        let mut paths = Vec::new();
+        collect_unread_keys(&raw, Some(&known), "", &mut paths);
+        let severity = if self.strict_mode {
+            FindingSeverity::Error
+        } else {
+            FindingSeverity::Warning
+        };
+        let findings = paths
+            .into_iter()
+            .map(|path| ValidationFinding {
+                message: match deprecation_hint(&path) {
+                    Some(hint) => format!("Deprecated configuration key '{path}': {hint}"),
+                    None => format!("Unknown configuration key '{path}' is ignored"),
+                },
+                path: Some(path),
+                severity,
+                rule: Some(RULE_KEYS.to_string()),
+            })
+            .collect();
+
+        Ok(ValidationResult::from_findings(findings))
     }
 
     /// Validate basic configuration structure
@@ -190,6 +500,35 @@ impl ConfigValidator {
             VersioningStrategy::Conventional => {
                 // No additional validation needed for conventional commits
             }
+            VersioningStrategy::Monorepo { packages } => {
+                Self::validate_packages(packages, errors);
+            }
+        }
+
+        if let Err(e) = CommitFilter::new(&versioning.exclusions) {
+            errors.push(format!("Invalid commit exclusion rules: {e}"));
+        }
+
+        if let Err(e) = versioning.parsed_minimum_version() {
+            errors.push(format!("versioning.minimum_version: {e}"));
+        }
+
+        if let Err(e) = versioning.parsed_initial_version() {
+            errors.push(format!("versioning.initial_version: {e}"));
+        }
+
+        if versioning
+            .breaking_scopes
+            .iter()
+            .any(|scope| scope.trim().is_empty())
+        {
+            errors.push("versioning.breaking_scopes: scopes must not be empty".to_string());
+        }
+
+        if let Some(template) = &config.release_pr.branch_template {
+            if let Err(e) = validate_branch_template(template, &config.core.version_prefix) {
+                errors.push(format!("release_pr.branch_template: {e}"));
+            }
         }
 
         // Validate webhook configuration
@@ -214,6 +553,195 @@ impl ConfigValidator {
                 }
             }
         }
+
+        // Validate tag signing configuration
+        if let Some(signing) = config.releases.enabled_tag_signing() {
+            if let Some(problem) = signing.key_source_problem() {
+                errors.push(problem.to_string());
+            }
+            if signing.tagger_name.trim().is_empty() || signing.tagger_email.trim().is_empty() {
+                errors.push(
+                    "releases.tag_signing: tagger_name and tagger_email must not be empty"
+                        .to_string(),
+                );
+            }
+        }
+    }
+
+    /// Validate that branch names and tag prefixes can be used in Git refs
+    ///
+    /// Each error names the field path and why the value is invalid. Empty
+    /// values are reported by [`validate_structure`](Self::validate_structure)
+    /// where they are not allowed.
+    fn validate_semantics(config: &ReleaseRegentConfig, errors: &mut Vec<String>) {
+        let main = &config.core.branches.main;
+        if !main.is_empty() {
+            if let Some(problem) = ref_name_problem(main) {
+                errors.push(format!(
+                    "core.branches.main: '{main}' is not a valid branch name: {problem}"
+                ));
+            }
+        }
+
+        if let Some(target) = &config.releases.target_branch {
+            if let Some(problem) = ref_name_problem(target) {
+                errors.push(format!(
+                    "releases.target_branch: '{target}' is not a valid branch name: {problem}"
+                ));
+            }
+        }
+
+        if let Some(problem) = ref_fragment_problem(&config.core.version_prefix) {
+            errors.push(format!(
+                "core.version_prefix: '{}' cannot start a tag name: {problem}",
+                config.core.version_prefix
+            ));
+        }
+
+        if let VersioningStrategy::Monorepo { packages } = &config.versioning.strategy {
+            for (index, package) in packages.iter().enumerate() {
+                let Some(tag_prefix) = &package.tag_prefix else {
+                    continue;
+                };
+                if let Some(problem) = ref_fragment_problem(tag_prefix) {
+                    errors.push(format!(
+                        "versioning.strategy.monorepo.packages[{index}].tag_prefix: \
+                         '{tag_prefix}' cannot start a tag name: {problem}"
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Validate the packages of the monorepo versioning strategy
+    fn validate_packages(packages: &[PackageSpec], errors: &mut Vec<String>) {
+        if packages.is_empty() {
+            errors.push("Monorepo versioning strategy requires at least one package".to_string());
+        }
+
+        let mut names = HashSet::new();
+        for package in packages {
+            if package.name.is_empty() {
+                errors.push("Monorepo package name cannot be empty".to_string());
+            } else if !names.insert(package.name.as_str()) {
+                errors.push(format!(
+                    "Monorepo package '{}' is defined more than once",
+                    package.name
+                ));
+            }
+            if let Err(e) = PathFilter::new(&package.path) {
+                errors.push(format!(
+                    "Monorepo package '{}' has an invalid path: {e}",
+                    package.name
+                ));
+            }
+        }
+    }
+}
+
+/// Characters that Git does not allow anywhere in a ref name.
+const FORBIDDEN_REF_CHARS: &[char] = &['~', '^', ':', '?', '*', '[', '\\'];
+
+/// Why `fragment` cannot appear in a Git ref name, or `None` if it can.
+///
+/// Checks the rules that apply to any part of a ref name; see
+/// [`ref_name_problem`] for the rules that apply to a complete name.
+fn ref_fragment_problem(fragment: &str) -> Option<String> {
+    if fragment.chars().any(char::is_whitespace) {
+        return Some("it contains whitespace".to_string());
+    }
+    if fragment.chars().any(char::is_control) {
+        return Some("it contains a control character".to_string());
+    }
+    if let Some(c) = fragment.chars().find(|c| FORBIDDEN_REF_CHARS.contains(c)) {
+        return Some(format!("Git does not allow '{c}' in ref names"));
+    }
+    if fragment.contains("..") {
+        return Some("Git does not allow '..' in ref names".to_string());
+    }
+    if fragment.contains("@{") {
+        return Some("Git does not allow '@{' in ref names".to_string());
+    }
+    None
+}
+
+/// Why `name` is not a valid Git branch name, or `None` if it is.
+///
+/// Follows the rules of `git check-ref-format --branch`.
+fn ref_name_problem(name: &str) -> Option<String> {
+    if let Some(problem) = ref_fragment_problem(name) {
+        return Some(problem);
+    }
+    if name == "@" {
+        return Some("Git does not allow '@' as a ref name".to_string());
+    }
+    if name.starts_with('-') {
+        return Some("it starts with '-'".to_string());
+    }
+    if name.starts_with('/') || name.ends_with('/') || name.contains("//") {
+        return Some("it has an empty path component".to_string());
+    }
+    if name.ends_with('.') {
+        return Some("it ends with '.'".to_string());
+    }
+    for component in name.split('/') {
+        if component.starts_with('.') {
+            return Some(format!("the component '{component}' starts with '.'"));
+        }
+        if component.ends_with(".lock") {
+            return Some(format!("the component '{component}' ends with '.lock'"));
+        }
+    }
+    None
+}
+
+/// The migration hint for a deprecated key, or `None` if `path` is not
+/// deprecated.
+fn deprecation_hint(path: &str) -> Option<&'static str> {
+    DEPRECATED_KEYS
+        .iter()
+        .find(|(key, _)| *key == path)
+        .map(|(_, hint)| *hint)
+}
+
+/// Collect the dotted paths of keys in `raw` that are missing from `known`.
+///
+/// `known` is `None` inside a section that is itself unknown; such a section
+/// is reported as a whole unless it holds deprecated keys, which are then
+/// reported one by one. Empty sections are never reported. Array elements are
+/// compared by index.
+fn collect_unread_keys(raw: &Table, known: Option<&Table>, prefix: &str, paths: &mut Vec<String>) {
+    for (key, value) in raw {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match (value, known.and_then(|known| known.get(key))) {
+            (Value::Table(table), Some(Value::Table(known))) => {
+                collect_unread_keys(table, Some(known), &path, paths);
+            }
+            (Value::Array(items), Some(Value::Array(known))) => {
+                for (index, item) in items.iter().enumerate() {
+                    if let (Value::Table(table), Some(Value::Table(known))) =
+                        (item, known.get(index))
+                    {
+                        collect_unread_keys(table, Some(known), &format!("{path}[{index}]"), paths);
+                    }
+                }
+            }
+            (_, Some(_)) => {}
+            (Value::Table(table), None) if table.is_empty() => {}
+            (Value::Table(table), None)
+                if deprecation_hint(&path).is_none()
+                    && DEPRECATED_KEYS
+                        .iter()
+                        .any(|(deprecated, _)| deprecated.starts_with(&format!("{path}."))) =>
+            {
+                collect_unread_keys(table, None, &path, paths);
+            }
+            (_, None) => paths.push(path),
+        }
     }
 }
 
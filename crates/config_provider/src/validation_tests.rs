@@ -1,6 +1,7 @@
 //! Unit tests for configuration validation.
 
 use super::*;
+use release_regent_core::config::TagSigningConfig;
 
 #[test]
 fn test_validation_result_creation() {
@@ -57,3 +58,585 @@ fn test_invalid_config_validation() {
     assert!(!result.is_valid);
     assert!(!result.errors.is_empty());
 }
+
+#[test]
+fn test_monorepo_strategy_validation() {
+    use release_regent_core::traits::version_calculator::PackageSpec;
+
+    let validator = ConfigValidator::new();
+    let package = |name: &str, path: &str| PackageSpec {
+        name: name.to_string(),
+        path: path.to_string(),
+        tag_prefix: None,
+        changelog_path: None,
+    };
+
+    let mut config = ReleaseRegentConfig::default();
+    config.versioning.strategy = VersioningStrategy::Monorepo {
+        packages: vec![package("api", "api/**"), package("web", "web/**")],
+    };
+    assert!(validator.validate(&config).unwrap().is_valid);
+
+    config.versioning.strategy = VersioningStrategy::Monorepo { packages: vec![] };
+    let result = validator.validate(&config).unwrap();
+    assert!(!result.is_valid);
+
+    config.versioning.strategy = VersioningStrategy::Monorepo {
+        packages: vec![
+            package("api", "api/**"),
+            package("api", "backend/**"),
+            package("", ""),
+        ],
+    };
+    let result = validator.validate(&config).unwrap();
+    assert!(!result.is_valid);
+    assert!(result
+        .errors
+        .iter()
+        .any(|e| e.contains("'api' is defined more than once")));
+    assert!(result
+        .errors
+        .iter()
+        .any(|e| e.contains("name cannot be empty")));
+    assert!(result.errors.iter().any(|e| e.contains("invalid path")));
+}
+
+#[test]
+fn test_invalid_commit_exclusion_pattern_fails_validation() {
+    let validator = ConfigValidator::new();
+
+    let mut config = ReleaseRegentConfig::default();
+    config.versioning.exclusions.subject_patterns = vec!["(unclosed".to_string()];
+
+    let result = validator.validate(&config).unwrap();
+    assert!(!result.is_valid);
+    assert!(result
+        .errors
+        .iter()
+        .any(|e| e.contains("Invalid commit exclusion rules")));
+}
+
+#[test]
+fn test_valid_config_has_no_semantic_findings() {
+    let mut config = ReleaseRegentConfig::default();
+    config.core.branches.main = "release/main-2.x".to_string();
+    config.core.version_prefix = "app-v".to_string();
+
+    let result = ConfigValidator::new().validate(&config).unwrap();
+
+    assert!(result.is_valid);
+    assert!(!result.has_issues(), "{result:?}");
+}
+
+#[test]
+fn test_empty_version_prefix_is_valid() {
+    let mut config = ReleaseRegentConfig::default();
+    config.core.version_prefix = String::new();
+
+    let result = ConfigValidator::new().validate(&config).unwrap();
+
+    assert!(result.is_valid, "{result:?}");
+}
+
+fn semantic_errors(config: &ReleaseRegentConfig) -> Vec<String> {
+    let result = ConfigValidator::new().validate(config).unwrap();
+    assert!(!result.is_valid);
+    result.errors
+}
+
+#[test]
+fn test_main_branch_with_space_is_rejected_with_field_path() {
+    let mut config = ReleaseRegentConfig::default();
+    config.core.branches.main = "my branch".to_string();
+
+    assert_eq!(
+        semantic_errors(&config),
+        vec!["core.branches.main: 'my branch' is not a valid branch name: it contains whitespace"]
+    );
+}
+
+#[test]
+fn test_release_target_branch_with_space_is_rejected_with_field_path() {
+    let mut config = ReleaseRegentConfig::default();
+    config.releases.target_branch = Some("release next".to_string());
+
+    assert_eq!(
+        semantic_errors(&config),
+        vec![
+            "releases.target_branch: 'release next' is not a valid branch name: it contains whitespace"
+        ]
+    );
+}
+
+#[test]
+fn test_main_branch_violating_ref_rules_is_rejected() {
+    for (name, reason) in [
+        ("feature..x", "'..'"),
+        ("main~1", "'~'"),
+        ("-main", "starts with '-'"),
+        ("main/", "empty path component"),
+        ("team//main", "empty path component"),
+        ("main.", "ends with '.'"),
+        ("team/.hidden", "starts with '.'"),
+        ("main.lock", "ends with '.lock'"),
+        ("main@{1}", "'@{'"),
+        ("@", "'@'"),
+    ] {
+        let mut config = ReleaseRegentConfig::default();
+        config.core.branches.main = name.to_string();
+
+        let errors = semantic_errors(&config);
+
+        assert_eq!(errors.len(), 1, "{name}: {errors:?}");
+        assert!(errors[0].starts_with("core.branches.main: "), "{errors:?}");
+        assert!(errors[0].contains(reason), "{name}: {errors:?}");
+    }
+}
+
+#[test]
+fn test_empty_main_branch_is_reported_once() {
+    let mut config = ReleaseRegentConfig::default();
+    config.core.branches.main = String::new();
+
+    assert_eq!(
+        semantic_errors(&config),
+        vec!["Main branch name cannot be empty"]
+    );
+}
+
+#[test]
+fn test_version_prefix_with_space_is_rejected() {
+    let mut config = ReleaseRegentConfig::default();
+    config.core.version_prefix = "release v".to_string();
+
+    assert_eq!(
+        semantic_errors(&config),
+        vec!["core.version_prefix: 'release v' cannot start a tag name: it contains whitespace"]
+    );
+}
+
+#[test]
+fn test_version_prefix_with_forbidden_character_is_rejected() {
+    let mut config = ReleaseRegentConfig::default();
+    config.core.version_prefix = "v:".to_string();
+
+    assert_eq!(
+        semantic_errors(&config),
+        vec!["core.version_prefix: 'v:' cannot start a tag name: Git does not allow ':' in ref names"]
+    );
+}
+
+#[test]
+fn test_monorepo_tag_prefix_with_invalid_characters_is_rejected() {
+    let mut config = ReleaseRegentConfig::default();
+    config.versioning.strategy = VersioningStrategy::Monorepo {
+        packages: vec![
+            PackageSpec {
+                name: "core".to_string(),
+                path: "crates/core/**".to_string(),
+                tag_prefix: Some("core-v".to_string()),
+                changelog_path: None,
+            },
+            PackageSpec {
+                name: "cli".to_string(),
+                path: "crates/cli/**".to_string(),
+                tag_prefix: Some("cli v*".to_string()),
+                changelog_path: None,
+            },
+        ],
+    };
+
+    assert_eq!(
+        semantic_errors(&config),
+        vec![
+            "versioning.strategy.monorepo.packages[1].tag_prefix: 'cli v*' cannot start a tag name: \
+             it contains whitespace"
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_minimum_version_is_rejected() {
+    let mut config = ReleaseRegentConfig::default();
+    config.versioning.minimum_version = Some("2.0".to_string());
+
+    let errors = semantic_errors(&config);
+
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(
+        errors[0].starts_with("versioning.minimum_version: "),
+        "{errors:?}"
+    );
+
+    config.versioning.minimum_version = Some("2.0.0-rc.1".to_string());
+    assert!(ConfigValidator::new().validate(&config).unwrap().is_valid);
+}
+
+#[test]
+fn test_invalid_initial_version_is_rejected() {
+    let mut config = ReleaseRegentConfig::default();
+    config.versioning.initial_version = Some("one".to_string());
+
+    let errors = semantic_errors(&config);
+
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(
+        errors[0].starts_with("versioning.initial_version: "),
+        "{errors:?}"
+    );
+
+    config.versioning.initial_version = Some("1.0.0".to_string());
+    assert!(ConfigValidator::new().validate(&config).unwrap().is_valid);
+}
+
+#[test]
+fn test_empty_breaking_scope_is_rejected() {
+    let mut config = ReleaseRegentConfig::default();
+    config.versioning.breaking_scopes = vec!["api".to_string(), " ".to_string()];
+
+    let errors = semantic_errors(&config);
+
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(
+        errors[0].starts_with("versioning.breaking_scopes: "),
+        "{errors:?}"
+    );
+
+    config.versioning.breaking_scopes = vec!["api".to_string()];
+    assert!(ConfigValidator::new().validate(&config).unwrap().is_valid);
+}
+
+#[test]
+fn test_illegal_branch_template_is_rejected() {
+    let mut config = ReleaseRegentConfig::default();
+    config.release_pr.branch_template = Some("release:{target_branch}/{version}".to_string());
+
+    let errors = semantic_errors(&config);
+
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(
+        errors[0].starts_with("release_pr.branch_template: "),
+        "{errors:?}"
+    );
+
+    config.release_pr.branch_template = Some("release/{target_branch}/{version}".to_string());
+    assert!(ConfigValidator::new().validate(&config).unwrap().is_valid);
+}
+
+#[test]
+fn test_tag_signing_enabled_without_key_is_rejected() {
+    let mut config = ReleaseRegentConfig::default();
+    config.releases.tag_signing = Some(TagSigningConfig::default());
+
+    let errors = semantic_errors(&config);
+
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(
+        errors[0].starts_with("releases.tag_signing.key_file: "),
+        "{errors:?}"
+    );
+
+    config.releases.tag_signing = Some(TagSigningConfig {
+        key_env: Some("TAG_SIGNING_KEY".to_string()),
+        ..TagSigningConfig::default()
+    });
+    assert!(ConfigValidator::new().validate(&config).unwrap().is_valid);
+}
+
+#[test]
+fn test_disabled_tag_signing_does_not_require_a_key() {
+    let mut config = ReleaseRegentConfig::default();
+    config.releases.tag_signing = Some(TagSigningConfig {
+        enabled: false,
+        ..TagSigningConfig::default()
+    });
+
+    assert!(ConfigValidator::new().validate(&config).unwrap().is_valid);
+}
+
+// ── validate_keys ─────────────────────────────────────────────────────────────
+
+const KNOWN_GOOD_CONFIG: &str = r#"
+config_version = 2
+
+[core]
+version_prefix = "v"
+
+[core.branches]
+main = "main"
+
+[releases]
+draft = true
+target_branch = "develop"
+
+[releases.tag_signing]
+format = "ssh"
+key_env = "TAG_SIGNING_KEY"
+
+[[versioning.strategy.monorepo.packages]]
+name = "core"
+path = "crates/core/**"
+
+[notifications.webhook]
+url = "https://example.com/hook"
+headers = { Authorization = "Bearer token" }
+"#;
+
+#[test]
+fn test_validate_keys_accepts_known_good_config() {
+    let result = ConfigValidator::new()
+        .validate_keys(KNOWN_GOOD_CONFIG)
+        .unwrap();
+
+    assert!(result.is_valid);
+    assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+}
+
+#[test]
+fn test_validate_keys_warns_about_unknown_key_with_its_path() {
+    let content = r#"
+[releases]
+draf = true
+
+[[versioning.strategy.monorepo.packages]]
+name = "core"
+path = "crates/core/**"
+tag_prefx = "core-v"
+
+[changelg]
+include_authors = true
+"#;
+
+    let result = ConfigValidator::new().validate_keys(content).unwrap();
+
+    assert!(result.is_valid);
+    assert_eq!(
+        result.warnings,
+        vec![
+            "Unknown configuration key 'changelg' is ignored",
+            "Unknown configuration key 'releases.draf' is ignored",
+            "Unknown configuration key 'versioning.strategy.monorepo.packages[0].tag_prefx' is ignored",
+        ]
+    );
+}
+
+#[test]
+fn test_validate_keys_flags_deprecated_keys_with_migration_hint() {
+    let content = r#"
+[releases]
+create_as_draft = true
+
+[repository]
+main_branch = "trunk"
+"#;
+
+    let result = ConfigValidator::new().validate_keys(content).unwrap();
+
+    assert_eq!(
+        result.warnings,
+        vec![
+            "Deprecated configuration key 'releases.create_as_draft': rename it to releases.draft",
+            "Deprecated configuration key 'repository.main_branch': move it to \
+             core.branches.main, or run `rr migrate`",
+        ]
+    );
+}
+
+#[test]
+fn test_validate_keys_ignores_empty_sections() {
+    let content = r#"
+[notifications.webhook]
+url = "https://example.com/hook"
+headers = {}
+"#;
+
+    let result = ConfigValidator::new().validate_keys(content).unwrap();
+
+    assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+}
+
+#[test]
+fn test_validate_keys_reports_errors_in_strict_mode() {
+    let result = ConfigValidator::strict()
+        .validate_keys("[releases]\ndraf = true\n")
+        .unwrap();
+
+    assert!(!result.is_valid);
+    assert_eq!(
+        result.errors,
+        vec!["Unknown configuration key 'releases.draf' is ignored"]
+    );
+}
+
+// ── findings and GitHub annotations ──────────────────────────────────────────
+
+const CONFIG_WITH_FINDINGS: &str = r#"[core]
+version_prefix = "v"
+
+[releases]
+target_branch = "bad branch"
+draf = true
+
+[[versioning.strategy.monorepo.packages]]
+name = "core"
+path = "crates/core/**"
+tag_prefix = "core v"
+"#;
+
+/// Validate `CONFIG_WITH_FINDINGS` the way a file is validated: values first,
+/// then unknown keys.
+fn findings_result() -> ValidationResult {
+    let validator = ConfigValidator::new();
+    let config = parse_config(CONFIG_WITH_FINDINGS).unwrap();
+    let mut findings = validator.validate(&config).unwrap().findings;
+    findings.extend(
+        validator
+            .validate_keys(CONFIG_WITH_FINDINGS)
+            .unwrap()
+            .findings,
+    );
+    ValidationResult::from_findings(findings)
+}
+
+#[test]
+fn test_findings_carry_path_severity_and_rule() {
+    let result = findings_result();
+
+    assert!(!result.is_valid);
+    assert_eq!(
+        result.findings,
+        vec![
+            ValidationFinding {
+                path: Some("releases.target_branch".to_string()),
+                severity: FindingSeverity::Error,
+                message: "releases.target_branch: 'bad branch' is not a valid branch name: \
+                          it contains whitespace"
+                    .to_string(),
+                rule: Some(RULE_SEMANTICS.to_string()),
+            },
+            ValidationFinding {
+                path: Some("versioning.strategy.monorepo.packages[0].tag_prefix".to_string()),
+                severity: FindingSeverity::Error,
+                message: "versioning.strategy.monorepo.packages[0].tag_prefix: 'core v' cannot \
+                          start a tag name: it contains whitespace"
+                    .to_string(),
+                rule: Some(RULE_SEMANTICS.to_string()),
+            },
+            ValidationFinding {
+                path: Some("releases.draf".to_string()),
+                severity: FindingSeverity::Warning,
+                message: "Unknown configuration key 'releases.draf' is ignored".to_string(),
+                rule: Some(RULE_KEYS.to_string()),
+            },
+        ]
+    );
+    assert_eq!(result.errors.len(), 2);
+    assert_eq!(
+        result.warnings,
+        vec!["Unknown configuration key 'releases.draf' is ignored"]
+    );
+}
+
+#[test]
+fn test_result_with_mixed_severities_serializes_findings() {
+    let result = ValidationResult::invalid(vec!["core.version_prefix: bad".to_string()])
+        .with_warning("Release PR title template is empty".to_string())
+        .with_rule("custom");
+
+    let json = serde_json::to_value(&result).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "is_valid": false,
+            "metadata": {},
+            "findings": [
+                {
+                    "path": "core.version_prefix",
+                    "severity": "error",
+                    "message": "core.version_prefix: bad",
+                    "rule": "custom"
+                },
+                {
+                    "path": null,
+                    "severity": "warning",
+                    "message": "Release PR title template is empty",
+                    "rule": "custom"
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_custom_rule_findings_are_attributed_to_the_rule() {
+    let mut config = ReleaseRegentConfig::default();
+    config.release_pr.title_template = String::new();
+
+    let result = ConfigValidator::new()
+        .with_rule(Box::new(GitHubRepositoryRule))
+        .validate(&config)
+        .unwrap();
+
+    let finding = result
+        .findings
+        .iter()
+        .find(|f| f.message == "Release PR title template is empty")
+        .expect("the custom rule should report the empty title template");
+    assert_eq!(finding.severity, FindingSeverity::Warning);
+    assert_eq!(finding.rule.as_deref(), Some("github_repository"));
+}
+
+#[test]
+fn test_strict_mode_reports_warning_findings_as_errors() {
+    let result = ConfigValidator::strict()
+        .validate_keys("[releases]\ndraf = true\n")
+        .unwrap();
+
+    assert_eq!(result.findings.len(), 1);
+    assert_eq!(result.findings[0].severity, FindingSeverity::Error);
+    assert_eq!(result.findings[0].path.as_deref(), Some("releases.draf"));
+}
+
+#[test]
+fn test_github_annotations_point_at_the_line_of_each_key() {
+    let annotations =
+        findings_result().to_github_annotations("release-regent.toml", CONFIG_WITH_FINDINGS);
+
+    assert_eq!(
+        annotations.lines().collect::<Vec<_>>(),
+        vec![
+            "::error file=release-regent.toml,line=5,title=semantics::releases.target_branch: \
+             'bad branch' is not a valid branch name: it contains whitespace",
+            "::error file=release-regent.toml,line=11,title=semantics::\
+             versioning.strategy.monorepo.packages[0].tag_prefix: 'core v' cannot start a tag \
+             name: it contains whitespace",
+            "::warning file=release-regent.toml,line=6,title=keys::Unknown configuration key \
+             'releases.draf' is ignored",
+        ]
+    );
+}
+
+#[test]
+fn test_github_annotations_escape_workflow_command_syntax() {
+    let result = ValidationResult::invalid(vec!["100% wrong\nsecond line".to_string()]);
+
+    let annotations = result.to_github_annotations("configs/a,b.toml", "");
+
+    assert_eq!(
+        annotations,
+        "::error file=configs/a%2Cb.toml::100%25 wrong%0Asecond line\n"
+    );
+}
+
+#[test]
+fn test_github_annotations_without_key_point_at_the_file() {
+    let result = ValidationResult::valid().with_warning("releases.draft: odd".to_string());
+
+    let annotations = result.to_github_annotations("release-regent.toml", "[core]\n");
+
+    assert_eq!(
+        annotations,
+        "::warning file=release-regent.toml::releases.draft: odd\n"
+    );
+}
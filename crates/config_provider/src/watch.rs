@@ -0,0 +1,235 @@
+//! Hot reload of configuration files.
+//!
+//! [`FileConfigurationProvider::watch`](crate::FileConfigurationProvider::watch)
+//! watches the directories the provider reads from and reports every valid
+//! edit to a callback as a [`ConfigChange`]. Edits that do not parse or fail
+//! validation are logged and dropped, so the last configuration the callback
+//! received stays the current one. Watching stops when the returned
+//! [`ConfigWatchGuard`] is dropped.
+
+use crate::errors::{ConfigProviderError, ConfigProviderResult};
+use crate::formats::{is_toml_path, parse_config};
+use crate::validation::ConfigValidator;
+use notify_debouncer_mini::{
+    new_debouncer,
+    notify::{RecommendedWatcher, RecursiveMode},
+    DebounceEventResult, Debouncer,
+};
+use release_regent_core::config::ReleaseRegentConfig;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, error, info, warn};
+
+#[cfg(test)]
+#[path = "watch_tests.rs"]
+mod tests;
+
+/// Time to wait for a burst of file system events to settle before reading a
+/// file, so that a save that truncates and then writes is seen once.
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// File names that hold the global configuration, as searched by the provider.
+pub(crate) const GLOBAL_CONFIG_FILE_NAMES: [&str; 3] =
+    ["release-regent.toml", "release_regent.toml", "config.toml"];
+
+/// Which configuration a changed file provides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigScope {
+    /// The global configuration.
+    Global,
+    /// The configuration of one repository, from an `{owner}-{repo}.toml` file.
+    ///
+    /// Owners and repository names may both contain hyphens, so the file name
+    /// does not say where one ends; `name` is the file stem, `{owner}-{repo}`,
+    /// unsplit.
+    Repository {
+        /// The file stem, `{owner}-{repo}`
+        name: String,
+    },
+    /// The file set with `set_repository_config_path`, used for every
+    /// repository without a file of its own.
+    RepositoryDefault,
+}
+
+/// A configuration file that changed and passed validation.
+#[derive(Debug, Clone)]
+pub struct ConfigChange {
+    /// Which configuration changed
+    pub scope: ConfigScope,
+    /// The file that changed
+    pub path: PathBuf,
+    /// The new configuration, with overrides applied
+    pub config: ReleaseRegentConfig,
+}
+
+/// Keeps a configuration watch alive. Dropping the guard, or calling
+/// [`stop`](Self::stop), stops watching.
+pub struct ConfigWatchGuard {
+    _debouncer: Debouncer<RecommendedWatcher>,
+}
+
+impl ConfigWatchGuard {
+    /// Stop watching for changes.
+    pub fn stop(self) {
+        info!("Stopped watching configuration files");
+    }
+}
+
+impl std::fmt::Debug for ConfigWatchGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConfigWatchGuard").finish_non_exhaustive()
+    }
+}
+
+/// Everything needed to turn a changed path into a [`ConfigChange`], captured
+/// from the provider when the watch starts.
+pub(crate) struct WatchContext {
+    pub(crate) directories: Vec<PathBuf>,
+    pub(crate) global_config_path: Option<PathBuf>,
+    pub(crate) repository_config_path: Option<PathBuf>,
+    pub(crate) validator: Arc<ConfigValidator>,
+    pub(crate) overrides: HashMap<String, String>,
+}
+
+impl WatchContext {
+    /// Start watching and report valid changes to `callback`.
+    ///
+    /// # Errors
+    /// - `ConfigProviderError::FileSystem` — the watcher could not be
+    ///   created or a directory could not be watched
+    #[allow(clippy::result_large_err)] // ConfigProviderError is intentionally large
+    pub(crate) fn start<F>(self, callback: F) -> ConfigProviderResult<ConfigWatchGuard>
+    where
+        F: Fn(ConfigChange) + Send + 'static,
+    {
+        let directories = self.directories.clone();
+        let mut debouncer =
+            new_debouncer(
+                DEBOUNCE_TIMEOUT,
+                move |result: DebounceEventResult| match result {
+                    Ok(events) => {
+                        for event in events {
+                            if let Some(change) = self.reload(&event.path) {
+                                callback(change);
+                            }
+                        }
+                    }
+                    Err(e) => error!("Configuration watcher error: {e}"),
+                },
+            )
+            .map_err(|e| {
+                ConfigProviderError::file_system_with_source("Failed to create file watcher", e)
+            })?;
+
+        for directory in &directories {
+            debouncer
+                .watcher()
+                .watch(directory, RecursiveMode::NonRecursive)
+                .map_err(|e| {
+                    ConfigProviderError::file_system_with_source(
+                        format!("Failed to watch {}", directory.display()),
+                        e,
+                    )
+                })?;
+            info!("Watching configuration directory: {:?}", directory);
+        }
+
+        Ok(ConfigWatchGuard {
+            _debouncer: debouncer,
+        })
+    }
+
+    /// Load and validate `path`, returning `None` for files that are not
+    /// configuration, have been removed, or are invalid.
+    fn reload(&self, path: &Path) -> Option<ConfigChange> {
+        let scope = self.scope_of(path)?;
+        if !path.is_file() {
+            debug!("Configuration file {:?} was removed; ignoring", path);
+            return None;
+        }
+
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) => {
+                error!("Failed to read changed configuration {:?}: {e}", path);
+                return None;
+            }
+        };
+        let mut config = match parse_config(&content) {
+            Ok(config) => config,
+            Err(e) => {
+                error!(
+                    "Ignoring invalid edit to {:?}; keeping the previous configuration: {e}",
+                    path
+                );
+                return None;
+            }
+        };
+        crate::file_provider::apply_overrides(&self.overrides, &mut config);
+
+        match self.validator.validate(&config) {
+            Ok(result) if result.is_valid => {
+                info!("Reloaded configuration from {:?}", path);
+                Some(ConfigChange {
+                    scope,
+                    path: path.to_path_buf(),
+                    config,
+                })
+            }
+            Ok(result) => {
+                error!(
+                    "Ignoring invalid edit to {:?}; keeping the previous configuration: {:?}",
+                    path, result.errors
+                );
+                None
+            }
+            Err(e) => {
+                error!("Failed to validate changed configuration {:?}: {e}", path);
+                None
+            }
+        }
+    }
+
+    fn scope_of(&self, path: &Path) -> Option<ConfigScope> {
+        if !is_toml_path(path) {
+            return None;
+        }
+        if let Some(global) = &self.global_config_path {
+            if same_file(path, global) {
+                return Some(ConfigScope::Global);
+            }
+        }
+        if let Some(repository) = &self.repository_config_path {
+            if same_file(path, repository) {
+                return Some(ConfigScope::RepositoryDefault);
+            }
+        }
+
+        let file_name = path.file_name()?.to_str()?;
+        if GLOBAL_CONFIG_FILE_NAMES.contains(&file_name) {
+            return Some(ConfigScope::Global);
+        }
+        let stem = path.file_stem()?.to_str()?;
+        if stem.contains('-') && !stem.starts_with('-') && !stem.ends_with('-') {
+            Some(ConfigScope::Repository {
+                name: stem.to_string(),
+            })
+        } else {
+            warn!(
+                "Ignoring change to unrecognised configuration file {:?}",
+                path
+            );
+            None
+        }
+    }
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    a == b
+        || matches!(
+            (a.canonicalize(), b.canonicalize()),
+            (Ok(a), Ok(b)) if a == b
+        )
+}
@@ -0,0 +1,212 @@
+//! Unit tests for configuration hot reload.
+
+use super::*;
+use crate::FileConfigurationProvider;
+use std::sync::mpsc;
+use tempfile::TempDir;
+
+const CONFIG: &str = r#"
+[core]
+version_prefix = "v"
+
+[core.branches]
+main = "main"
+"#;
+
+/// Wait for a change matching `predicate`, skipping intermediate events.
+fn wait_for(
+    rx: &mpsc::Receiver<ConfigChange>,
+    predicate: impl Fn(&ConfigChange) -> bool,
+) -> Option<ConfigChange> {
+    let deadline = std::time::Instant::now() + Duration::from_secs(10);
+    while let Some(remaining) = deadline.checked_duration_since(std::time::Instant::now()) {
+        match rx.recv_timeout(remaining) {
+            Ok(change) if predicate(&change) => return Some(change),
+            Ok(_) => {}
+            Err(_) => return None,
+        }
+    }
+    None
+}
+
+async fn watched_dir() -> (TempDir, ConfigWatchGuard, mpsc::Receiver<ConfigChange>) {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("release-regent.toml"), CONFIG).unwrap();
+    let provider = FileConfigurationProvider::new(dir.path()).await.unwrap();
+    let (tx, rx) = mpsc::channel();
+    let guard = provider
+        .watch(move |change| {
+            let _ = tx.send(change);
+        })
+        .unwrap();
+    (dir, guard, rx)
+}
+
+fn context(global: Option<&str>, repository: Option<&str>) -> WatchContext {
+    WatchContext {
+        directories: Vec::new(),
+        global_config_path: global.map(PathBuf::from),
+        repository_config_path: repository.map(PathBuf::from),
+        validator: Arc::new(ConfigValidator::new()),
+        overrides: HashMap::new(),
+    }
+}
+
+#[test]
+fn test_scope_of_global_file_names() {
+    let ctx = context(None, None);
+
+    for name in GLOBAL_CONFIG_FILE_NAMES {
+        assert_eq!(
+            ctx.scope_of(&PathBuf::from("/cfg").join(name)),
+            Some(ConfigScope::Global)
+        );
+    }
+}
+
+#[test]
+fn test_scope_of_repository_file() {
+    let ctx = context(None, None);
+
+    assert_eq!(
+        ctx.scope_of(Path::new("/cfg/acme-widgets.toml")),
+        Some(ConfigScope::Repository {
+            name: "acme-widgets".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_scope_of_repository_file_with_hyphenated_owner() {
+    let ctx = context(None, None);
+
+    assert_eq!(
+        ctx.scope_of(Path::new("/cfg/my-org-widgets.toml")),
+        Some(ConfigScope::Repository {
+            name: "my-org-widgets".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_scope_of_explicit_paths() {
+    let ctx = context(Some("/etc/rr/global.toml"), Some("/etc/rr/repo.toml"));
+
+    assert_eq!(
+        ctx.scope_of(Path::new("/etc/rr/global.toml")),
+        Some(ConfigScope::Global)
+    );
+    assert_eq!(
+        ctx.scope_of(Path::new("/etc/rr/repo.toml")),
+        Some(ConfigScope::RepositoryDefault)
+    );
+}
+
+#[test]
+fn test_scope_of_ignores_other_files() {
+    let ctx = context(None, None);
+
+    assert_eq!(ctx.scope_of(Path::new("/cfg/notes.txt")), None);
+    assert_eq!(ctx.scope_of(Path::new("/cfg/settings.toml")), None);
+    assert_eq!(
+        ctx.scope_of(Path::new("/cfg/release-regent.toml.swp")),
+        None
+    );
+}
+
+#[test]
+fn test_reload_rejects_invalid_content() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("release-regent.toml");
+    std::fs::write(&path, "[core\nversion_prefix = ").unwrap();
+
+    assert!(context(None, None).reload(&path).is_none());
+}
+
+#[test]
+fn test_reload_rejects_config_failing_validation() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("release-regent.toml");
+    std::fs::write(&path, CONFIG.replace("main = \"main\"", "main = \"\"")).unwrap();
+
+    assert!(context(None, None).reload(&path).is_none());
+}
+
+#[test]
+fn test_reload_applies_overrides() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("release-regent.toml");
+    std::fs::write(&path, CONFIG).unwrap();
+    let mut ctx = context(None, None);
+    ctx.overrides
+        .insert("branches.main_branch".to_string(), "trunk".to_string());
+
+    let change = ctx.reload(&path).expect("valid config should reload");
+
+    assert_eq!(change.scope, ConfigScope::Global);
+    assert_eq!(change.config.core.branches.main, "trunk");
+}
+
+#[tokio::test]
+async fn test_watch_reports_edited_global_config() {
+    let (dir, _guard, rx) = watched_dir().await;
+
+    std::fs::write(
+        dir.path().join("release-regent.toml"),
+        CONFIG.replace("\"v\"", "\"release-\""),
+    )
+    .unwrap();
+
+    let change = wait_for(&rx, |c| c.config.core.version_prefix == "release-")
+        .expect("callback should fire with the new config");
+    assert_eq!(change.scope, ConfigScope::Global);
+}
+
+#[tokio::test]
+async fn test_watch_reports_new_repository_config() {
+    let (dir, _guard, rx) = watched_dir().await;
+
+    std::fs::write(
+        dir.path().join("acme-widgets.toml"),
+        CONFIG.replace("\"v\"", "\"w-\""),
+    )
+    .unwrap();
+
+    let change = wait_for(&rx, |c| c.config.core.version_prefix == "w-")
+        .expect("callback should fire for the repository config");
+    assert_eq!(
+        change.scope,
+        ConfigScope::Repository {
+            name: "acme-widgets".to_string(),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_watch_skips_invalid_edit_and_keeps_watching() {
+    let (dir, _guard, rx) = watched_dir().await;
+    let path = dir.path().join("release-regent.toml");
+
+    std::fs::write(&path, "[core\nnot toml").unwrap();
+    assert!(
+        rx.recv_timeout(Duration::from_secs(1)).is_err(),
+        "an invalid edit must not reach the callback"
+    );
+
+    std::fs::write(&path, CONFIG.replace("\"v\"", "\"fixed-\"")).unwrap();
+    assert!(wait_for(&rx, |c| c.config.core.version_prefix == "fixed-").is_some());
+}
+
+#[tokio::test]
+async fn test_dropping_guard_stops_watching() {
+    let (dir, guard, rx) = watched_dir().await;
+
+    guard.stop();
+    std::fs::write(
+        dir.path().join("release-regent.toml"),
+        CONFIG.replace("\"v\"", "\"late-\""),
+    )
+    .unwrap();
+
+    assert!(rx.recv_timeout(Duration::from_secs(1)).is_err());
+}
@@ -0,0 +1,405 @@
+//! Release branch names rendered from a configurable template
+//!
+//! Every release PR lives on a branch named by a template such as
+//! `release/{version_tag}` (the default) or
+//! `release-regent/{target_branch}/{version}`. The following placeholders are
+//! supported:
+//!
+//! | Placeholder       | Value                                                |
+//! |-------------------|------------------------------------------------------|
+//! | `{owner}`         | Repository owner, e.g. `acme`                        |
+//! | `{repo}`          | Repository name, e.g. `widgets`                      |
+//! | `{target_branch}` | Branch the release PR merges into, e.g. `main`       |
+//! | `{version}`       | Semantic version without prefix, e.g. `1.2.3`        |
+//! | `{version_tag}`   | Version with the configured prefix, e.g. `v1.2.3`    |
+//!
+//! A template that carries the version must end with `{version}` or
+//! `{version_tag}`. Everything before it is the *release branch prefix*: open
+//! release PRs are found by searching for branches with that prefix, and their
+//! version is read back from the rest of the branch name.
+//!
+//! A template without a version, such as `release/next` or
+//! `release-regent/{target_branch}`, names a single release branch that is
+//! reused from release to release. Its release PR is found by that exact name
+//! and its version is read from the PR instead; see
+//! [`version_from_release_pr`].
+//!
+//! Rendered names are checked against the rules of `git check-ref-format`,
+//! so a template can never produce a branch GitHub would reject.
+
+use crate::{
+    release_automator::find_version_token,
+    release_orchestrator::extract_version_sentinel,
+    versioning::{SemanticVersion, VersionCalculator},
+    CoreError, CoreResult,
+};
+
+#[cfg(test)]
+#[path = "branch_template_tests.rs"]
+mod tests;
+
+/// The branch template used when none is configured.
+pub const DEFAULT_BRANCH_TEMPLATE: &str = "release/{version_tag}";
+
+/// Values substituted into a branch template.
+#[derive(Debug, Clone, Copy)]
+pub struct BranchNameVars<'a> {
+    /// Repository owner
+    pub owner: &'a str,
+    /// Repository name
+    pub repo: &'a str,
+    /// Branch the release PR merges into
+    pub target_branch: &'a str,
+    /// Version prefix used by `{version_tag}`, e.g. `"v"`
+    pub version_prefix: &'a str,
+}
+
+/// A placeholder in a branch template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Placeholder {
+    Owner,
+    Repo,
+    TargetBranch,
+    Version,
+    VersionTag,
+}
+
+/// A piece of a parsed branch template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Segment<'t> {
+    Literal(&'t str),
+    Placeholder(Placeholder),
+}
+
+/// Render the branch name for `version`.
+///
+/// # Errors
+/// - `CoreError::InvalidInput` (`branch_template`) — the template is malformed;
+///   see [`validate_branch_template`]
+/// - `CoreError::InvalidInput` (`branch`) — the rendered name is not a legal
+///   Git branch name
+///
+/// # Examples
+///
+/// ```
+/// use release_regent_core::branch_template::{render_branch_name, BranchNameVars};
+/// use release_regent_core::versioning::VersionCalculator;
+///
+/// let vars = BranchNameVars {
+///     owner: "acme",
+///     repo: "widgets",
+///     target_branch: "main",
+///     version_prefix: "v",
+/// };
+/// let version = VersionCalculator::parse_version("1.2.3").unwrap();
+///
+/// let name = render_branch_name("release-regent/{target_branch}/{version_tag}", &vars, &version);
+/// assert_eq!(name.unwrap(), "release-regent/main/v1.2.3");
+/// ```
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+pub fn render_branch_name(
+    template: &str,
+    vars: &BranchNameVars<'_>,
+    version: &SemanticVersion,
+) -> CoreResult<String> {
+    // A versioned template ends with the version placeholder, so the name is
+    // the prefix followed by the version; a fixed name is the prefix itself.
+    let prefix = release_branch_prefix(template, vars)?;
+    let name = if has_version(&parse_template(template)?) {
+        format!("{prefix}{version}")
+    } else {
+        prefix
+    };
+    validate_branch_name(&name)?;
+    Ok(name)
+}
+
+/// The part of every release branch name that precedes the version, e.g.
+/// `release/v` for the default template and a `v` version prefix. For a
+/// template without a version this is the whole branch name.
+///
+/// # Errors
+/// - `CoreError::InvalidInput` (`branch_template`) — the template is malformed;
+///   see [`validate_branch_template`]
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+pub fn release_branch_prefix(template: &str, vars: &BranchNameVars<'_>) -> CoreResult<String> {
+    let segments = parse_template(template)?;
+    let mut prefix = String::new();
+    for segment in &segments {
+        match segment {
+            Segment::Literal(text) => prefix.push_str(text),
+            Segment::Placeholder(Placeholder::Owner) => prefix.push_str(vars.owner),
+            Segment::Placeholder(Placeholder::Repo) => prefix.push_str(vars.repo),
+            Segment::Placeholder(Placeholder::TargetBranch) => {
+                prefix.push_str(vars.target_branch);
+            }
+            Segment::Placeholder(Placeholder::VersionTag) => prefix.push_str(vars.version_prefix),
+            Segment::Placeholder(Placeholder::Version) => {}
+        }
+    }
+    Ok(prefix)
+}
+
+/// Returns `true` if `branch` is a release branch rendered from `template`:
+/// it starts with the release branch prefix or, for a template without a
+/// version, is exactly the rendered name.
+///
+/// # Errors
+/// - `CoreError::InvalidInput` (`branch_template`) — the template is malformed
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+pub fn is_release_branch(
+    template: &str,
+    vars: &BranchNameVars<'_>,
+    branch: &str,
+) -> CoreResult<bool> {
+    let prefix = release_branch_prefix(template, vars)?;
+    Ok(if has_version(&parse_template(template)?) {
+        branch.starts_with(&prefix)
+    } else {
+        branch == prefix
+    })
+}
+
+/// Read the version back from a release branch name rendered from
+/// `template`.
+///
+/// # Errors
+/// - `CoreError::InvalidInput` (`branch_template`) — the template is malformed
+/// - `CoreError::InvalidInput` (`branch`) — `branch` does not start with the
+///   release branch prefix, or the template puts no version in the name
+/// - `CoreError::Versioning` — the rest of the name is not a semantic version
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+pub fn version_from_branch_name(
+    template: &str,
+    vars: &BranchNameVars<'_>,
+    branch: &str,
+) -> CoreResult<SemanticVersion> {
+    if !has_version(&parse_template(template)?) {
+        return Err(CoreError::invalid_input(
+            "branch",
+            format!("Branch template '{template}' puts no version in the branch name"),
+        ));
+    }
+    let prefix = release_branch_prefix(template, vars)?;
+    let version = branch.strip_prefix(&prefix).ok_or_else(|| {
+        CoreError::invalid_input(
+            "branch",
+            format!(
+                "Branch '{branch}' does not match the release branch pattern '{prefix}<version>'"
+            ),
+        )
+    })?;
+    VersionCalculator::parse_version(version)
+}
+
+/// Read the version of the release PR on `branch` with `title` and `body`.
+///
+/// A versioned template carries the version in the branch name. For a
+/// template without a version, `branch` must be exactly the rendered name and
+/// the version is read from the `version` sentinel of the PR body (see
+/// [`extract_version_sentinel`]), or else from the first semantic version in
+/// `title`.
+///
+/// # Errors
+/// - `CoreError::InvalidInput` (`branch_template`) — the template is malformed
+/// - `CoreError::InvalidInput` (`branch`) — `branch` is not a release branch
+///   of `template`
+/// - `CoreError::InvalidInput` (`version`) — neither the body nor the title
+///   carries a version
+/// - `CoreError::Versioning` — the branch name carries an invalid version
+///
+/// # Examples
+///
+/// ```
+/// use release_regent_core::branch_template::{version_from_release_pr, BranchNameVars};
+///
+/// let vars = BranchNameVars {
+///     owner: "acme",
+///     repo: "widgets",
+///     target_branch: "main",
+///     version_prefix: "v",
+/// };
+/// let version =
+///     version_from_release_pr("release/next", &vars, "release/next", "chore(release): v1.4.0", "");
+/// assert_eq!(version.unwrap().to_string(), "1.4.0");
+/// ```
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+pub fn version_from_release_pr(
+    template: &str,
+    vars: &BranchNameVars<'_>,
+    branch: &str,
+    title: &str,
+    body: &str,
+) -> CoreResult<SemanticVersion> {
+    if has_version(&parse_template(template)?) {
+        return version_from_branch_name(template, vars, branch);
+    }
+    if !is_release_branch(template, vars, branch)? {
+        return Err(CoreError::invalid_input(
+            "branch",
+            format!(
+                "Branch '{branch}' is not the release branch '{}'",
+                release_branch_prefix(template, vars)?
+            ),
+        ));
+    }
+    extract_version_sentinel(body)
+        .and_then(|v| VersionCalculator::parse_version(&v).ok())
+        .or_else(|| find_version_token(title, false))
+        .ok_or_else(|| {
+            CoreError::invalid_input(
+                "version",
+                format!("Release PR on '{branch}' carries no version in its body or title"),
+            )
+        })
+}
+
+/// Check that `template` only uses known placeholders, uses at most one
+/// version placeholder and only at its end, and renders a legal branch name
+/// for sample values.
+///
+/// # Errors
+/// - `CoreError::InvalidInput` (`branch_template`) — the template is malformed
+/// - `CoreError::InvalidInput` (`branch`) — the template cannot produce a
+///   legal Git branch name
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+pub fn validate_branch_template(template: &str, version_prefix: &str) -> CoreResult<()> {
+    let sample = BranchNameVars {
+        owner: "octo-org",
+        repo: "widgets",
+        target_branch: "main",
+        version_prefix,
+    };
+    let version = SemanticVersion {
+        major: 1,
+        minor: 2,
+        patch: 3,
+        prerelease: None,
+        build: None,
+    };
+    render_branch_name(template, &sample, &version).map(drop)
+}
+
+/// Check that `name` is a legal Git branch name.
+///
+/// Applies the rules of `git check-ref-format --branch`: no empty or
+/// `.`-leading path components, no component ending in `.lock`, no `..`,
+/// `@{` or `//`, no leading `-` or `/`, no trailing `/` or `.`, and none of
+/// the characters space, `~`, `^`, `:`, `?`, `*`, `[`, `\` or ASCII control
+/// characters. `@` on its own is not a branch name either.
+///
+/// # Errors
+/// - `CoreError::InvalidInput` (`branch`) — `name` breaks one of the rules
+///
+/// # Examples
+///
+/// ```
+/// use release_regent_core::branch_template::validate_branch_name;
+///
+/// assert!(validate_branch_name("release/v1.2.3").is_ok());
+/// assert!(validate_branch_name("release/v1.2..3").is_err());
+/// assert!(validate_branch_name("release/next:1").is_err());
+/// ```
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+pub fn validate_branch_name(name: &str) -> CoreResult<()> {
+    let reject = |reason: &str| {
+        Err(CoreError::invalid_input(
+            "branch",
+            format!("'{name}' is not a valid branch name: {reason}"),
+        ))
+    };
+
+    if name.is_empty() {
+        return reject("it is empty");
+    }
+    if name == "@" {
+        return reject("'@' is reserved");
+    }
+    if name.starts_with('-') {
+        return reject("it starts with '-'");
+    }
+    if name.starts_with('/') || name.ends_with('/') {
+        return reject("it starts or ends with '/'");
+    }
+    if name.ends_with('.') {
+        return reject("it ends with '.'");
+    }
+    if let Some(c) = name.chars().find(|&c| {
+        c.is_ascii_control() || matches!(c, ' ' | '~' | '^' | ':' | '?' | '*' | '[' | '\\')
+    }) {
+        return reject(&format!("it contains the character {c:?}"));
+    }
+    for sequence in ["..", "@{", "//"] {
+        if name.contains(sequence) {
+            return reject(&format!("it contains '{sequence}'"));
+        }
+    }
+    for component in name.split('/') {
+        if component.starts_with('.') {
+            return reject("a path component starts with '.'");
+        }
+        if component.ends_with(".lock") {
+            return reject("a path component ends with '.lock'");
+        }
+    }
+    Ok(())
+}
+
+/// Split `template` into literals and placeholders, rejecting unknown or
+/// unterminated placeholders and templates with more than one version
+/// placeholder or one that is not at the end.
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+fn parse_template(template: &str) -> CoreResult<Vec<Segment<'_>>> {
+    let invalid = |message: String| Err(CoreError::invalid_input("branch_template", message));
+
+    let mut segments = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        if start > 0 {
+            segments.push(Segment::Literal(&rest[..start]));
+        }
+        let Some(end) = rest[start..].find('}') else {
+            return invalid(format!("unterminated placeholder in '{template}'"));
+        };
+        let placeholder = match &rest[start + 1..start + end] {
+            "owner" => Placeholder::Owner,
+            "repo" => Placeholder::Repo,
+            "target_branch" => Placeholder::TargetBranch,
+            "version" => Placeholder::Version,
+            "version_tag" => Placeholder::VersionTag,
+            other => return invalid(format!("unknown placeholder '{{{other}}}' in '{template}'")),
+        };
+        segments.push(Segment::Placeholder(placeholder));
+        rest = &rest[start + end + 1..];
+    }
+    if !rest.is_empty() {
+        segments.push(Segment::Literal(rest));
+    }
+
+    let versions = segments
+        .iter()
+        .filter(|s| {
+            matches!(
+                s,
+                Segment::Placeholder(Placeholder::Version | Placeholder::VersionTag)
+            )
+        })
+        .count();
+    if versions > 1 || (versions == 1 && !has_version(&segments)) {
+        return invalid(format!(
+            "'{template}' may use {{version}} or {{version_tag}} only once, at its end; \
+             release PRs are recognised by the version at the end of their branch name"
+        ));
+    }
+    Ok(segments)
+}
+
+/// Whether the parsed template ends with a version placeholder.
+fn has_version(segments: &[Segment<'_>]) -> bool {
+    matches!(
+        segments.last(),
+        Some(Segment::Placeholder(
+            Placeholder::Version | Placeholder::VersionTag
+        ))
+    )
+}
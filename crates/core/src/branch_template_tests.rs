@@ -0,0 +1,287 @@
+use super::*;
+
+fn vars() -> BranchNameVars<'static> {
+    BranchNameVars {
+        owner: "acme",
+        repo: "widgets",
+        target_branch: "main",
+        version_prefix: "v",
+    }
+}
+
+fn version(s: &str) -> SemanticVersion {
+    VersionCalculator::parse_version(s).unwrap()
+}
+
+fn assert_invalid_input(result: CoreResult<impl std::fmt::Debug>, expected_field: &str) {
+    match result {
+        Err(CoreError::InvalidInput { field, .. }) => assert_eq!(field, expected_field),
+        other => panic!("expected InvalidInput for '{expected_field}', got {other:?}"),
+    }
+}
+
+// ── render_branch_name ───────────────────────────────────────────────────────
+
+#[test]
+fn test_render_default_template() {
+    let name = render_branch_name(DEFAULT_BRANCH_TEMPLATE, &vars(), &version("1.2.3")).unwrap();
+
+    assert_eq!(name, "release/v1.2.3");
+}
+
+#[test]
+fn test_render_expands_every_placeholder() {
+    let name = render_branch_name(
+        "bots/{owner}-{repo}/{target_branch}/{version}",
+        &vars(),
+        &version("2.0.0-rc.1"),
+    )
+    .unwrap();
+
+    assert_eq!(name, "bots/acme-widgets/main/2.0.0-rc.1");
+}
+
+#[test]
+fn test_render_version_tag_with_empty_prefix() {
+    let vars = BranchNameVars {
+        version_prefix: "",
+        ..vars()
+    };
+
+    let name = render_branch_name("release/{version_tag}", &vars, &version("1.0.0")).unwrap();
+
+    assert_eq!(name, "release/1.0.0");
+}
+
+#[test]
+fn test_render_rejects_target_branch_that_makes_an_illegal_name() {
+    let vars = BranchNameVars {
+        target_branch: "main..old",
+        ..vars()
+    };
+
+    let result = render_branch_name(
+        "release-regent/{target_branch}/{version}",
+        &vars,
+        &version("1.0.0"),
+    );
+
+    assert_invalid_input(result, "branch");
+}
+
+// ── template parsing ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_unknown_placeholder_is_rejected() {
+    let result = render_branch_name("release/{branch}/{version}", &vars(), &version("1.0.0"));
+
+    assert_invalid_input(result, "branch_template");
+}
+
+#[test]
+fn test_unterminated_placeholder_is_rejected() {
+    assert_invalid_input(
+        release_branch_prefix("release/{version", &vars()),
+        "branch_template",
+    );
+}
+
+#[test]
+fn test_template_without_version_renders_a_fixed_name() {
+    for version in ["1.0.0", "2.3.4"] {
+        assert_eq!(
+            render_branch_name("release/next", &vars(), &self::version(version)).unwrap(),
+            "release/next"
+        );
+    }
+    assert_eq!(
+        render_branch_name("release-regent/{target_branch}", &vars(), &version("1.0.0")).unwrap(),
+        "release-regent/main"
+    );
+    assert!(validate_branch_template("release-regent/{target_branch}", "v").is_ok());
+}
+
+#[test]
+fn test_template_with_version_not_at_end_is_rejected() {
+    assert_invalid_input(
+        release_branch_prefix("release/{version}/next", &vars()),
+        "branch_template",
+    );
+}
+
+#[test]
+fn test_template_with_two_versions_is_rejected() {
+    assert_invalid_input(
+        release_branch_prefix("release/{version}/{version_tag}", &vars()),
+        "branch_template",
+    );
+}
+
+// ── release_branch_prefix / version_from_branch_name ─────────────────────────
+
+#[test]
+fn test_prefix_includes_version_prefix_only_for_version_tag() {
+    assert_eq!(
+        release_branch_prefix("release/{version_tag}", &vars()).unwrap(),
+        "release/v"
+    );
+    assert_eq!(
+        release_branch_prefix("release/{target_branch}/{version}", &vars()).unwrap(),
+        "release/main/"
+    );
+}
+
+#[test]
+fn test_version_round_trips_through_branch_name() {
+    let template = "release-regent/{target_branch}/{version_tag}";
+    let original = version("3.4.5-beta.2");
+
+    let name = render_branch_name(template, &vars(), &original).unwrap();
+    let parsed = version_from_branch_name(template, &vars(), &name).unwrap();
+
+    assert_eq!(parsed, original);
+}
+
+#[test]
+fn test_version_from_branch_with_other_target_is_rejected() {
+    let result = version_from_branch_name(
+        "release-regent/{target_branch}/{version}",
+        &vars(),
+        "release-regent/develop/1.0.0",
+    );
+
+    assert_invalid_input(result, "branch");
+}
+
+#[test]
+fn test_fixed_release_branch_is_matched_exactly() {
+    assert!(is_release_branch("release/next", &vars(), "release/next").unwrap());
+    assert!(!is_release_branch("release/next", &vars(), "release/next-2").unwrap());
+    assert!(is_release_branch(DEFAULT_BRANCH_TEMPLATE, &vars(), "release/v1.2.3").unwrap());
+}
+
+#[test]
+fn test_version_from_branch_name_without_version_is_rejected() {
+    assert_invalid_input(
+        version_from_branch_name("release/next", &vars(), "release/next"),
+        "branch",
+    );
+}
+
+// ── version_from_release_pr ──────────────────────────────────────────────────
+
+#[test]
+fn test_version_from_release_pr_prefers_branch_name_for_versioned_templates() {
+    let parsed = version_from_release_pr(
+        DEFAULT_BRANCH_TEMPLATE,
+        &vars(),
+        "release/v1.2.3",
+        "chore(release): v9.9.9",
+        "<!-- release-regent: version=8.8.8 -->",
+    )
+    .unwrap();
+
+    assert_eq!(parsed, version("1.2.3"));
+}
+
+#[test]
+fn test_version_from_release_pr_reads_body_sentinel_then_title() {
+    let template = "release-regent/{target_branch}";
+    let from_body = version_from_release_pr(
+        template,
+        &vars(),
+        "release-regent/main",
+        "chore(release): v1.0.0",
+        "## Changelog\n<!-- release-regent: version=1.1.0 -->",
+    )
+    .unwrap();
+    let from_title = version_from_release_pr(
+        template,
+        &vars(),
+        "release-regent/main",
+        "chore(release): v1.0.0",
+        "",
+    )
+    .unwrap();
+
+    assert_eq!(from_body, version("1.1.0"));
+    assert_eq!(from_title, version("1.0.0"));
+}
+
+#[test]
+fn test_version_from_release_pr_rejects_other_branches_and_missing_versions() {
+    assert_invalid_input(
+        version_from_release_pr("release/next", &vars(), "release/other", "v1.0.0", ""),
+        "branch",
+    );
+    assert_invalid_input(
+        version_from_release_pr("release/next", &vars(), "release/next", "Release", ""),
+        "version",
+    );
+}
+
+// ── validate_branch_template ─────────────────────────────────────────────────
+
+#[test]
+fn test_validate_branch_template_accepts_default() {
+    assert!(validate_branch_template(DEFAULT_BRANCH_TEMPLATE, "v").is_ok());
+}
+
+#[test]
+fn test_validate_branch_template_rejects_illegal_literal() {
+    assert_invalid_input(
+        validate_branch_template("release next/{version}", "v"),
+        "branch",
+    );
+}
+
+#[test]
+fn test_validate_branch_template_rejects_illegal_version_prefix() {
+    assert_invalid_input(
+        validate_branch_template("release/{version_tag}", "~"),
+        "branch",
+    );
+}
+
+// ── validate_branch_name ─────────────────────────────────────────────────────
+
+#[test]
+fn test_validate_branch_name_accepts_common_names() {
+    for name in [
+        "release/v1.2.3",
+        "release-regent/main/1.0.0-rc.1",
+        "v1",
+        "a/b/c_d",
+    ] {
+        assert!(validate_branch_name(name).is_ok(), "{name} should be valid");
+    }
+}
+
+#[test]
+fn test_validate_branch_name_rejects_illegal_names() {
+    for name in [
+        "",
+        "@",
+        "-release/v1",
+        "/release/v1",
+        "release/v1/",
+        "release/v1.",
+        "release//v1",
+        "release/v1..2",
+        "release/v1@{2}",
+        "release/.hidden",
+        "release.lock/v1",
+        "release/v1.lock",
+        "release v1",
+        "release/v1~1",
+        "release/v1^",
+        "release:v1",
+        "release/v1?",
+        "release/*",
+        "release/[v1]",
+        "release\\v1",
+        "release/v1\t",
+    ] {
+        assert_invalid_input(validate_branch_name(name), "branch");
+    }
+}
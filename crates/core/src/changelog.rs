@@ -3,10 +3,19 @@
 //! This module handles generating formatted markdown changelogs from conventional commits
 //! with proper categorization and formatting.
 
+use crate::traits::{
+    changelog_renderer::{ChangelogContext, ChangelogRenderer},
+    git_operations::GitUser,
+    github_operations::GitHubOperations,
+};
 use crate::versioning::ConventionalCommit;
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, Utc,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use tracing::debug;
+use std::{collections::HashMap, fmt::Write as _};
+use tracing::{debug, warn};
 
 // git-cliff-core integration
 use git_cliff_core::{
@@ -14,6 +23,12 @@ use git_cliff_core::{
     config::Config as GitCliffConfig, release::Release as GitCliffRelease,
 };
 
+/// Changelog text used when a release has no commits to list.
+const NO_CHANGES: &str = "No changes in this release.";
+
+/// First line of the changelog of a repository's first release.
+pub const INITIAL_RELEASE_HEADER: &str = "Initial release.";
+
 /// Strategy for changelog generation.
 ///
 /// Controls how [`ChangelogGenerator`] produces formatted release notes:
@@ -74,6 +89,56 @@ fn default_commit_template() -> String {
     "- {description} [{sha}]".to_string()
 }
 
+fn default_other_section_title() -> String {
+    OTHER_SECTION_TITLE.to_string()
+}
+
+fn default_date_format() -> String {
+    DEFAULT_DATE_FORMAT.to_string()
+}
+
+/// Default format of the release date in changelog version headers; see
+/// [`ChangelogConfig::date_format`].
+pub const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Default title of the section that collects commit types without a section
+/// of their own; see [`ChangelogConfig::other_section_title`].
+pub const OTHER_SECTION_TITLE: &str = "Other Changes";
+
+/// Prefix put in front of the description of `revert` commits.
+pub const REVERT_PREFIX: &str = "Revert: ";
+
+/// A changelog section: the commit types it collects and its heading.
+///
+/// Sections are rendered in the order they appear in
+/// [`ChangelogConfig::sections`].
+///
+/// Example TOML:
+/// ```toml
+/// [[changelog.sections]]
+/// types = ["feat"]
+/// title = "New Features"
+///
+/// [[changelog.sections]]
+/// types = ["fix", "perf"]
+/// title = "Fixes"
+///
+/// [[changelog.sections]]
+/// types = ["chore"]
+/// hidden = true
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SectionConfig {
+    /// Commit types (`feat`, `fix`, …) routed to this section.
+    pub types: Vec<String>,
+    /// Section heading.  Not required for hidden sections.
+    #[serde(default)]
+    pub title: String,
+    /// Leave commits of these types out of the changelog entirely.
+    #[serde(default)]
+    pub hidden: bool,
+}
+
 /// Configuration for changelog generation.
 ///
 /// The `strategy` field selects the rendering back-end; the remaining fields
@@ -89,26 +154,73 @@ pub struct ChangelogConfig {
     #[serde(default)]
     pub strategy: ChangelogStrategy,
     /// Whether to include commit authors
+    ///
+    /// Authors are rendered through the `{author}` placeholder in
+    /// `commit_template`, or appended as ` by {author}` when the template has
+    /// no placeholder. An author with a known GitHub login is rendered as an
+    /// `@login` mention; any other author is rendered by display name.
     #[serde(default = "default_true")]
     pub include_authors: bool,
+    /// Commit author email → GitHub login, used for `@mentions`.
+    ///
+    /// Emails are matched case-insensitively. Entries take precedence over
+    /// logins reported by the commit data.
+    #[serde(default)]
+    pub author_map: HashMap<String, String>,
+    /// Whether to ask GitHub for the login of authors that are not in
+    /// `author_map`; see [`ChangelogGenerator::resolve_author_logins`].
+    #[serde(default)]
+    pub resolve_author_logins: bool,
     /// Whether to include commit SHAs
     #[serde(default = "default_true")]
     pub include_shas: bool,
     /// Whether to include links to commits/PRs
     #[serde(default = "default_true")]
     pub include_links: bool,
+    /// Sections to render, in order, and the commit types each collects.
+    ///
+    /// Empty (the default) uses the built-in sections.  Otherwise commit types
+    /// not listed in any section are collected under `other_section_title`,
+    /// rendered last.
+    #[serde(default)]
+    pub sections: Vec<SectionConfig>,
+    /// Title of the section, rendered last, that collects commits whose type
+    /// has no section of its own.
+    #[serde(default = "default_other_section_title")]
+    pub other_section_title: String,
     /// Template for changelog sections
     #[serde(default = "default_section_template")]
     pub section_template: String,
     /// Template for individual commit entries
+    ///
+    /// Supports `{description}`, `{sha}`, `{author}` and `{body}`. `{body}`
+    /// expands to the commit body on the following lines, indented so that it
+    /// stays part of the list item, or to nothing when the commit has no body.
     #[serde(default = "default_commit_template")]
     pub commit_template: String,
+    /// Upper-case the first letter of each commit description.
+    #[serde(default)]
+    pub capitalize_descriptions: bool,
+    /// Remove trailing periods from commit descriptions.
+    #[serde(default)]
+    pub strip_trailing_periods: bool,
+    /// Replace each run of whitespace in commit descriptions with a single
+    /// space, and trim the ends.
+    #[serde(default)]
+    pub collapse_whitespace: bool,
     /// Git repository path for git-cliff-core (optional)
     #[serde(default)]
     pub repository_path: Option<String>,
     /// Remote repository URL for link generation
     #[serde(default)]
     pub remote_url: Option<String>,
+    /// strftime-style format of the release date in version headers such as
+    /// `## [1.2.3] - 2024-06-01`.
+    ///
+    /// `%B %-d, %Y` renders `June 1, 2024`; `%Y-%m-%dT%H:%M:%SZ` adds the
+    /// time in UTC. Defaults to [`DEFAULT_DATE_FORMAT`].
+    #[serde(default = "default_date_format")]
+    pub date_format: String,
 }
 
 impl Default for ChangelogConfig {
@@ -116,12 +228,20 @@ impl Default for ChangelogConfig {
         Self {
             strategy: ChangelogStrategy::default(),
             include_authors: true,
+            author_map: HashMap::new(),
+            resolve_author_logins: false,
             include_shas: true,
             include_links: true,
+            sections: Vec::new(),
+            other_section_title: default_other_section_title(),
             section_template: "### {title}\n\n{entries}\n".to_string(),
             commit_template: "- {description} [{sha}]".to_string(),
+            capitalize_descriptions: false,
+            strip_trailing_periods: false,
+            collapse_whitespace: false,
             repository_path: None,
             remote_url: None,
+            date_format: default_date_format(),
         }
     }
 }
@@ -136,21 +256,33 @@ impl Default for ChangelogConfig {
 /// All paths return `CoreResult<String>` so callers handle errors uniformly.
 pub struct ChangelogGenerator {
     config: ChangelogConfig,
+    initial_release: bool,
 }
 
 impl ChangelogGenerator {
     /// Create a new changelog generator with default configuration
     #[must_use]
     pub fn new() -> Self {
-        Self {
-            config: ChangelogConfig::default(),
-        }
+        Self::with_config(ChangelogConfig::default())
     }
 
     /// Create a new changelog generator with custom configuration
     #[must_use]
     pub fn with_config(config: ChangelogConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            initial_release: false,
+        }
+    }
+
+    /// Render the changelog of a repository's first release.
+    ///
+    /// The changelog then starts with [`INITIAL_RELEASE_HEADER`], and a release
+    /// without commits is described by that header alone.
+    #[must_use]
+    pub fn with_initial_release(mut self, initial_release: bool) -> Self {
+        self.initial_release = initial_release;
+        self
     }
 
     /// Generate a changelog from conventional commits.
@@ -172,21 +304,139 @@ impl ChangelogGenerator {
     ) -> crate::errors::CoreResult<String> {
         debug!("Generating changelog from {} commits", commits.len());
 
-        if commits.is_empty() {
-            return Ok("No changes in this release.".to_string());
+        let changelog = if commits.is_empty() {
+            NO_CHANGES.to_string()
+        } else {
+            match &self.config.strategy {
+                ChangelogStrategy::Internal => self.generate_with_template(commits),
+                ChangelogStrategy::GitCliff => self.generate_with_git_cliff(commits)?,
+                ChangelogStrategy::External {
+                    command,
+                    env_vars,
+                    timeout_ms,
+                } => self.generate_with_external(command, env_vars, *timeout_ms, commits)?,
+            }
+        };
+
+        if !self.initial_release {
+            return Ok(changelog);
+        }
+        if changelog == NO_CHANGES {
+            return Ok(INITIAL_RELEASE_HEADER.to_string());
         }
+        Ok(format!("{INITIAL_RELEASE_HEADER}\n\n{changelog}"))
+    }
 
-        match &self.config.strategy {
-            ChangelogStrategy::Internal => Ok(self.generate_with_template(commits)),
-            ChangelogStrategy::GitCliff => self.generate_with_git_cliff(commits),
-            ChangelogStrategy::External {
-                command,
-                env_vars,
-                timeout_ms,
-            } => self.generate_with_external(command, env_vars, *timeout_ms, commits),
+    /// Format `date` for a version header with [`ChangelogConfig::date_format`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::errors::CoreError::ChangelogGeneration`] when the
+    /// format is empty or not a valid strftime format.
+    // CoreError is intentionally large; this is the established pattern throughout the codebase.
+    #[allow(clippy::result_large_err)]
+    pub fn format_date(&self, date: DateTime<Utc>) -> crate::errors::CoreResult<String> {
+        format_changelog_date(&self.config.date_format, date)
+    }
+
+    /// Render the header of a version's changelog section, e.g.
+    /// `## [1.2.3] - 2024-06-01`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::errors::CoreError::ChangelogGeneration`] when
+    /// [`ChangelogConfig::date_format`] is invalid.
+    // CoreError is intentionally large; this is the established pattern throughout the codebase.
+    #[allow(clippy::result_large_err)]
+    pub fn render_version_header(
+        &self,
+        version: &str,
+        date: DateTime<Utc>,
+    ) -> crate::errors::CoreResult<String> {
+        Ok(format!("## [{version}] - {}", self.format_date(date)?))
+    }
+
+    /// Fill in the GitHub login of commit authors that have none.
+    ///
+    /// Does nothing unless both `include_authors` and `resolve_author_logins`
+    /// are enabled. Authors already in `author_map` are skipped. For the rest,
+    /// the login GitHub associates with the commit is fetched once per email
+    /// address; the cache lives for this call only, so each changelog
+    /// generation sees current data. Lookup failures are logged and leave the
+    /// author unresolved, which renders it by display name.
+    pub async fn resolve_author_logins<G>(
+        &self,
+        github: &G,
+        owner: &str,
+        repo: &str,
+        commits: &mut [ConventionalCommit],
+    ) where
+        G: GitHubOperations + ?Sized,
+    {
+        if !self.config.include_authors || !self.config.resolve_author_logins {
+            return;
+        }
+
+        let mut cache: HashMap<String, Option<String>> = HashMap::new();
+        for commit in commits.iter_mut() {
+            let Some(author) = commit.author.as_mut() else {
+                continue;
+            };
+            if author.login.is_some()
+                || author.email.is_empty()
+                || self.mapped_login(&author.email).is_some()
+            {
+                continue;
+            }
+
+            let key = author.email.to_lowercase();
+            if !cache.contains_key(&key) {
+                let login = match github.get_commit(owner, repo, &commit.sha).await {
+                    Ok(found) => found.author.login,
+                    Err(e) => {
+                        warn!(
+                            sha = %commit.sha,
+                            error = %e,
+                            "Failed to look up commit author login"
+                        );
+                        None
+                    }
+                };
+                cache.insert(key.clone(), login);
+            }
+            author.login.clone_from(&cache[&key]);
         }
     }
 
+    /// Render a commit author for the changelog.
+    ///
+    /// Returns `@login` when the author's email is in `author_map` or the
+    /// commit carries a login, otherwise the display name without an `@`.
+    /// Returns `None` when the author has neither.
+    fn author_mention(&self, author: &GitUser) -> Option<String> {
+        let login = self
+            .mapped_login(&author.email)
+            .or(author.login.as_deref())
+            .filter(|login| !login.is_empty());
+        match login {
+            Some(login) => Some(format!("@{}", login.trim_start_matches('@'))),
+            None if !author.name.is_empty() => Some(author.name.clone()),
+            None => None,
+        }
+    }
+
+    /// Look up `email` in `author_map`, ignoring case.
+    fn mapped_login(&self, email: &str) -> Option<&str> {
+        if email.is_empty() {
+            return None;
+        }
+        self.config
+            .author_map
+            .iter()
+            .find(|(mapped, _)| mapped.eq_ignore_ascii_case(email))
+            .map(|(_, login)| login.as_str())
+    }
+
     /// Delegate changelog generation to an external subprocess.
     ///
     /// Commits are written to the child's stdin as `{sha} {message}\n` lines.
@@ -298,7 +548,7 @@ impl ChangelogGenerator {
 
         let trimmed = raw.trim().to_string();
         if trimmed.is_empty() {
-            Ok("No changes in this release.".to_string())
+            Ok(NO_CHANGES.to_string())
         } else {
             Ok(trimmed)
         }
@@ -316,7 +566,7 @@ impl ChangelogGenerator {
             .map(|commit| Self::convert_to_git_cliff_commit(commit))
             .collect();
 
-        let git_cliff_config = Self::create_git_cliff_config()?;
+        let git_cliff_config = Self::create_git_cliff_config(&self.config.other_section_title)?;
 
         let release = GitCliffRelease {
             version: Some("Unreleased".to_string()),
@@ -354,7 +604,7 @@ impl ChangelogGenerator {
             // All commits were filtered (e.g. only merge commits with filter_unconventional=true).
             // Return the same sentinel the public API uses for an empty commit list so callers
             // get a meaningful message rather than a blank PR body.
-            Ok("No changes in this release.".to_string())
+            Ok(NO_CHANGES.to_string())
         } else {
             Ok(trimmed)
         }
@@ -362,6 +612,10 @@ impl ChangelogGenerator {
 
     /// Generate changelog using the built-in template renderer.
     fn generate_with_template(&self, commits: &[ConventionalCommit]) -> String {
+        if !self.config.sections.is_empty() {
+            return self.generate_with_configured_sections(commits);
+        }
+
         let sections = Self::organize_commits_by_type(commits);
         let mut changelog = String::new();
 
@@ -386,18 +640,66 @@ impl ChangelogGenerator {
             }
         }
 
-        // Add any other commit types not in the standard list
-        for (commit_type, commits) in &sections {
-            if !section_order.iter().any(|(t, _)| t == commit_type) {
-                let title = Self::format_commit_type_title(commit_type);
-                let section_content = self.generate_section(&title, commits);
-                changelog.push_str(&section_content);
+        // Collect every other commit type into a single trailing section so
+        // that no change is dropped.
+        let mut other: Vec<&ConventionalCommit> = sections
+            .iter()
+            .filter(|(commit_type, _)| !section_order.iter().any(|(t, _)| t == *commit_type))
+            .flat_map(|(_, commits)| commits.iter().copied())
+            .collect();
+        if !other.is_empty() {
+            Self::sort_section_commits(&mut other);
+            changelog.push_str(&self.generate_section(&self.config.other_section_title, &other));
+        }
+
+        changelog.trim_end().to_string()
+    }
+
+    /// Render the sections listed in [`ChangelogConfig::sections`], in order.
+    ///
+    /// Each commit goes to the first section listing its type.  Types that no
+    /// section lists are collected under
+    /// [`ChangelogConfig::other_section_title`] at the end.
+    fn generate_with_configured_sections(&self, commits: &[ConventionalCommit]) -> String {
+        let mut changelog = String::new();
+
+        for (index, section) in self.config.sections.iter().enumerate() {
+            if section.hidden {
+                continue;
             }
+            let mut routed: Vec<&ConventionalCommit> = commits
+                .iter()
+                .filter(|c| self.section_index(&c.commit_type) == Some(index))
+                .collect();
+            if routed.is_empty() {
+                continue;
+            }
+            Self::sort_section_commits(&mut routed);
+            changelog.push_str(&self.generate_section(&section.title, &routed));
+        }
+
+        let mut other: Vec<&ConventionalCommit> = commits
+            .iter()
+            .filter(|c| self.section_index(&c.commit_type).is_none())
+            .collect();
+        if !other.is_empty() {
+            Self::sort_section_commits(&mut other);
+            changelog.push_str(&self.generate_section(&self.config.other_section_title, &other));
         }
 
         changelog.trim_end().to_string()
     }
 
+    /// Index of the first configured section that lists `commit_type`.
+    fn section_index(&self, commit_type: &str) -> Option<usize> {
+        self.config.sections.iter().position(|section| {
+            section
+                .types
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(commit_type))
+        })
+    }
+
     /// Convert a [`ConventionalCommit`] to a git-cliff-core `Commit`.
     fn convert_to_git_cliff_commit(commit: &ConventionalCommit) -> GitCliffCommit<'_> {
         GitCliffCommit::new(commit.sha.clone(), commit.message.clone())
@@ -405,13 +707,17 @@ impl ChangelogGenerator {
 
     /// Create git-cliff-core configuration.
     ///
+    /// Conventional commits of a type without a group of its own are grouped
+    /// under `other_title`.
+    ///
     /// # Errors
     ///
     /// Returns [`crate::errors::CoreError::ChangelogGeneration`] when the TOML template
     /// cannot be parsed by git-cliff-core.
     // CoreError is intentionally large; this is the established pattern throughout the codebase.
     #[allow(clippy::result_large_err)]
-    fn create_git_cliff_config() -> crate::errors::CoreResult<GitCliffConfig> {
+    fn create_git_cliff_config(other_title: &str) -> crate::errors::CoreResult<GitCliffConfig> {
+        let other_group = toml::Value::String(other_title.to_string());
         let config_toml = r#"
 [changelog]
 body = """
@@ -445,6 +751,7 @@ commit_parsers = [
     { message = "^build", group = "Build System" },
     { message = "^ci", group = "Continuous Integration" },
     { message = "^chore", group = "Chores" },
+    { message = ".*", group = OTHER_GROUP },
 ]
 link_parsers = []
 protect_breaking_commits = false
@@ -456,9 +763,10 @@ sort_commits = "newest"
 use_branch_tags = false
 include_paths = []
 exclude_paths = []
-"#;
+"#
+        .replace("OTHER_GROUP", &other_group.to_string());
 
-        let config: GitCliffConfig = toml::from_str(config_toml).map_err(|e| {
+        let config: GitCliffConfig = toml::from_str(&config_toml).map_err(|e| {
             crate::errors::CoreError::changelog_generation(format!("Config parsing error: {e}"))
         })?;
 
@@ -478,21 +786,25 @@ exclude_paths = []
             entry.push(commit);
         }
 
-        // Sort commits within each section by scope, then by description
         for commits in sections.values_mut() {
-            commits.sort_by(|a, b| match (&a.scope, &b.scope) {
-                (Some(a_scope), Some(b_scope)) => a_scope
-                    .cmp(b_scope)
-                    .then_with(|| a.description.cmp(&b.description)),
-                (Some(_), None) => std::cmp::Ordering::Less,
-                (None, Some(_)) => std::cmp::Ordering::Greater,
-                (None, None) => a.description.cmp(&b.description),
-            });
+            Self::sort_section_commits(commits);
         }
 
         sections
     }
 
+    /// Sort commits within a section by scope, then by description
+    fn sort_section_commits(commits: &mut [&ConventionalCommit]) {
+        commits.sort_by(|a, b| match (&a.scope, &b.scope) {
+            (Some(a_scope), Some(b_scope)) => a_scope
+                .cmp(b_scope)
+                .then_with(|| a.description.cmp(&b.description)),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => a.description.cmp(&b.description),
+        });
+    }
+
     /// Generate a section of the changelog
     fn generate_section(&self, title: &str, commits: &[&ConventionalCommit]) -> String {
         let mut entries = String::new();
@@ -510,15 +822,48 @@ exclude_paths = []
             + "\n\n"
     }
 
+    /// Apply the description normalizations enabled in [`ChangelogConfig`].
+    ///
+    /// Whitespace is collapsed first, then trailing periods are stripped, then
+    /// the first letter is capitalized. The commit itself keeps its raw
+    /// description.
+    #[must_use]
+    pub fn normalize_description(&self, description: &str) -> String {
+        let mut normalized = if self.config.collapse_whitespace {
+            description.split_whitespace().collect::<Vec<_>>().join(" ")
+        } else {
+            description.to_string()
+        };
+
+        if self.config.strip_trailing_periods {
+            let kept = normalized.trim_end().trim_end_matches('.').trim_end().len();
+            normalized.truncate(kept);
+        }
+
+        if self.config.capitalize_descriptions {
+            let mut chars = normalized.chars();
+            if let Some(first) = chars.next() {
+                normalized = first.to_uppercase().chain(chars).collect();
+            }
+        }
+
+        normalized
+    }
+
     /// Format a single commit entry
     fn format_commit_entry(&self, commit: &ConventionalCommit) -> String {
-        let mut description = commit.description.clone();
+        let mut description = self.normalize_description(&commit.description);
 
         // Add scope if present
         if let Some(scope) = &commit.scope {
             description = format!("**{scope}**: {description}");
         }
 
+        // Mark reverts, which may share a section with other changes
+        if commit.commit_type.eq_ignore_ascii_case("revert") {
+            description = format!("{REVERT_PREFIX}{description}");
+        }
+
         // Add breaking change indicator
         if commit.breaking_change {
             description = format!("⚠️ BREAKING: {description}");
@@ -527,7 +872,8 @@ exclude_paths = []
         let mut entry = self
             .config
             .commit_template
-            .replace("{description}", &description);
+            .replace("{description}", &description)
+            .replace("{body}", &Self::indented_body(commit));
 
         if self.config.include_shas {
             entry = entry.replace("{sha}", &commit.sha);
@@ -536,31 +882,46 @@ exclude_paths = []
             entry = entry.replace("[{sha}]", "");
         }
 
+        let mention = commit
+            .author
+            .as_ref()
+            .filter(|_| self.config.include_authors)
+            .and_then(|author| self.author_mention(author));
+        match mention {
+            Some(mention) if entry.contains("{author}") => {
+                entry = entry.replace("{author}", &mention);
+            }
+            Some(mention) => {
+                entry.push_str(" by ");
+                entry.push_str(&mention);
+            }
+            None => {
+                entry = entry.replace(" by {author}", "");
+                entry = entry.replace("{author}", "");
+            }
+        }
+
         entry
     }
 
-    /// Format commit type as a title
-    fn format_commit_type_title(commit_type: &str) -> String {
-        match commit_type {
-            "feat" => "Features".to_string(),
-            "fix" => "Bug Fixes".to_string(),
-            "perf" => "Performance Improvements".to_string(),
-            "revert" => "Reverts".to_string(),
-            "docs" => "Documentation".to_string(),
-            "style" => "Styles".to_string(),
-            "refactor" => "Code Refactoring".to_string(),
-            "test" => "Tests".to_string(),
-            "build" => "Build System".to_string(),
-            "ci" => "Continuous Integration".to_string(),
-            "chore" => "Chores".to_string(),
-            _ => {
-                let mut chars = commit_type.chars();
-                match chars.next() {
-                    None => String::new(),
-                    Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+    /// The commit body for the `{body}` placeholder: a blank line, then the
+    /// body indented by two spaces. Empty when the commit has no body.
+    fn indented_body(commit: &ConventionalCommit) -> String {
+        let Some(body) = &commit.body else {
+            return String::new();
+        };
+        let indented = body
+            .lines()
+            .map(|line| {
+                if line.trim().is_empty() {
+                    String::new()
+                } else {
+                    format!("  {line}")
                 }
-            }
-        }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!("\n\n{indented}")
     }
 }
 
@@ -570,6 +931,70 @@ impl Default for ChangelogGenerator {
     }
 }
 
+/// Format `date` with the strftime-style `format` used for changelog
+/// version headers.
+///
+/// # Errors
+///
+/// Returns [`crate::errors::CoreError::ChangelogGeneration`] when `format` is
+/// empty or contains an unknown or incomplete `%` specifier.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use release_regent_core::changelog::format_changelog_date;
+///
+/// let date = Utc.with_ymd_and_hms(2024, 6, 1, 9, 30, 0).unwrap();
+///
+/// assert_eq!(format_changelog_date("%Y-%m-%d", date).unwrap(), "2024-06-01");
+/// assert_eq!(format_changelog_date("%B %-d, %Y", date).unwrap(), "June 1, 2024");
+/// assert!(format_changelog_date("%Q", date).is_err());
+/// ```
+// CoreError is intentionally large; this is the established pattern throughout the codebase.
+#[allow(clippy::result_large_err)]
+pub fn format_changelog_date(
+    format: &str,
+    date: DateTime<Utc>,
+) -> crate::errors::CoreResult<String> {
+    let invalid = || {
+        crate::errors::CoreError::changelog_generation(format!(
+            "changelog.date_format '{format}' is not a valid strftime format"
+        ))
+    };
+
+    if format.trim().is_empty() {
+        return Err(invalid());
+    }
+    let items: Vec<Item<'_>> = StrftimeItems::new(format).collect();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return Err(invalid());
+    }
+
+    let mut rendered = String::new();
+    write!(rendered, "{}", date.format_with_items(items.into_iter())).map_err(|_| invalid())?;
+    Ok(rendered)
+}
+
+/// The built-in [`ChangelogRenderer`], backed by [`ChangelogGenerator`].
+///
+/// Renders with the back-end selected by the repository's
+/// [`ChangelogConfig::strategy`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultChangelogRenderer;
+
+impl ChangelogRenderer for DefaultChangelogRenderer {
+    fn generate(
+        &self,
+        commits: &[ConventionalCommit],
+        context: &ChangelogContext<'_>,
+    ) -> crate::errors::CoreResult<String> {
+        ChangelogGenerator::with_config(context.config.clone())
+            .with_initial_release(context.initial_release)
+            .generate_changelog(commits)
+    }
+}
+
 #[cfg(test)]
 #[path = "changelog_tests.rs"]
 mod tests;
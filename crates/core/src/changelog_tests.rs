@@ -1,4 +1,5 @@
 use super::*;
+use crate::traits::git_operations::GitUser;
 use crate::versioning::ConventionalCommit;
 
 #[test]
@@ -11,7 +12,9 @@ fn test_changelog_generation_basic() {
             description: "add user authentication".to_string(),
             breaking_change: false,
             message: "feat: add user authentication".to_string(),
+            body: None,
             sha: "abc123456789".to_string(),
+            author: None,
         },
         ConventionalCommit {
             commit_type: "fix".to_string(),
@@ -19,7 +22,9 @@ fn test_changelog_generation_basic() {
             description: "resolve login bug".to_string(),
             breaking_change: false,
             message: "fix: resolve login bug".to_string(),
+            body: None,
             sha: "def456789012".to_string(),
+            author: None,
         },
     ];
 
@@ -45,7 +50,9 @@ fn test_changelog_generation_with_scope() {
             description: "add OAuth support".to_string(),
             breaking_change: false,
             message: "feat(auth): add OAuth support".to_string(),
+            body: None,
             sha: "abc123456789".to_string(),
+            author: None,
         },
         ConventionalCommit {
             commit_type: "fix".to_string(),
@@ -53,7 +60,9 @@ fn test_changelog_generation_with_scope() {
             description: "button alignment".to_string(),
             breaking_change: false,
             message: "fix(ui): button alignment".to_string(),
+            body: None,
             sha: "def456789012".to_string(),
+            author: None,
         },
     ];
 
@@ -75,7 +84,9 @@ fn test_changelog_generation_breaking_changes() {
             description: "remove deprecated API".to_string(),
             breaking_change: true,
             message: "feat!: remove deprecated API".to_string(),
+            body: None,
             sha: "abc123456789".to_string(),
+            author: None,
         },
         ConventionalCommit {
             commit_type: "fix".to_string(),
@@ -85,6 +96,8 @@ fn test_changelog_generation_breaking_changes() {
             message: "fix(auth): change login flow\n\nBREAKING CHANGE: Login flow changed"
                 .to_string(),
             sha: "def456789012".to_string(),
+            body: None,
+            author: None,
         },
     ];
 
@@ -108,6 +121,63 @@ fn test_changelog_generation_empty_commits() {
     assert_eq!(changelog, "No changes in this release.");
 }
 
+#[test]
+fn test_changelog_generation_initial_release_starts_with_header() {
+    let generator = ChangelogGenerator::new().with_initial_release(true);
+    let commits = vec![ConventionalCommit {
+        commit_type: "feat".to_string(),
+        scope: None,
+        description: "first public API".to_string(),
+        breaking_change: false,
+        message: "feat: first public API".to_string(),
+        body: None,
+        sha: "abc123456789".to_string(),
+        author: None,
+    }];
+
+    let changelog = generator
+        .generate_changelog(&commits)
+        .expect("changelog generation failed");
+
+    assert!(
+        changelog.starts_with("Initial release.\n\n### Features"),
+        "{changelog}"
+    );
+    assert!(changelog.contains("first public API"));
+}
+
+#[test]
+fn test_changelog_generation_initial_release_without_commits() {
+    let generator = ChangelogGenerator::new().with_initial_release(true);
+
+    let changelog = generator
+        .generate_changelog(&[])
+        .expect("changelog generation failed");
+
+    assert_eq!(changelog, INITIAL_RELEASE_HEADER);
+}
+
+#[test]
+fn test_default_renderer_honours_initial_release_context() {
+    let config = ChangelogConfig::default();
+    let render = |initial_release| {
+        DefaultChangelogRenderer
+            .generate(
+                &[],
+                &ChangelogContext {
+                    owner: "acme",
+                    repo: "app",
+                    config: &config,
+                    initial_release,
+                },
+            )
+            .unwrap()
+    };
+
+    assert_eq!(render(true), INITIAL_RELEASE_HEADER);
+    assert_eq!(render(false), "No changes in this release.");
+}
+
 #[test]
 fn test_changelog_generation_section_ordering() {
     let generator = ChangelogGenerator::new();
@@ -118,7 +188,9 @@ fn test_changelog_generation_section_ordering() {
             description: "update dependencies".to_string(),
             breaking_change: false,
             message: "chore: update dependencies".to_string(),
+            body: None,
             sha: "abc123456789".to_string(),
+            author: None,
         },
         ConventionalCommit {
             commit_type: "feat".to_string(),
@@ -126,7 +198,9 @@ fn test_changelog_generation_section_ordering() {
             description: "add new feature".to_string(),
             breaking_change: false,
             message: "feat: add new feature".to_string(),
+            body: None,
             sha: "def456789012".to_string(),
+            author: None,
         },
         ConventionalCommit {
             commit_type: "fix".to_string(),
@@ -134,7 +208,9 @@ fn test_changelog_generation_section_ordering() {
             description: "fix bug".to_string(),
             breaking_change: false,
             message: "fix: fix bug".to_string(),
+            body: None,
             sha: "ghi789012345".to_string(),
+            author: None,
         },
     ];
 
@@ -156,12 +232,20 @@ fn test_changelog_generation_custom_config() {
     let config = ChangelogConfig {
         strategy: ChangelogStrategy::Internal,
         include_authors: false,
+        author_map: HashMap::new(),
+        resolve_author_logins: false,
         include_shas: false,
         include_links: false,
+        sections: Vec::new(),
+        other_section_title: OTHER_SECTION_TITLE.to_string(),
         section_template: "## {title}\n\n{entries}\n".to_string(),
         commit_template: "* {description}".to_string(),
+        capitalize_descriptions: false,
+        strip_trailing_periods: false,
+        collapse_whitespace: false,
         repository_path: None,
         remote_url: None,
+        date_format: DEFAULT_DATE_FORMAT.to_string(),
     };
 
     let generator = ChangelogGenerator::with_config(config);
@@ -171,7 +255,9 @@ fn test_changelog_generation_custom_config() {
         description: "add feature".to_string(),
         breaking_change: false,
         message: "feat: add feature".to_string(),
+        body: None,
         sha: "abc123456789".to_string(),
+        author: None,
     }];
 
     let changelog = generator
@@ -193,7 +279,9 @@ fn test_changelog_generation_scope_sorting() {
             description: "add button".to_string(),
             breaking_change: false,
             message: "feat(ui): add button".to_string(),
+            body: None,
             sha: "abc123456789".to_string(),
+            author: None,
         },
         ConventionalCommit {
             commit_type: "feat".to_string(),
@@ -201,7 +289,9 @@ fn test_changelog_generation_scope_sorting() {
             description: "add login".to_string(),
             breaking_change: false,
             message: "feat(auth): add login".to_string(),
+            body: None,
             sha: "def456789012".to_string(),
+            author: None,
         },
         ConventionalCommit {
             commit_type: "feat".to_string(),
@@ -209,7 +299,9 @@ fn test_changelog_generation_scope_sorting() {
             description: "add core feature".to_string(),
             breaking_change: false,
             message: "feat: add core feature".to_string(),
+            body: None,
             sha: "ghi789012345".to_string(),
+            author: None,
         },
     ];
 
@@ -241,7 +333,9 @@ fn test_changelog_generation_template_path_basic() {
             description: "add user authentication".to_string(),
             breaking_change: false,
             message: "feat: add user authentication".to_string(),
+            body: None,
             sha: "abc123456789".to_string(),
+            author: None,
         },
         ConventionalCommit {
             commit_type: "fix".to_string(),
@@ -249,7 +343,9 @@ fn test_changelog_generation_template_path_basic() {
             description: "resolve login bug".to_string(),
             breaking_change: false,
             message: "fix: resolve login bug".to_string(),
+            body: None,
             sha: "def456789012".to_string(),
+            author: None,
         },
     ];
 
@@ -316,7 +412,9 @@ fn test_changelog_with_git_cliff_enabled() {
             description: "add OAuth support".to_string(),
             breaking_change: false,
             message: "feat(auth): add OAuth support".to_string(),
+            body: None,
             sha: "abc123456789".to_string(),
+            author: None,
         },
         ConventionalCommit {
             commit_type: "fix".to_string(),
@@ -324,7 +422,9 @@ fn test_changelog_with_git_cliff_enabled() {
             description: "button alignment".to_string(),
             breaking_change: false,
             message: "fix(ui): button alignment".to_string(),
+            body: None,
             sha: "def456789012".to_string(),
+            author: None,
         },
     ];
 
@@ -345,7 +445,9 @@ fn test_changelog_error_handling() {
         description: "test feature".to_string(),
         breaking_change: false,
         message: "feat: test feature".to_string(),
+        body: None,
         sha: "".to_string(), // Empty SHA to potentially trigger errors
+        author: None,
     }];
 
     let result = generator.generate_changelog(&commits);
@@ -386,7 +488,9 @@ fn test_generate_with_git_cliff_happy_path() {
             description: "add new capability".to_string(),
             breaking_change: false,
             message: "feat: add new capability".to_string(),
+            body: None,
             sha: "abc123456789abcd".to_string(),
+            author: None,
         },
         ConventionalCommit {
             commit_type: "fix".to_string(),
@@ -394,7 +498,9 @@ fn test_generate_with_git_cliff_happy_path() {
             description: "resolve off-by-one error".to_string(),
             breaking_change: false,
             message: "fix(core): resolve off-by-one error".to_string(),
+            body: None,
             sha: "def456789012abcd".to_string(),
+            author: None,
         },
     ];
 
@@ -426,7 +532,9 @@ fn test_generate_with_git_cliff_single_commit() {
         description: "update Cargo.lock".to_string(),
         breaking_change: false,
         message: "chore: update Cargo.lock".to_string(),
+        body: None,
         sha: "aabbccddeeff0011".to_string(),
+        author: None,
     }];
 
     let result = generator.generate_changelog(&commits);
@@ -455,7 +563,9 @@ fn test_generate_with_git_cliff_links_without_remote_url() {
         description: "add widget".to_string(),
         breaking_change: false,
         message: "feat: add widget".to_string(),
+        body: None,
         sha: "1122334455667788".to_string(),
+        author: None,
     }];
 
     // add_remote_context failure is swallowed; result should still be Ok.
@@ -485,7 +595,9 @@ fn test_generate_with_git_cliff_filters_merge_commit() {
             description: "return 400 when input name is empty".to_string(),
             breaking_change: false,
             message: "fix(api): return 400 when input name is empty".to_string(),
+            body: None,
             sha: "ab5749c3ab5749c3ab5749c3ab5749c3ab5749c3".to_string(),
+            author: None,
         },
         ConventionalCommit {
             commit_type: "chore".to_string(),
@@ -494,7 +606,9 @@ fn test_generate_with_git_cliff_filters_merge_commit() {
                 .to_string(),
             breaking_change: false,
             message: "Merge pull request #1 from glitchgrove/fix/handle-empty-input".to_string(),
+            body: None,
             sha: "0a382b0d0a382b0d0a382b0d0a382b0d0a382b0d".to_string(),
+            author: None,
         },
     ];
 
@@ -534,7 +648,9 @@ fn test_generate_with_git_cliff_all_non_conventional_returns_no_changes() {
         description: "Merge pull request #2 from owner/branch".to_string(),
         breaking_change: false,
         message: "Merge pull request #2 from owner/branch".to_string(),
+        body: None,
         sha: "deadbeefdeadbeefdeadbeefdeadbeefdeadbeef".to_string(),
+        author: None,
     }];
 
     let result = generator.generate_changelog(&commits);
@@ -641,7 +757,9 @@ fn test_generate_with_external_empty_command_returns_error() {
         description: "add thing".to_string(),
         breaking_change: false,
         message: "feat: add thing".to_string(),
+        body: None,
         sha: "abc123".to_string(),
+        author: None,
     }];
 
     let result = generator.generate_changelog(&commits);
@@ -681,7 +799,9 @@ fn test_generate_with_external_empty_output_returns_sentinel() {
         description: "add thing".to_string(),
         breaking_change: false,
         message: "feat: add thing".to_string(),
+        body: None,
         sha: "abc123".to_string(),
+        author: None,
     }];
 
     let result = generator.generate_changelog(&commits).unwrap();
@@ -690,3 +810,511 @@ fn test_generate_with_external_empty_output_returns_sentinel() {
         "empty external output should return sentinel"
     );
 }
+
+fn commit_by(name: &str, email: &str, login: Option<&str>) -> ConventionalCommit {
+    ConventionalCommit {
+        commit_type: "fix".to_string(),
+        scope: None,
+        description: "handle empty input".to_string(),
+        breaking_change: false,
+        message: "fix: handle empty input".to_string(),
+        body: None,
+        sha: "abc1234".to_string(),
+        author: Some(GitUser {
+            name: name.to_string(),
+            email: email.to_string(),
+            login: login.map(str::to_string),
+        }),
+    }
+}
+
+fn config_with_author_map(entries: &[(&str, &str)]) -> ChangelogConfig {
+    ChangelogConfig {
+        author_map: entries
+            .iter()
+            .map(|(email, login)| ((*email).to_string(), (*login).to_string()))
+            .collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_mapped_author_email_renders_mention() {
+    let generator =
+        ChangelogGenerator::with_config(config_with_author_map(&[("jane@example.com", "janed")]));
+
+    let changelog = generator
+        .generate_changelog(&[commit_by("Jane Doe", "Jane@Example.com", None)])
+        .unwrap();
+
+    assert!(changelog.contains("- handle empty input [abc1234] by @janed"));
+}
+
+#[test]
+fn test_unmapped_author_email_renders_plain_name() {
+    let generator =
+        ChangelogGenerator::with_config(config_with_author_map(&[("other@example.com", "other")]));
+
+    let changelog = generator
+        .generate_changelog(&[commit_by("Jane Doe", "jane@example.com", None)])
+        .unwrap();
+
+    assert!(changelog.contains("- handle empty input [abc1234] by Jane Doe"));
+    assert!(!changelog.contains('@'));
+}
+
+#[test]
+fn test_author_map_takes_precedence_over_commit_login() {
+    let generator =
+        ChangelogGenerator::with_config(config_with_author_map(&[("jane@example.com", "janed")]));
+
+    let changelog = generator
+        .generate_changelog(&[commit_by("Jane Doe", "jane@example.com", Some("jane-old"))])
+        .unwrap();
+
+    assert!(changelog.contains("by @janed"));
+    assert!(!changelog.contains("jane-old"));
+}
+
+#[test]
+fn test_commit_login_renders_mention_without_mapping() {
+    let generator = ChangelogGenerator::new();
+
+    let changelog = generator
+        .generate_changelog(&[commit_by("Jane Doe", "jane@example.com", Some("janed"))])
+        .unwrap();
+
+    assert!(changelog.contains("by @janed"));
+}
+
+#[test]
+fn test_author_placeholder_in_commit_template() {
+    let generator = ChangelogGenerator::with_config(ChangelogConfig {
+        commit_template: "- {description} ({author})".to_string(),
+        ..config_with_author_map(&[("jane@example.com", "janed")])
+    });
+
+    let changelog = generator
+        .generate_changelog(&[commit_by("Jane Doe", "jane@example.com", None)])
+        .unwrap();
+
+    assert!(changelog.contains("- handle empty input (@janed)"));
+}
+
+#[test]
+fn test_authors_omitted_when_disabled() {
+    let generator = ChangelogGenerator::with_config(ChangelogConfig {
+        include_authors: false,
+        ..config_with_author_map(&[("jane@example.com", "janed")])
+    });
+
+    let changelog = generator
+        .generate_changelog(&[commit_by("Jane Doe", "jane@example.com", None)])
+        .unwrap();
+
+    assert!(changelog.contains("- handle empty input [abc1234]"));
+    assert!(!changelog.contains("by "));
+    assert!(!changelog.contains("@janed"));
+}
+
+#[test]
+fn test_body_placeholder_renders_indented_body() {
+    let generator = ChangelogGenerator::with_config(ChangelogConfig {
+        commit_template: "- {description} [{sha}]{body}".to_string(),
+        ..ChangelogConfig::default()
+    });
+    let commit = ConventionalCommit {
+        body: Some("First paragraph.\n\nSecond paragraph\nwraps here.".to_string()),
+        ..typed_commit("feat", "add login")
+    };
+
+    let changelog = generator.generate_changelog(&[commit]).unwrap();
+
+    assert!(
+        changelog.contains(
+            "- add login [abc1234]\n\n  First paragraph.\n\n  Second paragraph\n  wraps here."
+        ),
+        "{changelog}"
+    );
+}
+
+#[test]
+fn test_body_placeholder_is_empty_without_body() {
+    let generator = ChangelogGenerator::with_config(ChangelogConfig {
+        commit_template: "- {description} [{sha}]{body}".to_string(),
+        ..ChangelogConfig::default()
+    });
+
+    let changelog = generator
+        .generate_changelog(&[typed_commit("feat", "add login")])
+        .unwrap();
+
+    assert!(changelog.contains("- add login [abc1234]"), "{changelog}");
+    assert!(!changelog.contains("{body}"), "{changelog}");
+}
+
+#[test]
+fn test_default_template_does_not_render_body() {
+    let commit = ConventionalCommit {
+        body: Some("Details that stay out of the default changelog.".to_string()),
+        ..typed_commit("feat", "add login")
+    };
+
+    let changelog = ChangelogGenerator::new()
+        .generate_changelog(&[commit])
+        .unwrap();
+
+    assert!(!changelog.contains("Details"), "{changelog}");
+}
+
+fn typed_commit(commit_type: &str, description: &str) -> ConventionalCommit {
+    ConventionalCommit {
+        commit_type: commit_type.to_string(),
+        scope: None,
+        description: description.to_string(),
+        breaking_change: false,
+        message: format!("{commit_type}: {description}"),
+        body: None,
+        sha: "abc1234".to_string(),
+        author: None,
+    }
+}
+
+fn section(types: &[&str], title: &str) -> SectionConfig {
+    SectionConfig {
+        types: types.iter().map(|t| (*t).to_string()).collect(),
+        title: title.to_string(),
+        hidden: false,
+    }
+}
+
+fn sectioned_generator(sections: Vec<SectionConfig>) -> ChangelogGenerator {
+    ChangelogGenerator::with_config(ChangelogConfig {
+        include_shas: false,
+        sections,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_configured_sections_render_in_configured_order() {
+    let generator = sectioned_generator(vec![
+        section(&["fix"], "Bug Fixes"),
+        section(&["feat"], "New Features"),
+    ]);
+
+    let changelog = generator
+        .generate_changelog(&[
+            typed_commit("feat", "add export"),
+            typed_commit("fix", "handle empty input"),
+        ])
+        .unwrap();
+
+    let fix_pos = changelog.find("### Bug Fixes").unwrap();
+    let feat_pos = changelog.find("### New Features").unwrap();
+    assert!(
+        fix_pos < feat_pos,
+        "fix section must come first:\n{changelog}"
+    );
+    assert!(!changelog.contains("### Features"));
+}
+
+#[test]
+fn test_configured_section_merges_multiple_types() {
+    let generator = sectioned_generator(vec![section(&["fix", "perf"], "Fixes")]);
+
+    let changelog = generator
+        .generate_changelog(&[
+            typed_commit("perf", "cache lookups"),
+            typed_commit("fix", "handle empty input"),
+        ])
+        .unwrap();
+
+    assert_eq!(
+        changelog,
+        "### Fixes\n\n- cache lookups\n- handle empty input"
+    );
+}
+
+#[test]
+fn test_hidden_section_omits_chore_commits() {
+    let generator = sectioned_generator(vec![
+        section(&["feat"], "Features"),
+        SectionConfig {
+            hidden: true,
+            ..section(&["chore"], "")
+        },
+    ]);
+
+    let changelog = generator
+        .generate_changelog(&[
+            typed_commit("feat", "add export"),
+            typed_commit("chore", "bump dependencies"),
+        ])
+        .unwrap();
+
+    assert!(changelog.contains("- add export"));
+    assert!(!changelog.contains("bump dependencies"));
+    assert!(!changelog.contains(OTHER_SECTION_TITLE));
+}
+
+#[test]
+fn test_unmapped_types_fall_back_to_other_section() {
+    let generator = sectioned_generator(vec![section(&["feat"], "Features")]);
+
+    let changelog = generator
+        .generate_changelog(&[
+            typed_commit("docs", "document sections"),
+            typed_commit("feat", "add export"),
+            typed_commit("ci", "cache cargo"),
+        ])
+        .unwrap();
+
+    let other = changelog.find("### Other Changes").unwrap();
+    assert!(changelog.find("### Features").unwrap() < other);
+    assert!(changelog[other..].ends_with("### Other Changes\n\n- cache cargo\n- document sections"));
+}
+
+#[test]
+fn test_builtin_sections_keep_ci_build_and_revert_commits() {
+    let generator = ChangelogGenerator::with_config(ChangelogConfig {
+        include_shas: false,
+        ..Default::default()
+    });
+
+    let changelog = generator
+        .generate_changelog(&[
+            typed_commit("ci", "cache cargo"),
+            typed_commit("build", "bump msrv"),
+            typed_commit("revert", "add export"),
+        ])
+        .unwrap();
+
+    assert!(changelog.contains("### Continuous Integration\n\n- cache cargo"));
+    assert!(changelog.contains("### Build System\n\n- bump msrv"));
+    assert!(changelog.contains("### Reverts\n\n- Revert: add export"));
+}
+
+#[test]
+fn test_builtin_sections_collect_unknown_types_in_one_other_section() {
+    let generator = ChangelogGenerator::with_config(ChangelogConfig {
+        include_shas: false,
+        ..Default::default()
+    });
+
+    let changelog = generator
+        .generate_changelog(&[
+            typed_commit("deps", "update serde"),
+            typed_commit("feat", "add export"),
+            typed_commit("wip", "sketch parser"),
+        ])
+        .unwrap();
+
+    assert!(changelog.ends_with("### Other Changes\n\n- sketch parser\n- update serde"));
+    assert!(!changelog.contains("### Deps"));
+    assert!(!changelog.contains("### Wip"));
+}
+
+#[test]
+fn test_other_section_title_is_configurable() {
+    let generator = ChangelogGenerator::with_config(ChangelogConfig {
+        include_shas: false,
+        sections: vec![section(&["feat"], "Features"), {
+            let mut hidden = section(&["chore"], "");
+            hidden.hidden = true;
+            hidden
+        }],
+        other_section_title: "Miscellaneous".to_string(),
+        ..Default::default()
+    });
+
+    let changelog = generator
+        .generate_changelog(&[
+            typed_commit("chore", "tidy"),
+            typed_commit("build", "bump msrv"),
+            typed_commit("revert", "add export"),
+        ])
+        .unwrap();
+
+    assert_eq!(
+        changelog,
+        "### Miscellaneous\n\n- Revert: add export\n- bump msrv"
+    );
+}
+
+#[test]
+fn test_sections_config_from_toml() {
+    let config: ChangelogConfig = toml::from_str(
+        r#"
+[[sections]]
+types = ["feat"]
+title = "New Features"
+
+[[sections]]
+types = ["chore", "ci"]
+hidden = true
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(config.sections.len(), 2);
+    assert_eq!(config.sections[0], section(&["feat"], "New Features"));
+    assert!(config.sections[1].hidden);
+    assert!(config.sections[1].title.is_empty());
+}
+
+// ── date format ──────────────────────────────────────────────────────────────
+
+fn release_date() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2024-06-01T09:30:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+fn generator_with_date_format(date_format: &str) -> ChangelogGenerator {
+    ChangelogGenerator::with_config(ChangelogConfig {
+        date_format: date_format.to_string(),
+        ..ChangelogConfig::default()
+    })
+}
+
+#[test]
+fn test_version_header_defaults_to_iso_date() {
+    let header = ChangelogGenerator::new()
+        .render_version_header("1.2.3", release_date())
+        .unwrap();
+
+    assert_eq!(header, "## [1.2.3] - 2024-06-01");
+}
+
+#[test]
+fn test_same_release_renders_with_two_date_formats() {
+    let long = generator_with_date_format("%B %-d, %Y")
+        .render_version_header("1.2.3", release_date())
+        .unwrap();
+    let with_time = generator_with_date_format("%Y-%m-%dT%H:%M:%SZ")
+        .render_version_header("1.2.3", release_date())
+        .unwrap();
+
+    assert_eq!(long, "## [1.2.3] - June 1, 2024");
+    assert_eq!(with_time, "## [1.2.3] - 2024-06-01T09:30:00Z");
+}
+
+#[test]
+fn test_invalid_date_format_is_a_changelog_error() {
+    for format in ["%Q", "%Y-%", "", "  "] {
+        let result = generator_with_date_format(format).format_date(release_date());
+
+        assert!(
+            matches!(
+                result,
+                Err(crate::errors::CoreError::ChangelogGeneration { .. })
+            ),
+            "'{format}' should be rejected, got {result:?}"
+        );
+    }
+}
+
+#[test]
+fn test_date_format_defaults_when_missing_from_toml() {
+    let config: ChangelogConfig = toml::from_str("").unwrap();
+    assert_eq!(config.date_format, DEFAULT_DATE_FORMAT);
+
+    let config: ChangelogConfig = toml::from_str(r#"date_format = "%d.%m.%Y""#).unwrap();
+    assert_eq!(config.date_format, "%d.%m.%Y");
+}
+
+// ── description normalization ────────────────────────────────────────────────
+
+fn normalizing_generator(
+    capitalize_descriptions: bool,
+    strip_trailing_periods: bool,
+    collapse_whitespace: bool,
+) -> ChangelogGenerator {
+    ChangelogGenerator::with_config(ChangelogConfig {
+        include_shas: false,
+        capitalize_descriptions,
+        strip_trailing_periods,
+        collapse_whitespace,
+        ..ChangelogConfig::default()
+    })
+}
+
+#[test]
+fn test_descriptions_are_not_normalized_by_default() {
+    let generator = ChangelogGenerator::new();
+
+    assert_eq!(
+        generator.normalize_description("add  login  page."),
+        "add  login  page."
+    );
+}
+
+#[test]
+fn test_capitalize_descriptions_upper_cases_first_letter() {
+    let generator = normalizing_generator(true, false, false);
+
+    assert_eq!(generator.normalize_description("add login"), "Add login");
+    assert_eq!(
+        generator.normalize_description("éclair support"),
+        "Éclair support"
+    );
+    assert_eq!(
+        generator.normalize_description("Already done"),
+        "Already done"
+    );
+    assert_eq!(generator.normalize_description(""), "");
+}
+
+#[test]
+fn test_strip_trailing_periods_removes_final_periods() {
+    let generator = normalizing_generator(false, true, false);
+
+    assert_eq!(generator.normalize_description("fix crash."), "fix crash");
+    assert_eq!(
+        generator.normalize_description("wait for it... "),
+        "wait for it"
+    );
+    assert_eq!(
+        generator.normalize_description("support v1.2 config"),
+        "support v1.2 config"
+    );
+}
+
+#[test]
+fn test_collapse_whitespace_joins_runs_with_single_spaces() {
+    let generator = normalizing_generator(false, false, true);
+
+    assert_eq!(
+        generator.normalize_description("  add \t login\n  page  "),
+        "add login page"
+    );
+}
+
+#[test]
+fn test_all_normalizations_apply_to_rendered_entries() {
+    let generator = normalizing_generator(true, true, true);
+    let commit = typed_commit("feat", "  add   dark mode . ");
+
+    let changelog = generator
+        .generate_changelog(std::slice::from_ref(&commit))
+        .unwrap();
+
+    assert_eq!(changelog, "### Features\n\n- Add dark mode");
+    assert_eq!(commit.description, "  add   dark mode . ");
+}
+
+#[test]
+fn test_normalization_flags_from_toml() {
+    let config: ChangelogConfig = toml::from_str(
+        r#"
+capitalize_descriptions = true
+strip_trailing_periods = true
+"#,
+    )
+    .unwrap();
+
+    assert!(config.capitalize_descriptions);
+    assert!(config.strip_trailing_periods);
+    assert!(!config.collapse_whitespace);
+}
@@ -0,0 +1,36 @@
+//! Wall-clock time.
+//!
+//! Code that depends on the current time, such as build metadata timestamps
+//! or token expiry, reads it from a [`Clock`] instead of calling
+//! `Utc::now()` directly. Production code uses [`SystemClock`]; tests can
+//! substitute a clock they control (see `MockClock` in the testing crate) to
+//! make time-dependent behaviour deterministic.
+
+use chrono::{DateTime, Utc};
+use std::{fmt, sync::Arc};
+
+#[cfg(test)]
+#[path = "clock_tests.rs"]
+mod tests;
+
+/// A source of the current time.
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// The current time in UTC.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The operating system's clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A [`SystemClock`] behind the shared handle components store.
+#[must_use]
+pub fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
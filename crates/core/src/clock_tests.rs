@@ -0,0 +1,18 @@
+use super::*;
+
+#[test]
+fn test_system_clock_reads_the_current_time() {
+    let before = Utc::now();
+
+    let now = SystemClock.now();
+
+    assert!(now >= before);
+    assert!(now <= Utc::now());
+}
+
+#[test]
+fn test_system_clock_handle_is_a_system_clock() {
+    let clock = system_clock();
+
+    assert_eq!(format!("{clock:?}"), "SystemClock");
+}
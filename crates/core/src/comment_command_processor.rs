@@ -387,12 +387,20 @@ impl<'a, G: GitHubOperations + Send + Sync> CommentCommandProcessor<'a, G> {
     ) -> CoreResult<()> {
         // Guard: only accept !set-version on the release PR (head branch release/v*).
         let pr = self.github.get_pull_request(owner, repo, pr_number).await?;
-        let branch_prefix = &self.config.orchestrator_config.branch_prefix;
-        let release_head_prefix = format!("{branch_prefix}/v");
-        if !pr.head.ref_name.starts_with(&release_head_prefix) {
+        let release_head_prefix = self.config.orchestrator_config.release_branch_prefix(
+            owner,
+            repo,
+            &pr.base.ref_name,
+        )?;
+        if !self.config.orchestrator_config.is_release_branch(
+            owner,
+            repo,
+            &pr.base.ref_name,
+            &pr.head.ref_name,
+        )? {
             let rejection = format!(
                 "⚠️ **Release Regent**: `!set-version` must be posted on the active \
-                 release PR (branch `{branch_prefix}/v*`). Please re-post this \
+                 release PR (branch `{release_head_prefix}*`). Please re-post this \
                  command on the release PR."
             );
             warn!(
@@ -464,8 +472,13 @@ impl<'a, G: GitHubOperations + Send + Sync> CommentCommandProcessor<'a, G> {
             &self.config.orchestrator_config.changelog_header,
         );
 
+        // Pinning the version keeps the package tags of the release.
+        let package_tags = crate::release_orchestrator::extract_package_tag_sentinels(
+            pr.body.as_deref().unwrap_or(""),
+        );
         let orchestrator =
-            ReleaseOrchestrator::new(self.config.orchestrator_config.clone(), self.github);
+            ReleaseOrchestrator::new(self.config.orchestrator_config.clone(), self.github)
+                .with_package_tags(package_tags);
 
         let orch_result = orchestrator
             .orchestrate(
@@ -482,7 +495,7 @@ impl<'a, G: GitHubOperations + Send + Sync> CommentCommandProcessor<'a, G> {
         let confirmation = Self::format_set_version_confirmation(
             pinned_version,
             &orch_result,
-            &self.config.orchestrator_config.branch_prefix,
+            &release_head_prefix,
         );
         self.post_comment(owner, repo, pr_number, &confirmation)
             .await
@@ -500,7 +513,7 @@ impl<'a, G: GitHubOperations + Send + Sync> CommentCommandProcessor<'a, G> {
     fn format_set_version_confirmation(
         pinned_version: &SemanticVersion,
         result: &crate::release_orchestrator::OrchestratorResult,
-        branch_prefix: &str,
+        release_head_prefix: &str,
     ) -> String {
         use crate::release_orchestrator::OrchestratorResult;
         match result {
@@ -520,11 +533,10 @@ impl<'a, G: GitHubOperations + Send + Sync> CommentCommandProcessor<'a, G> {
                 // Strip the release branch prefix (e.g. "release/v") to show
                 // a clean version number like "2.0.0" rather than the full
                 // branch name like "release/v2.0.0".
-                let release_v_prefix = format!("{branch_prefix}/v");
                 let version_display = pr
                     .head
                     .ref_name
-                    .strip_prefix(&release_v_prefix)
+                    .strip_prefix(release_head_prefix)
                     .unwrap_or(&pr.head.ref_name);
                 format!(
                     "⚠️ **Release Regent**: `!set-version {pinned_version}` was not applied \
@@ -536,7 +548,7 @@ impl<'a, G: GitHubOperations + Send + Sync> CommentCommandProcessor<'a, G> {
             // from a `!set-version` command: the caller always supplies an
             // explicit pinned version, so the orchestrator always proceeds past
             // the no-bump guard.  This arm exists only for exhaustive matching.
-            OrchestratorResult::NoBumpNeeded => unreachable!(
+            OrchestratorResult::NoBumpNeeded { .. } => unreachable!(
                 "NoBumpNeeded cannot be returned by orchestrate() from a \
                  !set-version command path"
             ),
@@ -546,6 +558,12 @@ impl<'a, G: GitHubOperations + Send + Sync> CommentCommandProcessor<'a, G> {
                 "TaggedRelease cannot be returned by orchestrate() from a \
                  !set-version command path"
             ),
+            // NotReleaseTarget is decided by the processor before the
+            // orchestrator runs.
+            OrchestratorResult::NotReleaseTarget { .. } => unreachable!(
+                "NotReleaseTarget cannot be returned by orchestrate() from a \
+                 !set-version command path"
+            ),
         }
     }
 
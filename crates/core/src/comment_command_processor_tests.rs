@@ -6,9 +6,9 @@ use crate::{
             ListTagsOptions,
         },
         github_operations::{
-            CollaboratorPermission, CreatePullRequestParams, CreateReleaseParams, GitHubOperations,
-            GitUser as GitHubUser, Label, PullRequest, PullRequestBranch, Release, Repository, Tag,
-            UpdateReleaseParams,
+            CollaboratorPermission, CreateCommitStatusParams, CreatePullRequestParams,
+            CreateReleaseParams, GitHubOperations, GitUser as GitHubUser, Label, LabelMode,
+            PullRequest, PullRequestBranch, Release, Repository, Tag, UpdateReleaseParams,
         },
     },
     CoreError,
@@ -33,10 +33,6 @@ struct TestState {
     prs_by_number: HashMap<u64, PullRequest>,
     /// PRs returned by `search_pull_requests` (drives `ReleaseOrchestrator`).
     search_results: Vec<PullRequest>,
-    /// When `true`, `search_pull_requests` returns a `CoreError::Network` error.
-    search_returns_error: bool,
-    /// Whether the next `create_branch` call should return `CoreError::Conflict`.
-    next_create_branch_conflict: bool,
     /// Recorded `create_issue_comment` calls: `(issue_number, body)`.
     created_issue_comments: Vec<(u64, String)>,
     /// Recorded `create_pull_request` calls.
@@ -90,18 +86,6 @@ impl TestGitHub {
         self
     }
 
-    /// Make all `search_pull_requests` calls return a network error.
-    async fn with_search_error(self) -> Self {
-        self.state.lock().await.search_returns_error = true;
-        self
-    }
-
-    /// Make the next `create_branch` call fail with `CoreError::Conflict`.
-    async fn with_next_create_branch_conflict(self) -> Self {
-        self.state.lock().await.next_create_branch_conflict = true;
-        self
-    }
-
     /// Pre-set the collaborator permission returned for any username.
     async fn with_commenter_permission(self, permission: CollaboratorPermission) -> Self {
         self.state.lock().await.commenter_permission = Some(permission);
@@ -150,6 +134,16 @@ impl GitOperations for TestGitHub {
         Ok(vec![])
     }
 
+    async fn list_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _head: &str,
+        _options: GetCommitsOptions,
+    ) -> CoreResult<Vec<GitCommit>> {
+        Ok(vec![])
+    }
+
     async fn get_commit(&self, _owner: &str, _repo: &str, _sha: &str) -> CoreResult<GitCommit> {
         Err(CoreError::not_found("stub"))
     }
@@ -245,6 +239,15 @@ impl GitHubOperations for TestGitHub {
         Err(CoreError::not_supported("create_tag", "stub"))
     }
 
+    async fn create_signed_tag(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _params: crate::traits::github_operations::SignedTagParams,
+    ) -> CoreResult<Tag> {
+        Err(CoreError::not_supported("create_signed_tag", "stub"))
+    }
+
     async fn get_latest_release(&self, _owner: &str, _repo: &str) -> CoreResult<Option<Release>> {
         Ok(None)
     }
@@ -267,8 +270,8 @@ impl GitHubOperations for TestGitHub {
         _owner: &str,
         _repo: &str,
         _tag: &str,
-    ) -> CoreResult<Release> {
-        Err(CoreError::not_found("stub"))
+    ) -> CoreResult<Option<Release>> {
+        Ok(None)
     }
 
     async fn list_releases(
@@ -281,6 +284,18 @@ impl GitHubOperations for TestGitHub {
         Ok(vec![])
     }
 
+    async fn upload_release_asset(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _release_id: u64,
+        _name: &str,
+        _content_type: Option<&str>,
+        _data: Vec<u8>,
+    ) -> CoreResult<String> {
+        Err(CoreError::not_found("stub"))
+    }
+
     async fn list_pull_requests(
         &self,
         _owner: &str,
@@ -300,14 +315,7 @@ impl GitHubOperations for TestGitHub {
         _repo: &str,
         _query: &str,
     ) -> CoreResult<Vec<PullRequest>> {
-        let mut st = self.state.lock().await;
-        if st.search_returns_error {
-            // One-shot: fail once, then succeed on subsequent calls so the
-            // orchestrator's internal search can still work.
-            st.search_returns_error = false;
-            return Err(CoreError::network("Simulated search_pull_requests failure"));
-        }
-        Ok(st.search_results.clone())
+        Ok(self.state.lock().await.search_results.clone())
     }
 
     async fn update_pull_request(
@@ -361,18 +369,24 @@ impl GitHubOperations for TestGitHub {
         branch_name: &str,
         sha: &str,
     ) -> CoreResult<()> {
-        let mut st = self.state.lock().await;
-        if st.next_create_branch_conflict {
-            st.next_create_branch_conflict = false;
-            return Err(CoreError::conflict(format!(
-                "branch '{branch_name}' already exists"
-            )));
-        }
-        st.created_branches
+        self.state
+            .lock()
+            .await
+            .created_branches
             .push((branch_name.to_string(), sha.to_string()));
         Ok(())
     }
 
+    async fn create_commit_status(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _sha: &str,
+        _params: CreateCommitStatusParams,
+    ) -> CoreResult<()> {
+        Ok(())
+    }
+
     async fn delete_branch(&self, _owner: &str, _repo: &str, _branch_name: &str) -> CoreResult<()> {
         Ok(())
     }
@@ -387,6 +401,24 @@ impl GitHubOperations for TestGitHub {
         Ok(())
     }
 
+    /// Creates the branch like `create_branch`; an existing branch is reported
+    /// as diverged, as a release branch carrying its release commit would be.
+    async fn upsert_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch_name: &str,
+        from_sha: &str,
+    ) -> CoreResult<crate::traits::github_operations::BranchUpsert> {
+        match self.create_branch(owner, repo, branch_name, from_sha).await {
+            Ok(()) => Ok(crate::traits::github_operations::BranchUpsert::Created),
+            Err(CoreError::Conflict { .. }) => {
+                Err(CoreError::not_fast_forward(branch_name, from_sha))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     async fn create_issue_comment(
         &self,
         _owner: &str,
@@ -463,6 +495,21 @@ impl GitHubOperations for TestGitHub {
         Ok(())
     }
 
+    async fn resolve_ref(&self, _owner: &str, _repo: &str, _git_ref: &str) -> CoreResult<String> {
+        Err(CoreError::not_found("stub"))
+    }
+
+    async fn set_pull_request_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+        _labels: &[&str],
+        _mode: LabelMode,
+    ) -> CoreResult<()> {
+        Ok(())
+    }
+
     async fn list_pr_labels(
         &self,
         _owner: &str,
@@ -537,6 +584,15 @@ impl GitHubOperations for TestGitHub {
         Ok(vec![])
     }
 
+    async fn list_pull_request_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+    ) -> CoreResult<Vec<crate::traits::git_operations::GitCommit>> {
+        Ok(vec![])
+    }
+
     async fn update_issue_comment(
         &self,
         _owner: &str,
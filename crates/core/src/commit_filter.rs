@@ -0,0 +1,169 @@
+//! Exclusion of commits from version calculation and changelog generation.
+//!
+//! A [`CommitFilter`] is built from the [`CommitExclusionConfig`] of a
+//! repository and applied to the commit history before it is parsed, so
+//! excluded commits neither bump the version nor appear in the changelog.
+//!
+//! [`bump_candidates`] applies the narrower [`ScopeFilterConfig`] after
+//! parsing: commits it filters out are kept for the changelog but do not
+//! bump the version.
+
+use crate::{
+    config::{CommitExclusionConfig, ScopeFilterConfig},
+    traits::version_calculator::CommitAnalysis,
+    CoreError, CoreResult,
+};
+use regex::Regex;
+use tracing::debug;
+
+#[cfg(test)]
+#[path = "commit_filter_tests.rs"]
+mod tests;
+
+/// Compiled commit exclusion rules.
+#[derive(Debug, Clone)]
+pub struct CommitFilter {
+    subject_patterns: Vec<Regex>,
+    allowed_authors: Vec<String>,
+    denied_authors: Vec<String>,
+    skip_token: String,
+    ignore_merge_commits: bool,
+}
+
+/// Subject prefixes Git and GitHub generate for merge commits.
+const MERGE_SUBJECT_PREFIXES: &[&str] = &[
+    "Merge pull request ",
+    "Merge branch ",
+    "Merge remote-tracking branch ",
+    "Merge tag ",
+    "Merge commit ",
+];
+
+/// The commit attributes the exclusion rules look at.
+#[derive(Debug, Clone, Copy)]
+pub struct CommitCandidate<'a> {
+    /// Commit SHA, used for logging
+    pub sha: &'a str,
+    /// Full commit message; the first line is the subject
+    pub message: &'a str,
+    /// Author name, email and GitHub login, whichever are known
+    pub author_identities: &'a [&'a str],
+    /// Number of parent commits; more than one marks a merge commit
+    pub parent_count: usize,
+}
+
+impl CommitCandidate<'_> {
+    /// Returns `true` for a merge commit: one with more than one parent or
+    /// with a subject generated by Git or GitHub for a merge.
+    ///
+    /// The subject check catches merges whose parents are unknown, for
+    /// example when the history was flattened by the API that returned it.
+    #[must_use]
+    pub fn is_merge(&self) -> bool {
+        let subject = self.message.lines().next().unwrap_or_default();
+        self.parent_count > 1
+            || MERGE_SUBJECT_PREFIXES
+                .iter()
+                .any(|prefix| subject.starts_with(prefix))
+    }
+}
+
+impl CommitFilter {
+    /// Compile the exclusion rules.
+    ///
+    /// # Errors
+    /// - `CoreError::InvalidInput` — a subject pattern is not a valid regular expression
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large; established pattern
+    pub fn new(config: &CommitExclusionConfig) -> CoreResult<Self> {
+        let subject_patterns = config
+            .subject_patterns
+            .iter()
+            .map(|pattern| {
+                Regex::new(pattern).map_err(|e| {
+                    CoreError::invalid_input(
+                        "versioning.exclusions.subject_patterns",
+                        format!("invalid pattern '{pattern}': {e}"),
+                    )
+                })
+            })
+            .collect::<CoreResult<Vec<_>>>()?;
+
+        Ok(Self {
+            subject_patterns,
+            allowed_authors: config.allowed_authors.clone(),
+            denied_authors: config.denied_authors.clone(),
+            skip_token: config.skip_token.clone(),
+            ignore_merge_commits: config.ignore_merge_commits,
+        })
+    }
+
+    /// Why `commit` is excluded, or `None` if it is kept.
+    #[must_use]
+    pub fn exclusion_reason(&self, commit: &CommitCandidate<'_>) -> Option<String> {
+        if self.ignore_merge_commits && commit.is_merge() {
+            return Some("merge commit".to_string());
+        }
+
+        if !self.skip_token.is_empty() && commit.message.contains(&self.skip_token) {
+            return Some(format!("message contains '{}'", self.skip_token));
+        }
+
+        let subject = commit.message.lines().next().unwrap_or_default();
+        if let Some(pattern) = self.subject_patterns.iter().find(|p| p.is_match(subject)) {
+            return Some(format!("subject matches '{pattern}'"));
+        }
+
+        let is_listed = |list: &[String]| {
+            commit.author_identities.iter().find(|identity| {
+                list.iter()
+                    .any(|entry| entry.eq_ignore_ascii_case(identity))
+            })
+        };
+        if let Some(author) = is_listed(&self.denied_authors) {
+            return Some(format!("author '{author}' is denied"));
+        }
+        if !self.allowed_authors.is_empty() && is_listed(&self.allowed_authors).is_none() {
+            return Some("author is not in the allowed list".to_string());
+        }
+
+        None
+    }
+
+    /// Returns `true` if `commit` should be kept, logging the reason at debug
+    /// level when it is excluded.
+    #[must_use]
+    pub fn keep(&self, commit: &CommitCandidate<'_>) -> bool {
+        match self.exclusion_reason(commit) {
+            Some(reason) => {
+                debug!(sha = %commit.sha, %reason, "Excluding commit from release");
+                false
+            }
+            None => true,
+        }
+    }
+}
+
+/// The analyses whose scope may bump the version under `scopes`.
+///
+/// Filtered commits are logged at debug level.
+#[must_use]
+pub fn bump_candidates(
+    analyses: &[CommitAnalysis],
+    scopes: &ScopeFilterConfig,
+) -> Vec<CommitAnalysis> {
+    analyses
+        .iter()
+        .filter(|analysis| {
+            let allowed = scopes.allows(analysis.scope.as_deref());
+            if !allowed {
+                debug!(
+                    sha = %analysis.sha,
+                    scope = ?analysis.scope,
+                    "Commit scope does not affect the version bump"
+                );
+            }
+            allowed
+        })
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,174 @@
+use super::*;
+
+fn filter(config: CommitExclusionConfig) -> CommitFilter {
+    CommitFilter::new(&config).expect("rules should compile")
+}
+
+fn candidate<'a>(message: &'a str, authors: &'a [&'a str]) -> CommitCandidate<'a> {
+    CommitCandidate {
+        sha: "abc123",
+        message,
+        author_identities: authors,
+        parent_count: 1,
+    }
+}
+
+#[test]
+fn test_default_rules_keep_ordinary_commits() {
+    let filter = filter(CommitExclusionConfig::default());
+
+    assert!(filter.keep(&candidate("feat: add login", &["Dev", "dev@example.com"])));
+}
+
+#[test]
+fn test_skip_token_excludes_commit_anywhere_in_message() {
+    let filter = filter(CommitExclusionConfig::default());
+
+    let in_subject = candidate("fix: typo [skip release]", &["Dev"]);
+    let in_body = candidate("feat: add login\n\nInternal only. [skip release]", &["Dev"]);
+
+    assert!(filter
+        .exclusion_reason(&in_subject)
+        .is_some_and(|r| r.contains("[skip release]")));
+    assert!(!filter.keep(&in_body));
+}
+
+#[test]
+fn test_empty_skip_token_disables_the_check() {
+    let filter = filter(CommitExclusionConfig {
+        skip_token: String::new(),
+        ..CommitExclusionConfig::default()
+    });
+
+    assert!(filter.keep(&candidate("fix: typo [skip release]", &["Dev"])));
+}
+
+#[test]
+fn test_subject_patterns_match_first_line_only() {
+    let filter = filter(CommitExclusionConfig {
+        subject_patterns: vec!["^Merge branch ".to_string()],
+        ignore_merge_commits: false,
+        ..CommitExclusionConfig::default()
+    });
+
+    assert!(!filter.keep(&candidate("Merge branch 'main' into feature", &["Dev"])));
+    assert!(filter.keep(&candidate(
+        "fix: conflict\n\nMerge branch 'main' into feature",
+        &["Dev"]
+    )));
+}
+
+#[test]
+fn test_denied_authors_match_any_identity_ignoring_case() {
+    let filter = filter(CommitExclusionConfig {
+        denied_authors: vec!["Dependabot[bot]".to_string()],
+        ..CommitExclusionConfig::default()
+    });
+
+    let reason = filter.exclusion_reason(&candidate(
+        "fix(deps): bump serde",
+        &[
+            "dependabot[bot]",
+            "49699333+dependabot[bot]@users.noreply.github.com",
+        ],
+    ));
+
+    assert_eq!(
+        reason.as_deref(),
+        Some("author 'dependabot[bot]' is denied")
+    );
+    assert!(filter.keep(&candidate("fix: bug", &["Dev", "dev@example.com"])));
+}
+
+#[test]
+fn test_allowed_authors_exclude_everyone_else() {
+    let filter = filter(CommitExclusionConfig {
+        allowed_authors: vec!["lead@example.com".to_string()],
+        ..CommitExclusionConfig::default()
+    });
+
+    assert!(filter.keep(&candidate("feat: x", &["Lead", "lead@example.com"])));
+    assert!(!filter.keep(&candidate("feat: y", &["Other", "other@example.com"])));
+}
+
+#[test]
+fn test_merge_commits_are_excluded_by_default() {
+    let filter = filter(CommitExclusionConfig::default());
+    let two_parents = CommitCandidate {
+        parent_count: 2,
+        ..candidate("feat: sync main into release", &["Dev"])
+    };
+
+    assert_eq!(
+        filter.exclusion_reason(&two_parents).as_deref(),
+        Some("merge commit")
+    );
+    assert!(!filter.keep(&candidate(
+        "Merge pull request #12 from acme/feature\n\nfeat: add login",
+        &["Dev"]
+    )));
+    assert!(!filter.keep(&candidate("Merge branch 'main' into feature", &["Dev"])));
+    assert!(filter.keep(&candidate("feat: merge user profiles", &["Dev"])));
+}
+
+#[test]
+fn test_merge_commits_are_kept_when_not_ignored() {
+    let filter = filter(CommitExclusionConfig {
+        ignore_merge_commits: false,
+        ..CommitExclusionConfig::default()
+    });
+    let two_parents = CommitCandidate {
+        parent_count: 2,
+        ..candidate("feat: sync main into release", &["Dev"])
+    };
+
+    assert!(filter.keep(&two_parents));
+    assert!(filter.keep(&candidate(
+        "Merge pull request #12 from acme/feature",
+        &["Dev"]
+    )));
+}
+
+#[test]
+fn test_invalid_subject_pattern_is_rejected() {
+    let result = CommitFilter::new(&CommitExclusionConfig {
+        subject_patterns: vec!["(unclosed".to_string()],
+        ..CommitExclusionConfig::default()
+    });
+
+    let err = result.unwrap_err();
+    assert!(err.to_string().contains("(unclosed"), "got: {err}");
+}
+
+fn scopes(include: &[&str], exclude: &[&str]) -> ScopeFilterConfig {
+    ScopeFilterConfig {
+        include: include.iter().map(ToString::to_string).collect(),
+        exclude: exclude.iter().map(ToString::to_string).collect(),
+    }
+}
+
+#[test]
+fn test_default_scope_filter_allows_every_commit() {
+    let scopes = ScopeFilterConfig::default();
+
+    assert!(scopes.allows(None));
+    assert!(scopes.allows(Some("internal")));
+}
+
+#[test]
+fn test_scope_include_list_rejects_other_and_unscoped_commits() {
+    let scopes = scopes(&["core", "api"], &[]);
+
+    assert!(scopes.allows(Some("api")));
+    assert!(scopes.allows(Some("Core")));
+    assert!(!scopes.allows(Some("docs")));
+    assert!(!scopes.allows(None));
+}
+
+#[test]
+fn test_scope_exclude_list_wins_over_include_list() {
+    let scopes = scopes(&["internal"], &["internal"]);
+
+    assert!(!scopes.allows(Some("internal")));
+    assert!(!scopes.allows(Some("INTERNAL")));
+}
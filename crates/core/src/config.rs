@@ -3,11 +3,27 @@
 //! This module handles loading and validating Release Regent configuration from
 //! YAML files with support for both application-wide and repository-specific settings.
 
-use crate::{changelog::ChangelogConfig, manifest::ManifestFileConfig, CoreError, CoreResult};
+use crate::{
+    changelog::ChangelogConfig,
+    manifest::ManifestFileConfig,
+    secret::{describe_toml_error, SensitiveString},
+    traits::{github_operations::LabelMode, version_calculator::PackageSpec},
+    CoreError, CoreResult,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::Path;
-use tracing::{debug, info};
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+
+/// Configuration file version written by this release of Release Regent.
+///
+/// Files with an older `config_version` can be brought up to date with the
+/// config provider's `ConfigMigrator`.
+pub const CURRENT_CONFIG_VERSION: u32 = 2;
+
+fn default_config_version() -> u32 {
+    CURRENT_CONFIG_VERSION
+}
 
 /// Branch configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -162,6 +178,30 @@ pub struct ReleasePrConfig {
     /// Whether to auto-detect standard language manifests (Cargo.toml, package.json, etc.).
     #[serde(default = "default_auto_detect_manifests")]
     pub auto_detect_manifests: bool,
+    /// Release PR branch name template, e.g.
+    /// `release-regent/{target_branch}/{version_tag}`; see
+    /// [`crate::branch_template`]. Unset, branches are named
+    /// `release/{version_tag}`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_template: Option<String>,
+    /// Labels applied to the release PR whenever it is created or updated.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Whether [`labels`](Self::labels) are added to the labels already on
+    /// the release PR or replace them.
+    #[serde(default)]
+    pub label_mode: LabelMode,
+}
+
+impl ReleasePrConfig {
+    /// The configured [`branch_template`](Self::branch_template), or
+    /// [`DEFAULT_BRANCH_TEMPLATE`](crate::branch_template::DEFAULT_BRANCH_TEMPLATE).
+    #[must_use]
+    pub fn branch_template(&self) -> &str {
+        self.branch_template
+            .as_deref()
+            .unwrap_or(crate::branch_template::DEFAULT_BRANCH_TEMPLATE)
+    }
 }
 
 fn default_pr_title_template() -> String {
@@ -182,13 +222,21 @@ impl Default for ReleasePrConfig {
             draft: false,
             manifest_files: Vec::new(),
             auto_detect_manifests: default_auto_detect_manifests(),
+            branch_template: None,
+            labels: Vec::new(),
+            label_mode: LabelMode::default(),
         }
     }
 }
 
 /// Main Release Regent configuration
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReleaseRegentConfig {
+    /// Version of the configuration file layout.
+    ///
+    /// Defaults to [`CURRENT_CONFIG_VERSION`] when absent.
+    #[serde(default = "default_config_version")]
+    pub config_version: u32,
     /// Core settings
     #[serde(default)]
     pub core: CoreConfig,
@@ -234,30 +282,230 @@ pub struct ReleaseRegentConfig {
     pub versioning: VersioningConfig,
 }
 
+impl Default for ReleaseRegentConfig {
+    fn default() -> Self {
+        Self {
+            config_version: CURRENT_CONFIG_VERSION,
+            core: CoreConfig::default(),
+            group: None,
+            locked_fields: Vec::new(),
+            changelog: ChangelogConfig::default(),
+            release_pr: ReleasePrConfig::default(),
+            releases: ReleasesConfig::default(),
+            error_handling: ErrorHandlingConfig::default(),
+            notifications: NotificationConfig::default(),
+            versioning: VersioningConfig::default(),
+        }
+    }
+}
+
 /// GitHub releases configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReleasesConfig {
     /// Whether to create releases as drafts
-    #[serde(default)]
+    #[serde(default, alias = "create_as_draft")]
     pub draft: bool,
-    /// Whether to mark as prerelease
+    /// Whether to mark every release as a prerelease. Versions with a
+    /// prerelease segment are always marked as prereleases.
     #[serde(default)]
     pub prerelease: bool,
     /// Whether to generate release notes automatically
     #[serde(default = "default_generate_notes")]
     pub generate_notes: bool,
+    /// Branch releases are cut from, e.g. `develop`.
+    ///
+    /// When set, only pull requests merged into this branch start or update a
+    /// release PR, and the release PR targets it. When absent, the base branch
+    /// of the merged pull request is used, falling back to the repository's
+    /// default branch.
+    #[serde(default)]
+    pub target_branch: Option<String>,
+    /// Signing of the release tags Release Regent creates.
+    ///
+    /// When absent, or when `enabled` is `false`, release tags are created
+    /// unsigned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag_signing: Option<TagSigningConfig>,
 }
 
 fn default_generate_notes() -> bool {
     false
 }
 
+impl ReleasesConfig {
+    /// The tag signing configuration, when signing is enabled.
+    #[must_use]
+    pub fn enabled_tag_signing(&self) -> Option<&TagSigningConfig> {
+        self.tag_signing.as_ref().filter(|signing| signing.enabled)
+    }
+}
+
+/// Release tag signing configuration
+///
+/// The signature is produced locally with the configured private key and
+/// submitted together with the tag object, so the key never leaves the
+/// process that runs Release Regent.
+///
+/// The configuration holds only a reference to the key, a file or an
+/// environment variable on the host that runs Release Regent, and is read
+/// from the deployment's own configuration; see [`overlay`](Self::overlay).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagSigningConfig {
+    /// Whether release tags are signed
+    #[serde(default = "default_tag_signing_enabled")]
+    pub enabled: bool,
+    /// Signature format, `gpg` or `ssh`
+    #[serde(default)]
+    pub format: TagSigningFormat,
+    /// File holding the private key used to sign tags: an ASCII-armored GPG
+    /// secret key or an OpenSSH private key without a passphrase
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_file: Option<PathBuf>,
+    /// Environment variable holding the private key, as an alternative to
+    /// [`key_file`](Self::key_file)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_env: Option<String>,
+    /// Name recorded as the tagger of signed tags
+    #[serde(default = "default_tagger_name")]
+    pub tagger_name: String,
+    /// Email recorded as the tagger of signed tags. GitHub only shows the
+    /// tag as verified when this matches an email of the key's owner.
+    #[serde(default = "default_tagger_email")]
+    pub tagger_email: String,
+}
+
+fn default_tag_signing_enabled() -> bool {
+    true
+}
+
+fn default_tagger_name() -> String {
+    "Release Regent".to_string()
+}
+
+fn default_tagger_email() -> String {
+    "release-regent@users.noreply.github.com".to_string()
+}
+
+impl Default for TagSigningConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_tag_signing_enabled(),
+            format: TagSigningFormat::default(),
+            key_file: None,
+            key_env: None,
+            tagger_name: default_tagger_name(),
+            tagger_email: default_tagger_email(),
+        }
+    }
+}
+
+impl TagSigningConfig {
+    /// Why the key reference is unusable, or `None` when exactly one of
+    /// [`key_file`](Self::key_file) and [`key_env`](Self::key_env) is set.
+    #[must_use]
+    pub fn key_source_problem(&self) -> Option<&'static str> {
+        match (&self.key_file, &self.key_env) {
+            (Some(_), Some(_)) => {
+                Some("releases.tag_signing: set only one of key_file and key_env")
+            }
+            (None, None) => Some(
+                "releases.tag_signing.key_file: a key_file or key_env is required when tag signing is enabled",
+            ),
+            _ => None,
+        }
+    }
+
+    /// Read the private key from [`key_file`](Self::key_file) or
+    /// [`key_env`](Self::key_env).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CoreError::Config` when no key source is set, or when the
+    /// source cannot be read or is empty. The key itself is never part of
+    /// the message.
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large
+    pub fn load_key(&self) -> CoreResult<SensitiveString> {
+        let key = match (&self.key_file, &self.key_env) {
+            (Some(path), None) => std::fs::read_to_string(path).map_err(|e| {
+                CoreError::config(format!(
+                    "releases.tag_signing.key_file: cannot read {}: {e}",
+                    path.display()
+                ))
+            })?,
+            (None, Some(var)) => std::env::var(var).map_err(|_| {
+                CoreError::config(format!(
+                    "releases.tag_signing.key_env: environment variable {var} is not set"
+                ))
+            })?,
+            _ => {
+                return Err(CoreError::config(
+                    self.key_source_problem().unwrap_or_default(),
+                ))
+            }
+        };
+        if key.trim().is_empty() {
+            return Err(CoreError::config(
+                "releases.tag_signing: the signing key is empty",
+            ));
+        }
+        Ok(SensitiveString::new(key))
+    }
+
+    /// Apply the tag signing table of a lower configuration level to that of
+    /// a trusted one.
+    ///
+    /// Policy and repository files can only switch signing on or off: the
+    /// key reference, format and tagger always come from `trusted`, the
+    /// deployment's own configuration. Without a trusted table, tags stay
+    /// unsigned whatever the lower level asks for.
+    #[must_use]
+    pub fn overlay(trusted: Option<Self>, lower: Option<&Self>) -> Option<Self> {
+        let Some(lower) = lower else {
+            return trusted;
+        };
+        if lower.key_file.is_some() || lower.key_env.is_some() {
+            warn!(
+                "releases.tag_signing key references are only read from the app-level \
+                 configuration; ignoring the one in a policy or repository file"
+            );
+        }
+        match trusted {
+            Some(trusted) => Some(Self {
+                enabled: lower.enabled,
+                ..trusted
+            }),
+            None => {
+                if lower.enabled {
+                    warn!(
+                        "releases.tag_signing is enabled but the app-level configuration \
+                         has no signing key; release tags stay unsigned"
+                    );
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Signature formats for release tags
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TagSigningFormat {
+    /// OpenPGP signature made with `gpg`
+    #[default]
+    Gpg,
+    /// SSH signature made with `ssh-keygen -Y sign`
+    Ssh,
+}
+
 impl Default for ReleasesConfig {
     fn default() -> Self {
         Self {
             draft: false,
             prerelease: false,
             generate_notes: default_generate_notes(),
+            target_branch: None,
+            tag_signing: None,
         }
     }
 }
@@ -265,8 +513,9 @@ impl Default for ReleasesConfig {
 /// Slack notification configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SlackConfig {
-    /// Slack webhook URL
-    pub webhook_url: String,
+    /// Slack webhook URL. The URL is the credential, so it is redacted in
+    /// `Debug` output.
+    pub webhook_url: SensitiveString,
     /// Channel to post to (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<String>,
@@ -290,6 +539,191 @@ pub struct VersioningConfig {
     /// noise rather than signal.
     #[serde(default)]
     pub excluded_pr_authors: Vec<String>,
+    /// Rules for leaving commits out of version calculation and the changelog
+    #[serde(default)]
+    pub exclusions: CommitExclusionConfig,
+    /// Commit scopes that may bump the version. Filtered commits still
+    /// appear in the changelog.
+    #[serde(default)]
+    pub scopes: ScopeFilterConfig,
+    /// Commit scopes, e.g. `"api"`, whose commits are always breaking changes:
+    /// they force a major bump and are marked as breaking in the changelog.
+    /// Compared case-insensitively.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub breaking_scopes: Vec<String>,
+    /// Whether the title of a merged pull request counts as a conventional
+    /// commit. A title such as `feat!: new API` then bumps the version as
+    /// much as a commit with that message would, even when the individual
+    /// commits are only fixes. The larger of the two bumps wins.
+    #[serde(default)]
+    pub use_pr_title: bool,
+    /// Whether a merge without version-bumping commits, e.g. only `chore:`
+    /// or `docs:` commits, releases the next patch version. When `false`,
+    /// such merges are skipped and no release PR is created or updated.
+    #[serde(default)]
+    pub release_on_no_bump: bool,
+    /// What to do when a merge brings commits but none of them is a
+    /// conventional commit.
+    #[serde(default)]
+    pub no_conventional_commits: NoConventionalCommitsPolicy,
+    /// Lowest version to release, e.g. `"2.0.0"`. When the version calculated
+    /// from the commits is lower, this version is used instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimum_version: Option<String>,
+    /// Version of the first release, e.g. `"1.0.0"`, used when the repository
+    /// has no release yet. Unset, the first release is `0.1.0` bumped by the
+    /// commits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_version: Option<String>,
+}
+
+impl VersioningConfig {
+    /// Parse [`minimum_version`](Self::minimum_version).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::Versioning`](crate::CoreError::Versioning) when
+    /// the value is not a semantic version.
+    // CoreError is intentionally large; this is the established pattern throughout the codebase.
+    #[allow(clippy::result_large_err)]
+    pub fn parsed_minimum_version(
+        &self,
+    ) -> crate::CoreResult<Option<crate::versioning::SemanticVersion>> {
+        self.minimum_version
+            .as_deref()
+            .map(crate::versioning::VersionCalculator::parse_version)
+            .transpose()
+    }
+
+    /// Parse [`initial_version`](Self::initial_version).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::Versioning`](crate::CoreError::Versioning) when
+    /// the value is not a semantic version.
+    // CoreError is intentionally large; this is the established pattern throughout the codebase.
+    #[allow(clippy::result_large_err)]
+    pub fn parsed_initial_version(
+        &self,
+    ) -> crate::CoreResult<Option<crate::versioning::SemanticVersion>> {
+        self.initial_version
+            .as_deref()
+            .map(crate::versioning::VersionCalculator::parse_version)
+            .transpose()
+    }
+}
+
+/// What the version calculator does when every commit since the last release
+/// fails to parse as a conventional commit.
+///
+/// Such commits never bump the version on their own, so without a fallback
+/// nothing is released. The chosen policy is named in the bump reason.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NoConventionalCommitsPolicy {
+    /// Fail the calculation with a versioning error
+    Error,
+    /// Release the next patch version
+    Patch,
+    /// Do not bump the version
+    #[default]
+    None,
+}
+
+impl NoConventionalCommitsPolicy {
+    /// The configuration value of the policy, e.g. `"patch"`.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Patch => "patch",
+            Self::None => "none",
+        }
+    }
+}
+
+impl std::fmt::Display for NoConventionalCommitsPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Rules for leaving commits out of version calculation and the changelog.
+///
+/// A commit is excluded when any rule matches it. Excluded commits are
+/// logged at debug level.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitExclusionConfig {
+    /// Regular expressions matched against the commit subject line
+    #[serde(default)]
+    pub subject_patterns: Vec<String>,
+    /// When non-empty, only commits by these authors are considered.
+    /// Entries match the author's name, email or GitHub login.
+    #[serde(default)]
+    pub allowed_authors: Vec<String>,
+    /// Commits by these authors are excluded. Entries match the author's
+    /// name, email or GitHub login.
+    #[serde(default)]
+    pub denied_authors: Vec<String>,
+    /// Commits whose message contains this token are excluded. An empty
+    /// token disables the check.
+    #[serde(default = "default_skip_token")]
+    pub skip_token: String,
+    /// Whether merge commits are excluded. A merge commit has more than one
+    /// parent or a subject generated by Git or GitHub, such as
+    /// `Merge pull request #12 from ...` or `Merge branch 'main'`. When
+    /// `false`, merge commits are parsed like any other commit.
+    #[serde(default = "default_ignore_merge_commits")]
+    pub ignore_merge_commits: bool,
+}
+
+fn default_skip_token() -> String {
+    "[skip release]".to_string()
+}
+
+fn default_ignore_merge_commits() -> bool {
+    true
+}
+
+impl Default for CommitExclusionConfig {
+    fn default() -> Self {
+        Self {
+            subject_patterns: Vec::new(),
+            allowed_authors: Vec::new(),
+            denied_authors: Vec::new(),
+            skip_token: default_skip_token(),
+            ignore_merge_commits: default_ignore_merge_commits(),
+        }
+    }
+}
+
+/// Commit scopes allowed to influence the version bump.
+///
+/// Scopes are compared case-insensitively. `exclude` takes precedence over
+/// `include`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScopeFilterConfig {
+    /// When non-empty, only commits with one of these scopes bump the
+    /// version; commits without a scope do not.
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Commits with one of these scopes never bump the version.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+impl ScopeFilterConfig {
+    /// Returns `true` if a commit with `scope` may bump the version.
+    #[must_use]
+    pub fn allows(&self, scope: Option<&str>) -> bool {
+        let listed = |list: &[String]| {
+            scope.is_some_and(|scope| list.iter().any(|s| s.eq_ignore_ascii_case(scope)))
+        };
+        if listed(&self.exclude) {
+            return false;
+        }
+        self.include.is_empty() || listed(&self.include)
+    }
 }
 
 fn default_versioning_strategy() -> VersioningStrategy {
@@ -305,6 +739,14 @@ impl Default for VersioningConfig {
             strategy: default_versioning_strategy(),
             allow_override: default_allow_override(),
             excluded_pr_authors: Vec::new(),
+            exclusions: CommitExclusionConfig::default(),
+            scopes: ScopeFilterConfig::default(),
+            minimum_version: None,
+            breaking_scopes: Vec::new(),
+            use_pr_title: false,
+            release_on_no_bump: false,
+            no_conventional_commits: NoConventionalCommitsPolicy::default(),
+            initial_version: None,
         }
     }
 }
@@ -314,9 +756,10 @@ impl Default for VersioningConfig {
 pub struct WebhookConfig {
     /// Webhook URL
     pub url: String,
-    /// Additional headers
+    /// Additional headers. Values typically carry credentials and are
+    /// redacted in `Debug` output.
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub headers: HashMap<String, String>,
+    pub headers: HashMap<String, SensitiveString>,
 }
 
 /// Notification strategies
@@ -371,6 +814,21 @@ pub enum VersioningStrategy {
         #[serde(default = "default_external_timeout_ms")]
         timeout_ms: u64,
     },
+    /// Version each package of a monorepo separately, from the conventional
+    /// commits that change its files.
+    ///
+    /// Example TOML:
+    /// ```toml
+    /// [[versioning.strategy.monorepo.packages]]
+    /// name = "core"
+    /// path = "crates/core/**"
+    /// tag_prefix = "core-v"
+    /// changelog_path = "crates/core/CHANGELOG.md"
+    /// ```
+    Monorepo {
+        /// Packages, each selected by a path glob
+        packages: Vec<PackageSpec>,
+    },
 }
 
 /// Default timeout for external versioning commands (30 seconds).
@@ -381,6 +839,17 @@ fn default_external_timeout_ms() -> u64 {
     crate::changelog::default_external_timeout_ms()
 }
 
+impl VersioningStrategy {
+    /// The monorepo packages; empty for the single-package strategies.
+    #[must_use]
+    pub fn packages(&self) -> &[PackageSpec] {
+        match self {
+            Self::Monorepo { packages } => packages,
+            Self::Conventional | Self::External { .. } => &[],
+        }
+    }
+}
+
 impl From<VersioningStrategy> for crate::traits::version_calculator::VersioningStrategy {
     fn from(strategy: VersioningStrategy) -> Self {
         match strategy {
@@ -399,6 +868,9 @@ impl From<VersioningStrategy> for crate::traits::version_calculator::VersioningS
                 env_vars,
                 timeout_ms,
             },
+            VersioningStrategy::Monorepo { packages } => {
+                crate::traits::version_calculator::VersioningStrategy::Monorepo { packages }
+            }
         }
     }
 }
@@ -420,8 +892,9 @@ impl ReleaseRegentConfig {
         let content = tokio::fs::read_to_string(path).await?;
         let config: Self = toml::from_str(&content).map_err(|e| {
             CoreError::config(format!(
-                "Failed to parse TOML config at {}: {e}",
-                path.display()
+                "Failed to parse TOML config at {}: {}",
+                path.display(),
+                describe_toml_error(&e, Some(&content))
             ))
         })?;
 
@@ -438,11 +911,39 @@ impl ReleaseRegentConfig {
     ///   or is inconsistent with other fields (e.g. webhook URL missing when strategy is `webhook`)
     #[allow(clippy::result_large_err)] // CoreError is intentionally large; established pattern
     pub fn validate(&self) -> CoreResult<()> {
+        // Reject files written for a newer release
+        if self.config_version > CURRENT_CONFIG_VERSION {
+            return Err(CoreError::config(format!(
+                "Configuration version {} is newer than the latest supported version {}; \
+                 upgrade Release Regent to load it",
+                self.config_version, CURRENT_CONFIG_VERSION
+            )));
+        }
+
         // Validate main branch name
         if self.core.branches.main.trim().is_empty() {
             return Err(CoreError::config("Main branch name cannot be empty"));
         }
 
+        // Validate release target branch name
+        if self
+            .releases
+            .target_branch
+            .as_ref()
+            .is_some_and(|b| b.trim().is_empty())
+        {
+            return Err(CoreError::config(
+                "releases.target_branch cannot be empty when set",
+            ));
+        }
+
+        // Validate tag signing
+        if let Some(signing) = self.releases.enabled_tag_signing() {
+            if let Some(problem) = signing.key_source_problem() {
+                return Err(CoreError::config(problem));
+            }
+        }
+
         // Validate version prefix
         if self.core.version_prefix.contains(char::is_whitespace) {
             return Err(CoreError::config(
@@ -450,21 +951,35 @@ impl ReleaseRegentConfig {
             ));
         }
 
+        // Validate release branch template
+        if let Some(template) = &self.release_pr.branch_template {
+            crate::branch_template::validate_branch_template(template, &self.core.version_prefix)
+                .map_err(|e| CoreError::config(format!("release_pr.branch_template: {e}")))?;
+        }
+
+        // Validate release PR labels
+        if self
+            .release_pr
+            .labels
+            .iter()
+            .any(|label| label.trim().is_empty())
+        {
+            return Err(CoreError::config(
+                "release_pr.labels cannot contain empty label names",
+            ));
+        }
+
         // Validate notification configuration
         match self.notifications.strategy {
-            NotificationStrategy::Webhook => {
-                if self.notifications.webhook.is_none() {
-                    return Err(CoreError::config(
-                        "Webhook configuration required when strategy is 'webhook'",
-                    ));
-                }
+            NotificationStrategy::Webhook if self.notifications.webhook.is_none() => {
+                return Err(CoreError::config(
+                    "Webhook configuration required when strategy is 'webhook'",
+                ));
             }
-            NotificationStrategy::Slack => {
-                if self.notifications.slack.is_none() {
-                    return Err(CoreError::config(
-                        "Slack configuration required when strategy is 'slack'",
-                    ));
-                }
+            NotificationStrategy::Slack if self.notifications.slack.is_none() => {
+                return Err(CoreError::config(
+                    "Slack configuration required when strategy is 'slack'",
+                ));
             }
             _ => {} // No additional validation needed
         }
@@ -480,6 +995,36 @@ impl ReleaseRegentConfig {
             }
         }
 
+        // Validate changelog sections
+        if self.changelog.other_section_title.trim().is_empty() {
+            return Err(CoreError::config(
+                "changelog.other_section_title cannot be empty",
+            ));
+        }
+        if crate::changelog::format_changelog_date(
+            &self.changelog.date_format,
+            chrono::DateTime::default(),
+        )
+        .is_err()
+        {
+            return Err(CoreError::config(format!(
+                "changelog.date_format '{}' is not a valid strftime format",
+                self.changelog.date_format
+            )));
+        }
+        for (i, section) in self.changelog.sections.iter().enumerate() {
+            if section.types.iter().all(|t| t.trim().is_empty()) {
+                return Err(CoreError::config(format!(
+                    "changelog.sections[{i}].types must list at least one commit type"
+                )));
+            }
+            if !section.hidden && section.title.trim().is_empty() {
+                return Err(CoreError::config(format!(
+                    "changelog.sections[{i}].title cannot be empty unless the section is hidden"
+                )));
+            }
+        }
+
         debug!("Configuration validation passed");
         Ok(())
     }
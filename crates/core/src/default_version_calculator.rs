@@ -17,20 +17,26 @@
 //! [`ReleaseRegentProcessor`]: crate::ReleaseRegentProcessor
 
 use crate::{
+    clock::{system_clock, Clock},
+    commit_filter::{bump_candidates, CommitCandidate, CommitFilter},
+    monorepo,
     traits::version_calculator::{
-        CalculationOptions, ChangelogEntry, CommitAnalysis, ValidationRules, VersionBump,
-        VersionCalculationResult, VersionCalculator as VersionCalculatorTrait, VersionContext,
-        VersioningStrategy,
+        BumpReason, CalculationOptions, ChangelogEntry, CommitAnalysis, ValidationRules,
+        VersionBump, VersionCalculationResult, VersionCalculator as VersionCalculatorTrait,
+        VersionContext, VersioningStrategy,
     },
     versioning::{
-        apply_semver_bump, ConventionalCommit, SemanticVersion,
+        apply_no_conventional_commits_policy, apply_semver_bump, cancelled_by_reverts,
+        mark_breaking_scopes, render_build_metadata, ConventionalCommit, SemanticVersion,
         VersionCalculator as ConventionalCalculator,
     },
     CoreError, CoreResult,
 };
 use async_trait::async_trait;
-use chrono::Utc;
-use std::collections::HashMap;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
 use tracing::debug;
 
 #[cfg(test)]
@@ -50,39 +56,54 @@ mod tests;
 ///
 /// All methods that analyse commit history require `git` to be available on
 /// `$PATH` and the working directory to be inside a git repository.
-#[derive(Debug, Default)]
-pub struct DefaultVersionCalculator;
+#[derive(Debug)]
+pub struct DefaultVersionCalculator {
+    clock: Arc<dyn Clock>,
+}
+
+impl Default for DefaultVersionCalculator {
+    fn default() -> Self {
+        Self {
+            clock: system_clock(),
+        }
+    }
+}
 
 /// Private helpers used within the trait implementation and tests.
 impl DefaultVersionCalculator {
     /// Create a new default version calculator.
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Read the current time from `clock` instead of the system clock.
+    ///
+    /// The time stamps build metadata and dates commits whose own date is
+    /// unknown.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
     }
 
     /// Fetch commit history from local git between two refs.
     ///
-    /// Returns `(sha, subject)` pairs for every commit in `base..head` (or
-    /// the latest 100 commits when `base_ref` is `None`).
+    /// Returns `(sha, subject)` pairs for every commit in the range given by
+    /// [`log_range`](Self::log_range) that `filter` keeps.
     #[allow(clippy::unused_async)] // declared async for interface uniformity; no await needed (uses blocking Command)
     async fn fetch_git_commits(
         base_ref: Option<&str>,
         head_ref: &str,
+        first_release: bool,
+        filter: &CommitFilter,
     ) -> CoreResult<Vec<(String, String)>> {
         use std::process::Command;
 
         let mut cmd = Command::new("git");
-        cmd.arg("log").arg("--format=%H %s");
-
-        match base_ref {
-            Some(base) => {
-                cmd.arg(format!("{base}..{head_ref}"));
-            }
-            None => {
-                cmd.arg(head_ref).arg("-n").arg("100");
-            }
-        }
+        cmd.arg("log")
+            .arg("--format=%H%x1f%P%x1f%an%x1f%ae%x1f%B%x1e")
+            .args(Self::log_range(base_ref, head_ref, first_release));
 
         let output = cmd
             .output()
@@ -93,16 +114,7 @@ impl DefaultVersionCalculator {
             return Err(CoreError::versioning(format!("git log failed: {stderr}")));
         }
 
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let commits: Vec<(String, String)> = stdout
-            .lines()
-            .filter_map(|line| {
-                let mut iter = line.splitn(2, ' ');
-                let sha = iter.next()?.to_string();
-                let subject = iter.next()?.to_string();
-                Some((sha, subject))
-            })
-            .collect();
+        let commits = Self::parse_git_log(&String::from_utf8_lossy(&output.stdout), filter);
 
         debug!(
             commit_count = commits.len(),
@@ -111,22 +123,77 @@ impl DefaultVersionCalculator {
         Ok(commits)
     }
 
-    /// Convert a parsed [`ConventionalCommit`] into a trait-layer [`CommitAnalysis`].
-    fn to_commit_analysis(commit: ConventionalCommit) -> CommitAnalysis {
-        let version_bump = if commit.breaking_change {
-            VersionBump::Major
-        } else if commit.commit_type == "feat" {
-            VersionBump::Minor
-        } else if commit.commit_type == "fix" {
-            VersionBump::Patch
-        } else {
-            VersionBump::None
+    /// The `git log` revision arguments for the commits to analyse.
+    ///
+    /// With a base ref this is `base..head`. Without one, a first release
+    /// covers the full history of `head`, and otherwise only the latest 100
+    /// commits are read.
+    fn log_range(base_ref: Option<&str>, head_ref: &str, first_release: bool) -> Vec<String> {
+        match base_ref {
+            Some(base) => vec![format!("{base}..{head_ref}")],
+            None if first_release => vec![head_ref.to_string()],
+            None => vec![head_ref.to_string(), "-n".to_string(), "100".to_string()],
+        }
+    }
+
+    /// The configured version of a first release, with the reason it was
+    /// chosen, or `None` when the calculated version applies.
+    fn initial_release_version(
+        initial_version: Option<&SemanticVersion>,
+        first_release: bool,
+        bump: &VersionBump,
+        build: Option<String>,
+    ) -> Option<(SemanticVersion, BumpReason)> {
+        let initial = initial_version.filter(|_| first_release)?;
+        let reason = BumpReason {
+            bump: bump.clone(),
+            rule: format!("configured initial version {initial} for the first release"),
+            commits: Vec::new(),
         };
+        Some((
+            SemanticVersion {
+                build,
+                ..initial.clone()
+            },
+            reason,
+        ))
+    }
+
+    /// Parse `git log --format=%H%x1f%P%x1f%an%x1f%ae%x1f%B%x1e` output into
+    /// `(sha, subject)` pairs, dropping the commits `filter` excludes.
+    fn parse_git_log(output: &str, filter: &CommitFilter) -> Vec<(String, String)> {
+        output
+            .split('\x1e')
+            .filter_map(|record| {
+                let mut fields = record.trim_start_matches('\n').splitn(5, '\x1f');
+                let sha = fields.next()?;
+                let parents = fields.next()?;
+                let name = fields.next()?;
+                let email = fields.next()?;
+                let message = fields.next()?.trim();
+
+                let keep = filter.keep(&CommitCandidate {
+                    sha,
+                    message,
+                    author_identities: &[name, email],
+                    parent_count: parents.split_whitespace().count(),
+                });
+                keep.then(|| {
+                    let subject = message.lines().next().unwrap_or_default();
+                    (sha.to_string(), subject.to_string())
+                })
+            })
+            .collect()
+    }
+
+    /// Convert a parsed [`ConventionalCommit`] into a trait-layer [`CommitAnalysis`].
+    fn to_commit_analysis(&self, commit: ConventionalCommit) -> CommitAnalysis {
+        let version_bump = commit.version_bump();
 
         CommitAnalysis {
             author: String::new(),
             commit_type: Some(commit.commit_type),
-            date: Utc::now(),
+            date: self.clock.now(),
             is_breaking: commit.breaking_change,
             message: commit.message,
             metadata: HashMap::new(),
@@ -189,18 +256,114 @@ impl DefaultVersionCalculator {
             next_version,
             strategy,
             version_bump: bump,
+            bump_reason: None,
+            packages: BTreeMap::new(),
+        }
+    }
+
+    /// Render the build metadata template from the calculation options,
+    /// resolving `head_ref` with `git rev-parse` when the template refers to
+    /// the commit SHA.
+    #[allow(clippy::unused_async)] // declared async for interface uniformity; no await needed (uses blocking Command)
+    async fn build_metadata(
+        &self,
+        template: Option<&str>,
+        head_ref: &str,
+    ) -> CoreResult<Option<String>> {
+        use std::process::Command;
+
+        let Some(template) = template else {
+            return Ok(None);
+        };
+
+        let sha = if template.contains("sha}") {
+            let output = Command::new("git")
+                .arg("rev-parse")
+                .arg(head_ref)
+                .output()
+                .map_err(|e| {
+                    CoreError::versioning(format!("Failed to execute git rev-parse: {e}"))
+                })?;
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(CoreError::versioning(format!(
+                    "git rev-parse failed for {head_ref}: {stderr}"
+                )));
+            }
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        } else {
+            head_ref.to_string()
+        };
+
+        render_build_metadata(template, &sha, self.clock.now()).map(Some)
+    }
+
+    /// Fetch the files changed by each commit between two refs from local git.
+    ///
+    /// Covers the same commits as [`fetch_git_commits`](Self::fetch_git_commits)
+    /// and returns the changed paths keyed by commit SHA.
+    #[allow(clippy::unused_async)] // declared async for interface uniformity; no await needed (uses blocking Command)
+    async fn fetch_changed_files(
+        base_ref: Option<&str>,
+        head_ref: &str,
+        first_release: bool,
+    ) -> CoreResult<HashMap<String, Vec<String>>> {
+        use std::process::Command;
+
+        let mut cmd = Command::new("git");
+        cmd.arg("log")
+            .arg("--format=%x1e%H")
+            .arg("--name-only")
+            .args(Self::log_range(base_ref, head_ref, first_release));
+
+        let output = cmd
+            .output()
+            .map_err(|e| CoreError::versioning(format!("Failed to execute git log: {e}")))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(CoreError::versioning(format!("git log failed: {stderr}")));
         }
+
+        Ok(Self::parse_changed_files(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
+    /// Parse `git log --format=%x1e%H --name-only` output into changed paths
+    /// keyed by commit SHA.
+    fn parse_changed_files(output: &str) -> HashMap<String, Vec<String>> {
+        output
+            .split('\x1e')
+            .filter_map(|record| {
+                let mut lines = record.lines();
+                let sha = lines.next()?.trim();
+                if sha.is_empty() {
+                    return None;
+                }
+                let files = lines
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                Some((sha.to_string(), files))
+            })
+            .collect()
     }
 }
 
 #[async_trait]
 impl VersionCalculatorTrait for DefaultVersionCalculator {
     /// Calculate the next version by running `git log` and applying conventional-commit rules.
+    ///
+    /// Without a current version this is the first release: every commit
+    /// reachable from `head_ref` is analysed, and the version is
+    /// `options.initial_version` when set.
     async fn calculate_version(
         &self,
         context: VersionContext,
         strategy: VersioningStrategy,
-        _options: CalculationOptions,
+        options: CalculationOptions,
     ) -> CoreResult<VersionCalculationResult> {
         debug!(
             owner = %context.owner,
@@ -210,17 +373,41 @@ impl VersionCalculatorTrait for DefaultVersionCalculator {
             "Calculating version",
         );
 
-        let raw_commits =
-            Self::fetch_git_commits(context.base_ref.as_deref(), &context.head_ref).await?;
+        let filter = CommitFilter::new(&options.exclusions)?;
+        let first_release = context.current_version.is_none();
+        let raw_commits = Self::fetch_git_commits(
+            context.base_ref.as_deref(),
+            &context.head_ref,
+            first_release,
+            &filter,
+        )
+        .await?;
 
-        let conventional = ConventionalCalculator::parse_conventional_commits(&raw_commits);
+        let mut conventional = ConventionalCalculator::parse_conventional_commits(&raw_commits);
+        mark_breaking_scopes(&mut conventional, &options.breaking_scopes);
+        let cancelled = cancelled_by_reverts(&conventional);
 
         let analyses: Vec<CommitAnalysis> = conventional
             .into_iter()
-            .map(Self::to_commit_analysis)
+            .enumerate()
+            .map(|(index, c)| {
+                let mut analysis = self.to_commit_analysis(c);
+                if cancelled.contains(&index) {
+                    analysis.version_bump = VersionBump::None;
+                }
+                analysis
+            })
             .collect();
 
-        let bump = Self::highest_bump(&analyses);
+        let candidates = bump_candidates(&analyses, &options.bump_scopes);
+        let bump = Self::highest_bump(&candidates);
+        // `git log` lists the newest commit first.
+        let (bump, mut bump_reason) = apply_no_conventional_commits_policy(
+            options.no_conventional_commits,
+            &raw_commits,
+            bump.clone(),
+            BumpReason::from_analyses(&bump, candidates.iter().rev()),
+        )?;
 
         let current = context.current_version.clone().unwrap_or(SemanticVersion {
             major: 0,
@@ -230,15 +417,50 @@ impl VersionCalculatorTrait for DefaultVersionCalculator {
             build: None,
         });
 
-        let next_version = self.apply_version_bump(current, bump.clone(), None, None)?;
+        let build = self
+            .build_metadata(options.build_metadata.as_deref(), &context.head_ref)
+            .await?;
+        let next_version = match Self::initial_release_version(
+            options.initial_version.as_ref(),
+            first_release,
+            &bump,
+            build.clone(),
+        ) {
+            Some((initial, reason)) => {
+                bump_reason = reason;
+                initial
+            }
+            None => self.apply_version_bump(current, bump.clone(), None, build.clone())?,
+        };
 
-        Ok(Self::build_result(
-            &context,
-            strategy,
-            analyses,
-            next_version,
-            bump,
-        ))
+        let packages = match &strategy {
+            VersioningStrategy::Monorepo { packages } => {
+                let changed_files = Self::fetch_changed_files(
+                    context.base_ref.as_deref(),
+                    &context.head_ref,
+                    first_release,
+                )
+                .await?;
+                monorepo::calculate_package_versions(
+                    packages,
+                    &analyses,
+                    &changed_files,
+                    &context.package_versions,
+                )?
+            }
+            _ => BTreeMap::new(),
+        };
+
+        let mut result = Self::build_result(&context, strategy, analyses, next_version, bump);
+        result.build_metadata = build;
+        result.packages = packages;
+        result.bump_reason = Some(bump_reason);
+        if let Some(minimum) = &options.minimum_version {
+            if result.apply_minimum_version(minimum) {
+                debug!(minimum = %minimum, "Raised calculated version to the minimum version");
+            }
+        }
+        Ok(result)
     }
 
     /// Analyse individual commits identified by their SHAs.
@@ -276,7 +498,7 @@ impl VersionCalculatorTrait for DefaultVersionCalculator {
                 let raw = vec![(commit_sha.to_string(), subject.to_string())];
                 let parsed = ConventionalCalculator::parse_conventional_commits(&raw);
                 for c in parsed {
-                    analyses.push(Self::to_commit_analysis(c));
+                    analyses.push(self.to_commit_analysis(c));
                 }
             }
         }
@@ -348,6 +570,10 @@ impl VersionCalculatorTrait for DefaultVersionCalculator {
             "conventional_commits".to_string(),
             "Semantic versioning derived from conventional commits".to_string(),
         );
+        map.insert(
+            "monorepo".to_string(),
+            "Conventional commits versioning per package, by changed paths".to_string(),
+        );
         map
     }
 
@@ -385,7 +611,10 @@ impl VersionCalculatorTrait for DefaultVersionCalculator {
 
         let raw = vec![("unknown".to_string(), commit_message.to_string())];
         let parsed = ConventionalCalculator::parse_conventional_commits(&raw);
-        Ok(parsed.into_iter().next().map(Self::to_commit_analysis))
+        Ok(parsed
+            .into_iter()
+            .next()
+            .map(|c| self.to_commit_analysis(c)))
     }
 
     /// Apply a version bump to an existing version.
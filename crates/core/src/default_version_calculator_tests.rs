@@ -1,8 +1,6 @@
 use super::*;
 use crate::{
-    traits::version_calculator::{
-        CalculationOptions, CommitAnalysis, VersionBump, VersionContext, VersioningStrategy,
-    },
+    traits::version_calculator::{CommitAnalysis, VersionBump, VersionContext, VersioningStrategy},
     versioning::SemanticVersion,
 };
 use std::collections::HashMap;
@@ -22,6 +20,7 @@ fn test_context() -> VersionContext {
         owner: "test-owner".to_string(),
         repo: "test-repo".to_string(),
         target_branch: "main".to_string(),
+        package_versions: HashMap::new(),
     }
 }
 
@@ -128,9 +127,11 @@ fn to_commit_analysis_maps_feat_to_minor_bump() {
         description: "add OAuth".to_string(),
         breaking_change: false,
         message: "feat(auth): add OAuth".to_string(),
+        body: None,
         sha: "deadbeef".to_string(),
+        author: None,
     };
-    let analysis = DefaultVersionCalculator::to_commit_analysis(commit);
+    let analysis = DefaultVersionCalculator::new().to_commit_analysis(commit);
     assert_eq!(analysis.version_bump, VersionBump::Minor);
     assert!(!analysis.is_breaking);
     assert_eq!(analysis.scope, Some("auth".to_string()));
@@ -145,13 +146,48 @@ fn to_commit_analysis_maps_breaking_change_to_major_bump() {
         description: "remove deprecated API".to_string(),
         breaking_change: true,
         message: "feat!: remove deprecated API".to_string(),
+        body: None,
         sha: "cafebabe".to_string(),
+        author: None,
     };
-    let analysis = DefaultVersionCalculator::to_commit_analysis(commit);
+    let analysis = DefaultVersionCalculator::new().to_commit_analysis(commit);
     assert_eq!(analysis.version_bump, VersionBump::Major);
     assert!(analysis.is_breaking);
 }
 
+/// A clock stopped at a fixed time.
+#[derive(Debug)]
+struct FixedClock(chrono::DateTime<chrono::Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> chrono::DateTime<chrono::Utc> {
+        self.0
+    }
+}
+
+#[test]
+fn to_commit_analysis_dates_commit_from_the_clock() {
+    use crate::versioning::ConventionalCommit;
+    let at = chrono::DateTime::parse_from_rfc3339("2024-05-01T12:30:00Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+    let calc = DefaultVersionCalculator::new().with_clock(Arc::new(FixedClock(at)));
+    let commit = ConventionalCommit {
+        commit_type: "fix".to_string(),
+        scope: None,
+        description: "handle empty input".to_string(),
+        breaking_change: false,
+        message: "fix: handle empty input".to_string(),
+        body: None,
+        sha: "f00d".to_string(),
+        author: None,
+    };
+
+    let analysis = calc.to_commit_analysis(commit);
+
+    assert_eq!(analysis.date, at);
+}
+
 #[test]
 fn to_commit_analysis_maps_fix_to_patch_bump() {
     use crate::versioning::ConventionalCommit;
@@ -161,9 +197,11 @@ fn to_commit_analysis_maps_fix_to_patch_bump() {
         description: "resolve null pointer".to_string(),
         breaking_change: false,
         message: "fix: resolve null pointer".to_string(),
+        body: None,
         sha: "1234567".to_string(),
+        author: None,
     };
-    let analysis = DefaultVersionCalculator::to_commit_analysis(commit);
+    let analysis = DefaultVersionCalculator::new().to_commit_analysis(commit);
     assert_eq!(analysis.version_bump, VersionBump::Patch);
 }
 
@@ -174,6 +212,7 @@ fn to_commit_analysis_maps_fix_to_patch_bump() {
 #[test]
 fn build_result_excludes_none_bump_commits_from_changelog() {
     use crate::versioning::ConventionalCommit;
+    let calc = DefaultVersionCalculator::new();
     let ctx = test_context();
     let strategy = VersioningStrategy::ConventionalCommits {
         custom_types: HashMap::new(),
@@ -185,7 +224,9 @@ fn build_result_excludes_none_bump_commits_from_changelog() {
         description: "new feature".to_string(),
         breaking_change: false,
         message: "feat: new feature".to_string(),
+        body: None,
         sha: "aaa".to_string(),
+        author: None,
     };
     let chore_commit = ConventionalCommit {
         commit_type: "chore".to_string(),
@@ -193,11 +234,13 @@ fn build_result_excludes_none_bump_commits_from_changelog() {
         description: "update deps".to_string(),
         breaking_change: false,
         message: "chore: update deps".to_string(),
+        body: None,
         sha: "bbb".to_string(),
+        author: None,
     };
     let analyses = vec![
-        DefaultVersionCalculator::to_commit_analysis(feat_commit),
-        DefaultVersionCalculator::to_commit_analysis(chore_commit),
+        calc.to_commit_analysis(feat_commit),
+        calc.to_commit_analysis(chore_commit),
     ];
     let next = SemanticVersion {
         major: 1,
@@ -352,3 +395,129 @@ fn parse_conventional_commit_identifies_breaking_change() {
     assert_eq!(analysis.version_bump, VersionBump::Major);
     assert!(analysis.is_breaking);
 }
+
+// ──────────────────────────────────────────────────────────────
+// parse_changed_files
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn parse_changed_files_groups_paths_by_commit() {
+    let output = "\x1eaaa111\n\nservices/api/src/lib.rs\nservices/web/index.ts\n\
+                  \x1ebbb222\n\nREADME.md\n\
+                  \x1eccc333\n";
+
+    let files = DefaultVersionCalculator::parse_changed_files(output);
+
+    assert_eq!(
+        files["aaa111"],
+        vec!["services/api/src/lib.rs", "services/web/index.ts"]
+    );
+    assert_eq!(files["bbb222"], vec!["README.md"]);
+    assert!(files["ccc333"].is_empty());
+    assert_eq!(files.len(), 3);
+}
+
+// ──────────────────────────────────────────────────────────────
+// parse_git_log
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn parse_git_log_returns_subjects_of_kept_commits() {
+    use crate::{commit_filter::CommitFilter, config::CommitExclusionConfig};
+
+    let output = "aaa111\x1fp1\x1fDev\x1fdev@example.com\x1ffeat: add parser\n\nDetails.\n\x1e\n\
+                  bbb222\x1fp1\x1fDev\x1fdev@example.com\x1ffix: typo\n\n[skip release]\n\x1e\n\
+                  ccc333\x1fp1\x1fRenovate Bot\x1fbot@renovateapp.com\x1fchore(deps): update\n\x1e\n";
+    let filter = CommitFilter::new(&CommitExclusionConfig {
+        denied_authors: vec!["bot@renovateapp.com".to_string()],
+        ..CommitExclusionConfig::default()
+    })
+    .unwrap();
+
+    let commits = DefaultVersionCalculator::parse_git_log(output, &filter);
+
+    assert_eq!(
+        commits,
+        vec![("aaa111".to_string(), "feat: add parser".to_string())]
+    );
+}
+
+#[test]
+fn parse_git_log_excludes_merge_commits_unless_disabled() {
+    use crate::{commit_filter::CommitFilter, config::CommitExclusionConfig};
+
+    let output = "aaa111\x1fp1\x1fDev\x1fdev@example.com\x1ffix: typo\n\x1e\n\
+                  bbb222\x1fp1 p2\x1fDev\x1fdev@example.com\x1ffeat: sync main\n\x1e\n";
+    let ignoring = CommitFilter::new(&CommitExclusionConfig::default()).unwrap();
+    let keeping = CommitFilter::new(&CommitExclusionConfig {
+        ignore_merge_commits: false,
+        ..CommitExclusionConfig::default()
+    })
+    .unwrap();
+
+    let shas = |filter: &CommitFilter| -> Vec<String> {
+        DefaultVersionCalculator::parse_git_log(output, filter)
+            .into_iter()
+            .map(|(sha, _)| sha)
+            .collect()
+    };
+
+    assert_eq!(shas(&ignoring), vec!["aaa111"]);
+    assert_eq!(shas(&keeping), vec!["aaa111", "bbb222"]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// First release
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn log_range_reads_full_history_for_a_first_release() {
+    assert_eq!(
+        DefaultVersionCalculator::log_range(Some("v1.0.0"), "HEAD", false),
+        vec!["v1.0.0..HEAD"]
+    );
+    assert_eq!(
+        DefaultVersionCalculator::log_range(None, "HEAD", true),
+        vec!["HEAD"]
+    );
+    assert_eq!(
+        DefaultVersionCalculator::log_range(None, "HEAD", false),
+        vec!["HEAD", "-n", "100"]
+    );
+}
+
+#[test]
+fn initial_release_version_applies_only_to_a_first_release() {
+    let initial = SemanticVersion {
+        major: 1,
+        minor: 0,
+        patch: 0,
+        prerelease: None,
+        build: None,
+    };
+
+    let (version, reason) = DefaultVersionCalculator::initial_release_version(
+        Some(&initial),
+        true,
+        &VersionBump::Minor,
+        Some("sha.abc1234".to_string()),
+    )
+    .expect("first release should use the initial version");
+
+    assert_eq!(version.to_string(), "1.0.0+sha.abc1234");
+    assert!(reason.rule.contains("initial version 1.0.0"), "{reason:?}");
+    assert!(DefaultVersionCalculator::initial_release_version(
+        Some(&initial),
+        false,
+        &VersionBump::Minor,
+        None
+    )
+    .is_none());
+    assert!(DefaultVersionCalculator::initial_release_version(
+        None,
+        true,
+        &VersionBump::Minor,
+        None
+    )
+    .is_none());
+}
@@ -0,0 +1,895 @@
+//! Dry-run [`GitHubOperations`] wrapper.
+//!
+//! [`DryRunGitHubOperations`] delegates every read to the wrapped client so
+//! that version calculation and changelog generation see real repository
+//! state, but intercepts every mutating call.  Intercepted calls are logged,
+//! recorded as [`DryRunAction`]s and answered with a synthesized success value
+//! so that the processing pipeline runs to completion without changing
+//! anything on GitHub.
+//!
+//! Used by `rr run --dry-run`; any other binary can wrap its client the same
+//! way and inspect the [`DryRunLog`] afterwards:
+//!
+//! ```rust,ignore
+//! let github = DryRunGitHubOperations::new(github_client);
+//! let log = github.log();
+//! let processor = ReleaseRegentProcessor::new(github, config_provider, version_calculator);
+//! // ... process events ...
+//! for action in log.actions() {
+//!     println!("{action}");
+//! }
+//! ```
+//!
+//! Pass the same log to
+//! [`ReleaseRegentProcessor::with_dry_run_log`](crate::ReleaseRegentProcessor::with_dry_run_log)
+//! to also record the calculated version and changelog; [`DryRunLog::report`]
+//! then combines both into a machine-readable [`DryRunReport`].
+
+use crate::{
+    traits::{
+        event_source::ProcessingEvent,
+        git_operations::{
+            GetCommitsOptions, GitCommit, GitOperations, GitRepository, GitTag, ListTagsOptions,
+        },
+        github_operations::{
+            BranchUpsert, CollaboratorPermission, CreateCommitStatusParams,
+            CreatePullRequestParams, CreateReleaseParams, FileUpdate, GitHubOperations, GitUser,
+            IssueComment, Label, LabelMode, PullRequest, PullRequestBranch, Release, Repository,
+            SignedTagParams, Tag, UpdateReleaseParams,
+        },
+        version_calculator::{VersionBump, VersionCalculationResult},
+    },
+    CoreResult,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use tracing::info;
+
+#[cfg(test)]
+#[path = "dry_run_tests.rs"]
+mod tests;
+
+/// A mutating GitHub operation that was intercepted in dry-run mode.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DryRunAction {
+    /// A pull request would have been opened.
+    CreatePullRequest {
+        repository: String,
+        head: String,
+        base: String,
+        title: String,
+        body: Option<String>,
+    },
+    /// A pull request would have been updated.
+    UpdatePullRequest {
+        repository: String,
+        number: u64,
+        title: Option<String>,
+        body: Option<String>,
+        state: Option<String>,
+    },
+    /// A release would have been published.
+    CreateRelease {
+        repository: String,
+        tag_name: String,
+        name: Option<String>,
+        body: Option<String>,
+    },
+    /// Any other mutation, described in one line.
+    Other {
+        repository: String,
+        description: String,
+    },
+}
+
+impl std::fmt::Display for DryRunAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CreatePullRequest {
+                repository,
+                head,
+                base,
+                title,
+                ..
+            } => write!(
+                f,
+                "{repository}: create pull request '{title}' ({head} → {base})"
+            ),
+            Self::UpdatePullRequest {
+                repository, number, ..
+            } => write!(f, "{repository}: update pull request #{number}"),
+            Self::CreateRelease {
+                repository,
+                tag_name,
+                ..
+            } => write!(f, "{repository}: create release {tag_name}"),
+            Self::Other {
+                repository,
+                description,
+            } => write!(f, "{repository}: {description}"),
+        }
+    }
+}
+
+/// The version and changelog a dry run calculated for a merged pull request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DryRunCalculation {
+    /// The latest released version, if any.
+    pub current_version: Option<String>,
+    /// The calculated next version.
+    pub next_version: String,
+    /// The bump applied: `major`, `minor`, `patch` or `none`.
+    pub bump: String,
+    /// Why this bump was chosen, in one line.
+    pub bump_reason: String,
+    /// The rendered changelog.
+    pub changelog: String,
+}
+
+impl DryRunCalculation {
+    /// Summarize `result` and the changelog rendered from it.
+    #[must_use]
+    pub fn new(result: &VersionCalculationResult, changelog: &str) -> Self {
+        Self {
+            current_version: result.current_version.as_ref().map(ToString::to_string),
+            next_version: result.next_version.to_string(),
+            bump: result.version_bump.name().to_string(),
+            bump_reason: result
+                .bump_reason
+                .as_ref()
+                .map_or_else(|| bump_reason(result), ToString::to_string),
+            changelog: changelog.to_string(),
+        }
+    }
+}
+
+/// One-line explanation of the bump in `result`, naming the commits that
+/// required it, for calculators that do not set
+/// [`VersionCalculationResult::bump_reason`].
+fn bump_reason(result: &VersionCalculationResult) -> String {
+    if let Some(minimum) = result
+        .metadata
+        .get(VersionCalculationResult::MINIMUM_VERSION_APPLIED)
+    {
+        return format!("raised to the configured minimum version {minimum}");
+    }
+    if result.version_bump == VersionBump::None {
+        return "no version-bumping commits since the last release".to_string();
+    }
+
+    let subjects: Vec<String> = result
+        .analyzed_commits
+        .iter()
+        .filter(|c| c.version_bump == result.version_bump)
+        .map(|c| {
+            let sha: String = c.sha.chars().take(7).collect();
+            let subject = c.message.lines().next().unwrap_or_default();
+            format!("{sha} {subject}")
+        })
+        .collect();
+    if subjects.is_empty() {
+        return format!("{} bump", result.version_bump.name());
+    }
+    format!(
+        "{} bump required by {}",
+        result.version_bump.name(),
+        subjects.join("; ")
+    )
+}
+
+/// Machine-readable summary of everything a dry run would have done.
+///
+/// Built by [`DryRunLog::report`] once the event has been processed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DryRunReport {
+    /// The internal event type, e.g. `pull_request_merged`.
+    pub event_type: String,
+    /// The repository as `owner/name`.
+    pub repository: String,
+    /// The calculated version and changelog; `None` when the event did not
+    /// calculate a version, e.g. a merged release PR.
+    pub calculation: Option<DryRunCalculation>,
+    /// Why no release was prepared, e.g. because only `chore:` commits were
+    /// merged; `None` when the event went ahead.
+    pub no_release_reason: Option<String>,
+    /// The GitHub mutations that were intercepted, in call order.
+    pub actions: Vec<DryRunAction>,
+}
+
+/// Shared, append-only record of the mutations intercepted by a
+/// [`DryRunGitHubOperations`] and all of its scoped copies.
+#[derive(Debug, Clone, Default)]
+pub struct DryRunLog {
+    actions: Arc<Mutex<Vec<DryRunAction>>>,
+    calculation: Arc<Mutex<Option<DryRunCalculation>>>,
+    no_release_reason: Arc<Mutex<Option<String>>>,
+}
+
+impl DryRunLog {
+    /// Snapshot of the intercepted mutations in call order.
+    #[must_use]
+    pub fn actions(&self) -> Vec<DryRunAction> {
+        self.actions
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
+    /// The most recently recorded version calculation.
+    #[must_use]
+    pub fn calculation(&self) -> Option<DryRunCalculation> {
+        self.calculation
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Why the most recently processed event did not prepare a release.
+    #[must_use]
+    pub fn no_release_reason(&self) -> Option<String> {
+        self.no_release_reason
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Record that processing an event stopped because no release is needed.
+    pub fn record_no_release(&self, reason: &str) {
+        info!(reason, "Dry run: no release needed");
+        *self
+            .no_release_reason
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(reason.to_string());
+    }
+
+    /// Record the version and changelog calculated while processing an event.
+    pub fn record_calculation(&self, calculation: DryRunCalculation) {
+        info!(
+            next_version = %calculation.next_version,
+            bump = %calculation.bump,
+            "Dry run: calculated version"
+        );
+        *self
+            .calculation
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(calculation);
+    }
+
+    /// Summarize what processing `event` would have done.
+    #[must_use]
+    pub fn report(&self, event: &ProcessingEvent) -> DryRunReport {
+        DryRunReport {
+            event_type: event.event_type.to_string(),
+            repository: format!("{}/{}", event.repository.owner, event.repository.name),
+            calculation: self.calculation(),
+            no_release_reason: self.no_release_reason(),
+            actions: self.actions(),
+        }
+    }
+
+    fn record(&self, action: DryRunAction) {
+        info!(action = %action, "Dry run: skipping GitHub mutation");
+        self.actions
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(action);
+    }
+}
+
+/// [`GitHubOperations`] wrapper that forwards reads and records writes.
+#[derive(Debug, Clone)]
+pub struct DryRunGitHubOperations<G> {
+    inner: G,
+    log: DryRunLog,
+}
+
+impl<G> DryRunGitHubOperations<G> {
+    /// Wrap `inner` so that its mutating operations are never executed.
+    #[must_use]
+    pub fn new(inner: G) -> Self {
+        Self {
+            inner,
+            log: DryRunLog::default(),
+        }
+    }
+
+    /// The log of intercepted mutations, shared with every scoped copy.
+    #[must_use]
+    pub fn log(&self) -> DryRunLog {
+        self.log.clone()
+    }
+
+    fn record_other(&self, owner: &str, repo: &str, description: String) {
+        self.log.record(DryRunAction::Other {
+            repository: format!("{owner}/{repo}"),
+            description,
+        });
+    }
+}
+
+fn synthetic_user() -> GitUser {
+    GitUser {
+        name: "release-regent (dry run)".to_string(),
+        email: String::new(),
+        login: None,
+    }
+}
+
+fn synthetic_branch(owner: &str, repo: &str, ref_name: &str) -> PullRequestBranch {
+    PullRequestBranch {
+        ref_name: ref_name.to_string(),
+        repo: Repository {
+            clone_url: format!("https://github.com/{owner}/{repo}.git"),
+            default_branch: String::new(),
+            description: None,
+            full_name: format!("{owner}/{repo}"),
+            homepage: None,
+            id: 0,
+            name: repo.to_string(),
+            owner: owner.to_string(),
+            private: false,
+            ssh_url: format!("git@github.com:{owner}/{repo}.git"),
+        },
+        sha: String::new(),
+    }
+}
+
+#[async_trait]
+impl<G: GitOperations> GitOperations for DryRunGitHubOperations<G> {
+    async fn get_commits_between(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+        options: GetCommitsOptions,
+    ) -> CoreResult<Vec<GitCommit>> {
+        self.inner
+            .get_commits_between(owner, repo, base, head, options)
+            .await
+    }
+
+    async fn list_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        head: &str,
+        options: GetCommitsOptions,
+    ) -> CoreResult<Vec<GitCommit>> {
+        self.inner.list_commits(owner, repo, head, options).await
+    }
+
+    async fn get_commit(&self, owner: &str, repo: &str, commit_sha: &str) -> CoreResult<GitCommit> {
+        self.inner.get_commit(owner, repo, commit_sha).await
+    }
+
+    async fn list_tags(
+        &self,
+        owner: &str,
+        repo: &str,
+        options: ListTagsOptions,
+    ) -> CoreResult<Vec<GitTag>> {
+        self.inner.list_tags(owner, repo, options).await
+    }
+
+    async fn get_tag(&self, owner: &str, repo: &str, tag_name: &str) -> CoreResult<GitTag> {
+        self.inner.get_tag(owner, repo, tag_name).await
+    }
+
+    async fn tag_exists(&self, owner: &str, repo: &str, tag_name: &str) -> CoreResult<bool> {
+        self.inner.tag_exists(owner, repo, tag_name).await
+    }
+
+    async fn get_head_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: Option<&str>,
+    ) -> CoreResult<GitCommit> {
+        self.inner.get_head_commit(owner, repo, branch).await
+    }
+
+    async fn get_repository_info(&self, owner: &str, repo: &str) -> CoreResult<GitRepository> {
+        self.inner.get_repository_info(owner, repo).await
+    }
+}
+
+#[async_trait]
+impl<G: GitHubOperations> GitHubOperations for DryRunGitHubOperations<G> {
+    async fn add_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        labels: &[&str],
+    ) -> CoreResult<()> {
+        self.record_other(
+            owner,
+            repo,
+            format!("add labels {labels:?} to #{issue_number}"),
+        );
+        Ok(())
+    }
+
+    async fn create_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch_name: &str,
+        sha: &str,
+    ) -> CoreResult<()> {
+        self.record_other(owner, repo, format!("create branch {branch_name} at {sha}"));
+        Ok(())
+    }
+
+    async fn create_commit_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        params: CreateCommitStatusParams,
+    ) -> CoreResult<()> {
+        self.record_other(
+            owner,
+            repo,
+            format!(
+                "set commit status {} on {sha}: {}",
+                params.context, params.description
+            ),
+        );
+        Ok(())
+    }
+
+    async fn create_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        body: &str,
+    ) -> CoreResult<()> {
+        self.record_other(
+            owner,
+            repo,
+            format!("comment on #{issue_number} ({} bytes)", body.len()),
+        );
+        Ok(())
+    }
+
+    async fn create_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: CreatePullRequestParams,
+    ) -> CoreResult<PullRequest> {
+        self.log.record(DryRunAction::CreatePullRequest {
+            repository: format!("{owner}/{repo}"),
+            head: params.head.clone(),
+            base: params.base.clone(),
+            title: params.title.clone(),
+            body: params.body.clone(),
+        });
+        let now = Utc::now();
+        Ok(PullRequest {
+            base: synthetic_branch(owner, repo, &params.base),
+            body: params.body,
+            created_at: now,
+            draft: params.draft,
+            head: synthetic_branch(owner, repo, &params.head),
+            merged_at: None,
+            number: 0,
+            state: "open".to_string(),
+            title: params.title,
+            updated_at: now,
+            user: synthetic_user(),
+        })
+    }
+
+    async fn create_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: CreateReleaseParams,
+    ) -> CoreResult<Release> {
+        self.log.record(DryRunAction::CreateRelease {
+            repository: format!("{owner}/{repo}"),
+            tag_name: params.tag_name.clone(),
+            name: params.name.clone(),
+            body: params.body.clone(),
+        });
+        let now = Utc::now();
+        Ok(Release {
+            author: synthetic_user(),
+            body: params.body,
+            created_at: now,
+            draft: params.draft,
+            id: 0,
+            name: params.name,
+            prerelease: params.prerelease,
+            published_at: (!params.draft).then_some(now),
+            tag_name: params.tag_name,
+            target_commitish: params.target_commitish.unwrap_or_default(),
+        })
+    }
+
+    async fn create_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag_name: &str,
+        commit_sha: &str,
+        message: Option<String>,
+        tagger: Option<GitUser>,
+    ) -> CoreResult<Tag> {
+        self.record_other(
+            owner,
+            repo,
+            format!("create tag {tag_name} at {commit_sha}"),
+        );
+        Ok(Tag {
+            commit_sha: commit_sha.to_string(),
+            created_at: Some(Utc::now()),
+            message,
+            name: tag_name.to_string(),
+            tagger,
+        })
+    }
+
+    async fn create_signed_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: SignedTagParams,
+    ) -> CoreResult<Tag> {
+        self.record_other(
+            owner,
+            repo,
+            format!(
+                "create signed tag {} at {}",
+                params.tag_name, params.commit_sha
+            ),
+        );
+        Ok(Tag {
+            commit_sha: params.commit_sha,
+            created_at: Some(params.tagged_at),
+            message: Some(params.message),
+            name: params.tag_name,
+            tagger: Some(params.tagger),
+        })
+    }
+
+    async fn delete_branch(&self, owner: &str, repo: &str, branch_name: &str) -> CoreResult<()> {
+        self.record_other(owner, repo, format!("delete branch {branch_name}"));
+        Ok(())
+    }
+
+    async fn force_update_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch_name: &str,
+        sha: &str,
+    ) -> CoreResult<()> {
+        self.record_other(
+            owner,
+            repo,
+            format!("force-update branch {branch_name} to {sha}"),
+        );
+        Ok(())
+    }
+
+    async fn upsert_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch_name: &str,
+        from_sha: &str,
+    ) -> CoreResult<BranchUpsert> {
+        self.record_other(
+            owner,
+            repo,
+            format!("create or fast-forward branch {branch_name} to {from_sha}"),
+        );
+        Ok(BranchUpsert::Created)
+    }
+
+    async fn get_collaborator_permission(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> CoreResult<CollaboratorPermission> {
+        self.inner
+            .get_collaborator_permission(owner, repo, username)
+            .await
+    }
+
+    async fn get_latest_release(&self, owner: &str, repo: &str) -> CoreResult<Option<Release>> {
+        self.inner.get_latest_release(owner, repo).await
+    }
+
+    async fn get_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> CoreResult<PullRequest> {
+        self.inner.get_pull_request(owner, repo, pr_number).await
+    }
+
+    async fn get_release_by_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+    ) -> CoreResult<Option<Release>> {
+        self.inner.get_release_by_tag(owner, repo, tag).await
+    }
+
+    async fn list_pr_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+    ) -> CoreResult<Vec<Label>> {
+        self.inner.list_pr_labels(owner, repo, issue_number).await
+    }
+
+    async fn list_issue_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+    ) -> CoreResult<Vec<IssueComment>> {
+        self.inner
+            .list_issue_comments(owner, repo, issue_number)
+            .await
+    }
+
+    async fn list_pull_request_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> CoreResult<Vec<GitCommit>> {
+        self.inner
+            .list_pull_request_commits(owner, repo, pr_number)
+            .await
+    }
+
+    async fn list_pull_requests(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: Option<&str>,
+        head: Option<&str>,
+        base: Option<&str>,
+        per_page: Option<u8>,
+        page: Option<u32>,
+    ) -> CoreResult<Vec<PullRequest>> {
+        self.inner
+            .list_pull_requests(owner, repo, state, head, base, per_page, page)
+            .await
+    }
+
+    async fn list_releases(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: Option<u8>,
+        page: Option<u32>,
+    ) -> CoreResult<Vec<Release>> {
+        self.inner.list_releases(owner, repo, per_page, page).await
+    }
+
+    async fn upload_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: u64,
+        name: &str,
+        _content_type: Option<&str>,
+        data: Vec<u8>,
+    ) -> CoreResult<String> {
+        self.record_other(
+            owner,
+            repo,
+            format!(
+                "upload asset {name} ({} bytes) to release {release_id}",
+                data.len()
+            ),
+        );
+        Ok(format!(
+            "https://github.com/{owner}/{repo}/releases/download/dry-run/{name}"
+        ))
+    }
+
+    async fn remove_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        label_name: &str,
+    ) -> CoreResult<()> {
+        self.record_other(
+            owner,
+            repo,
+            format!("remove label '{label_name}' from #{issue_number}"),
+        );
+        Ok(())
+    }
+
+    async fn resolve_ref(&self, owner: &str, repo: &str, git_ref: &str) -> CoreResult<String> {
+        self.inner.resolve_ref(owner, repo, git_ref).await
+    }
+
+    async fn set_pull_request_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        labels: &[&str],
+        mode: LabelMode,
+    ) -> CoreResult<()> {
+        let verb = match mode {
+            LabelMode::Add => "add",
+            LabelMode::Replace => "set",
+        };
+        self.record_other(
+            owner,
+            repo,
+            format!("{verb} labels {labels:?} on #{pr_number}"),
+        );
+        Ok(())
+    }
+
+    async fn search_pull_requests(
+        &self,
+        owner: &str,
+        repo: &str,
+        query: &str,
+    ) -> CoreResult<Vec<PullRequest>> {
+        self.inner.search_pull_requests(owner, repo, query).await
+    }
+
+    async fn update_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        title: Option<String>,
+        body: Option<String>,
+        state: Option<String>,
+    ) -> CoreResult<PullRequest> {
+        self.log.record(DryRunAction::UpdatePullRequest {
+            repository: format!("{owner}/{repo}"),
+            number: pr_number,
+            title: title.clone(),
+            body: body.clone(),
+            state: state.clone(),
+        });
+        // Answer with the current PR with the requested changes applied so
+        // callers see the state they asked for.
+        let mut pr = self.inner.get_pull_request(owner, repo, pr_number).await?;
+        if let Some(title) = title {
+            pr.title = title;
+        }
+        if body.is_some() {
+            pr.body = body;
+        }
+        if let Some(state) = state {
+            pr.state = state;
+        }
+        Ok(pr)
+    }
+
+    async fn update_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: u64,
+        body: &str,
+    ) -> CoreResult<()> {
+        self.record_other(
+            owner,
+            repo,
+            format!("update comment {comment_id} ({} bytes)", body.len()),
+        );
+        Ok(())
+    }
+
+    async fn update_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: u64,
+        params: UpdateReleaseParams,
+    ) -> CoreResult<Release> {
+        self.record_other(owner, repo, format!("update release {release_id}"));
+        let now = Utc::now();
+        Ok(Release {
+            author: synthetic_user(),
+            body: params.body,
+            created_at: now,
+            draft: params.draft.unwrap_or(false),
+            id: release_id,
+            name: params.name,
+            prerelease: params.prerelease.unwrap_or(false),
+            published_at: Some(now),
+            tag_name: String::new(),
+            target_commitish: String::new(),
+        })
+    }
+
+    async fn get_installation_id_for_repo(&self, owner: &str, repo: &str) -> CoreResult<u64> {
+        self.inner.get_installation_id_for_repo(owner, repo).await
+    }
+
+    async fn upsert_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        _commit_message: &str,
+        _content: &str,
+        branch: &str,
+    ) -> CoreResult<()> {
+        self.record_other(owner, repo, format!("write {path} on {branch}"));
+        Ok(())
+    }
+
+    fn scoped_to(&self, installation_id: u64) -> Self {
+        Self {
+            inner: self.inner.scoped_to(installation_id),
+            log: self.log.clone(),
+        }
+    }
+
+    async fn get_file_content(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        branch: &str,
+    ) -> CoreResult<Option<String>> {
+        self.inner.get_file_content(owner, repo, path, branch).await
+    }
+
+    async fn batch_commit_files(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        files: &[FileUpdate],
+        _message: &str,
+    ) -> CoreResult<()> {
+        self.record_other(
+            owner,
+            repo,
+            format!("commit {} file(s) to {branch}", files.len()),
+        );
+        Ok(())
+    }
+
+    async fn batch_commit_files_rebased(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        files: &[FileUpdate],
+        _message: &str,
+        parent_sha: &str,
+    ) -> CoreResult<()> {
+        self.record_other(
+            owner,
+            repo,
+            format!(
+                "commit {} file(s) to {branch} on top of {parent_sha}",
+                files.len()
+            ),
+        );
+        Ok(())
+    }
+}
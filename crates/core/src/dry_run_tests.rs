@@ -0,0 +1,692 @@
+use super::*;
+use crate::CoreError;
+
+// ── Recording inner client ───────────────────────────────────────────────────
+
+/// Inner client that records the name of every method invoked on it.
+///
+/// Every read fails with `NotFound` except `get_pull_request`, which returns
+/// a fixed open PR so that `update_pull_request` can be answered.
+#[derive(Clone, Default)]
+struct RecordingGitHub {
+    calls: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl RecordingGitHub {
+    fn calls(&self) -> Vec<&'static str> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large
+    fn called<T>(&self, method: &'static str) -> CoreResult<T> {
+        self.calls.lock().unwrap().push(method);
+        Err(CoreError::not_found("stub"))
+    }
+}
+
+/// Every `GitHubOperations`/`GitOperations` method that changes state on GitHub.
+const MUTATING_METHODS: &[&str] = &[
+    "add_labels",
+    "batch_commit_files",
+    "batch_commit_files_rebased",
+    "create_branch",
+    "create_issue_comment",
+    "create_pull_request",
+    "create_release",
+    "create_signed_tag",
+    "create_tag",
+    "delete_branch",
+    "force_update_branch",
+    "remove_label",
+    "set_pull_request_labels",
+    "update_issue_comment",
+    "update_pull_request",
+    "update_release",
+    "upload_release_asset",
+    "upsert_branch",
+    "upsert_file",
+];
+
+#[async_trait]
+impl GitOperations for RecordingGitHub {
+    async fn get_commits_between(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _base: &str,
+        _head: &str,
+        _options: GetCommitsOptions,
+    ) -> CoreResult<Vec<GitCommit>> {
+        self.called("get_commits_between")
+    }
+
+    async fn list_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _head: &str,
+        _options: GetCommitsOptions,
+    ) -> CoreResult<Vec<GitCommit>> {
+        self.called("list_commits")
+    }
+
+    async fn get_commit(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _commit_sha: &str,
+    ) -> CoreResult<GitCommit> {
+        self.called("get_commit")
+    }
+
+    async fn list_tags(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _options: ListTagsOptions,
+    ) -> CoreResult<Vec<GitTag>> {
+        self.called("list_tags")
+    }
+
+    async fn get_tag(&self, _owner: &str, _repo: &str, _tag_name: &str) -> CoreResult<GitTag> {
+        self.called("get_tag")
+    }
+
+    async fn tag_exists(&self, _owner: &str, _repo: &str, _tag_name: &str) -> CoreResult<bool> {
+        self.called("tag_exists")
+    }
+
+    async fn get_head_commit(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch: Option<&str>,
+    ) -> CoreResult<GitCommit> {
+        self.called("get_head_commit")
+    }
+
+    async fn get_repository_info(&self, _owner: &str, _repo: &str) -> CoreResult<GitRepository> {
+        self.called("get_repository_info")
+    }
+}
+
+#[async_trait]
+impl GitHubOperations for RecordingGitHub {
+    async fn add_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _issue_number: u64,
+        _labels: &[&str],
+    ) -> CoreResult<()> {
+        self.called("add_labels")
+    }
+
+    async fn batch_commit_files(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch: &str,
+        _files: &[FileUpdate],
+        _message: &str,
+    ) -> CoreResult<()> {
+        self.called("batch_commit_files")
+    }
+
+    async fn batch_commit_files_rebased(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch: &str,
+        _files: &[FileUpdate],
+        _message: &str,
+        _parent_sha: &str,
+    ) -> CoreResult<()> {
+        self.called("batch_commit_files_rebased")
+    }
+
+    async fn create_branch(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch_name: &str,
+        _sha: &str,
+    ) -> CoreResult<()> {
+        self.called("create_branch")
+    }
+
+    async fn create_commit_status(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _sha: &str,
+        _params: CreateCommitStatusParams,
+    ) -> CoreResult<()> {
+        self.called("create_commit_status")
+    }
+
+    async fn create_issue_comment(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _issue_number: u64,
+        _body: &str,
+    ) -> CoreResult<()> {
+        self.called("create_issue_comment")
+    }
+
+    async fn create_pull_request(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _params: CreatePullRequestParams,
+    ) -> CoreResult<PullRequest> {
+        self.called("create_pull_request")
+    }
+
+    async fn create_release(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _params: CreateReleaseParams,
+    ) -> CoreResult<Release> {
+        self.called("create_release")
+    }
+
+    async fn create_tag(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _tag_name: &str,
+        _commit_sha: &str,
+        _message: Option<String>,
+        _tagger: Option<GitUser>,
+    ) -> CoreResult<Tag> {
+        self.called("create_tag")
+    }
+
+    async fn create_signed_tag(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _params: crate::traits::github_operations::SignedTagParams,
+    ) -> CoreResult<Tag> {
+        self.called("create_signed_tag")
+    }
+
+    async fn delete_branch(&self, _owner: &str, _repo: &str, _branch_name: &str) -> CoreResult<()> {
+        self.called("delete_branch")
+    }
+
+    async fn force_update_branch(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch_name: &str,
+        _sha: &str,
+    ) -> CoreResult<()> {
+        self.called("force_update_branch")
+    }
+
+    async fn upsert_branch(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch_name: &str,
+        _from_sha: &str,
+    ) -> CoreResult<BranchUpsert> {
+        self.called("upsert_branch")
+    }
+
+    async fn get_collaborator_permission(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _username: &str,
+    ) -> CoreResult<CollaboratorPermission> {
+        self.called("get_collaborator_permission")
+    }
+
+    async fn get_file_content(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _path: &str,
+        _branch: &str,
+    ) -> CoreResult<Option<String>> {
+        self.called("get_file_content")
+    }
+
+    async fn get_installation_id_for_repo(&self, _owner: &str, _repo: &str) -> CoreResult<u64> {
+        self.called("get_installation_id_for_repo")
+    }
+
+    async fn get_latest_release(&self, _owner: &str, _repo: &str) -> CoreResult<Option<Release>> {
+        self.called("get_latest_release")
+    }
+
+    async fn get_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> CoreResult<PullRequest> {
+        self.calls.lock().unwrap().push("get_pull_request");
+        let now = Utc::now();
+        Ok(PullRequest {
+            base: synthetic_branch(owner, repo, "main"),
+            body: Some("original body".to_string()),
+            created_at: now,
+            draft: false,
+            head: synthetic_branch(owner, repo, "release/v1.0.0"),
+            merged_at: None,
+            number: pr_number,
+            state: "open".to_string(),
+            title: "chore(release): v1.0.0".to_string(),
+            updated_at: now,
+            user: synthetic_user(),
+        })
+    }
+
+    async fn get_release_by_tag(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _tag: &str,
+    ) -> CoreResult<Option<Release>> {
+        self.called("get_release_by_tag")
+    }
+
+    async fn list_issue_comments(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _issue_number: u64,
+    ) -> CoreResult<Vec<IssueComment>> {
+        self.called("list_issue_comments")
+    }
+
+    async fn list_pull_request_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+    ) -> CoreResult<Vec<GitCommit>> {
+        self.called("list_pull_request_commits")
+    }
+
+    async fn list_pr_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _issue_number: u64,
+    ) -> CoreResult<Vec<Label>> {
+        self.called("list_pr_labels")
+    }
+
+    async fn list_pull_requests(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _state: Option<&str>,
+        _head: Option<&str>,
+        _base: Option<&str>,
+        _per_page: Option<u8>,
+        _page: Option<u32>,
+    ) -> CoreResult<Vec<PullRequest>> {
+        self.called("list_pull_requests")
+    }
+
+    async fn list_releases(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _per_page: Option<u8>,
+        _page: Option<u32>,
+    ) -> CoreResult<Vec<Release>> {
+        self.called("list_releases")
+    }
+
+    async fn upload_release_asset(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _release_id: u64,
+        _name: &str,
+        _content_type: Option<&str>,
+        _data: Vec<u8>,
+    ) -> CoreResult<String> {
+        self.called("upload_release_asset")
+    }
+
+    async fn remove_label(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _issue_number: u64,
+        _label_name: &str,
+    ) -> CoreResult<()> {
+        self.called("remove_label")
+    }
+
+    async fn resolve_ref(&self, _owner: &str, _repo: &str, _git_ref: &str) -> CoreResult<String> {
+        self.called("resolve_ref")
+    }
+
+    async fn set_pull_request_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+        _labels: &[&str],
+        _mode: LabelMode,
+    ) -> CoreResult<()> {
+        self.called("set_pull_request_labels")
+    }
+
+    async fn search_pull_requests(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _query: &str,
+    ) -> CoreResult<Vec<PullRequest>> {
+        self.called("search_pull_requests")
+    }
+
+    async fn update_issue_comment(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _comment_id: u64,
+        _body: &str,
+    ) -> CoreResult<()> {
+        self.called("update_issue_comment")
+    }
+
+    async fn update_pull_request(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+        _title: Option<String>,
+        _body: Option<String>,
+        _state: Option<String>,
+    ) -> CoreResult<PullRequest> {
+        self.called("update_pull_request")
+    }
+
+    async fn update_release(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _release_id: u64,
+        _params: UpdateReleaseParams,
+    ) -> CoreResult<Release> {
+        self.called("update_release")
+    }
+
+    async fn upsert_file(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _path: &str,
+        _commit_message: &str,
+        _content: &str,
+        _branch: &str,
+    ) -> CoreResult<()> {
+        self.called("upsert_file")
+    }
+
+    fn scoped_to(&self, _installation_id: u64) -> Self {
+        self.clone()
+    }
+}
+
+fn dry_run() -> (DryRunGitHubOperations<RecordingGitHub>, RecordingGitHub) {
+    let inner = RecordingGitHub::default();
+    (DryRunGitHubOperations::new(inner.clone()), inner)
+}
+
+// ── Tests ────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_reads_are_delegated_to_inner_client() {
+    let (github, inner) = dry_run();
+
+    let _ = github.get_repository_info("o", "r").await;
+    let _ = github
+        .get_commits_between("o", "r", "v1.0.0", "main", GetCommitsOptions::default())
+        .await;
+    let _ = github.list_tags("o", "r", ListTagsOptions::default()).await;
+    let result = github.get_latest_release("o", "r").await;
+
+    assert!(matches!(result, Err(CoreError::NotFound { .. })));
+    assert_eq!(
+        inner.calls(),
+        vec![
+            "get_repository_info",
+            "get_commits_between",
+            "list_tags",
+            "get_latest_release"
+        ]
+    );
+    assert!(github.log().actions().is_empty());
+}
+
+#[tokio::test]
+async fn test_no_mutating_call_reaches_inner_client() {
+    let (github, inner) = dry_run();
+
+    github.add_labels("o", "r", 1, &["release"]).await.unwrap();
+    github
+        .set_pull_request_labels("o", "r", 1, &["release"], LabelMode::Replace)
+        .await
+        .unwrap();
+    github
+        .batch_commit_files("o", "r", "release/v1.1.0", &[], "msg")
+        .await
+        .unwrap();
+    github
+        .batch_commit_files_rebased("o", "r", "release/v1.1.0", &[], "msg", "abc")
+        .await
+        .unwrap();
+    github
+        .create_branch("o", "r", "release/v1.1.0", "abc")
+        .await
+        .unwrap();
+    github
+        .create_issue_comment("o", "r", 1, "hi")
+        .await
+        .unwrap();
+    github
+        .create_pull_request(
+            "o",
+            "r",
+            CreatePullRequestParams {
+                base: "main".to_string(),
+                body: Some("notes".to_string()),
+                draft: false,
+                head: "release/v1.1.0".to_string(),
+                maintainer_can_modify: true,
+                title: "chore(release): v1.1.0".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    let release = github
+        .create_release(
+            "o",
+            "r",
+            CreateReleaseParams {
+                body: Some("notes".to_string()),
+                draft: false,
+                generate_release_notes: false,
+                name: Some("v1.1.0".to_string()),
+                prerelease: false,
+                tag_name: "v1.1.0".to_string(),
+                target_commitish: Some("abc".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(release.tag_name, "v1.1.0");
+    assert_eq!(release.target_commitish, "abc");
+
+    let tag = github
+        .create_tag("o", "r", "v1.1.0", "abc", None, None)
+        .await
+        .unwrap();
+    assert_eq!(tag.commit_sha, "abc");
+    let signed = github
+        .create_signed_tag(
+            "o",
+            "r",
+            SignedTagParams {
+                commit_sha: "abc".to_string(),
+                message: "Release v1.1.0".to_string(),
+                signature: "sig".to_string(),
+                tagged_at: chrono::Utc::now(),
+                tagger: GitUser {
+                    name: "Release Regent".to_string(),
+                    email: "releases@example.com".to_string(),
+                    login: None,
+                },
+                tag_name: "v1.1.0".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(signed.commit_sha, "abc");
+
+    github
+        .delete_branch("o", "r", "release/v1.0.0")
+        .await
+        .unwrap();
+    github
+        .force_update_branch("o", "r", "release/v1.1.0", "def")
+        .await
+        .unwrap();
+    github.remove_label("o", "r", 1, "release").await.unwrap();
+    github
+        .update_issue_comment("o", "r", 5, "hi")
+        .await
+        .unwrap();
+    github
+        .update_pull_request("o", "r", 7, None, Some("new".to_string()), None)
+        .await
+        .unwrap();
+    github
+        .update_release(
+            "o",
+            "r",
+            3,
+            UpdateReleaseParams {
+                body: None,
+                draft: None,
+                name: None,
+                prerelease: None,
+            },
+        )
+        .await
+        .unwrap();
+    let url = github
+        .upload_release_asset("o", "r", 3, "app.tar.gz", None, vec![1, 2, 3])
+        .await
+        .unwrap();
+    assert!(url.ends_with("/app.tar.gz"));
+    github
+        .upsert_branch("o", "r", "release/v1.1.0", "def")
+        .await
+        .unwrap();
+    github
+        .upsert_file("o", "r", "CHANGELOG.md", "msg", "content", "main")
+        .await
+        .unwrap();
+
+    let mutating_calls: Vec<_> = inner
+        .calls()
+        .into_iter()
+        .filter(|call| MUTATING_METHODS.contains(call))
+        .collect();
+    assert!(
+        mutating_calls.is_empty(),
+        "mutations reached the inner client: {mutating_calls:?}"
+    );
+    assert_eq!(github.log().actions().len(), MUTATING_METHODS.len());
+}
+
+#[tokio::test]
+async fn test_log_records_structured_actions_in_order() {
+    let (github, _inner) = dry_run();
+
+    github
+        .create_pull_request(
+            "o",
+            "r",
+            CreatePullRequestParams {
+                base: "main".to_string(),
+                body: Some("notes".to_string()),
+                draft: false,
+                head: "release/v1.1.0".to_string(),
+                maintainer_can_modify: true,
+                title: "chore(release): v1.1.0".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    github
+        .create_tag("o", "r", "v1.1.0", "abc", None, None)
+        .await
+        .unwrap();
+
+    let actions = github.log().actions();
+    assert_eq!(
+        actions,
+        vec![
+            DryRunAction::CreatePullRequest {
+                repository: "o/r".to_string(),
+                head: "release/v1.1.0".to_string(),
+                base: "main".to_string(),
+                title: "chore(release): v1.1.0".to_string(),
+                body: Some("notes".to_string()),
+            },
+            DryRunAction::Other {
+                repository: "o/r".to_string(),
+                description: "create tag v1.1.0 at abc".to_string(),
+            },
+        ]
+    );
+    assert_eq!(actions[1].to_string(), "o/r: create tag v1.1.0 at abc");
+}
+
+#[tokio::test]
+async fn test_update_pull_request_applies_changes_to_current_pr() {
+    let (github, inner) = dry_run();
+
+    let pr = github
+        .update_pull_request(
+            "o",
+            "r",
+            7,
+            Some("chore(release): v1.2.0".to_string()),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(pr.number, 7);
+    assert_eq!(pr.title, "chore(release): v1.2.0");
+    assert_eq!(pr.body.as_deref(), Some("original body"));
+    assert_eq!(inner.calls(), vec!["get_pull_request"]);
+}
+
+#[tokio::test]
+async fn test_scoped_copies_share_the_log() {
+    let (github, _inner) = dry_run();
+    let scoped = github.scoped_to(42);
+
+    scoped.delete_branch("o", "r", "old").await.unwrap();
+
+    assert_eq!(github.log().actions().len(), 1);
+}
@@ -1,5 +1,46 @@
+use std::fmt;
 use thiserror::Error;
 
+/// Stage of event processing in which an error occurred
+///
+/// Attached to an error's [`ErrorContext`] by [`CoreError::in_stage`] so that
+/// logs and reports can name the failing stage without changing the error's
+/// category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProcessingStage {
+    /// Looking up the repository, e.g. to resolve its default branch
+    RepositoryLookup,
+    /// Resolving the GitHub App installation for the repository
+    InstallationLookup,
+    /// Loading and checking the repository configuration
+    ConfigLoad,
+    /// Collecting the commits made since the previous release
+    CommitComparison,
+    /// Calculating the next version from the commits
+    VersionCalculation,
+    /// Rendering the changelog
+    ChangelogGeneration,
+    /// Creating or updating the release pull request
+    ReleasePullRequest,
+    /// Creating the tag and GitHub release after a release PR merges
+    ReleaseCreation,
+}
+
+impl fmt::Display for ProcessingStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::RepositoryLookup => "repository lookup",
+            Self::InstallationLookup => "installation lookup",
+            Self::ConfigLoad => "configuration loading",
+            Self::CommitComparison => "commit comparison",
+            Self::VersionCalculation => "version calculation",
+            Self::ChangelogGeneration => "changelog generation",
+            Self::ReleasePullRequest => "release PR update",
+            Self::ReleaseCreation => "release creation",
+        })
+    }
+}
+
 /// Error context information for better debugging and testing
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ErrorContext {
@@ -11,6 +52,8 @@ pub struct ErrorContext {
     pub context_data: std::collections::HashMap<String, String>,
     /// Error correlation ID for tracing
     pub correlation_id: Option<String>,
+    /// Processing stage the error occurred in
+    pub stage: Option<ProcessingStage>,
 }
 
 impl ErrorContext {
@@ -22,6 +65,7 @@ impl ErrorContext {
             component: component.into(),
             context_data: std::collections::HashMap::new(),
             correlation_id: None,
+            stage: None,
         }
     }
 
@@ -38,11 +82,30 @@ impl ErrorContext {
         self.correlation_id = Some(id.into());
         self
     }
+
+    /// Set the processing stage
+    #[must_use]
+    pub fn with_stage(mut self, stage: ProcessingStage) -> Self {
+        self.stage = Some(stage);
+        self
+    }
 }
 
 /// Errors that can occur in core Release Regent operations
 #[derive(Error, Debug)]
 pub enum CoreError {
+    /// The GitHub App is not installed on a repository
+    ///
+    /// Returned when `GET /repos/{owner}/{repo}/installation` answers 404.
+    /// Events for such repositories cannot be processed until the App is
+    /// installed, so callers should drop them rather than retry.
+    #[error("GitHub App is not installed on {owner}/{repo}")]
+    AppNotInstalled {
+        owner: String,
+        repo: String,
+        context: Option<ErrorContext>,
+    },
+
     /// Authentication/authorization errors
     #[error("Authentication error: {message}")]
     Authentication {
@@ -129,6 +192,18 @@ pub enum CoreError {
         source: Option<Box<dyn std::error::Error + Send + Sync>>,
     },
 
+    /// A branch update was rejected because it is not a fast-forward
+    ///
+    /// Returned when moving a branch to a new commit would discard commits
+    /// that exist only on the branch. The branch was left untouched; callers
+    /// that own the branch may rebase their changes onto the new commit.
+    #[error("Branch '{branch}' cannot be fast-forwarded to {sha}")]
+    NotFastForward {
+        branch: String,
+        sha: String,
+        context: Option<ErrorContext>,
+    },
+
     /// Resource not found
     #[error("Not found: {resource}")]
     NotFound {
@@ -418,6 +493,24 @@ impl CoreError {
         }
     }
 
+    /// Create an error for a branch update that is not a fast-forward
+    pub fn not_fast_forward(branch: impl Into<String>, sha: impl Into<String>) -> Self {
+        Self::NotFastForward {
+            branch: branch.into(),
+            sha: sha.into(),
+            context: None,
+        }
+    }
+
+    /// Create an error for a repository the GitHub App is not installed on
+    pub fn app_not_installed(owner: impl Into<String>, repo: impl Into<String>) -> Self {
+        Self::AppNotInstalled {
+            owner: owner.into(),
+            repo: repo.into(),
+            context: None,
+        }
+    }
+
     /// Create a new authentication error
     pub fn authentication(message: impl Into<String>) -> Self {
         Self::Authentication {
@@ -480,8 +573,10 @@ impl CoreError {
             | Self::Validation { context, .. }
             | Self::Timeout { context, .. }
             | Self::Network { context, .. }
+            | Self::AppNotInstalled { context, .. }
             | Self::Authentication { context, .. }
             | Self::Conflict { context, .. }
+            | Self::NotFastForward { context, .. }
             | Self::RateLimit { context, .. }
             | Self::NotFound { context, .. } => context.as_ref(),
             Self::NotSupported { error_context, .. } => error_context.as_ref(),
@@ -489,6 +584,75 @@ impl CoreError {
         }
     }
 
+    fn context_slot(&mut self) -> Option<&mut Option<ErrorContext>> {
+        match self {
+            Self::Config { context, .. }
+            | Self::Versioning { context, .. }
+            | Self::ChangelogGeneration { context, .. }
+            | Self::Webhook { context, .. }
+            | Self::GitHub { context, .. }
+            | Self::InvalidInput { context, .. }
+            | Self::InternalState { context, .. }
+            | Self::Validation { context, .. }
+            | Self::Timeout { context, .. }
+            | Self::Network { context, .. }
+            | Self::AppNotInstalled { context, .. }
+            | Self::Authentication { context, .. }
+            | Self::Conflict { context, .. }
+            | Self::NotFastForward { context, .. }
+            | Self::RateLimit { context, .. }
+            | Self::NotFound { context, .. } => Some(context),
+            Self::NotSupported { error_context, .. } => Some(error_context),
+            _ => None,
+        }
+    }
+
+    /// Record that this error occurred in `stage` while processing
+    /// `owner/repo`.
+    ///
+    /// The first stage recorded wins, so a stage attached close to the
+    /// failing call (such as [`ProcessingStage::CommitComparison`]) is kept
+    /// when an enclosing stage wraps the error again. The variant, and with it
+    /// the error category, is unchanged. Parse and I/O errors converted with
+    /// `?` carry no context and are returned as they are.
+    #[must_use]
+    pub fn in_stage(mut self, stage: ProcessingStage, owner: &str, repo: &str) -> Self {
+        if let Some(slot) = self.context_slot() {
+            let context = slot.get_or_insert_with(|| {
+                ErrorContext::new(stage.to_string(), "processor")
+                    .with_data("repository", format!("{owner}/{repo}"))
+            });
+            if context.stage.is_none() {
+                context.stage = Some(stage);
+                context
+                    .context_data
+                    .entry("repository".to_string())
+                    .or_insert_with(|| format!("{owner}/{repo}"));
+            }
+        }
+        self
+    }
+
+    /// The processing stage recorded by [`Self::in_stage`], if any
+    #[must_use]
+    pub fn stage(&self) -> Option<ProcessingStage> {
+        self.context().and_then(|context| context.stage)
+    }
+
+    /// Describe where the error occurred, e.g.
+    /// `failed during version calculation for acme/widgets`
+    ///
+    /// Returns `None` when no stage has been recorded.
+    #[must_use]
+    pub fn stage_summary(&self) -> Option<String> {
+        let context = self.context()?;
+        let stage = context.stage?;
+        Some(match context.context_data.get("repository") {
+            Some(repository) => format!("failed during {stage} for {repository}"),
+            None => format!("failed during {stage}"),
+        })
+    }
+
     /// Returns `true` for transient errors that are safe to retry after a back-off delay.
     ///
     /// # Retryable variants
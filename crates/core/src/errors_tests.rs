@@ -161,6 +161,44 @@ fn test_not_found_error_creation() {
     assert!(CoreError::not_found("r").context().is_none());
 }
 
+/// Verify `not_fast_forward()` names the branch and target commit.
+#[test]
+fn test_not_fast_forward_error_creation() {
+    let error = CoreError::not_fast_forward("release/v1.1.0", "abc123");
+
+    assert!(matches!(
+        error,
+        CoreError::NotFastForward { ref branch, ref sha, .. }
+            if branch == "release/v1.1.0" && sha == "abc123"
+    ));
+    assert_eq!(
+        error.to_string(),
+        "Branch 'release/v1.1.0' cannot be fast-forwarded to abc123"
+    );
+}
+
+/// Verify `app_not_installed()` keeps the repository and names it in the display text.
+#[test]
+fn test_app_not_installed_error_creation() {
+    let error = CoreError::app_not_installed("acme", "app");
+
+    match error {
+        CoreError::AppNotInstalled {
+            ref owner,
+            ref repo,
+            ..
+        } => {
+            assert_eq!(owner, "acme");
+            assert_eq!(repo, "app");
+        }
+        _ => panic!("Expected AppNotInstalled error"),
+    }
+
+    assert_eq!(error.to_string(), "GitHub App is not installed on acme/app");
+    assert!(error.context().is_none());
+    assert_eq!(error.retry_delay_seconds(), None);
+}
+
 #[test]
 fn test_toml_error_conversion() {
     let toml_error = toml::from_str::<toml::Value>("invalid toml content [[[").unwrap_err();
@@ -333,6 +371,16 @@ fn test_is_retryable_json_parsing_not_retryable() {
     assert!(!CoreError::from(json_err).is_retryable());
 }
 
+#[test]
+fn test_is_retryable_not_fast_forward_not_retryable() {
+    assert!(!CoreError::not_fast_forward("release/v1.1.0", "abc123").is_retryable());
+}
+
+#[test]
+fn test_is_retryable_app_not_installed_not_retryable() {
+    assert!(!CoreError::app_not_installed("acme", "app").is_retryable());
+}
+
 #[test]
 fn test_is_retryable_not_found_not_retryable() {
     assert!(!CoreError::not_found("release for tag 'v1.0.0'").is_retryable());
@@ -460,3 +508,61 @@ fn test_is_config_error_non_config_variants_all_return_false() {
         );
     }
 }
+
+// ============================================================================
+// Processing stage tests
+// ============================================================================
+
+#[test]
+fn test_in_stage_records_stage_and_repository() {
+    let error = CoreError::versioning("no tags").in_stage(
+        ProcessingStage::VersionCalculation,
+        "acme",
+        "widgets",
+    );
+
+    assert_eq!(error.stage(), Some(ProcessingStage::VersionCalculation));
+    assert_eq!(
+        error.stage_summary().as_deref(),
+        Some("failed during version calculation for acme/widgets")
+    );
+    assert!(matches!(error, CoreError::Versioning { .. }));
+    assert_eq!(error.to_string(), "Version calculation failed: no tags");
+}
+
+#[test]
+fn test_in_stage_keeps_the_innermost_stage() {
+    let error = CoreError::network("compare failed")
+        .in_stage(ProcessingStage::CommitComparison, "acme", "widgets")
+        .in_stage(ProcessingStage::VersionCalculation, "acme", "widgets");
+
+    assert_eq!(error.stage(), Some(ProcessingStage::CommitComparison));
+    assert!(error.is_retryable());
+}
+
+#[test]
+fn test_in_stage_preserves_existing_context() {
+    let context = ErrorContext::new("load", "config_provider").with_correlation_id("corr-1");
+    let error = CoreError::config_with_context("bad file", context).in_stage(
+        ProcessingStage::ConfigLoad,
+        "acme",
+        "widgets",
+    );
+
+    let context = error.context().unwrap();
+    assert_eq!(context.operation, "load");
+    assert_eq!(context.correlation_id.as_deref(), Some("corr-1"));
+    assert_eq!(context.stage, Some(ProcessingStage::ConfigLoad));
+    assert_eq!(
+        context.context_data.get("repository").map(String::as_str),
+        Some("acme/widgets")
+    );
+}
+
+#[test]
+fn test_errors_without_stage_have_no_summary() {
+    let error = CoreError::not_found("tag v1.0.0");
+
+    assert_eq!(error.stage(), None);
+    assert_eq!(error.stage_summary(), None);
+}
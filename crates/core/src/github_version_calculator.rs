@@ -7,21 +7,28 @@
 //! [`VersionCalculatorTrait`]: crate::traits::version_calculator::VersionCalculator
 
 use crate::{
+    clock::{system_clock, Clock},
+    commit_filter::{bump_candidates, CommitCandidate, CommitFilter},
+    monorepo,
     traits::{
-        git_operations::GetCommitsOptions,
+        git_operations::{GetCommitsOptions, GitCommit, GitUser},
         github_operations::GitHubOperations,
         version_calculator::{
-            CalculationOptions, ChangelogEntry, CommitAnalysis, ValidationRules, VersionBump,
-            VersionCalculationResult, VersionCalculator as VersionCalculatorTrait, VersionContext,
-            VersioningStrategy,
+            BumpReason, CalculationOptions, ChangelogEntry, CommitAnalysis, ValidationRules,
+            VersionBump, VersionCalculationResult, VersionCalculator as VersionCalculatorTrait,
+            VersionContext, VersioningStrategy,
         },
     },
-    versioning::{apply_semver_bump, SemanticVersion, VersionCalculator as ConventionalCalculator},
-    CoreError, CoreResult,
+    versioning::{
+        apply_no_conventional_commits_policy, apply_semver_bump, cancelled_by_reverts,
+        commits_since_release, mark_breaking_scopes, render_build_metadata, SemanticVersion,
+        VersionCalculator as ConventionalCalculator,
+    },
+    CoreError, CoreResult, ProcessingStage,
 };
 use async_trait::async_trait;
 use chrono::Utc;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 use tracing::debug;
 
@@ -46,6 +53,7 @@ mod tests;
 #[derive(Debug, Clone)]
 pub struct GitHubVersionCalculator<G: GitHubOperations> {
     github_operations: G,
+    clock: Arc<dyn Clock>,
 }
 
 impl<G: GitHubOperations> GitHubVersionCalculator<G> {
@@ -54,7 +62,20 @@ impl<G: GitHubOperations> GitHubVersionCalculator<G> {
     /// The client will be scoped per calculation using `context.installation_id`.
     #[must_use]
     pub fn new(github_operations: G) -> Self {
-        Self { github_operations }
+        Self {
+            github_operations,
+            clock: system_clock(),
+        }
+    }
+
+    /// Read the current time from `clock` instead of the system clock.
+    ///
+    /// The time stamps build metadata and dates commits whose own date is
+    /// unknown.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
     }
 
     /// Derive the highest `VersionBump` from a slice of analyses.
@@ -80,29 +101,36 @@ impl<G: GitHubOperations> GitHubVersionCalculator<G> {
     /// The `date` and `author` parameters are threaded in from the originating
     /// [`crate::traits::git_operations::GitCommit`] because [`ConventionalCommit`]
     /// only carries the SHA and message text — the full commit metadata is
-    /// discarded by the parser.
+    /// discarded by the parser. The author's email and login are kept in the
+    /// metadata under [`CommitAnalysis::AUTHOR_EMAIL`] and
+    /// [`CommitAnalysis::AUTHOR_LOGIN`] for changelog mentions.
     fn to_commit_analysis(
         commit: crate::versioning::ConventionalCommit,
         date: chrono::DateTime<Utc>,
-        author: String,
+        author: Option<&GitUser>,
     ) -> CommitAnalysis {
-        let version_bump = if commit.breaking_change {
-            VersionBump::Major
-        } else if commit.commit_type == "feat" {
-            VersionBump::Minor
-        } else if commit.commit_type == "fix" {
-            VersionBump::Patch
-        } else {
-            VersionBump::None
-        };
+        let version_bump = commit.version_bump();
+
+        let mut metadata = HashMap::new();
+        if let Some(author) = author {
+            if !author.email.is_empty() {
+                metadata.insert(
+                    CommitAnalysis::AUTHOR_EMAIL.to_string(),
+                    author.email.clone(),
+                );
+            }
+            if let Some(login) = &author.login {
+                metadata.insert(CommitAnalysis::AUTHOR_LOGIN.to_string(), login.clone());
+            }
+        }
 
         CommitAnalysis {
-            author,
+            author: author.map(|a| a.name.clone()).unwrap_or_default(),
             commit_type: Some(commit.commit_type),
             date,
             is_breaking: commit.breaking_change,
             message: commit.message,
-            metadata: HashMap::new(),
+            metadata,
             scope: commit.scope,
             sha: commit.sha,
             version_bump,
@@ -141,9 +169,77 @@ impl<G: GitHubOperations> GitHubVersionCalculator<G> {
             next_version,
             strategy,
             version_bump: bump,
+            bump_reason: None,
+            packages: BTreeMap::new(),
         }
     }
 
+    /// Collect the files changed by each commit, keyed by commit SHA.
+    ///
+    /// The compare API does not list files per commit, so commits that arrive
+    /// without file information are fetched individually, concurrently.
+    async fn changed_files(
+        &self,
+        context: &VersionContext,
+        commits: Vec<GitCommit>,
+    ) -> CoreResult<HashMap<String, Vec<String>>> {
+        use futures::future::try_join_all;
+
+        let owner = context.owner.as_str();
+        let repo = context.repo.as_str();
+
+        let commits = try_join_all(commits.into_iter().map(|commit| async move {
+            if !commit.files.is_empty() {
+                return Ok(commit);
+            }
+            self.github_operations
+                .get_commit(owner, repo, &commit.sha)
+                .await
+                .map_err(|e| {
+                    CoreError::versioning(format!(
+                        "Failed to fetch changed files for commit {}: {e}",
+                        commit.sha
+                    ))
+                })
+        }))
+        .await?;
+
+        Ok(commits.into_iter().map(|c| (c.sha, c.files)).collect())
+    }
+
+    /// Render the build metadata template from the calculation options.
+    ///
+    /// `context.head_ref` is used as the commit SHA when it already is one;
+    /// otherwise it is resolved through the API, and only when the template
+    /// actually refers to the SHA.
+    async fn build_metadata(
+        &self,
+        context: &VersionContext,
+        template: Option<&str>,
+    ) -> CoreResult<Option<String>> {
+        let Some(template) = template else {
+            return Ok(None);
+        };
+
+        let head = context.head_ref.as_str();
+        let is_sha = head.len() == 40 && head.chars().all(|c| c.is_ascii_hexdigit());
+        let sha = if template.contains("sha}") && !is_sha {
+            self.github_operations
+                .get_commit(&context.owner, &context.repo, head)
+                .await
+                .map_err(|e| {
+                    CoreError::versioning(format!(
+                        "Failed to resolve {head} for build metadata: {e}"
+                    ))
+                })?
+                .sha
+        } else {
+            head.to_string()
+        };
+
+        render_build_metadata(template, &sha, self.clock.now()).map(Some)
+    }
+
     /// Apply a version bump to a semantic version, returning the bumped version.
     ///
     /// Delegates to [`apply_semver_bump`] — the single canonical semver
@@ -174,11 +270,15 @@ impl<G: GitHubOperations + 'static> VersionCalculatorTrait for GitHubVersionCalc
     ///
     /// The underlying GitHub client must already be scoped to the correct
     /// installation (i.e. call `scoped_to(installation_id)` first).
+    ///
+    /// Without a current version this is the first release: every commit
+    /// reachable from `head_ref` is analysed, and the version is
+    /// `options.initial_version` when set.
     async fn calculate_version(
         &self,
         context: VersionContext,
         strategy: VersioningStrategy,
-        _options: CalculationOptions,
+        options: CalculationOptions,
     ) -> CoreResult<VersionCalculationResult> {
         debug!(
             owner = %context.owner,
@@ -188,49 +288,126 @@ impl<G: GitHubOperations + 'static> VersionCalculatorTrait for GitHubVersionCalc
             "Calculating version via GitHub API",
         );
 
+        let filter = CommitFilter::new(&options.exclusions)?;
         let raw_commits: Vec<(String, String)>;
-        let mut sha_to_meta: HashMap<String, (chrono::DateTime<Utc>, String)> = HashMap::new();
-
-        if let Some(ref base) = context.base_ref {
-            let commits = self
-                .github_operations
-                .get_commits_between(
+        let mut sha_to_meta: HashMap<String, (chrono::DateTime<Utc>, GitUser)> = HashMap::new();
+        let mut changed_files = HashMap::new();
+
+        let first_release = context.current_version.is_none();
+        let commits = match &context.base_ref {
+            Some(base) => {
+                let commits = commits_since_release(
+                    &self.github_operations,
                     &context.owner,
                     &context.repo,
                     base,
                     &context.head_ref,
-                    GetCommitsOptions::default(),
                 )
-                .await?;
-            debug!(
-                commit_count = commits.len(),
-                "Fetched commits between refs via GitHub API"
-            );
+                .await
+                .map_err(|e| {
+                    e.in_stage(
+                        ProcessingStage::CommitComparison,
+                        &context.owner,
+                        &context.repo,
+                    )
+                })?;
+                debug!(
+                    commit_count = commits.len(),
+                    "Fetched commits between refs via GitHub API"
+                );
+                Some(commits)
+            }
+            None if first_release => {
+                let commits = self
+                    .github_operations
+                    .list_commits(
+                        &context.owner,
+                        &context.repo,
+                        &context.head_ref,
+                        GetCommitsOptions::default(),
+                    )
+                    .await
+                    .map_err(|e| {
+                        e.in_stage(
+                            ProcessingStage::CommitComparison,
+                            &context.owner,
+                            &context.repo,
+                        )
+                    })?;
+                debug!(
+                    commit_count = commits.len(),
+                    "First release; fetched full history via GitHub API"
+                );
+                Some(commits)
+            }
+            None => None,
+        };
+
+        if let Some(commits) = commits {
+            let commits: Vec<GitCommit> = commits
+                .into_iter()
+                .filter(|c| {
+                    let mut identities = vec![c.author.name.as_str(), c.author.email.as_str()];
+                    identities.extend(c.author.login.as_deref());
+                    filter.keep(&CommitCandidate {
+                        sha: &c.sha,
+                        message: &c.message,
+                        author_identities: &identities,
+                        parent_count: c.parents.len(),
+                    })
+                })
+                .collect();
             // Build a lookup table keyed by SHA so that to_commit_analysis can
             // populate the date and author fields from the original GitCommit
-            // rather than falling back to Utc::now() / empty string.
+            // rather than falling back to the clock / empty string.
             for c in &commits {
-                sha_to_meta.insert(c.sha.clone(), (c.author_date, c.author.name.clone()));
+                sha_to_meta.insert(c.sha.clone(), (c.author_date, c.author.clone()));
+            }
+            // Parse the full message, not just the subject, so that
+            // `BREAKING CHANGE:` footers in the body are honoured.
+            raw_commits = commits
+                .iter()
+                .map(|c| (c.sha.clone(), c.message.clone()))
+                .collect();
+            if matches!(strategy, VersioningStrategy::Monorepo { .. }) {
+                changed_files = self.changed_files(&context, commits).await?;
             }
-            raw_commits = commits.into_iter().map(|c| (c.sha, c.subject)).collect();
         } else {
-            // No base ref — first release, no prior tag to compare against.
-            debug!("No base_ref; skipping commit analysis for first release");
+            // A current version without a base ref to compare against.
+            debug!("No base_ref; skipping commit analysis");
             raw_commits = Vec::new();
         };
 
-        let conventional = ConventionalCalculator::parse_conventional_commits(&raw_commits);
+        let mut conventional = ConventionalCalculator::parse_conventional_commits(&raw_commits);
+        mark_breaking_scopes(&mut conventional, &options.breaking_scopes);
+        // A revert and the commit it reverts leave the version unchanged.
+        let cancelled = cancelled_by_reverts(&conventional);
         let analyses: Vec<CommitAnalysis> = conventional
             .into_iter()
-            .map(|c| {
+            .enumerate()
+            .map(|(index, c)| {
                 let (date, author) = sha_to_meta
                     .remove(&c.sha)
-                    .unwrap_or_else(|| (Utc::now(), String::new()));
-                Self::to_commit_analysis(c, date, author)
+                    .map_or_else(|| (self.clock.now(), None), |(d, a)| (d, Some(a)));
+                let mut analysis = Self::to_commit_analysis(c, date, author.as_ref());
+                if cancelled.contains(&index) {
+                    analysis.version_bump = VersionBump::None;
+                }
+                analysis
             })
             .collect();
 
-        let bump = Self::highest_bump(&analyses);
+        let mut candidates = bump_candidates(&analyses, &options.bump_scopes);
+        let bump = Self::highest_bump(&candidates);
+        // The compare API lists commits oldest first but the commit list of a
+        // first release is newest first; order by date for the reason.
+        candidates.sort_by_key(|c| c.date);
+        let (bump, mut bump_reason) = apply_no_conventional_commits_policy(
+            options.no_conventional_commits,
+            &raw_commits,
+            bump.clone(),
+            BumpReason::from_analyses(&bump, &candidates),
+        )?;
 
         let current = context.current_version.clone().unwrap_or(SemanticVersion {
             major: 0,
@@ -240,19 +417,51 @@ impl<G: GitHubOperations + 'static> VersionCalculatorTrait for GitHubVersionCalc
             build: None,
         });
 
-        let next_version = Self::bump_version(current, &bump, None, None)?;
+        let build = self
+            .build_metadata(&context, options.build_metadata.as_deref())
+            .await?;
+        let next_version = match &options.initial_version {
+            Some(initial) if first_release => {
+                bump_reason = BumpReason {
+                    bump: bump.clone(),
+                    rule: format!("configured initial version {initial} for the first release"),
+                    commits: Vec::new(),
+                };
+                SemanticVersion {
+                    build: build.clone(),
+                    ..initial.clone()
+                }
+            }
+            _ => Self::bump_version(current, &bump, None, build.clone())?,
+        };
 
-        Ok(Self::build_result(
-            &context,
-            strategy,
-            analyses,
-            next_version,
-            bump,
-        ))
+        let packages = match &strategy {
+            VersioningStrategy::Monorepo { packages } => monorepo::calculate_package_versions(
+                packages,
+                &analyses,
+                &changed_files,
+                &context.package_versions,
+            )?,
+            _ => BTreeMap::new(),
+        };
+
+        let mut result = Self::build_result(&context, strategy, analyses, next_version, bump);
+        result.build_metadata = build;
+        result.packages = packages;
+        result.bump_reason = Some(bump_reason);
+        if let Some(minimum) = &options.minimum_version {
+            if result.apply_minimum_version(minimum) {
+                debug!(minimum = %minimum, "Raised calculated version to the minimum version");
+            }
+        }
+        Ok(result)
     }
 
     /// Analyse individual commits identified by their SHAs using the GitHub API.
     ///
+    /// Each commit is fetched with `get_commit` and its full message is
+    /// parsed, so `BREAKING CHANGE:` footers in the body are detected.
+    ///
     /// All commits are fetched concurrently to avoid an O(N × RTT) sequential
     /// chain of API round-trips. GitHub provides no batch commit endpoint, so
     /// N requests are still sent, but they are issued in parallel and the total
@@ -279,11 +488,10 @@ impl<G: GitHubOperations + 'static> VersionCalculatorTrait for GitHubVersionCalc
         let mut analyses = Vec::with_capacity(commits.len());
         for commit in commits {
             let date = commit.author_date;
-            let author = commit.author.name.clone();
-            let raw = vec![(commit.sha.clone(), commit.subject.clone())];
+            let raw = vec![(commit.sha.clone(), commit.message.clone())];
             let parsed = ConventionalCalculator::parse_conventional_commits(&raw);
             for c in parsed {
-                analyses.push(Self::to_commit_analysis(c, date, author.clone()));
+                analyses.push(Self::to_commit_analysis(c, date, Some(&commit.author)));
             }
         }
 
@@ -351,6 +559,10 @@ impl<G: GitHubOperations + 'static> VersionCalculatorTrait for GitHubVersionCalc
             "conventional_commits".to_string(),
             "Semantic versioning derived from conventional commits".to_string(),
         );
+        map.insert(
+            "monorepo".to_string(),
+            "Conventional commits versioning per package, by changed paths".to_string(),
+        );
         map
     }
 
@@ -387,7 +599,7 @@ impl<G: GitHubOperations + 'static> VersionCalculatorTrait for GitHubVersionCalc
         Ok(parsed
             .into_iter()
             .next()
-            .map(|c| Self::to_commit_analysis(c, Utc::now(), String::new())))
+            .map(|c| Self::to_commit_analysis(c, self.clock.now(), None)))
     }
 
     /// Apply a version bump to an existing version.
@@ -410,6 +622,7 @@ impl<G: GitHubOperations + 'static> VersionCalculatorTrait for GitHubVersionCalc
     fn scoped_to(&self, installation_id: u64) -> Arc<dyn VersionCalculatorTrait + Send + Sync> {
         Arc::new(Self {
             github_operations: self.github_operations.scoped_to(installation_id),
+            clock: Arc::clone(&self.clock),
         })
     }
 }
@@ -12,18 +12,24 @@ use chrono::Utc;
 use tokio::sync::Mutex;
 
 use crate::{
+    clock::Clock,
     github_version_calculator::GitHubVersionCalculator,
     traits::{
         git_operations::{
             GetCommitsOptions, GitCommit, GitRepository, GitTag, GitUser, ListTagsOptions,
+            RepositoryVisibility,
         },
         github_operations::{
-            CreatePullRequestParams, CreateReleaseParams, GitHubOperations, GitUser as GHGitUser,
-            Label, PullRequest, Release, Repository, Tag, UpdateReleaseParams,
+            CreateCommitStatusParams, CreatePullRequestParams, CreateReleaseParams,
+            GitHubOperations, GitUser as GHGitUser, Label, LabelMode, PullRequest, Release, Tag,
+            UpdateReleaseParams,
+        },
+        version_calculator::{
+            BumpReason, CalculationOptions, PackageSpec, VersionBump, VersionCalculationResult,
+            VersionCalculator, VersionContext, VersioningStrategy,
         },
-        version_calculator::{VersionCalculator, VersionContext, VersioningStrategy},
     },
-    CoreError, CoreResult,
+    CoreError, CoreResult, ProcessingStage,
 };
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -38,6 +44,10 @@ struct StubGitHub {
     commits: HashMap<String, GitCommit>,
     /// Counter incremented on every `get_commit` call.
     get_commit_call_count: Arc<Mutex<usize>>,
+    /// Commits returned by `get_commits_between`, in order.
+    range: Vec<GitCommit>,
+    /// Whether `get_commits_between` fails.
+    fail_comparison: bool,
 }
 
 impl StubGitHub {
@@ -46,9 +56,23 @@ impl StubGitHub {
         Self {
             commits: map,
             get_commit_call_count: Arc::new(Mutex::new(0)),
+            range: Vec::new(),
+            fail_comparison: false,
         }
     }
 
+    /// Serve `range` from `get_commits_between`.
+    fn with_range(mut self, range: Vec<GitCommit>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Fail every `get_commits_between` call with a network error.
+    fn with_failing_comparison(mut self) -> Self {
+        self.fail_comparison = true;
+        self
+    }
+
     async fn get_commit_call_count(&self) -> usize {
         *self.get_commit_call_count.lock().await
     }
@@ -87,7 +111,20 @@ impl crate::traits::git_operations::GitOperations for StubGitHub {
         _head: &str,
         _options: GetCommitsOptions,
     ) -> CoreResult<Vec<GitCommit>> {
-        Ok(vec![])
+        if self.fail_comparison {
+            return Err(CoreError::network("compare request failed"));
+        }
+        Ok(self.range.clone())
+    }
+
+    async fn list_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _head: &str,
+        _options: GetCommitsOptions,
+    ) -> CoreResult<Vec<GitCommit>> {
+        Ok(self.range.clone())
     }
 
     async fn get_commit(
@@ -139,33 +176,13 @@ impl crate::traits::git_operations::GitOperations for StubGitHub {
             ssh_url: format!("git@github.com:{owner}/{repo}.git"),
             private: false,
             description: None,
+            topics: Vec::new(),
+            language: None,
+            visibility: RepositoryVisibility::Public,
         })
     }
 }
 
-fn stub_repo() -> Repository {
-    Repository {
-        id: 1,
-        name: "repo".to_string(),
-        full_name: "owner/repo".to_string(),
-        owner: "owner".to_string(),
-        description: None,
-        private: false,
-        default_branch: "main".to_string(),
-        clone_url: "https://github.com/owner/repo.git".to_string(),
-        ssh_url: "git@github.com:owner/repo.git".to_string(),
-        homepage: None,
-    }
-}
-
-fn stub_git_user() -> GHGitUser {
-    GHGitUser {
-        name: "bot".to_string(),
-        email: "bot@example.com".to_string(),
-        login: Some("bot".to_string()),
-    }
-}
-
 #[async_trait]
 impl GitHubOperations for StubGitHub {
     async fn create_pull_request(
@@ -204,6 +221,21 @@ impl GitHubOperations for StubGitHub {
         })
     }
 
+    async fn create_signed_tag(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        params: crate::traits::github_operations::SignedTagParams,
+    ) -> CoreResult<Tag> {
+        Ok(Tag {
+            name: params.tag_name,
+            commit_sha: params.commit_sha,
+            message: Some(params.message),
+            tagger: Some(params.tagger),
+            created_at: Some(params.tagged_at),
+        })
+    }
+
     async fn get_latest_release(&self, _owner: &str, _repo: &str) -> CoreResult<Option<Release>> {
         Ok(None)
     }
@@ -222,8 +254,8 @@ impl GitHubOperations for StubGitHub {
         _owner: &str,
         _repo: &str,
         _tag: &str,
-    ) -> CoreResult<Release> {
-        Err(CoreError::not_found("release"))
+    ) -> CoreResult<Option<Release>> {
+        Ok(None)
     }
 
     async fn list_releases(
@@ -236,6 +268,18 @@ impl GitHubOperations for StubGitHub {
         Ok(vec![])
     }
 
+    async fn upload_release_asset(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _release_id: u64,
+        _name: &str,
+        _content_type: Option<&str>,
+        _data: Vec<u8>,
+    ) -> CoreResult<String> {
+        Err(CoreError::not_found("stub"))
+    }
+
     async fn list_pull_requests(
         &self,
         _owner: &str,
@@ -290,6 +334,16 @@ impl GitHubOperations for StubGitHub {
         Ok(())
     }
 
+    async fn create_commit_status(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _sha: &str,
+        _params: CreateCommitStatusParams,
+    ) -> CoreResult<()> {
+        Ok(())
+    }
+
     async fn delete_branch(&self, _owner: &str, _repo: &str, _branch_name: &str) -> CoreResult<()> {
         Ok(())
     }
@@ -304,6 +358,16 @@ impl GitHubOperations for StubGitHub {
         Ok(())
     }
 
+    async fn upsert_branch(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch_name: &str,
+        _from_sha: &str,
+    ) -> CoreResult<crate::traits::github_operations::BranchUpsert> {
+        Ok(crate::traits::github_operations::BranchUpsert::Created)
+    }
+
     async fn create_issue_comment(
         &self,
         _owner: &str,
@@ -343,6 +407,21 @@ impl GitHubOperations for StubGitHub {
         Ok(())
     }
 
+    async fn resolve_ref(&self, _owner: &str, _repo: &str, _git_ref: &str) -> CoreResult<String> {
+        Err(CoreError::not_found("not implemented"))
+    }
+
+    async fn set_pull_request_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+        _labels: &[&str],
+        _mode: LabelMode,
+    ) -> CoreResult<()> {
+        Ok(())
+    }
+
     async fn list_pr_labels(
         &self,
         _owner: &str,
@@ -410,6 +489,15 @@ impl GitHubOperations for StubGitHub {
         Ok(vec![])
     }
 
+    async fn list_pull_request_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+    ) -> CoreResult<Vec<crate::traits::git_operations::GitCommit>> {
+        Ok(vec![])
+    }
+
     async fn update_issue_comment(
         &self,
         _owner: &str,
@@ -436,6 +524,7 @@ fn make_context() -> VersionContext {
         owner: "owner".to_string(),
         repo: "repo".to_string(),
         target_branch: "main".to_string(),
+        package_versions: HashMap::new(),
     }
 }
 
@@ -525,8 +614,6 @@ async fn test_analyze_commits_breaking_change_is_flagged() {
 
     assert_eq!(result.len(), 1);
     assert!(result[0].is_breaking);
-
-    use crate::traits::version_calculator::VersionBump;
     assert_eq!(result[0].version_bump, VersionBump::Major);
 }
 
@@ -572,3 +659,968 @@ async fn test_analyze_commits_calls_api_once_per_sha() {
     assert_eq!(result.len(), n);
     assert_eq!(stub.get_commit_call_count().await, n);
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// calculate_version — monorepo strategy
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn with_files(mut commit: GitCommit, files: &[&str]) -> GitCommit {
+    commit.files = files.iter().map(|f| (*f).to_string()).collect();
+    commit
+}
+
+fn monorepo_strategy() -> VersioningStrategy {
+    VersioningStrategy::Monorepo {
+        packages: vec![
+            PackageSpec {
+                name: "api".to_string(),
+                path: "services/api/**".to_string(),
+                tag_prefix: Some("api-v".to_string()),
+                changelog_path: None,
+            },
+            PackageSpec {
+                name: "web".to_string(),
+                path: "services/web".to_string(),
+                tag_prefix: None,
+                changelog_path: None,
+            },
+        ],
+    }
+}
+
+fn monorepo_context() -> VersionContext {
+    let mut context = make_context();
+    context.base_ref = Some("v1.0.0".to_string());
+    context.package_versions.insert(
+        "api".to_string(),
+        crate::versioning::SemanticVersion {
+            major: 1,
+            minor: 4,
+            patch: 2,
+            prerelease: None,
+            build: None,
+        },
+    );
+    context
+}
+
+/// Commits only bump the packages whose files they change, and commits that
+/// arrive from the compare API without file information are fetched.
+#[tokio::test]
+async fn test_calculate_version_monorepo_versions_packages_independently() {
+    let commits = vec![
+        with_files(
+            make_commit("sha1", "feat: add endpoint"),
+            &["services/api/src/routes.rs"],
+        ),
+        with_files(
+            make_commit("sha2", "fix: button colour"),
+            &["services/web/src/button.ts"],
+        ),
+        with_files(make_commit("sha3", "docs: readme"), &["README.md"]),
+    ];
+    let range: Vec<GitCommit> = commits.iter().map(|c| with_files(c.clone(), &[])).collect();
+    let stub = StubGitHub::new(commits).with_range(range);
+    let calc = GitHubVersionCalculator::new(stub.clone());
+
+    let result = calc
+        .calculate_version(
+            monorepo_context(),
+            monorepo_strategy(),
+            CalculationOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(stub.get_commit_call_count().await, 3);
+
+    let api = &result.packages["api"];
+    assert_eq!(api.version_bump, VersionBump::Minor);
+    assert_eq!(api.next_version.to_string(), "1.5.0");
+    assert_eq!(api.tag_prefix.as_deref(), Some("api-v"));
+    assert_eq!(api.changelog_entries.len(), 1);
+    assert_eq!(api.changelog_entries[0].commit_sha, "sha1");
+
+    let web = &result.packages["web"];
+    assert_eq!(web.version_bump, VersionBump::Patch);
+    assert!(web.current_version.is_none());
+    assert_eq!(web.next_version.to_string(), "0.1.1");
+    assert_eq!(web.analyzed_commits.len(), 1);
+    assert_eq!(web.analyzed_commits[0].sha, "sha2");
+
+    // The top-level result still covers every commit.
+    assert_eq!(result.analyzed_commits.len(), 3);
+    assert_eq!(result.version_bump, VersionBump::Minor);
+}
+
+/// A commit that changes files in several packages bumps each of them.
+#[tokio::test]
+async fn test_calculate_version_monorepo_commit_touching_two_packages_bumps_both() {
+    let commits = vec![
+        with_files(
+            make_commit("sha1", "feat!: rename shared field"),
+            &["services/api/src/model.rs", "services/web/src/model.ts"],
+        ),
+        with_files(
+            make_commit("sha2", "fix: api timeout"),
+            &["services/api/src/client.rs"],
+        ),
+    ];
+    let stub = StubGitHub::new(vec![]).with_range(commits);
+    let calc = GitHubVersionCalculator::new(stub.clone());
+
+    let result = calc
+        .calculate_version(
+            monorepo_context(),
+            monorepo_strategy(),
+            CalculationOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    // Files were already present, so no commit had to be fetched.
+    assert_eq!(stub.get_commit_call_count().await, 0);
+
+    let api = &result.packages["api"];
+    assert_eq!(api.version_bump, VersionBump::Major);
+    assert_eq!(api.next_version.to_string(), "2.0.0");
+    assert_eq!(api.analyzed_commits.len(), 2);
+
+    let web = &result.packages["web"];
+    assert_eq!(web.version_bump, VersionBump::Major);
+    assert_eq!(web.analyzed_commits.len(), 1);
+    assert!(web.changelog_entries[0].is_breaking);
+}
+
+/// Other strategies leave the per-package map empty and never fetch files.
+#[tokio::test]
+async fn test_calculate_version_conventional_has_no_packages() {
+    let commits = vec![make_commit("sha1", "feat: add endpoint")];
+    let stub = StubGitHub::new(vec![]).with_range(commits);
+    let calc = GitHubVersionCalculator::new(stub.clone());
+
+    let result = calc
+        .calculate_version(
+            monorepo_context(),
+            conventional_strategy(),
+            CalculationOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    assert!(result.packages.is_empty());
+    assert_eq!(stub.get_commit_call_count().await, 0);
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// calculate_version — commit exclusions
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn by_author(mut commit: GitCommit, name: &str, login: &str) -> GitCommit {
+    commit.author.name = name.to_string();
+    commit.author.login = Some(login.to_string());
+    commit
+}
+
+/// A commit carrying the `[skip release]` token does not bump the version.
+#[tokio::test]
+async fn test_calculate_version_skip_release_commit_produces_no_bump() {
+    let mut commit = make_commit("sha1", "feat: internal tooling");
+    commit.message = "feat: internal tooling\n\n[skip release]".to_string();
+    let stub = StubGitHub::new(vec![]).with_range(vec![commit]);
+    let calc = GitHubVersionCalculator::new(stub);
+
+    let result = calc
+        .calculate_version(
+            monorepo_context(),
+            conventional_strategy(),
+            CalculationOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.version_bump, VersionBump::None);
+    assert!(result.analyzed_commits.is_empty());
+    assert!(result.changelog_entries.is_empty());
+}
+
+/// Commits by a denied bot author are dropped before the changelog is built.
+#[tokio::test]
+async fn test_calculate_version_denied_author_is_dropped_from_changelog() {
+    let commits = vec![
+        by_author(
+            make_commit("bot1", "fix(deps): bump serde"),
+            "dependabot[bot]",
+            "dependabot[bot]",
+        ),
+        make_commit("dev1", "fix: handle empty input"),
+    ];
+    let stub = StubGitHub::new(vec![]).with_range(commits);
+    let calc = GitHubVersionCalculator::new(stub);
+    let options = CalculationOptions {
+        exclusions: crate::config::CommitExclusionConfig {
+            denied_authors: vec!["dependabot[bot]".to_string()],
+            subject_patterns: vec!["^Merge branch ".to_string()],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let result = calc
+        .calculate_version(monorepo_context(), conventional_strategy(), options)
+        .await
+        .unwrap();
+
+    let changelog_shas: Vec<&str> = result
+        .changelog_entries
+        .iter()
+        .map(|e| e.commit_sha.as_str())
+        .collect();
+    assert_eq!(changelog_shas, vec!["dev1"]);
+    assert_eq!(result.analyzed_commits.len(), 1);
+}
+
+/// A history with a GitHub merge commit and a hand-written merge whose
+/// subject is a conventional `feat:`.
+fn history_with_merge_commits() -> Vec<GitCommit> {
+    let mut pr_merge = make_commit(
+        "merge1",
+        "Merge pull request #12 from acme/fix-input\n\nfix: handle empty input",
+    );
+    pr_merge.parents = vec!["dev1".to_string(), "base".to_string()];
+    let mut sync_merge = make_commit("merge2", "feat: sync release branch");
+    sync_merge.parents = vec!["merge1".to_string(), "release".to_string()];
+    vec![
+        make_commit("dev1", "fix: handle empty input"),
+        pr_merge,
+        sync_merge,
+    ]
+}
+
+async fn calculate_with_merge_commits(ignore_merge_commits: bool) -> VersionCalculationResult {
+    let stub = StubGitHub::new(vec![]).with_range(history_with_merge_commits());
+    let options = CalculationOptions {
+        exclusions: crate::config::CommitExclusionConfig {
+            ignore_merge_commits,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    GitHubVersionCalculator::new(stub)
+        .calculate_version(monorepo_context(), conventional_strategy(), options)
+        .await
+        .unwrap()
+}
+
+/// By default merge commits neither bump the version nor reach the changelog.
+#[tokio::test]
+async fn test_calculate_version_ignores_merge_commits_by_default() {
+    let result = calculate_with_merge_commits(true).await;
+
+    let analyzed: Vec<&str> = result
+        .analyzed_commits
+        .iter()
+        .map(|a| a.sha.as_str())
+        .collect();
+    assert_eq!(analyzed, vec!["dev1"]);
+    assert_eq!(result.version_bump, VersionBump::Patch);
+    assert_eq!(result.changelog_entries.len(), 1);
+    assert_eq!(result.changelog_entries[0].commit_sha, "dev1");
+}
+
+/// With `ignore_merge_commits = false` merge commits are parsed like any
+/// other commit, so a `feat:` merge bumps the minor version.
+#[tokio::test]
+async fn test_calculate_version_parses_merge_commits_when_not_ignored() {
+    let result = calculate_with_merge_commits(false).await;
+
+    let analyzed: Vec<&str> = result
+        .analyzed_commits
+        .iter()
+        .map(|a| a.sha.as_str())
+        .collect();
+    assert_eq!(analyzed, vec!["dev1", "merge1", "merge2"]);
+    assert_eq!(result.version_bump, VersionBump::Minor);
+    assert!(result
+        .changelog_entries
+        .iter()
+        .any(|e| e.commit_sha == "merge2"));
+}
+
+/// An invalid exclusion pattern is reported instead of being ignored.
+#[tokio::test]
+async fn test_calculate_version_invalid_exclusion_pattern_is_an_error() {
+    let calc = GitHubVersionCalculator::new(StubGitHub::new(vec![]));
+    let options = CalculationOptions {
+        exclusions: crate::config::CommitExclusionConfig {
+            subject_patterns: vec!["[".to_string()],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let result = calc
+        .calculate_version(monorepo_context(), conventional_strategy(), options)
+        .await;
+
+    assert!(matches!(result, Err(CoreError::InvalidInput { .. })));
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// calculate_version — build metadata
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The build metadata template is rendered from the head commit and appended
+/// to the calculated version without changing the bump.
+#[tokio::test]
+async fn test_calculate_version_appends_rendered_build_metadata() {
+    let head = make_commit("0123456789abcdef0123456789abcdef01234567", "fix: edge case");
+    let mut stub = StubGitHub::new(vec![]).with_range(vec![head.clone()]);
+    stub.commits.insert("main".to_string(), head);
+    let calc = GitHubVersionCalculator::new(stub.clone());
+    let mut context = monorepo_context();
+    context.current_version =
+        Some(crate::versioning::VersionCalculator::parse_version("1.4.2+sha.fffffff").unwrap());
+    let options = CalculationOptions {
+        build_metadata: Some("sha.{short_sha}".to_string()),
+        ..Default::default()
+    };
+
+    let result = calc
+        .calculate_version(context, conventional_strategy(), options)
+        .await
+        .unwrap();
+
+    // `main` is not a SHA, so it is resolved through the API.
+    assert_eq!(stub.get_commit_call_count().await, 1);
+    assert_eq!(result.version_bump, VersionBump::Patch);
+    assert_eq!(result.next_version.to_string(), "1.4.3+sha.0123456");
+    assert_eq!(result.build_metadata.as_deref(), Some("sha.0123456"));
+}
+
+/// A head ref that already is a SHA is used as-is.
+#[tokio::test]
+async fn test_calculate_version_build_metadata_uses_sha_head_ref_directly() {
+    let stub = StubGitHub::new(vec![]);
+    let calc = GitHubVersionCalculator::new(stub.clone());
+    let mut context = make_context();
+    context.head_ref = "abcdef0123456789abcdef0123456789abcdef01".to_string();
+    let options = CalculationOptions {
+        build_metadata: Some("{sha}".to_string()),
+        ..Default::default()
+    };
+
+    let result = calc
+        .calculate_version(context, conventional_strategy(), options)
+        .await
+        .unwrap();
+
+    assert_eq!(stub.get_commit_call_count().await, 0);
+    assert_eq!(
+        result.next_version.build.as_deref(),
+        Some("abcdef0123456789abcdef0123456789abcdef01")
+    );
+}
+
+/// A clock stopped at a fixed time.
+#[derive(Debug)]
+struct FixedClock(chrono::DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> chrono::DateTime<Utc> {
+        self.0
+    }
+}
+
+/// Date placeholders are rendered from the calculator's clock.
+#[tokio::test]
+async fn test_calculate_version_build_metadata_timestamp_comes_from_the_clock() {
+    let at = chrono::DateTime::parse_from_rfc3339("2024-05-01T12:30:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    let calc =
+        GitHubVersionCalculator::new(StubGitHub::new(vec![])).with_clock(Arc::new(FixedClock(at)));
+    let options = CalculationOptions {
+        build_metadata: Some("build.{date}.{timestamp}".to_string()),
+        ..Default::default()
+    };
+
+    let result = calc
+        .calculate_version(make_context(), conventional_strategy(), options)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.build_metadata.as_deref(),
+        Some("build.20240501.20240501123000")
+    );
+}
+
+/// A failed commit comparison keeps its category and names the stage.
+#[tokio::test]
+async fn test_calculate_version_comparison_error_carries_commit_comparison_stage() {
+    let calc = GitHubVersionCalculator::new(StubGitHub::new(vec![]).with_failing_comparison());
+    let context = VersionContext {
+        base_ref: Some("v1.0.0".to_string()),
+        current_version: Some(crate::versioning::SemanticVersion {
+            major: 1,
+            minor: 0,
+            patch: 0,
+            prerelease: None,
+            build: None,
+        }),
+        ..make_context()
+    };
+
+    let err = calc
+        .calculate_version(
+            context,
+            conventional_strategy(),
+            CalculationOptions::default(),
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, CoreError::Network { .. }), "got {err:?}");
+    assert!(err.is_retryable());
+    assert_eq!(err.stage(), Some(ProcessingStage::CommitComparison));
+    assert_eq!(
+        err.stage_summary().as_deref(),
+        Some("failed during commit comparison for owner/repo")
+    );
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Breaking-change footers in the commit body
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A commit whose subject is not breaking but whose body carries a
+/// `BREAKING CHANGE:` footer.
+fn commit_with_breaking_footer(sha: &str) -> GitCommit {
+    let mut commit = make_commit(sha, "fix: tighten token validation");
+    let body = "Tokens without an expiry are now rejected.\n\n\
+                BREAKING CHANGE: clients must send tokens with an exp claim";
+    commit.message = format!("{}\n\n{body}", commit.subject);
+    commit.body = Some(body.to_string());
+    commit
+}
+
+/// `analyze_commits` re-parses the full message fetched with `get_commit`,
+/// so a footer that only appears in the body is detected.
+#[tokio::test]
+async fn test_analyze_commits_detects_breaking_footer_in_full_body() {
+    let stub = StubGitHub::new(vec![commit_with_breaking_footer("sha1")]);
+    let calc = GitHubVersionCalculator::new(stub.clone());
+
+    let result = calc
+        .analyze_commits(
+            make_context(),
+            conventional_strategy(),
+            vec!["sha1".to_string()],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(stub.get_commit_call_count().await, 1);
+    assert_eq!(result[0].commit_type, Some("fix".to_string()));
+    assert!(result[0].is_breaking);
+    assert_eq!(result[0].version_bump, VersionBump::Major);
+}
+
+/// `calculate_version` honours a breaking footer in the body of a commit in
+/// the compared range.
+#[tokio::test]
+async fn test_calculate_version_breaking_footer_in_body_bumps_major() {
+    let stub = StubGitHub::new(vec![]).with_range(vec![commit_with_breaking_footer("sha1")]);
+    let calc = GitHubVersionCalculator::new(stub);
+
+    let result = calc
+        .calculate_version(
+            monorepo_context(),
+            conventional_strategy(),
+            CalculationOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.version_bump, VersionBump::Major);
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Minimum version
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A conventional bump below the configured floor is replaced by the floor.
+#[tokio::test]
+async fn test_calculate_version_minimum_version_wins_over_lower_bump() {
+    let stub = StubGitHub::new(vec![]).with_range(vec![make_commit("sha1", "fix: edge case")]);
+    let calc = GitHubVersionCalculator::new(stub);
+    let mut context = monorepo_context();
+    context.current_version =
+        Some(crate::versioning::VersionCalculator::parse_version("1.4.2").unwrap());
+    let options = CalculationOptions {
+        minimum_version: Some(
+            crate::versioning::VersionCalculator::parse_version("2.0.0").unwrap(),
+        ),
+        ..Default::default()
+    };
+
+    let result = calc
+        .calculate_version(context, conventional_strategy(), options)
+        .await
+        .unwrap();
+
+    assert_eq!(result.next_version.to_string(), "2.0.0");
+    assert_eq!(result.version_bump, VersionBump::Patch);
+    assert_eq!(
+        result
+            .metadata
+            .get(crate::traits::version_calculator::VersionCalculationResult::MINIMUM_VERSION_APPLIED)
+            .map(String::as_str),
+        Some("2.0.0")
+    );
+}
+
+/// A conventional bump above the floor is kept.
+#[tokio::test]
+async fn test_calculate_version_minimum_version_below_bump_is_ignored() {
+    let stub = StubGitHub::new(vec![]).with_range(vec![make_commit("sha1", "feat: widgets")]);
+    let calc = GitHubVersionCalculator::new(stub);
+    let mut context = monorepo_context();
+    context.current_version =
+        Some(crate::versioning::VersionCalculator::parse_version("2.3.0").unwrap());
+    let options = CalculationOptions {
+        minimum_version: Some(
+            crate::versioning::VersionCalculator::parse_version("2.0.0").unwrap(),
+        ),
+        ..Default::default()
+    };
+
+    let result = calc
+        .calculate_version(context, conventional_strategy(), options)
+        .await
+        .unwrap();
+
+    assert_eq!(result.next_version.to_string(), "2.4.0");
+    assert!(!result.metadata.contains_key(
+        crate::traits::version_calculator::VersionCalculationResult::MINIMUM_VERSION_APPLIED
+    ));
+}
+
+fn internal_scope_excluded() -> CalculationOptions {
+    CalculationOptions {
+        bump_scopes: crate::config::ScopeFilterConfig {
+            exclude: vec!["internal".to_string()],
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// A `feat` in an excluded scope does not bump the version but is still
+/// analyzed, so it can appear in the changelog.
+#[tokio::test]
+async fn test_calculate_version_excluded_scope_does_not_bump() {
+    let commits = vec![make_commit("int1", "feat(internal): rework cache")];
+    let calc = GitHubVersionCalculator::new(StubGitHub::new(vec![]).with_range(commits));
+
+    let result = calc
+        .calculate_version(
+            monorepo_context(),
+            conventional_strategy(),
+            internal_scope_excluded(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.version_bump, VersionBump::None);
+    assert_eq!(result.analyzed_commits.len(), 1);
+}
+
+/// Only the commit in an allowed scope determines the bump.
+#[tokio::test]
+async fn test_calculate_version_allowed_scope_bumps_minor() {
+    let commits = vec![
+        make_commit("int1", "feat(internal): rework cache"),
+        make_commit("api1", "feat(api): add endpoint"),
+        make_commit("doc1", "feat(docs)!: restructure guide"),
+    ];
+    let calc = GitHubVersionCalculator::new(StubGitHub::new(vec![]).with_range(commits));
+    let options = CalculationOptions {
+        bump_scopes: crate::config::ScopeFilterConfig {
+            include: vec!["core".to_string(), "API".to_string()],
+            exclude: vec!["internal".to_string()],
+        },
+        ..Default::default()
+    };
+
+    let result = calc
+        .calculate_version(monorepo_context(), conventional_strategy(), options)
+        .await
+        .unwrap();
+
+    assert_eq!(result.version_bump, VersionBump::Minor);
+    assert_eq!(result.analyzed_commits.len(), 3);
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// First release
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn first_release_history() -> Vec<GitCommit> {
+    vec![
+        make_commit("sha1", "chore: initial commit"),
+        make_commit("sha2", "feat: add parser"),
+        make_commit("sha3", "fix: handle empty input"),
+    ]
+}
+
+/// Without a current version the whole history of the head ref is analysed.
+#[tokio::test]
+async fn test_calculate_version_first_release_analyses_full_history() {
+    let stub = StubGitHub::new(vec![]).with_range(first_release_history());
+    let calc = GitHubVersionCalculator::new(stub);
+
+    let result = calc
+        .calculate_version(
+            make_context(),
+            conventional_strategy(),
+            CalculationOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    assert!(result.is_first_release());
+    let shas: Vec<&str> = result
+        .analyzed_commits
+        .iter()
+        .map(|c| c.sha.as_str())
+        .collect();
+    assert_eq!(shas, vec!["sha1", "sha2", "sha3"]);
+    assert_eq!(result.changelog_entries.len(), 2);
+    // Without an initial version the first release is calculated from 0.1.0.
+    assert_eq!(result.version_bump, VersionBump::Minor);
+    assert_eq!(result.next_version.to_string(), "0.2.0");
+}
+
+/// The configured initial version is used as-is for the first release.
+#[tokio::test]
+async fn test_calculate_version_first_release_uses_initial_version() {
+    let stub = StubGitHub::new(vec![]).with_range(first_release_history());
+    let calc = GitHubVersionCalculator::new(stub);
+    let options = CalculationOptions {
+        initial_version: Some(
+            crate::versioning::VersionCalculator::parse_version("1.0.0").unwrap(),
+        ),
+        ..Default::default()
+    };
+
+    let result = calc
+        .calculate_version(make_context(), conventional_strategy(), options)
+        .await
+        .unwrap();
+
+    assert_eq!(result.next_version.to_string(), "1.0.0");
+    assert_eq!(result.analyzed_commits.len(), 3);
+}
+
+/// The initial version is ignored once a release exists.
+#[tokio::test]
+async fn test_calculate_version_initial_version_ignored_after_first_release() {
+    let stub = StubGitHub::new(vec![]).with_range(vec![make_commit("sha4", "fix: typo")]);
+    let calc = GitHubVersionCalculator::new(stub);
+    let mut context = monorepo_context();
+    context.current_version =
+        Some(crate::versioning::VersionCalculator::parse_version("1.2.0").unwrap());
+    let options = CalculationOptions {
+        initial_version: Some(
+            crate::versioning::VersionCalculator::parse_version("1.0.0").unwrap(),
+        ),
+        ..Default::default()
+    };
+
+    let result = calc
+        .calculate_version(context, conventional_strategy(), options)
+        .await
+        .unwrap();
+
+    assert!(!result.is_first_release());
+    assert_eq!(result.next_version.to_string(), "1.2.1");
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Reverts
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Calculate the version of `commits` on top of 1.2.0.
+async fn calculate_after_1_2_0(
+    commits: Vec<GitCommit>,
+) -> crate::traits::version_calculator::VersionCalculationResult {
+    let stub = StubGitHub::new(vec![]).with_range(commits);
+    let calc = GitHubVersionCalculator::new(stub);
+    let mut context = monorepo_context();
+    context.current_version =
+        Some(crate::versioning::VersionCalculator::parse_version("1.2.0").unwrap());
+
+    calc.calculate_version(
+        context,
+        conventional_strategy(),
+        CalculationOptions::default(),
+    )
+    .await
+    .unwrap()
+}
+
+/// A feature reverted in the same release does not bump the minor version.
+#[tokio::test]
+async fn test_calculate_version_reverted_feature_does_not_bump_minor() {
+    let result = calculate_after_1_2_0(vec![
+        make_commit("aaaaaaa1", "feat: add export"),
+        make_commit(
+            "bbbbbbb2",
+            "Revert \"feat: add export\"\n\nThis reverts commit aaaaaaa1.",
+        ),
+    ])
+    .await;
+
+    assert_eq!(result.version_bump, VersionBump::None);
+    assert_eq!(result.next_version.to_string(), "1.2.0");
+    // Both commits are still analysed, so the changelog can note the revert.
+    assert_eq!(result.analyzed_commits.len(), 2);
+    assert_eq!(
+        result.analyzed_commits[1].commit_type.as_deref(),
+        Some("revert")
+    );
+}
+
+/// Other commits in the release still count when a feature is reverted.
+#[tokio::test]
+async fn test_calculate_version_reverted_feature_leaves_other_bumps() {
+    let result = calculate_after_1_2_0(vec![
+        make_commit("aaaaaaa1", "feat: add export"),
+        make_commit("ccccccc3", "fix: handle empty input"),
+        make_commit("bbbbbbb2", "revert: feat: add export"),
+    ])
+    .await;
+
+    assert_eq!(result.version_bump, VersionBump::Patch);
+    assert_eq!(result.next_version.to_string(), "1.2.1");
+}
+
+/// A revert of a commit from an earlier release is a patch.
+#[tokio::test]
+async fn test_calculate_version_unpaired_revert_bumps_patch() {
+    let result = calculate_after_1_2_0(vec![make_commit(
+        "bbbbbbb2",
+        "Revert \"feat: add export\"\n\nThis reverts commit 0123456789abcdef.",
+    )])
+    .await;
+
+    assert_eq!(result.version_bump, VersionBump::Patch);
+    assert_eq!(result.next_version.to_string(), "1.2.1");
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Breaking scopes
+// ─────────────────────────────────────────────────────────────────────────────
+
+async fn calculate_with_breaking_scopes(
+    breaking_scopes: Vec<String>,
+) -> crate::traits::version_calculator::VersionCalculationResult {
+    let stub = StubGitHub::new(vec![]).with_range(vec![
+        make_commit("sha1", "feat(api): add export"),
+        make_commit("sha2", "fix(ui): align button"),
+    ]);
+    let calc = GitHubVersionCalculator::new(stub);
+    let mut context = monorepo_context();
+    context.current_version =
+        Some(crate::versioning::VersionCalculator::parse_version("1.2.0").unwrap());
+    let options = CalculationOptions {
+        breaking_scopes,
+        ..Default::default()
+    };
+
+    calc.calculate_version(context, conventional_strategy(), options)
+        .await
+        .unwrap()
+}
+
+/// A `feat(api)` commit is a major bump when `api` is a breaking scope.
+#[tokio::test]
+async fn test_calculate_version_breaking_scope_bumps_major() {
+    let result = calculate_with_breaking_scopes(vec!["api".to_string()]).await;
+
+    assert_eq!(result.version_bump, VersionBump::Major);
+    assert_eq!(result.next_version.to_string(), "2.0.0");
+    let breaking: Vec<bool> = result
+        .changelog_entries
+        .iter()
+        .map(|e| e.is_breaking)
+        .collect();
+    assert_eq!(breaking, vec![true, false]);
+}
+
+/// Without breaking scopes the same commits are a minor bump.
+#[tokio::test]
+async fn test_calculate_version_without_breaking_scopes_bumps_minor() {
+    let result = calculate_with_breaking_scopes(Vec::new()).await;
+
+    assert_eq!(result.version_bump, VersionBump::Minor);
+    assert_eq!(result.next_version.to_string(), "1.3.0");
+    assert!(result.changelog_entries.iter().all(|e| !e.is_breaking));
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Bump reason
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A major bump is attributed to the breaking commit.
+#[tokio::test]
+async fn test_calculate_version_bump_reason_names_breaking_commit() {
+    let result = calculate_after_1_2_0(vec![
+        make_commit("aaaaaaa1", "feat: add export"),
+        make_commit("bbbbbbb2", "feat!: drop the v1 API"),
+        make_commit("ccccccc3", "fix: handle empty input"),
+    ])
+    .await;
+
+    let reason = result.bump_reason.expect("bump reason");
+    assert_eq!(reason.bump, VersionBump::Major);
+    assert_eq!(reason.rule, "BREAKING CHANGE");
+    assert_eq!(reason.commits, vec!["bbbbbbb2"]);
+    assert_eq!(reason.to_string(), "major: BREAKING CHANGE in bbbbbbb");
+}
+
+/// A minor bump is attributed to the earliest feature, by commit date.
+#[tokio::test]
+async fn test_calculate_version_bump_reason_names_earliest_feature() {
+    let now = Utc::now();
+    let dated = |sha: &str, message: &str, minutes_ago: i64| GitCommit {
+        author_date: now - chrono::Duration::minutes(minutes_ago),
+        ..make_commit(sha, message)
+    };
+    let result = calculate_after_1_2_0(vec![
+        dated("ccccccc3", "feat: add import", 1),
+        dated("ddddddd4", "fix: handle empty input", 2),
+        dated("aaaaaaa1", "feat: add export", 3),
+    ])
+    .await;
+
+    let reason = result.bump_reason.expect("bump reason");
+    assert_eq!(reason.bump, VersionBump::Minor);
+    assert_eq!(reason.rule, "feat");
+    assert_eq!(reason.commits, vec!["aaaaaaa1", "ccccccc3"]);
+    assert_eq!(reason.to_string(), "minor: feat in aaaaaaa (and 1 more)");
+}
+
+/// Without bumping commits the reason says so.
+#[tokio::test]
+async fn test_calculate_version_bump_reason_without_bumping_commits() {
+    let result = calculate_after_1_2_0(vec![make_commit("aaaaaaa1", "docs: typo")]).await;
+
+    let reason = result.bump_reason.expect("bump reason");
+    assert_eq!(reason.bump, VersionBump::None);
+    assert!(reason.commits.is_empty());
+    assert_eq!(reason.to_string(), BumpReason::NO_BUMPING_COMMITS);
+}
+
+/// A history in which no commit follows the conventional commit format.
+fn history_without_conventional_commits() -> Vec<GitCommit> {
+    vec![
+        make_commit("c1", "Update README"),
+        make_commit("c2", "tweak build script"),
+    ]
+}
+
+async fn calculate_with_policy(
+    policy: crate::config::NoConventionalCommitsPolicy,
+) -> CoreResult<VersionCalculationResult> {
+    let stub = StubGitHub::new(vec![]).with_range(history_without_conventional_commits());
+    let options = CalculationOptions {
+        no_conventional_commits: policy,
+        ..Default::default()
+    };
+
+    GitHubVersionCalculator::new(stub)
+        .calculate_version(monorepo_context(), conventional_strategy(), options)
+        .await
+}
+
+/// The default policy keeps the "no bump" outcome and says why.
+#[tokio::test]
+async fn test_no_conventional_commits_policy_none_keeps_no_bump() {
+    let result = calculate_with_policy(Default::default()).await.unwrap();
+
+    assert_eq!(result.version_bump, VersionBump::None);
+    let reason = result.bump_reason.expect("bump reason");
+    assert_eq!(reason.bump, VersionBump::None);
+    assert!(reason.commits.is_empty());
+    assert!(
+        reason.rule.starts_with(BumpReason::NO_CONVENTIONAL_COMMITS),
+        "{}",
+        reason.rule
+    );
+    assert!(
+        reason.rule.contains("no_conventional_commits = \"none\""),
+        "{}",
+        reason.rule
+    );
+}
+
+/// The `patch` policy bumps the patch version and attributes it to every
+/// commit.
+#[tokio::test]
+async fn test_no_conventional_commits_policy_patch_bumps_patch() {
+    let result = calculate_with_policy(crate::config::NoConventionalCommitsPolicy::Patch)
+        .await
+        .unwrap();
+
+    assert_eq!(result.version_bump, VersionBump::Patch);
+    let reason = result.bump_reason.expect("bump reason");
+    assert_eq!(reason.bump, VersionBump::Patch);
+    assert_eq!(reason.commits, vec!["c1", "c2"]);
+    assert!(
+        reason.rule.contains("no_conventional_commits = \"patch\""),
+        "{}",
+        reason.rule
+    );
+}
+
+/// The `error` policy fails the calculation.
+#[tokio::test]
+async fn test_no_conventional_commits_policy_error_fails() {
+    let result = calculate_with_policy(crate::config::NoConventionalCommitsPolicy::Error).await;
+
+    match result {
+        Err(CoreError::Versioning { reason, .. }) => {
+            assert!(reason.contains("no_conventional_commits"), "{reason}");
+        }
+        other => panic!("expected a versioning error, got {other:?}"),
+    }
+}
+
+/// A single conventional commit means the policy does not apply.
+#[tokio::test]
+async fn test_no_conventional_commits_policy_ignored_with_a_conventional_commit() {
+    let mut history = history_without_conventional_commits();
+    history.push(make_commit("c3", "fix: handle empty input"));
+    let stub = StubGitHub::new(vec![]).with_range(history);
+    let options = CalculationOptions {
+        no_conventional_commits: crate::config::NoConventionalCommitsPolicy::Error,
+        ..Default::default()
+    };
+
+    let result = GitHubVersionCalculator::new(stub)
+        .calculate_version(monorepo_context(), conventional_strategy(), options)
+        .await
+        .unwrap();
+
+    assert_eq!(result.version_bump, VersionBump::Patch);
+    let reason = result.bump_reason.expect("bump reason");
+    assert!(
+        !reason.rule.contains("no_conventional_commits"),
+        "{}",
+        reason.rule
+    );
+}
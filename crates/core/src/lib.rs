@@ -203,24 +203,42 @@
 //! - **Input Sanitization**: Comprehensive validation of all external inputs
 //! - **Audit Logging**: Structured logging with correlation IDs for security monitoring
 
+pub mod branch_template;
 pub mod changelog;
+pub mod clock;
 pub mod comment_command_processor;
+pub mod commit_filter;
 pub mod config;
 pub(crate) mod default_version_calculator;
+pub mod dry_run;
 pub mod errors;
 pub(crate) mod github_version_calculator;
 pub mod manifest;
+pub mod monorepo;
 pub(crate) mod pr_status_commenter;
 pub mod release_automator;
 pub mod release_orchestrator;
+pub mod repository_allow_list;
+pub mod secret;
+pub mod tag_signing;
+pub mod telemetry;
 pub mod traits;
 pub mod versioning;
+pub mod webhook;
 
 pub use default_version_calculator::DefaultVersionCalculator;
-pub use errors::{CoreError, CoreResult};
+pub use dry_run::{
+    DryRunAction, DryRunCalculation, DryRunGitHubOperations, DryRunLog, DryRunReport,
+};
+pub use errors::{CoreError, CoreResult, ProcessingStage};
 pub use github_version_calculator::GitHubVersionCalculator;
 pub use manifest::{ManifestFileConfig, ManifestFormat};
-pub use traits::{ConfigurationProvider, GitHubOperations, GitOperations, VersionCalculator};
+pub use repository_allow_list::RepositoryAllowList;
+pub use secret::SensitiveString;
+pub use traits::{
+    ChangelogContext, ChangelogRenderer, ConfigurationProvider, GitHubOperations, GitOperations,
+    VersionCalculator,
+};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // MergedPullRequestHandler — event handler trait
@@ -286,6 +304,29 @@ pub trait MergedPullRequestHandler: Send + Sync {
     ) -> CoreResult<()> {
         Ok(())
     }
+
+    /// Process a single `PullRequestReactivated` event.
+    ///
+    /// Called when the event loop receives
+    /// [`EventType::PullRequestReactivated`].  The default implementation
+    /// delegates to [`handle_pull_request_activity`](Self::handle_pull_request_activity).
+    /// Override in the production processor to also refresh the release PR
+    /// when the reactivated PR is one.
+    async fn handle_pull_request_reactivated(
+        &self,
+        event: &traits::event_source::ProcessingEvent,
+    ) -> CoreResult<()> {
+        self.handle_pull_request_activity(event).await
+    }
+
+    /// Return `true` if events for `repository` should be processed.
+    ///
+    /// Called by the event loop before dispatching each event. Events for a
+    /// repository that is not allowed are acknowledged without calling any
+    /// handler method. The default implementation allows every repository.
+    fn is_repository_allowed(&self, _repository: &traits::event_source::RepositoryInfo) -> bool {
+        true
+    }
 }
 
 #[async_trait::async_trait]
@@ -295,11 +336,18 @@ where
     C: ConfigurationProvider + Send + Sync,
     V: VersionCalculator + Send + Sync,
 {
+    fn is_repository_allowed(&self, repository: &traits::event_source::RepositoryInfo) -> bool {
+        self.allowed_repos
+            .is_allowed(&repository.owner, &repository.name)
+    }
+
     async fn handle_merged_pull_request(
         &self,
         event: &traits::event_source::ProcessingEvent,
     ) -> CoreResult<()> {
-        match self.handle_merged_pull_request(event).await {
+        let event = self.with_resolved_default_branch(event).await?;
+        let event = &*event;
+        match self.orchestrate_merged_pull_request(event).await {
             Ok(result) => {
                 tracing::info!(result = ?result, "Release orchestration completed");
                 self.try_refresh_feature_pr_status_comments(
@@ -321,13 +369,15 @@ where
         use release_automator::{AutomatorConfig, ReleaseAutomator};
         use traits::configuration_provider::LoadOptions;
 
+        let event = self.with_resolved_default_branch(event).await?;
+        let event = &*event;
         let correlation_id = &event.correlation_id;
         let owner = &event.repository.owner;
         let repo = &event.repository.name;
 
-        let repo_config = self
-            .configuration_provider
-            .get_merged_config(
+        let repo_config = telemetry::time_stage(
+            telemetry::STAGE_CONFIG_LOAD,
+            self.configuration_provider.get_merged_config(
                 owner,
                 repo,
                 LoadOptions {
@@ -335,8 +385,9 @@ where
                     default_branch: Some(event.repository.default_branch.clone()),
                     ..Default::default()
                 },
-            )
-            .await?;
+            ),
+        )
+        .await?;
 
         let config = AutomatorConfig {
             branch_prefix: release_orchestrator::OrchestratorConfig::DEFAULT_BRANCH_PREFIX
@@ -346,19 +397,40 @@ where
             ),
             version_prefix: repo_config.core.version_prefix.clone(),
             generate_release_notes: repo_config.releases.generate_notes,
+            create_as_draft: repo_config.releases.draft,
+            prerelease: repo_config.releases.prerelease,
+            tag_signing: repo_config
+                .releases
+                .enabled_tag_signing()
+                .map(tag_signing::TagSigning::from_config)
+                .transpose()?,
+            branch_template: repo_config.release_pr.branch_template.clone(),
         };
 
-        match ReleaseAutomator::new(
-            config,
-            &self
-                .github_operations
-                .scoped_to(self.resolve_installation_id(owner, repo).await?),
+        match telemetry::time_stage(
+            telemetry::STAGE_RELEASE_CREATION,
+            ReleaseAutomator::new(
+                config,
+                &self
+                    .github_operations
+                    .scoped_to(self.resolve_installation_id(owner, repo).await?),
+            )
+            .automate(owner, repo, event, correlation_id),
         )
-        .automate(owner, repo, event, correlation_id)
         .await
         {
             Ok(result) => {
                 tracing::info!(result = ?result, "Release automation completed");
+                let release = result.release();
+                if release.draft {
+                    // A draft release is not published yet, so the override
+                    // labels still apply to the current release cycle.
+                    tracing::info!(
+                        release_id = release.id,
+                        "Release created as draft; skipping post-publish steps"
+                    );
+                    return Ok(());
+                }
                 // Clear stale rr:override-* labels from open feature PRs now that
                 // a new release has been published.
                 self.clear_stale_override_labels_after_release(
@@ -387,12 +459,14 @@ where
         use comment_command_processor::{CommentCommandConfig, CommentCommandProcessor};
         use traits::configuration_provider::LoadOptions;
 
+        let event = self.with_resolved_default_branch(event).await?;
+        let event = &*event;
         let owner = &event.repository.owner;
         let repo = &event.repository.name;
 
-        let repo_config = self
-            .configuration_provider
-            .get_merged_config(
+        let repo_config = telemetry::time_stage(
+            telemetry::STAGE_CONFIG_LOAD,
+            self.configuration_provider.get_merged_config(
                 owner,
                 repo,
                 LoadOptions {
@@ -400,22 +474,12 @@ where
                     default_branch: Some(event.repository.default_branch.clone()),
                     ..Default::default()
                 },
-            )
-            .await?;
+            ),
+        )
+        .await?;
 
         let config = CommentCommandConfig {
-            orchestrator_config: release_orchestrator::OrchestratorConfig {
-                branch_prefix: release_orchestrator::OrchestratorConfig::DEFAULT_BRANCH_PREFIX
-                    .to_string(),
-                version_prefix: repo_config.core.version_prefix.clone(),
-                title_template: repo_config.release_pr.title_template.clone(),
-                changelog_header: release_orchestrator::extract_changelog_header(
-                    &repo_config.release_pr.body_template,
-                ),
-                body_template: repo_config.release_pr.body_template.clone(),
-                manifest_files: repo_config.release_pr.manifest_files.clone(),
-                auto_detect_manifests: repo_config.release_pr.auto_detect_manifests,
-            },
+            orchestrator_config: release_orchestrator_config(&repo_config),
             allow_override: repo_config.versioning.allow_override,
         };
 
@@ -429,14 +493,25 @@ where
         .await
     }
 
+    async fn handle_pull_request_reactivated(
+        &self,
+        event: &traits::event_source::ProcessingEvent,
+    ) -> CoreResult<()> {
+        if let Some(result) = self.refresh_release_pr(event).await? {
+            tracing::info!(result = ?result, "Release PR refresh completed");
+        }
+        self.handle_pull_request_activity(event).await
+    }
+
     async fn handle_pull_request_activity(
         &self,
         event: &traits::event_source::ProcessingEvent,
     ) -> CoreResult<()> {
-        use release_automator::is_release_pr_branch;
         use traits::configuration_provider::LoadOptions;
         use traits::version_calculator::{CalculationOptions, VersionContext, VersioningStrategy};
 
+        let event = self.with_resolved_default_branch(event).await?;
+        let event = &*event;
         let owner = &event.repository.owner;
         let repo = &event.repository.name;
 
@@ -482,12 +557,18 @@ where
             .and_then(serde_json::Value::as_str)
             .unwrap_or_default()
             .to_string();
+        let pr_base_branch = event
+            .payload
+            .pointer("/pull_request/base/ref")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or(&event.repository.default_branch)
+            .to_string();
 
         let installation_id = self.resolve_installation_id(owner, repo).await?;
 
-        let repo_config = self
-            .configuration_provider
-            .get_merged_config(
+        let repo_config = telemetry::time_stage(
+            telemetry::STAGE_CONFIG_LOAD,
+            self.configuration_provider.get_merged_config(
                 owner,
                 repo,
                 LoadOptions {
@@ -495,8 +576,9 @@ where
                     default_branch: Some(event.repository.default_branch.clone()),
                     ..Default::default()
                 },
-            )
-            .await?;
+            ),
+        )
+        .await?;
 
         // Skip PRs from excluded authors.
         if repo_config
@@ -515,17 +597,33 @@ where
             return Ok(());
         }
 
-        let branch_prefix =
-            release_orchestrator::OrchestratorConfig::DEFAULT_BRANCH_PREFIX.to_string();
-        let version_prefix = repo_config.core.version_prefix.clone();
+        let branch_template = repo_config.release_pr.branch_template();
+        let branch_vars = release_branch_vars(&repo_config, owner, repo, &pr_base_branch);
+        let release_branch_prefix =
+            branch_template::release_branch_prefix(branch_template, &branch_vars)?;
         let scoped_github = self.github_operations.scoped_to(installation_id);
 
-        let body = if is_release_pr_branch(&pr_head_branch, &branch_prefix, &version_prefix) {
-            // Release PR path (F.3): extract version from branch name.
-            let release_version = release_automator::extract_version_from_branch(
+        let body = if branch_template::is_release_branch(
+            branch_template,
+            &branch_vars,
+            &pr_head_branch,
+        )? {
+            // Release PR path (F.3): read the version from the branch name, or
+            // from the PR when the branch name carries none.
+            let release_version = branch_template::version_from_release_pr(
+                branch_template,
+                &branch_vars,
                 &pr_head_branch,
-                &branch_prefix,
-                &version_prefix,
+                event
+                    .payload
+                    .pointer("/pull_request/title")
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or_default(),
+                event
+                    .payload
+                    .pointer("/pull_request/body")
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or_default(),
             )?;
             pr_status_commenter::render_release_pr_comment(
                 &release_version,
@@ -535,6 +633,13 @@ where
             // Feature PR path (F.2): project the next version from commits.
             let current_version =
                 versioning::resolve_current_version(&scoped_github, owner, repo, false).await?;
+            let package_versions = monorepo::resolve_package_versions(
+                &scoped_github,
+                owner,
+                repo,
+                repo_config.versioning.strategy.packages(),
+            )
+            .await?;
 
             let strategy = match repo_config.versioning.strategy {
                 config::VersioningStrategy::Conventional
@@ -544,23 +649,39 @@ where
                         include_prerelease: false,
                     }
                 }
+                ref monorepo @ config::VersioningStrategy::Monorepo { .. } => {
+                    monorepo.clone().into()
+                }
             };
 
-            let ctx = VersionContext {
-                base_ref: current_version
-                    .as_ref()
-                    .map(|v| format!("{}{v}", repo_config.core.version_prefix)),
-                current_version: current_version.clone(),
-                head_ref: pr_head_sha,
-                owner: owner.to_string(),
-                repo: repo.to_string(),
-                target_branch: pr_head_branch,
-            };
+            let ctx = VersionContext::builder()
+                .with_owner(owner)
+                .with_repo(repo)
+                .with_current_version(current_version.clone())
+                .with_package_versions(package_versions)
+                .with_version_prefix(&repo_config.core.version_prefix)
+                .with_head_ref(pr_head_sha)
+                .with_target_branch(pr_head_branch)
+                .build()?;
 
             let scoped_calc = self.version_calculator.scoped_to(installation_id);
-            let calc_result = scoped_calc
-                .calculate_version(ctx, strategy, CalculationOptions::default())
-                .await?;
+            let calc_result = telemetry::time_stage(
+                telemetry::STAGE_VERSION_CALCULATION,
+                scoped_calc.calculate_version(
+                    ctx,
+                    strategy,
+                    CalculationOptions {
+                        bump_scopes: repo_config.versioning.scopes.clone(),
+                        breaking_scopes: repo_config.versioning.breaking_scopes.clone(),
+                        no_conventional_commits: repo_config.versioning.no_conventional_commits,
+                        exclusions: repo_config.versioning.exclusions.clone(),
+                        minimum_version: repo_config.versioning.parsed_minimum_version()?,
+                        initial_version: repo_config.versioning.parsed_initial_version()?,
+                        ..Default::default()
+                    },
+                ),
+            )
+            .await?;
 
             let base_version = current_version.unwrap_or(versioning::SemanticVersion {
                 major: 0,
@@ -573,8 +694,7 @@ where
             // Check whether a release PR is already open with a higher version.
             // The trailing * makes this a prefix match so all versioned release
             // branches are captured (e.g. "is:open head:release/v*").
-            let release_search_query =
-                format!("is:open head:{}/{}*", branch_prefix, version_prefix);
+            let release_search_query = format!("is:open head:{release_branch_prefix}*");
             let queued_release_version: Option<versioning::SemanticVersion> = scoped_github
                 .search_pull_requests(owner, repo, &release_search_query)
                 .await
@@ -587,10 +707,12 @@ where
                 })
                 .iter()
                 .filter_map(|pr| {
-                    release_automator::extract_version_from_branch(
+                    branch_template::version_from_release_pr(
+                        branch_template,
+                        &branch_vars,
                         &pr.head.ref_name,
-                        &branch_prefix,
-                        &version_prefix,
+                        &pr.title,
+                        pr.body.as_deref().unwrap_or_default(),
                     )
                     .ok()
                 })
@@ -668,6 +790,7 @@ where
 
         match source.next_event().await {
             Ok(Some(event)) => {
+                let started = std::time::Instant::now();
                 let span = tracing::info_span!(
                     "process_event",
                     event_id = %event.event_id,
@@ -675,7 +798,21 @@ where
                     event_type = %event.event_type,
                 );
 
+                let allowed = handler.is_repository_allowed(&event.repository);
+
                 let dispatch_result: CoreResult<()> = async {
+                    if !allowed {
+                        tracing::info!(
+                            event_id = %event.event_id,
+                            repository = %format!(
+                                "{}/{}",
+                                event.repository.owner, event.repository.name
+                            ),
+                            "Repository is not on the allow-list; ignoring event"
+                        );
+                        return Ok(());
+                    }
+
                     match &event.event_type {
                         EventType::PullRequestMerged => {
                             tracing::info!(
@@ -714,6 +851,13 @@ where
                             );
                             handler.handle_pull_request_activity(&event).await
                         }
+                        EventType::PullRequestReactivated => {
+                            tracing::debug!(
+                                event_id = %event.event_id,
+                                "Pull request reactivated — refreshing release PR and status"
+                            );
+                            handler.handle_pull_request_reactivated(&event).await
+                        }
                         EventType::Unknown(raw) => {
                             tracing::debug!(
                                 event_id = %event.event_id,
@@ -724,9 +868,37 @@ where
                         }
                     }
                 }
-                .instrument(span)
+                .instrument(span.clone())
                 .await;
 
+                // Nothing can be done for a repository the App is not
+                // installed on; drop the event instead of rejecting it.
+                let not_installed =
+                    matches!(dispatch_result, Err(CoreError::AppNotInstalled { .. }));
+                let dispatch_result = match dispatch_result {
+                    Err(e @ CoreError::AppNotInstalled { .. }) => {
+                        span.in_scope(|| tracing::info!(error = %e, "Ignoring event"));
+                        Ok(())
+                    }
+                    other => other,
+                };
+
+                let outcome = match (&dispatch_result, &event.event_type) {
+                    (Err(_), _) => "error",
+                    (Ok(()), _) if !allowed || not_installed => "ignored",
+                    (Ok(()), EventType::Unknown(_)) => "ignored",
+                    (Ok(()), _) => "processed",
+                };
+                let duration = started.elapsed();
+                telemetry::record_event(&event.event_type.to_string(), outcome, duration);
+                span.in_scope(|| {
+                    tracing::info!(
+                        outcome,
+                        duration_ms = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX),
+                        "Event processing finished"
+                    );
+                });
+
                 match dispatch_result {
                     Ok(()) => {
                         if let Err(e) = source.acknowledge(&event.event_id).await {
@@ -742,6 +914,8 @@ where
                         tracing::warn!(
                             error = %e,
                             event_id = %event.event_id,
+                            stage = e.stage().map(|stage| stage.to_string()),
+                            failure = e.stage_summary(),
                             permanent,
                             "Event processing failed; rejecting"
                         );
@@ -807,10 +981,72 @@ impl ReleaseRegent {
 struct MergeCalcResult {
     calc_result: traits::version_calculator::VersionCalculationResult,
     changelog: String,
+    package_changelogs: Vec<release_orchestrator::PackageChangelog>,
     current_version: Option<versioning::SemanticVersion>,
     repo_config: config::ReleaseRegentConfig,
 }
 
+/// Values for the release branch template of `owner/repo` when the release PR
+/// merges into `target_branch`.
+fn release_branch_vars<'a>(
+    repo_config: &'a config::ReleaseRegentConfig,
+    owner: &'a str,
+    repo: &'a str,
+    target_branch: &'a str,
+) -> branch_template::BranchNameVars<'a> {
+    branch_template::BranchNameVars {
+        owner,
+        repo,
+        target_branch,
+        version_prefix: &repo_config.core.version_prefix,
+    }
+}
+
+/// Release orchestrator settings from the release PR options of `repo_config`.
+fn release_orchestrator_config(
+    repo_config: &config::ReleaseRegentConfig,
+) -> release_orchestrator::OrchestratorConfig {
+    release_orchestrator::OrchestratorConfig {
+        branch_prefix: release_orchestrator::OrchestratorConfig::DEFAULT_BRANCH_PREFIX.to_string(),
+        version_prefix: repo_config.core.version_prefix.clone(),
+        title_template: repo_config.release_pr.title_template.clone(),
+        changelog_header: release_orchestrator::extract_changelog_header(
+            &repo_config.release_pr.body_template,
+        ),
+        body_template: repo_config.release_pr.body_template.clone(),
+        manifest_files: repo_config.release_pr.manifest_files.clone(),
+        auto_detect_manifests: repo_config.release_pr.auto_detect_manifests,
+        branch_template: repo_config.release_pr.branch_template.clone(),
+        labels: repo_config.release_pr.labels.clone(),
+        label_mode: repo_config.release_pr.label_mode,
+        changelog_date_format: repo_config.changelog.date_format.clone(),
+    }
+}
+
+/// Commit status description announcing `version_tag` and why it was chosen,
+/// e.g. `Next release: v1.3.0 (minor: feat in 1a2b3c4)`.
+///
+/// Truncated to the length GitHub accepts for a status description.
+fn version_status_description(
+    version_tag: &str,
+    calc_result: &traits::version_calculator::VersionCalculationResult,
+) -> String {
+    let reason = calc_result.bump_reason.as_ref().map_or_else(
+        || format!("{} bump", calc_result.version_bump.name()),
+        ToString::to_string,
+    );
+    let description = format!("Next release: {version_tag} ({reason})");
+    if description.chars().count() <= release_orchestrator::MAX_STATUS_DESCRIPTION_LEN {
+        return description;
+    }
+    let mut truncated: String = description
+        .chars()
+        .take(release_orchestrator::MAX_STATUS_DESCRIPTION_LEN - 1)
+        .collect();
+    truncated.push('…');
+    truncated
+}
+
 /// Release Regent processor with dependency injection
 ///
 /// This is the main business logic processor that uses dependency injection
@@ -843,6 +1079,10 @@ where
     github_operations: G,
     configuration_provider: C,
     version_calculator: V,
+    allowed_repos: RepositoryAllowList,
+    always_look_up_default_branch: bool,
+    changelog_renderer: Box<dyn ChangelogRenderer>,
+    dry_run_log: Option<dry_run::DryRunLog>,
 }
 
 impl<G, C, V> ReleaseRegentProcessor<G, C, V>
@@ -874,7 +1114,100 @@ where
             github_operations,
             configuration_provider,
             version_calculator,
+            allowed_repos: RepositoryAllowList::allow_all(),
+            always_look_up_default_branch: false,
+            changelog_renderer: Box::new(changelog::DefaultChangelogRenderer),
+            dry_run_log: None,
+        }
+    }
+
+    /// Restrict processing to repositories on `allowed_repos`.
+    ///
+    /// By default every repository is processed. Events for other
+    /// repositories are acknowledged by the event loop without any GitHub
+    /// operations and reported with the `ignored` outcome.
+    #[must_use]
+    pub fn with_allowed_repos(mut self, allowed_repos: RepositoryAllowList) -> Self {
+        self.allowed_repos = allowed_repos;
+        self
+    }
+
+    /// Get the repository allow-list
+    pub fn allowed_repos(&self) -> &RepositoryAllowList {
+        &self.allowed_repos
+    }
+
+    /// Always read the default branch from the GitHub API instead of trusting
+    /// the event.
+    ///
+    /// By default the `repository.default_branch` of the event is used, and
+    /// the API is only consulted when the event does not carry one. Enable
+    /// this when events may be stale, e.g. when they are replayed from a
+    /// queue after the default branch was renamed.
+    #[must_use]
+    pub fn with_default_branch_lookup(mut self, always: bool) -> Self {
+        self.always_look_up_default_branch = always;
+        self
+    }
+
+    /// Render changelogs with `renderer` instead of the built-in
+    /// [`changelog::DefaultChangelogRenderer`].
+    #[must_use]
+    pub fn with_changelog_renderer(mut self, renderer: Box<dyn ChangelogRenderer>) -> Self {
+        self.changelog_renderer = renderer;
+        self
+    }
+
+    /// Record every calculated version and changelog in `log`.
+    ///
+    /// Pass the log of the [`dry_run::DryRunGitHubOperations`] the processor
+    /// was built with, so that [`dry_run::DryRunLog::report`] can describe the
+    /// planned release as well as the intercepted mutations.
+    #[must_use]
+    pub fn with_dry_run_log(mut self, log: dry_run::DryRunLog) -> Self {
+        self.dry_run_log = Some(log);
+        self
+    }
+
+    /// Return `event` with an authoritative `repository.default_branch`.
+    ///
+    /// When the event has no default branch, or
+    /// [`with_default_branch_lookup`](Self::with_default_branch_lookup) is
+    /// enabled, the branch is read from the GitHub API. The returned event is
+    /// used for the rest of the processing, so the lookup happens at most once
+    /// per event.
+    async fn with_resolved_default_branch<'a>(
+        &self,
+        event: &'a traits::event_source::ProcessingEvent,
+    ) -> CoreResult<std::borrow::Cow<'a, traits::event_source::ProcessingEvent>> {
+        use std::borrow::Cow;
+
+        let from_event = event.repository.default_branch.trim();
+        if !from_event.is_empty() && !self.always_look_up_default_branch {
+            return Ok(Cow::Borrowed(event));
+        }
+
+        let owner = &event.repository.owner;
+        let repo = &event.repository.name;
+        let repository = self
+            .github_operations
+            .scoped_to(self.resolve_installation_id(owner, repo).await?)
+            .get_repository_info(owner, repo)
+            .await
+            .map_err(|e| e.in_stage(ProcessingStage::RepositoryLookup, owner, repo))?;
+
+        if repository.default_branch == from_event {
+            return Ok(Cow::Borrowed(event));
         }
+        tracing::info!(
+            event_id = %event.event_id,
+            event_default_branch = %from_event,
+            default_branch = %repository.default_branch,
+            "Using default branch from the GitHub API"
+        );
+        let mut resolved = event.clone();
+        resolved.repository.default_branch = repository.default_branch;
+        Ok(Cow::Owned(resolved))
     }
 
     /// Get a reference to the GitHub operations
@@ -900,6 +1233,7 @@ where
         self.github_operations
             .get_installation_id_for_repo(owner, repo)
             .await
+            .map_err(|e| e.in_stage(ProcessingStage::InstallationLookup, owner, repo))
     }
 
     /// Handle a merged pull request event by orchestrating the creation or
@@ -908,8 +1242,13 @@ where
     /// This is the main entry point for processing `EventType::PullRequestMerged`
     /// events. It performs the following steps in order:
     ///
-    /// 1. Extract `base_branch` and `merge_commit_sha` from the event payload.
-    /// 2. Load the merged repository configuration.
+    /// 1. Extract `base_branch` and `merge_commit_sha` from the event payload,
+    ///    falling back to the repository's default branch, which is read from
+    ///    the GitHub API when the event lacks it (see
+    ///    [`with_default_branch_lookup`](Self::with_default_branch_lookup)).
+    /// 2. Load the merged repository configuration.  When
+    ///    `releases.target_branch` is set, a merge into any other branch is
+    ///    skipped, and the target branch must exist.
     /// 3. Resolve the current release version from Git tags.
     /// 4. Calculate the next semantic version from commit history.
     /// 5. Format a changelog body from the commit analysis.
@@ -929,10 +1268,21 @@ where
     ///   and `head.sha`.
     /// - [`CoreError::GitHub`] / [`CoreError::Network`] — a GitHub API call failed.
     /// - [`CoreError::Versioning`] — version calculation failed.
-    /// - [`CoreError::Config`] — configuration loading failed.
+    /// - [`CoreError::Config`] — configuration loading failed, or the configured
+    ///   `releases.target_branch` does not exist.
     pub async fn handle_merged_pull_request(
         &self,
         event: &traits::event_source::ProcessingEvent,
+    ) -> CoreResult<release_orchestrator::OrchestratorResult> {
+        let event = self.with_resolved_default_branch(event).await?;
+        self.orchestrate_merged_pull_request(&event).await
+    }
+
+    /// [`handle_merged_pull_request`](Self::handle_merged_pull_request) for an
+    /// event whose default branch has already been resolved.
+    async fn orchestrate_merged_pull_request(
+        &self,
+        event: &traits::event_source::ProcessingEvent,
     ) -> CoreResult<release_orchestrator::OrchestratorResult> {
         let owner = &event.repository.owner;
         let repo = &event.repository.name;
@@ -949,6 +1299,11 @@ where
             .unwrap_or(&event.repository.default_branch)
             .to_string();
 
+        let installation_id = self.resolve_installation_id(owner, repo).await?;
+        let repo_config = self
+            .load_repo_config(owner, repo, installation_id, &base_branch)
+            .await?;
+
         // Check the merged PR's head branch early to avoid running the expensive
         // calculate_version_for_merge (tag fetching + version calculation +
         // changelog generation) when this is a release PR merge.  We need only
@@ -961,41 +1316,24 @@ where
             .and_then(|v| v.as_str())
             .unwrap_or_default()
             .to_string();
-        let release_branch_prefix = format!(
-            "{}/",
-            release_orchestrator::OrchestratorConfig::DEFAULT_BRANCH_PREFIX
-        );
-        if merged_pr_head_ref.starts_with(&release_branch_prefix) {
-            use traits::configuration_provider::LoadOptions;
-            let installation_id = self.resolve_installation_id(owner, repo).await?;
-            let repo_config = self
-                .configuration_provider
-                .get_merged_config(
+        let is_release_branch = branch_template::is_release_branch(
+            repo_config.release_pr.branch_template(),
+            &release_branch_vars(&repo_config, owner, repo, &base_branch),
+            &merged_pr_head_ref,
+        )
+        .map_err(|e| e.in_stage(ProcessingStage::ConfigLoad, owner, repo))?;
+        if is_release_branch {
+            return self
+                .process_release_pr_merged(
                     owner,
                     repo,
-                    LoadOptions {
-                        installation_id: Some(installation_id),
-                        default_branch: Some(base_branch.clone()),
-                        ..Default::default()
-                    },
+                    installation_id,
+                    correlation_id,
+                    &repo_config,
+                    event,
                 )
-                .await?;
-            if release_automator::is_release_pr_branch(
-                &merged_pr_head_ref,
-                release_orchestrator::OrchestratorConfig::DEFAULT_BRANCH_PREFIX,
-                &repo_config.core.version_prefix,
-            ) {
-                return self
-                    .process_release_pr_merged(
-                        owner,
-                        repo,
-                        installation_id,
-                        correlation_id,
-                        &repo_config,
-                        event,
-                    )
-                    .await;
-            }
+                .await
+                .map_err(|e| e.in_stage(ProcessingStage::ReleaseCreation, owner, repo));
         }
 
         // Feature PR path: the merge commit SHA is required as the branch
@@ -1022,43 +1360,99 @@ where
             })?
             .to_string();
 
-        let installation_id = self.resolve_installation_id(owner, repo).await?;
+        // A configured release target replaces the merged PR's base branch:
+        // merges into any other branch do not take part in the release.
+        let base_branch = match repo_config.releases.target_branch.clone() {
+            Some(target_branch) if target_branch != base_branch => {
+                tracing::info!(
+                    base_branch = %base_branch,
+                    target_branch = %target_branch,
+                    "Pull request was not merged into the release target branch; skipping"
+                );
+                return Ok(release_orchestrator::OrchestratorResult::NotReleaseTarget {
+                    base_branch,
+                    target_branch,
+                });
+            }
+            Some(target_branch) => {
+                self.ensure_branch_exists(owner, repo, installation_id, &target_branch)
+                    .await
+                    .map_err(|e| e.in_stage(ProcessingStage::ConfigLoad, owner, repo))?;
+                target_branch
+            }
+            None => base_branch,
+        };
 
         let MergeCalcResult {
             calc_result,
             changelog,
+            package_changelogs,
             current_version,
             repo_config,
         } = self
-            .calculate_version_for_merge(owner, repo, &base_sha, &base_branch, installation_id)
-            .await?;
+            .calculate_version_for_merge(
+                owner,
+                repo,
+                &base_sha,
+                &base_branch,
+                installation_id,
+                repo_config,
+            )
+            .await
+            .map_err(|e| e.in_stage(ProcessingStage::VersionCalculation, owner, repo))?;
 
         // Build orchestrator config honouring the repository PR title template.
-        let orch_config = release_orchestrator::OrchestratorConfig {
-            branch_prefix: release_orchestrator::OrchestratorConfig::DEFAULT_BRANCH_PREFIX
-                .to_string(),
-            version_prefix: repo_config.core.version_prefix.clone(),
-            title_template: repo_config.release_pr.title_template.clone(),
-            changelog_header: release_orchestrator::extract_changelog_header(
-                &repo_config.release_pr.body_template,
-            ),
-            body_template: repo_config.release_pr.body_template.clone(),
-            manifest_files: repo_config.release_pr.manifest_files.clone(),
-            auto_detect_manifests: repo_config.release_pr.auto_detect_manifests,
-        };
+        let orch_config = release_orchestrator_config(&repo_config);
 
         // Resolve the merged PR number (needed to read override labels on the
         // feature-PR path).
-        let merged_pr_number: u64 = event
+        let merged_pr_number = event
             .payload
             .get("pull_request")
             .and_then(|pr| pr.get("number"))
             .and_then(serde_json::Value::as_u64)
-            .unwrap_or(0);
+            .filter(|number| *number > 0);
+        if merged_pr_number.is_none() {
+            tracing::warn!(
+                correlation_id = %correlation_id,
+                "Merged PR payload is missing pull_request.number; \
+                 bump-override floor will not be applied for this release"
+            );
+        }
+
+        // With versioning.use_pr_title the merged PR's title counts as one more
+        // conventional commit, so `feat!: ...` forces a major bump even when
+        // the individual commits are only fixes.
+        let title_bump = if repo_config.versioning.use_pr_title {
+            event
+                .payload
+                .get("pull_request")
+                .and_then(|pr| pr.get("title"))
+                .and_then(|v| v.as_str())
+                .and_then(|title| {
+                    versioning::pr_title_bump(title, &repo_config.versioning.breaking_scopes)
+                })
+        } else {
+            None
+        };
+        // With versioning.release_on_no_bump a merge without version-bumping
+        // commits still releases the next patch version instead of being
+        // skipped.
+        let min_bump = title_bump.or_else(|| {
+            (repo_config.versioning.release_on_no_bump
+                && calc_result.version_bump == traits::version_calculator::VersionBump::None)
+                .then_some(versioning::BumpKind::Patch)
+        });
 
+        let package_tags = monorepo::package_release_tags(
+            repo_config.versioning.strategy.packages(),
+            &calc_result.packages,
+        );
         let scoped_github = self.github_operations.scoped_to(installation_id);
         let orchestrator =
-            release_orchestrator::ReleaseOrchestrator::new(orch_config, &scoped_github);
+            release_orchestrator::ReleaseOrchestrator::new(orch_config, &scoped_github)
+                .with_package_changelogs(package_changelogs)
+                .with_package_tags(package_tags);
 
         self.process_feature_pr_merged(
             owner,
@@ -1069,52 +1463,209 @@ where
             &orchestrator,
             current_version.as_ref(),
             &calc_result,
+            min_bump,
             &changelog,
             &base_branch,
             &base_sha,
         )
         .await
+        .map_err(|e| e.in_stage(ProcessingStage::ReleasePullRequest, owner, repo))
     }
 
-    /// Load configuration and calculate the next version for a merge event.
-    async fn calculate_version_for_merge(
+    /// Recalculate and update a release PR that was reopened or marked ready
+    /// for review.
+    ///
+    /// The version and changelog are calculated from the head of the release
+    /// PR's target branch, as after a merge into it, and the release PR is
+    /// updated through the [`release_orchestrator::ReleaseOrchestrator`].
+    /// Returns `None` without changes when the pull request is not a release
+    /// PR.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the configuration cannot be loaded, the target
+    /// branch cannot be read, or the version calculation or the release PR
+    /// update fails.
+    pub async fn refresh_release_pr(
+        &self,
+        event: &traits::event_source::ProcessingEvent,
+    ) -> CoreResult<Option<release_orchestrator::OrchestratorResult>> {
+        let event = self.with_resolved_default_branch(event).await?;
+        let event = &*event;
+        let owner = &event.repository.owner;
+        let repo = &event.repository.name;
+        let correlation_id = &event.correlation_id;
+
+        let base_branch = event
+            .payload
+            .pointer("/pull_request/base/ref")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or(&event.repository.default_branch)
+            .to_string();
+        let head_branch = event
+            .payload
+            .pointer("/pull_request/head/ref")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default();
+
+        let installation_id = self.resolve_installation_id(owner, repo).await?;
+        let repo_config = self
+            .load_repo_config(owner, repo, installation_id, &base_branch)
+            .await?;
+        let is_release_branch = branch_template::is_release_branch(
+            repo_config.release_pr.branch_template(),
+            &release_branch_vars(&repo_config, owner, repo, &base_branch),
+            head_branch,
+        )
+        .map_err(|e| e.in_stage(ProcessingStage::ConfigLoad, owner, repo))?;
+        if !is_release_branch {
+            return Ok(None);
+        }
+
+        let base_sha = self
+            .github_operations
+            .scoped_to(installation_id)
+            .get_head_commit(owner, repo, Some(&base_branch))
+            .await
+            .map_err(|e| e.in_stage(ProcessingStage::CommitComparison, owner, repo))?
+            .sha;
+
+        let MergeCalcResult {
+            calc_result,
+            changelog,
+            package_changelogs,
+            current_version,
+            repo_config,
+        } = self
+            .calculate_version_for_merge(
+                owner,
+                repo,
+                &base_sha,
+                &base_branch,
+                installation_id,
+                repo_config,
+            )
+            .await
+            .map_err(|e| e.in_stage(ProcessingStage::VersionCalculation, owner, repo))?;
+
+        let min_bump = (repo_config.versioning.release_on_no_bump
+            && calc_result.version_bump == traits::version_calculator::VersionBump::None)
+            .then_some(versioning::BumpKind::Patch);
+        let package_tags = monorepo::package_release_tags(
+            repo_config.versioning.strategy.packages(),
+            &calc_result.packages,
+        );
+        let scoped_github = self.github_operations.scoped_to(installation_id);
+        let orchestrator = release_orchestrator::ReleaseOrchestrator::new(
+            release_orchestrator_config(&repo_config),
+            &scoped_github,
+        )
+        .with_package_changelogs(package_changelogs)
+        .with_package_tags(package_tags);
+
+        self.process_feature_pr_merged(
+            owner,
+            repo,
+            installation_id,
+            None,
+            correlation_id,
+            &orchestrator,
+            current_version.as_ref(),
+            &calc_result,
+            min_bump,
+            &changelog,
+            &base_branch,
+            &base_sha,
+        )
+        .await
+        .map(Some)
+        .map_err(|e| e.in_stage(ProcessingStage::ReleasePullRequest, owner, repo))
+    }
+
+    /// Load the merged repository configuration, reading repository files
+    /// from `branch`.
+    async fn load_repo_config(
         &self,
         owner: &str,
         repo: &str,
-        base_sha: &str,
-        base_branch: &str,
         installation_id: u64,
-    ) -> CoreResult<MergeCalcResult> {
+        branch: &str,
+    ) -> CoreResult<config::ReleaseRegentConfig> {
         use traits::configuration_provider::LoadOptions;
-        use traits::version_calculator::{CalculationOptions, VersionContext, VersioningStrategy};
 
-        let repo_config = self
-            .configuration_provider
-            .get_merged_config(
+        telemetry::time_stage(
+            telemetry::STAGE_CONFIG_LOAD,
+            self.configuration_provider.get_merged_config(
                 owner,
                 repo,
                 LoadOptions {
                     installation_id: Some(installation_id),
-                    default_branch: Some(base_branch.to_string()),
+                    default_branch: Some(branch.to_string()),
                     ..Default::default()
                 },
-            )
-            .await?;
+            ),
+        )
+        .await
+        .map_err(|e| e.in_stage(ProcessingStage::ConfigLoad, owner, repo))
+    }
+
+    /// Verify that the configured `releases.target_branch` exists.
+    ///
+    /// # Errors
+    /// - [`CoreError::Config`] — the branch does not exist in the repository.
+    /// - [`CoreError::GitHub`] / [`CoreError::Network`] — the lookup failed.
+    async fn ensure_branch_exists(
+        &self,
+        owner: &str,
+        repo: &str,
+        installation_id: u64,
+        branch: &str,
+    ) -> CoreResult<()> {
+        let scoped_github = self.github_operations.scoped_to(installation_id);
+        match scoped_github
+            .get_head_commit(owner, repo, Some(branch))
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(CoreError::NotFound { .. }) => Err(CoreError::config(format!(
+                "releases.target_branch '{branch}' does not exist in {owner}/{repo}"
+            ))),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Calculate the next version for a merge event.
+    async fn calculate_version_for_merge(
+        &self,
+        owner: &str,
+        repo: &str,
+        base_sha: &str,
+        base_branch: &str,
+        installation_id: u64,
+        repo_config: config::ReleaseRegentConfig,
+    ) -> CoreResult<MergeCalcResult> {
+        use traits::version_calculator::{CalculationOptions, VersionContext, VersioningStrategy};
 
         let scoped_github = self.github_operations.scoped_to(installation_id);
         let current_version =
             versioning::resolve_current_version(&scoped_github, owner, repo, false).await?;
-
-        let ctx = VersionContext {
-            base_ref: current_version
-                .as_ref()
-                .map(|v| format!("{}{v}", repo_config.core.version_prefix)),
-            current_version: current_version.clone(),
-            head_ref: base_sha.to_string(),
-            owner: owner.to_string(),
-            repo: repo.to_string(),
-            target_branch: base_branch.to_string(),
-        };
+        let package_versions = monorepo::resolve_package_versions(
+            &scoped_github,
+            owner,
+            repo,
+            repo_config.versioning.strategy.packages(),
+        )
+        .await?;
+
+        let ctx = VersionContext::builder()
+            .with_owner(owner)
+            .with_repo(repo)
+            .with_current_version(current_version.clone())
+            .with_package_versions(package_versions)
+            .with_version_prefix(&repo_config.core.version_prefix)
+            .with_head_ref(base_sha.to_string())
+            .with_target_branch(base_branch.to_string())
+            .build()?;
 
         let strategy = match repo_config.versioning.strategy {
             config::VersioningStrategy::Conventional
@@ -1124,19 +1675,28 @@ where
                     include_prerelease: false,
                 }
             }
+            ref monorepo @ config::VersioningStrategy::Monorepo { .. } => monorepo.clone().into(),
         };
 
         let options = CalculationOptions {
             generate_changelog: true,
+            bump_scopes: repo_config.versioning.scopes.clone(),
+            breaking_scopes: repo_config.versioning.breaking_scopes.clone(),
+            no_conventional_commits: repo_config.versioning.no_conventional_commits,
+            exclusions: repo_config.versioning.exclusions.clone(),
+            minimum_version: repo_config.versioning.parsed_minimum_version()?,
+            initial_version: repo_config.versioning.parsed_initial_version()?,
             ..Default::default()
         };
 
         // Scope the calculator to the resolved installation before calling it,
         // keeping authentication concerns out of VersionContext.
         let scoped_calc = self.version_calculator.scoped_to(installation_id);
-        let calc_result = scoped_calc
-            .calculate_version(ctx, strategy, options)
-            .await?;
+        let calc_result = telemetry::time_stage(
+            telemetry::STAGE_VERSION_CALCULATION,
+            scoped_calc.calculate_version(ctx, strategy, options),
+        )
+        .await?;
 
         // Build ConventionalCommit items from the raw analyzed_commits so that
         // all ChangelogGenerator strategies receive the correct vocabulary:
@@ -1150,41 +1710,134 @@ where
         // by filter_unconventional=true; they are not omitted here.
         // The filter_map's `?` guards only against custom VersionCalculator
         // implementations that might return None for commit_type.
-        let commits: Vec<versioning::ConventionalCommit> = calc_result
+        let mut commits: Vec<versioning::ConventionalCommit> = calc_result
             .analyzed_commits
             .iter()
             .filter_map(|a| {
                 let commit_type = a.commit_type.clone()?;
                 // Extract the description by dropping the "type(scope): " prefix from
-                // the full commit message.  For non-conventional messages (e.g. merge
-                // commits) where no ": " separator exists, fall back to the full message.
-                let description = a
-                    .message
-                    .find(": ")
-                    .map(|i| a.message[i + 2..].to_string())
-                    .unwrap_or_else(|| a.message.clone());
+                // the subject line; the rest of the message is carried as the body.
+                // For non-conventional messages (e.g. merge commits) where no ": "
+                // separator exists, fall back to the whole subject line. A
+                // `Revert "<subject>"` commit is described by the reverted subject.
+                let subject = a.message.lines().next().unwrap_or_default();
+                let description = match versioning::reverted_subject_of_revert(subject) {
+                    Some(reverted) => reverted.to_string(),
+                    None => subject
+                        .find(": ")
+                        .map_or_else(|| subject.to_string(), |i| subject[i + 2..].to_string()),
+                };
                 Some(versioning::ConventionalCommit {
                     commit_type,
                     scope: a.scope.clone(),
                     description,
                     breaking_change: a.is_breaking,
                     message: a.message.clone(),
+                    body: versioning::commit_body(&a.message),
                     sha: a.sha.clone(),
+                    author: a.author_user(),
                 })
             })
             .collect();
 
-        let changelog = changelog::ChangelogGenerator::with_config(repo_config.changelog.clone())
-            .generate_changelog(&commits)?;
+        let generator = changelog::ChangelogGenerator::with_config(repo_config.changelog.clone());
+        generator
+            .resolve_author_logins(&scoped_github, owner, repo, &mut commits)
+            .await;
+        let in_changelog_stage =
+            |e: CoreError| e.in_stage(ProcessingStage::ChangelogGeneration, owner, repo);
+        let changelog = self
+            .changelog_renderer
+            .generate(
+                &commits,
+                &ChangelogContext {
+                    owner,
+                    repo,
+                    config: &repo_config.changelog,
+                    initial_release: calc_result.is_first_release(),
+                },
+            )
+            .map_err(in_changelog_stage)?;
+        let package_changelogs = self
+            .render_package_changelogs(owner, repo, &repo_config, &calc_result, &commits)
+            .map_err(in_changelog_stage)?;
+        if let Some(log) = &self.dry_run_log {
+            log.record_calculation(dry_run::DryRunCalculation::new(&calc_result, &changelog));
+        }
 
         Ok(MergeCalcResult {
             calc_result,
             changelog,
+            package_changelogs,
             current_version,
             repo_config,
         })
     }
 
+    /// Render the changelog of every monorepo package that has a
+    /// `changelog_path`.
+    ///
+    /// Each package takes the commits of its partition from `commits`, which
+    /// already carry resolved authors, so a commit that touches several
+    /// packages is in each of their changelogs. Packages without entries are
+    /// skipped.
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large
+    fn render_package_changelogs(
+        &self,
+        owner: &str,
+        repo: &str,
+        repo_config: &config::ReleaseRegentConfig,
+        calc_result: &traits::version_calculator::VersionCalculationResult,
+        commits: &[versioning::ConventionalCommit],
+    ) -> CoreResult<Vec<release_orchestrator::PackageChangelog>> {
+        let config::VersioningStrategy::Monorepo { packages } = &repo_config.versioning.strategy
+        else {
+            return Ok(Vec::new());
+        };
+
+        let mut rendered = Vec::new();
+        for spec in packages {
+            let (Some(path), Some(result)) =
+                (&spec.changelog_path, calc_result.packages.get(&spec.name))
+            else {
+                continue;
+            };
+            let shas: std::collections::HashSet<&str> = result
+                .analyzed_commits
+                .iter()
+                .map(|c| c.sha.as_str())
+                .collect();
+            let package_commits: Vec<versioning::ConventionalCommit> = commits
+                .iter()
+                .filter(|c| shas.contains(c.sha.as_str()))
+                .cloned()
+                .collect();
+            if package_commits.is_empty() {
+                continue;
+            }
+
+            let changelog = self.changelog_renderer.generate(
+                &package_commits,
+                &ChangelogContext {
+                    owner,
+                    repo,
+                    config: &repo_config.changelog,
+                    initial_release: result.current_version.is_none(),
+                },
+            )?;
+            if changelog.trim().is_empty() {
+                continue;
+            }
+            rendered.push(release_orchestrator::PackageChangelog {
+                package: spec.name.clone(),
+                path: path.clone(),
+                version: result.next_version.clone(),
+                changelog,
+            });
+        }
+        Ok(rendered)
+    }
+
     /// Handle the release-PR path when a merged pull request is identified as a
     /// release PR.
     ///
@@ -1225,11 +1878,34 @@ where
             ),
             version_prefix: repo_config.core.version_prefix.clone(),
             generate_release_notes: repo_config.releases.generate_notes,
+            create_as_draft: repo_config.releases.draft,
+            prerelease: repo_config.releases.prerelease,
+            tag_signing: repo_config
+                .releases
+                .enabled_tag_signing()
+                .map(tag_signing::TagSigning::from_config)
+                .transpose()?,
+            branch_template: repo_config.release_pr.branch_template.clone(),
         };
 
-        ReleaseAutomator::new(config, &self.github_operations.scoped_to(installation_id))
-            .automate(owner, repo, event, correlation_id)
-            .await?;
+        let result = telemetry::time_stage(
+            telemetry::STAGE_RELEASE_CREATION,
+            ReleaseAutomator::new(config, &self.github_operations.scoped_to(installation_id))
+                .automate(owner, repo, event, correlation_id),
+        )
+        .await?;
+        // A release that already existed still goes through the post-publish
+        // steps: they are idempotent, and the attempt that created the release
+        // may have failed before reaching them.
+        let release = result.release();
+
+        if release.draft {
+            tracing::info!(
+                release_id = release.id,
+                "Release created as draft; skipping post-publish steps"
+            );
+            return Ok(release_orchestrator::OrchestratorResult::TaggedRelease);
+        }
 
         // After the release is published, clear stale rr:override-* labels from
         // any open feature PRs.  These overrides were scoped to this release cycle.
@@ -1383,20 +2059,26 @@ where
 
     /// Handle the feature-PR path after a merged pull request.
     ///
-    /// Applies any bump-floor override from the merged PR's labels, orchestrates
-    /// the release PR, posts an audit comment if the floor was applied, and
-    /// removes the consumed override labels.
-    #[allow(clippy::too_many_arguments)] // owner/repo/installation_id/pr_num/correlation/orchestrator/current/calc/changelog/branch/sha is minimal
+    /// Raises the calculated version to `min_bump`, the bump called for by
+    /// the merged PR's title or by `versioning.release_on_no_bump`, then
+    /// applies any bump-floor override from the merged PR's labels,
+    /// orchestrates the release PR, posts an audit comment if the label floor
+    /// was applied, and removes the consumed override labels.
+    ///
+    /// Returns [`OrchestratorResult::NoBumpNeeded`](release_orchestrator::OrchestratorResult::NoBumpNeeded)
+    /// without touching the release PR when nothing calls for a new version.
+    #[allow(clippy::too_many_arguments)] // owner/repo/installation_id/pr_num/correlation/orchestrator/current/calc/title/changelog/branch/sha is minimal
     async fn process_feature_pr_merged(
         &self,
         owner: &str,
         repo: &str,
         installation_id: u64,
-        merged_pr_number: u64,
+        merged_pr_number: Option<u64>,
         correlation_id: &str,
         orchestrator: &release_orchestrator::ReleaseOrchestrator<'_, G>,
         current_version: Option<&versioning::SemanticVersion>,
         calc_result: &traits::version_calculator::VersionCalculationResult,
+        min_bump: Option<versioning::BumpKind>,
         changelog: &str,
         base_branch: &str,
         base_sha: &str,
@@ -1410,17 +2092,9 @@ where
 
         // Read any rr:override-* label from the merged PR and apply it as a
         // minimum-bump floor before calling the orchestrator.
-        let labels = if merged_pr_number > 0 {
-            scoped_github
-                .list_pr_labels(owner, repo, merged_pr_number)
-                .await?
-        } else {
-            tracing::warn!(
-                correlation_id = %correlation_id,
-                "Merged PR payload is missing pull_request.number; \
-                 bump-override floor will not be applied for this release"
-            );
-            vec![]
+        let labels = match merged_pr_number {
+            Some(number) => scoped_github.list_pr_labels(owner, repo, number).await?,
+            None => vec![],
         };
 
         let floor_kind: Option<BumpKind> = labels.iter().find_map(|l| match l.name.as_str() {
@@ -1430,11 +2104,38 @@ where
             _ => None,
         });
 
+        // Short-circuit: without version-bumping commits, a title or label
+        // bump, or a configured minimum version, there is nothing to release.
+        // The first release is never skipped.
+        if calc_result.version_bump == traits::version_calculator::VersionBump::None
+            && current_version.is_some()
+            && min_bump.is_none()
+            && floor_kind.is_none()
+            && !calc_result.metadata.contains_key(
+                traits::version_calculator::VersionCalculationResult::MINIMUM_VERSION_APPLIED,
+            )
+        {
+            let reason = calc_result.bump_reason.as_ref().map_or_else(
+                || traits::version_calculator::BumpReason::NO_BUMPING_COMMITS.to_string(),
+                |r| r.rule.clone(),
+            );
+            return Ok(self.no_release_needed(owner, repo, correlation_id, reason));
+        }
+
+        // The minimum bump comes first so that the audit comment below only
+        // reports what the override label changed.
+        let calculated_version =
+            if let (Some(ref min_floor), Some(current)) = (&min_bump, current_version) {
+                versioning::apply_bump_floor(current, &calc_result.next_version, min_floor)
+            } else {
+                calc_result.next_version.clone()
+            };
+
         let effective_version =
             if let (Some(ref floor), Some(current)) = (&floor_kind, current_version) {
-                versioning::apply_bump_floor(current, &calc_result.next_version, floor)
+                versioning::apply_bump_floor(current, &calculated_version, floor)
             } else {
-                calc_result.next_version.clone()
+                calculated_version.clone()
             };
 
         tracing::debug!(
@@ -1442,6 +2143,7 @@ where
             repo = %repo,
             calculated = %calc_result.next_version,
             effective = %effective_version,
+            min_bump = ?min_bump,
             floor = ?floor_kind,
             correlation_id = %correlation_id,
             "Resolved effective release version after bump-floor check"
@@ -1455,15 +2157,12 @@ where
         // after the 0.3.0 release branch is merged.
         if let Some(current) = current_version {
             if effective_version.compare_precedence(current) == std::cmp::Ordering::Equal {
-                tracing::info!(
-                    owner = %owner,
-                    repo = %repo,
-                    version = %effective_version,
-                    correlation_id = %correlation_id,
-                    "Effective version equals current released version; \
-                     no version-bumping commits — skipping release branch creation"
-                );
-                return Ok(release_orchestrator::OrchestratorResult::NoBumpNeeded);
+                return Ok(self.no_release_needed(
+                    owner,
+                    repo,
+                    correlation_id,
+                    format!("version {effective_version} is already released"),
+                ));
             }
         }
 
@@ -1488,8 +2187,24 @@ where
             )
             .await?;
 
+        self.post_version_status(
+            owner,
+            repo,
+            installation_id,
+            correlation_id,
+            &orch_result,
+            &format!("{}{effective_version}", orchestrator.version_prefix()),
+            calc_result,
+        )
+        .await;
+
+        // A floor comes from the labels of a merged PR, so its number is known.
+        let Some(merged_pr_number) = merged_pr_number.filter(|_| floor_kind.is_some()) else {
+            return Ok(orch_result);
+        };
+
         // Post an audit comment on the release PR when the floor was applied.
-        if effective_version != calc_result.next_version {
+        if effective_version != calculated_version {
             if let Some(ref floor) = floor_kind {
                 self.post_bump_floor_audit_comment(
                     owner,
@@ -1499,7 +2214,7 @@ where
                     correlation_id,
                     &orch_result,
                     floor,
-                    &calc_result.next_version,
+                    &calculated_version,
                     &effective_version,
                 )
                 .await;
@@ -1509,26 +2224,94 @@ where
         // Consume override label: remove from the now-merged feature PR.
         // This is idempotent (remove_label treats 404 as Ok) and runs
         // unconditionally to clean up any label applied by `!release` commands.
-        if floor_kind.is_some() {
-            for &label in ALL_OVERRIDE_LABELS {
-                if let Err(e) = scoped_github
-                    .remove_label(owner, repo, merged_pr_number, label)
-                    .await
-                {
-                    tracing::warn!(
-                        error = %e,
-                        merged_pr = merged_pr_number,
-                        label,
-                        correlation_id = %correlation_id,
-                        "Failed to remove consumed override label; continuing"
-                    );
-                }
+        for &label in ALL_OVERRIDE_LABELS {
+            if let Err(e) = scoped_github
+                .remove_label(owner, repo, merged_pr_number, label)
+                .await
+            {
+                tracing::warn!(
+                    error = %e,
+                    merged_pr = merged_pr_number,
+                    label,
+                    correlation_id = %correlation_id,
+                    "Failed to remove consumed override label; continuing"
+                );
             }
         }
 
         Ok(orch_result)
     }
 
+    /// Log that a merged pull request needs no release, record the reason in
+    /// the dry-run log, and build the matching result.
+    fn no_release_needed(
+        &self,
+        owner: &str,
+        repo: &str,
+        correlation_id: &str,
+        reason: String,
+    ) -> release_orchestrator::OrchestratorResult {
+        tracing::info!(
+            owner = %owner,
+            repo = %repo,
+            reason = %reason,
+            correlation_id = %correlation_id,
+            "No release needed; skipping release PR creation"
+        );
+        if let Some(log) = &self.dry_run_log {
+            log.record_no_release(&reason);
+        }
+        release_orchestrator::OrchestratorResult::NoBumpNeeded { reason }
+    }
+
+    /// Report the planned release version as a commit status on the head of
+    /// the release PR.
+    ///
+    /// Best-effort: a failure is logged and never fails the merge event.
+    #[allow(clippy::too_many_arguments)] // status context needs the repo, PR, version and reason
+    async fn post_version_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        installation_id: u64,
+        correlation_id: &str,
+        orch_result: &release_orchestrator::OrchestratorResult,
+        version_tag: &str,
+        calc_result: &traits::version_calculator::VersionCalculationResult,
+    ) {
+        let pr = match orch_result {
+            release_orchestrator::OrchestratorResult::Created { pr, .. }
+            | release_orchestrator::OrchestratorResult::Updated { pr }
+            | release_orchestrator::OrchestratorResult::Renamed { pr }
+            | release_orchestrator::OrchestratorResult::NoOp { pr } => pr,
+            release_orchestrator::OrchestratorResult::NoBumpNeeded { .. }
+            | release_orchestrator::OrchestratorResult::TaggedRelease
+            | release_orchestrator::OrchestratorResult::NotReleaseTarget { .. } => return,
+        };
+        if pr.head.sha.is_empty() {
+            return;
+        }
+
+        let params = traits::github_operations::CreateCommitStatusParams {
+            context: release_orchestrator::VERSION_STATUS_CONTEXT.to_string(),
+            description: version_status_description(version_tag, calc_result),
+            state: traits::github_operations::CommitStatusState::Success,
+            target_url: None,
+        };
+        let scoped_github = self.github_operations.scoped_to(installation_id);
+        if let Err(e) = scoped_github
+            .create_commit_status(owner, repo, &pr.head.sha, params)
+            .await
+        {
+            tracing::warn!(
+                error = %e,
+                release_pr = pr.number,
+                correlation_id = %correlation_id,
+                "Failed to post release version commit status; continuing"
+            );
+        }
+    }
+
     /// Post an audit comment on the release PR explaining a bump-floor override.
     #[allow(clippy::too_many_arguments)] // audit context requires all 9 data points; no good grouping
     async fn post_bump_floor_audit_comment(
@@ -1557,10 +2340,12 @@ where
             | release_orchestrator::OrchestratorResult::NoOp { pr } => Some(pr.number),
             // NoBumpNeeded is returned before the orchestrator is called, so
             // there is no release PR to post the audit comment on.
-            release_orchestrator::OrchestratorResult::NoBumpNeeded => None,
+            release_orchestrator::OrchestratorResult::NoBumpNeeded { .. } => None,
             // TaggedRelease is produced by the release-PR merge path, not by the
             // orchestrator, so there is no open release PR to comment on.
             release_orchestrator::OrchestratorResult::TaggedRelease => None,
+            // NotReleaseTarget is returned before the orchestrator is called.
+            release_orchestrator::OrchestratorResult::NotReleaseTarget { .. } => None,
         };
         let Some(release_pr) = release_pr_number else {
             return;
@@ -1613,9 +2398,9 @@ where
             }
         };
 
-        let repo_config = match self
-            .configuration_provider
-            .get_merged_config(
+        let repo_config = match telemetry::time_stage(
+            telemetry::STAGE_CONFIG_LOAD,
+            self.configuration_provider.get_merged_config(
                 owner,
                 repo,
                 LoadOptions {
@@ -1623,8 +2408,9 @@ where
                     default_branch: Some(default_branch.to_string()),
                     ..Default::default()
                 },
-            )
-            .await
+            ),
+        )
+        .await
         {
             Ok(c) => c,
             Err(e) => {
@@ -1657,12 +2443,15 @@ where
         installation_id: u64,
         repo_config: &config::ReleaseRegentConfig,
     ) {
-        use release_automator::is_release_pr_branch;
         use traits::version_calculator::{CalculationOptions, VersionContext, VersioningStrategy};
 
-        let branch_prefix =
-            release_orchestrator::OrchestratorConfig::DEFAULT_BRANCH_PREFIX.to_string();
-        let version_prefix = repo_config.core.version_prefix.clone();
+        let branch_template = repo_config.release_pr.branch_template();
+        // Release branches are named after the branch their PR merges into.
+        let is_release_pr = |pr: &traits::github_operations::PullRequest| {
+            let vars = release_branch_vars(repo_config, owner, repo, &pr.base.ref_name);
+            branch_template::is_release_branch(branch_template, &vars, &pr.head.ref_name)
+                .unwrap_or(false)
+        };
         let scoped_github = self.github_operations.scoped_to(installation_id);
 
         let open_prs = match scoped_github
@@ -1687,12 +2476,14 @@ where
         // so we can annotate feature PR comments when a release is already queued.
         let queued_release_version: Option<versioning::SemanticVersion> = open_prs
             .iter()
-            .filter(|pr| is_release_pr_branch(&pr.head.ref_name, &branch_prefix, &version_prefix))
+            .filter(|pr| is_release_pr(pr))
             .filter_map(|pr| {
-                release_automator::extract_version_from_branch(
+                branch_template::version_from_release_pr(
+                    branch_template,
+                    &release_branch_vars(repo_config, owner, repo, &pr.base.ref_name),
                     &pr.head.ref_name,
-                    &branch_prefix,
-                    &version_prefix,
+                    &pr.title,
+                    pr.body.as_deref().unwrap_or_default(),
                 )
                 .ok()
             })
@@ -1701,7 +2492,7 @@ where
         // Feature PRs only; skip excluded authors; cap at 25.
         let candidates: Vec<_> = open_prs
             .into_iter()
-            .filter(|pr| !is_release_pr_branch(&pr.head.ref_name, &branch_prefix, &version_prefix))
+            .filter(|pr| !is_release_pr(pr))
             .filter(|pr| {
                 let login = pr.user.login.as_deref().unwrap_or_default();
                 !excluded.iter().any(|a| a == login)
@@ -1722,6 +2513,25 @@ where
                     return;
                 }
             };
+        let package_versions = match monorepo::resolve_package_versions(
+            &scoped_github,
+            owner,
+            repo,
+            repo_config.versioning.strategy.packages(),
+        )
+        .await
+        {
+            Ok(v) => v,
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    owner = %owner,
+                    repo = %repo,
+                    "Failed to resolve package versions for PR refresh"
+                );
+                return;
+            }
+        };
 
         let base_version = current_version
             .clone()
@@ -1741,6 +2551,21 @@ where
                     include_prerelease: false,
                 }
             }
+            ref monorepo @ config::VersioningStrategy::Monorepo { .. } => monorepo.clone().into(),
+        };
+        let minimum_version = match repo_config.versioning.parsed_minimum_version() {
+            Ok(v) => v,
+            Err(e) => {
+                tracing::warn!(error = %e, "Invalid minimum version; skipping PR refresh");
+                return;
+            }
+        };
+        let initial_version = match repo_config.versioning.parsed_initial_version() {
+            Ok(v) => v,
+            Err(e) => {
+                tracing::warn!(error = %e, "Invalid initial version; skipping PR refresh");
+                return;
+            }
         };
 
         for pr in candidates {
@@ -1767,21 +2592,45 @@ where
                 continue;
             }
 
-            let ctx = VersionContext {
-                base_ref: current_version
-                    .as_ref()
-                    .map(|v| format!("{}{v}", repo_config.core.version_prefix)),
-                current_version: current_version.clone(),
-                head_ref: pr.head.sha.clone(),
-                owner: owner.to_string(),
-                repo: repo.to_string(),
-                target_branch: pr.head.ref_name.clone(),
+            let ctx = VersionContext::builder()
+                .with_owner(owner)
+                .with_repo(repo)
+                .with_current_version(current_version.clone())
+                .with_package_versions(package_versions.clone())
+                .with_version_prefix(&repo_config.core.version_prefix)
+                .with_head_ref(pr.head.sha.clone())
+                .with_target_branch(pr.head.ref_name.clone())
+                .build();
+            let ctx = match ctx {
+                Ok(ctx) => ctx,
+                Err(e) => {
+                    tracing::warn!(
+                        error = %e,
+                        pr = pr.number,
+                        "Invalid version context for PR refresh; skipping PR"
+                    );
+                    continue;
+                }
             };
 
             let scoped_calc = self.version_calculator.scoped_to(installation_id);
-            let calc_result = match scoped_calc
-                .calculate_version(ctx, strategy.clone(), CalculationOptions::default())
-                .await
+            let calc_result = match telemetry::time_stage(
+                telemetry::STAGE_VERSION_CALCULATION,
+                scoped_calc.calculate_version(
+                    ctx,
+                    strategy.clone(),
+                    CalculationOptions {
+                        bump_scopes: repo_config.versioning.scopes.clone(),
+                        breaking_scopes: repo_config.versioning.breaking_scopes.clone(),
+                        no_conventional_commits: repo_config.versioning.no_conventional_commits,
+                        exclusions: repo_config.versioning.exclusions.clone(),
+                        minimum_version: minimum_version.clone(),
+                        initial_version: initial_version.clone(),
+                        ..Default::default()
+                    },
+                ),
+            )
+            .await
             {
                 Ok(r) => r,
                 Err(e) => {
@@ -491,6 +491,35 @@ async fn test_run_event_loop_dispatches_pull_request_updated_to_activity_handler
     assert!(handler.received_event_ids().await.is_empty());
 }
 
+/// `run_event_loop` dispatches `PullRequestReactivated` events to
+/// `handle_pull_request_reactivated`, which by default refreshes the PR
+/// status like any other activity.
+#[tokio::test]
+async fn test_run_event_loop_dispatches_pull_request_reactivated_to_activity_handler() {
+    let token = CancellationToken::new();
+    let source = TestEventSource::new(vec![make_test_event(
+        "evt-reactivated",
+        EventType::PullRequestReactivated,
+    )]);
+    let source_for_loop = source.clone();
+    let loop_token = token.clone();
+
+    let handler = SpyMergedPRHandler::new();
+    let handler_for_loop = handler.clone();
+
+    let loop_handle = tokio::spawn(async move {
+        run_event_loop(&source_for_loop, &handler_for_loop, loop_token).await
+    });
+
+    let acked = wait_for_acks(&source, 1, &token).await;
+    loop_handle.await.unwrap().unwrap();
+
+    assert_eq!(acked, vec!["evt-reactivated"]);
+    let activity = handler.received_activity_event_ids().await;
+    assert_eq!(activity, vec!["evt-reactivated"]);
+    assert!(handler.received_event_ids().await.is_empty());
+}
+
 /// A `PullRequestMerged` event whose handler returns an error is rejected.
 #[tokio::test]
 async fn test_run_event_loop_rejects_event_when_handler_fails() {
@@ -539,6 +568,41 @@ async fn test_run_event_loop_rejects_event_when_handler_fails() {
     assert!(source.acknowledged_ids().await.is_empty());
 }
 
+/// A handler error saying the App is not installed acknowledges the event
+/// instead of rejecting it, since retrying cannot succeed.
+#[tokio::test]
+async fn test_run_event_loop_acknowledges_event_when_app_not_installed() {
+    #[derive(Clone)]
+    struct NotInstalledHandler;
+
+    #[async_trait]
+    impl MergedPullRequestHandler for NotInstalledHandler {
+        async fn handle_merged_pull_request(&self, event: &ProcessingEvent) -> CoreResult<()> {
+            Err(CoreError::app_not_installed(
+                &event.repository.owner,
+                &event.repository.name,
+            ))
+        }
+    }
+
+    let token = CancellationToken::new();
+    let source = TestEventSource::new(vec![make_test_event(
+        "evt-not-installed",
+        EventType::PullRequestMerged,
+    )]);
+    let source_for_loop = source.clone();
+    let loop_token = token.clone();
+
+    let loop_handle = tokio::spawn(async move {
+        run_event_loop(&source_for_loop, &NotInstalledHandler, loop_token).await
+    });
+
+    let acked = wait_for_acks(&source, 1, &token).await;
+    loop_handle.await.unwrap().unwrap();
+    assert_eq!(acked, vec!["evt-not-installed"]);
+    assert!(source.rejected_ids().await.is_empty());
+}
+
 use std::collections::HashMap;
 use traits::{
     configuration_provider::{
@@ -546,20 +610,24 @@ use traits::{
     },
     git_operations::{
         GetCommitsOptions, GitCommit, GitOperations, GitRepository, GitTag, GitTagType,
-        GitUser as GitOpsUser, ListTagsOptions,
+        GitUser as GitOpsUser, ListTagsOptions, RepositoryVisibility,
     },
     github_operations::{
-        CreatePullRequestParams, CreateReleaseParams, GitHubOperations, GitUser, Label,
-        PullRequest, PullRequestBranch, Release, Repository, Tag, UpdateReleaseParams,
+        CommitStatusState, CreateCommitStatusParams, CreatePullRequestParams, CreateReleaseParams,
+        GitHubOperations, GitUser, Label, LabelMode, PullRequest, PullRequestBranch, Release,
+        Repository, Tag, UpdateReleaseParams,
     },
     version_calculator::{
-        CalculationOptions, ChangelogEntry, CommitAnalysis, ValidationRules, VersionBump,
-        VersionCalculationResult, VersionCalculator, VersionContext,
+        BumpReason, CalculationOptions, ChangelogEntry, CommitAnalysis, ValidationRules,
+        VersionBump, VersionCalculationResult, VersionCalculator, VersionContext,
         VersioningStrategy as VCalcStrategy,
     },
 };
 use versioning::SemanticVersion;
 
+/// Recorded `set_pull_request_labels` call: (pr_number, labels, mode).
+type LabelCall = (u64, Vec<String>, LabelMode);
+
 // ── Shared helpers ─────────────────────────────────────────────────────────
 
 fn make_repo() -> Repository {
@@ -642,9 +710,17 @@ struct TestGitHubForLib {
     /// Labels keyed by PR / issue number (drives `list_pr_labels`).
     pr_labels: HashMap<u64, Vec<Label>>,
     created_prs: Arc<Mutex<Vec<(String, String, String)>>>, // (branch, title, body)
+    /// Records the base branch of every created pull request.
+    created_pr_bases: Arc<Mutex<Vec<String>>>,
+    /// Branches `get_head_commit` reports as missing.
+    missing_branches: Vec<String>,
     create_branch_calls: Arc<Mutex<Vec<String>>>,
+    /// Records every `(sha, params)` passed to `create_commit_status`.
+    commit_statuses: Arc<Mutex<Vec<(String, CreateCommitStatusParams)>>>,
     /// Records every `(issue_number, label_name)` passed to `remove_label`.
     removed_labels: Arc<Mutex<Vec<(u64, String)>>>,
+    /// Records every `(pr_number, labels, mode)` passed to `set_pull_request_labels`.
+    set_labels_calls: Arc<Mutex<Vec<LabelCall>>>,
     /// Records every `(issue_number, body)` passed to `create_issue_comment`.
     issue_comments: Arc<Mutex<Vec<(u64, String)>>>,
     /// Pre-seeded issue comments returned by `list_issue_comments`, keyed by
@@ -652,9 +728,6 @@ struct TestGitHubForLib {
     stored_issue_comments: HashMap<u64, Vec<crate::traits::github_operations::IssueComment>>,
     /// Records every `(comment_id, body)` passed to `update_issue_comment`.
     update_comment_calls: Arc<Mutex<Vec<(u64, String)>>>,
-    /// When set, `search_pull_requests` returns an error if the query
-    /// contains this label name.  Other searches succeed normally.
-    fail_search_for_label: Option<String>,
     /// When set, `remove_label` returns a network error for the specified PR number.
     fail_remove_label_for_pr: Option<u64>,
     /// When set, `create_issue_comment` returns a network error for the specified
@@ -663,6 +736,16 @@ struct TestGitHubForLib {
     /// When set, `list_pr_labels` returns a `CoreError::GitHub` for the
     /// specified PR / issue number.
     fail_list_labels_for_pr: Option<u64>,
+    /// Default branch reported by `get_repository_info`.
+    default_branch: String,
+    /// Number of `get_repository_info` calls.
+    repository_info_calls: Arc<Mutex<usize>>,
+    /// Records every `CreateReleaseParams` passed to `create_release`.
+    created_releases: Arc<Mutex<Vec<CreateReleaseParams>>>,
+    /// Author login reported by `get_commit`, keyed by commit SHA.
+    commit_logins: HashMap<String, String>,
+    /// Records every SHA passed to `get_commit`.
+    get_commit_calls: Arc<Mutex<Vec<String>>>,
 }
 
 impl TestGitHubForLib {
@@ -673,23 +756,49 @@ impl TestGitHubForLib {
             search_results: vec![],
             pr_labels: HashMap::new(),
             created_prs: Arc::new(Mutex::new(vec![])),
+            created_pr_bases: Arc::new(Mutex::new(vec![])),
+            missing_branches: vec![],
             create_branch_calls: Arc::new(Mutex::new(vec![])),
+            commit_statuses: Arc::new(Mutex::new(vec![])),
             removed_labels: Arc::new(Mutex::new(vec![])),
+            set_labels_calls: Arc::new(Mutex::new(vec![])),
             issue_comments: Arc::new(Mutex::new(vec![])),
             stored_issue_comments: HashMap::new(),
             update_comment_calls: Arc::new(Mutex::new(vec![])),
-            fail_search_for_label: None,
             fail_remove_label_for_pr: None,
             fail_comment_for_pr: None,
             fail_list_labels_for_pr: None,
+            default_branch: "main".into(),
+            repository_info_calls: Arc::new(Mutex::new(0)),
+            created_releases: Arc::new(Mutex::new(vec![])),
+            commit_logins: HashMap::new(),
+            get_commit_calls: Arc::new(Mutex::new(vec![])),
         }
     }
 
+    /// Report `login` as the author login of commit `sha` from `get_commit`.
+    fn with_commit_login(mut self, sha: &str, login: &str) -> Self {
+        self.commit_logins
+            .insert(sha.to_string(), login.to_string());
+        self
+    }
+
+    /// Report `branch` as the default branch from `get_repository_info`.
+    fn with_default_branch(mut self, branch: &str) -> Self {
+        self.default_branch = branch.to_string();
+        self
+    }
+
     fn with_tags(mut self, tags: Vec<GitTag>) -> Self {
         self.tags = tags;
         self
     }
 
+    fn with_missing_branch(mut self, branch: &str) -> Self {
+        self.missing_branches.push(branch.to_string());
+        self
+    }
+
     fn with_existing_prs(mut self, prs: Vec<PullRequest>) -> Self {
         self.existing_prs = prs;
         self
@@ -715,13 +824,6 @@ impl TestGitHubForLib {
         self
     }
 
-    /// Make `search_pull_requests` return an error when the query contains
-    /// the specified label name.  Other searches succeed normally.
-    fn with_fail_search_for_label(mut self, label: impl Into<String>) -> Self {
-        self.fail_search_for_label = Some(label.into());
-        self
-    }
-
     /// Make `remove_label` return a network error for the specified PR number.
     fn with_fail_remove_label_for_pr(mut self, pr_number: u64) -> Self {
         self.fail_remove_label_for_pr = Some(pr_number);
@@ -756,8 +858,21 @@ impl GitOperations for TestGitHubForLib {
         Ok(vec![])
     }
 
+    async fn list_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _head: &str,
+        _options: GetCommitsOptions,
+    ) -> CoreResult<Vec<GitCommit>> {
+        Ok(vec![])
+    }
+
     async fn get_commit(&self, _owner: &str, _repo: &str, sha: &str) -> CoreResult<GitCommit> {
-        Ok(make_git_commit(sha))
+        self.get_commit_calls.lock().await.push(sha.to_string());
+        let mut commit = make_git_commit(sha);
+        commit.author.login = self.commit_logins.get(sha).cloned();
+        Ok(commit)
     }
 
     async fn list_tags(
@@ -781,21 +896,28 @@ impl GitOperations for TestGitHubForLib {
         &self,
         _owner: &str,
         _repo: &str,
-        _branch: Option<&str>,
+        branch: Option<&str>,
     ) -> CoreResult<GitCommit> {
+        if let Some(branch) = branch.filter(|b| self.missing_branches.iter().any(|m| m == b)) {
+            return Err(CoreError::not_found(format!("branch '{branch}'")));
+        }
         Ok(make_git_commit("head000000000000000000000000000000000000"))
     }
 
     async fn get_repository_info(&self, owner: &str, repo: &str) -> CoreResult<GitRepository> {
+        *self.repository_info_calls.lock().await += 1;
         Ok(GitRepository {
             name: repo.to_string(),
             owner: owner.to_string(),
             full_name: format!("{owner}/{repo}"),
-            default_branch: "main".into(),
+            default_branch: self.default_branch.clone(),
             clone_url: format!("https://github.com/{owner}/{repo}"),
             ssh_url: format!("git@github.com:{owner}/{repo}.git"),
             private: false,
             description: None,
+            topics: Vec::new(),
+            language: None,
+            visibility: RepositoryVisibility::Public,
         })
     }
 }
@@ -809,6 +931,7 @@ impl GitHubOperations for TestGitHubForLib {
         params: CreatePullRequestParams,
     ) -> CoreResult<PullRequest> {
         let pr = make_pr(42, &params.head, &params.title);
+        self.created_pr_bases.lock().await.push(params.base.clone());
         self.created_prs.lock().await.push((
             params.head,
             params.title,
@@ -823,6 +946,7 @@ impl GitHubOperations for TestGitHubForLib {
         _repo: &str,
         params: CreateReleaseParams,
     ) -> CoreResult<Release> {
+        self.created_releases.lock().await.push(params.clone());
         Ok(Release {
             id: 1,
             tag_name: params.tag_name,
@@ -861,6 +985,21 @@ impl GitHubOperations for TestGitHubForLib {
         })
     }
 
+    async fn create_signed_tag(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        params: crate::traits::github_operations::SignedTagParams,
+    ) -> CoreResult<Tag> {
+        Ok(Tag {
+            name: params.tag_name,
+            commit_sha: params.commit_sha,
+            message: None,
+            tagger: None,
+            created_at: None,
+        })
+    }
+
     async fn get_latest_release(&self, _owner: &str, _repo: &str) -> CoreResult<Option<Release>> {
         Ok(None)
     }
@@ -883,8 +1022,8 @@ impl GitHubOperations for TestGitHubForLib {
         _owner: &str,
         _repo: &str,
         _tag: &str,
-    ) -> CoreResult<Release> {
-        Err(CoreError::not_found("release"))
+    ) -> CoreResult<Option<Release>> {
+        Ok(None)
     }
 
     async fn list_releases(
@@ -897,6 +1036,18 @@ impl GitHubOperations for TestGitHubForLib {
         Ok(vec![])
     }
 
+    async fn upload_release_asset(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _release_id: u64,
+        _name: &str,
+        _content_type: Option<&str>,
+        _data: Vec<u8>,
+    ) -> CoreResult<String> {
+        Err(CoreError::not_found("stub"))
+    }
+
     async fn list_pull_requests(
         &self,
         _owner: &str,
@@ -920,15 +1071,8 @@ impl GitHubOperations for TestGitHubForLib {
         &self,
         _owner: &str,
         _repo: &str,
-        query: &str,
+        _query: &str,
     ) -> CoreResult<Vec<PullRequest>> {
-        if let Some(ref failing_label) = self.fail_search_for_label {
-            if query.contains(failing_label.as_str()) {
-                return Err(CoreError::network(format!(
-                    "Simulated search_pull_requests failure for label {failing_label}"
-                )));
-            }
-        }
         Ok(self.search_results.clone())
     }
 
@@ -972,6 +1116,20 @@ impl GitHubOperations for TestGitHubForLib {
         Ok(())
     }
 
+    async fn create_commit_status(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        sha: &str,
+        params: CreateCommitStatusParams,
+    ) -> CoreResult<()> {
+        self.commit_statuses
+            .lock()
+            .await
+            .push((sha.to_string(), params));
+        Ok(())
+    }
+
     async fn delete_branch(&self, _owner: &str, _repo: &str, _branch_name: &str) -> CoreResult<()> {
         Ok(())
     }
@@ -986,6 +1144,24 @@ impl GitHubOperations for TestGitHubForLib {
         Ok(())
     }
 
+    /// Creates the branch like `create_branch`; an existing branch is reported
+    /// as diverged, as a release branch carrying its release commit would be.
+    async fn upsert_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch_name: &str,
+        from_sha: &str,
+    ) -> CoreResult<crate::traits::github_operations::BranchUpsert> {
+        match self.create_branch(owner, repo, branch_name, from_sha).await {
+            Ok(()) => Ok(crate::traits::github_operations::BranchUpsert::Created),
+            Err(CoreError::Conflict { .. }) => {
+                Err(CoreError::not_fast_forward(branch_name, from_sha))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     async fn create_issue_comment(
         &self,
         _owner: &str,
@@ -1043,6 +1219,26 @@ impl GitHubOperations for TestGitHubForLib {
         Ok(())
     }
 
+    async fn resolve_ref(&self, _owner: &str, _repo: &str, _git_ref: &str) -> CoreResult<String> {
+        Err(CoreError::not_found("stub"))
+    }
+
+    async fn set_pull_request_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        pr_number: u64,
+        labels: &[&str],
+        mode: LabelMode,
+    ) -> CoreResult<()> {
+        self.set_labels_calls.lock().await.push((
+            pr_number,
+            labels.iter().map(ToString::to_string).collect(),
+            mode,
+        ));
+        Ok(())
+    }
+
     async fn list_pr_labels(
         &self,
         _owner: &str,
@@ -1124,6 +1320,15 @@ impl GitHubOperations for TestGitHubForLib {
             .unwrap_or_default())
     }
 
+    async fn list_pull_request_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+    ) -> CoreResult<Vec<crate::traits::git_operations::GitCommit>> {
+        Ok(vec![])
+    }
+
     async fn update_issue_comment(
         &self,
         _owner: &str,
@@ -1145,15 +1350,23 @@ impl GitHubOperations for TestGitHubForLib {
             search_results: self.search_results.clone(),
             pr_labels: self.pr_labels.clone(),
             created_prs: Arc::clone(&self.created_prs),
+            created_pr_bases: Arc::clone(&self.created_pr_bases),
+            missing_branches: self.missing_branches.clone(),
             create_branch_calls: Arc::clone(&self.create_branch_calls),
+            commit_statuses: Arc::clone(&self.commit_statuses),
             removed_labels: Arc::clone(&self.removed_labels),
+            set_labels_calls: Arc::clone(&self.set_labels_calls),
             issue_comments: Arc::clone(&self.issue_comments),
             stored_issue_comments: self.stored_issue_comments.clone(),
             update_comment_calls: Arc::clone(&self.update_comment_calls),
-            fail_search_for_label: self.fail_search_for_label.clone(),
             fail_remove_label_for_pr: self.fail_remove_label_for_pr,
             fail_comment_for_pr: self.fail_comment_for_pr,
             fail_list_labels_for_pr: self.fail_list_labels_for_pr,
+            default_branch: self.default_branch.clone(),
+            repository_info_calls: Arc::clone(&self.repository_info_calls),
+            created_releases: Arc::clone(&self.created_releases),
+            commit_logins: self.commit_logins.clone(),
+            get_commit_calls: Arc::clone(&self.get_commit_calls),
         }
     }
 }
@@ -1351,6 +1564,12 @@ struct TestVersionCalcForLib {
     next_version: SemanticVersion,
     changelog_entries: Vec<ChangelogEntry>,
     version_bump: VersionBump,
+    /// Rule of the `bump_reason` returned with every result.
+    bump_reason: Option<String>,
+    /// Whether `calculate_version` fails as if comparing commits failed.
+    fail_comparison: bool,
+    /// Files changed by each commit, used to version monorepo packages.
+    changed_files: HashMap<String, Vec<String>>,
     captured_ctx: Arc<Mutex<Option<VersionContext>>>,
 }
 
@@ -1360,10 +1579,27 @@ impl TestVersionCalcForLib {
             next_version: versioning::VersionCalculator::parse_version(version).unwrap(),
             changelog_entries: vec![],
             version_bump: VersionBump::Minor,
+            bump_reason: None,
+            fail_comparison: false,
+            changed_files: HashMap::new(),
             captured_ctx: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// Report `files` as the files changed by commit `sha`.
+    fn with_changed_files(mut self, sha: &str, files: &[&str]) -> Self {
+        self.changed_files.insert(
+            sha.to_string(),
+            files.iter().map(ToString::to_string).collect(),
+        );
+        self
+    }
+
+    fn with_failing_comparison(mut self) -> Self {
+        self.fail_comparison = true;
+        self
+    }
+
     fn with_entries(mut self, entries: Vec<ChangelogEntry>) -> Self {
         self.changelog_entries = entries;
         self
@@ -1374,20 +1610,17 @@ impl TestVersionCalcForLib {
         self
     }
 
+    fn with_bump_reason(mut self, rule: &str) -> Self {
+        self.bump_reason = Some(rule.to_string());
+        self
+    }
+
     async fn last_context(&self) -> Option<VersionContext> {
         self.captured_ctx.lock().await.clone()
     }
-}
 
-#[async_trait]
-impl VersionCalculator for TestVersionCalcForLib {
-    async fn calculate_version(
-        &self,
-        ctx: VersionContext,
-        _strategy: VCalcStrategy,
-        _options: CalculationOptions,
-    ) -> CoreResult<VersionCalculationResult> {
-        *self.captured_ctx.lock().await = Some(ctx.clone());
+    /// The result returned for a calculation starting at `current_version`.
+    fn result_for(&self, current_version: Option<SemanticVersion>) -> VersionCalculationResult {
         // Synthesise CommitAnalysis entries from changelog_entries so that
         // calculate_version_for_merge can build ConventionalCommit items from
         // analyzed_commits (which carry the raw commit_type/message data).
@@ -1415,10 +1648,15 @@ impl VersionCalculator for TestVersionCalcForLib {
                 }
             })
             .collect();
-        Ok(VersionCalculationResult {
+        VersionCalculationResult {
             next_version: self.next_version.clone(),
-            current_version: ctx.current_version,
+            current_version,
             version_bump: self.version_bump.clone(),
+            bump_reason: self.bump_reason.as_ref().map(|rule| BumpReason {
+                bump: self.version_bump.clone(),
+                rule: rule.clone(),
+                commits: Vec::new(),
+            }),
             is_prerelease: false,
             build_metadata: None,
             analyzed_commits,
@@ -1428,7 +1666,46 @@ impl VersionCalculator for TestVersionCalcForLib {
                 include_prerelease: false,
             },
             metadata: HashMap::new(),
-        })
+            packages: std::collections::BTreeMap::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl VersionCalculator for TestVersionCalcForLib {
+    async fn calculate_version(
+        &self,
+        ctx: VersionContext,
+        strategy: VCalcStrategy,
+        _options: CalculationOptions,
+    ) -> CoreResult<VersionCalculationResult> {
+        *self.captured_ctx.lock().await = Some(ctx.clone());
+        if self.fail_comparison {
+            return Err(CoreError::network("compare request failed").in_stage(
+                ProcessingStage::CommitComparison,
+                &ctx.owner,
+                &ctx.repo,
+            ));
+        }
+        let mut result = self.result_for(ctx.current_version.clone());
+        if let VCalcStrategy::Monorepo { packages } = &strategy {
+            let analyses: Vec<CommitAnalysis> = result
+                .analyzed_commits
+                .iter()
+                .cloned()
+                .map(|mut analysis| {
+                    analysis.version_bump = self.version_bump.clone();
+                    analysis
+                })
+                .collect();
+            result.packages = monorepo::calculate_package_versions(
+                packages,
+                &analyses,
+                &self.changed_files,
+                &ctx.package_versions,
+            )?;
+        }
+        Ok(result)
     }
 
     async fn analyze_commits(
@@ -1507,6 +1784,9 @@ impl VersionCalculator for TestVersionCalcForLib {
             next_version: self.next_version.clone(),
             changelog_entries: self.changelog_entries.clone(),
             version_bump: self.version_bump.clone(),
+            bump_reason: self.bump_reason.clone(),
+            fail_comparison: self.fail_comparison,
+            changed_files: self.changed_files.clone(),
             captured_ctx: Arc::clone(&self.captured_ctx),
         })
     }
@@ -1565,6 +1845,80 @@ async fn test_handle_merged_pr_creates_release_pr_when_none_exists() {
     assert_eq!(created_prs.len(), 1);
 }
 
+/// After the release PR is created, the planned version and the reason for
+/// the bump are reported as a successful commit status on the PR head.
+#[tokio::test]
+async fn test_handle_merged_pr_posts_version_status_on_release_pr() {
+    let github = TestGitHubForLib::new_empty();
+    let version_calc = TestVersionCalcForLib::returning("0.2.0").with_bump_reason("feat");
+    let processor = ReleaseRegentProcessor::new(github.clone(), TestConfigForLib, version_calc);
+
+    processor
+        .handle_merged_pull_request(&merged_pr_event_for_acme_app())
+        .await
+        .unwrap();
+
+    let statuses = github.commit_statuses.lock().await;
+    assert_eq!(statuses.len(), 1, "expected one commit status");
+    let (sha, params) = &statuses[0];
+    assert_eq!(sha, "head000000000000000000000000000000000000");
+    assert_eq!(params.context, release_orchestrator::VERSION_STATUS_CONTEXT);
+    assert_eq!(params.state, CommitStatusState::Success);
+    assert_eq!(params.description, "Next release: v0.2.0 (feat)");
+}
+
+/// No commit status is posted when the merge needs no release.
+#[tokio::test]
+async fn test_handle_merged_pr_posts_no_version_status_when_no_release_needed() {
+    let tag = GitTag {
+        name: "v0.1.0".to_string(),
+        target_sha: "a".repeat(40),
+        tag_type: GitTagType::Lightweight,
+        message: None,
+        tagger: None,
+        created_at: None,
+    };
+    let github = TestGitHubForLib::new_empty().with_tags(vec![tag]);
+    let version_calc =
+        TestVersionCalcForLib::returning("0.1.0").with_version_bump(VersionBump::None);
+    let processor = ReleaseRegentProcessor::new(github.clone(), TestConfigForLib, version_calc);
+
+    let result = processor
+        .handle_merged_pull_request(&merged_pr_event_for_acme_app())
+        .await
+        .unwrap();
+
+    assert!(
+        matches!(
+            result,
+            release_orchestrator::OrchestratorResult::NoBumpNeeded { .. }
+        ),
+        "expected NoBumpNeeded, got {result:?}"
+    );
+    assert!(github.commit_statuses.lock().await.is_empty());
+}
+
+/// Status descriptions longer than GitHub's limit are cut with an ellipsis.
+#[test]
+fn test_version_status_description_is_truncated_to_github_limit() {
+    let mut result = TestVersionCalcForLib::returning("1.0.0")
+        .with_bump_reason(&"x".repeat(200))
+        .result_for(None);
+    let description = version_status_description("v1.0.0", &result);
+    assert_eq!(
+        description.chars().count(),
+        release_orchestrator::MAX_STATUS_DESCRIPTION_LEN
+    );
+    assert!(description.starts_with("Next release: v1.0.0 (xxx"));
+    assert!(description.ends_with('…'));
+
+    result.bump_reason = None;
+    assert_eq!(
+        version_status_description("v1.0.0", &result),
+        "Next release: v1.0.0 (minor bump)"
+    );
+}
+
 /// When the payload is missing both `merge_commit_sha` and `head.sha`, the
 /// method returns `CoreError::InvalidInput`.
 #[tokio::test]
@@ -1642,60 +1996,302 @@ async fn test_handle_merged_pr_uses_default_branch_when_base_ref_absent() {
     assert_eq!(prs.len(), 1);
 }
 
-/// Changelog entries produced by the version calculator are rendered into the
-/// PR body.
-#[tokio::test]
-async fn test_handle_merged_pr_includes_changelog_entries_in_pr_body() {
-    let github = TestGitHubForLib::new_empty();
-    let config = TestConfigForLib;
-    let version_calc = TestVersionCalcForLib::returning("0.3.0").with_entries(vec![
-        ChangelogEntry {
-            commit_sha: "a".repeat(40),
-            description: "add shiny feature".into(),
-            entry_type: "feat".into(),
-            is_breaking: false,
-            issues: vec![],
-            pr_number: None,
-            scope: None,
-        },
-        ChangelogEntry {
-            commit_sha: "b".repeat(40),
-            description: "fix nasty bug".into(),
-            entry_type: "fix".into(),
-            is_breaking: false,
-            issues: vec![],
-            pr_number: None,
-            scope: None,
+/// Build a feature-PR merge event into `base_ref` for a repository whose
+/// default branch is `main`.
+fn make_merged_feature_event(base_ref: &str) -> ProcessingEvent {
+    ProcessingEvent {
+        event_id: "evt-target".into(),
+        correlation_id: "corr-target".into(),
+        event_type: EventType::PullRequestMerged,
+        repository: RepositoryInfo {
+            owner: "acme".into(),
+            name: "app".into(),
+            default_branch: "main".into(),
         },
-    ]);
-
-    let processor = ReleaseRegentProcessor::new(github.clone(), config, version_calc);
+        payload: serde_json::json!({
+            "pull_request": {
+                "base": { "ref": base_ref },
+                "head": { "ref": "feat/thing" },
+                "merge_commit_sha": "b".repeat(40)
+            }
+        }),
+        received_at: Utc::now(),
+        source: EventSourceKind::Webhook,
+        installation_id: 0,
+    }
+}
 
-    let event = ProcessingEvent {
-        event_id: "evt-4".into(),
-        correlation_id: "corr-4".into(),
-        event_type: EventType::PullRequestMerged,
+/// A `reopened` or `ready_for_review` event for PR #42 with head `head_ref`.
+fn make_reactivated_event(head_ref: &str) -> ProcessingEvent {
+    ProcessingEvent {
+        event_id: "evt-reactivated".into(),
+        correlation_id: "corr-reactivated".into(),
+        event_type: EventType::PullRequestReactivated,
         repository: RepositoryInfo {
             owner: "acme".into(),
             name: "app".into(),
             default_branch: "main".into(),
         },
         payload: serde_json::json!({
+            "action": "reopened",
             "pull_request": {
+                "number": 42,
                 "base": { "ref": "main" },
-                "merge_commit_sha": "cccccccccccccccccccccccccccccccccccccccc"
+                "head": { "ref": head_ref, "sha": "c".repeat(40) }
             }
         }),
         received_at: Utc::now(),
         source: EventSourceKind::Webhook,
         installation_id: 0,
-    };
+    }
+}
 
-    processor.handle_merged_pull_request(&event).await.unwrap();
+/// A reopened release PR is recalculated from its target branch and updated
+/// in place.
+#[tokio::test]
+async fn test_refresh_release_pr_updates_reopened_release_pr() {
+    let release_pr = make_pr(42, "release/v0.2.0", "chore(release): v0.2.0");
+    let github = TestGitHubForLib::new_empty()
+        .with_search_results(vec![release_pr.clone()])
+        .with_existing_prs(vec![release_pr]);
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigForLib,
+        TestVersionCalcForLib::returning("0.2.0"),
+    );
+
+    let result = processor
+        .refresh_release_pr(&make_reactivated_event("release/v0.2.0"))
+        .await
+        .unwrap();
 
-    let prs = github.created_prs.lock().await;
-    assert_eq!(prs.len(), 1);
-    let body = &prs[0].2;
+    assert!(
+        matches!(
+            result,
+            Some(release_orchestrator::OrchestratorResult::Updated { ref pr }) if pr.number == 42
+        ),
+        "expected PR 42 to be updated, got {result:?}"
+    );
+    assert!(github.create_branch_calls.lock().await.is_empty());
+    assert!(github.created_prs.lock().await.is_empty());
+}
+
+/// Reactivating a feature PR leaves the release PR alone.
+#[tokio::test]
+async fn test_refresh_release_pr_ignores_feature_prs() {
+    let github = TestGitHubForLib::new_empty();
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigForLib,
+        TestVersionCalcForLib::returning("0.2.0"),
+    );
+
+    let result = processor
+        .refresh_release_pr(&make_reactivated_event("feat/thing"))
+        .await
+        .unwrap();
+
+    assert!(result.is_none(), "{result:?}");
+    assert!(github.create_branch_calls.lock().await.is_empty());
+    assert!(github.created_prs.lock().await.is_empty());
+}
+
+fn config_with_target_branch(branch: &str) -> config::ReleaseRegentConfig {
+    let mut cfg = config::ReleaseRegentConfig::default();
+    cfg.releases.target_branch = Some(branch.to_string());
+    cfg
+}
+
+/// A repository configured to release from `develop` opens its release PR
+/// against `develop` rather than the default branch.
+#[tokio::test]
+async fn test_handle_merged_pr_releases_from_configured_target_branch() {
+    let github = TestGitHubForLib::new_empty();
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigWith::new(config_with_target_branch("develop")),
+        TestVersionCalcForLib::returning("0.2.0"),
+    );
+
+    let result = processor
+        .handle_merged_pull_request(&make_merged_feature_event("develop"))
+        .await
+        .unwrap();
+
+    assert!(
+        matches!(
+            result,
+            release_orchestrator::OrchestratorResult::Created { .. }
+        ),
+        "expected Created, got {result:?}"
+    );
+    assert_eq!(*github.created_pr_bases.lock().await, vec!["develop"]);
+}
+
+/// With a release target configured, merges into other branches are skipped.
+#[tokio::test]
+async fn test_handle_merged_pr_into_non_target_branch_is_skipped() {
+    let github = TestGitHubForLib::new_empty();
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigWith::new(config_with_target_branch("develop")),
+        TestVersionCalcForLib::returning("0.2.0"),
+    );
+
+    let result = processor
+        .handle_merged_pull_request(&make_merged_feature_event("main"))
+        .await
+        .unwrap();
+
+    match result {
+        release_orchestrator::OrchestratorResult::NotReleaseTarget {
+            base_branch,
+            target_branch,
+        } => {
+            assert_eq!(base_branch, "main");
+            assert_eq!(target_branch, "develop");
+        }
+        other => panic!("expected NotReleaseTarget, got {other:?}"),
+    }
+    assert!(github.create_branch_calls.lock().await.is_empty());
+    assert!(github.created_prs.lock().await.is_empty());
+}
+
+/// `release_pr.labels` from the repository configuration are applied to the
+/// new release PR.
+#[tokio::test]
+async fn test_handle_merged_pr_applies_configured_release_pr_labels() {
+    let mut cfg = config::ReleaseRegentConfig::default();
+    cfg.release_pr.labels = vec!["autorelease: pending".to_string()];
+    cfg.release_pr.label_mode = LabelMode::Replace;
+    let github = TestGitHubForLib::new_empty();
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigWith::new(cfg),
+        TestVersionCalcForLib::returning("0.2.0"),
+    );
+
+    let result = processor
+        .handle_merged_pull_request(&make_merged_feature_event("main"))
+        .await
+        .unwrap();
+
+    let release_orchestrator::OrchestratorResult::Created { pr, .. } = result else {
+        panic!("expected Created, got {result:?}");
+    };
+    assert_eq!(
+        *github.set_labels_calls.lock().await,
+        vec![(
+            pr.number,
+            vec!["autorelease: pending".to_string()],
+            LabelMode::Replace
+        )]
+    );
+}
+
+/// A configured release target that does not exist is a configuration error.
+#[tokio::test]
+async fn test_handle_merged_pr_with_missing_target_branch_returns_config_error() {
+    let github = TestGitHubForLib::new_empty().with_missing_branch("develop");
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigWith::new(config_with_target_branch("develop")),
+        TestVersionCalcForLib::returning("0.2.0"),
+    );
+
+    let err = processor
+        .handle_merged_pull_request(&make_merged_feature_event("develop"))
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, CoreError::Config { .. }),
+        "expected Config, got {err:?}"
+    );
+    assert!(err.to_string().contains("develop"), "{err}");
+    assert_eq!(err.stage(), Some(ProcessingStage::ConfigLoad));
+    assert!(github.created_prs.lock().await.is_empty());
+}
+
+/// A failed commit comparison is reported with its stage, not the enclosing
+/// version calculation stage.
+#[tokio::test]
+async fn test_handle_merged_pr_comparison_error_carries_commit_comparison_stage() {
+    let github = TestGitHubForLib::new_empty();
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigForLib,
+        TestVersionCalcForLib::returning("0.2.0").with_failing_comparison(),
+    );
+
+    let err = processor
+        .handle_merged_pull_request(&merged_pr_event_for_acme_app())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, CoreError::Network { .. }), "got {err:?}");
+    assert_eq!(err.stage(), Some(ProcessingStage::CommitComparison));
+    assert_eq!(
+        err.stage_summary().as_deref(),
+        Some("failed during commit comparison for acme/app")
+    );
+    assert!(github.created_prs.lock().await.is_empty());
+}
+
+/// Changelog entries produced by the version calculator are rendered into the
+/// PR body.
+#[tokio::test]
+async fn test_handle_merged_pr_includes_changelog_entries_in_pr_body() {
+    let github = TestGitHubForLib::new_empty();
+    let config = TestConfigForLib;
+    let version_calc = TestVersionCalcForLib::returning("0.3.0").with_entries(vec![
+        ChangelogEntry {
+            commit_sha: "a".repeat(40),
+            description: "add shiny feature".into(),
+            entry_type: "feat".into(),
+            is_breaking: false,
+            issues: vec![],
+            pr_number: None,
+            scope: None,
+        },
+        ChangelogEntry {
+            commit_sha: "b".repeat(40),
+            description: "fix nasty bug".into(),
+            entry_type: "fix".into(),
+            is_breaking: false,
+            issues: vec![],
+            pr_number: None,
+            scope: None,
+        },
+    ]);
+
+    let processor = ReleaseRegentProcessor::new(github.clone(), config, version_calc);
+
+    let event = ProcessingEvent {
+        event_id: "evt-4".into(),
+        correlation_id: "corr-4".into(),
+        event_type: EventType::PullRequestMerged,
+        repository: RepositoryInfo {
+            owner: "acme".into(),
+            name: "app".into(),
+            default_branch: "main".into(),
+        },
+        payload: serde_json::json!({
+            "pull_request": {
+                "base": { "ref": "main" },
+                "merge_commit_sha": "cccccccccccccccccccccccccccccccccccccccc"
+            }
+        }),
+        received_at: Utc::now(),
+        source: EventSourceKind::Webhook,
+        installation_id: 0,
+    };
+
+    processor.handle_merged_pull_request(&event).await.unwrap();
+
+    let prs = github.created_prs.lock().await;
+    assert_eq!(prs.len(), 1);
+    let body = &prs[0].2;
     assert!(
         body.contains("add shiny feature"),
         "body missing 'add shiny feature': {body}"
@@ -1722,6 +2318,526 @@ async fn test_handle_merged_pr_includes_changelog_entries_in_pr_body() {
     );
 }
 
+fn feat_entry(description: &str) -> ChangelogEntry {
+    ChangelogEntry {
+        commit_sha: "a".repeat(40),
+        description: description.into(),
+        entry_type: "feat".into(),
+        is_breaking: false,
+        issues: vec![],
+        pr_number: None,
+        scope: None,
+    }
+}
+
+fn lightweight_tag(name: &str) -> GitTag {
+    GitTag {
+        name: name.to_string(),
+        target_sha: "a".repeat(40),
+        tag_type: GitTagType::Lightweight,
+        message: None,
+        tagger: None,
+        created_at: None,
+    }
+}
+
+fn monorepo_config() -> config::ReleaseRegentConfig {
+    let mut cfg = config::ReleaseRegentConfig::default();
+    cfg.versioning.strategy = config::VersioningStrategy::Monorepo {
+        packages: vec![
+            traits::version_calculator::PackageSpec {
+                name: "core".into(),
+                path: "packages/core/**".into(),
+                tag_prefix: None,
+                changelog_path: None,
+            },
+            traits::version_calculator::PackageSpec {
+                name: "cli".into(),
+                path: "packages/cli/**".into(),
+                tag_prefix: Some("cli/v".into()),
+                changelog_path: None,
+            },
+        ],
+    };
+    cfg
+}
+
+/// Monorepo packages are versioned from their own release tags: the current
+/// version of `core` comes from its highest `core-v` tag, pre-release and
+/// repository tags are ignored, and the release PR records the tags of the
+/// bumped packages.
+#[tokio::test]
+async fn test_handle_merged_pr_versions_monorepo_packages_from_their_tags() {
+    let github = TestGitHubForLib::new_empty().with_tags(vec![
+        lightweight_tag("v2.0.0"),
+        lightweight_tag("core-v1.1.0"),
+        lightweight_tag("core-v1.2.0"),
+        lightweight_tag("core-v1.3.0-rc.1"),
+    ]);
+    let version_calc = TestVersionCalcForLib::returning("2.1.0")
+        .with_entries(vec![feat_entry("core feature")])
+        .with_changed_files(
+            &"a".repeat(40),
+            &["packages/core/src/lib.rs", "packages/cli/src/main.rs"],
+        );
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigWith::new(monorepo_config()),
+        version_calc.clone(),
+    );
+
+    processor
+        .handle_merged_pull_request(&merged_pr_event_for_acme_app())
+        .await
+        .unwrap();
+
+    let ctx = version_calc.last_context().await.unwrap();
+    assert_eq!(
+        ctx.package_versions,
+        HashMap::from([(
+            "core".to_string(),
+            versioning::VersionCalculator::parse_version("1.2.0").unwrap()
+        )])
+    );
+    let prs = github.created_prs.lock().await;
+    assert_eq!(prs.len(), 1);
+    let package_tags = release_orchestrator::extract_package_tag_sentinels(&prs[0].2);
+    assert_eq!(package_tags, vec!["core-v1.3.0", "cli/v0.2.0"]);
+}
+
+/// Merging a release PR tags and releases every package tag it records
+/// before the repository release.
+#[tokio::test]
+async fn test_merged_release_pr_releases_each_package_tag() {
+    let github = TestGitHubForLib::new_empty();
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigWith::new(monorepo_config()),
+        TestVersionCalcForLib::returning("2.1.0"),
+    );
+    let mut event = make_merged_feature_event("main");
+    event.payload = serde_json::json!({
+        "pull_request": {
+            "head": { "ref": "release/v2.1.0" },
+            "base": { "ref": "main" },
+            "number": 50,
+            "body": "## Changelog\n\n- feat: core feature\n\
+                     <!-- release-regent: previous-version=2.0.0 -->\n\
+                     <!-- release-regent: package-tag=core-v1.3.0 -->\n\
+                     <!-- release-regent: package-tag=cli/v0.2.0 -->",
+            "merge_commit_sha": "e".repeat(40)
+        }
+    });
+
+    let result = processor.handle_merged_pull_request(&event).await.unwrap();
+
+    assert!(
+        matches!(
+            result,
+            release_orchestrator::OrchestratorResult::TaggedRelease
+        ),
+        "expected TaggedRelease, got: {result:?}"
+    );
+    let tags: Vec<String> = github
+        .created_releases
+        .lock()
+        .await
+        .iter()
+        .map(|r| r.tag_name.clone())
+        .collect();
+    assert_eq!(tags, vec!["core-v1.3.0", "cli/v0.2.0", "v2.1.0"]);
+}
+
+/// Without any release tag the merge is the first release, and the changelog
+/// says so.
+#[tokio::test]
+async fn test_handle_merged_pr_first_release_changelog_has_initial_release_header() {
+    let github = TestGitHubForLib::new_empty();
+    let version_calc =
+        TestVersionCalcForLib::returning("0.1.0").with_entries(vec![feat_entry("first feature")]);
+    let processor = ReleaseRegentProcessor::new(github.clone(), TestConfigForLib, version_calc);
+
+    processor
+        .handle_merged_pull_request(&merged_pr_event_for_acme_app())
+        .await
+        .unwrap();
+
+    let prs = github.created_prs.lock().await;
+    let body = &prs[0].2;
+    assert!(
+        body.contains(&format!(
+            "{}\n\n### Features",
+            changelog::INITIAL_RELEASE_HEADER
+        )),
+        "body: {body}"
+    );
+    assert!(body.contains("first feature"), "body: {body}");
+}
+
+/// Once a release exists the changelog has no initial-release header.
+#[tokio::test]
+async fn test_handle_merged_pr_later_release_changelog_has_no_initial_release_header() {
+    let tag = GitTag {
+        name: "v1.0.0".to_string(),
+        target_sha: "a".repeat(40),
+        tag_type: GitTagType::Lightweight,
+        message: None,
+        tagger: None,
+        created_at: None,
+    };
+    let github = TestGitHubForLib::new_empty().with_tags(vec![tag]);
+    let version_calc =
+        TestVersionCalcForLib::returning("1.1.0").with_entries(vec![feat_entry("next feature")]);
+    let processor = ReleaseRegentProcessor::new(github.clone(), TestConfigForLib, version_calc);
+
+    processor
+        .handle_merged_pull_request(&merged_pr_event_for_acme_app())
+        .await
+        .unwrap();
+
+    let prs = github.created_prs.lock().await;
+    let body = &prs[0].2;
+    assert!(
+        !body.contains(changelog::INITIAL_RELEASE_HEADER),
+        "body: {body}"
+    );
+    assert!(body.contains("next feature"), "body: {body}");
+}
+
+/// `(owner/repo, commit descriptions)` of each render request.
+type RenderRequests = Arc<StdMutex<Vec<(String, Vec<String>)>>>;
+
+/// `ChangelogRenderer` stub that records the commits it is asked to render
+/// and returns fixed text, or an error when `fail` is set.
+#[derive(Debug, Default)]
+struct StubChangelogRenderer {
+    rendered: RenderRequests,
+    fail: bool,
+}
+
+impl ChangelogRenderer for StubChangelogRenderer {
+    fn generate(
+        &self,
+        commits: &[versioning::ConventionalCommit],
+        context: &ChangelogContext<'_>,
+    ) -> CoreResult<String> {
+        self.rendered.lock().unwrap().push((
+            format!("{}/{}", context.owner, context.repo),
+            commits.iter().map(|c| c.description.clone()).collect(),
+        ));
+        if self.fail {
+            return Err(CoreError::changelog_generation("stub renderer failed"));
+        }
+        Ok("STUB CHANGELOG".to_string())
+    }
+}
+
+fn merged_pr_event_for_acme_app() -> ProcessingEvent {
+    ProcessingEvent {
+        event_id: "evt-stub".into(),
+        correlation_id: "corr-stub".into(),
+        event_type: EventType::PullRequestMerged,
+        repository: RepositoryInfo {
+            owner: "acme".into(),
+            name: "app".into(),
+            default_branch: "main".into(),
+        },
+        payload: serde_json::json!({
+            "pull_request": {
+                "base": { "ref": "main" },
+                "merge_commit_sha": "cccccccccccccccccccccccccccccccccccccccc"
+            }
+        }),
+        received_at: Utc::now(),
+        source: EventSourceKind::Webhook,
+        installation_id: 0,
+    }
+}
+
+#[tokio::test]
+async fn test_handle_merged_pr_renders_changelog_with_injected_renderer() {
+    let github = TestGitHubForLib::new_empty();
+    let version_calc =
+        TestVersionCalcForLib::returning("0.3.0").with_entries(vec![ChangelogEntry {
+            commit_sha: "a".repeat(40),
+            description: "add shiny feature".into(),
+            entry_type: "feat".into(),
+            is_breaking: false,
+            issues: vec![],
+            pr_number: None,
+            scope: None,
+        }]);
+    let renderer = StubChangelogRenderer::default();
+    let rendered = Arc::clone(&renderer.rendered);
+
+    let processor = ReleaseRegentProcessor::new(github.clone(), TestConfigForLib, version_calc)
+        .with_changelog_renderer(Box::new(renderer));
+
+    processor
+        .handle_merged_pull_request(&merged_pr_event_for_acme_app())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *rendered.lock().unwrap(),
+        vec![(
+            "acme/app".to_string(),
+            vec!["add shiny feature".to_string()]
+        )]
+    );
+    let prs = github.created_prs.lock().await;
+    assert_eq!(prs.len(), 1);
+    let body = &prs[0].2;
+    assert!(body.contains("STUB CHANGELOG"), "body: {body}");
+    assert!(
+        !body.contains("### Features"),
+        "built-in renderer should not be used: {body}"
+    );
+}
+
+#[tokio::test]
+async fn test_handle_merged_pr_propagates_changelog_renderer_error() {
+    let github = TestGitHubForLib::new_empty();
+    let renderer = StubChangelogRenderer {
+        fail: true,
+        ..StubChangelogRenderer::default()
+    };
+
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigForLib,
+        TestVersionCalcForLib::returning("0.3.0"),
+    )
+    .with_changelog_renderer(Box::new(renderer));
+
+    let result = processor
+        .handle_merged_pull_request(&merged_pr_event_for_acme_app())
+        .await;
+
+    assert!(
+        matches!(result, Err(CoreError::ChangelogGeneration { .. })),
+        "expected ChangelogGeneration error, got {result:?}"
+    );
+    assert_eq!(
+        result.unwrap_err().stage(),
+        Some(ProcessingStage::ChangelogGeneration)
+    );
+    assert!(github.created_prs.lock().await.is_empty());
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// Dry-run report tests
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+/// A dry-run merge of a feature PR reports the calculated version and the
+/// release PR it would have opened, without opening it.
+#[tokio::test]
+async fn test_dry_run_report_captures_version_and_planned_release_pr() {
+    let github = TestGitHubForLib::new_empty();
+    let dry_run = dry_run::DryRunGitHubOperations::new(github.clone());
+    let log = dry_run.log();
+    let processor = ReleaseRegentProcessor::new(
+        dry_run,
+        TestConfigForLib,
+        TestVersionCalcForLib::returning("0.3.0"),
+    )
+    .with_dry_run_log(log.clone());
+    let event = merged_pr_event_for_acme_app();
+
+    processor.handle_merged_pull_request(&event).await.unwrap();
+
+    let report = log.report(&event);
+    assert_eq!(report.event_type, "pull_request_merged");
+    assert_eq!(report.repository, "acme/app");
+    let calculation = report
+        .calculation
+        .as_ref()
+        .expect("a version should be calculated");
+    assert_eq!(calculation.next_version, "0.3.0");
+    assert!(
+        report.actions.iter().any(|a| matches!(
+            a,
+            dry_run::DryRunAction::CreatePullRequest { title, .. } if title.contains("0.3.0")
+        )),
+        "actions: {:?}",
+        report.actions
+    );
+    assert!(github.created_prs.lock().await.is_empty());
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["calculation"]["next_version"], "0.3.0");
+    assert!(json["actions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|a| a["kind"] == "create_pull_request"));
+}
+
+/// A dry-run merge of a release PR reports the release it would have
+/// published.
+#[tokio::test]
+async fn test_dry_run_report_captures_planned_release_creation() {
+    let github = TestGitHubForLib::new_empty();
+    let dry_run = dry_run::DryRunGitHubOperations::new(github.clone());
+    let log = dry_run.log();
+    let processor = ReleaseRegentProcessor::new(
+        dry_run,
+        TestConfigForLib,
+        TestVersionCalcForLib::returning("1.1.0"),
+    )
+    .with_dry_run_log(log.clone());
+    let event = ProcessingEvent {
+        event_id: "evt-dry-run".into(),
+        correlation_id: "corr-dry-run".into(),
+        event_type: EventType::PullRequestMerged,
+        repository: RepositoryInfo {
+            owner: "acme".into(),
+            name: "app".into(),
+            default_branch: "main".into(),
+        },
+        payload: serde_json::json!({
+            "pull_request": {
+                "head": { "ref": "release/v1.0.0" },
+                "base": { "ref": "main" },
+                "number": 100,
+                "merge_commit_sha": "d".repeat(40)
+            }
+        }),
+        received_at: Utc::now(),
+        source: EventSourceKind::Webhook,
+        installation_id: 0,
+    };
+
+    processor.handle_merged_pull_request(&event).await.unwrap();
+
+    let report = log.report(&event);
+    assert!(report.calculation.is_none());
+    assert!(
+        report.actions.iter().any(|a| matches!(
+            a,
+            dry_run::DryRunAction::CreateRelease { tag_name, .. } if tag_name == "v1.0.0"
+        )),
+        "actions: {:?}",
+        report.actions
+    );
+    assert!(github.created_releases.lock().await.is_empty());
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// Per-package changelog tests
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+fn package_commit(sha: &str, message: &str) -> (CommitAnalysis, versioning::ConventionalCommit) {
+    let commit = versioning::VersionCalculator::parse_conventional_commits(&[(
+        sha.to_string(),
+        message.to_string(),
+    )])
+    .remove(0);
+    let analysis = CommitAnalysis {
+        author: String::new(),
+        commit_type: Some(commit.commit_type.clone()),
+        date: Utc::now(),
+        is_breaking: commit.breaking_change,
+        message: message.to_string(),
+        metadata: HashMap::new(),
+        scope: commit.scope.clone(),
+        sha: sha.to_string(),
+        version_bump: VersionBump::Minor,
+    };
+    (analysis, commit)
+}
+
+/// Each package's changelog is rendered from the commits that touch it: a
+/// commit under `packages/a` is only in package A's changelog, while a commit
+/// touching both packages is in both.
+#[test]
+fn test_render_package_changelogs_uses_the_package_partition() {
+    use traits::version_calculator::PackageSpec;
+
+    let spec = |name: &str| PackageSpec {
+        name: name.to_string(),
+        path: format!("packages/{name}/**"),
+        tag_prefix: Some(format!("{name}-v")),
+        changelog_path: Some(format!("packages/{name}/CHANGELOG.md")),
+    };
+    let packages = vec![spec("a"), spec("b")];
+    let (only_a, only_a_commit) = package_commit(&"a".repeat(40), "feat: only touches a");
+    let (both, both_commit) = package_commit(&"c".repeat(40), "feat: touches a and b");
+    let changed_files = HashMap::from([
+        (
+            only_a.sha.clone(),
+            vec!["packages/a/src/lib.rs".to_string()],
+        ),
+        (
+            both.sha.clone(),
+            vec![
+                "packages/a/README.md".to_string(),
+                "packages/b/src/main.rs".to_string(),
+            ],
+        ),
+    ]);
+
+    let mut calc_result = TestVersionCalcForLib::returning("1.0.0").result_for(None);
+    calc_result.packages = monorepo::calculate_package_versions(
+        &packages,
+        &[only_a, both],
+        &changed_files,
+        &HashMap::new(),
+    )
+    .unwrap();
+    let mut repo_config = config::ReleaseRegentConfig::default();
+    repo_config.versioning.strategy = config::VersioningStrategy::Monorepo { packages };
+
+    let processor = ReleaseRegentProcessor::new(
+        TestGitHubForLib::new_empty(),
+        TestConfigForLib,
+        TestVersionCalcForLib::returning("1.0.0"),
+    );
+    let rendered = processor
+        .render_package_changelogs(
+            "acme",
+            "app",
+            &repo_config,
+            &calc_result,
+            &[only_a_commit, both_commit],
+        )
+        .unwrap();
+
+    assert_eq!(rendered.len(), 2);
+    let a = &rendered[0];
+    assert_eq!(a.path, "packages/a/CHANGELOG.md");
+    assert_eq!(a.version.to_string(), "0.2.0");
+    assert!(a.changelog.contains("only touches a"), "{}", a.changelog);
+    assert!(a.changelog.contains("touches a and b"), "{}", a.changelog);
+    let b = &rendered[1];
+    assert_eq!(b.path, "packages/b/CHANGELOG.md");
+    assert!(!b.changelog.contains("only touches a"), "{}", b.changelog);
+    assert!(b.changelog.contains("touches a and b"), "{}", b.changelog);
+}
+
+/// Without the monorepo strategy no package changelogs are rendered.
+#[test]
+fn test_render_package_changelogs_is_empty_for_single_package_repos() {
+    let processor = ReleaseRegentProcessor::new(
+        TestGitHubForLib::new_empty(),
+        TestConfigForLib,
+        TestVersionCalcForLib::returning("1.0.0"),
+    );
+    let (_, commit) = package_commit(&"a".repeat(40), "feat: a change");
+
+    let rendered = processor
+        .render_package_changelogs(
+            "acme",
+            "app",
+            &config::ReleaseRegentConfig::default(),
+            &TestVersionCalcForLib::returning("1.0.0").result_for(None),
+            &[commit],
+        )
+        .unwrap();
+
+    assert!(rendered.is_empty());
+}
+
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
 // Bump-override floor tests (task 9.20)
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
@@ -1885,13 +3001,108 @@ async fn test_handle_merged_feature_pr_without_override_label_uses_calculated_ve
         other => panic!("expected Created, got {other:?}"),
     }
 
-    // No audit comment should have been posted (no floor was applied).
-    let comments = github.issue_comments.lock().await;
+    // No audit comment should have been posted (no floor was applied).
+    let comments = github.issue_comments.lock().await;
+    assert!(
+        comments
+            .iter()
+            .all(|(_, body)| !body.contains("Version floor applied")),
+        "unexpected audit comment when no floor was applied"
+    );
+}
+
+/// Merge a feature PR titled `title` whose commits only call for a patch bump
+/// over v1.0.0, and return the resulting release branch name.
+async fn merge_fix_only_pr_with_title(use_pr_title: bool, title: &str) -> String {
+    let tag = GitTag {
+        name: "v1.0.0".to_string(),
+        target_sha: "a".repeat(40),
+        tag_type: GitTagType::Lightweight,
+        message: None,
+        tagger: None,
+        created_at: None,
+    };
+    let mut cfg = config::ReleaseRegentConfig::default();
+    cfg.versioning.use_pr_title = use_pr_title;
+
+    let github = TestGitHubForLib::new_empty().with_tags(vec![tag]);
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigWith::new(cfg),
+        TestVersionCalcForLib::returning("1.0.1"),
+    );
+
+    let event = ProcessingEvent {
+        event_id: "evt-title-1".into(),
+        correlation_id: "corr-title-1".into(),
+        event_type: EventType::PullRequestMerged,
+        repository: RepositoryInfo {
+            owner: "acme".into(),
+            name: "app".into(),
+            default_branch: "main".into(),
+        },
+        payload: serde_json::json!({
+            "pull_request": {
+                "head": { "ref": "feat/new-config" },
+                "base": { "ref": "main" },
+                "number": 9,
+                "title": title,
+                "merge_commit_sha": "d".repeat(40)
+            }
+        }),
+        received_at: Utc::now(),
+        source: EventSourceKind::Webhook,
+        installation_id: 0,
+    };
+
+    let result = processor.handle_merged_pull_request(&event).await.unwrap();
+
+    let comments = github.issue_comments.lock().await;
+    assert!(
+        comments
+            .iter()
+            .all(|(_, body)| !body.contains("Version floor applied")),
+        "the PR title must not be reported as an override label"
+    );
+    match result {
+        release_orchestrator::OrchestratorResult::Created { branch_name, .. } => branch_name,
+        other => panic!("expected Created, got {other:?}"),
+    }
+}
+
+/// With `versioning.use_pr_title`, a breaking PR title raises fix-only
+/// commits to a major bump.
+#[tokio::test]
+async fn test_handle_merged_feature_pr_with_breaking_title_bumps_major() {
+    let branch_name = merge_fix_only_pr_with_title(true, "feat!: replace the config format").await;
+
+    assert!(
+        branch_name.contains("2.0.0"),
+        "branch should reference 2.0.0 (title bump), got: {branch_name}"
+    );
+}
+
+/// A PR title calling for a smaller bump than the commits leaves the
+/// calculated version alone.
+#[tokio::test]
+async fn test_handle_merged_feature_pr_with_smaller_title_bump_keeps_calculated_version() {
+    let branch_name = merge_fix_only_pr_with_title(true, "docs: explain the config format").await;
+
+    assert!(
+        branch_name.contains("1.0.1"),
+        "branch should reference 1.0.1 (calculated), got: {branch_name}"
+    );
+}
+
+/// Without `versioning.use_pr_title`, the PR title does not affect the
+/// version.
+#[tokio::test]
+async fn test_handle_merged_feature_pr_ignores_title_by_default() {
+    let branch_name = merge_fix_only_pr_with_title(false, "feat!: replace the config format").await;
+
     assert!(
-        comments
-            .iter()
-            .all(|(_, body)| !body.contains("Version floor applied")),
-        "unexpected audit comment when no floor was applied"
+        branch_name.contains("1.0.1"),
+        "branch should reference 1.0.1 (title ignored), got: {branch_name}"
     );
 }
 
@@ -1984,6 +3195,66 @@ async fn test_handle_merged_release_pr_clears_stale_override_labels_from_open_pr
     );
 }
 
+/// With `releases.draft` enabled the release is created as a draft, and the
+/// post-publish cleanup of stale override labels is skipped because nothing
+/// has been published yet.
+#[tokio::test]
+async fn test_handle_merged_release_pr_with_draft_config_creates_draft_and_keeps_labels() {
+    let stale_pr = make_pr(99, "feat/stale-feature", "feat: stale work");
+    let override_major = Label {
+        id: 10,
+        name: "rr:override-major".to_string(),
+        color: "b60205".to_string(),
+        description: None,
+    };
+    let github = TestGitHubForLib::new_empty()
+        .with_existing_prs(vec![stale_pr])
+        .with_pr_labels(99, vec![override_major]);
+    let mut cfg = config::ReleaseRegentConfig::default();
+    cfg.releases.draft = true;
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigWith::new(cfg),
+        TestVersionCalcForLib::returning("1.1.0"),
+    );
+
+    let event = ProcessingEvent {
+        event_id: "evt-draft".into(),
+        correlation_id: "corr-draft".into(),
+        event_type: EventType::PullRequestMerged,
+        repository: RepositoryInfo {
+            owner: "acme".into(),
+            name: "app".into(),
+            default_branch: "main".into(),
+        },
+        payload: serde_json::json!({
+            "pull_request": {
+                "head": { "ref": "release/v1.0.0" },
+                "base": { "ref": "main" },
+                "number": 100,
+                "merge_commit_sha": "d".repeat(40)
+            }
+        }),
+        received_at: Utc::now(),
+        source: EventSourceKind::Webhook,
+        installation_id: 0,
+    };
+
+    let result = processor.handle_merged_pull_request(&event).await.unwrap();
+
+    assert!(matches!(
+        result,
+        release_orchestrator::OrchestratorResult::TaggedRelease
+    ));
+    let releases = github.created_releases.lock().await;
+    assert_eq!(releases.len(), 1);
+    assert!(releases[0].draft, "releases.draft must be passed through");
+    assert!(
+        github.removed_labels.lock().await.is_empty(),
+        "override labels must not be cleared for a draft release"
+    );
+}
+
 /// After a release PR merges the processor must invoke the release automator
 /// (creating a tag + GitHub release) instead of the release orchestrator
 /// (which would create a new release branch and PR).
@@ -2046,6 +3317,56 @@ async fn test_merged_release_pr_creates_tag_not_new_release_branch() {
     );
 }
 
+/// A merged PR whose head branch was rendered from `release_pr.branch_template`
+/// is recognised as a release PR.
+#[tokio::test]
+async fn test_merged_release_pr_on_templated_branch_creates_tag() {
+    let github = TestGitHubForLib::new_empty();
+    let mut repo_config = config::ReleaseRegentConfig::default();
+    repo_config.release_pr.branch_template =
+        Some("release-regent/{target_branch}/{version}".to_string());
+    let version_calc = TestVersionCalcForLib::returning("1.0.0");
+
+    let processor =
+        ReleaseRegentProcessor::new(github.clone(), TestConfigWith(repo_config), version_calc);
+
+    let event = ProcessingEvent {
+        event_id: "evt-templated-release-pr".into(),
+        correlation_id: "corr-templated-release-pr".into(),
+        event_type: EventType::PullRequestMerged,
+        repository: RepositoryInfo {
+            owner: "acme".into(),
+            name: "app".into(),
+            default_branch: "main".into(),
+        },
+        payload: serde_json::json!({
+            "pull_request": {
+                "head": { "ref": "release-regent/main/1.3.0" },
+                "base": { "ref": "main" },
+                "number": 51,
+                "merge_commit_sha": "e".repeat(40)
+            }
+        }),
+        received_at: Utc::now(),
+        source: EventSourceKind::Webhook,
+        installation_id: 0,
+    };
+
+    let result = processor.handle_merged_pull_request(&event).await.unwrap();
+
+    assert!(
+        matches!(
+            result,
+            release_orchestrator::OrchestratorResult::TaggedRelease
+        ),
+        "expected TaggedRelease, got: {result:?}"
+    );
+    let releases = github.created_releases.lock().await;
+    assert_eq!(releases.len(), 1);
+    assert_eq!(releases[0].tag_name, "v1.3.0");
+    assert!(github.created_prs.lock().await.is_empty());
+}
+
 /// Open PRs that carry no override labels must not receive a cleanup comment
 /// after a release.
 ///
@@ -2689,13 +4010,12 @@ async fn test_handle_merged_non_bumping_feature_pr_after_release_does_not_recrea
         .handle_merged_pull_request(&event)
         .await
         .expect("expected Ok for non-bumping PR after release");
-    // The guard is version-equality-based: effective_version == current_version
-    // triggers NoBumpNeeded regardless of the VersionBump variant.  The
-    // VersionBump::None field on the version_calc is incidental.
+    // VersionBump::None without a bump floor short-circuits to NoBumpNeeded
+    // before the orchestrator is called.
     assert!(
         matches!(
             result,
-            release_orchestrator::OrchestratorResult::NoBumpNeeded
+            release_orchestrator::OrchestratorResult::NoBumpNeeded { .. }
         ),
         "expected NoBumpNeeded but got: {result:?}"
     );
@@ -2783,6 +4103,177 @@ async fn test_handle_merged_non_bumping_pr_with_patch_floor_creates_next_patch_r
     );
 }
 
+fn released_tag(name: &str) -> GitTag {
+    GitTag {
+        name: name.to_string(),
+        target_sha: "a".repeat(40),
+        tag_type: GitTagType::Lightweight,
+        message: None,
+        tagger: None,
+        created_at: None,
+    }
+}
+
+fn merged_chore_pr_event() -> ProcessingEvent {
+    ProcessingEvent {
+        event_id: "evt-chore".into(),
+        correlation_id: "corr-chore".into(),
+        event_type: EventType::PullRequestMerged,
+        repository: RepositoryInfo {
+            owner: "acme".into(),
+            name: "app".into(),
+            default_branch: "main".into(),
+        },
+        payload: serde_json::json!({
+            "pull_request": {
+                "head": { "ref": "chore/tidy-up", "sha": "b".repeat(40) },
+                "base": { "ref": "main" },
+                "number": 42,
+                "title": "chore: tidy up",
+                "merge_commit_sha": "c".repeat(40)
+            }
+        }),
+        received_at: Utc::now(),
+        source: EventSourceKind::Webhook,
+        installation_id: 0,
+    }
+}
+
+/// A merge of only `chore:`/`docs:` commits returns `NoBumpNeeded` with the
+/// reason and makes no GitHub mutations at all.
+#[tokio::test]
+async fn test_handle_merged_chore_only_pr_short_circuits_without_mutations() {
+    let mut cfg = config::ReleaseRegentConfig::default();
+    cfg.release_pr.labels = vec!["release".to_string()];
+    let github = TestGitHubForLib::new_empty().with_tags(vec![released_tag("v0.3.0")]);
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigWith::new(cfg),
+        TestVersionCalcForLib::returning("0.3.0").with_version_bump(VersionBump::None),
+    );
+
+    let result = processor
+        .handle_merged_pull_request(&merged_chore_pr_event())
+        .await
+        .unwrap();
+
+    match result {
+        release_orchestrator::OrchestratorResult::NoBumpNeeded { reason } => {
+            assert_eq!(reason, BumpReason::NO_BUMPING_COMMITS);
+        }
+        other => panic!("expected NoBumpNeeded, got {other:?}"),
+    }
+    assert!(github.create_branch_calls.lock().await.is_empty());
+    assert!(github.created_prs.lock().await.is_empty());
+    assert!(github.set_labels_calls.lock().await.is_empty());
+    assert!(github.issue_comments.lock().await.is_empty());
+    assert!(github.removed_labels.lock().await.is_empty());
+}
+
+/// The reason reported by the calculator is passed through.
+#[tokio::test]
+async fn test_no_bump_needed_reports_calculator_reason() {
+    let github = TestGitHubForLib::new_empty().with_tags(vec![released_tag("v0.3.0")]);
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigForLib,
+        TestVersionCalcForLib::returning("0.3.0")
+            .with_version_bump(VersionBump::None)
+            .with_bump_reason("only docs commits"),
+    );
+
+    let result = processor
+        .handle_merged_pull_request(&merged_chore_pr_event())
+        .await
+        .unwrap();
+
+    assert!(
+        matches!(
+            &result,
+            release_orchestrator::OrchestratorResult::NoBumpNeeded { reason }
+                if reason == "only docs commits"
+        ),
+        "{result:?}"
+    );
+}
+
+/// The first release is prepared even without version-bumping commits.
+#[tokio::test]
+async fn test_handle_merged_chore_only_pr_without_release_creates_first_release() {
+    let github = TestGitHubForLib::new_empty();
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigForLib,
+        TestVersionCalcForLib::returning("0.1.0").with_version_bump(VersionBump::None),
+    );
+
+    let result = processor
+        .handle_merged_pull_request(&merged_chore_pr_event())
+        .await
+        .unwrap();
+
+    assert!(
+        matches!(
+            result,
+            release_orchestrator::OrchestratorResult::Created { .. }
+        ),
+        "expected Created, got {result:?}"
+    );
+}
+
+/// With `versioning.release_on_no_bump` a chore-only merge releases the next
+/// patch version.
+#[tokio::test]
+async fn test_release_on_no_bump_releases_next_patch_version() {
+    let mut cfg = config::ReleaseRegentConfig::default();
+    cfg.versioning.release_on_no_bump = true;
+    let github = TestGitHubForLib::new_empty().with_tags(vec![released_tag("v0.3.0")]);
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigWith::new(cfg),
+        TestVersionCalcForLib::returning("0.3.0").with_version_bump(VersionBump::None),
+    );
+
+    let result = processor
+        .handle_merged_pull_request(&merged_chore_pr_event())
+        .await
+        .unwrap();
+
+    let release_orchestrator::OrchestratorResult::Created { branch_name, .. } = result else {
+        panic!("expected Created, got {result:?}");
+    };
+    assert_eq!(branch_name, "release/v0.3.1");
+}
+
+/// A dry run of a chore-only merge reports why no release is needed and
+/// intercepts no mutations.
+#[tokio::test]
+async fn test_dry_run_report_explains_no_release() {
+    let github = TestGitHubForLib::new_empty().with_tags(vec![released_tag("v0.3.0")]);
+    let dry_run = dry_run::DryRunGitHubOperations::new(github.clone());
+    let log = dry_run.log();
+    let processor = ReleaseRegentProcessor::new(
+        dry_run,
+        TestConfigForLib,
+        TestVersionCalcForLib::returning("0.3.0").with_version_bump(VersionBump::None),
+    )
+    .with_dry_run_log(log.clone());
+    let event = merged_chore_pr_event();
+
+    processor.handle_merged_pull_request(&event).await.unwrap();
+
+    let report = log.report(&event);
+    assert_eq!(
+        report.no_release_reason.as_deref(),
+        Some(BumpReason::NO_BUMPING_COMMITS)
+    );
+    assert_eq!(report.calculation.as_ref().unwrap().bump, "none");
+    assert!(report.actions.is_empty(), "{:?}", report.actions);
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["no_release_reason"], BumpReason::NO_BUMPING_COMMITS);
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Phase F — PR status comment end-to-end scenarios
 // ─────────────────────────────────────────────────────────────────────────────
@@ -3474,3 +4965,261 @@ async fn test_pr_refresh_scan_uses_version_prefix_for_base_ref_when_prefix_is_em
         ctx.base_ref
     );
 }
+
+// ── Repository allow-list ───────────────────────────────────────────────────
+
+/// A `PullRequestMerged` event for `acme/app` with a valid merge payload.
+fn make_merged_event_for_acme_app(id: &str) -> ProcessingEvent {
+    ProcessingEvent {
+        payload: serde_json::json!({
+            "pull_request": {
+                "base": { "ref": "main" },
+                "merge_commit_sha": "deadbeefdeadbeefdeadbeefdeadbeefdeadbeef"
+            }
+        }),
+        ..make_test_event(id, EventType::PullRequestMerged)
+    }
+}
+
+/// Run the event loop over `events` with `processor` until they are all
+/// acknowledged.
+async fn run_loop_until_acked(
+    processor: ReleaseRegentProcessor<TestGitHubForLib, TestConfigForLib, TestVersionCalcForLib>,
+    events: Vec<ProcessingEvent>,
+) -> Vec<String> {
+    let token = CancellationToken::new();
+    let expected = events.len();
+    let source = TestEventSource::new(events);
+    let source_for_loop = source.clone();
+    let loop_token = token.clone();
+
+    let loop_handle =
+        tokio::spawn(async move { run_event_loop(&source_for_loop, &processor, loop_token).await });
+
+    let acked = wait_for_acks(&source, expected, &token).await;
+    loop_handle.await.unwrap().unwrap();
+    acked
+}
+
+/// An event for a repository outside the allow-list is acknowledged without
+/// any GitHub operations.
+#[tokio::test]
+async fn test_run_event_loop_ignores_event_for_repository_not_on_allow_list() {
+    let github = TestGitHubForLib::new_empty();
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigForLib,
+        TestVersionCalcForLib::returning("0.2.0"),
+    )
+    .with_allowed_repos(RepositoryAllowList::new(["other/*", "acme/lib"]));
+
+    let acked =
+        run_loop_until_acked(processor, vec![make_merged_event_for_acme_app("evt-1")]).await;
+
+    assert_eq!(acked, vec!["evt-1"]);
+    assert!(github.create_branch_calls.lock().await.is_empty());
+    assert!(github.created_prs.lock().await.is_empty());
+    assert!(github.issue_comments.lock().await.is_empty());
+}
+
+/// An `owner/*` pattern lets events for that owner's repositories through.
+#[tokio::test]
+async fn test_run_event_loop_processes_event_for_repository_matching_owner_wildcard() {
+    let github = TestGitHubForLib::new_empty();
+    let processor = ReleaseRegentProcessor::new(
+        github.clone(),
+        TestConfigForLib,
+        TestVersionCalcForLib::returning("0.2.0"),
+    )
+    .with_allowed_repos(RepositoryAllowList::new(["acme/*"]));
+
+    let acked =
+        run_loop_until_acked(processor, vec![make_merged_event_for_acme_app("evt-1")]).await;
+
+    assert_eq!(acked, vec!["evt-1"]);
+    assert_eq!(github.created_prs.lock().await.len(), 1);
+}
+
+/// Processors allow every repository unless an allow-list is configured.
+#[test]
+fn test_processor_allows_all_repositories_by_default() {
+    let processor = ReleaseRegentProcessor::new(
+        TestGitHubForLib::new_empty(),
+        TestConfigForLib,
+        TestVersionCalcForLib::returning("0.2.0"),
+    );
+
+    assert_eq!(processor.allowed_repos(), &RepositoryAllowList::allow_all());
+    assert!(MergedPullRequestHandler::is_repository_allowed(
+        &processor,
+        &test_repo()
+    ));
+}
+
+// ── Default branch resolution ───────────────────────────────────────────────
+
+/// A merged-PR event without `base.ref` whose repository has
+/// `default_branch`.
+fn make_merged_event_with_default_branch(default_branch: &str) -> ProcessingEvent {
+    ProcessingEvent {
+        repository: RepositoryInfo {
+            default_branch: default_branch.to_string(),
+            ..test_repo()
+        },
+        payload: serde_json::json!({
+            "pull_request": {
+                "merge_commit_sha": "deadbeefdeadbeefdeadbeefdeadbeefdeadbeef"
+            }
+        }),
+        ..make_test_event("evt-branch", EventType::PullRequestMerged)
+    }
+}
+
+/// When the payload omits the default branch, the branch reported by the
+/// GitHub API is used as the comparison base.
+#[tokio::test]
+async fn test_handle_merged_pr_looks_up_default_branch_missing_from_payload() {
+    let github = TestGitHubForLib::new_empty().with_default_branch("trunk");
+    let version_calc = TestVersionCalcForLib::returning("0.2.0");
+    let processor =
+        ReleaseRegentProcessor::new(github.clone(), TestConfigForLib, version_calc.clone());
+
+    processor
+        .handle_merged_pull_request(&make_merged_event_with_default_branch(""))
+        .await
+        .unwrap();
+
+    let ctx = version_calc.last_context().await.unwrap();
+    assert_eq!(ctx.target_branch, "trunk");
+    assert_eq!(*github.repository_info_calls.lock().await, 1);
+}
+
+/// The default branch carried by the event is trusted unless a lookup is
+/// requested.
+#[tokio::test]
+async fn test_handle_merged_pr_uses_event_default_branch_without_lookup() {
+    let github = TestGitHubForLib::new_empty().with_default_branch("trunk");
+    let version_calc = TestVersionCalcForLib::returning("0.2.0");
+    let processor =
+        ReleaseRegentProcessor::new(github.clone(), TestConfigForLib, version_calc.clone());
+
+    processor
+        .handle_merged_pull_request(&make_merged_event_with_default_branch("main"))
+        .await
+        .unwrap();
+
+    let ctx = version_calc.last_context().await.unwrap();
+    assert_eq!(ctx.target_branch, "main");
+    assert_eq!(*github.repository_info_calls.lock().await, 0);
+}
+
+/// With the lookup enabled, a stale default branch in the event is replaced
+/// by the API's value, which is looked up once for the whole event.
+#[tokio::test]
+async fn test_merged_pr_event_with_lookup_enabled_replaces_stale_default_branch() {
+    let github = TestGitHubForLib::new_empty().with_default_branch("develop");
+    let version_calc = TestVersionCalcForLib::returning("0.2.0");
+    let processor =
+        ReleaseRegentProcessor::new(github.clone(), TestConfigForLib, version_calc.clone())
+            .with_default_branch_lookup(true);
+
+    MergedPullRequestHandler::handle_merged_pull_request(
+        &processor,
+        &make_merged_event_with_default_branch("master"),
+    )
+    .await
+    .unwrap();
+
+    let ctx = version_calc.last_context().await.unwrap();
+    assert_eq!(ctx.target_branch, "develop");
+    assert_eq!(*github.repository_info_calls.lock().await, 1);
+}
+
+// ── Changelog author lookup ─────────────────────────────────────────────────
+
+fn authored_commit(sha: &str, name: &str, email: &str) -> versioning::ConventionalCommit {
+    versioning::ConventionalCommit {
+        commit_type: "feat".to_string(),
+        scope: None,
+        description: format!("change {sha}"),
+        breaking_change: false,
+        message: format!("feat: change {sha}"),
+        body: None,
+        sha: sha.to_string(),
+        author: Some(traits::git_operations::GitUser {
+            name: name.to_string(),
+            email: email.to_string(),
+            login: None,
+        }),
+    }
+}
+
+fn lookup_generator(author_map: &[(&str, &str)]) -> changelog::ChangelogGenerator {
+    changelog::ChangelogGenerator::with_config(changelog::ChangelogConfig {
+        author_map: author_map
+            .iter()
+            .map(|(email, login)| ((*email).to_string(), (*login).to_string()))
+            .collect(),
+        resolve_author_logins: true,
+        ..Default::default()
+    })
+}
+
+/// Logins GitHub associates with a commit are used for authors missing from
+/// `author_map`, and each email is looked up only once per generation.
+#[tokio::test]
+async fn test_resolve_author_logins_looks_up_each_email_once() {
+    let github = TestGitHubForLib::new_empty().with_commit_login("aaa111", "octocat");
+    let generator = lookup_generator(&[]);
+    let mut commits = vec![
+        authored_commit("aaa111", "Mona Lisa", "mona@example.com"),
+        authored_commit("bbb222", "Mona Lisa", "MONA@example.com"),
+    ];
+
+    generator
+        .resolve_author_logins(&github, "owner", "repo", &mut commits)
+        .await;
+
+    assert_eq!(*github.get_commit_calls.lock().await, vec!["aaa111"]);
+    let changelog = generator.generate_changelog(&commits).unwrap();
+    assert!(changelog.contains("- change aaa111 [aaa111] by @octocat"));
+    assert!(changelog.contains("- change bbb222 [bbb222] by @octocat"));
+}
+
+/// Mapped authors are not looked up, and authors GitHub cannot associate
+/// with an account fall back to their display name.
+#[tokio::test]
+async fn test_resolve_author_logins_skips_mapped_and_keeps_unresolved_names() {
+    let github = TestGitHubForLib::new_empty();
+    let generator = lookup_generator(&[("mapped@example.com", "mapped-user")]);
+    let mut commits = vec![
+        authored_commit("aaa111", "Mapped", "mapped@example.com"),
+        authored_commit("bbb222", "Jane Doe", "jane@example.com"),
+    ];
+
+    generator
+        .resolve_author_logins(&github, "owner", "repo", &mut commits)
+        .await;
+
+    assert_eq!(*github.get_commit_calls.lock().await, vec!["bbb222"]);
+    let changelog = generator.generate_changelog(&commits).unwrap();
+    assert!(changelog.contains("by @mapped-user"));
+    assert!(changelog.contains("by Jane Doe"));
+    assert!(!changelog.contains("@Jane"));
+}
+
+/// No lookups are made unless `resolve_author_logins` is enabled.
+#[tokio::test]
+async fn test_resolve_author_logins_disabled_by_default() {
+    let github = TestGitHubForLib::new_empty().with_commit_login("aaa111", "octocat");
+    let generator = changelog::ChangelogGenerator::new();
+    let mut commits = vec![authored_commit("aaa111", "Mona Lisa", "mona@example.com")];
+
+    generator
+        .resolve_author_logins(&github, "owner", "repo", &mut commits)
+        .await;
+
+    assert!(github.get_commit_calls.lock().await.is_empty());
+    let changelog = generator.generate_changelog(&commits).unwrap();
+    assert!(changelog.contains("by Mona Lisa"));
+}
@@ -0,0 +1,282 @@
+//! Per-package version calculation for monorepos.
+//!
+//! With the [`VersioningStrategy::Monorepo`] strategy every package is
+//! versioned independently from the commits that change files under its path.
+//! A commit that touches several packages bumps each of them; a commit that
+//! touches none of them is left out of every package.
+//!
+//! [`VersioningStrategy::Monorepo`]: crate::traits::version_calculator::VersioningStrategy::Monorepo
+
+use crate::{
+    traits::{
+        git_operations::{GitTag, ListTagsOptions},
+        version_calculator::{
+            ChangelogEntry, CommitAnalysis, PackageSpec, PackageVersionResult, VersionBump,
+        },
+        GitOperations,
+    },
+    versioning::{apply_semver_bump, SemanticVersion, VersionCalculator},
+    CoreError, CoreResult,
+};
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap};
+
+#[cfg(test)]
+#[path = "monorepo_tests.rs"]
+mod tests;
+
+/// A compiled package path glob.
+///
+/// `*` matches within a single path segment, `**` matches across segments and
+/// `?` matches one character other than `/`. A pattern without wildcards
+/// matches that path and everything below it, so `crates/core` is the same as
+/// `crates/core/**`.
+#[derive(Debug, Clone)]
+pub struct PathFilter {
+    regex: Regex,
+}
+
+impl PathFilter {
+    /// Compile a path glob.
+    ///
+    /// # Errors
+    /// - `CoreError::InvalidInput` — the pattern is empty
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large; established pattern
+    pub fn new(pattern: &str) -> CoreResult<Self> {
+        let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+        if pattern.is_empty() {
+            return Err(CoreError::invalid_input(
+                "path",
+                "package path pattern cannot be empty",
+            ));
+        }
+
+        let mut expression = String::from("^");
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '*' if chars.peek() == Some(&'*') => {
+                    chars.next();
+                    if chars.peek() == Some(&'/') {
+                        chars.next();
+                        expression.push_str("(?:.*/)?");
+                    } else {
+                        expression.push_str(".*");
+                    }
+                }
+                '*' => expression.push_str("[^/]*"),
+                '?' => expression.push_str("[^/]"),
+                other => expression.push_str(&regex::escape(&other.to_string())),
+            }
+        }
+        if !pattern.contains(['*', '?']) {
+            expression.push_str("(?:/.*)?");
+        }
+        expression.push('$');
+
+        let regex = Regex::new(&expression).map_err(|e| {
+            CoreError::invalid_input("path", format!("invalid path pattern '{pattern}': {e}"))
+        })?;
+        Ok(Self { regex })
+    }
+
+    /// Returns `true` if `path` (relative to the repository root) matches.
+    #[must_use]
+    pub fn matches(&self, path: &str) -> bool {
+        self.regex.is_match(path.trim_start_matches("./"))
+    }
+}
+
+/// Split commits by the packages whose files they change.
+///
+/// `changed_files` maps a commit SHA to the paths it changed; commits without
+/// an entry touch no package. Every package has an entry in the result, in
+/// commit order, even when no commit touches it.
+///
+/// # Errors
+/// - `CoreError::InvalidInput` — two packages share a name or a path pattern is empty
+#[allow(clippy::result_large_err)] // CoreError is intentionally large; established pattern
+pub fn partition_commits(
+    packages: &[PackageSpec],
+    commits: &[CommitAnalysis],
+    changed_files: &HashMap<String, Vec<String>>,
+) -> CoreResult<BTreeMap<String, Vec<CommitAnalysis>>> {
+    let mut partitions = BTreeMap::new();
+    for package in packages {
+        let filter = PathFilter::new(&package.path)?;
+        let touching: Vec<CommitAnalysis> = commits
+            .iter()
+            .filter(|commit| {
+                changed_files
+                    .get(&commit.sha)
+                    .is_some_and(|files| files.iter().any(|file| filter.matches(file)))
+            })
+            .cloned()
+            .collect();
+
+        if partitions.insert(package.name.clone(), touching).is_some() {
+            return Err(CoreError::invalid_input(
+                "packages",
+                format!("package '{}' is defined more than once", package.name),
+            ));
+        }
+    }
+    Ok(partitions)
+}
+
+/// Calculate the next version of every package.
+///
+/// Each package starts from its entry in `current_versions`, or from `0.1.0`
+/// when it has not been released yet, and is bumped by the highest bump among
+/// the commits that touch it.
+///
+/// # Errors
+/// - `CoreError::InvalidInput` — see [`partition_commits`]
+#[allow(clippy::result_large_err)] // CoreError is intentionally large; established pattern
+pub fn calculate_package_versions(
+    packages: &[PackageSpec],
+    commits: &[CommitAnalysis],
+    changed_files: &HashMap<String, Vec<String>>,
+    current_versions: &HashMap<String, SemanticVersion>,
+) -> CoreResult<BTreeMap<String, PackageVersionResult>> {
+    let mut partitions = partition_commits(packages, commits, changed_files)?;
+
+    let mut results = BTreeMap::new();
+    for package in packages {
+        let analyses = partitions.remove(&package.name).unwrap_or_default();
+        let bump = highest_bump(&analyses);
+        let current_version = current_versions.get(&package.name).cloned();
+        let base = current_version.clone().unwrap_or(SemanticVersion {
+            major: 0,
+            minor: 1,
+            patch: 0,
+            prerelease: None,
+            build: None,
+        });
+
+        results.insert(
+            package.name.clone(),
+            PackageVersionResult {
+                changelog_entries: changelog_entries(&analyses),
+                analyzed_commits: analyses,
+                current_version,
+                next_version: apply_semver_bump(&base, bump.clone()),
+                tag_prefix: package.tag_prefix.clone(),
+                version_bump: bump,
+            },
+        );
+    }
+    Ok(results)
+}
+
+/// The prefix of a package's release tags: its `tag_prefix`, or `<name>-v`
+/// when none is configured.
+#[must_use]
+pub fn package_tag_prefix(package: &PackageSpec) -> String {
+    package
+        .tag_prefix
+        .clone()
+        .unwrap_or_else(|| format!("{}-v", package.name))
+}
+
+/// The highest released version of every package, read from tags named
+/// `<tag prefix><version>`.
+///
+/// Pre-release tags are ignored. Packages without a matching tag have no
+/// entry, so [`calculate_package_versions`] treats them as unreleased.
+#[must_use]
+pub fn latest_package_versions(
+    packages: &[PackageSpec],
+    tags: &[GitTag],
+) -> HashMap<String, SemanticVersion> {
+    packages
+        .iter()
+        .filter_map(|package| {
+            let prefix = package_tag_prefix(package);
+            tags.iter()
+                .filter_map(|t| t.name.strip_prefix(prefix.as_str()))
+                .filter_map(|v| VersionCalculator::parse_version(v).ok())
+                .filter(|v| !v.is_prerelease())
+                .max_by(SemanticVersion::compare_precedence)
+                .map(|v| (package.name.clone(), v))
+        })
+        .collect()
+}
+
+/// The release tags of the packages in `results` whose version is bumped,
+/// named `<tag prefix><next version>`, in package order.
+#[must_use]
+pub fn package_release_tags(
+    packages: &[PackageSpec],
+    results: &BTreeMap<String, PackageVersionResult>,
+) -> Vec<String> {
+    packages
+        .iter()
+        .filter_map(|package| {
+            let result = results.get(&package.name)?;
+            (result.version_bump != VersionBump::None)
+                .then(|| format!("{}{}", package_tag_prefix(package), result.next_version))
+        })
+        .collect()
+}
+
+/// Resolve the current version of every package from the repository tags.
+///
+/// Returns an empty map without listing tags when `packages` is empty.
+///
+/// # Errors
+/// Propagates errors from [`GitOperations::list_tags`].
+pub async fn resolve_package_versions<G>(
+    github: &G,
+    owner: &str,
+    repo: &str,
+    packages: &[PackageSpec],
+) -> CoreResult<HashMap<String, SemanticVersion>>
+where
+    G: GitOperations,
+{
+    if packages.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let tags = github
+        .list_tags(owner, repo, ListTagsOptions::default())
+        .await?;
+    let versions = latest_package_versions(packages, &tags);
+    tracing::debug!(
+        owner = %owner,
+        repo = %repo,
+        resolved = versions.len(),
+        "resolved package versions from tags"
+    );
+    Ok(versions)
+}
+
+fn highest_bump(analyses: &[CommitAnalysis]) -> VersionBump {
+    let rank = |bump: &VersionBump| match bump {
+        VersionBump::None => 0,
+        VersionBump::Patch => 1,
+        VersionBump::Minor => 2,
+        VersionBump::Major => 3,
+    };
+    analyses
+        .iter()
+        .map(|a| a.version_bump.clone())
+        .max_by_key(rank)
+        .unwrap_or(VersionBump::None)
+}
+
+fn changelog_entries(analyses: &[CommitAnalysis]) -> Vec<ChangelogEntry> {
+    analyses
+        .iter()
+        .filter(|a| a.version_bump != VersionBump::None || a.is_breaking)
+        .map(|a| ChangelogEntry {
+            commit_sha: a.sha.clone(),
+            description: a.message.clone(),
+            entry_type: a.commit_type.clone().unwrap_or_else(|| "chore".to_string()),
+            is_breaking: a.is_breaking,
+            issues: Vec::new(),
+            pr_number: None,
+            scope: a.scope.clone(),
+        })
+        .collect()
+}
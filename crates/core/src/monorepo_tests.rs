@@ -0,0 +1,280 @@
+use super::*;
+use chrono::Utc;
+
+fn package(name: &str, path: &str) -> PackageSpec {
+    PackageSpec {
+        name: name.to_string(),
+        path: path.to_string(),
+        tag_prefix: Some(format!("{name}-v")),
+        changelog_path: None,
+    }
+}
+
+fn analysis(sha: &str, commit_type: &str, bump: VersionBump) -> CommitAnalysis {
+    CommitAnalysis {
+        author: "Dev".to_string(),
+        commit_type: Some(commit_type.to_string()),
+        date: Utc::now(),
+        is_breaking: bump == VersionBump::Major,
+        message: format!("{commit_type}: change {sha}"),
+        metadata: HashMap::new(),
+        scope: None,
+        sha: sha.to_string(),
+        version_bump: bump,
+    }
+}
+
+fn files(entries: &[(&str, &[&str])]) -> HashMap<String, Vec<String>> {
+    entries
+        .iter()
+        .map(|(sha, paths)| {
+            (
+                (*sha).to_string(),
+                paths.iter().map(|p| (*p).to_string()).collect(),
+            )
+        })
+        .collect()
+}
+
+fn version(major: u64, minor: u64, patch: u64) -> SemanticVersion {
+    SemanticVersion {
+        major,
+        minor,
+        patch,
+        prerelease: None,
+        build: None,
+    }
+}
+
+fn shas(commits: &[CommitAnalysis]) -> Vec<&str> {
+    commits.iter().map(|c| c.sha.as_str()).collect()
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// PathFilter
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_path_filter_single_star_stays_within_segment() {
+    let filter = PathFilter::new("packages/*/package.json").unwrap();
+
+    assert!(filter.matches("packages/ui/package.json"));
+    assert!(!filter.matches("packages/ui/nested/package.json"));
+    assert!(!filter.matches("other/ui/package.json"));
+}
+
+#[test]
+fn test_path_filter_double_star_crosses_segments() {
+    let filter = PathFilter::new("crates/core/**").unwrap();
+
+    assert!(filter.matches("crates/core/Cargo.toml"));
+    assert!(filter.matches("crates/core/src/deep/file.rs"));
+    assert!(!filter.matches("crates/core-extra/src/lib.rs"));
+
+    let any_depth = PathFilter::new("**/*.proto").unwrap();
+    assert!(any_depth.matches("api.proto"));
+    assert!(any_depth.matches("schema/v1/api.proto"));
+    assert!(!any_depth.matches("schema/v1/api.json"));
+}
+
+#[test]
+fn test_path_filter_without_wildcards_matches_directory_contents() {
+    let filter = PathFilter::new("./services/web/").unwrap();
+
+    assert!(filter.matches("services/web"));
+    assert!(filter.matches("services/web/src/app.ts"));
+    assert!(!filter.matches("services/webhooks/src/app.ts"));
+}
+
+#[test]
+fn test_path_filter_escapes_regex_characters() {
+    let filter = PathFilter::new("lib/a+b/?.rs").unwrap();
+
+    assert!(filter.matches("lib/a+b/x.rs"));
+    assert!(!filter.matches("lib/aab/x.rs"));
+}
+
+#[test]
+fn test_path_filter_rejects_empty_pattern() {
+    assert!(PathFilter::new("").is_err());
+    assert!(PathFilter::new("./").is_err());
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// partition_commits
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_partition_commits_non_overlapping_changes() {
+    let packages = [package("api", "api/**"), package("web", "web/**")];
+    let commits = [
+        analysis("a1", "feat", VersionBump::Minor),
+        analysis("w1", "fix", VersionBump::Patch),
+        analysis("a2", "fix", VersionBump::Patch),
+    ];
+    let changed = files(&[
+        ("a1", &["api/src/lib.rs"]),
+        ("w1", &["web/index.html"]),
+        ("a2", &["api/Cargo.toml"]),
+    ]);
+
+    let partitions = partition_commits(&packages, &commits, &changed).unwrap();
+
+    assert_eq!(shas(&partitions["api"]), vec!["a1", "a2"]);
+    assert_eq!(shas(&partitions["web"]), vec!["w1"]);
+}
+
+#[test]
+fn test_partition_commits_overlapping_change_goes_to_every_package() {
+    let packages = [package("api", "api/**"), package("web", "web/**")];
+    let commits = [analysis("both", "feat", VersionBump::Minor)];
+    let changed = files(&[("both", &["api/src/model.rs", "web/src/model.ts"])]);
+
+    let partitions = partition_commits(&packages, &commits, &changed).unwrap();
+
+    assert_eq!(shas(&partitions["api"]), vec!["both"]);
+    assert_eq!(shas(&partitions["web"]), vec!["both"]);
+}
+
+#[test]
+fn test_partition_commits_ignores_commits_outside_every_package() {
+    let packages = [package("api", "api/**")];
+    let commits = [
+        analysis("docs", "docs", VersionBump::None),
+        analysis("unknown", "fix", VersionBump::Patch),
+    ];
+    // `unknown` has no file information at all.
+    let changed = files(&[("docs", &["README.md"])]);
+
+    let partitions = partition_commits(&packages, &commits, &changed).unwrap();
+
+    assert!(partitions["api"].is_empty());
+}
+
+#[test]
+fn test_partition_commits_rejects_duplicate_package_names() {
+    let packages = [package("api", "api/**"), package("api", "backend/**")];
+
+    let err = partition_commits(&packages, &[], &HashMap::new()).unwrap_err();
+
+    assert!(err.to_string().contains("api"), "got: {err}");
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// calculate_package_versions
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_calculate_package_versions_bumps_each_package_from_its_own_version() {
+    let packages = [
+        package("api", "api/**"),
+        package("web", "web/**"),
+        package("docs", "docs/**"),
+    ];
+    let commits = [
+        analysis("a1", "fix", VersionBump::Patch),
+        analysis("both", "feat", VersionBump::Minor),
+        analysis("w1", "feat", VersionBump::Major),
+    ];
+    let changed = files(&[
+        ("a1", &["api/src/lib.rs"]),
+        ("both", &["api/src/model.rs", "web/src/model.ts"]),
+        ("w1", &["web/src/app.ts"]),
+    ]);
+    let current = HashMap::from([
+        ("api".to_string(), version(1, 2, 3)),
+        ("web".to_string(), version(3, 0, 0)),
+        ("docs".to_string(), version(0, 4, 0)),
+    ]);
+
+    let results = calculate_package_versions(&packages, &commits, &changed, &current).unwrap();
+
+    let api = &results["api"];
+    assert_eq!(api.version_bump, VersionBump::Minor);
+    assert_eq!(api.next_version, version(1, 3, 0));
+    assert_eq!(api.current_version, Some(version(1, 2, 3)));
+    assert_eq!(api.changelog_entries.len(), 2);
+    assert_eq!(api.tag_prefix.as_deref(), Some("api-v"));
+
+    let web = &results["web"];
+    assert_eq!(web.version_bump, VersionBump::Major);
+    assert_eq!(web.next_version, version(4, 0, 0));
+    assert_eq!(shas(&web.analyzed_commits), vec!["both", "w1"]);
+
+    let docs = &results["docs"];
+    assert_eq!(docs.version_bump, VersionBump::None);
+    assert_eq!(docs.next_version, version(0, 4, 0));
+    assert!(docs.changelog_entries.is_empty());
+}
+
+#[test]
+fn test_calculate_package_versions_unreleased_package_starts_at_0_1_0() {
+    let packages = [package("new", "new/**")];
+    let commits = [analysis("n1", "feat", VersionBump::Minor)];
+    let changed = files(&[("n1", &["new/src/lib.rs"])]);
+
+    let results =
+        calculate_package_versions(&packages, &commits, &changed, &HashMap::new()).unwrap();
+
+    assert!(results["new"].current_version.is_none());
+    assert_eq!(results["new"].next_version, version(0, 2, 0));
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Package tags
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn tag(name: &str) -> GitTag {
+    GitTag {
+        name: name.to_string(),
+        target_sha: "a".repeat(40),
+        tag_type: crate::traits::git_operations::GitTagType::Lightweight,
+        message: None,
+        tagger: None,
+        created_at: None,
+    }
+}
+
+#[test]
+fn test_package_tag_prefix_defaults_to_name_v() {
+    let mut spec = package("api", "api/**");
+    assert_eq!(package_tag_prefix(&spec), "api-v");
+
+    spec.tag_prefix = None;
+    assert_eq!(package_tag_prefix(&spec), "api-v");
+
+    spec.tag_prefix = Some("api/".to_string());
+    assert_eq!(package_tag_prefix(&spec), "api/");
+}
+
+#[test]
+fn test_latest_package_versions_reads_each_package_prefix() {
+    let packages = [package("api", "api/**"), package("web", "web/**")];
+    let tags = [
+        tag("v9.0.0"),
+        tag("api-v1.2.0"),
+        tag("api-v1.10.0"),
+        tag("api-v2.0.0-rc.1"),
+        tag("web-vnext"),
+    ];
+
+    let versions = latest_package_versions(&packages, &tags);
+
+    assert_eq!(versions.len(), 1);
+    assert_eq!(versions["api"], version(1, 10, 0));
+}
+
+#[test]
+fn test_package_release_tags_lists_bumped_packages_only() {
+    let packages = [package("api", "api/**"), package("web", "web/**")];
+    let commits = [analysis("a1", "fix", VersionBump::Patch)];
+    let changed = files(&[("a1", &["api/src/lib.rs"])]);
+    let current = HashMap::from([("api".to_string(), version(1, 2, 0))]);
+
+    let results = calculate_package_versions(&packages, &commits, &changed, &current).unwrap();
+
+    assert_eq!(
+        package_release_tags(&packages, &results),
+        vec!["api-v1.2.1"]
+    );
+}
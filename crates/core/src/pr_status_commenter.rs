@@ -14,6 +14,7 @@
 //! - [`PR_STATUS_MARKER`] — the HTML marker string embedded in every comment
 
 use crate::{traits::github_operations::GitHubOperations, versioning::SemanticVersion, CoreResult};
+use std::cmp::Ordering;
 
 /// HTML marker embedded in every status comment posted by Release Regent.
 ///
@@ -77,7 +78,8 @@ pub(crate) async fn upsert_pr_status_comment<G: GitHubOperations>(
 ///
 /// Produces a Markdown string beginning with [`PR_STATUS_MARKER`].
 ///
-/// When `projected_version == base_version` the commits in this PR do not
+/// When `projected_version` has the same precedence as `base_version` (build
+/// metadata is ignored) the commits in this PR do not
 /// trigger any version bump; the comment says so rather than echoing back the
 /// current version as "the next release".
 ///
@@ -110,7 +112,7 @@ pub(crate) fn render_feature_pr_comment(
     queued_release_version: Option<&SemanticVersion>,
     allow_override: bool,
 ) -> String {
-    let mut body = if projected_version == base_version {
+    let mut body = if projected_version.compare_precedence(base_version) == Ordering::Equal {
         format!(
             "{marker}\n\
              **Release Regent \u{2014} no version change**\n\n\
@@ -133,7 +135,7 @@ pub(crate) fn render_feature_pr_comment(
     };
 
     if let Some(queued) = queued_release_version {
-        if queued > base_version {
+        if queued.compare_precedence(base_version) == Ordering::Greater {
             body.push_str(&format!(
                 "\n\n\
                  > **Note:** Release PR for **v{queued}** is already open. \
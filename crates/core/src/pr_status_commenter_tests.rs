@@ -5,9 +5,9 @@ use crate::{
             GetCommitsOptions, GitCommit, GitOperations, GitRepository, GitTag, ListTagsOptions,
         },
         github_operations::{
-            CollaboratorPermission, CreatePullRequestParams, CreateReleaseParams, FileUpdate,
-            GitHubOperations, GitUser, IssueComment, Label, PullRequest, PullRequestBranch,
-            Release, Repository, Tag, UpdateReleaseParams,
+            CollaboratorPermission, CreateCommitStatusParams, CreatePullRequestParams,
+            CreateReleaseParams, FileUpdate, GitHubOperations, GitUser, IssueComment, Label,
+            LabelMode, PullRequest, Release, Tag, UpdateReleaseParams,
         },
     },
     versioning::SemanticVersion,
@@ -93,6 +93,16 @@ impl GitOperations for TestGitHubForUpsert {
         Ok(vec![])
     }
 
+    async fn list_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _head: &str,
+        _options: GetCommitsOptions,
+    ) -> crate::CoreResult<Vec<GitCommit>> {
+        Ok(vec![])
+    }
+
     async fn get_commit(
         &self,
         _owner: &str,
@@ -237,6 +247,25 @@ impl GitHubOperations for TestGitHubForUpsert {
         Err(CoreError::not_found("stub"))
     }
 
+    async fn create_signed_tag(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _params: crate::traits::github_operations::SignedTagParams,
+    ) -> crate::CoreResult<Tag> {
+        Err(CoreError::not_found("stub"))
+    }
+
+    async fn create_commit_status(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _sha: &str,
+        _params: CreateCommitStatusParams,
+    ) -> crate::CoreResult<()> {
+        Ok(())
+    }
+
     async fn delete_branch(
         &self,
         _owner: &str,
@@ -256,6 +285,16 @@ impl GitHubOperations for TestGitHubForUpsert {
         Ok(())
     }
 
+    async fn upsert_branch(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch_name: &str,
+        _from_sha: &str,
+    ) -> crate::CoreResult<crate::traits::github_operations::BranchUpsert> {
+        Ok(crate::traits::github_operations::BranchUpsert::Created)
+    }
+
     async fn get_collaborator_permission(
         &self,
         _owner: &str,
@@ -305,8 +344,8 @@ impl GitHubOperations for TestGitHubForUpsert {
         _owner: &str,
         _repo: &str,
         _tag: &str,
-    ) -> crate::CoreResult<Release> {
-        Err(CoreError::not_found("stub"))
+    ) -> crate::CoreResult<Option<Release>> {
+        Ok(None)
     }
 
     async fn list_issue_comments(
@@ -321,6 +360,15 @@ impl GitHubOperations for TestGitHubForUpsert {
         Ok(self.state.lock().await.comments.clone())
     }
 
+    async fn list_pull_request_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+    ) -> crate::CoreResult<Vec<crate::traits::git_operations::GitCommit>> {
+        Ok(vec![])
+    }
+
     async fn list_pr_labels(
         &self,
         _owner: &str,
@@ -353,6 +401,18 @@ impl GitHubOperations for TestGitHubForUpsert {
         Ok(vec![])
     }
 
+    async fn upload_release_asset(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _release_id: u64,
+        _name: &str,
+        _content_type: Option<&str>,
+        _data: Vec<u8>,
+    ) -> crate::CoreResult<String> {
+        Err(crate::CoreError::not_found("stub"))
+    }
+
     async fn remove_label(
         &self,
         _owner: &str,
@@ -363,6 +423,26 @@ impl GitHubOperations for TestGitHubForUpsert {
         Ok(())
     }
 
+    async fn resolve_ref(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _git_ref: &str,
+    ) -> crate::CoreResult<String> {
+        Err(CoreError::not_found("stub"))
+    }
+
+    async fn set_pull_request_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+        _labels: &[&str],
+        _mode: LabelMode,
+    ) -> crate::CoreResult<()> {
+        Ok(())
+    }
+
     async fn search_pull_requests(
         &self,
         _owner: &str,
@@ -634,6 +714,17 @@ fn test_render_feature_pr_comment_no_bump_does_not_say_next_release_will_be() {
     );
 }
 
+#[test]
+fn test_render_feature_pr_comment_build_metadata_only_shows_no_version_change() {
+    let mut projected = v100();
+    projected.build = Some("sha.abc1234".to_string());
+    let body = render_feature_pr_comment(&projected, &v100(), None, false);
+    assert!(
+        body.contains("no version change"),
+        "build metadata alone must not count as a version change; got: {body}"
+    );
+}
+
 #[test]
 fn test_render_feature_pr_comment_queued_version_higher_than_base_includes_note() {
     let queued = v200();
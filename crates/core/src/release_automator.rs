@@ -9,7 +9,8 @@
 //!
 //! 1. **Extracts** the version from the merged PR using a three-level fallback chain:
 //!    - Branch name: `{branch_prefix}/{version_prefix}{version}` (e.g. `release/v1.2.3`
-//!      with the default `version_prefix = "v"`, or `release/1.2.3` with an empty prefix).
+//!      with the default `version_prefix = "v"`, or `release/1.2.3` with an empty prefix),
+//!      or the name rendered from [`AutomatorConfig::branch_template`] when one is set.
 //!    - PR title: first `v`-prefixed semver token (e.g. `chore(release): v1.2.3`).
 //!      **Note:** the title scan always looks for a `v`-prefixed token regardless of
 //!      `version_prefix`; users with a custom or empty prefix should ensure the branch
@@ -18,25 +19,36 @@
 //!      Fails with [`CoreError::InvalidInput`] only if no valid semver is found in any
 //!      of the three sources.
 //! 2. **Extracts** the merge commit SHA from the webhook payload.
-//! 3. **Creates an annotated Git tag** pointing to the merge commit.
+//! 3. **Creates an annotated Git tag** pointing to the merge commit. When
+//!    [`AutomatorConfig::tag_signing`] is set the tag object is signed locally
+//!    and created with [`GitHubOperations::create_signed_tag`].
 //! 4. **Extracts** the changelog from the PR body.
 //! 5. **Creates a GitHub release** using the tag, with the changelog as release
 //!    notes and the pre-release flag set when the version contains a pre-release
-//!    identifier.
+//!    identifier (or when [`AutomatorConfig::prerelease`] forces it). When
+//!    [`AutomatorConfig::create_as_draft`] is set the release is created as a
+//!    draft; [`ReleaseAutomator::publish_draft`] promotes it later.
 //! 6. **Deletes the release branch** after a successful release (non-fatal on
 //!    failure — the release has already been published).
 //!
+//! Before step 3 every monorepo package tag recorded in the PR body (see
+//! [`extract_package_tag_sentinels`]) is tagged at the merge commit and gets
+//! its own release, so the packages are versioned from their own tags.
+//!
 //! ## Idempotency
 //!
 //! All operations are safe to retry:
 //!
-//! - If `create_tag` returns [`CoreError::NotSupported`] (tag already exists),
-//!   the automator checks whether a matching release also exists via
-//!   `get_release_by_tag`.
-//!   - **Release exists**: return `Ok(AutomatorResult::Created { release })` — already done.
-//!   - **Release absent**: skip tag creation and proceed from step 4 onward to
-//!     create the release using the existing tag.
-//! - Other GitHub API failures are propagated so the event loop can retry.
+//! - Before tagging, the automator looks up the release for the tag in the
+//!   release list, which unlike `get_release_by_tag` also holds drafts. When
+//!   one exists the workflow stops and returns
+//!   [`AutomatorResult::AlreadyReleased`]; only the branch cleanup is retried.
+//! - If `create_tag` returns [`CoreError::NotSupported`] (tag already exists
+//!   but no release does), tag creation is skipped and the release is created
+//!   from the existing tag.
+//! - Package releases that already exist are skipped the same way.
+//! - Other GitHub API failures, including a failed release lookup, are
+//!   propagated so the event loop can retry.
 //!
 //! ## Usage
 //!
@@ -51,16 +63,25 @@
 //! ```
 
 use crate::{
-    release_orchestrator::extract_changelog_from_pr_body,
+    branch_template::{self, BranchNameVars},
+    release_orchestrator::{extract_changelog_from_pr_body, extract_package_tag_sentinels},
+    tag_signing::{tag_payload, TagSigning},
     traits::{
         event_source::ProcessingEvent,
-        github_operations::{CreateReleaseParams, GitHubOperations, Release},
+        github_operations::{
+            CreateReleaseParams, GitHubOperations, Release, SignedTagParams, Tag,
+            UpdateReleaseParams,
+        },
     },
     versioning::{SemanticVersion, VersionCalculator},
     CoreError, CoreResult,
 };
+use chrono::{SubsecRound, Utc};
 use tracing::{info, warn};
 
+/// Number of releases requested per page when searching for a release by tag.
+const RELEASE_PAGE_SIZE: u8 = 100;
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Public types
 // ─────────────────────────────────────────────────────────────────────────────
@@ -96,6 +117,44 @@ pub struct AutomatorConfig {
     ///
     /// Defaults to `false`.
     pub generate_release_notes: bool,
+
+    /// Whether to create the GitHub release as a draft.
+    ///
+    /// Draft releases are not publicly visible and do not emit the
+    /// `release: published` webhook, so downstream publish automation is not
+    /// triggered until the draft is promoted with
+    /// [`ReleaseAutomator::publish_draft`].
+    ///
+    /// Defaults to `false`.
+    pub create_as_draft: bool,
+
+    /// Whether to mark every release as a pre-release.
+    ///
+    /// Releases whose version has a pre-release segment (e.g. `1.2.0-rc.1`)
+    /// are always marked as pre-releases; this flag extends that to stable
+    /// versions.
+    ///
+    /// Defaults to `false`.
+    pub prerelease: bool,
+
+    /// Signer and tagger for release tags.
+    ///
+    /// When set, release tags are signed locally and created with
+    /// [`GitHubOperations::create_signed_tag`]; otherwise they are created
+    /// unsigned.
+    ///
+    /// Defaults to `None`.
+    pub tag_signing: Option<TagSigning>,
+
+    /// Template the release branch was named from; see
+    /// [`crate::branch_template`].
+    ///
+    /// When set, the version is read from the part of the branch name that
+    /// follows the rendered template prefix. When `None` the branch is
+    /// expected to be named `{branch_prefix}/{version_prefix}{version}`.
+    ///
+    /// Defaults to `None`.
+    pub branch_template: Option<String>,
 }
 
 impl Default for AutomatorConfig {
@@ -105,6 +164,10 @@ impl Default for AutomatorConfig {
             changelog_header: "## Changelog".to_string(),
             version_prefix: "v".to_string(),
             generate_release_notes: false,
+            create_as_draft: false,
+            prerelease: false,
+            tag_signing: None,
+            branch_template: None,
         }
     }
 }
@@ -112,15 +175,30 @@ impl Default for AutomatorConfig {
 /// The outcome of a single [`ReleaseAutomator::automate`] call.
 #[derive(Debug, Clone)]
 pub enum AutomatorResult {
-    /// The GitHub release is ready — either freshly created or already present.
-    ///
-    /// When the matching Git tag **and** GitHub release both existed before this
-    /// call, no new resources are created and this variant is returned
-    /// unchanged, making the operation safe to retry.
+    /// A new GitHub release was created.
     Created {
-        /// The created (or previously existing) GitHub release.
+        /// The created GitHub release.
         release: Release,
     },
+
+    /// A release already existed for the tag, so nothing was created.
+    ///
+    /// Returned when a delivery is retried after an earlier attempt already
+    /// released the version.
+    AlreadyReleased {
+        /// The previously existing GitHub release.
+        release: Release,
+    },
+}
+
+impl AutomatorResult {
+    /// The release the workflow ended with, whether created or pre-existing.
+    #[must_use]
+    pub fn release(&self) -> &Release {
+        match self {
+            Self::Created { release } | Self::AlreadyReleased { release } => release,
+        }
+    }
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -174,13 +252,31 @@ impl<'a, G: GitHubOperations + Send + Sync> ReleaseAutomator<'a, G> {
         correlation_id: &str,
     ) -> CoreResult<AutomatorResult> {
         let (branch, merge_sha, pr_body, pr_title) = extract_payload_fields(event)?;
-        let version = extract_version_from_pr(
-            &branch,
-            &pr_title,
-            &pr_body,
-            &self.config.branch_prefix,
-            &self.config.version_prefix,
-        )?;
+        let templated_version = self.config.branch_template.as_deref().and_then(|template| {
+            let target_branch = event
+                .payload
+                .pointer("/pull_request/base/ref")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or(&event.repository.default_branch);
+            let vars = BranchNameVars {
+                owner,
+                repo,
+                target_branch,
+                version_prefix: &self.config.version_prefix,
+            };
+            branch_template::version_from_release_pr(template, &vars, &branch, &pr_title, &pr_body)
+                .ok()
+        });
+        let version = match templated_version {
+            Some(version) => version,
+            None => extract_version_from_pr(
+                &branch,
+                &pr_title,
+                &pr_body,
+                &self.config.branch_prefix,
+                &self.config.version_prefix,
+            )?,
+        };
         let tag_name = format!("{}{version}", self.config.version_prefix);
 
         info!(
@@ -188,15 +284,14 @@ impl<'a, G: GitHubOperations + Send + Sync> ReleaseAutomator<'a, G> {
             correlation_id, "Automating GitHub release for merged release PR"
         );
 
-        // Create the annotated Git tag, handling the idempotent case where the
-        // tag already exists.
-        if let Some(existing) = self
-            .ensure_tag_and_get_existing_release(owner, repo, &tag_name, &merge_sha)
-            .await?
-        {
-            // Tag and release both exist — this is a full idempotent retry.
+        if let Some(existing) = self.find_release(owner, repo, &tag_name).await? {
+            // The release exists — this is a full idempotent retry.
             // Still attempt branch cleanup: a previous run may have succeeded at
             // tag+release creation but failed before (or during) deletion.
+            info!(
+                tag = %tag_name, release_id = existing.id,
+                "Release already exists for tag; skipping creation"
+            );
             if let Err(e) = self.github.delete_branch(owner, repo, &branch).await {
                 warn!(
                     error = %e, branch = %branch,
@@ -205,12 +300,17 @@ impl<'a, G: GitHubOperations + Send + Sync> ReleaseAutomator<'a, G> {
             } else {
                 tracing::debug!(branch = %branch, "Deleted release branch (idempotent path)");
             }
-            return Ok(AutomatorResult::Created { release: existing });
+            return Ok(AutomatorResult::AlreadyReleased { release: existing });
         }
 
+        self.release_packages(owner, repo, &pr_body, &tag_name, &merge_sha)
+            .await?;
+        self.ensure_tag(owner, repo, &tag_name, &merge_sha).await?;
+
         // Extract changelog and create the GitHub release.
         let changelog = extract_changelog_from_pr_body(&pr_body, &self.config.changelog_header);
-        let is_prerelease = version.is_prerelease();
+        let is_prerelease = self.config.prerelease || version.is_prerelease();
+        let draft = self.config.create_as_draft;
 
         let release = self
             .github
@@ -221,7 +321,7 @@ impl<'a, G: GitHubOperations + Send + Sync> ReleaseAutomator<'a, G> {
                     tag_name: tag_name.clone(),
                     name: Some(tag_name.clone()),
                     body: Some(changelog),
-                    draft: false,
+                    draft,
                     prerelease: is_prerelease,
                     generate_release_notes: self.config.generate_release_notes,
                     target_commitish: Some(merge_sha),
@@ -230,7 +330,7 @@ impl<'a, G: GitHubOperations + Send + Sync> ReleaseAutomator<'a, G> {
             .await?;
 
         info!(
-            release_id = release.id, tag = %tag_name, prerelease = is_prerelease,
+            release_id = release.id, tag = %tag_name, prerelease = is_prerelease, draft,
             "Created GitHub release"
         );
 
@@ -247,57 +347,205 @@ impl<'a, G: GitHubOperations + Send + Sync> ReleaseAutomator<'a, G> {
         Ok(AutomatorResult::Created { release })
     }
 
+    /// Promote the draft release for `tag_name` to a published release.
+    ///
+    /// Publishing a release that is already published is a no-op that returns
+    /// the release unchanged, so the operation is safe to retry.
+    ///
+    /// # Errors
+    ///
+    /// - [`CoreError::NotFound`] — no release exists for `tag_name`.
+    /// - [`CoreError::GitHub`] — a GitHub API call failed.
+    #[tracing::instrument(skip(self), fields(owner, repo, tag_name))]
+    pub async fn publish_draft(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag_name: &str,
+    ) -> CoreResult<Release> {
+        let release = self
+            .find_release(owner, repo, tag_name)
+            .await?
+            .ok_or_else(|| CoreError::not_found(format!("release for tag '{tag_name}'")))?;
+        if !release.draft {
+            tracing::debug!(tag = %tag_name, release_id = release.id, "Release is already published");
+            return Ok(release);
+        }
+
+        let published = self
+            .github
+            .update_release(
+                owner,
+                repo,
+                release.id,
+                UpdateReleaseParams {
+                    body: None,
+                    draft: Some(false),
+                    name: None,
+                    prerelease: None,
+                },
+            )
+            .await?;
+
+        info!(release_id = published.id, tag = %tag_name, "Published draft release");
+        Ok(published)
+    }
+
     // ── Private helpers ────────────────────────────────────────────────────
 
-    /// Create the annotated Git tag for `tag_name` at `merge_sha`.
+    /// Find the release for `tag_name`, including a draft release.
+    ///
+    /// `get_release_by_tag` only returns published releases, so the release
+    /// list, which also holds drafts, is searched page by page instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying `CoreError` when listing releases fails.
+    async fn find_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag_name: &str,
+    ) -> CoreResult<Option<Release>> {
+        for page in 1.. {
+            let releases = self
+                .github
+                .list_releases(owner, repo, Some(RELEASE_PAGE_SIZE), Some(page))
+                .await?;
+            let last_page = releases.len() < usize::from(RELEASE_PAGE_SIZE);
+            if let Some(release) = releases.into_iter().find(|r| r.tag_name == tag_name) {
+                return Ok(Some(release));
+            }
+            if last_page {
+                break;
+            }
+        }
+        Ok(None)
+    }
+
+    /// Tag and release every monorepo package tag recorded in `pr_body`.
     ///
-    /// Returns `Ok(Some(release))` when the tag **and** release already exist
-    /// (idempotent resumption).  Returns `Ok(None)` when the caller should
-    /// continue and create the release.
+    /// Packages are released before the repository release, so a retry after
+    /// a partial failure finds the repository release absent and resumes here;
+    /// package releases that already exist are skipped.
     ///
     /// # Errors
     ///
     /// Returns the underlying `CoreError` on any non-idempotent GitHub API
     /// failure.
-    async fn ensure_tag_and_get_existing_release(
+    async fn release_packages(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_body: &str,
+        release_tag: &str,
+        merge_sha: &str,
+    ) -> CoreResult<()> {
+        for package_tag in extract_package_tag_sentinels(pr_body) {
+            if self
+                .find_release(owner, repo, &package_tag)
+                .await?
+                .is_some()
+            {
+                tracing::debug!(tag = %package_tag, "Package release already exists; skipping");
+                continue;
+            }
+            self.ensure_tag(owner, repo, &package_tag, merge_sha)
+                .await?;
+            let release = self
+                .github
+                .create_release(
+                    owner,
+                    repo,
+                    CreateReleaseParams {
+                        tag_name: package_tag.clone(),
+                        name: Some(package_tag.clone()),
+                        body: Some(format!("Released as part of {release_tag}.")),
+                        draft: self.config.create_as_draft,
+                        prerelease: self.config.prerelease,
+                        generate_release_notes: false,
+                        target_commitish: Some(merge_sha.to_string()),
+                    },
+                )
+                .await?;
+            info!(release_id = release.id, tag = %package_tag, "Created package release");
+        }
+        Ok(())
+    }
+
+    /// Create the annotated Git tag for `tag_name` at `merge_sha`.
+    ///
+    /// The tag is signed when [`AutomatorConfig::tag_signing`] is set. A tag
+    /// that already exists is left as is, so that a retry after a run that
+    /// tagged but failed to create the release resumes from the existing tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying `CoreError` on any non-idempotent GitHub API
+    /// failure, or [`CoreError::Config`] when the tag cannot be signed.
+    async fn ensure_tag(
         &self,
         owner: &str,
         repo: &str,
         tag_name: &str,
         merge_sha: &str,
-    ) -> CoreResult<Option<Release>> {
+    ) -> CoreResult<()> {
         let tag_message = format!("Release {tag_name}");
-        match self
-            .github
-            .create_tag(owner, repo, tag_name, merge_sha, Some(tag_message), None)
-            .await
-        {
-            Ok(_) => Ok(None),
+        let created = match &self.config.tag_signing {
+            Some(signing) => {
+                self.create_signed_tag(owner, repo, signing, tag_name, merge_sha, tag_message)
+                    .await
+            }
+            None => {
+                self.github
+                    .create_tag(owner, repo, tag_name, merge_sha, Some(tag_message), None)
+                    .await
+            }
+        };
+        match created {
+            Ok(_) => Ok(()),
             Err(CoreError::NotSupported { .. }) => {
-                // Tag already exists; check whether a release also exists.
-                tracing::debug!(tag = %tag_name, "Tag already exists; checking for existing release");
-                match self.github.get_release_by_tag(owner, repo, tag_name).await {
-                    Ok(existing_release) => {
-                        info!(
-                            tag = %tag_name, release_id = existing_release.id,
-                            "Release already exists for tag; returning idempotent result"
-                        );
-                        Ok(Some(existing_release))
-                    }
-                    Err(CoreError::NotFound { .. } | CoreError::NotSupported { .. }) => {
-                        // Tag exists but release does not — fall through to create release.
-                        tracing::debug!(
-                            tag = %tag_name,
-                            "Tag exists but release is absent; creating release from existing tag"
-                        );
-                        Ok(None)
-                    }
-                    Err(other) => Err(other),
-                }
+                tracing::debug!(
+                    tag = %tag_name,
+                    "Tag exists but release is absent; creating release from existing tag"
+                );
+                Ok(())
             }
             Err(other) => Err(other),
         }
     }
+
+    /// Sign the tag object for `tag_name` and create it.
+    async fn create_signed_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        signing: &TagSigning,
+        tag_name: &str,
+        merge_sha: &str,
+        message: String,
+    ) -> CoreResult<Tag> {
+        // The tag object records whole seconds, so the signed time must too.
+        let tagged_at = Utc::now().trunc_subsecs(0);
+        let payload = tag_payload(tag_name, merge_sha, &signing.tagger, tagged_at, &message);
+        let signature = signing.signer.sign(payload.as_bytes()).await?;
+
+        info!(tag = %tag_name, "Signed release tag");
+        self.github
+            .create_signed_tag(
+                owner,
+                repo,
+                SignedTagParams {
+                    commit_sha: merge_sha.to_string(),
+                    message,
+                    signature,
+                    tagged_at,
+                    tagger: signing.tagger.clone(),
+                    tag_name: tag_name.to_string(),
+                },
+            )
+            .await
+    }
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -541,7 +789,7 @@ pub fn extract_version_from_pr(
 /// When `require_v_prefix` is `true`, only tokens that begin with `v` (followed
 /// by a digit) are considered.  When `false`, a `v` prefix is stripped when
 /// present but is not required.
-fn find_version_token(text: &str, require_v_prefix: bool) -> Option<SemanticVersion> {
+pub(crate) fn find_version_token(text: &str, require_v_prefix: bool) -> Option<SemanticVersion> {
     for token in text.split_whitespace() {
         // Strip common surrounding punctuation that cannot appear in semver.
         // The trailing `.` in prose like "version v3.1.4." is intentionally
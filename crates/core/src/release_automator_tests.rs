@@ -1,13 +1,15 @@
 use super::*;
 use crate::{
+    tag_signing::{TagSigner, TagSigning},
     traits::{
         event_source::{EventSourceKind, EventType, ProcessingEvent, RepositoryInfo},
         git_operations::{
             GetCommitsOptions, GitCommit, GitOperations, GitRepository, GitTag, ListTagsOptions,
         },
         github_operations::{
-            CollaboratorPermission, CreatePullRequestParams, CreateReleaseParams, GitHubOperations,
-            GitUser as GitHubUser, Label, PullRequest, PullRequestBranch, Release, Repository, Tag,
+            CollaboratorPermission, CreateCommitStatusParams, CreatePullRequestParams,
+            CreateReleaseParams, GitHubOperations, GitUser as GitHubUser, Label, LabelMode,
+            PullRequest, PullRequestBranch, Release, Repository, SignedTagParams, Tag,
             UpdateReleaseParams,
         },
     },
@@ -27,6 +29,8 @@ use tokio::sync::Mutex;
 struct TestState {
     /// Tags returned by successful `create_tag`.
     created_tags: Vec<(String, String)>,
+    /// Recorded successful `create_signed_tag` calls.
+    signed_tags: Vec<SignedTagParams>,
     /// Whether the next `create_tag` call should return `NotSupported` (tag exists).
     next_create_tag_conflict: bool,
     /// Whether `create_tag` always errors with a GitHub error.
@@ -35,12 +39,20 @@ struct TestState {
     create_release_error: bool,
     /// Whether `delete_branch` should return an error.
     delete_branch_error: bool,
-    /// Releases stored (keyed by tag name) — returned by `get_release_by_tag`.
+    /// Releases stored (keyed by tag name). Like GitHub, `get_release_by_tag`
+    /// returns only the published ones and `list_releases` returns all of them.
     releases_by_tag: std::collections::HashMap<String, Release>,
+    /// Whether `get_release_by_tag` and `list_releases` should return a
+    /// network error.
+    get_release_error: bool,
+    /// Number of `list_releases` calls.
+    release_listings: usize,
     /// Recorded `create_release` calls.
     created_releases: Vec<CreateReleaseParams>,
     /// Recorded `delete_branch` calls.
     deleted_branches: Vec<String>,
+    /// Recorded `update_release` calls.
+    updated_releases: Vec<(u64, UpdateReleaseParams)>,
     /// Sequential release ID counter.
     next_release_id: u64,
 }
@@ -78,13 +90,19 @@ impl TestGitHub {
         self
     }
 
+    /// Make `get_release_by_tag` and `list_releases` fail with a network error.
+    async fn with_get_release_error(self) -> Self {
+        self.state.lock().await.get_release_error = true;
+        self
+    }
+
     /// Make `delete_branch` fail (should be non-fatal).
     async fn with_delete_branch_error(self) -> Self {
         self.state.lock().await.delete_branch_error = true;
         self
     }
 
-    /// Pre-load a release returned by `get_release_by_tag` for `tag_name`.
+    /// Pre-load the release for `tag_name`.
     async fn with_release_for_tag(self, tag_name: impl Into<String>, release: Release) -> Self {
         self.state
             .lock()
@@ -98,13 +116,25 @@ impl TestGitHub {
         self.state.lock().await.created_tags.clone()
     }
 
+    async fn signed_tags(&self) -> Vec<SignedTagParams> {
+        self.state.lock().await.signed_tags.clone()
+    }
+
     async fn created_releases(&self) -> Vec<CreateReleaseParams> {
         self.state.lock().await.created_releases.clone()
     }
 
+    async fn release_listings(&self) -> usize {
+        self.state.lock().await.release_listings
+    }
+
     async fn deleted_branches(&self) -> Vec<String> {
         self.state.lock().await.deleted_branches.clone()
     }
+
+    async fn updated_releases(&self) -> Vec<(u64, UpdateReleaseParams)> {
+        self.state.lock().await.updated_releases.clone()
+    }
 }
 
 // ── GitOperations stub ────────────────────────────────────────────────────
@@ -122,6 +152,16 @@ impl GitOperations for TestGitHub {
         Ok(vec![])
     }
 
+    async fn list_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _head: &str,
+        _options: GetCommitsOptions,
+    ) -> CoreResult<Vec<GitCommit>> {
+        Ok(vec![])
+    }
+
     async fn get_commit(&self, _owner: &str, _repo: &str, _sha: &str) -> CoreResult<GitCommit> {
         Err(CoreError::not_found("stub"))
     }
@@ -236,7 +276,7 @@ impl GitHubOperations for TestGitHub {
         st.next_release_id += 1;
         st.created_releases.push(params.clone());
         let now = Utc::now();
-        Ok(Release {
+        let release = Release {
             id,
             tag_name: params.tag_name.clone(),
             name: params.name,
@@ -249,7 +289,10 @@ impl GitHubOperations for TestGitHub {
             target_commitish: params
                 .target_commitish
                 .unwrap_or_else(|| params.tag_name.clone()),
-        })
+        };
+        st.releases_by_tag
+            .insert(release.tag_name.clone(), release.clone());
+        Ok(release)
     }
 
     async fn create_tag(
@@ -284,6 +327,40 @@ impl GitHubOperations for TestGitHub {
         })
     }
 
+    async fn create_signed_tag(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        params: SignedTagParams,
+    ) -> CoreResult<Tag> {
+        let mut st = self.state.lock().await;
+        if st.next_create_tag_conflict {
+            st.next_create_tag_conflict = false;
+            return Err(CoreError::not_supported(
+                "create_signed_tag",
+                format!("tag '{}' already exists", params.tag_name),
+            ));
+        }
+        st.signed_tags.push(params.clone());
+        Ok(Tag {
+            name: params.tag_name,
+            commit_sha: params.commit_sha,
+            message: Some(params.message),
+            tagger: Some(params.tagger),
+            created_at: Some(params.tagged_at),
+        })
+    }
+
+    async fn create_commit_status(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _sha: &str,
+        _params: CreateCommitStatusParams,
+    ) -> CoreResult<()> {
+        Ok(())
+    }
+
     async fn delete_branch(&self, _owner: &str, _repo: &str, branch_name: &str) -> CoreResult<()> {
         let mut st = self.state.lock().await;
         if st.delete_branch_error {
@@ -303,6 +380,16 @@ impl GitHubOperations for TestGitHub {
         Ok(())
     }
 
+    async fn upsert_branch(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _branch_name: &str,
+        _from_sha: &str,
+    ) -> CoreResult<crate::traits::github_operations::BranchUpsert> {
+        Ok(crate::traits::github_operations::BranchUpsert::Created)
+    }
+
     async fn get_collaborator_permission(
         &self,
         _owner: &str,
@@ -330,12 +417,13 @@ impl GitHubOperations for TestGitHub {
         _owner: &str,
         _repo: &str,
         tag: &str,
-    ) -> CoreResult<Release> {
+    ) -> CoreResult<Option<Release>> {
         let st = self.state.lock().await;
-        st.releases_by_tag
-            .get(tag)
-            .cloned()
-            .ok_or_else(|| CoreError::not_found(format!("release for tag '{tag}'")))
+        if st.get_release_error {
+            return Err(CoreError::network("simulated release lookup failure"));
+        }
+        // GET /releases/tags/{tag} never returns drafts.
+        Ok(st.releases_by_tag.get(tag).filter(|r| !r.draft).cloned())
     }
 
     async fn list_pr_labels(
@@ -365,9 +453,30 @@ impl GitHubOperations for TestGitHub {
         _owner: &str,
         _repo: &str,
         _per_page: Option<u8>,
-        _page: Option<u32>,
+        page: Option<u32>,
     ) -> CoreResult<Vec<Release>> {
-        Ok(vec![])
+        let mut st = self.state.lock().await;
+        st.release_listings += 1;
+        if st.get_release_error {
+            return Err(CoreError::network("simulated release listing failure"));
+        }
+        // Every stored release fits on the first page.
+        if page.unwrap_or(1) > 1 {
+            return Ok(vec![]);
+        }
+        Ok(st.releases_by_tag.values().cloned().collect())
+    }
+
+    async fn upload_release_asset(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _release_id: u64,
+        _name: &str,
+        _content_type: Option<&str>,
+        _data: Vec<u8>,
+    ) -> CoreResult<String> {
+        Err(CoreError::not_found("stub"))
     }
 
     async fn remove_label(
@@ -380,6 +489,21 @@ impl GitHubOperations for TestGitHub {
         Ok(())
     }
 
+    async fn resolve_ref(&self, _owner: &str, _repo: &str, _git_ref: &str) -> CoreResult<String> {
+        Err(CoreError::not_found("stub"))
+    }
+
+    async fn set_pull_request_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+        _labels: &[&str],
+        _mode: LabelMode,
+    ) -> CoreResult<()> {
+        Ok(())
+    }
+
     async fn search_pull_requests(
         &self,
         _owner: &str,
@@ -427,10 +551,20 @@ impl GitHubOperations for TestGitHub {
         &self,
         _owner: &str,
         _repo: &str,
-        _release_id: u64,
-        _params: UpdateReleaseParams,
+        release_id: u64,
+        params: UpdateReleaseParams,
     ) -> CoreResult<Release> {
-        Err(CoreError::not_supported("update_release", "stub"))
+        let mut st = self.state.lock().await;
+        st.updated_releases.push((release_id, params.clone()));
+        let release = st
+            .releases_by_tag
+            .values_mut()
+            .find(|r| r.id == release_id)
+            .ok_or_else(|| CoreError::not_found(format!("release {release_id}")))?;
+        if let Some(draft) = params.draft {
+            release.draft = draft;
+        }
+        Ok(release.clone())
     }
 
     async fn get_installation_id_for_repo(&self, _owner: &str, _repo: &str) -> CoreResult<u64> {
@@ -491,6 +625,15 @@ impl GitHubOperations for TestGitHub {
         Ok(vec![])
     }
 
+    async fn list_pull_request_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+    ) -> CoreResult<Vec<crate::traits::git_operations::GitCommit>> {
+        Ok(vec![])
+    }
+
     async fn update_issue_comment(
         &self,
         _owner: &str,
@@ -887,7 +1030,9 @@ async fn test_automate_happy_path_creates_tag_release_and_deletes_branch() {
         .await
         .unwrap();
 
-    let AutomatorResult::Created { release } = result;
+    let AutomatorResult::Created { release } = result else {
+        panic!("Expected a newly created release");
+    };
     assert_eq!(release.tag_name, "v1.2.3");
     assert!(!release.prerelease);
 
@@ -921,7 +1066,9 @@ async fn test_automate_prerelease_version_sets_prerelease_flag() {
         .await
         .unwrap();
 
-    let AutomatorResult::Created { release } = result;
+    let AutomatorResult::Created { release } = result else {
+        panic!("Expected a newly created release");
+    };
     assert!(release.prerelease, "Expected prerelease flag to be set");
     assert_eq!(release.tag_name, "v1.0.0-rc.1");
 
@@ -946,7 +1093,9 @@ async fn test_automate_stable_version_does_not_set_prerelease_flag() {
         .await
         .unwrap();
 
-    let AutomatorResult::Created { release } = result;
+    let AutomatorResult::Created { release } = result else {
+        panic!("Expected a newly created release");
+    };
     assert!(
         !release.prerelease,
         "Stable version must not set prerelease"
@@ -974,7 +1123,9 @@ async fn test_automate_idempotent_tag_and_release_both_exist() {
         .await
         .unwrap();
 
-    let AutomatorResult::Created { release } = result;
+    let AutomatorResult::AlreadyReleased { release } = result else {
+        panic!("Expected the already-released result");
+    };
     assert_eq!(
         release.id, 42,
         "Expected the pre-existing release to be returned"
@@ -994,6 +1145,82 @@ async fn test_automate_idempotent_tag_and_release_both_exist() {
     );
 }
 
+#[tokio::test]
+async fn test_automate_existing_release_skips_tag_and_release_creation() {
+    let github = TestGitHub::new()
+        .with_release_for_tag("v1.2.3", stub_release(42, "v1.2.3", false))
+        .await;
+    let automator = ReleaseAutomator::new(AutomatorConfig::default(), &github);
+
+    let event = make_release_pr_event(
+        "release/v1.2.3",
+        "deadbeef1234567890deadbeef1234567890abcd",
+        "",
+    );
+
+    let result = automator
+        .automate("testorg", "testrepo", &event, "corr-001")
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        result,
+        AutomatorResult::AlreadyReleased { ref release } if release.id == 42
+    ));
+    assert_eq!(result.release().id, 42);
+    assert!(
+        github.created_tags().await.is_empty(),
+        "No tag should be created when the release already exists"
+    );
+    assert!(github.created_releases().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_automate_missing_release_is_looked_up_then_created() {
+    let github = TestGitHub::new();
+    let automator = ReleaseAutomator::new(AutomatorConfig::default(), &github);
+
+    let event = make_release_pr_event(
+        "release/v1.2.3",
+        "deadbeef1234567890deadbeef1234567890abcd",
+        "",
+    );
+
+    let result = automator
+        .automate("testorg", "testrepo", &event, "corr-001")
+        .await
+        .unwrap();
+
+    assert!(matches!(result, AutomatorResult::Created { .. }));
+    assert_eq!(github.release_listings().await, 1);
+    assert_eq!(github.created_tags().await.len(), 1);
+    assert_eq!(github.created_releases().await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_automate_release_lookup_failure_propagates_without_creating() {
+    let github = TestGitHub::new().with_get_release_error().await;
+    let automator = ReleaseAutomator::new(AutomatorConfig::default(), &github);
+
+    let event = make_release_pr_event(
+        "release/v1.2.3",
+        "deadbeef1234567890deadbeef1234567890abcd",
+        "",
+    );
+
+    let err = automator
+        .automate("testorg", "testrepo", &event, "corr-001")
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, CoreError::Network { .. }),
+        "A failed lookup must not be treated as a missing release, got: {err:?}"
+    );
+    assert!(github.created_tags().await.is_empty());
+    assert!(github.created_releases().await.is_empty());
+}
+
 #[tokio::test]
 async fn test_automate_tag_exists_but_release_missing_resumes() {
     // Tag exists but release does not — automator should create the release.
@@ -1011,7 +1238,9 @@ async fn test_automate_tag_exists_but_release_missing_resumes() {
         .await
         .unwrap();
 
-    let AutomatorResult::Created { release } = result;
+    let AutomatorResult::Created { release } = result else {
+        panic!("Expected a newly created release");
+    };
     assert_eq!(release.tag_name, "v1.2.3");
 
     let releases = github.created_releases().await;
@@ -1048,6 +1277,153 @@ async fn test_automate_github_api_failure_on_tag_creation_propagates() {
     );
 }
 
+/// Signer that records the payloads it signs instead of running a tool.
+#[derive(Debug, Default)]
+struct RecordingSigner {
+    payloads: std::sync::Mutex<Vec<String>>,
+    fail: bool,
+}
+
+#[async_trait]
+impl TagSigner for RecordingSigner {
+    async fn sign(&self, payload: &[u8]) -> CoreResult<String> {
+        if self.fail {
+            return Err(CoreError::config("simulated signing failure"));
+        }
+        self.payloads
+            .lock()
+            .unwrap()
+            .push(String::from_utf8(payload.to_vec()).unwrap());
+        Ok("-----BEGIN SSH SIGNATURE-----\nAAAA\n-----END SSH SIGNATURE-----\n".to_string())
+    }
+}
+
+fn signing_config(signer: Arc<RecordingSigner>) -> AutomatorConfig {
+    AutomatorConfig {
+        tag_signing: Some(TagSigning {
+            signer,
+            tagger: GitHubUser {
+                name: "Release Regent".to_string(),
+                email: "releases@example.com".to_string(),
+                login: None,
+            },
+        }),
+        ..AutomatorConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn test_automate_without_tag_signing_creates_unsigned_tag() {
+    let github = TestGitHub::new();
+    let automator = ReleaseAutomator::new(AutomatorConfig::default(), &github);
+
+    let event = make_release_pr_event(
+        "release/v1.2.3",
+        "deadbeef1234567890deadbeef1234567890abcd",
+        "",
+    );
+
+    automator
+        .automate("testorg", "testrepo", &event, "corr-001")
+        .await
+        .unwrap();
+
+    assert_eq!(github.created_tags().await.len(), 1);
+    assert!(github.signed_tags().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_automate_with_tag_signing_creates_signed_tag() {
+    let github = TestGitHub::new();
+    let signer = Arc::new(RecordingSigner::default());
+    let automator = ReleaseAutomator::new(signing_config(signer.clone()), &github);
+
+    let event = make_release_pr_event(
+        "release/v1.2.3",
+        "deadbeef1234567890deadbeef1234567890abcd",
+        "",
+    );
+
+    let result = automator
+        .automate("testorg", "testrepo", &event, "corr-001")
+        .await
+        .unwrap();
+
+    assert!(matches!(result, AutomatorResult::Created { .. }));
+    assert!(github.created_tags().await.is_empty());
+
+    let signed = github.signed_tags().await;
+    assert_eq!(signed.len(), 1);
+    let tag = &signed[0];
+    assert_eq!(tag.tag_name, "v1.2.3");
+    assert_eq!(tag.commit_sha, "deadbeef1234567890deadbeef1234567890abcd");
+    assert_eq!(tag.message, "Release v1.2.3");
+    assert_eq!(tag.tagger.email, "releases@example.com");
+    assert_eq!(tag.tagged_at.timestamp_subsec_nanos(), 0);
+    assert!(tag.signature.starts_with("-----BEGIN SSH SIGNATURE-----"));
+
+    // The signature covers exactly the tag object GitHub will store.
+    let payloads = signer.payloads.lock().unwrap().clone();
+    assert_eq!(
+        payloads,
+        vec![crate::tag_signing::tag_payload(
+            &tag.tag_name,
+            &tag.commit_sha,
+            &tag.tagger,
+            tag.tagged_at,
+            &tag.message,
+        )]
+    );
+    assert_eq!(github.created_releases().await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_automate_signed_tag_exists_but_release_missing_resumes() {
+    let github = TestGitHub::new().with_tag_conflict().await;
+    let signer = Arc::new(RecordingSigner::default());
+    let automator = ReleaseAutomator::new(signing_config(signer), &github);
+
+    let event = make_release_pr_event(
+        "release/v1.2.3",
+        "deadbeef1234567890deadbeef1234567890abcd",
+        "",
+    );
+
+    let result = automator
+        .automate("testorg", "testrepo", &event, "corr-001")
+        .await
+        .unwrap();
+
+    assert!(matches!(result, AutomatorResult::Created { .. }));
+    assert_eq!(github.created_releases().await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_automate_signing_failure_propagates_without_creating() {
+    let github = TestGitHub::new();
+    let signer = Arc::new(RecordingSigner {
+        fail: true,
+        ..RecordingSigner::default()
+    });
+    let automator = ReleaseAutomator::new(signing_config(signer), &github);
+
+    let event = make_release_pr_event(
+        "release/v1.2.3",
+        "deadbeef1234567890deadbeef1234567890abcd",
+        "",
+    );
+
+    let err = automator
+        .automate("testorg", "testrepo", &event, "corr-001")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, CoreError::Config { .. }), "got: {err:?}");
+    assert!(github.signed_tags().await.is_empty());
+    assert!(github.created_tags().await.is_empty());
+    assert!(github.created_releases().await.is_empty());
+}
+
 #[tokio::test]
 async fn test_automate_github_api_failure_on_release_creation_propagates() {
     let github = TestGitHub::new().with_create_release_error().await;
@@ -1087,7 +1463,9 @@ async fn test_automate_branch_delete_failure_is_nonfatal() {
         .await
         .unwrap();
 
-    let AutomatorResult::Created { release } = result;
+    let AutomatorResult::Created { release } = result else {
+        panic!("Expected a newly created release");
+    };
     assert_eq!(release.tag_name, "v1.2.3");
 
     // Release was still created despite branch deletion failure.
@@ -1281,7 +1659,9 @@ async fn test_automate_title_fallback_when_branch_version_is_invalid() {
         .await
         .unwrap();
 
-    let AutomatorResult::Created { release } = result;
+    let AutomatorResult::Created { release } = result else {
+        panic!("Expected a newly created release");
+    };
     assert_eq!(release.tag_name, "v1.5.0");
 
     let tags = github.created_tags().await;
@@ -1308,7 +1688,9 @@ async fn test_automate_body_fallback_when_branch_and_title_lack_version() {
         .await
         .unwrap();
 
-    let AutomatorResult::Created { release } = result;
+    let AutomatorResult::Created { release } = result else {
+        panic!("Expected a newly created release");
+    };
     assert_eq!(release.tag_name, "v2.3.4");
 
     let tags = github.created_tags().await;
@@ -1367,7 +1749,9 @@ async fn test_automate_empty_version_prefix_creates_tag_without_v() {
         .await
         .unwrap();
 
-    let AutomatorResult::Created { release } = result;
+    let AutomatorResult::Created { release } = result else {
+        panic!("Expected a newly created release");
+    };
     assert_eq!(release.tag_name, "1.2.3", "tag must have no v prefix");
 
     let tags = github.created_tags().await;
@@ -1375,6 +1759,36 @@ async fn test_automate_empty_version_prefix_creates_tag_without_v() {
     assert_eq!(tags[0].0, "1.2.3", "created tag name must have no v prefix");
 }
 
+#[tokio::test]
+async fn test_automate_reads_version_from_templated_branch() {
+    let github = TestGitHub::new();
+    let config = AutomatorConfig {
+        branch_template: Some("release-regent/{target_branch}/{version}".to_string()),
+        ..AutomatorConfig::default()
+    };
+    let automator = ReleaseAutomator::new(config, &github);
+
+    let event = make_release_pr_event(
+        "release-regent/main/1.4.0",
+        "deadbeef1234567890deadbeef1234567890abcd",
+        "## Changelog\n\n- feat: add widget [abc123def456789012345678901234567890abcd]\n",
+    );
+
+    let result = automator
+        .automate("testorg", "testrepo", &event, "corr-001")
+        .await
+        .unwrap();
+
+    let AutomatorResult::Created { release } = result else {
+        panic!("Expected a newly created release");
+    };
+    assert_eq!(release.tag_name, "v1.4.0");
+    assert_eq!(
+        github.deleted_branches().await,
+        vec!["release-regent/main/1.4.0".to_string()]
+    );
+}
+
 #[tokio::test]
 async fn test_automate_custom_version_prefix_creates_tag_with_prefix() {
     // When version_prefix = "release-", the created tag must be "release-1.2.3".
@@ -1397,7 +1811,9 @@ async fn test_automate_custom_version_prefix_creates_tag_with_prefix() {
         .await
         .unwrap();
 
-    let AutomatorResult::Created { release } = result;
+    let AutomatorResult::Created { release } = result else {
+        panic!("Expected a newly created release");
+    };
     assert_eq!(
         release.tag_name, "release-1.2.3",
         "tag must use the configured prefix"
@@ -1472,6 +1888,175 @@ async fn test_automate_generate_release_notes_false_clears_flag_in_api_call() {
     );
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Draft and prerelease config tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_automate_create_as_draft_passes_draft_flag_to_create_release() {
+    let github = TestGitHub::new();
+    let config = AutomatorConfig {
+        create_as_draft: true,
+        ..AutomatorConfig::default()
+    };
+    let automator = ReleaseAutomator::new(config, &github);
+    let event = make_release_pr_event(
+        "release/v1.2.3",
+        "deadbeef1234567890deadbeef1234567890abcd",
+        "",
+    );
+
+    let AutomatorResult::Created { release } = automator
+        .automate("testorg", "testrepo", &event, "corr-001")
+        .await
+        .unwrap()
+    else {
+        panic!("Expected a newly created release");
+    };
+
+    assert!(release.draft);
+    let releases = github.created_releases().await;
+    assert_eq!(releases.len(), 1);
+    assert!(
+        releases[0].draft,
+        "draft flag must be passed to create_release"
+    );
+}
+
+#[tokio::test]
+async fn test_automate_retry_with_draft_release_does_not_create_a_second_release() {
+    let github = TestGitHub::new();
+    let config = AutomatorConfig {
+        create_as_draft: true,
+        ..AutomatorConfig::default()
+    };
+    let automator = ReleaseAutomator::new(config, &github);
+    let event = make_release_pr_event(
+        "release/v1.2.3",
+        "deadbeef1234567890deadbeef1234567890abcd",
+        "",
+    );
+
+    let first = automator
+        .automate("testorg", "testrepo", &event, "corr-001")
+        .await
+        .unwrap();
+    // The tag lookup hides drafts, so only the release list can find it.
+    assert!(github
+        .get_release_by_tag("testorg", "testrepo", "v1.2.3")
+        .await
+        .unwrap()
+        .is_none());
+    let retry = automator
+        .automate("testorg", "testrepo", &event, "corr-001")
+        .await
+        .unwrap();
+
+    let AutomatorResult::Created { release: created } = first else {
+        panic!("Expected a newly created release");
+    };
+    let AutomatorResult::AlreadyReleased { release: existing } = retry else {
+        panic!("Expected the retry to find the draft release");
+    };
+    assert_eq!(existing.id, created.id);
+    assert_eq!(github.created_releases().await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_automate_default_config_creates_published_release() {
+    let github = TestGitHub::new();
+    let automator = ReleaseAutomator::new(AutomatorConfig::default(), &github);
+    let event = make_release_pr_event(
+        "release/v1.2.3",
+        "deadbeef1234567890deadbeef1234567890abcd",
+        "",
+    );
+
+    automator
+        .automate("testorg", "testrepo", &event, "corr-001")
+        .await
+        .unwrap();
+
+    let releases = github.created_releases().await;
+    assert_eq!(releases.len(), 1);
+    assert!(!releases[0].draft);
+}
+
+#[tokio::test]
+async fn test_automate_prerelease_config_marks_stable_version_as_prerelease() {
+    let github = TestGitHub::new();
+    let config = AutomatorConfig {
+        prerelease: true,
+        ..AutomatorConfig::default()
+    };
+    let automator = ReleaseAutomator::new(config, &github);
+    let event = make_release_pr_event(
+        "release/v2.0.0",
+        "deadbeef1234567890deadbeef1234567890abcd",
+        "",
+    );
+
+    automator
+        .automate("testorg", "testrepo", &event, "corr-001")
+        .await
+        .unwrap();
+
+    let releases = github.created_releases().await;
+    assert_eq!(releases.len(), 1);
+    assert!(releases[0].prerelease);
+}
+
+#[tokio::test]
+async fn test_publish_draft_promotes_draft_release() {
+    let draft = Release {
+        draft: true,
+        ..stub_release(7, "v1.2.3", false)
+    };
+    let github = TestGitHub::new()
+        .with_release_for_tag("v1.2.3", draft)
+        .await;
+    let automator = ReleaseAutomator::new(AutomatorConfig::default(), &github);
+
+    let release = automator
+        .publish_draft("testorg", "testrepo", "v1.2.3")
+        .await
+        .unwrap();
+
+    assert!(!release.draft);
+    let updates = github.updated_releases().await;
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].0, 7);
+    assert_eq!(updates[0].1.draft, Some(false));
+}
+
+#[tokio::test]
+async fn test_publish_draft_already_published_is_noop() {
+    let github = TestGitHub::new()
+        .with_release_for_tag("v1.2.3", stub_release(7, "v1.2.3", false))
+        .await;
+    let automator = ReleaseAutomator::new(AutomatorConfig::default(), &github);
+
+    let release = automator
+        .publish_draft("testorg", "testrepo", "v1.2.3")
+        .await
+        .unwrap();
+
+    assert!(!release.draft);
+    assert!(github.updated_releases().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_publish_draft_missing_release_returns_not_found() {
+    let github = TestGitHub::new();
+    let automator = ReleaseAutomator::new(AutomatorConfig::default(), &github);
+
+    let result = automator
+        .publish_draft("testorg", "testrepo", "v9.9.9")
+        .await;
+
+    assert!(matches!(result, Err(CoreError::NotFound { .. })));
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Property-based tests
 //
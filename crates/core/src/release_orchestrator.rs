@@ -7,7 +7,8 @@
 //! ## Responsibilities
 //!
 //! 1. **Search** for an existing open release PR whose head branch matches the
-//!    `release/v*` pattern.
+//!    `release/v*` pattern, or the prefix of the configured
+//!    [`branch_template`](OrchestratorConfig::branch_template).
 //! 2. **Decide** what action to take based on the relationship between the
 //!    existing PR's version (if any) and the newly calculated version:
 //!
@@ -18,7 +19,9 @@
 //! | Yes            | Equal to new            | Merge changelogs only     |
 //! | Yes            | Higher than new         | No-op (never downgrade)   |
 //!
-//! 3. **Idempotency**: Every sub-operation is safe to retry without side effects.
+//! 3. **Label** the created, updated, or renamed release PR with the configured
+//!    [`labels`](OrchestratorConfig::labels).
+//! 4. **Idempotency**: Every sub-operation is safe to retry without side effects.
 //!    If the canonical release branch already exists (e.g. from a previous run that
 //!    created the branch but failed before opening the PR), the orchestrator reuses
 //!    it rather than failing.
@@ -47,20 +50,30 @@
 //! ```
 
 use crate::{
+    branch_template::{self, BranchNameVars},
+    changelog::{format_changelog_date, DEFAULT_DATE_FORMAT},
+    clock::{system_clock, Clock},
     manifest::ManifestFileConfig,
     traits::github_operations::{
-        CreatePullRequestParams, FileUpdate, GitHubOperations, PullRequest,
+        BranchUpsert, CreatePullRequestParams, FileUpdate, GitHubOperations, LabelMode, PullRequest,
     },
     versioning::SemanticVersion,
     CoreError, CoreResult,
 };
-use chrono::Utc;
+use std::sync::Arc;
 use tracing::{debug, info, warn};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Public types
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Commit status context under which the planned release version is reported
+/// on the head commit of the release PR.
+pub const VERSION_STATUS_CONTEXT: &str = "release-regent/version";
+
+/// Maximum length GitHub accepts for a commit status description.
+pub const MAX_STATUS_DESCRIPTION_LEN: usize = 140;
+
 /// Configuration for the [`ReleaseOrchestrator`].
 #[derive(Debug, Clone)]
 pub struct OrchestratorConfig {
@@ -133,6 +146,32 @@ pub struct OrchestratorConfig {
     ///
     /// Set to `false` to disable auto-detection and rely solely on the explicit list.
     pub auto_detect_manifests: bool,
+
+    /// Template for release branch names, e.g.
+    /// `release-regent/{target_branch}/{version_tag}`; see
+    /// [`crate::branch_template`].
+    ///
+    /// Defaults to `None`: branches are named `{branch_prefix}/{version_tag}`.
+    pub branch_template: Option<String>,
+
+    /// Labels applied to the release PR each time it is created, updated, or
+    /// renamed.
+    ///
+    /// Defaults to an empty list (labels are left untouched).
+    pub labels: Vec<String>,
+
+    /// Whether [`Self::labels`] are added to the labels already on the release
+    /// PR or replace them.
+    ///
+    /// Defaults to [`LabelMode::Add`].
+    pub label_mode: LabelMode,
+
+    /// strftime-style format of the release date in the version headers of
+    /// `CHANGELOG.md` and package changelogs; see
+    /// [`crate::changelog::ChangelogConfig::date_format`].
+    ///
+    /// Defaults to [`DEFAULT_DATE_FORMAT`] (`2024-06-01`).
+    pub changelog_date_format: String,
 }
 
 impl OrchestratorConfig {
@@ -144,6 +183,67 @@ impl OrchestratorConfig {
 
     /// The default PR body template string.
     pub const DEFAULT_BODY_TEMPLATE: &'static str = "## Changelog\n\n${changelog}";
+
+    /// The release branch template in effect: [`Self::branch_template`], or
+    /// `{branch_prefix}/{version_tag}` when it is unset.
+    #[must_use]
+    pub fn effective_branch_template(&self) -> String {
+        self.branch_template
+            .clone()
+            .unwrap_or_else(|| format!("{}/{{version_tag}}", self.branch_prefix))
+    }
+
+    /// The part of the release branch names of `owner/repo` that precedes the
+    /// version, e.g. `"release/v"` with the default config.
+    ///
+    /// # Errors
+    /// - `CoreError::InvalidInput` — the branch template is malformed
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large
+    pub fn release_branch_prefix(
+        &self,
+        owner: &str,
+        repo: &str,
+        target_branch: &str,
+    ) -> CoreResult<String> {
+        branch_template::release_branch_prefix(
+            &self.effective_branch_template(),
+            &self.branch_name_vars(owner, repo, target_branch),
+        )
+    }
+
+    /// Returns `true` if `branch` is a release branch of `owner/repo` for
+    /// `target_branch`; see [`branch_template::is_release_branch`].
+    ///
+    /// # Errors
+    /// - `CoreError::InvalidInput` — the branch template is malformed
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large
+    pub fn is_release_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        target_branch: &str,
+        branch: &str,
+    ) -> CoreResult<bool> {
+        branch_template::is_release_branch(
+            &self.effective_branch_template(),
+            &self.branch_name_vars(owner, repo, target_branch),
+            branch,
+        )
+    }
+
+    fn branch_name_vars<'v>(
+        &'v self,
+        owner: &'v str,
+        repo: &'v str,
+        target_branch: &'v str,
+    ) -> BranchNameVars<'v> {
+        BranchNameVars {
+            owner,
+            repo,
+            target_branch,
+            version_prefix: &self.version_prefix,
+        }
+    }
 }
 
 /// Derive the changelog-section header from a PR body template.
@@ -214,6 +314,61 @@ pub fn extract_previous_version_sentinel(body: &str) -> Option<String> {
         .map(str::to_owned)
 }
 
+/// Extract the release version embedded by [`ReleaseOrchestrator::render_body`].
+///
+/// `render_body` appends `<!-- release-regent: version=VALUE -->` to every
+/// rendered PR body, so the version of a release PR whose branch name carries
+/// none (e.g. `release/next`) can be read back.
+///
+/// # Examples
+///
+/// ```
+/// use release_regent_core::release_orchestrator::extract_version_sentinel;
+///
+/// let body = "## Changelog\n<!-- release-regent: version=1.3.0 -->";
+/// assert_eq!(extract_version_sentinel(body), Some("1.3.0".to_string()));
+/// ```
+#[must_use]
+pub fn extract_version_sentinel(body: &str) -> Option<String> {
+    const PREFIX: &str = "<!-- release-regent: version=";
+    const SUFFIX: &str = " -->";
+    body.lines()
+        .find_map(|line| {
+            line.strip_prefix(PREFIX)
+                .and_then(|rest| rest.strip_suffix(SUFFIX))
+        })
+        .map(str::to_owned)
+}
+
+/// Extract the package release tags embedded by [`ReleaseOrchestrator::render_body`].
+///
+/// `render_body` appends one `<!-- release-regent: package-tag=TAG -->` comment
+/// per monorepo package the release bumps, so the release automator can tag
+/// and release each package when the release PR merges.
+///
+/// # Examples
+///
+/// ```
+/// use release_regent_core::release_orchestrator::extract_package_tag_sentinels;
+///
+/// let body = "## Changelog\n<!-- release-regent: package-tag=core-v1.3.0 -->\n\
+///             <!-- release-regent: package-tag=cli-v0.2.0 -->";
+/// assert_eq!(extract_package_tag_sentinels(body), vec!["core-v1.3.0", "cli-v0.2.0"]);
+/// ```
+#[must_use]
+pub fn extract_package_tag_sentinels(body: &str) -> Vec<String> {
+    const PREFIX: &str = "<!-- release-regent: package-tag=";
+    const SUFFIX: &str = " -->";
+    body.lines()
+        .filter_map(|line| {
+            line.trim()
+                .strip_prefix(PREFIX)
+                .and_then(|rest| rest.strip_suffix(SUFFIX))
+        })
+        .map(str::to_owned)
+        .collect()
+}
+
 /// Remove all `<!-- release-regent: … -->` sentinel lines from a changelog string.
 ///
 /// `render_body` appends a sentinel comment to the PR body so that the update
@@ -242,6 +397,10 @@ impl Default for OrchestratorConfig {
             body_template,
             manifest_files: Vec::new(),
             auto_detect_manifests: true,
+            branch_template: None,
+            labels: Vec::new(),
+            label_mode: LabelMode::Add,
+            changelog_date_format: DEFAULT_DATE_FORMAT.to_string(),
         }
     }
 }
@@ -265,7 +424,8 @@ pub enum OrchestratorResult {
     },
 
     /// An existing PR with a lower version was renamed to the new version and
-    /// its body was replaced.
+    /// its body was replaced. When the branch name carries no version the PR
+    /// is moved to the new version in place instead.
     Renamed {
         /// The updated pull request.
         pr: PullRequest,
@@ -285,7 +445,11 @@ pub enum OrchestratorResult {
     /// No release branch or PR was created or modified.  This is the expected
     /// outcome when a `chore:`, `docs:`, or other non-bumping PR is merged
     /// immediately after a release.
-    NoBumpNeeded,
+    NoBumpNeeded {
+        /// Why no release is needed, e.g. that there are no version-bumping
+        /// commits since the last release.
+        reason: String,
+    },
 
     /// A merged pull request was identified as a release PR by the
     /// [`crate::ReleaseRegentProcessor`] even though it arrived via the
@@ -307,6 +471,18 @@ pub enum OrchestratorResult {
     /// the `unreachable!()` guards that exhaustive matches on
     /// `OrchestratorResult` must carry for this variant.
     TaggedRelease,
+
+    /// The pull request was merged into a branch other than the configured
+    /// `releases.target_branch`, so it does not take part in the release.
+    ///
+    /// Like [`Self::NoBumpNeeded`], this is returned by the
+    /// [`crate::ReleaseRegentProcessor`] before the orchestrator is called.
+    NotReleaseTarget {
+        /// Branch the pull request was merged into.
+        base_branch: String,
+        /// Branch releases are cut from.
+        target_branch: String,
+    },
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -350,6 +526,23 @@ struct BodyRenderContext<'a> {
 pub struct ReleaseOrchestrator<'a, G: GitHubOperations> {
     config: OrchestratorConfig,
     github: &'a G,
+    package_changelogs: Vec<PackageChangelog>,
+    package_tags: Vec<String>,
+    clock: Arc<dyn Clock>,
+}
+
+/// The changelog section of one monorepo package, written to the package's
+/// own changelog file alongside the repository `CHANGELOG.md`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageChangelog {
+    /// Package name
+    pub package: String,
+    /// Path of the package's changelog file, relative to the repository root
+    pub path: String,
+    /// The package's next version, used as the section heading
+    pub version: SemanticVersion,
+    /// Formatted changelog entries of the commits that touch the package
+    pub changelog: String,
 }
 
 impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
@@ -359,7 +552,43 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
     /// - `config`: Orchestration configuration (branch prefix, templates, …)
     /// - `github`: A reference to the `GitHubOperations` implementation to use
     pub fn new(config: OrchestratorConfig, github: &'a G) -> Self {
-        Self { config, github }
+        Self {
+            config,
+            github,
+            package_changelogs: Vec::new(),
+            package_tags: Vec::new(),
+            clock: system_clock(),
+        }
+    }
+
+    /// Date release PR bodies and changelog version headers with `clock`
+    /// instead of the system clock.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Also prepend each of `package_changelogs` to its package's changelog
+    /// file in the release commit.
+    #[must_use]
+    pub fn with_package_changelogs(mut self, package_changelogs: Vec<PackageChangelog>) -> Self {
+        self.package_changelogs = package_changelogs;
+        self
+    }
+
+    /// Record the release tags of the monorepo packages this release bumps
+    /// in the PR body, so they are tagged when the release PR merges.
+    #[must_use]
+    pub fn with_package_tags(mut self, package_tags: Vec<String>) -> Self {
+        self.package_tags = package_tags;
+        self
+    }
+
+    /// Version prefix prepended to the semver in branch names and PR titles.
+    #[must_use]
+    pub fn version_prefix(&self) -> &str {
+        &self.config.version_prefix
     }
 
     // ── Public API ─────────────────────────────────────────────────────────
@@ -398,9 +627,11 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
     ) -> CoreResult<OrchestratorResult> {
         info!(owner, repo, version = %version, correlation_id, "Starting release orchestration");
 
-        let existing = self.search_for_existing_release_pr(owner, repo).await?;
+        let existing = self
+            .search_for_existing_release_pr(owner, repo, base_branch)
+            .await?;
 
-        match existing {
+        let result = match existing {
             None => {
                 debug!("No existing release PR found; creating new one");
                 let (pr, branch_name) = self
@@ -415,7 +646,7 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
                         None,
                     )
                     .await?;
-                Ok(OrchestratorResult::Created { pr, branch_name })
+                OrchestratorResult::Created { pr, branch_name }
             }
             Some((existing_pr, existing_version)) => {
                 use std::cmp::Ordering;
@@ -444,7 +675,37 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
                                 previous_version,
                             )
                             .await?;
-                        Ok(OrchestratorResult::Updated { pr })
+                        OrchestratorResult::Updated { pr }
+                    }
+                    Ordering::Less
+                        if self.make_branch_name(owner, repo, base_branch, version)?
+                            == existing_pr.head.ref_name =>
+                    {
+                        // The branch name carries no version (e.g. `release/next`),
+                        // so the PR is moved to the new version in place.
+                        debug!(
+                            pr_number = existing_pr.number,
+                            existing = %existing_version,
+                            new = %version,
+                            "Existing PR has lower version; updating it in place"
+                        );
+                        let previous_version = existing_pr
+                            .body
+                            .as_deref()
+                            .and_then(extract_previous_version_sentinel);
+                        let pr = self
+                            .update_release_pr(
+                                owner,
+                                repo,
+                                &existing_pr,
+                                version,
+                                changelog,
+                                base_sha,
+                                correlation_id,
+                                previous_version,
+                            )
+                            .await?;
+                        OrchestratorResult::Renamed { pr }
                     }
                     Ordering::Less => {
                         // existing version < new version → rename & update
@@ -467,7 +728,7 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
                                 Some(existing_version.to_string()),
                             )
                             .await?;
-                        Ok(OrchestratorResult::Renamed { pr })
+                        OrchestratorResult::Renamed { pr }
                     }
                     Ordering::Greater => {
                         // existing version > new version → no-op
@@ -477,17 +738,49 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
                             new = %version,
                             "Existing PR version is higher; skipping"
                         );
-                        Ok(OrchestratorResult::NoOp { pr: existing_pr })
+                        OrchestratorResult::NoOp { pr: existing_pr }
                     }
                 }
             }
+        };
+
+        if let OrchestratorResult::Created { pr, .. }
+        | OrchestratorResult::Updated { pr }
+        | OrchestratorResult::Renamed { pr } = &result
+        {
+            if let Err(e) = self.apply_labels(owner, repo, pr.number).await {
+                warn!(
+                    error = %e,
+                    release_pr = pr.number,
+                    correlation_id = %correlation_id,
+                    "Failed to label release PR; continuing"
+                );
+            }
         }
+        Ok(result)
     }
 
     // ── Private helpers ────────────────────────────────────────────────────
 
+    /// Apply the configured labels to the release PR; a no-op when none are
+    /// configured.
+    ///
+    /// The caller treats a failure as non-fatal: the release PR already
+    /// exists, and the labels are applied again on its next update.
+    async fn apply_labels(&self, owner: &str, repo: &str, pr_number: u64) -> CoreResult<()> {
+        if self.config.labels.is_empty() {
+            return Ok(());
+        }
+        let labels: Vec<&str> = self.config.labels.iter().map(String::as_str).collect();
+        debug!(pr_number, ?labels, mode = ?self.config.label_mode, "Labelling release PR");
+        self.github
+            .set_pull_request_labels(owner, repo, pr_number, &labels, self.config.label_mode)
+            .await
+    }
+
     /// Search the repository for an open release PR whose head branch starts
-    /// with the configured `{branch_prefix}/{version_prefix}` pattern.
+    /// with the release branch prefix, `{branch_prefix}/{version_prefix}` by
+    /// default.
     ///
     /// Returns `None` when no matching PR exists, or the PR together with the
     /// parsed `SemanticVersion` extracted from its branch name.
@@ -495,8 +788,14 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
         &self,
         owner: &str,
         repo: &str,
+        base_branch: &str,
     ) -> CoreResult<Option<(PullRequest, SemanticVersion)>> {
-        let query = format!("is:open head:{}*", self.release_branch_prefix());
+        let template = self.config.effective_branch_template();
+        let vars = self.config.branch_name_vars(owner, repo, base_branch);
+        let query = format!(
+            "is:open head:{}*",
+            branch_template::release_branch_prefix(&template, &vars)?
+        );
         let prs = self
             .github
             .search_pull_requests(owner, repo, &query)
@@ -513,8 +812,15 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
         let best = prs
             .into_iter()
             .filter_map(|pr| {
-                self.parse_version_from_branch(&pr.head.ref_name)
-                    .map(|version| (pr, version))
+                branch_template::version_from_release_pr(
+                    &template,
+                    &vars,
+                    &pr.head.ref_name,
+                    &pr.title,
+                    pr.body.as_deref().unwrap_or(""),
+                )
+                .ok()
+                .map(|version| (pr, version))
             })
             .max_by(|(_, va), (_, vb)| va.compare_precedence(vb));
 
@@ -523,8 +829,11 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
 
     /// Create a new release branch and pull request.
     ///
-    /// On a `CoreError::Conflict` (branch already exists) the method retries once
-    /// with a timestamped fallback branch name.
+    /// The release branch is brought to `base_sha` with
+    /// [`GitHubOperations::upsert_branch`]: it is created when missing and
+    /// fast-forwarded when a previous run left it behind the base branch.  A
+    /// branch that still carries an earlier release commit is not a
+    /// fast-forward and is reused as-is.
     ///
     /// All file changes (CHANGELOG.md plus any version manifest files) are committed
     /// via [`GitHubOperations::batch_commit_files_rebased`] with `base_sha` as the
@@ -543,18 +852,27 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
         correlation_id: &str,
         previous_version: Option<String>,
     ) -> CoreResult<(PullRequest, String)> {
-        let branch_name = self.make_branch_name(version);
+        let branch_name = self.make_branch_name(owner, repo, base_branch, version)?;
+        let now = self.clock.now();
+        let today = format_changelog_date(&self.config.changelog_date_format, now)?;
 
         let actual_branch = match self
             .github
-            .create_branch(owner, repo, &branch_name, base_sha)
+            .upsert_branch(owner, repo, &branch_name, base_sha)
             .await
         {
-            Ok(()) => branch_name,
-            Err(CoreError::Conflict { .. }) => {
-                // The branch already exists — most likely from a previous run that
-                // created the branch but failed before opening the PR, or because an
-                // open release PR was active when this orchestration ran.
+            Ok(BranchUpsert::Created) => branch_name,
+            Ok(BranchUpsert::Updated) => {
+                info!(
+                    branch = %branch_name,
+                    "Release branch already existed; fast-forwarded it to the base branch"
+                );
+                branch_name
+            }
+            Err(CoreError::NotFastForward { .. }) => {
+                // The branch already exists with commits of its own — most likely
+                // the release commit of a previous run that failed before opening
+                // the PR, or of an open release PR active when this orchestration ran.
                 //
                 // Do NOT call `force_update_branch(base_sha)` here: that would
                 // temporarily set the branch tip equal to `base_sha`, making head
@@ -575,7 +893,7 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
         let body = self.render_body(&BodyRenderContext {
             changelog,
             version,
-            date: Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            date: now.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
             correlation_id,
             previous_version,
             repository: format!("{owner}/{repo}"),
@@ -594,7 +912,6 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
             })
             .unwrap_or_default();
 
-        let today = Utc::now().format("%Y-%m-%d").to_string();
         let changelog_file_content = build_changelog_file_content(
             &existing_changelog_file,
             &version.to_string(),
@@ -602,11 +919,14 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
             changelog,
         );
 
-        // Build the batch of files: CHANGELOG.md plus any manifest files.
+        // Build the batch of files: CHANGELOG.md, package changelogs and any
+        // manifest files.
         let mut file_updates = vec![FileUpdate {
             path: "CHANGELOG.md".to_string(),
             content: changelog_file_content,
         }];
+        self.collect_package_changelog_updates(owner, repo, base_branch, &today, &mut file_updates)
+            .await;
         // Read manifests from the base branch so that the conflict/recovery case
         // (branch already exists from a prior failed run) gets fresh content instead
         // of whatever stale versions were left on the old release branch head.
@@ -666,6 +986,7 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
     ///
     /// Performs a fresh `get_pull_request` before the update so we operate on
     /// current data (prep for future `ETag` enforcement).
+    #[allow(clippy::too_many_arguments)]
     async fn update_release_pr(
         &self,
         owner: &str,
@@ -677,6 +998,9 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
         correlation_id: &str,
         previous_version: Option<String>,
     ) -> CoreResult<PullRequest> {
+        let now = self.clock.now();
+        let today = format_changelog_date(&self.config.changelog_date_format, now)?;
+
         // Always re-fetch to get the latest body (ETag prep).
         let fresh_pr = self
             .github
@@ -693,7 +1017,7 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
         let new_body = self.render_body(&BodyRenderContext {
             changelog: &clean_changelog,
             version,
-            date: Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            date: now.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
             correlation_id,
             previous_version,
             repository: format!("{owner}/{repo}"),
@@ -737,7 +1061,6 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
             })
             .unwrap_or_default();
 
-        let today = Utc::now().format("%Y-%m-%d").to_string();
         let changelog_file_content = build_changelog_file_content(
             &existing_changelog_file,
             &version.to_string(),
@@ -745,11 +1068,20 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
             &merged_changelog,
         );
 
-        // Build batch: CHANGELOG.md plus manifest files — all in one atomic commit.
+        // Build batch: CHANGELOG.md, package changelogs and manifest files —
+        // all in one atomic commit.
         let mut file_updates = vec![FileUpdate {
             path: "CHANGELOG.md".to_string(),
             content: changelog_file_content,
         }];
+        self.collect_package_changelog_updates(
+            owner,
+            repo,
+            &fresh_pr.base.ref_name,
+            &today,
+            &mut file_updates,
+        )
+        .await;
         // Read manifests from the *base* branch (e.g. master), not the release
         // branch head.  The release branch head contains the bot's own previous
         // output, which may have stale dependency versions from an earlier rebase.
@@ -876,24 +1208,69 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
 
     // ── Naming helpers ─────────────────────────────────────────────────────
 
-    /// Returns the head-branch query prefix, e.g. `"release/v"` with the default config,
-    /// or `"release/"` when `version_prefix` is empty.
-    fn release_branch_prefix(&self) -> String {
-        format!(
-            "{}/{}",
-            self.config.branch_prefix, self.config.version_prefix
-        )
-    }
-
     /// Construct the canonical release branch name, e.g. `"release/v1.2.3"` with
     /// the default config, or `"release/1.2.3"` when `version_prefix` is empty.
-    pub(crate) fn make_branch_name(&self, version: &SemanticVersion) -> String {
-        format!(
-            "{}/{}{version}",
-            self.config.branch_prefix, self.config.version_prefix,
+    ///
+    /// # Errors
+    /// - `CoreError::InvalidInput` — the branch template is malformed or renders
+    ///   an illegal branch name
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large
+    pub(crate) fn make_branch_name(
+        &self,
+        owner: &str,
+        repo: &str,
+        base_branch: &str,
+        version: &SemanticVersion,
+    ) -> CoreResult<String> {
+        branch_template::render_branch_name(
+            &self.config.effective_branch_template(),
+            &self.config.branch_name_vars(owner, repo, base_branch),
+            version,
         )
     }
 
+    // ── Package changelog helpers ─────────────────────────────────────────
+
+    /// Append a [`FileUpdate`] for every package changelog.
+    ///
+    /// Each file is read from `base_branch`, like `CHANGELOG.md`, and the
+    /// package's section is prepended to it. A file that cannot be read is
+    /// started fresh.
+    async fn collect_package_changelog_updates(
+        &self,
+        owner: &str,
+        repo: &str,
+        base_branch: &str,
+        date: &str,
+        updates: &mut Vec<FileUpdate>,
+    ) {
+        for package in &self.package_changelogs {
+            let existing = self
+                .github
+                .get_file_content(owner, repo, &package.path, base_branch)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!(
+                        error = %e,
+                        package = %package.package,
+                        path = %package.path,
+                        "Failed to fetch existing package changelog; starting fresh"
+                    );
+                    None
+                })
+                .unwrap_or_default();
+            updates.push(FileUpdate {
+                path: package.path.clone(),
+                content: build_changelog_file_content(
+                    &existing,
+                    &package.version.to_string(),
+                    date,
+                    &package.changelog,
+                ),
+            });
+        }
+    }
+
     // ── Manifest file helpers ─────────────────────────────────────────────
 
     /// Build the list of manifest [`FileUpdate`] entries for the release commit.
@@ -1180,7 +1557,9 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
     ///
     /// A hidden sentinel comment `<!-- release-regent: previous-version=… -->` is
     /// appended so that the update path can round-trip the value without fragile
-    /// text parsing of the rendered template output.
+    /// text parsing of the rendered template output, followed by the
+    /// `<!-- release-regent: version=… -->` sentinel and one
+    /// `<!-- release-regent: package-tag=… -->` comment per package tag.
     fn render_body(&self, ctx: &BodyRenderContext<'_>) -> String {
         let version_str = ctx.version.to_string();
         let version_tag_str = format!("{}{version_str}", self.config.version_prefix);
@@ -1216,7 +1595,14 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
             .replace("{changelog}", ctx.changelog);
         // Append a hidden sentinel so the update path can retrieve the
         // previous_version value without parsing the rendered template text.
-        format!("{rendered}\n<!-- release-regent: previous-version={previous_version_str} -->")
+        let mut body = format!(
+            "{rendered}\n<!-- release-regent: previous-version={previous_version_str} -->\n\
+             <!-- release-regent: version={version_str} -->"
+        );
+        for tag in &self.package_tags {
+            body.push_str(&format!("\n<!-- release-regent: package-tag={tag} -->"));
+        }
+        body
     }
 
     /// Render the PR title from the configured template.
@@ -1270,17 +1656,6 @@ impl<'a, G: GitHubOperations> ReleaseOrchestrator<'a, G> {
         let existing_changelog = self.extract_changelog_from_body(existing_body);
         merge_changelog_sections(existing_changelog, new_changelog)
     }
-
-    /// Try to parse a [`SemanticVersion`] from a branch name.
-    ///
-    /// Expects the branch to start with `{branch_prefix}/{version_prefix}` followed by a
-    /// valid semver string, e.g. `"release/v1.2.3"` when `version_prefix` is `"v"`, or
-    /// `"release/1.2.3"` when `version_prefix` is `""`.
-    fn parse_version_from_branch(&self, branch: &str) -> Option<SemanticVersion> {
-        let prefix = self.release_branch_prefix();
-        let version_str = branch.strip_prefix(&prefix)?;
-        crate::versioning::VersionCalculator::parse_version(version_str).ok()
-    }
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
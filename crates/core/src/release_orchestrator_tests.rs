@@ -5,9 +5,9 @@ use crate::{
             GetCommitsOptions, GitCommit, GitOperations, GitRepository, GitTag, ListTagsOptions,
         },
         github_operations::{
-            CreatePullRequestParams, CreateReleaseParams, FileUpdate, GitHubOperations,
-            GitUser as GitHubUser, PullRequest, PullRequestBranch, Release, Repository, Tag,
-            UpdateReleaseParams,
+            CreateCommitStatusParams, CreatePullRequestParams, CreateReleaseParams, FileUpdate,
+            GitHubOperations, GitUser as GitHubUser, LabelMode, PullRequest, PullRequestBranch,
+            Release, Repository, Tag, UpdateReleaseParams,
         },
     },
     versioning::SemanticVersion,
@@ -19,6 +19,9 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 use tracing_test::traced_test;
 
+/// Recorded `update_pull_request` call: (number, title, body, state).
+type UpdatedPr = (u64, Option<String>, Option<String>, Option<String>);
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Inline test double
 //
@@ -37,16 +40,20 @@ struct TestState {
     next_create_branch_conflict: bool,
     /// Recorded `create_branch` calls: (branch_name, sha).
     created_branches: Vec<(String, String)>,
+    /// Whether `upsert_branch` finds a stale release branch it can fast-forward.
+    stale_branch_exists: bool,
+    /// Recorded fast-forwarding `upsert_branch` calls: (branch_name, sha).
+    fast_forwarded_branches: Vec<(String, String)>,
     /// Recorded `create_pull_request` calls.
     created_prs: Vec<CreatePullRequestParams>,
     /// Recorded `update_pull_request` calls: (number, title, body, state).
-    updated_prs: Vec<(u64, Option<String>, Option<String>, Option<String>)>,
+    updated_prs: Vec<UpdatedPr>,
     /// Recorded `delete_branch` calls: branch name.
     deleted_branches: Vec<String>,
+    /// Recorded `set_pull_request_labels` calls: (number, labels, mode).
+    labeled_prs: Vec<(u64, Vec<String>, LabelMode)>,
     /// Recorded `force_update_branch` calls: (branch_name, sha).
     force_updated_branches: Vec<(String, String)>,
-    /// Recorded `upsert_file` calls: (path, branch).
-    upserted_files: Vec<(String, String)>,
     /// Recorded `batch_commit_files` calls: (branch, paths, message).
     batch_commits: Vec<(String, Vec<String>, String)>,
     /// Recorded `batch_commit_files_rebased` calls: (branch, paths, message, parent_sha).
@@ -59,6 +66,8 @@ struct TestState {
     next_pr_number: u64,
     /// Whether `search_pull_requests` should return an error.
     search_error: bool,
+    /// Whether `set_pull_request_labels` should return an error.
+    label_error: bool,
 }
 
 #[derive(Clone, Default)]
@@ -88,11 +97,23 @@ impl TestGitHub {
         self
     }
 
+    /// Make `upsert_branch` find a stale branch behind the base branch.
+    async fn with_stale_branch(self) -> Self {
+        self.state.lock().await.stale_branch_exists = true;
+        self
+    }
+
     async fn with_search_error(self) -> Self {
         self.state.lock().await.search_error = true;
         self
     }
 
+    /// Make `set_pull_request_labels` fail.
+    async fn with_label_error(self) -> Self {
+        self.state.lock().await.label_error = true;
+        self
+    }
+
     async fn with_pr_by_number(self, pr: PullRequest) -> Self {
         self.state.lock().await.pr_by_number.push(pr);
         self
@@ -106,6 +127,10 @@ impl TestGitHub {
         self.state.lock().await.created_prs.clone()
     }
 
+    async fn labeled_prs(&self) -> Vec<(u64, Vec<String>, LabelMode)> {
+        self.state.lock().await.labeled_prs.clone()
+    }
+
     async fn updated_prs(&self) -> Vec<(u64, Option<String>, Option<String>, Option<String>)> {
         self.state.lock().await.updated_prs.clone()
     }
@@ -118,10 +143,6 @@ impl TestGitHub {
         self.state.lock().await.force_updated_branches.clone()
     }
 
-    async fn upserted_files(&self) -> Vec<(String, String)> {
-        self.state.lock().await.upserted_files.clone()
-    }
-
     async fn batch_commits(&self) -> Vec<(String, Vec<String>, String)> {
         self.state.lock().await.batch_commits.clone()
     }
@@ -160,6 +181,16 @@ impl GitOperations for TestGitHub {
         Ok(vec![])
     }
 
+    async fn list_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _head: &str,
+        _options: GetCommitsOptions,
+    ) -> CoreResult<Vec<GitCommit>> {
+        Ok(vec![])
+    }
+
     async fn get_commit(&self, _owner: &str, _repo: &str, _sha: &str) -> CoreResult<GitCommit> {
         Err(CoreError::not_found("stub"))
     }
@@ -256,6 +287,15 @@ impl GitHubOperations for TestGitHub {
         Err(CoreError::not_supported("create_tag", "stub"))
     }
 
+    async fn create_signed_tag(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _params: crate::traits::github_operations::SignedTagParams,
+    ) -> CoreResult<Tag> {
+        Err(CoreError::not_supported("create_signed_tag", "stub"))
+    }
+
     async fn get_latest_release(&self, _owner: &str, _repo: &str) -> CoreResult<Option<Release>> {
         Ok(None)
     }
@@ -279,8 +319,8 @@ impl GitHubOperations for TestGitHub {
         _owner: &str,
         _repo: &str,
         _tag: &str,
-    ) -> CoreResult<Release> {
-        Err(CoreError::not_found("stub"))
+    ) -> CoreResult<Option<Release>> {
+        Ok(None)
     }
 
     async fn list_releases(
@@ -293,6 +333,18 @@ impl GitHubOperations for TestGitHub {
         Ok(vec![])
     }
 
+    async fn upload_release_asset(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _release_id: u64,
+        _name: &str,
+        _content_type: Option<&str>,
+        _data: Vec<u8>,
+    ) -> CoreResult<String> {
+        Err(CoreError::not_found("stub"))
+    }
+
     async fn list_pull_requests(
         &self,
         _owner: &str,
@@ -387,6 +439,16 @@ impl GitHubOperations for TestGitHub {
         Ok(())
     }
 
+    async fn create_commit_status(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _sha: &str,
+        _params: CreateCommitStatusParams,
+    ) -> CoreResult<()> {
+        Ok(())
+    }
+
     async fn delete_branch(&self, _owner: &str, _repo: &str, branch_name: &str) -> CoreResult<()> {
         self.state
             .lock()
@@ -411,6 +473,32 @@ impl GitHubOperations for TestGitHub {
         Ok(())
     }
 
+    /// Creates the branch like `create_branch`; an existing branch is reported
+    /// as diverged, as a release branch carrying its release commit would be.
+    async fn upsert_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch_name: &str,
+        from_sha: &str,
+    ) -> CoreResult<crate::traits::github_operations::BranchUpsert> {
+        {
+            let mut st = self.state.lock().await;
+            if st.stale_branch_exists {
+                st.fast_forwarded_branches
+                    .push((branch_name.to_string(), from_sha.to_string()));
+                return Ok(crate::traits::github_operations::BranchUpsert::Updated);
+            }
+        }
+        match self.create_branch(owner, repo, branch_name, from_sha).await {
+            Ok(()) => Ok(crate::traits::github_operations::BranchUpsert::Created),
+            Err(CoreError::Conflict { .. }) => {
+                Err(CoreError::not_fast_forward(branch_name, from_sha))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     async fn create_issue_comment(
         &self,
         _owner: &str,
@@ -450,6 +538,30 @@ impl GitHubOperations for TestGitHub {
         Ok(())
     }
 
+    async fn resolve_ref(&self, _owner: &str, _repo: &str, _git_ref: &str) -> CoreResult<String> {
+        Err(CoreError::not_found("stub"))
+    }
+
+    async fn set_pull_request_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        pr_number: u64,
+        labels: &[&str],
+        mode: LabelMode,
+    ) -> CoreResult<()> {
+        let mut st = self.state.lock().await;
+        if st.label_error {
+            return Err(CoreError::network("simulated label failure"));
+        }
+        st.labeled_prs.push((
+            pr_number,
+            labels.iter().map(ToString::to_string).collect(),
+            mode,
+        ));
+        Ok(())
+    }
+
     async fn list_pr_labels(
         &self,
         _owner: &str,
@@ -467,16 +579,11 @@ impl GitHubOperations for TestGitHub {
         &self,
         _owner: &str,
         _repo: &str,
-        path: &str,
+        _path: &str,
         _commit_message: &str,
         _content: &str,
-        branch: &str,
+        _branch: &str,
     ) -> CoreResult<()> {
-        self.state
-            .lock()
-            .await
-            .upserted_files
-            .push((path.to_string(), branch.to_string()));
         Ok(())
     }
 
@@ -541,6 +648,15 @@ impl GitHubOperations for TestGitHub {
         Ok(vec![])
     }
 
+    async fn list_pull_request_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _pr_number: u64,
+    ) -> CoreResult<Vec<crate::traits::git_operations::GitCommit>> {
+        Ok(vec![])
+    }
+
     async fn update_issue_comment(
         &self,
         _owner: &str,
@@ -936,7 +1052,42 @@ async fn test_orchestrate_existing_higher_version_pr_is_no_op() {
     );
 }
 
-/// Branch already exists (conflict from create_branch) → orchestrator reuses it.
+/// A stale branch behind the base branch is fast-forwarded, then gets the release commit.
+#[tokio::test]
+async fn test_orchestrate_fast_forwards_stale_release_branch() {
+    let github = TestGitHub::new().with_stale_branch().await;
+
+    let orchestrator = ReleaseOrchestrator::new(default_config(), &github);
+
+    let result = orchestrator
+        .orchestrate(
+            "testorg",
+            "testrepo",
+            &ver(1, 0, 0),
+            "- fix: patch [ab12cd34ef5678901234abcdef12345678901234]",
+            "main",
+            "sha006",
+            "corr-006",
+        )
+        .await
+        .expect("orchestrate should succeed when the branch is stale");
+
+    assert!(
+        matches!(&result, OrchestratorResult::Created { branch_name, .. } if branch_name == "release/v1.0.0"),
+        "expected Created on the canonical branch, got {result:?}"
+    );
+
+    let state = github.state.lock().await;
+    assert_eq!(
+        state.fast_forwarded_branches,
+        vec![("release/v1.0.0".to_string(), "sha006".to_string())]
+    );
+    assert!(state.created_branches.is_empty());
+    assert_eq!(state.rebased_batch_commits.len(), 1);
+    assert_eq!(state.rebased_batch_commits[0].3, "sha006");
+}
+
+/// Branch already exists with its own commits (not a fast-forward) → orchestrator reuses it.
 #[tokio::test]
 async fn test_orchestrate_branch_already_exists_reuses_it() {
     let github = TestGitHub::new().with_next_create_branch_conflict().await;
@@ -1096,15 +1247,187 @@ fn test_make_branch_name_format() {
     let github = TestGitHub::new();
     let orchestrator = ReleaseOrchestrator::new(default_config(), &github);
     assert_eq!(
-        orchestrator.make_branch_name(&ver(1, 2, 3)),
+        orchestrator
+            .make_branch_name("testorg", "testrepo", "main", &ver(1, 2, 3))
+            .unwrap(),
         "release/v1.2.3"
     );
     assert_eq!(
-        orchestrator.make_branch_name(&ver(0, 1, 0)),
+        orchestrator
+            .make_branch_name("testorg", "testrepo", "main", &ver(0, 1, 0))
+            .unwrap(),
         "release/v0.1.0"
     );
 }
 
+/// Custom `branch_template` — the release branch is named after the base branch.
+#[tokio::test]
+async fn test_custom_branch_template_is_used() {
+    let config = OrchestratorConfig {
+        branch_template: Some("release-regent/{target_branch}/{version}".to_string()),
+        ..OrchestratorConfig::default()
+    };
+    let github = TestGitHub::new();
+    let orchestrator = ReleaseOrchestrator::new(config, &github);
+
+    let result = orchestrator
+        .orchestrate(
+            "testorg",
+            "testrepo",
+            &ver(2, 0, 0),
+            "- feat: big change [ff00aabb1122334455667788990011223344556677]",
+            "develop",
+            "sha008",
+            "corr-008",
+        )
+        .await
+        .expect("orchestrate should succeed");
+
+    if let OrchestratorResult::Created { branch_name, .. } = result {
+        assert_eq!(branch_name, "release-regent/develop/2.0.0");
+    } else {
+        panic!("expected Created");
+    }
+}
+
+/// An open release PR named from the `branch_template` is recognised.
+#[tokio::test]
+async fn test_custom_branch_template_finds_existing_release_pr() {
+    let config = OrchestratorConfig {
+        branch_template: Some("release-regent/{target_branch}/{version}".to_string()),
+        ..OrchestratorConfig::default()
+    };
+    let github = TestGitHub::new()
+        .with_search_results(vec![make_open_release_pr(
+            55,
+            "release-regent/main/2.0.0",
+            None,
+        )])
+        .await;
+    let orchestrator = ReleaseOrchestrator::new(config, &github);
+
+    let result = orchestrator
+        .orchestrate(
+            "testorg",
+            "testrepo",
+            &ver(1, 1, 0),
+            "- chore: minor stuff [deadbeef012345678901234567890123456789ab]",
+            "main",
+            "sha004",
+            "corr-004",
+        )
+        .await
+        .expect("orchestrate should succeed");
+
+    assert!(
+        matches!(result, OrchestratorResult::NoOp { .. }),
+        "expected NoOp, got {result:?}"
+    );
+}
+
+/// A branch template without a version names one reused branch: the first
+/// release creates it, and a later, higher version moves its PR in place
+/// instead of opening a second PR on the same branch.
+#[tokio::test]
+async fn test_fixed_branch_template_moves_release_pr_in_place() {
+    let config = OrchestratorConfig {
+        branch_template: Some("release/next".to_string()),
+        ..OrchestratorConfig::default()
+    };
+    let github = TestGitHub::new();
+    let result = ReleaseOrchestrator::new(config.clone(), &github)
+        .orchestrate(
+            "testorg",
+            "testrepo",
+            &ver(1, 0, 0),
+            "- feat: first [aa00aabb1122334455667788990011223344556677]",
+            "main",
+            "sha020",
+            "corr-020",
+        )
+        .await
+        .expect("orchestrate should succeed");
+    let OrchestratorResult::Created { pr, branch_name } = result else {
+        panic!("expected Created, got {result:?}");
+    };
+    assert_eq!(branch_name, "release/next");
+    assert_eq!(
+        extract_version_sentinel(pr.body.as_deref().unwrap_or_default()).as_deref(),
+        Some("1.0.0")
+    );
+
+    let existing_body =
+        "## Changelog\n\n- feat: first [aa00aabb1122334455667788990011223344556677]\n\
+                         <!-- release-regent: previous-version=initial release -->\n\
+                         <!-- release-regent: version=1.0.0 -->";
+    let existing_pr = make_open_release_pr(60, "release/next", Some(existing_body));
+    let github = TestGitHub::new()
+        .with_search_results(vec![existing_pr.clone()])
+        .await
+        .with_pr_by_number(existing_pr)
+        .await;
+    let result = ReleaseOrchestrator::new(config, &github)
+        .orchestrate(
+            "testorg",
+            "testrepo",
+            &ver(1, 1, 0),
+            "- feat: second [bb00aabb1122334455667788990011223344556677]",
+            "main",
+            "sha021",
+            "corr-021",
+        )
+        .await
+        .expect("orchestrate should succeed");
+
+    assert!(
+        matches!(result, OrchestratorResult::Renamed { ref pr } if pr.number == 60),
+        "expected PR 60 to be moved in place, got {result:?}"
+    );
+    assert!(github.created_branches().await.is_empty());
+    assert!(github.deleted_branches().await.is_empty());
+    let updates = github.updated_prs().await;
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].1.as_deref(), Some("chore(release): v1.1.0"));
+    assert!(
+        updates[0]
+            .2
+            .as_deref()
+            .unwrap_or_default()
+            .contains("version=1.1.0"),
+        "{updates:?}"
+    );
+}
+
+/// A template that renders an illegal branch name fails before any mutation.
+#[tokio::test]
+async fn test_branch_template_rendering_illegal_name_is_rejected() {
+    let config = OrchestratorConfig {
+        branch_template: Some("release/{target_branch}/{version}".to_string()),
+        ..OrchestratorConfig::default()
+    };
+    let github = TestGitHub::new();
+    let orchestrator = ReleaseOrchestrator::new(config, &github);
+
+    let result = orchestrator
+        .orchestrate(
+            "testorg",
+            "testrepo",
+            &ver(1, 0, 0),
+            "- feat: x [ff00aabb1122334455667788990011223344556677]",
+            "main..old",
+            "sha008",
+            "corr-008",
+        )
+        .await;
+
+    assert!(
+        matches!(result, Err(CoreError::InvalidInput { .. })),
+        "expected InvalidInput, got {result:?}"
+    );
+    assert!(github.created_branches().await.is_empty());
+    assert!(github.created_prs().await.is_empty());
+}
+
 /// Custom `OrchestratorConfig` — branch prefix is respected.
 #[tokio::test]
 async fn test_custom_branch_prefix_is_used() {
@@ -1253,6 +1576,149 @@ async fn test_body_template_is_used_in_pr_body() {
     }
 }
 
+fn labels_config(mode: LabelMode) -> OrchestratorConfig {
+    OrchestratorConfig {
+        labels: vec!["autorelease: pending".to_string(), "release".to_string()],
+        label_mode: mode,
+        ..OrchestratorConfig::default()
+    }
+}
+
+fn expected_labels() -> Vec<String> {
+    vec!["autorelease: pending".to_string(), "release".to_string()]
+}
+
+/// Configured labels are applied to a newly created release PR.
+#[tokio::test]
+async fn test_labels_are_applied_to_created_release_pr() {
+    let github = TestGitHub::new();
+    let orchestrator = ReleaseOrchestrator::new(labels_config(LabelMode::Add), &github);
+
+    let result = orchestrator
+        .orchestrate(
+            "testorg",
+            "testrepo",
+            &ver(1, 2, 0),
+            "- feat: labelled [aa00bb11cc22dd33ee44ff5566778899aabbccdd]",
+            "main",
+            "sha012",
+            "corr-012",
+        )
+        .await
+        .expect("orchestrate should succeed");
+
+    let OrchestratorResult::Created { pr, .. } = result else {
+        panic!("expected Created, got {result:?}");
+    };
+    assert_eq!(
+        github.labeled_prs().await,
+        vec![(pr.number, expected_labels(), LabelMode::Add)]
+    );
+}
+
+/// Labels are re-applied, with the configured mode, when a release PR is
+/// renamed to a higher version.
+#[tokio::test]
+async fn test_labels_are_applied_to_renamed_release_pr() {
+    let github = TestGitHub::new()
+        .with_search_results(vec![make_open_release_pr(10, "release/v1.0.0", None)])
+        .await;
+    let orchestrator = ReleaseOrchestrator::new(labels_config(LabelMode::Replace), &github);
+
+    let result = orchestrator
+        .orchestrate(
+            "testorg",
+            "testrepo",
+            &ver(1, 1, 0),
+            "- feat: bump [ccddee112233445566778899aabbccdd00112233]",
+            "main",
+            "sha013",
+            "corr-013",
+        )
+        .await
+        .expect("orchestrate should succeed");
+
+    let OrchestratorResult::Renamed { pr, .. } = result else {
+        panic!("expected Renamed, got {result:?}");
+    };
+    assert_eq!(
+        github.labeled_prs().await,
+        vec![(pr.number, expected_labels(), LabelMode::Replace)]
+    );
+}
+
+/// Labels are not touched when the existing release PR is left alone.
+#[tokio::test]
+async fn test_labels_are_not_applied_on_no_op() {
+    let github = TestGitHub::new()
+        .with_search_results(vec![make_open_release_pr(55, "release/v2.0.0", None)])
+        .await;
+    let orchestrator = ReleaseOrchestrator::new(labels_config(LabelMode::Add), &github);
+
+    let result = orchestrator
+        .orchestrate(
+            "testorg",
+            "testrepo",
+            &ver(1, 1, 0),
+            "- chore: minor stuff [deadbeef012345678901234567890123456789ab]",
+            "main",
+            "sha014",
+            "corr-014",
+        )
+        .await
+        .expect("orchestrate should succeed");
+
+    assert!(matches!(result, OrchestratorResult::NoOp { .. }));
+    assert!(github.labeled_prs().await.is_empty());
+}
+
+/// Without configured labels the labels endpoint is never called.
+#[tokio::test]
+async fn test_no_labels_configured_sets_no_labels() {
+    let github = TestGitHub::new();
+    let orchestrator = ReleaseOrchestrator::new(default_config(), &github);
+
+    orchestrator
+        .orchestrate(
+            "testorg",
+            "testrepo",
+            &ver(1, 2, 0),
+            "- feat: unlabelled [aa00bb11cc22dd33ee44ff5566778899aabbccdd]",
+            "main",
+            "sha015",
+            "corr-015",
+        )
+        .await
+        .expect("orchestrate should succeed");
+
+    assert!(github.labeled_prs().await.is_empty());
+}
+
+/// A failure to label the release PR does not fail the orchestration: the PR
+/// has already been created.
+#[tokio::test]
+async fn test_label_failure_does_not_fail_orchestration() {
+    let github = TestGitHub::new().with_label_error().await;
+    let orchestrator = ReleaseOrchestrator::new(labels_config(LabelMode::Add), &github);
+
+    let result = orchestrator
+        .orchestrate(
+            "testorg",
+            "testrepo",
+            &ver(1, 2, 0),
+            "- feat: labelled [aa00bb11cc22dd33ee44ff5566778899aabbccdd]",
+            "main",
+            "sha016",
+            "corr-016",
+        )
+        .await
+        .expect("orchestrate should succeed despite the label failure");
+
+    assert!(matches!(result, OrchestratorResult::Created { .. }));
+    assert_eq!(github.created_prs().await.len(), 1);
+    assert!(github.labeled_prs().await.is_empty());
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Unit tests for internal helpers
 // ─────────────────────────────────────────────────────────────────────────────
@@ -3132,3 +3598,161 @@ async fn test_successive_updates_do_not_accumulate_sentinels() {
         "second entry must be present; body:\n{new_body}"
     );
 }
+
+/// Package changelogs are prepended to their own files, read from the base
+/// branch, in the same release commit as `CHANGELOG.md`.
+#[tokio::test]
+async fn test_orchestrate_commits_package_changelogs() {
+    let github = TestGitHub::new()
+        .with_file_content(
+            "packages/a/CHANGELOG.md",
+            "main",
+            "# Changelog\n\n## [0.3.0] - 2026-01-01\n\n- feat: older a change\n",
+        )
+        .await;
+    let orchestrator =
+        ReleaseOrchestrator::new(default_config(), &github).with_package_changelogs(vec![
+            PackageChangelog {
+                package: "a".to_string(),
+                path: "packages/a/CHANGELOG.md".to_string(),
+                version: ver(0, 4, 0),
+                changelog: "- feat: new a change".to_string(),
+            },
+            PackageChangelog {
+                package: "b".to_string(),
+                path: "packages/b/CHANGELOG.md".to_string(),
+                version: ver(2, 0, 1),
+                changelog: "- fix: b change".to_string(),
+            },
+        ]);
+
+    orchestrator
+        .orchestrate(
+            "testorg",
+            "testrepo",
+            &ver(1, 2, 3),
+            "- feat: new a change\n- fix: b change",
+            "main",
+            "sha001",
+            "corr-001",
+        )
+        .await
+        .expect("orchestrate should succeed");
+
+    let file_updates = github.rebased_batch_file_updates().await;
+    assert_eq!(file_updates.len(), 1, "expected one release commit");
+    let content = |path: &str| {
+        file_updates[0]
+            .iter()
+            .find(|f| f.path == path)
+            .unwrap_or_else(|| panic!("{path} must be committed"))
+            .content
+            .clone()
+    };
+
+    let a = content("packages/a/CHANGELOG.md");
+    assert!(a.starts_with("# Changelog\n\n## [0.4.0] - "), "got:\n{a}");
+    assert!(a.contains("- feat: new a change"), "got:\n{a}");
+    assert!(a.contains("## [0.3.0] - 2026-01-01"), "history lost:\n{a}");
+    assert!(!a.contains("b change"), "got:\n{a}");
+
+    let b = content("packages/b/CHANGELOG.md");
+    assert!(b.starts_with("# Changelog\n\n## [2.0.1] - "), "got:\n{b}");
+    assert!(b.contains("- fix: b change"), "got:\n{b}");
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// changelog date format
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A clock stopped at a fixed time.
+#[derive(Debug)]
+struct FixedClock(chrono::DateTime<Utc>);
+
+impl crate::clock::Clock for FixedClock {
+    fn now(&self) -> chrono::DateTime<Utc> {
+        self.0
+    }
+}
+
+/// Create the 1.2.3 release PR on 2024-06-01 and return the committed
+/// CHANGELOG.md.
+async fn changelog_released_with_date_format(date_format: &str) -> CoreResult<String> {
+    let config = OrchestratorConfig {
+        changelog_date_format: date_format.to_string(),
+        ..default_config()
+    };
+    let at = chrono::DateTime::parse_from_rfc3339("2024-06-01T09:30:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    let github = TestGitHub::new();
+    let orchestrator =
+        ReleaseOrchestrator::new(config, &github).with_clock(Arc::new(FixedClock(at)));
+
+    orchestrator
+        .orchestrate(
+            "testorg",
+            "testrepo",
+            &ver(1, 2, 3),
+            "- feat: add thing",
+            "main",
+            "sha001",
+            "corr-001",
+        )
+        .await?;
+
+    let file_updates = github.rebased_batch_file_updates().await;
+    Ok(file_updates[0]
+        .iter()
+        .find(|f| f.path == "CHANGELOG.md")
+        .expect("CHANGELOG.md must be committed")
+        .content
+        .clone())
+}
+
+#[tokio::test]
+async fn test_changelog_version_header_uses_configured_date_format() {
+    let iso = changelog_released_with_date_format("%Y-%m-%d")
+        .await
+        .unwrap();
+    let long = changelog_released_with_date_format("%B %-d, %Y")
+        .await
+        .unwrap();
+
+    assert!(
+        iso.starts_with("# Changelog\n\n## [1.2.3] - 2024-06-01\n"),
+        "got:\n{iso}"
+    );
+    assert!(
+        long.starts_with("# Changelog\n\n## [1.2.3] - June 1, 2024\n"),
+        "got:\n{long}"
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_changelog_date_format_fails_before_creating_the_branch() {
+    let config = OrchestratorConfig {
+        changelog_date_format: "%Y-%Q".to_string(),
+        ..default_config()
+    };
+    let github = TestGitHub::new();
+    let orchestrator = ReleaseOrchestrator::new(config, &github);
+
+    let result = orchestrator
+        .orchestrate(
+            "testorg",
+            "testrepo",
+            &ver(1, 2, 3),
+            "- feat: add thing",
+            "main",
+            "sha001",
+            "corr-001",
+        )
+        .await;
+
+    assert!(
+        matches!(result, Err(CoreError::ChangelogGeneration { .. })),
+        "expected ChangelogGeneration, got {result:?}"
+    );
+    assert!(github.created_branches().await.is_empty());
+}
@@ -0,0 +1,111 @@
+//! Repository allow-list matching.
+//!
+//! An allow-list restricts Release Regent to a set of repositories. Each entry
+//! is an `owner/name` pattern in which `*` matches any run of characters
+//! within a single path segment:
+//!
+//! | Pattern        | Matches                                  |
+//! |----------------|------------------------------------------|
+//! | `*`            | every repository                         |
+//! | `acme/*`       | every repository owned by `acme`         |
+//! | `acme/svc-*`   | `acme/svc-billing`, `acme/svc-auth`, ... |
+//! | `acme/app`     | only `acme/app`                          |
+//!
+//! Matching is case-insensitive because GitHub owner and repository names are.
+
+#[cfg(test)]
+#[path = "repository_allow_list_tests.rs"]
+mod tests;
+
+/// Pattern that matches every repository.
+pub const ALLOW_ALL: &str = "*";
+
+/// A list of `owner/name` patterns that repositories are matched against.
+///
+/// An empty list allows nothing; use [`RepositoryAllowList::allow_all`] to
+/// allow every repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepositoryAllowList {
+    patterns: Vec<String>,
+}
+
+impl RepositoryAllowList {
+    /// Create an allow-list from `owner/name` patterns.
+    ///
+    /// Surrounding whitespace and empty entries are ignored.
+    pub fn new<I, S>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            patterns: patterns
+                .into_iter()
+                .map(|p| p.as_ref().trim().to_string())
+                .filter(|p| !p.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Create an allow-list that allows every repository.
+    #[must_use]
+    pub fn allow_all() -> Self {
+        Self::new([ALLOW_ALL])
+    }
+
+    /// The configured patterns.
+    #[must_use]
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    /// Return `true` if `owner/name` matches at least one pattern.
+    #[must_use]
+    pub fn is_allowed(&self, owner: &str, name: &str) -> bool {
+        self.is_allowed_full_name(&format!("{owner}/{name}"))
+    }
+
+    /// Return `true` if `full_name` (`owner/name`) matches at least one
+    /// pattern.
+    #[must_use]
+    pub fn is_allowed_full_name(&self, full_name: &str) -> bool {
+        self.patterns
+            .iter()
+            .any(|pattern| pattern_matches(pattern, full_name))
+    }
+}
+
+impl Default for RepositoryAllowList {
+    fn default() -> Self {
+        Self::allow_all()
+    }
+}
+
+/// Match `full_name` against a single allow-list pattern.
+fn pattern_matches(pattern: &str, full_name: &str) -> bool {
+    if pattern == ALLOW_ALL {
+        return true;
+    }
+
+    let pattern = pattern.to_ascii_lowercase();
+    let full_name = full_name.to_ascii_lowercase();
+    let pattern_segments: Vec<&str> = pattern.split('/').collect();
+    let name_segments: Vec<&str> = full_name.split('/').collect();
+
+    pattern_segments.len() == name_segments.len()
+        && pattern_segments
+            .iter()
+            .zip(&name_segments)
+            .all(|(p, n)| segment_matches(p.as_bytes(), n.as_bytes()))
+}
+
+/// Glob-match a single path segment where `*` matches any run of bytes.
+fn segment_matches(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => (0..=text.len()).any(|skip| segment_matches(rest, &text[skip..])),
+        Some((c, rest)) => text
+            .split_first()
+            .is_some_and(|(t, text_rest)| t == c && segment_matches(rest, text_rest)),
+    }
+}
@@ -0,0 +1,63 @@
+use super::*;
+
+#[test]
+fn test_exact_pattern_matches_only_that_repository() {
+    let allow_list = RepositoryAllowList::new(["acme/app"]);
+
+    assert!(allow_list.is_allowed("acme", "app"));
+    assert!(!allow_list.is_allowed("acme", "app2"));
+    assert!(!allow_list.is_allowed("other", "app"));
+}
+
+#[test]
+fn test_wildcard_matches_every_repository() {
+    let allow_list = RepositoryAllowList::new(["*"]);
+
+    assert!(allow_list.is_allowed("acme", "app"));
+    assert!(allow_list.is_allowed("other", "project"));
+    assert_eq!(
+        RepositoryAllowList::default(),
+        RepositoryAllowList::allow_all()
+    );
+}
+
+#[test]
+fn test_owner_wildcard_matches_repositories_of_that_owner() {
+    let allow_list = RepositoryAllowList::new(["acme/*"]);
+
+    assert!(allow_list.is_allowed("acme", "app"));
+    assert!(allow_list.is_allowed("acme", "lib"));
+    assert!(!allow_list.is_allowed("acme-labs", "app"));
+    assert!(!allow_list.is_allowed("other", "app"));
+}
+
+#[test]
+fn test_wildcard_within_segment_matches_prefix() {
+    let allow_list = RepositoryAllowList::new(["acme/svc-*"]);
+
+    assert!(allow_list.is_allowed("acme", "svc-billing"));
+    assert!(allow_list.is_allowed("acme", "svc-"));
+    assert!(!allow_list.is_allowed("acme", "app"));
+}
+
+#[test]
+fn test_wildcard_does_not_cross_segments() {
+    let allow_list = RepositoryAllowList::new(["acme*"]);
+
+    assert!(!allow_list.is_allowed_full_name("acme/app"));
+}
+
+#[test]
+fn test_matching_is_case_insensitive() {
+    let allow_list = RepositoryAllowList::new(["Acme/App"]);
+
+    assert!(allow_list.is_allowed("acme", "APP"));
+}
+
+#[test]
+fn test_empty_allow_list_denies_everything() {
+    let allow_list = RepositoryAllowList::new(["", "  "]);
+
+    assert!(allow_list.patterns().is_empty());
+    assert!(!allow_list.is_allowed("acme", "app"));
+}
@@ -0,0 +1,84 @@
+//! Redaction of sensitive configuration values.
+//!
+//! Configuration files can hold credentials such as Slack webhook URLs or
+//! authorization headers. Fields holding them use [`SensitiveString`], which
+//! serializes like a plain string but never prints its value in `Debug`
+//! output, so logging a configuration or an error containing one does not
+//! leak it.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[cfg(test)]
+#[path = "secret_tests.rs"]
+mod tests;
+
+/// Text shown in place of a sensitive value.
+pub const REDACTED: &str = "REDACTED";
+
+/// A string whose value is hidden from `Debug` output.
+///
+/// There is deliberately no `Display` implementation; use
+/// [`expose`](Self::expose) where the value itself is needed.
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SensitiveString(String);
+
+impl SensitiveString {
+    /// Wrap a sensitive value.
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// The wrapped value.
+    #[must_use]
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if the value is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Debug for SensitiveString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl From<String> for SensitiveString {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for SensitiveString {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+/// Describe a TOML parse error without quoting the input.
+///
+/// The `Display` form of [`toml::de::Error`] includes the offending source
+/// line, and its `Debug` form the whole document, either of which may contain
+/// a secret. This keeps only the message and, when `content` is the parsed
+/// input, the line and column.
+#[must_use]
+pub fn describe_toml_error(error: &toml::de::Error, content: Option<&str>) -> String {
+    let position = error
+        .span()
+        .zip(content)
+        .and_then(|(span, content)| content.get(..span.start))
+        .map(|before| {
+            let line = before.matches('\n').count() + 1;
+            let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+            format!(" at line {line}, column {column}")
+        })
+        .unwrap_or_default();
+
+    format!("{}{position}", error.message())
+}
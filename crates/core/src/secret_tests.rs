@@ -0,0 +1,70 @@
+use super::*;
+use crate::config::{NotificationStrategy, ReleaseRegentConfig, SlackConfig, WebhookConfig};
+use std::collections::HashMap;
+
+const SLACK_URL: &str = "https://hooks.slack.com/services/T000/B000/s3cr3t-token";
+const AUTH_HEADER: &str = "Bearer s3cr3t-bearer";
+
+fn config_with_secrets() -> ReleaseRegentConfig {
+    let mut config = ReleaseRegentConfig::default();
+    config.notifications.strategy = NotificationStrategy::Slack;
+    config.notifications.slack = Some(SlackConfig {
+        webhook_url: SLACK_URL.into(),
+        channel: Some("#releases".to_string()),
+    });
+    config.notifications.webhook = Some(WebhookConfig {
+        url: "https://hooks.example.com/release".to_string(),
+        headers: HashMap::from([("Authorization".to_string(), AUTH_HEADER.into())]),
+    });
+    config
+}
+
+#[test]
+fn test_debug_is_redacted() {
+    let secret = SensitiveString::new("hunter2");
+
+    assert_eq!(format!("{secret:?}"), "REDACTED");
+    assert_eq!(secret.expose(), "hunter2");
+}
+
+#[test]
+fn test_sensitive_data_not_in_config_debug_output() {
+    let debug = format!("{:?}", config_with_secrets());
+
+    assert!(!debug.contains("s3cr3t"), "secret leaked: {debug}");
+    assert!(debug.contains(REDACTED));
+    // Non-sensitive fields are still shown.
+    assert!(debug.contains("#releases"));
+    assert!(debug.contains("Authorization"));
+}
+
+#[test]
+fn test_serialization_keeps_the_value() {
+    let config = config_with_secrets();
+
+    let toml = toml::to_string(&config).unwrap();
+    assert!(toml.contains(SLACK_URL));
+
+    let round_trip: ReleaseRegentConfig = toml::from_str(&toml).unwrap();
+    assert_eq!(
+        round_trip.notifications.slack.unwrap().webhook_url.expose(),
+        SLACK_URL
+    );
+}
+
+#[test]
+fn test_describe_toml_error_does_not_quote_content() {
+    let content = "[notifications.slack]\nwebhook_url = \"s3cr3t\" oops\n";
+    let error = toml::from_str::<ReleaseRegentConfig>(content).unwrap_err();
+
+    let description = describe_toml_error(&error, Some(content));
+
+    assert!(
+        !description.contains("s3cr3t"),
+        "content leaked: {description}"
+    );
+    assert!(
+        description.contains("at line 2"),
+        "description: {description}"
+    );
+}
@@ -0,0 +1,286 @@
+//! Signing of release tags
+//!
+//! A signed Git tag is an annotated tag object whose message is followed by a
+//! detached signature over the rest of the object. Release Regent builds the
+//! exact bytes of that object with [`tag_payload`], signs them locally with a
+//! [`TagSigner`], and submits the tag through
+//! [`GitHubOperations::create_signed_tag`](crate::traits::github_operations::GitHubOperations::create_signed_tag)
+//! so that GitHub stores an object identical to the one that was signed.
+//!
+//! [`CommandTagSigner`] signs with the `gpg` or `ssh-keygen` binaries, using
+//! the private key that [`TagSigningConfig`] refers to. The key is written to a private
+//! temporary directory for the duration of a single signature and never
+//! logged.
+
+use crate::{
+    config::{TagSigningConfig, TagSigningFormat},
+    secret::SensitiveString,
+    traits::git_operations::GitUser,
+    CoreError, CoreResult,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    process::Stdio,
+    sync::Arc,
+};
+use tempfile::TempDir;
+use tokio::{io::AsyncWriteExt, process::Command};
+use tracing::debug;
+
+#[cfg(test)]
+#[path = "tag_signing_tests.rs"]
+mod tests;
+
+/// Produces detached signatures over tag objects.
+///
+/// Implementations must return an ASCII-armored signature that Git accepts
+/// after a tag message: an OpenPGP signature block for `gpg` or an SSH
+/// signature block for `ssh`.
+#[async_trait]
+pub trait TagSigner: fmt::Debug + Send + Sync {
+    /// Sign `payload` and return the armored signature.
+    ///
+    /// # Errors
+    /// - `CoreError::Config` - The key is unusable or the signing tool failed
+    async fn sign(&self, payload: &[u8]) -> CoreResult<String>;
+}
+
+/// The signer and tagger identity used for signed release tags.
+#[derive(Debug, Clone)]
+pub struct TagSigning {
+    /// Signer that produces the tag signature
+    pub signer: Arc<dyn TagSigner>,
+    /// Identity recorded as the tagger of signed tags
+    pub tagger: GitUser,
+}
+
+impl TagSigning {
+    /// Build the signing settings for an enabled [`TagSigningConfig`],
+    /// reading the key it refers to.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CoreError::Config` when the key cannot be read; see
+    /// [`TagSigningConfig::load_key`].
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large
+    pub fn from_config(config: &TagSigningConfig) -> CoreResult<Self> {
+        Ok(Self {
+            signer: Arc::new(CommandTagSigner::new(config.format, config.load_key()?)),
+            tagger: GitUser {
+                name: config.tagger_name.clone(),
+                email: config.tagger_email.clone(),
+                login: None,
+            },
+        })
+    }
+}
+
+/// Build the tag object that a signature covers.
+///
+/// The layout matches the object Git (and the GitHub Git database API)
+/// writes for an annotated tag of a commit. The tagger time is recorded in
+/// whole seconds in UTC, and the message always ends with a newline.
+#[must_use]
+pub fn tag_payload(
+    tag_name: &str,
+    commit_sha: &str,
+    tagger: &GitUser,
+    tagged_at: DateTime<Utc>,
+    message: &str,
+) -> String {
+    format!(
+        "object {commit_sha}\ntype commit\ntag {tag_name}\ntagger {} <{}> {} +0000\n\n{}",
+        tagger.name,
+        tagger.email,
+        tagged_at.timestamp(),
+        normalize_message(message)
+    )
+}
+
+/// Return `message` with exactly the trailing newline Git stores.
+#[must_use]
+pub fn normalize_message(message: &str) -> String {
+    format!("{}\n", message.trim_end_matches('\n'))
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CommandTagSigner
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Signs tags by running `gpg` or `ssh-keygen`.
+///
+/// The key must not be passphrase protected; both tools are run without a
+/// terminal.
+#[derive(Debug, Clone)]
+pub struct CommandTagSigner {
+    format: TagSigningFormat,
+    key: SensitiveString,
+}
+
+impl CommandTagSigner {
+    /// Create a signer for the given format and private key.
+    #[must_use]
+    pub fn new(format: TagSigningFormat, key: SensitiveString) -> Self {
+        Self { format, key }
+    }
+
+    async fn sign_with_ssh(&self, dir: &Path, payload: &[u8]) -> CoreResult<String> {
+        let key_file = write_private_file(dir, "signing_key", self.key.expose())?;
+        let mut command = Command::new("ssh-keygen");
+        command
+            .args(["-q", "-Y", "sign", "-n", "git", "-f"])
+            .arg(&key_file);
+        run("ssh-keygen", &mut command, payload).await
+    }
+
+    async fn sign_with_gpg(&self, dir: &Path, payload: &[u8]) -> CoreResult<String> {
+        let key_file = write_private_file(dir, "signing_key.asc", self.key.expose())?;
+        let home = dir.join("gnupg");
+        create_private_dir(&home)?;
+
+        let mut import = Command::new("gpg");
+        import
+            .arg("--homedir")
+            .arg(&home)
+            .args(["--batch", "--quiet", "--import"])
+            .arg(&key_file);
+        run("gpg --import", &mut import, &[]).await?;
+
+        let mut sign = Command::new("gpg");
+        sign.arg("--homedir").arg(&home).args([
+            "--batch",
+            "--quiet",
+            "--pinentry-mode",
+            "loopback",
+            "--armor",
+            "--detach-sign",
+        ]);
+        let signature = run("gpg --detach-sign", &mut sign, payload).await;
+
+        // Stop the agent gpg started for the throwaway home directory.
+        let _ = Command::new("gpgconf")
+            .arg("--homedir")
+            .arg(&home)
+            .args(["--kill", "gpg-agent"])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await;
+
+        signature
+    }
+}
+
+#[async_trait]
+impl TagSigner for CommandTagSigner {
+    async fn sign(&self, payload: &[u8]) -> CoreResult<String> {
+        if self.key.is_empty() {
+            return Err(CoreError::config(
+                "releases.tag_signing.key_file or key_env must provide a non-empty signing key when tag signing is enabled",
+            ));
+        }
+
+        let dir = create_key_dir()?;
+        debug!(format = ?self.format, "Signing tag object");
+        match self.format {
+            TagSigningFormat::Gpg => self.sign_with_gpg(dir.path(), payload).await,
+            TagSigningFormat::Ssh => self.sign_with_ssh(dir.path(), payload).await,
+        }
+    }
+}
+
+/// Run `command` with `stdin` as its input and return its stdout.
+async fn run(tool: &str, command: &mut Command, stdin: &[u8]) -> CoreResult<String> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| CoreError::config(format!("Failed to start {tool} to sign the tag: {e}")))?;
+
+    if let Some(mut input) = child.stdin.take() {
+        input.write_all(stdin).await.map_err(|e| {
+            CoreError::config(format!("Failed to pass the tag object to {tool}: {e}"))
+        })?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .await
+        .map_err(|e| CoreError::config(format!("{tool} failed while signing the tag: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(CoreError::config(format!(
+            "{tool} exited with {} while signing the tag: {}",
+            output.status,
+            stderr.trim()
+        )));
+    }
+
+    String::from_utf8(output.stdout)
+        .map_err(|e| CoreError::config(format!("{tool} produced a non-UTF-8 signature: {e}")))
+}
+
+/// Create a private temporary directory for the key, removed when dropped.
+///
+/// The name has a random suffix, so another local user cannot claim it first.
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+fn create_key_dir() -> CoreResult<TempDir> {
+    let mut builder = tempfile::Builder::new();
+    builder.prefix("release-regent-signing-");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        builder.permissions(std::fs::Permissions::from_mode(0o700));
+    }
+    builder.tempdir().map_err(|e| {
+        CoreError::config(format!(
+            "Failed to create a directory for the signing key: {e}"
+        ))
+    })
+}
+
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+fn create_private_dir(path: &Path) -> CoreResult<()> {
+    let mut builder = std::fs::DirBuilder::new();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt;
+        builder.mode(0o700);
+    }
+    builder.create(path).map_err(|e| {
+        CoreError::config(format!(
+            "Failed to create a directory for the signing key: {e}"
+        ))
+    })
+}
+
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+fn write_private_file(dir: &Path, name: &str, contents: &str) -> CoreResult<PathBuf> {
+    use std::io::Write as _;
+
+    let path = dir.join(name);
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options
+        .open(&path)
+        .and_then(|mut file| {
+            file.write_all(contents.as_bytes())?;
+            // OpenSSH refuses keys without a trailing newline.
+            if !contents.ends_with('\n') {
+                file.write_all(b"\n")?;
+            }
+            file.flush()
+        })
+        .map_err(|e| CoreError::config(format!("Failed to write the signing key: {e}")))?;
+    Ok(path)
+}
@@ -0,0 +1,126 @@
+//! Metric names and helpers for processing telemetry.
+//!
+//! Metrics are emitted through the [`metrics`] facade. They cost nothing until
+//! the host installs a recorder, which the server does to expose them in the
+//! Prometheus format; the CLI does not.
+//!
+//! Labels are limited to small, fixed vocabularies such as event types,
+//! outcomes and stage names. Repository names and anything derived from
+//! configuration values are never used as labels.
+//!
+//! [`LogFormat`] selects how the hosts render their log output.
+
+use std::fmt;
+use std::future::Future;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Environment variable selecting the [`LogFormat`] of the CLI and server.
+pub const LOG_FORMAT_ENV: &str = "RELEASE_REGENT_LOG_FORMAT";
+
+/// Rendering of log output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable lines, for local use
+    #[default]
+    Pretty,
+    /// One JSON object per line, for log ingestion. Fields of the enclosing
+    /// spans, such as `correlation_id`, are included.
+    Json,
+}
+
+impl LogFormat {
+    /// Read the format from [`LOG_FORMAT_ENV`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a message naming the variable when its value is not a format;
+    /// an unset variable selects [`LogFormat::Pretty`].
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var(LOG_FORMAT_ENV) {
+            Ok(value) => value.parse().map_err(|e| format!("{LOG_FORMAT_ENV}: {e}")),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "pretty" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            other => Err(format!(
+                "unknown log format '{other}'; expected 'json' or 'pretty'"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Pretty => "pretty",
+            Self::Json => "json",
+        })
+    }
+}
+
+/// Counter of events taken off the event source, labelled with `event_type`
+/// and `outcome` (`processed`, `ignored` or `error`).
+pub const EVENTS_TOTAL: &str = "release_regent_events_total";
+
+/// Histogram of the time spent processing an event, in seconds, labelled with
+/// `event_type` and `outcome`.
+pub const EVENT_PROCESSING_DURATION_SECONDS: &str =
+    "release_regent_event_processing_duration_seconds";
+
+/// Histogram of the time spent in a processing stage, in seconds, labelled
+/// with `stage` and `outcome` (`success` or `error`).
+pub const STAGE_DURATION_SECONDS: &str = "release_regent_stage_duration_seconds";
+
+/// Stage label for loading the repository configuration.
+pub const STAGE_CONFIG_LOAD: &str = "config_load";
+
+/// Stage label for calculating the next version.
+pub const STAGE_VERSION_CALCULATION: &str = "version_calculation";
+
+/// Stage label for creating the tag and GitHub release after a release PR merges.
+pub const STAGE_RELEASE_CREATION: &str = "release_creation";
+
+/// Record the outcome and duration of an event taken off the event source.
+pub fn record_event(event_type: &str, outcome: &'static str, duration: Duration) {
+    let labels = [
+        ("event_type", event_type.to_string()),
+        ("outcome", outcome.to_string()),
+    ];
+    metrics::counter!(EVENTS_TOTAL, &labels).increment(1);
+    metrics::histogram!(EVENT_PROCESSING_DURATION_SECONDS, &labels).record(duration.as_secs_f64());
+}
+
+/// Record the duration of a processing stage.
+pub fn record_stage(stage: &'static str, success: bool, duration: Duration) {
+    let outcome = if success { "success" } else { "error" };
+    metrics::histogram!(STAGE_DURATION_SECONDS, "stage" => stage, "outcome" => outcome)
+        .record(duration.as_secs_f64());
+}
+
+/// Await `future` and record its duration as `stage`.
+///
+/// # Errors
+///
+/// Returns the error produced by `future`, unchanged.
+pub async fn time_stage<T, E, F>(stage: &'static str, future: F) -> Result<T, E>
+where
+    F: Future<Output = Result<T, E>>,
+{
+    let started = Instant::now();
+    let result = future.await;
+    record_stage(stage, result.is_ok(), started.elapsed());
+    result
+}
+
+#[cfg(test)]
+#[path = "telemetry_tests.rs"]
+mod tests;
@@ -0,0 +1,27 @@
+use super::*;
+
+#[test]
+fn test_log_format_parses_known_values_ignoring_case() {
+    assert_eq!("json".parse::<LogFormat>().unwrap(), LogFormat::Json);
+    assert_eq!(" JSON ".parse::<LogFormat>().unwrap(), LogFormat::Json);
+    assert_eq!("pretty".parse::<LogFormat>().unwrap(), LogFormat::Pretty);
+}
+
+#[test]
+fn test_log_format_rejects_unknown_values() {
+    let error = "yaml".parse::<LogFormat>().unwrap_err();
+
+    assert!(error.contains("'yaml'"), "{error}");
+}
+
+#[test]
+fn test_log_format_round_trips_through_display() {
+    for format in [LogFormat::Pretty, LogFormat::Json] {
+        assert_eq!(format.to_string().parse::<LogFormat>().unwrap(), format);
+    }
+}
+
+#[test]
+fn test_log_format_defaults_to_pretty() {
+    assert_eq!(LogFormat::default(), LogFormat::Pretty);
+}
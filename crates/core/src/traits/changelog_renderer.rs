@@ -0,0 +1,54 @@
+//! Changelog renderer trait
+//!
+//! This trait defines the contract for turning the conventional commits of a
+//! release into the changelog text used in release PR bodies and release notes.
+//!
+//! It is named `ChangelogRenderer` rather than `ChangelogStrategy` because the
+//! latter is the configuration enum ([`crate::changelog::ChangelogStrategy`])
+//! that selects between the built-in back-ends.
+
+use crate::{changelog::ChangelogConfig, versioning::ConventionalCommit, CoreResult};
+use std::fmt;
+
+/// Information about the release a changelog is rendered for
+#[derive(Debug, Clone, Copy)]
+pub struct ChangelogContext<'a> {
+    /// Repository owner
+    pub owner: &'a str,
+    /// Repository name
+    pub repo: &'a str,
+    /// Changelog configuration of the repository
+    pub config: &'a ChangelogConfig,
+    /// Whether this is the repository's first release; the changelog then
+    /// covers its whole history
+    pub initial_release: bool,
+}
+
+/// Changelog rendering contract
+///
+/// The processor renders changelogs through a boxed `ChangelogRenderer`, which
+/// defaults to [`crate::changelog::DefaultChangelogRenderer`]. Provide another
+/// implementation to change how changelogs are produced without touching
+/// version calculation or PR management.
+///
+/// Commit author logins are resolved before `generate` is called, so
+/// implementations do not need GitHub access.
+pub trait ChangelogRenderer: Send + Sync + fmt::Debug {
+    /// Render the changelog for `commits`
+    ///
+    /// # Parameters
+    /// - `commits`: Commits included in the release, oldest first
+    /// - `context`: Repository and configuration the changelog is rendered for
+    ///
+    /// # Returns
+    /// Changelog text in markdown
+    ///
+    /// # Errors
+    /// - `CoreError::ChangelogGeneration` - The changelog could not be rendered
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large
+    fn generate(
+        &self,
+        commits: &[ConventionalCommit],
+        context: &ChangelogContext<'_>,
+    ) -> CoreResult<String>;
+}
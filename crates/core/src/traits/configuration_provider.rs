@@ -322,4 +322,32 @@ pub trait ConfigurationProvider: Send + Sync {
     /// # Errors
     /// - `CoreError::Config` - Failed to generate default configuration
     async fn get_default_config(&self) -> CoreResult<ReleaseRegentConfig>;
+
+    /// Get only the values this provider sets explicitly
+    ///
+    /// The other loading methods return a complete configuration, in which a
+    /// value set to its default cannot be told apart from one that was not
+    /// set at all. Providers that combine several sources use this method to
+    /// let a higher-priority source set a value back to its default.
+    ///
+    /// # Parameters
+    /// - `owner`: Repository owner (None for global config)
+    /// - `repo`: Repository name (None for global config)
+    /// - `options`: Configuration loading options
+    ///
+    /// # Returns
+    /// A table holding only the configuration paths the provider sets, or
+    /// `None` when the provider only knows its complete configuration. The
+    /// default implementation returns `None`.
+    ///
+    /// # Errors
+    /// - `CoreError::Config` - Configuration could not be loaded or is invalid
+    async fn load_explicit_values(
+        &self,
+        _owner: Option<&str>,
+        _repo: Option<&str>,
+        _options: LoadOptions,
+    ) -> CoreResult<Option<toml::Table>> {
+        Ok(None)
+    }
 }
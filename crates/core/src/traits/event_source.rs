@@ -89,6 +89,7 @@ pub enum EventSourceKind {
 /// - [`EventType::PullRequestCommentReceived`] → Comment command processor
 /// - [`EventType::PullRequestOpened`]        → PR status comment (feature/release preview)
 /// - [`EventType::PullRequestUpdated`]       → PR status comment refresh
+/// - [`EventType::PullRequestReactivated`]   → release PR refresh and PR status comment refresh
 /// - [`EventType::Unknown`]                  → logged and dropped
 ///
 /// # Parsing from strings
@@ -152,6 +153,15 @@ pub enum EventType {
     /// preview comment on the PR.
     PullRequestUpdated,
 
+    /// A pull request was reopened or marked ready for review (action
+    /// `reopened` or `ready_for_review`).
+    ///
+    /// When the pull request is a release PR, its version and changelog are
+    /// recalculated from the target branch and the PR is updated. The
+    /// projected-version preview comment is refreshed as for
+    /// [`EventType::PullRequestUpdated`].
+    PullRequestReactivated,
+
     /// A GitHub event that this version of Release Regent does not recognise.
     ///
     /// The inner `String` preserves the raw event type for diagnostic logging.
@@ -179,6 +189,7 @@ impl From<&str> for EventType {
             "pull_request_comment_received" => Self::PullRequestCommentReceived,
             "pull_request_opened" => Self::PullRequestOpened,
             "pull_request_updated" => Self::PullRequestUpdated,
+            "pull_request_reactivated" => Self::PullRequestReactivated,
             other => Self::Unknown(other.to_string()),
         }
     }
@@ -211,6 +222,7 @@ impl std::fmt::Display for EventType {
             Self::PullRequestCommentReceived => write!(f, "pull_request_comment_received"),
             Self::PullRequestOpened => write!(f, "pull_request_opened"),
             Self::PullRequestUpdated => write!(f, "pull_request_updated"),
+            Self::PullRequestReactivated => write!(f, "pull_request_reactivated"),
             Self::Unknown(s) => write!(f, "{s}"),
         }
     }
@@ -273,6 +273,7 @@ fn test_event_type_display_matches_from_round_trip() {
         EventType::PullRequestCommentReceived,
         EventType::PullRequestOpened,
         EventType::PullRequestUpdated,
+        EventType::PullRequestReactivated,
     ];
     for variant in &variants {
         let displayed = variant.to_string();
@@ -314,6 +315,7 @@ mod property_tests {
         "pull_request_comment_received",
         "pull_request_opened",
         "pull_request_updated",
+        "pull_request_reactivated",
     ];
 
     proptest! {
@@ -77,6 +77,32 @@ pub trait GitOperations: Send + Sync {
         options: GetCommitsOptions,
     ) -> CoreResult<Vec<GitCommit>>;
 
+    /// List the history of a reference
+    ///
+    /// Returns every commit reachable from `head`, for repositories with no
+    /// release to compare against.
+    ///
+    /// # Parameters
+    /// - `owner`: Repository owner
+    /// - `repo`: Repository name
+    /// - `head`: Head reference (commit SHA, branch, or tag) - included in results
+    /// - `options`: Additional options for commit retrieval; `limit` caps the
+    ///   number of commits, keeping the most recent ones
+    ///
+    /// # Returns
+    /// List of commits in chronological order (oldest first)
+    ///
+    /// # Errors
+    /// - `CoreError::Git` - Git operation failed
+    /// - `CoreError::NotFound` - Repository or reference not found
+    async fn list_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        head: &str,
+        options: GetCommitsOptions,
+    ) -> CoreResult<Vec<GitCommit>>;
+
     /// Get specific commit information
     ///
     /// Retrieves detailed information about a single commit, including
@@ -330,6 +356,44 @@ pub struct GitRepository {
     pub private: bool,
     /// Repository description
     pub description: Option<String>,
+    /// Repository topics, in the order the platform returns them
+    #[serde(default)]
+    pub topics: Vec<String>,
+    /// Primary language detected by the platform, if it detected one
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Who can see the repository
+    #[serde(default)]
+    pub visibility: RepositoryVisibility,
+}
+
+/// Repository visibility
+///
+/// `Internal` is only used by GitHub Enterprise, where the repository is
+/// visible to every member of the enterprise.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RepositoryVisibility {
+    /// Visible to everyone
+    #[default]
+    Public,
+    /// Visible only to people with access
+    Private,
+    /// Visible to members of the enterprise
+    Internal,
+}
+
+impl RepositoryVisibility {
+    /// The visibility implied by a `private` flag, for sources that do not
+    /// report visibility separately
+    #[must_use]
+    pub fn from_private(private: bool) -> Self {
+        if private {
+            Self::Private
+        } else {
+            Self::Public
+        }
+    }
 }
 
 impl GitCommit {
@@ -114,6 +114,32 @@ pub trait GitHubOperations: GitOperations + Send + Sync {
         sha: &str,
     ) -> CoreResult<()>;
 
+    /// Set a commit status on a commit
+    ///
+    /// A status with the same `context` as an earlier one on the commit
+    /// replaces it in the pull request checks list.
+    ///
+    /// # Parameters
+    /// - `owner`: Repository owner name
+    /// - `repo`: Repository name
+    /// - `sha`: Commit SHA to attach the status to
+    /// - `params`: Status state, context and description
+    ///
+    /// # Returns
+    /// `Ok(())` on success
+    ///
+    /// # Errors
+    /// - `CoreError::NotFound` - The commit does not exist
+    /// - `CoreError::GitHub` - API communication failed, or the App lacks the
+    ///   `statuses: write` permission
+    async fn create_commit_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        params: CreateCommitStatusParams,
+    ) -> CoreResult<()>;
+
     /// Post a comment on an issue or pull request
     ///
     /// # Parameters
@@ -205,6 +231,32 @@ pub trait GitHubOperations: GitOperations + Send + Sync {
         tagger: Option<GitUser>,
     ) -> CoreResult<Tag>;
 
+    /// Create a signed annotated tag
+    ///
+    /// Creates the tag object with the signature appended to its message and
+    /// points `refs/tags/{tag_name}` at it. The tagger and tag time must be
+    /// the ones the signature was made over, see
+    /// [`tag_payload`](crate::tag_signing::tag_payload).
+    ///
+    /// # Parameters
+    /// - `owner`: Repository owner name
+    /// - `repo`: Repository name
+    /// - `params`: Signed tag parameters
+    ///
+    /// # Returns
+    /// Created tag information
+    ///
+    /// # Errors
+    /// - `CoreError::GitHub` - API communication failed
+    /// - `CoreError::InvalidInput` - Invalid tag name or commit SHA
+    /// - `CoreError::NotSupported` - Tag already exists or insufficient permissions
+    async fn create_signed_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: SignedTagParams,
+    ) -> CoreResult<Tag>;
+
     /// Delete a branch
     ///
     /// Removes the named branch (ref) from the repository. This is a destructive
@@ -309,19 +361,30 @@ pub trait GitHubOperations: GitOperations + Send + Sync {
 
     /// Get release information by tag name
     ///
+    /// Used before creating a release so that a retried delivery does not
+    /// try to create the same release twice.
+    ///
     /// # Parameters
     /// - `owner`: Repository owner name
     /// - `repo`: Repository name
     /// - `tag`: Tag name to find release for
     ///
     /// # Returns
-    /// Release information if found
+    /// The published release for `tag`, or `None` when GitHub reports that no
+    /// release exists for it (404). Draft releases are never returned; find
+    /// them through [`list_releases`](Self::list_releases) instead.
     ///
     /// # Errors
     /// - `CoreError::GitHub` - API communication failed
     /// - `CoreError::InvalidInput` - Invalid tag name
-    /// - `CoreError::NotSupported` - Release not found
-    async fn get_release_by_tag(&self, owner: &str, repo: &str, tag: &str) -> CoreResult<Release>;
+    ///
+    /// Errors other than "not found" are never mapped to `None`.
+    async fn get_release_by_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+    ) -> CoreResult<Option<Release>>;
 
     /// List all labels currently applied to an issue or pull request.
     ///
@@ -381,6 +444,39 @@ pub trait GitHubOperations: GitOperations + Send + Sync {
         issue_number: u64,
     ) -> CoreResult<Vec<IssueComment>>;
 
+    /// List the commits of a pull request
+    ///
+    /// Returns the commits on the pull request's head branch, as they were
+    /// before merging. For a squash-merged or rebased pull request these are
+    /// the original commits rather than the commit that landed on the base
+    /// branch.
+    ///
+    /// # Parameters
+    /// - `owner`: Repository owner name
+    /// - `repo`: Repository name
+    /// - `pr_number`: Pull request number
+    ///
+    /// # Returns
+    /// Every commit of the pull request, oldest first. Implementations fetch
+    /// all pages. GitHub returns at most 250 commits for a pull request.
+    ///
+    /// # Errors
+    /// - `CoreError::NotFound` — the pull request does not exist
+    /// - `CoreError::GitHub` — the API call failed for any other reason
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let commits = github.list_pull_request_commits("owner", "repo", 42).await?;
+    /// let subjects: Vec<&str> = commits.iter().map(|c| c.subject.as_str()).collect();
+    /// ```
+    async fn list_pull_request_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> CoreResult<Vec<super::git_operations::GitCommit>>;
+
     /// List pull requests in a repository
     ///
     /// Returns pull requests matching the specified filters.
@@ -440,6 +536,35 @@ pub trait GitHubOperations: GitOperations + Send + Sync {
         page: Option<u32>,
     ) -> CoreResult<Vec<Release>>;
 
+    /// Upload a binary asset to an existing release
+    ///
+    /// # Parameters
+    /// - `owner`: Repository owner name
+    /// - `repo`: Repository name
+    /// - `release_id`: Numeric ID of the release to attach the asset to
+    /// - `name`: File name of the asset as shown on the release page
+    /// - `content_type`: MIME type of the asset; inferred from the file
+    ///   extension of `name` when `None`
+    /// - `data`: Asset content
+    ///
+    /// # Returns
+    /// The browser download URL of the uploaded asset
+    ///
+    /// # Errors
+    /// - `CoreError::Conflict` - An asset with the same name already exists on the release
+    /// - `CoreError::NotFound` - Release does not exist
+    /// - `CoreError::InvalidInput` - Asset name is empty
+    /// - `CoreError::GitHub` - API communication failed
+    async fn upload_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: u64,
+        name: &str,
+        content_type: Option<&str>,
+        data: Vec<u8>,
+    ) -> CoreResult<String>;
+
     /// Remove a single label from an issue or pull request.
     ///
     /// The operation is idempotent: if the label is not currently applied to
@@ -473,6 +598,35 @@ pub trait GitHubOperations: GitOperations + Send + Sync {
         label_name: &str,
     ) -> CoreResult<()>;
 
+    /// Resolve a branch or tag to the commit SHA it points to
+    ///
+    /// `git_ref` may be fully qualified (`refs/heads/main`, `refs/tags/v1.0.0`),
+    /// qualified by kind (`heads/main`, `tags/v1.0.0`) or a bare name. A bare
+    /// name is looked up as a tag first and then as a branch, the same
+    /// precedence `git rev-parse` uses. Annotated tags are dereferenced to the
+    /// commit they tag.
+    ///
+    /// # Parameters
+    /// - `owner`: Repository owner name
+    /// - `repo`: Repository name
+    /// - `git_ref`: Branch or tag to resolve
+    ///
+    /// # Returns
+    /// The full SHA of the commit the ref points to
+    ///
+    /// # Errors
+    /// - `CoreError::NotFound` - No branch or tag with that name exists
+    /// - `CoreError::InvalidInput` - `git_ref` is empty
+    /// - `CoreError::GitHub` - API communication failed
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let head = github.resolve_ref("owner", "repo", "main").await?;
+    /// let released = github.resolve_ref("owner", "repo", "refs/tags/v1.2.3").await?;
+    /// ```
+    async fn resolve_ref(&self, owner: &str, repo: &str, git_ref: &str) -> CoreResult<String>;
+
     /// Search pull requests using GitHub search query syntax
     ///
     /// Supports a subset of GitHub search qualifiers:
@@ -504,6 +658,42 @@ pub trait GitHubOperations: GitOperations + Send + Sync {
         query: &str,
     ) -> CoreResult<Vec<PullRequest>>;
 
+    /// Set the labels of a pull request.
+    ///
+    /// With [`LabelMode::Add`] the labels are added to those already on the
+    /// pull request; with [`LabelMode::Replace`] they become its only labels.
+    /// Labels that do not exist in the repository yet are created.
+    ///
+    /// # Parameters
+    /// - `owner`: Repository owner name
+    /// - `repo`: Repository name
+    /// - `pr_number`: Pull request number
+    /// - `labels`: Label names to apply
+    /// - `mode`: Whether to add the labels or replace the existing ones
+    ///
+    /// # Returns
+    /// `Ok(())` on success, including when every label was already present.
+    ///
+    /// # Errors
+    /// - `CoreError::NotFound` — the pull request does not exist
+    /// - `CoreError::GitHub` — a label could not be created or applied
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// github
+    ///     .set_pull_request_labels("owner", "repo", 42, &["autorelease: pending"], LabelMode::Replace)
+    ///     .await?;
+    /// ```
+    async fn set_pull_request_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        labels: &[&str],
+        mode: LabelMode,
+    ) -> CoreResult<()>;
+
     /// Update an existing pull request
     ///
     /// # Parameters
@@ -586,10 +776,41 @@ pub trait GitHubOperations: GitOperations + Send + Sync {
     ///
     /// # Errors
     ///
-    /// Returns [`CoreError::GitHub`] if the API call fails or if the App is not
-    /// installed on the repository.
+    /// - [`CoreError::AppNotInstalled`] — the App is not installed on the
+    ///   repository (HTTP 404).
+    /// - [`CoreError::GitHub`] / [`CoreError::Network`] — the API call failed.
     async fn get_installation_id_for_repo(&self, owner: &str, repo: &str) -> CoreResult<u64>;
 
+    /// Create a branch, or fast-forward it when it already exists.
+    ///
+    /// Keeps a long-lived branch (such as the release PR head branch) at
+    /// `from_sha` without ever discarding commits: the branch is created when
+    /// missing, and otherwise moved to `from_sha` only if that is a
+    /// fast-forward of its current tip.
+    ///
+    /// # Parameters
+    /// - `owner`: Repository owner name
+    /// - `repo`: Repository name
+    /// - `branch_name`: Name of the branch (without `refs/heads/` prefix)
+    /// - `from_sha`: Commit SHA the branch should point to
+    ///
+    /// # Returns
+    /// [`BranchUpsert::Created`] when the branch was created, or
+    /// [`BranchUpsert::Updated`] when an existing branch was fast-forwarded.
+    ///
+    /// # Errors
+    /// - `CoreError::NotFastForward` - The branch has commits `from_sha` does not
+    ///   contain; the branch is left untouched
+    /// - `CoreError::Conflict` - The branch was created or deleted concurrently
+    /// - `CoreError::GitHub` - API communication failed
+    async fn upsert_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch_name: &str,
+        from_sha: &str,
+    ) -> CoreResult<BranchUpsert>;
+
     /// Create or update a file at the given path on a branch.
     ///
     /// Uses the GitHub Contents API (`PUT /repos/{owner}/{repo}/contents/{path}`)
@@ -780,6 +1001,34 @@ pub trait GitHubOperations: GitOperations + Send + Sync {
 // Note: Git commit information is now provided by GitOperations trait
 // Use super::git_operations::GitCommit for commit data
 
+/// State of a commit status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommitStatusState {
+    /// The check could not run
+    Error,
+    /// The check failed
+    Failure,
+    /// The check has not finished
+    Pending,
+    /// The check passed
+    Success,
+}
+
+/// Parameters for setting a commit status
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateCommitStatusParams {
+    /// Label that tells this status apart from others on the commit
+    pub context: String,
+    /// Short, one-line description shown next to the status
+    pub description: String,
+    /// Status state
+    pub state: CommitStatusState,
+    /// Link shown as the status details
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_url: Option<String>,
+}
+
 /// Parameters for creating a new pull request
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreatePullRequestParams {
@@ -849,6 +1098,18 @@ pub struct Label {
     pub description: Option<String>,
 }
 
+/// How [`GitHubOperations::set_pull_request_labels`] treats the labels
+/// already on a pull request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LabelMode {
+    /// Keep the existing labels and add the new ones.
+    #[default]
+    Add,
+    /// Remove every existing label that is not in the new set.
+    Replace,
+}
+
 /// Pull request information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PullRequest {
@@ -952,6 +1213,36 @@ pub struct Tag {
     pub tagger: Option<GitUser>,
 }
 
+/// Parameters for creating a signed annotated tag
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedTagParams {
+    /// Commit SHA that the tag points to
+    pub commit_sha: String,
+    /// Tag message, without the signature
+    pub message: String,
+    /// Armored signature over the tag object
+    pub signature: String,
+    /// Time recorded in the tag object, in whole seconds
+    pub tagged_at: chrono::DateTime<chrono::Utc>,
+    /// Tag author information
+    pub tagger: GitUser,
+    /// Tag name
+    pub tag_name: String,
+}
+
+impl SignedTagParams {
+    /// The message stored in the tag object: the message followed by the
+    /// signature, which is how Git records a signed tag.
+    #[must_use]
+    pub fn signed_message(&self) -> String {
+        format!(
+            "{}{}",
+            crate::tag_signing::normalize_message(&self.message),
+            self.signature
+        )
+    }
+}
+
 /// Parameters for updating an existing release
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateReleaseParams {
@@ -965,6 +1256,15 @@ pub struct UpdateReleaseParams {
     pub prerelease: Option<bool>,
 }
 
+/// The outcome of [`GitHubOperations::upsert_branch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BranchUpsert {
+    /// The branch did not exist and was created at the requested commit.
+    Created,
+    /// The branch existed and was fast-forwarded to the requested commit.
+    Updated,
+}
+
 /// The level of access a GitHub user has to a repository.
 ///
 /// Returned by [`GitHubOperations::get_collaborator_permission`] and used
@@ -1001,3 +1301,22 @@ impl CollaboratorPermission {
         )
     }
 }
+
+/// The refs, relative to `refs/`, that [`GitHubOperations::resolve_ref`]
+/// tries for `git_ref`, in order.
+///
+/// A qualified ref (`refs/heads/main`, `tags/v1.0.0`) has a single
+/// candidate. A bare name is tried as a tag and then as a branch. Returns an
+/// empty list for an empty ref.
+#[must_use]
+pub fn ref_candidates(git_ref: &str) -> Vec<String> {
+    let git_ref = git_ref.trim();
+    let relative = git_ref.strip_prefix("refs/").unwrap_or(git_ref);
+    if relative.is_empty() {
+        Vec::new()
+    } else if relative.starts_with("heads/") || relative.starts_with("tags/") {
+        vec![relative.to_string()]
+    } else {
+        vec![format!("tags/{relative}"), format!("heads/{relative}")]
+    }
+}
@@ -14,6 +14,7 @@
 //!
 //! The trait abstractions follow a layered approach:
 //! - `EventSource`: Pluggable event delivery mechanism
+//! - `ChangelogRenderer`: Changelog rendering
 //! - `GitHubOperations`: GitHub API interactions
 //! - `ConfigurationProvider`: Configuration loading and validation
 //! - `VersionCalculator`: Version calculation strategies
@@ -25,12 +26,14 @@
 //! - Mock implementations (in testing crate)
 //! - Test fixtures (for deterministic testing)
 
+pub mod changelog_renderer;
 pub mod configuration_provider;
 pub mod event_source;
 pub mod git_operations;
 pub mod github_operations;
 pub mod version_calculator;
 
+pub use changelog_renderer::{ChangelogContext, ChangelogRenderer};
 pub use configuration_provider::ConfigurationProvider;
 pub use event_source::{EventSource, EventSourceKind, EventType, ProcessingEvent, RepositoryInfo};
 pub use git_operations::GitOperations;
@@ -69,6 +69,7 @@ fn test_version_calculator_data_structures() {
         owner: "owner".to_string(),
         repo: "repo".to_string(),
         target_branch: "main".to_string(),
+        package_versions: HashMap::new(),
     };
 
     let json = serde_json::to_string(&context).expect("VersionContext should serialize");
@@ -156,3 +157,17 @@ fn test_versioning_strategy_variants() {
     assert!(serde_json::to_string(&external).is_ok());
     assert!(serde_json::to_string(&manual).is_ok());
 }
+
+#[test]
+fn test_ref_candidates() {
+    use github_operations::ref_candidates;
+
+    assert_eq!(ref_candidates("refs/heads/main"), vec!["heads/main"]);
+    assert_eq!(ref_candidates("tags/v1.0.0"), vec!["tags/v1.0.0"]);
+    assert_eq!(
+        ref_candidates("release/v1.0.0"),
+        vec!["tags/release/v1.0.0", "heads/release/v1.0.0"]
+    );
+    assert!(ref_candidates(" ").is_empty());
+    assert!(ref_candidates("refs/").is_empty());
+}
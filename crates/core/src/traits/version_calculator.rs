@@ -3,10 +3,14 @@
 //! This trait defines the contract for calculating new versions based on
 //! commit history, conventional commits, and versioning strategies.
 
-use crate::{versioning::SemanticVersion, CoreResult};
+use crate::{
+    config::{CommitExclusionConfig, NoConventionalCommitsPolicy, ScopeFilterConfig},
+    versioning::SemanticVersion,
+    CoreResult,
+};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
 /// Version calculation context
@@ -24,6 +28,202 @@ pub struct VersionContext {
     pub repo: String,
     /// Target branch for version calculation
     pub target_branch: String,
+    /// Current version of each package, keyed by package name, for the
+    /// [`VersioningStrategy::Monorepo`] strategy
+    #[serde(default)]
+    pub package_versions: HashMap<String, SemanticVersion>,
+}
+
+impl VersionContext {
+    /// Start building a context; see [`VersionContextBuilder`] for the defaults.
+    #[must_use]
+    pub fn builder() -> VersionContextBuilder {
+        VersionContextBuilder::default()
+    }
+}
+
+/// Builder for [`VersionContext`]
+///
+/// Only the owner and repository name are required. Everything else has a
+/// default:
+///
+/// | Field | Default |
+/// |-------|---------|
+/// | `current_version` | `None` (no release yet) |
+/// | `base_ref` | `{version_prefix}{current_version}`, or `None` without a current version |
+/// | `head_ref` | `HEAD` |
+/// | `target_branch` | `main` |
+/// | `package_versions` | empty |
+///
+/// The version prefix used to derive `base_ref` defaults to `v`.
+#[derive(Debug, Clone)]
+pub struct VersionContextBuilder {
+    owner: String,
+    repo: String,
+    current_version: Option<SemanticVersion>,
+    base_ref: Option<String>,
+    version_prefix: String,
+    head_ref: String,
+    target_branch: String,
+    package_versions: HashMap<String, SemanticVersion>,
+}
+
+impl Default for VersionContextBuilder {
+    fn default() -> Self {
+        Self {
+            owner: String::new(),
+            repo: String::new(),
+            current_version: None,
+            base_ref: None,
+            version_prefix: "v".to_string(),
+            head_ref: "HEAD".to_string(),
+            target_branch: "main".to_string(),
+            package_versions: HashMap::new(),
+        }
+    }
+}
+
+impl VersionContextBuilder {
+    /// Set the repository owner
+    #[must_use]
+    pub fn with_owner(mut self, owner: impl Into<String>) -> Self {
+        self.owner = owner.into();
+        self
+    }
+
+    /// Set the repository name
+    #[must_use]
+    pub fn with_repo(mut self, repo: impl Into<String>) -> Self {
+        self.repo = repo.into();
+        self
+    }
+
+    /// Set the owner and repository name from an `owner/repo` string
+    ///
+    /// A string without a `/` is ignored and left for [`build`](Self::build)
+    /// to reject.
+    #[must_use]
+    pub fn with_repository(mut self, repository: &str) -> Self {
+        if let Some((owner, repo)) = repository.split_once('/') {
+            self.owner = owner.to_string();
+            self.repo = repo.to_string();
+        }
+        self
+    }
+
+    /// Set the current version; `None` means the repository has no release yet
+    #[must_use]
+    pub fn with_current_version(mut self, version: impl Into<Option<SemanticVersion>>) -> Self {
+        self.current_version = version.into();
+        self
+    }
+
+    /// Set the base reference explicitly instead of deriving it from the
+    /// current version
+    #[must_use]
+    pub fn with_base_ref(mut self, base_ref: impl Into<String>) -> Self {
+        self.base_ref = Some(base_ref.into());
+        self
+    }
+
+    /// Set the tag prefix used to derive the base reference from the current
+    /// version
+    #[must_use]
+    pub fn with_version_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.version_prefix = prefix.into();
+        self
+    }
+
+    /// Set the head reference
+    #[must_use]
+    pub fn with_head_ref(mut self, head_ref: impl Into<String>) -> Self {
+        self.head_ref = head_ref.into();
+        self
+    }
+
+    /// Set the target branch
+    #[must_use]
+    pub fn with_target_branch(mut self, branch: impl Into<String>) -> Self {
+        self.target_branch = branch.into();
+        self
+    }
+
+    /// Set the current version of a monorepo package
+    #[must_use]
+    pub fn with_package_version(
+        mut self,
+        package: impl Into<String>,
+        version: SemanticVersion,
+    ) -> Self {
+        self.package_versions.insert(package.into(), version);
+        self
+    }
+
+    /// Set the current versions of several monorepo packages
+    #[must_use]
+    pub fn with_package_versions(
+        mut self,
+        versions: impl IntoIterator<Item = (String, SemanticVersion)>,
+    ) -> Self {
+        self.package_versions.extend(versions);
+        self
+    }
+
+    /// Build the context
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::InvalidInput`](crate::CoreError::InvalidInput) when
+    /// the owner, repository name, head reference or target branch is empty.
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large
+    pub fn build(self) -> CoreResult<VersionContext> {
+        for (field, value) in [
+            ("owner", &self.owner),
+            ("repo", &self.repo),
+            ("head_ref", &self.head_ref),
+            ("target_branch", &self.target_branch),
+        ] {
+            if value.trim().is_empty() {
+                return Err(crate::CoreError::invalid_input(
+                    field,
+                    format!("{field} must not be empty"),
+                ));
+            }
+        }
+
+        let base_ref = self.base_ref.or_else(|| {
+            self.current_version
+                .as_ref()
+                .map(|v| format!("{}{v}", self.version_prefix))
+        });
+
+        Ok(VersionContext {
+            base_ref,
+            current_version: self.current_version,
+            head_ref: self.head_ref,
+            owner: self.owner,
+            repo: self.repo,
+            target_branch: self.target_branch,
+            package_versions: self.package_versions,
+        })
+    }
+}
+
+/// An independently versioned package within a monorepo
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackageSpec {
+    /// Package name
+    pub name: String,
+    /// Glob selecting the files that belong to the package (e.g. `crates/core/**`).
+    /// A pattern without wildcards matches that path and everything below it.
+    pub path: String,
+    /// Prefix of the package's release tags (e.g. `core-v`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag_prefix: Option<String>,
+    /// Changelog file of the package (e.g. `crates/core/CHANGELOG.md`); when
+    /// set, each release prepends the package's own entries to it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changelog_path: Option<String>,
 }
 
 /// Version calculation strategy
@@ -57,6 +257,11 @@ pub enum VersioningStrategy {
         /// Next version to use
         next_version: SemanticVersion,
     },
+    /// Conventional commits applied separately to each package of a monorepo
+    Monorepo {
+        /// Packages, each versioned from the commits that touch its files
+        packages: Vec<PackageSpec>,
+    },
 }
 
 /// Version bump type based on changes
@@ -72,6 +277,122 @@ pub enum VersionBump {
     Patch,
 }
 
+impl VersionBump {
+    /// Lower-case name of the bump: `major`, `minor`, `patch` or `none`.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Major => "major",
+            Self::Minor => "minor",
+            Self::Patch => "patch",
+            Self::None => "none",
+        }
+    }
+}
+
+/// Why a calculation chose its version bump
+///
+/// Displays as one line, e.g. `major: BREAKING CHANGE in abc1234` or
+/// `minor: feat in 1a2b3c4 (and 2 more)`. A reason without commits, such as
+/// `no version-bumping commits since the last release`, displays its rule
+/// alone.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BumpReason {
+    /// The bump this reason explains
+    pub bump: VersionBump,
+    /// The rule that selected the bump, e.g. `BREAKING CHANGE` or `feat`
+    pub rule: String,
+    /// SHAs of the commits that triggered the rule, earliest first
+    pub commits: Vec<String>,
+}
+
+impl BumpReason {
+    /// Rule of the reason for a [`VersionBump::None`] bump.
+    pub const NO_BUMPING_COMMITS: &'static str =
+        "no version-bumping commits since the last release";
+
+    /// Start of the rule when none of the commits since the last release is
+    /// a conventional commit; see
+    /// [`apply_no_conventional_commits_policy`](crate::versioning::apply_no_conventional_commits_policy).
+    pub const NO_CONVENTIONAL_COMMITS: &'static str =
+        "no conventional commits since the last release";
+
+    /// Explain `bump` from the commits it was calculated from.
+    ///
+    /// `commits` are `(sha, commit_type, version_bump)` triples, earliest
+    /// first. The commits whose own bump equals `bump` triggered it; a major
+    /// bump is attributed to `BREAKING CHANGE`, any other bump to the commit
+    /// types of its triggering commits.
+    #[must_use]
+    pub fn explain<'a>(
+        bump: &VersionBump,
+        commits: impl IntoIterator<Item = (&'a str, Option<&'a str>, &'a VersionBump)>,
+    ) -> Self {
+        if *bump == VersionBump::None {
+            return Self {
+                bump: VersionBump::None,
+                rule: Self::NO_BUMPING_COMMITS.to_string(),
+                commits: Vec::new(),
+            };
+        }
+
+        let mut types: Vec<&str> = Vec::new();
+        let mut shas = Vec::new();
+        for (sha, commit_type, commit_bump) in commits {
+            if commit_bump != bump {
+                continue;
+            }
+            if let Some(commit_type) = commit_type {
+                if !types.contains(&commit_type) {
+                    types.push(commit_type);
+                }
+            }
+            shas.push(sha.to_string());
+        }
+
+        let rule = if *bump == VersionBump::Major {
+            "BREAKING CHANGE".to_string()
+        } else if types.is_empty() {
+            format!("{} commits", bump.name())
+        } else {
+            types.join(", ")
+        };
+        Self {
+            bump: bump.clone(),
+            rule,
+            commits: shas,
+        }
+    }
+
+    /// Explain `bump` from `analyses`, given earliest first.
+    #[must_use]
+    pub fn from_analyses<'a>(
+        bump: &VersionBump,
+        analyses: impl IntoIterator<Item = &'a CommitAnalysis>,
+    ) -> Self {
+        Self::explain(
+            bump,
+            analyses
+                .into_iter()
+                .map(|a| (a.sha.as_str(), a.commit_type.as_deref(), &a.version_bump)),
+        )
+    }
+}
+
+impl std::fmt::Display for BumpReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(first) = self.commits.first() else {
+            return f.write_str(&self.rule);
+        };
+        let short: String = first.chars().take(7).collect();
+        write!(f, "{}: {} in {short}", self.bump.name(), self.rule)?;
+        if self.commits.len() > 1 {
+            write!(f, " (and {} more)", self.commits.len() - 1)?;
+        }
+        Ok(())
+    }
+}
+
 /// Commit analysis result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitAnalysis {
@@ -95,6 +416,36 @@ pub struct CommitAnalysis {
     pub version_bump: VersionBump,
 }
 
+impl CommitAnalysis {
+    /// Metadata key holding the commit author's email address, when known.
+    pub const AUTHOR_EMAIL: &'static str = "author_email";
+
+    /// Metadata key holding the commit author's platform login, when known.
+    pub const AUTHOR_LOGIN: &'static str = "author_login";
+
+    /// Rebuild the commit author from [`author`](Self::author) and the
+    /// [`AUTHOR_EMAIL`](Self::AUTHOR_EMAIL) / [`AUTHOR_LOGIN`](Self::AUTHOR_LOGIN)
+    /// metadata.
+    ///
+    /// Returns `None` when neither a name nor an email address is known.
+    #[must_use]
+    pub fn author_user(&self) -> Option<crate::traits::git_operations::GitUser> {
+        let email = self
+            .metadata
+            .get(Self::AUTHOR_EMAIL)
+            .cloned()
+            .unwrap_or_default();
+        if self.author.is_empty() && email.is_empty() {
+            return None;
+        }
+        Some(crate::traits::git_operations::GitUser {
+            name: self.author.clone(),
+            email,
+            login: self.metadata.get(Self::AUTHOR_LOGIN).cloned(),
+        })
+    }
+}
+
 /// Version calculation result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VersionCalculationResult {
@@ -116,6 +467,94 @@ pub struct VersionCalculationResult {
     pub strategy: VersioningStrategy,
     /// Version bump type applied
     pub version_bump: VersionBump,
+    /// Why [`version_bump`](Self::version_bump) was chosen; `None` when the
+    /// calculator does not explain its results
+    #[serde(default)]
+    pub bump_reason: Option<BumpReason>,
+    /// Per-package results keyed by package name; only filled in for the
+    /// [`VersioningStrategy::Monorepo`] strategy
+    #[serde(default)]
+    pub packages: BTreeMap<String, PackageVersionResult>,
+}
+
+impl VersionCalculationResult {
+    /// Metadata key set, to the minimum version, when
+    /// [`apply_minimum_version`](Self::apply_minimum_version) replaced the
+    /// calculated version.
+    pub const MINIMUM_VERSION_APPLIED: &'static str = "minimum_version_applied";
+
+    /// Whether this is the first release of the repository, i.e. there was no
+    /// current version to calculate from.
+    #[must_use]
+    pub fn is_first_release(&self) -> bool {
+        self.current_version.is_none()
+    }
+
+    /// Raise the calculated version to `minimum` when `minimum` is higher.
+    ///
+    /// This takes the highest of the calculated version and a fixed floor,
+    /// e.g. while migrating from another versioning scheme.
+    ///
+    /// - Versions are compared by SemVer precedence, so build metadata is
+    ///   ignored and a pre-release is lower than its release: a floor of
+    ///   `2.0.0-rc.1` replaces `1.4.0` but not `2.0.0`.
+    /// - When the floor wins, its major, minor, patch and pre-release are used,
+    ///   while the build metadata of the calculated version is kept, because it
+    ///   describes this build rather than the version. `is_prerelease` follows
+    ///   the floor.
+    /// - When both have the same precedence the calculated version is kept.
+    /// - When the floor wins, [`bump_reason`](Self::bump_reason) names it.
+    /// - Per-package versions of the monorepo strategy are not affected.
+    ///
+    /// Returns `true` when the floor replaced the calculated version.
+    pub fn apply_minimum_version(&mut self, minimum: &SemanticVersion) -> bool {
+        if minimum.compare_precedence(&self.next_version) != std::cmp::Ordering::Greater {
+            return false;
+        }
+
+        self.next_version = SemanticVersion {
+            build: self.next_version.build.take(),
+            ..minimum.clone()
+        };
+        self.is_prerelease = self.next_version.is_prerelease();
+        self.bump_reason = Some(BumpReason {
+            bump: self.version_bump.clone(),
+            rule: format!(
+                "raised to the configured minimum version {}",
+                SemanticVersion {
+                    build: None,
+                    ..minimum.clone()
+                }
+            ),
+            commits: Vec::new(),
+        });
+        self.metadata.insert(
+            Self::MINIMUM_VERSION_APPLIED.to_string(),
+            SemanticVersion {
+                build: None,
+                ..minimum.clone()
+            }
+            .to_string(),
+        );
+        true
+    }
+}
+
+/// Version calculation result for one package of a monorepo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageVersionResult {
+    /// Commits that touch the package
+    pub analyzed_commits: Vec<CommitAnalysis>,
+    /// Changelog entries generated from the package's commits
+    pub changelog_entries: Vec<ChangelogEntry>,
+    /// Current version of the package (starting point)
+    pub current_version: Option<SemanticVersion>,
+    /// Calculated next version of the package
+    pub next_version: SemanticVersion,
+    /// Prefix of the package's release tags
+    pub tag_prefix: Option<String>,
+    /// Version bump type applied
+    pub version_bump: VersionBump,
 }
 
 /// Changelog entry for a version
@@ -160,16 +599,42 @@ pub struct ValidationRules {
 // splitting into enums would add complexity without clarity.
 #[allow(clippy::struct_excessive_bools)]
 pub struct CalculationOptions {
-    /// Build metadata to include
+    /// Commit scopes that may bump the version; commits with other scopes
+    /// are still analyzed and included in the changelog
+    #[serde(default)]
+    pub bump_scopes: ScopeFilterConfig,
+    /// Commit scopes whose commits are breaking changes regardless of their
+    /// type; see [`mark_breaking_scopes`](crate::versioning::mark_breaking_scopes)
+    #[serde(default)]
+    pub breaking_scopes: Vec<String>,
+    /// Build metadata template appended to the calculated version, e.g.
+    /// `sha.{short_sha}` or `{timestamp}`; see
+    /// [`render_build_metadata`](crate::versioning::render_build_metadata)
     pub build_metadata: Option<String>,
     /// Whether to perform dry-run (no side effects)
     pub dry_run: bool,
+    /// Rules for leaving commits out of the calculation and changelog
+    #[serde(default)]
+    pub exclusions: CommitExclusionConfig,
     /// Whether to generate changelog entries
     pub generate_changelog: bool,
     /// Whether to include pre-release identifier
     pub include_prerelease: bool,
+    /// Version of the first release, used as-is when the context has no
+    /// current version; without it the first release is calculated from
+    /// `0.1.0`
+    #[serde(default)]
+    pub initial_version: Option<SemanticVersion>,
     /// Maximum number of commits to analyze
     pub max_commits: Option<u32>,
+    /// What to do when none of the analyzed commits is conventional; see
+    /// [`apply_no_conventional_commits_policy`](crate::versioning::apply_no_conventional_commits_policy)
+    #[serde(default)]
+    pub no_conventional_commits: NoConventionalCommitsPolicy,
+    /// Lowest version the calculation may return; see
+    /// [`VersionCalculationResult::apply_minimum_version`]
+    #[serde(default)]
+    pub minimum_version: Option<SemanticVersion>,
     /// Pre-release identifier to use
     pub prerelease_identifier: Option<String>,
     /// Whether to validate calculated version
@@ -191,6 +656,7 @@ pub struct CalculationOptions {
 /// - Calendar Versioning: Date-based version numbers
 /// - External: Custom calculation using external commands
 /// - Manual: Explicit version specification
+/// - Monorepo: Conventional commits per package, selected by changed paths
 ///
 /// # Error Handling
 ///
@@ -224,7 +690,9 @@ pub trait VersionCalculator: Send + Sync {
     /// - `options`: Calculation options and preferences
     ///
     /// # Returns
-    /// Complete version calculation result with analysis and metadata
+    /// Complete version calculation result with analysis and metadata. With
+    /// [`VersioningStrategy::Monorepo`] the top-level fields cover all commits
+    /// and [`VersionCalculationResult::packages`] holds one result per package.
     ///
     /// # Errors
     /// - `CoreError::Versioning` - Version calculation failed
@@ -535,3 +1003,7 @@ impl VersionCalculator for Arc<dyn VersionCalculator + Send + Sync> {
         (**self).scoped_to(installation_id)
     }
 }
+
+#[cfg(test)]
+#[path = "version_calculator_tests.rs"]
+mod tests;
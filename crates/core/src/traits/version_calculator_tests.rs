@@ -0,0 +1,228 @@
+use super::*;
+use crate::versioning::VersionCalculator as Parser;
+
+fn version(s: &str) -> SemanticVersion {
+    Parser::parse_version(s).unwrap()
+}
+
+fn result_with(next_version: &str) -> VersionCalculationResult {
+    let next_version = version(next_version);
+    VersionCalculationResult {
+        analyzed_commits: Vec::new(),
+        build_metadata: next_version.build.clone(),
+        changelog_entries: Vec::new(),
+        current_version: None,
+        is_prerelease: next_version.is_prerelease(),
+        metadata: HashMap::new(),
+        next_version,
+        strategy: VersioningStrategy::ConventionalCommits {
+            custom_types: HashMap::new(),
+            include_prerelease: false,
+        },
+        version_bump: VersionBump::Patch,
+        bump_reason: None,
+        packages: BTreeMap::new(),
+    }
+}
+
+#[test]
+fn test_apply_minimum_version_raises_lower_version() {
+    let mut result = result_with("1.4.3");
+
+    assert!(result.apply_minimum_version(&version("2.0.0")));
+    assert_eq!(result.next_version, version("2.0.0"));
+    assert_eq!(
+        result
+            .metadata
+            .get(VersionCalculationResult::MINIMUM_VERSION_APPLIED)
+            .map(String::as_str),
+        Some("2.0.0")
+    );
+    assert_eq!(
+        result.bump_reason.map(|r| r.to_string()).as_deref(),
+        Some("raised to the configured minimum version 2.0.0")
+    );
+}
+
+#[test]
+fn test_apply_minimum_version_keeps_higher_or_equal_version() {
+    let mut higher = result_with("2.1.0");
+    let mut equal = result_with("2.0.0+build.7");
+
+    assert!(!higher.apply_minimum_version(&version("2.0.0")));
+    assert!(!equal.apply_minimum_version(&version("2.0.0+other")));
+    assert_eq!(higher.next_version, version("2.1.0"));
+    assert_eq!(equal.next_version, version("2.0.0+build.7"));
+    assert!(equal.metadata.is_empty());
+}
+
+#[test]
+fn test_apply_minimum_version_keeps_calculated_build_metadata() {
+    let mut result = result_with("1.4.3+sha.0123456");
+
+    assert!(result.apply_minimum_version(&version("2.0.0+ignored")));
+    assert_eq!(result.next_version.to_string(), "2.0.0+sha.0123456");
+    assert_eq!(
+        result
+            .metadata
+            .get(VersionCalculationResult::MINIMUM_VERSION_APPLIED)
+            .map(String::as_str),
+        Some("2.0.0")
+    );
+}
+
+#[test]
+fn test_apply_minimum_version_compares_prereleases_by_precedence() {
+    let mut lower = result_with("1.9.0");
+    assert!(lower.apply_minimum_version(&version("2.0.0-rc.1")));
+    assert_eq!(lower.next_version, version("2.0.0-rc.1"));
+    assert!(lower.is_prerelease);
+
+    // A release is higher than its own pre-release.
+    let mut release = result_with("2.0.0");
+    assert!(!release.apply_minimum_version(&version("2.0.0-rc.1")));
+
+    // A stable floor above a calculated pre-release clears is_prerelease.
+    let mut prerelease = result_with("2.0.0-beta.2");
+    assert!(prerelease.apply_minimum_version(&version("2.0.0")));
+    assert!(!prerelease.is_prerelease);
+}
+
+#[test]
+fn test_version_context_builder_fills_defaults() {
+    let ctx = VersionContext::builder()
+        .with_owner("octo")
+        .with_repo("widgets")
+        .build()
+        .unwrap();
+
+    assert_eq!(ctx.owner, "octo");
+    assert_eq!(ctx.repo, "widgets");
+    assert_eq!(ctx.current_version, None);
+    assert_eq!(ctx.base_ref, None);
+    assert_eq!(ctx.head_ref, "HEAD");
+    assert_eq!(ctx.target_branch, "main");
+    assert!(ctx.package_versions.is_empty());
+}
+
+#[test]
+fn test_version_context_builder_derives_base_ref_from_current_version() {
+    let ctx = VersionContext::builder()
+        .with_repository("octo/widgets")
+        .with_current_version(version("1.2.3"))
+        .build()
+        .unwrap();
+
+    assert_eq!(ctx.current_version, Some(version("1.2.3")));
+    assert_eq!(ctx.base_ref.as_deref(), Some("v1.2.3"));
+
+    let ctx = VersionContext::builder()
+        .with_repository("octo/widgets")
+        .with_current_version(version("1.2.3"))
+        .with_version_prefix("release-")
+        .build()
+        .unwrap();
+
+    assert_eq!(ctx.base_ref.as_deref(), Some("release-1.2.3"));
+}
+
+#[test]
+fn test_version_context_builder_explicit_base_ref_wins() {
+    let ctx = VersionContext::builder()
+        .with_repository("octo/widgets")
+        .with_current_version(version("1.2.3"))
+        .with_base_ref("abc123")
+        .with_head_ref("def456")
+        .with_target_branch("develop")
+        .with_package_version("core", version("0.4.0"))
+        .build()
+        .unwrap();
+
+    assert_eq!(ctx.base_ref.as_deref(), Some("abc123"));
+    assert_eq!(ctx.head_ref, "def456");
+    assert_eq!(ctx.target_branch, "develop");
+    assert_eq!(ctx.package_versions.get("core"), Some(&version("0.4.0")));
+}
+
+#[test]
+fn test_version_context_builder_rejects_empty_owner_or_repo() {
+    for builder in [
+        VersionContext::builder().with_repo("widgets"),
+        VersionContext::builder().with_owner("octo"),
+        VersionContext::builder()
+            .with_owner(" ")
+            .with_repo("widgets"),
+        VersionContext::builder().with_repository("widgets"),
+    ] {
+        let err = builder.build().unwrap_err();
+        assert!(
+            matches!(err, crate::CoreError::InvalidInput { ref field, .. } if field == "owner" || field == "repo"),
+            "{err:?}"
+        );
+    }
+}
+
+#[test]
+fn test_version_context_builder_rejects_empty_head_ref() {
+    let err = VersionContext::builder()
+        .with_repository("octo/widgets")
+        .with_head_ref("")
+        .build()
+        .unwrap_err();
+
+    assert!(
+        matches!(err, crate::CoreError::InvalidInput { ref field, .. } if field == "head_ref"),
+        "{err:?}"
+    );
+}
+
+// ── BumpReason ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_bump_reason_attributes_major_bump_to_breaking_commits() {
+    let reason = BumpReason::explain(
+        &VersionBump::Major,
+        [
+            ("1111111aaaa", Some("feat"), &VersionBump::Minor),
+            ("2222222bbbb", Some("fix"), &VersionBump::Major),
+            ("3333333cccc", Some("feat"), &VersionBump::Major),
+        ],
+    );
+
+    assert_eq!(reason.rule, "BREAKING CHANGE");
+    assert_eq!(reason.commits, vec!["2222222bbbb", "3333333cccc"]);
+    assert_eq!(
+        reason.to_string(),
+        "major: BREAKING CHANGE in 2222222 (and 1 more)"
+    );
+}
+
+#[test]
+fn test_bump_reason_lists_every_triggering_commit_type() {
+    let reason = BumpReason::explain(
+        &VersionBump::Patch,
+        [
+            ("aaaaaaa", Some("fix"), &VersionBump::Patch),
+            ("bbbbbbb", Some("docs"), &VersionBump::None),
+            ("ccccccc", Some("revert"), &VersionBump::Patch),
+            ("ddddddd", Some("fix"), &VersionBump::Patch),
+        ],
+    );
+
+    assert_eq!(reason.rule, "fix, revert");
+    assert_eq!(
+        reason.to_string(),
+        "patch: fix, revert in aaaaaaa (and 2 more)"
+    );
+}
+
+#[test]
+fn test_bump_reason_for_no_bump_has_no_commits() {
+    let reason = BumpReason::explain(
+        &VersionBump::None,
+        [("aaaaaaa", Some("docs"), &VersionBump::None)],
+    );
+
+    assert!(reason.commits.is_empty());
+    assert_eq!(reason.to_string(), BumpReason::NO_BUMPING_COMMITS);
+}
@@ -36,7 +36,9 @@
 //!         description: "add OAuth support".to_string(),
 //!         breaking_change: false,
 //!         message: "feat(auth): add OAuth support".to_string(),
+//!         body: None,
 //!         sha: "abc123".to_string(),
+//!         author: None,
 //!     }
 //! ];
 //!
@@ -101,9 +103,18 @@
 //! |------------|----------------|--------------|
 //! | `feat` | No | Minor |
 //! | `fix` | No | Patch |
+//! | `revert` | No | Patch, or None when paired |
 //! | Any | Yes | Major |
 //! | Other types | No | None |
 //!
+//! ### Reverts
+//!
+//! Both `revert: feat: add X` and GitHub's `Revert "feat: add X"` are parsed as
+//! `revert` commits. A revert is paired with the commit it undoes — by the SHA
+//! in a `This reverts commit <sha>.` line, or else by the reverted subject —
+//! when that commit is part of the same release; the pair then has no effect
+//! on the version. See [`cancelled_by_reverts`].
+//!
 //! ## Semantic Versioning Compliance
 //!
 //! This implementation strictly follows [Semantic Versioning 2.0.0](https://semver.org/):
@@ -128,9 +139,12 @@
 //! ```
 
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::fmt;
 
-use crate::traits::git_operations::{GitTag, ListTagsOptions};
+use crate::config::NoConventionalCommitsPolicy;
+use crate::traits::git_operations::{GetCommitsOptions, GitCommit, GitTag, ListTagsOptions};
+use crate::traits::version_calculator::BumpReason;
 use crate::{CoreError, CoreResult};
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
@@ -174,8 +188,267 @@ pub struct ConventionalCommit {
     pub breaking_change: bool,
     /// Full commit message
     pub message: String,
+    /// Commit body: the message after the subject line, without footers such
+    /// as `BREAKING CHANGE:` or `Refs:`; see [`commit_body`]
+    #[serde(default)]
+    pub body: Option<String>,
     /// Commit SHA
     pub sha: String,
+    /// Commit author, when known.
+    ///
+    /// Used by [`crate::changelog::ChangelogGenerator`] to render contributor
+    /// mentions; commits parsed from bare messages carry no author.
+    #[serde(default)]
+    pub author: Option<crate::traits::git_operations::GitUser>,
+}
+
+impl ConventionalCommit {
+    /// Whether this commit reverts another one.
+    #[must_use]
+    pub fn is_revert(&self) -> bool {
+        self.commit_type.eq_ignore_ascii_case("revert")
+    }
+
+    /// The version bump this commit calls for on its own, ignoring reverts
+    /// paired with it; see [`cancelled_by_reverts`].
+    ///
+    /// Revert commits call for a patch bump, since they change released
+    /// behaviour.
+    #[must_use]
+    pub fn version_bump(&self) -> VersionBump {
+        if self.breaking_change {
+            VersionBump::Major
+        } else if self.commit_type == "feat" {
+            VersionBump::Minor
+        } else if self.commit_type == "fix" || self.is_revert() {
+            VersionBump::Patch
+        } else {
+            VersionBump::None
+        }
+    }
+
+    /// SHA of the reverted commit, taken from the `This reverts commit <sha>.`
+    /// line `git revert` adds to the message. `None` for other commits.
+    #[must_use]
+    pub fn reverted_sha(&self) -> Option<&str> {
+        if !self.is_revert() {
+            return None;
+        }
+        self.message.lines().find_map(|line| {
+            let sha = line
+                .trim()
+                .strip_prefix("This reverts commit ")?
+                .trim_end_matches('.');
+            (sha.len() >= 7 && sha.chars().all(|c| c.is_ascii_hexdigit())).then_some(sha)
+        })
+    }
+
+    /// Subject line of the reverted commit, i.e. the description without the
+    /// quotes `git revert` puts around it. `None` for other commits.
+    #[must_use]
+    pub fn reverted_subject(&self) -> Option<&str> {
+        if !self.is_revert() {
+            return None;
+        }
+        let description = self.description.trim();
+        Some(
+            description
+                .strip_prefix('"')
+                .and_then(|d| d.strip_suffix('"'))
+                .unwrap_or(description),
+        )
+    }
+
+    /// Whether `revert` names `self` by SHA.
+    fn is_reverted_by_sha(&self, revert: &Self) -> bool {
+        revert.reverted_sha().is_some_and(|sha| {
+            !self.sha.is_empty() && (self.sha.starts_with(sha) || sha.starts_with(&self.sha))
+        })
+    }
+
+    /// Whether `revert` names `self` by its subject line.
+    fn is_reverted_by_subject(&self, revert: &Self) -> bool {
+        revert
+            .reverted_subject()
+            .is_some_and(|subject| self.message.lines().next().map(str::trim) == Some(subject))
+    }
+}
+
+/// Mark commits whose scope is one of `breaking_scopes` as breaking changes.
+///
+/// Some teams treat every change to a scope such as `api` as breaking without
+/// writing `!` or a `BREAKING CHANGE:` footer. Marked commits force a major
+/// bump whatever their type, and the changelog renders them as breaking.
+/// Scopes are compared case-insensitively; commits without a scope are never
+/// marked.
+pub fn mark_breaking_scopes(commits: &mut [ConventionalCommit], breaking_scopes: &[String]) {
+    if breaking_scopes.is_empty() {
+        return;
+    }
+    for commit in commits.iter_mut().filter(|c| !c.breaking_change) {
+        let breaking = commit.scope.as_deref().is_some_and(|scope| {
+            breaking_scopes
+                .iter()
+                .any(|breaking| breaking.eq_ignore_ascii_case(scope))
+        });
+        if breaking {
+            debug!(sha = %commit.sha, scope = ?commit.scope, "Scope marks commit as breaking");
+            commit.breaking_change = true;
+        }
+    }
+}
+
+/// The bump a pull request title calls for when read as a conventional
+/// commit message, e.g. [`BumpKind::Major`] for `feat!: new API`.
+///
+/// Scopes in `breaking_scopes` mark the title as breaking, as they do for
+/// commits; see [`mark_breaking_scopes`]. Returns `None` for titles that are
+/// not conventional or whose type does not bump the version.
+///
+/// ```
+/// use release_regent_core::versioning::{pr_title_bump, BumpKind};
+///
+/// assert_eq!(pr_title_bump("feat!: drop v1 API", &[]), Some(BumpKind::Major));
+/// assert_eq!(pr_title_bump("fix: typo", &[]), Some(BumpKind::Patch));
+/// assert_eq!(pr_title_bump("Update README", &[]), None);
+/// ```
+#[must_use]
+pub fn pr_title_bump(title: &str, breaking_scopes: &[String]) -> Option<BumpKind> {
+    let mut parsed =
+        VersionCalculator::parse_conventional_commits(&[(String::new(), title.trim().to_string())]);
+    mark_breaking_scopes(&mut parsed, breaking_scopes);
+    match parsed.first()?.version_bump() {
+        VersionBump::Major => Some(BumpKind::Major),
+        VersionBump::Minor => Some(BumpKind::Minor),
+        VersionBump::Patch => Some(BumpKind::Patch),
+        VersionBump::None => None,
+    }
+}
+
+/// Whether `message` is a conventional commit message, e.g. `fix: typo`.
+///
+/// The subject GitHub and `git revert` give a revert, `Revert "<subject>"`,
+/// counts as conventional because it is analyzed as a `revert` commit.
+///
+/// ```
+/// use release_regent_core::versioning::is_conventional_message;
+///
+/// assert!(is_conventional_message("feat(api): add search"));
+/// assert!(is_conventional_message("Revert \"feat: add search\""));
+/// assert!(!is_conventional_message("Update README"));
+/// ```
+#[must_use]
+pub fn is_conventional_message(message: &str) -> bool {
+    let subject = message.lines().next().unwrap_or(message);
+    reverted_subject_of_revert(subject).is_some()
+        || git_conventional::Commit::parse(message).is_ok()
+        || git_conventional::Commit::parse(subject).is_ok()
+}
+
+/// Apply `policy` to a calculation over `commits`, `(sha, message)` pairs,
+/// that resulted in `bump` for `reason`.
+///
+/// The policy applies only when there is at least one commit and none of the
+/// messages is conventional; otherwise `bump` and `reason` are returned
+/// unchanged. [`NoConventionalCommitsPolicy::Patch`] turns the bump into a
+/// patch bump attributed to all commits, and
+/// [`NoConventionalCommitsPolicy::None`] keeps the bump. Both name the policy
+/// in the returned reason.
+///
+/// # Errors
+///
+/// Returns [`CoreError::Versioning`] under
+/// [`NoConventionalCommitsPolicy::Error`].
+///
+/// [`NoConventionalCommitsPolicy::Patch`]: crate::config::NoConventionalCommitsPolicy::Patch
+/// [`NoConventionalCommitsPolicy::None`]: crate::config::NoConventionalCommitsPolicy::None
+/// [`NoConventionalCommitsPolicy::Error`]: crate::config::NoConventionalCommitsPolicy::Error
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+pub fn apply_no_conventional_commits_policy(
+    policy: NoConventionalCommitsPolicy,
+    commits: &[(String, String)],
+    bump: VersionBump,
+    reason: BumpReason,
+) -> CoreResult<(VersionBump, BumpReason)> {
+    if commits.is_empty()
+        || commits
+            .iter()
+            .any(|(_, message)| is_conventional_message(message))
+    {
+        return Ok((bump, reason));
+    }
+
+    let rule = format!(
+        "{} ({} commits; no_conventional_commits = \"{policy}\")",
+        BumpReason::NO_CONVENTIONAL_COMMITS,
+        commits.len()
+    );
+    debug!(policy = %policy, commits = commits.len(), "No conventional commits");
+    match policy {
+        NoConventionalCommitsPolicy::Error => Err(CoreError::versioning(format!(
+            "none of the {} commits since the last release is a conventional commit \
+             and versioning.no_conventional_commits is \"error\"",
+            commits.len()
+        ))),
+        NoConventionalCommitsPolicy::Patch => Ok((
+            VersionBump::Patch,
+            BumpReason {
+                bump: VersionBump::Patch,
+                rule,
+                commits: commits.iter().map(|(sha, _)| sha.clone()).collect(),
+            },
+        )),
+        NoConventionalCommitsPolicy::None => Ok((
+            bump.clone(),
+            BumpReason {
+                bump,
+                rule,
+                commits: Vec::new(),
+            },
+        )),
+    }
+}
+
+/// The reverted subject in the default subject of `git revert` and GitHub's
+/// revert button, `Revert "<subject>"`.
+pub(crate) fn reverted_subject_of_revert(subject: &str) -> Option<&str> {
+    subject
+        .strip_prefix("Revert \"")
+        .and_then(|rest| rest.strip_suffix('"'))
+}
+
+/// Indices of the commits whose version impact is cancelled by reverts.
+///
+/// Each revert in `commits` is paired with the commit it reverts, when that
+/// commit is also in `commits`; both are included in the result. The SHA in a
+/// `This reverts commit <sha>.` line takes precedence over a matching subject. A commit is
+/// paired with at most one revert. Reverts whose target is not in `commits`,
+/// e.g. because it was part of an earlier release, stay unpaired and keep
+/// their own [`ConventionalCommit::version_bump`].
+#[must_use]
+pub fn cancelled_by_reverts(commits: &[ConventionalCommit]) -> HashSet<usize> {
+    let mut cancelled = HashSet::new();
+    for (revert_index, revert) in commits.iter().enumerate() {
+        if !revert.is_revert() || cancelled.contains(&revert_index) {
+            continue;
+        }
+        let candidates = || {
+            (0..commits.len()).filter(|&index| index != revert_index && !cancelled.contains(&index))
+        };
+        let target = candidates()
+            .find(|&index| commits[index].is_reverted_by_sha(revert))
+            .or_else(|| candidates().find(|&index| commits[index].is_reverted_by_subject(revert)));
+        if let Some(target) = target {
+            debug!(
+                revert = %revert.sha,
+                reverted = %commits[target].sha,
+                "Revert cancels the version impact of the reverted commit"
+            );
+            cancelled.insert(revert_index);
+            cancelled.insert(target);
+        }
+    }
+    cancelled
 }
 
 /// Semantic version representation
@@ -336,13 +609,25 @@ pub use crate::traits::version_calculator::VersionBump;
 /// Version calculation engine
 pub struct VersionCalculator {
     current_version: Option<SemanticVersion>,
+    breaking_scopes: Vec<String>,
 }
 
 impl VersionCalculator {
     /// Create a new version calculator
     #[must_use]
     pub fn new(current_version: Option<SemanticVersion>) -> Self {
-        Self { current_version }
+        Self {
+            current_version,
+            breaking_scopes: Vec::new(),
+        }
+    }
+
+    /// Treat commits with any of `breaking_scopes` as breaking changes; see
+    /// [`mark_breaking_scopes`].
+    #[must_use]
+    pub fn with_breaking_scopes(mut self, breaking_scopes: Vec<String>) -> Self {
+        self.breaking_scopes = breaking_scopes;
+        self
     }
 
     /// Calculate the next version based on conventional commits
@@ -364,7 +649,13 @@ impl VersionCalculator {
             "Calculating next version from commits"
         );
 
-        let bump = Self::determine_version_bump(commits);
+        let bump = if self.breaking_scopes.is_empty() {
+            Self::determine_version_bump(commits)
+        } else {
+            let mut commits = commits.to_vec();
+            mark_breaking_scopes(&mut commits, &self.breaking_scopes);
+            Self::determine_version_bump(&commits)
+        };
         debug!(bump = ?bump, "Determined version bump");
 
         let base_version = self.current_version.clone().unwrap_or_else(|| {
@@ -384,19 +675,27 @@ impl VersionCalculator {
         Ok(next_version)
     }
 
-    /// Determine the type of version bump needed
-    fn determine_version_bump(commits: &[ConventionalCommit]) -> VersionBump {
+    /// Determine the type of version bump required by `commits`.
+    ///
+    /// A revert and the commit it reverts cancel each other out; see
+    /// [`cancelled_by_reverts`]. Returns [`VersionBump::None`] when no other
+    /// commit is a breaking change, a feature, a fix or a revert.
+    #[must_use]
+    pub fn determine_version_bump(commits: &[ConventionalCommit]) -> VersionBump {
+        let cancelled = cancelled_by_reverts(commits);
         let mut has_breaking = false;
         let mut has_features = false;
         let mut has_fixes = false;
 
-        for commit in commits {
-            if commit.breaking_change {
-                has_breaking = true;
-            } else if commit.commit_type == "feat" {
-                has_features = true;
-            } else if commit.commit_type == "fix" {
-                has_fixes = true;
+        for (index, commit) in commits.iter().enumerate() {
+            if cancelled.contains(&index) {
+                continue;
+            }
+            match commit.version_bump() {
+                VersionBump::Major => has_breaking = true,
+                VersionBump::Minor => has_features = true,
+                VersionBump::Patch => has_fixes = true,
+                VersionBump::None => {}
             }
         }
 
@@ -411,6 +710,33 @@ impl VersionCalculator {
         }
     }
 
+    /// Explain the bump [`determine_version_bump`](Self::determine_version_bump)
+    /// chooses for `commits`, given earliest first.
+    ///
+    /// Commits cancelled by a revert never trigger the bump.
+    #[must_use]
+    pub fn explain_version_bump(commits: &[ConventionalCommit]) -> BumpReason {
+        let cancelled = cancelled_by_reverts(commits);
+        let bumps: Vec<VersionBump> = commits
+            .iter()
+            .enumerate()
+            .map(|(index, commit)| {
+                if cancelled.contains(&index) {
+                    VersionBump::None
+                } else {
+                    commit.version_bump()
+                }
+            })
+            .collect();
+
+        BumpReason::explain(
+            &Self::determine_version_bump(commits),
+            commits.iter().zip(&bumps).map(|(commit, bump)| {
+                (commit.sha.as_str(), Some(commit.commit_type.as_str()), bump)
+            }),
+        )
+    }
+
     /// Apply version bump to base version
     ///
     /// Delegates to the public [`apply_semver_bump`] free function so that all
@@ -607,8 +933,29 @@ impl VersionCalculator {
     }
 
     /// Parse a single conventional commit message
+    ///
+    /// The full message is parsed so that `BREAKING CHANGE:` footers in the
+    /// body are detected. When the body does not follow the conventional
+    /// layout (e.g. no blank line after the subject), the subject line alone
+    /// is parsed instead.
     fn parse_single_conventional_commit(sha: &str, message: &str) -> ConventionalCommit {
-        match git_conventional::Commit::parse(message) {
+        let subject = message.lines().next().unwrap_or(message);
+        if let Some(reverted) = reverted_subject_of_revert(subject) {
+            return ConventionalCommit {
+                commit_type: "revert".to_string(),
+                scope: None,
+                description: reverted.to_string(),
+                breaking_change: false,
+                message: message.to_string(),
+                body: commit_body(message),
+                sha: sha.to_string(),
+                author: None,
+            };
+        }
+
+        let parsed = git_conventional::Commit::parse(message)
+            .or_else(|_| git_conventional::Commit::parse(subject));
+        match parsed {
             Ok(parsed_commit) => {
                 let commit_type = parsed_commit.type_().as_str().to_string();
                 let scope = parsed_commit.scope().map(|s| s.as_str().to_string());
@@ -621,7 +968,9 @@ impl VersionCalculator {
                     description,
                     breaking_change,
                     message: message.to_string(),
+                    body: commit_body(message),
                     sha: sha.to_string(),
+                    author: None,
                 }
             }
             Err(err) => {
@@ -633,13 +982,52 @@ impl VersionCalculator {
                     description: message.lines().next().unwrap_or(message).to_string(),
                     breaking_change: false,
                     message: message.to_string(),
+                    body: commit_body(message),
                     sha: sha.to_string(),
+                    author: None,
                 }
             }
         }
     }
 }
 
+/// Extract the body of a commit message.
+///
+/// The body is the text after the subject line, with the trailing footers
+/// (`BREAKING CHANGE: ...`, `Refs: #12`, `Co-authored-by: ...`) removed and
+/// surrounding whitespace trimmed. Paragraphs inside the body are kept as
+/// they are. Works for non-conventional subjects too; when the rest of the
+/// message does not follow the conventional layout it is returned whole.
+///
+/// Returns `None` when the message has no body.
+///
+/// ```
+/// use release_regent_core::versioning::commit_body;
+///
+/// let message = "feat: add login\n\nFirst paragraph.\n\nSecond paragraph.\n\nRefs: #12";
+/// assert_eq!(
+///     commit_body(message).as_deref(),
+///     Some("First paragraph.\n\nSecond paragraph.")
+/// );
+/// assert_eq!(commit_body("fix: typo"), None);
+/// ```
+#[must_use]
+pub fn commit_body(message: &str) -> Option<String> {
+    let (_, rest) = message.split_once('\n')?;
+    let rest = rest.trim();
+    if rest.is_empty() {
+        return None;
+    }
+    // Only the body and footers matter here, so a fixed conventional subject
+    // lets the parser separate them whatever the real subject looks like.
+    let normalized = format!("chore: body\n\n{rest}");
+    let body = match git_conventional::Commit::parse(&normalized) {
+        Ok(parsed) => parsed.body().map(str::trim),
+        Err(_) => Some(rest),
+    };
+    body.filter(|body| !body.is_empty()).map(str::to_string)
+}
+
 /// Apply a standard semver bump to a base version.
 ///
 /// This is the single canonical implementation of semver arithmetic for
@@ -775,6 +1163,75 @@ pub fn apply_bump_floor(
     }
 }
 
+/// Render a build metadata template such as `sha.{short_sha}` or `{timestamp}`.
+///
+/// Supported placeholders:
+///
+/// | Placeholder   | Value                                            |
+/// |---------------|--------------------------------------------------|
+/// | `{sha}`       | Full commit SHA of the calculated head           |
+/// | `{short_sha}` | First 7 characters of the commit SHA             |
+/// | `{timestamp}` | `timestamp` in UTC, formatted as `%Y%m%d%H%M%S`  |
+/// | `{date}`      | `timestamp` in UTC, formatted as `%Y%m%d`        |
+///
+/// A template without placeholders renders as itself. A leading `+` is
+/// accepted and dropped, so `+sha.{short_sha}` and `sha.{short_sha}` are the
+/// same. Build metadata never affects version precedence (semver 2.0 §10).
+///
+/// # Errors
+/// - `CoreError::Versioning` — the template uses an unknown or unterminated
+///   placeholder, or the rendered text is not valid build metadata
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use release_regent_core::versioning::render_build_metadata;
+///
+/// let at = Utc.with_ymd_and_hms(2024, 5, 1, 12, 30, 0).unwrap();
+/// let sha = "abc1234def5678abc1234def5678abc1234def56";
+///
+/// assert_eq!(render_build_metadata("+sha.{short_sha}", sha, at).unwrap(), "sha.abc1234");
+/// assert_eq!(render_build_metadata("{timestamp}", sha, at).unwrap(), "20240501123000");
+/// ```
+// CoreError is intentionally large; this is the established pattern throughout the codebase.
+#[allow(clippy::result_large_err)]
+pub fn render_build_metadata(
+    template: &str,
+    sha: &str,
+    timestamp: chrono::DateTime<chrono::Utc>,
+) -> CoreResult<String> {
+    let template = template.strip_prefix('+').unwrap_or(template);
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let end = after.find('}').ok_or_else(|| {
+            CoreError::versioning(format!(
+                "Unterminated placeholder in build metadata template: {template}"
+            ))
+        })?;
+        match &after[..end] {
+            "sha" => rendered.push_str(sha),
+            "short_sha" => rendered.push_str(sha.get(..7).unwrap_or(sha)),
+            "timestamp" => rendered.push_str(&timestamp.format("%Y%m%d%H%M%S").to_string()),
+            "date" => rendered.push_str(&timestamp.format("%Y%m%d").to_string()),
+            other => {
+                return Err(CoreError::versioning(format!(
+                    "Unknown build metadata placeholder: {{{other}}}"
+                )))
+            }
+        }
+        rest = &after[end + 1..];
+    }
+    rendered.push_str(rest);
+
+    VersionCalculator::validate_build_metadata(&rendered)?;
+    Ok(rendered)
+}
+
 /// Compare two semver pre-release strings following the semver 2.0 specification (§11.4).
 ///
 /// Each dot-separated identifier is compared pairwise from left to right:
@@ -908,6 +1365,116 @@ where
     Ok(version)
 }
 
+/// Returns the commits added since the release at `base_tag_sha`.
+///
+/// `base_tag_sha` is the commit SHA of the previous release tag; any ref the
+/// compare API accepts, such as the tag name itself, works too.
+///
+/// # Inclusivity
+///
+/// The result is the `base_tag_sha..head_sha` range: every commit reachable
+/// from `head_sha` that is not reachable from `base_tag_sha`. `head_sha` is
+/// included; `base_tag_sha` is not, even when the underlying
+/// [`GitOperations::get_commits_between`](crate::traits::GitOperations::get_commits_between)
+/// implementation returns it.
+///
+/// Commits that already belong to a later release are excluded as well. When
+/// a commit in the range is the target of a stable semver tag above the base
+/// release (for example because `base_tag_sha` is an older release or a
+/// branch point rather than the most recent release), the range is recomputed
+/// from the newest such commit, so that the tagged commit and everything it
+/// contains are not counted twice. Pre-release tags such as `v1.1.0-rc.1` do
+/// not end the range: their commits still belong to the next stable release.
+///
+/// Returns an empty list when `base_tag_sha` and `head_sha` are the same
+/// commit or `head_sha` is itself a release.
+///
+/// Commits are returned in the order of `get_commits_between` (oldest first).
+///
+/// # Errors
+///
+/// Returns `Err` when listing tags or comparing commits fails.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use release_regent_core::versioning::commits_since_release;
+///
+/// let new_commits = commits_since_release(&github, "myorg", "myrepo", &tag_sha, &head_sha).await?;
+/// ```
+pub async fn commits_since_release<G>(
+    git: &G,
+    owner: &str,
+    repo: &str,
+    base_tag_sha: &str,
+    head_sha: &str,
+) -> CoreResult<Vec<GitCommit>>
+where
+    G: crate::traits::GitOperations + ?Sized,
+{
+    if base_tag_sha == head_sha {
+        return Ok(Vec::new());
+    }
+
+    let stable_tags: Vec<(SemanticVersion, String, String)> = git
+        .list_tags(owner, repo, ListTagsOptions::default())
+        .await?
+        .into_iter()
+        .filter_map(|t| {
+            VersionCalculator::parse_version(&t.name)
+                .ok()
+                .filter(|v| !v.is_prerelease())
+                .map(|v| (v, t.name, t.target_sha))
+        })
+        .collect();
+
+    // Only a stable release above the base ends the range early; pre-release
+    // tags and older releases in the range belong to the next release.
+    let base_version = stable_tags
+        .iter()
+        .filter(|(_, name, sha)| name == base_tag_sha || sha == base_tag_sha)
+        .map(|(v, _, _)| v)
+        .max_by(|a, b| a.compare_precedence(b));
+    let release_shas: HashSet<String> = stable_tags
+        .iter()
+        .filter(|(v, _, _)| {
+            base_version.map_or(true, |base| v.compare_precedence(base) == Ordering::Greater)
+        })
+        .map(|(_, _, sha)| sha.clone())
+        .collect();
+
+    let mut base = base_tag_sha.to_string();
+    let mut commits = git
+        .get_commits_between(owner, repo, &base, head_sha, GetCommitsOptions::default())
+        .await?;
+
+    // Each pass moves `base` forward to a release inside the range, so the
+    // loop ends after at most one pass per release tag.
+    while let Some(released) = commits
+        .iter()
+        .rev()
+        .find(|c| c.sha != base && release_shas.contains(&c.sha))
+    {
+        debug!(
+            owner = %owner,
+            repo = %repo,
+            base = %base,
+            release_sha = %released.sha,
+            "release tag found inside commit range; recomputing from it"
+        );
+        if released.sha == head_sha {
+            return Ok(Vec::new());
+        }
+        base = released.sha.clone();
+        commits = git
+            .get_commits_between(owner, repo, &base, head_sha, GetCommitsOptions::default())
+            .await?;
+    }
+
+    commits.retain(|c| c.sha != base);
+    Ok(commits)
+}
+
 #[cfg(test)]
 #[path = "versioning_tests.rs"]
 mod tests;
@@ -10,7 +10,9 @@ fn test_initial_version_calculation() {
         description: "initial feature".to_string(),
         breaking_change: false,
         message: "feat: initial feature".to_string(),
+        body: None,
         sha: "abc123".to_string(),
+        author: None,
     }];
 
     let version = calculator.calculate_next_version(&commits).unwrap();
@@ -169,7 +171,9 @@ fn test_initial_version_with_breaking_change_stays_pre_one_zero() {
         description: "rename endpoint".to_string(),
         breaking_change: true,
         message: "feat!: rename endpoint".to_string(),
+        body: None,
         sha: "deadbeef".to_string(),
+        author: None,
     }];
 
     let version = calculator.calculate_next_version(&commits).unwrap();
@@ -190,7 +194,9 @@ fn test_version_bump_determination() {
         description: "add new feature".to_string(),
         breaking_change: true,
         message: "feat: add new feature\n\nBREAKING CHANGE: API changed".to_string(),
+        body: None,
         sha: "abc123".to_string(),
+        author: None,
     }];
     assert_eq!(
         VersionCalculator::determine_version_bump(&breaking_commits),
@@ -204,7 +210,9 @@ fn test_version_bump_determination() {
         description: "add new feature".to_string(),
         breaking_change: false,
         message: "feat: add new feature".to_string(),
+        body: None,
         sha: "def456".to_string(),
+        author: None,
     }];
     assert_eq!(
         VersionCalculator::determine_version_bump(&feature_commits),
@@ -218,7 +226,9 @@ fn test_version_bump_determination() {
         description: "fix bug".to_string(),
         breaking_change: false,
         message: "fix: fix bug".to_string(),
+        body: None,
         sha: "ghi789".to_string(),
+        author: None,
     }];
     assert_eq!(
         VersionCalculator::determine_version_bump(&fix_commits),
@@ -232,7 +242,9 @@ fn test_version_bump_determination() {
         description: "update dependencies".to_string(),
         breaking_change: false,
         message: "chore: update dependencies".to_string(),
+        body: None,
         sha: "jkl012".to_string(),
+        author: None,
     }];
     assert_eq!(
         VersionCalculator::determine_version_bump(&chore_commits),
@@ -287,15 +299,15 @@ fn test_parse_conventional_commit_basic() {
     assert_eq!(parsed.len(), 3);
     assert_eq!(parsed[0].commit_type, "feat");
     assert_eq!(parsed[0].description, "add user authentication");
-    assert_eq!(parsed[0].breaking_change, false);
+    assert!(!parsed[0].breaking_change);
 
     assert_eq!(parsed[1].commit_type, "fix");
     assert_eq!(parsed[1].description, "resolve login bug");
-    assert_eq!(parsed[1].breaking_change, false);
+    assert!(!parsed[1].breaking_change);
 
     assert_eq!(parsed[2].commit_type, "docs");
     assert_eq!(parsed[2].description, "update README");
-    assert_eq!(parsed[2].breaking_change, false);
+    assert!(!parsed[2].breaking_change);
 }
 
 #[test]
@@ -340,12 +352,12 @@ fn test_parse_conventional_commit_breaking_change_exclamation() {
 
     assert_eq!(parsed.len(), 2);
     assert_eq!(parsed[0].commit_type, "feat");
-    assert_eq!(parsed[0].breaking_change, true);
+    assert!(parsed[0].breaking_change);
     assert_eq!(parsed[0].description, "remove deprecated API");
 
     assert_eq!(parsed[1].commit_type, "fix");
     assert_eq!(parsed[1].scope, Some("auth".to_string()));
-    assert_eq!(parsed[1].breaking_change, true);
+    assert!(parsed[1].breaking_change);
     assert_eq!(parsed[1].description, "change authentication flow");
 }
 
@@ -366,11 +378,11 @@ fn test_parse_conventional_commit_breaking_change_footer() {
 
     assert_eq!(parsed.len(), 2);
     assert_eq!(parsed[0].commit_type, "feat");
-    assert_eq!(parsed[0].breaking_change, true);
+    assert!(parsed[0].breaking_change);
     assert_eq!(parsed[0].description, "add new feature");
 
     assert_eq!(parsed[1].commit_type, "fix");
-    assert_eq!(parsed[1].breaking_change, true);
+    assert!(parsed[1].breaking_change);
     assert_eq!(parsed[1].description, "bug fix");
 }
 
@@ -388,15 +400,15 @@ fn test_parse_non_conventional_commit() {
     // Non-conventional commits should be treated as "chore"
     assert_eq!(parsed[0].commit_type, "chore");
     assert_eq!(parsed[0].description, "Update README file");
-    assert_eq!(parsed[0].breaking_change, false);
+    assert!(!parsed[0].breaking_change);
 
     assert_eq!(parsed[1].commit_type, "chore");
     assert_eq!(parsed[1].description, "random commit message");
-    assert_eq!(parsed[1].breaking_change, false);
+    assert!(!parsed[1].breaking_change);
 
     assert_eq!(parsed[2].commit_type, "chore");
     assert_eq!(parsed[2].description, "");
-    assert_eq!(parsed[2].breaking_change, false);
+    assert!(!parsed[2].breaking_change);
 }
 
 // Enhanced semantic version parsing tests
@@ -817,23 +829,94 @@ fn test_latest_semver_tag_numeric_prerelease_suffix_uses_integer_ordering() {
 // resolve_current_version
 // ─────────────────────────────────────────────────────────────────────────────
 
-/// Minimal test double for `GitOperations` that serves a fixed list of tags.
+/// Minimal test double for `GitOperations` that serves a fixed list of tags
+/// and compares commits over a linear history.
 struct FakeGitOps {
     tags: Vec<GitTag>,
     fail: bool,
+    /// Commit SHAs, oldest first.
+    history: Vec<String>,
+    /// Whether `get_commits_between` also returns the base commit, as some
+    /// implementations do.
+    include_base: bool,
+    /// Every `(base, head)` passed to `get_commits_between`.
+    compare_calls: std::sync::Mutex<Vec<(String, String)>>,
 }
 
 impl FakeGitOps {
     fn with_tags(tags: Vec<GitTag>) -> Self {
-        Self { tags, fail: false }
+        Self {
+            tags,
+            fail: false,
+            history: vec![],
+            include_base: false,
+            compare_calls: std::sync::Mutex::default(),
+        }
     }
 
     fn always_failing() -> Self {
         Self {
-            tags: vec![],
             fail: true,
+            ..Self::with_tags(vec![])
+        }
+    }
+
+    /// Linear history `c0..c{len-1}` with release tags `(name, index)`.
+    fn with_history(len: usize, releases: &[(&str, usize)]) -> Self {
+        Self {
+            history: (0..len).map(|i| format!("c{i}")).collect(),
+            ..Self::with_tags(
+                releases
+                    .iter()
+                    .map(|(name, index)| GitTag {
+                        target_sha: format!("c{index}"),
+                        ..make_lightweight_tag(name)
+                    })
+                    .collect(),
+            )
         }
     }
+
+    fn including_base(mut self) -> Self {
+        self.include_base = true;
+        self
+    }
+
+    fn position(&self, reference: &str) -> usize {
+        let sha = self
+            .tags
+            .iter()
+            .find(|t| t.name == reference)
+            .map_or(reference, |t| t.target_sha.as_str());
+        self.history
+            .iter()
+            .position(|c| c == sha)
+            .unwrap_or_else(|| panic!("unknown ref {reference}"))
+    }
+
+    fn compare_calls(&self) -> Vec<(String, String)> {
+        self.compare_calls.lock().unwrap().clone()
+    }
+}
+
+fn make_commit(sha: &str) -> crate::traits::git_operations::GitCommit {
+    let user = crate::traits::git_operations::GitUser {
+        name: "Dev".into(),
+        email: "dev@example.com".into(),
+        login: None,
+    };
+    crate::traits::git_operations::GitCommit {
+        sha: sha.to_string(),
+        author: user.clone(),
+        committer: user,
+        author_date: chrono::Utc::now(),
+        commit_date: chrono::Utc::now(),
+        message: "fix: change".into(),
+        subject: "fix: change".into(),
+        body: None,
+        parents: vec![],
+        files: vec![],
+    }
 }
 
 #[async_trait::async_trait]
@@ -842,11 +925,32 @@ impl crate::traits::GitOperations for FakeGitOps {
         &self,
         _owner: &str,
         _repo: &str,
-        _base: &str,
-        _head: &str,
+        base: &str,
+        head: &str,
         _options: crate::traits::git_operations::GetCommitsOptions,
     ) -> crate::CoreResult<Vec<crate::traits::git_operations::GitCommit>> {
-        unimplemented!()
+        self.compare_calls
+            .lock()
+            .unwrap()
+            .push((base.to_string(), head.to_string()));
+        let start = self.position(base) + usize::from(!self.include_base);
+        Ok(self.history[start..=self.position(head)]
+            .iter()
+            .map(|sha| make_commit(sha))
+            .collect())
+    }
+
+    async fn list_commits(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        head: &str,
+        _options: crate::traits::git_operations::GetCommitsOptions,
+    ) -> crate::CoreResult<Vec<crate::traits::git_operations::GitCommit>> {
+        Ok(self.history[..=self.position(head)]
+            .iter()
+            .map(|sha| make_commit(sha))
+            .collect())
     }
 
     async fn get_commit(
@@ -957,6 +1061,216 @@ async fn test_resolve_current_version_propagates_api_errors() {
     assert!(result.is_err());
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// commits_since_release
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn shas(commits: &[crate::traits::git_operations::GitCommit]) -> Vec<&str> {
+    commits.iter().map(|c| c.sha.as_str()).collect()
+}
+
+#[tokio::test]
+async fn test_commits_since_release_excludes_base_and_includes_head() {
+    let ops = FakeGitOps::with_history(4, &[("v1.0.0", 0)]);
+
+    let commits = commits_since_release(&ops, "owner", "repo", "c0", "c3")
+        .await
+        .unwrap();
+
+    assert_eq!(shas(&commits), vec!["c1", "c2", "c3"]);
+}
+
+#[tokio::test]
+async fn test_commits_since_release_drops_base_returned_by_implementation() {
+    let ops = FakeGitOps::with_history(3, &[("v1.0.0", 0)]).including_base();
+
+    let commits = commits_since_release(&ops, "owner", "repo", "c0", "c2")
+        .await
+        .unwrap();
+
+    assert_eq!(shas(&commits), vec!["c1", "c2"]);
+}
+
+#[tokio::test]
+async fn test_commits_since_release_skips_commits_of_release_between_base_and_head() {
+    // c0 = v1.0.0, c3 = v1.1.0; the caller passes the older release as base.
+    let ops = FakeGitOps::with_history(6, &[("v1.0.0", 0), ("v1.1.0", 3)]);
+
+    let commits = commits_since_release(&ops, "owner", "repo", "c0", "c5")
+        .await
+        .unwrap();
+
+    assert_eq!(shas(&commits), vec!["c4", "c5"]);
+    assert_eq!(
+        ops.compare_calls(),
+        vec![
+            ("c0".to_string(), "c5".to_string()),
+            ("c3".to_string(), "c5".to_string())
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_commits_since_release_ignores_non_semver_tags_in_range() {
+    let ops = FakeGitOps::with_history(4, &[("v1.0.0", 0), ("deploy-staging", 2)]);
+
+    let commits = commits_since_release(&ops, "owner", "repo", "v1.0.0", "c3")
+        .await
+        .unwrap();
+
+    assert_eq!(shas(&commits), vec!["c1", "c2", "c3"]);
+}
+
+#[tokio::test]
+async fn test_commits_since_release_keeps_commits_before_prerelease_tag_in_range() {
+    // c0 = v1.0.0, c1 = feat, c2 = v1.1.0-rc.1, c3 = fix. The feat still
+    // belongs to the next stable release, so it must not be dropped.
+    let ops = FakeGitOps::with_history(4, &[("v1.0.0", 0), ("v1.1.0-rc.1", 2)]);
+
+    let commits = commits_since_release(&ops, "owner", "repo", "c0", "c3")
+        .await
+        .unwrap();
+
+    assert_eq!(shas(&commits), vec!["c1", "c2", "c3"]);
+    assert_eq!(
+        ops.compare_calls(),
+        vec![("c0".to_string(), "c3".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_commits_since_release_ignores_older_release_tag_in_range() {
+    // A backport tag below the base release does not end the range.
+    let ops = FakeGitOps::with_history(4, &[("v1.1.0", 0), ("v1.0.1", 2)]);
+
+    let commits = commits_since_release(&ops, "owner", "repo", "c0", "c3")
+        .await
+        .unwrap();
+
+    assert_eq!(shas(&commits), vec!["c1", "c2", "c3"]);
+}
+
+#[tokio::test]
+async fn test_commits_since_release_returns_empty_when_head_is_a_release() {
+    let ops = FakeGitOps::with_history(3, &[("v1.0.0", 0), ("v1.1.0", 2)]);
+
+    let commits = commits_since_release(&ops, "owner", "repo", "c0", "c2")
+        .await
+        .unwrap();
+
+    assert!(commits.is_empty());
+}
+
+#[tokio::test]
+async fn test_commits_since_release_returns_empty_without_comparing_same_commit() {
+    let ops = FakeGitOps::with_history(2, &[]);
+
+    let commits = commits_since_release(&ops, "owner", "repo", "c1", "c1")
+        .await
+        .unwrap();
+
+    assert!(commits.is_empty());
+    assert!(ops.compare_calls().is_empty());
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Build metadata
+// ─────────────────────────────────────────────────────────────────────────────
+
+const HEAD_SHA: &str = "5114f85abc1234def5678abc1234def5678abc12";
+
+fn build_time() -> chrono::DateTime<chrono::Utc> {
+    use chrono::TimeZone;
+    chrono::Utc.with_ymd_and_hms(2024, 3, 9, 7, 5, 1).unwrap()
+}
+
+#[test]
+fn test_render_build_metadata_substitutes_placeholders() {
+    assert_eq!(
+        render_build_metadata("sha.{short_sha}", HEAD_SHA, build_time()).unwrap(),
+        "sha.5114f85"
+    );
+    assert_eq!(
+        render_build_metadata("{sha}", HEAD_SHA, build_time()).unwrap(),
+        HEAD_SHA
+    );
+    assert_eq!(
+        render_build_metadata("{date}.{timestamp}", HEAD_SHA, build_time()).unwrap(),
+        "20240309.20240309070501"
+    );
+}
+
+#[test]
+fn test_render_build_metadata_literal_and_leading_plus() {
+    assert_eq!(
+        render_build_metadata("+build.42", HEAD_SHA, build_time()).unwrap(),
+        "build.42"
+    );
+}
+
+#[test]
+fn test_render_build_metadata_rejects_unknown_placeholder() {
+    let err = render_build_metadata("{branch}", HEAD_SHA, build_time()).unwrap_err();
+    assert!(err.to_string().contains("{branch}"), "got: {err}");
+
+    assert!(render_build_metadata("sha.{short_sha", HEAD_SHA, build_time()).is_err());
+}
+
+#[test]
+fn test_render_build_metadata_rejects_invalid_result() {
+    // Underscores and empty identifiers are not valid build metadata.
+    assert!(render_build_metadata("ci_{short_sha}", HEAD_SHA, build_time()).is_err());
+    assert!(render_build_metadata("sha..{short_sha}", HEAD_SHA, build_time()).is_err());
+}
+
+#[test]
+fn test_rendered_build_metadata_round_trips_through_parse_version() {
+    let build = render_build_metadata("sha.{short_sha}", HEAD_SHA, build_time()).unwrap();
+    let version = SemanticVersion {
+        major: 1,
+        minor: 2,
+        patch: 3,
+        prerelease: None,
+        build: Some(build),
+    };
+
+    let parsed = VersionCalculator::parse_version(&version.to_string()).unwrap();
+
+    assert_eq!(parsed.to_string(), "1.2.3+sha.5114f85");
+    assert_eq!(parsed.build.as_deref(), Some("sha.5114f85"));
+}
+
+/// Versions that differ only in build metadata have equal precedence, so a
+/// bump floor and the latest-tag lookup treat them as the same release.
+#[test]
+fn test_build_metadata_does_not_affect_bump_decisions() {
+    let current = VersionCalculator::parse_version("1.2.3+sha.aaaaaaa").unwrap();
+    let calculated = VersionCalculator::parse_version("1.2.4+sha.bbbbbbb").unwrap();
+    let plain = VersionCalculator::parse_version("1.2.4").unwrap();
+
+    assert_eq!(calculated.compare_precedence(&plain), Ordering::Equal);
+
+    // The patch floor (1.2.4) equals the calculated version, so the
+    // calculated version, including its build metadata, is kept.
+    let effective = apply_bump_floor(&current, &calculated, &BumpKind::Patch);
+    assert_eq!(effective.to_string(), "1.2.4+sha.bbbbbbb");
+
+    // A bump from a version carrying build metadata produces a clean version.
+    assert_eq!(
+        apply_semver_bump(&current, VersionBump::Minor).to_string(),
+        "1.3.0"
+    );
+
+    let tags = vec![
+        make_lightweight_tag("v1.2.4+build.9"),
+        make_lightweight_tag("v1.2.3+build.10"),
+    ];
+    assert_eq!(
+        latest_semver_tag(&tags, false).unwrap().to_string(),
+        "1.2.4+build.9"
+    );
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // apply_bump_floor — additional unit tests (spec §9)
 // ─────────────────────────────────────────────────────────────────────────────
@@ -1129,8 +1443,9 @@ mod cross_calculator_consistency {
         GetCommitsOptions, GitCommit, GitOperations, GitRepository, ListTagsOptions,
     };
     use crate::traits::github_operations::{
-        CollaboratorPermission, CreatePullRequestParams, CreateReleaseParams, GitHubOperations,
-        GitUser, Label, PullRequest, Release, Repository, Tag, UpdateReleaseParams,
+        CollaboratorPermission, CreateCommitStatusParams, CreatePullRequestParams,
+        CreateReleaseParams, GitHubOperations, GitUser, Label, LabelMode, PullRequest, Release,
+        Tag, UpdateReleaseParams,
     };
     use crate::traits::version_calculator::{
         VersionBump, VersionCalculator as VersionCalculatorTrait,
@@ -1153,6 +1468,15 @@ mod cross_calculator_consistency {
         ) -> crate::CoreResult<Vec<GitCommit>> {
             Ok(vec![])
         }
+        async fn list_commits(
+            &self,
+            _: &str,
+            _: &str,
+            _: &str,
+            _: GetCommitsOptions,
+        ) -> crate::CoreResult<Vec<GitCommit>> {
+            Ok(vec![])
+        }
         async fn get_commit(&self, _: &str, _: &str, _: &str) -> crate::CoreResult<GitCommit> {
             Err(crate::CoreError::not_found("stub"))
         }
@@ -1227,6 +1551,23 @@ mod cross_calculator_consistency {
         ) -> crate::CoreResult<Tag> {
             Err(crate::CoreError::not_found("stub"))
         }
+        async fn create_signed_tag(
+            &self,
+            _: &str,
+            _: &str,
+            _: crate::traits::github_operations::SignedTagParams,
+        ) -> crate::CoreResult<Tag> {
+            Err(crate::CoreError::not_found("stub"))
+        }
+        async fn create_commit_status(
+            &self,
+            _: &str,
+            _: &str,
+            _: &str,
+            _: CreateCommitStatusParams,
+        ) -> crate::CoreResult<()> {
+            Ok(())
+        }
         async fn delete_branch(&self, _: &str, _: &str, _: &str) -> crate::CoreResult<()> {
             Ok(())
         }
@@ -1239,6 +1580,15 @@ mod cross_calculator_consistency {
         ) -> crate::CoreResult<()> {
             Ok(())
         }
+        async fn upsert_branch(
+            &self,
+            _: &str,
+            _: &str,
+            _: &str,
+            _: &str,
+        ) -> crate::CoreResult<crate::traits::github_operations::BranchUpsert> {
+            Ok(crate::traits::github_operations::BranchUpsert::Created)
+        }
         async fn get_collaborator_permission(
             &self,
             _: &str,
@@ -1266,8 +1616,8 @@ mod cross_calculator_consistency {
             _: &str,
             _: &str,
             _: &str,
-        ) -> crate::CoreResult<Release> {
-            Err(crate::CoreError::not_found("stub"))
+        ) -> crate::CoreResult<Option<Release>> {
+            Ok(None)
         }
         async fn list_pr_labels(&self, _: &str, _: &str, _: u64) -> crate::CoreResult<Vec<Label>> {
             Ok(vec![])
@@ -1293,9 +1643,33 @@ mod cross_calculator_consistency {
         ) -> crate::CoreResult<Vec<Release>> {
             Ok(vec![])
         }
+        async fn upload_release_asset(
+            &self,
+            _: &str,
+            _: &str,
+            _: u64,
+            _: &str,
+            _: Option<&str>,
+            _: Vec<u8>,
+        ) -> crate::CoreResult<String> {
+            Err(crate::CoreError::not_found("stub"))
+        }
         async fn remove_label(&self, _: &str, _: &str, _: u64, _: &str) -> crate::CoreResult<()> {
             Ok(())
         }
+        async fn resolve_ref(&self, _: &str, _: &str, _: &str) -> crate::CoreResult<String> {
+            Err(crate::CoreError::not_found("stub"))
+        }
+        async fn set_pull_request_labels(
+            &self,
+            _: &str,
+            _: &str,
+            _: u64,
+            _: &[&str],
+            _: LabelMode,
+        ) -> crate::CoreResult<()> {
+            Ok(())
+        }
         async fn search_pull_requests(
             &self,
             _: &str,
@@ -1373,6 +1747,15 @@ mod cross_calculator_consistency {
         ) -> crate::CoreResult<Vec<crate::traits::github_operations::IssueComment>> {
             Ok(vec![])
         }
+
+        async fn list_pull_request_commits(
+            &self,
+            _owner: &str,
+            _repo: &str,
+            _pr_number: u64,
+        ) -> crate::CoreResult<Vec<crate::traits::git_operations::GitCommit>> {
+            Ok(vec![])
+        }
         async fn update_issue_comment(
             &self,
             _: &str,
@@ -1568,3 +1951,448 @@ mod property_tests {
         }
     }
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// parse_conventional_commits — full messages
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_parse_conventional_commit_breaking_footer_after_body() {
+    let message = "fix: tighten validation\n\nTokens are checked more strictly.\n\n\
+                   BREAKING CHANGE: tokens must carry an exp claim";
+
+    let commits =
+        VersionCalculator::parse_conventional_commits(&[("sha1".to_string(), message.to_string())]);
+
+    assert_eq!(commits[0].commit_type, "fix");
+    assert!(commits[0].breaking_change);
+    assert_eq!(commits[0].message, message);
+}
+
+#[test]
+fn test_parse_conventional_commit_falls_back_to_subject_for_unconventional_body() {
+    // No blank line between subject and body: the full message does not parse.
+    let commits = VersionCalculator::parse_conventional_commits(&[(
+        "sha1".to_string(),
+        "feat(api): add endpoint\nwith a body that hugs the subject".to_string(),
+    )]);
+
+    assert_eq!(commits[0].commit_type, "feat");
+    assert_eq!(commits[0].scope.as_deref(), Some("api"));
+    assert_eq!(commits[0].description, "add endpoint");
+    assert!(!commits[0].breaking_change);
+    assert_eq!(
+        commits[0].body.as_deref(),
+        Some("with a body that hugs the subject")
+    );
+}
+
+#[test]
+fn test_parse_conventional_commit_captures_multi_paragraph_body() {
+    let message = "feat(auth): add OAuth support\n\n\
+                   Users can now sign in with GitHub.\n\n\
+                   Tokens are refreshed in the background\nbefore they expire.";
+
+    let commits =
+        VersionCalculator::parse_conventional_commits(&[("sha1".to_string(), message.to_string())]);
+
+    assert_eq!(commits[0].description, "add OAuth support");
+    assert_eq!(
+        commits[0].body.as_deref(),
+        Some(
+            "Users can now sign in with GitHub.\n\n\
+             Tokens are refreshed in the background\nbefore they expire."
+        )
+    );
+}
+
+#[test]
+fn test_parse_conventional_commit_separates_footers_from_body() {
+    let message = "fix: tighten validation\n\nTokens are checked more strictly.\n\n\
+                   BREAKING CHANGE: tokens must carry an exp claim\nRefs: #42";
+
+    let commits =
+        VersionCalculator::parse_conventional_commits(&[("sha1".to_string(), message.to_string())]);
+
+    assert!(commits[0].breaking_change);
+    assert_eq!(
+        commits[0].body.as_deref(),
+        Some("Tokens are checked more strictly.")
+    );
+}
+
+#[test]
+fn test_parse_conventional_commit_without_body_has_none() {
+    let commits = VersionCalculator::parse_conventional_commits(&[
+        ("sha1".to_string(), "feat: add endpoint".to_string()),
+        (
+            "sha2".to_string(),
+            "feat!: drop v1\n\nBREAKING CHANGE: v1 is gone".to_string(),
+        ),
+    ]);
+
+    assert_eq!(commits[0].body, None);
+    assert_eq!(commits[1].body, None);
+}
+
+#[test]
+fn test_commit_body_of_non_conventional_message() {
+    assert_eq!(
+        commit_body(
+            "Update README\n\nMention the new flag.\n\nSigned-off-by: Jane <jane@example.com>"
+        )
+        .as_deref(),
+        Some("Mention the new flag.")
+    );
+    assert_eq!(commit_body("Update README\n\n  \n"), None);
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Reverts
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn parse(commits: &[(&str, &str)]) -> Vec<ConventionalCommit> {
+    let raw: Vec<(String, String)> = commits
+        .iter()
+        .map(|(sha, message)| ((*sha).to_string(), (*message).to_string()))
+        .collect();
+    VersionCalculator::parse_conventional_commits(&raw)
+}
+
+#[test]
+fn test_parse_github_revert_subject() {
+    let commits = parse(&[(
+        "bbbbbbb2",
+        "Revert \"feat(api): add export\"\n\nThis reverts commit aaaaaaa1.",
+    )]);
+
+    assert_eq!(commits[0].commit_type, "revert");
+    assert!(commits[0].is_revert());
+    assert_eq!(commits[0].description, "feat(api): add export");
+    assert_eq!(commits[0].reverted_sha(), Some("aaaaaaa1"));
+    assert_eq!(commits[0].reverted_subject(), Some("feat(api): add export"));
+    assert_eq!(commits[0].version_bump(), VersionBump::Patch);
+}
+
+#[test]
+fn test_reverted_subject_strips_quotes_of_conventional_revert() {
+    let commits = parse(&[("bbbbbbb2", "revert: \"feat: add export\"")]);
+
+    assert!(commits[0].is_revert());
+    assert_eq!(commits[0].reverted_sha(), None);
+    assert_eq!(commits[0].reverted_subject(), Some("feat: add export"));
+}
+
+#[test]
+fn test_feat_and_its_revert_cancel_out() {
+    let commits = parse(&[
+        ("aaaaaaa1", "feat: add export"),
+        ("bbbbbbb2", "revert: feat: add export"),
+    ]);
+
+    assert_eq!(cancelled_by_reverts(&commits), HashSet::from([0, 1]));
+    assert_eq!(
+        VersionCalculator::determine_version_bump(&commits),
+        VersionBump::None
+    );
+}
+
+#[test]
+fn test_revert_is_paired_by_sha_before_subject() {
+    let commits = parse(&[
+        ("aaaaaaa1", "feat: add export"),
+        ("ccccccc3", "feat: add export"),
+        (
+            "bbbbbbb2",
+            "Revert \"feat: add export\"\n\nThis reverts commit ccccccc3.",
+        ),
+    ]);
+
+    assert_eq!(cancelled_by_reverts(&commits), HashSet::from([1, 2]));
+    // The feature added by the other commit still bumps the minor version.
+    assert_eq!(
+        VersionCalculator::determine_version_bump(&commits),
+        VersionBump::Minor
+    );
+}
+
+#[test]
+fn test_unpaired_revert_is_a_patch() {
+    let commits = parse(&[
+        ("aaaaaaa1", "docs: update README"),
+        ("bbbbbbb2", "Revert \"feat: add export\""),
+    ]);
+
+    assert!(cancelled_by_reverts(&commits).is_empty());
+    assert_eq!(
+        VersionCalculator::determine_version_bump(&commits),
+        VersionBump::Patch
+    );
+}
+
+#[test]
+fn test_breaking_revert_is_still_major() {
+    let commits = parse(&[
+        ("aaaaaaa1", "feat: add export"),
+        ("bbbbbbb2", "revert!: feat: add export"),
+    ]);
+
+    // The pair cancels out, including the breaking flag of the revert.
+    assert_eq!(
+        VersionCalculator::determine_version_bump(&commits),
+        VersionBump::None
+    );
+    assert_eq!(
+        VersionCalculator::determine_version_bump(&commits[1..]),
+        VersionBump::Major
+    );
+}
+
+#[test]
+fn test_calculate_next_version_ignores_reverted_feature() {
+    let calculator =
+        VersionCalculator::new(Some(VersionCalculator::parse_version("1.2.0").unwrap()));
+    let commits = parse(&[
+        ("aaaaaaa1", "feat: add export"),
+        (
+            "bbbbbbb2",
+            "Revert \"feat: add export\"\n\nThis reverts commit aaaaaaa1.",
+        ),
+        ("ccccccc3", "fix: handle empty input"),
+    ]);
+
+    let next = calculator.calculate_next_version(&commits).unwrap();
+
+    assert_eq!(next.to_string(), "1.2.1");
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Breaking scopes
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_mark_breaking_scopes_marks_only_listed_scopes() {
+    let mut commits = parse(&[
+        ("sha1", "feat(api): add export"),
+        ("sha2", "fix(API): handle empty input"),
+        ("sha3", "feat(ui): add button"),
+        ("sha4", "feat: add parser"),
+    ]);
+
+    mark_breaking_scopes(&mut commits, &["api".to_string()]);
+
+    let breaking: Vec<bool> = commits.iter().map(|c| c.breaking_change).collect();
+    assert_eq!(breaking, vec![true, true, false, false]);
+}
+
+#[test]
+fn test_feat_in_breaking_scope_bumps_major() {
+    let current = VersionCalculator::parse_version("1.2.0").unwrap();
+    let commits = parse(&[("sha1", "feat(api): add export")]);
+
+    let with_scope = VersionCalculator::new(Some(current.clone()))
+        .with_breaking_scopes(vec!["api".to_string()])
+        .calculate_next_version(&commits)
+        .unwrap();
+    let without_scope = VersionCalculator::new(Some(current))
+        .calculate_next_version(&commits)
+        .unwrap();
+
+    assert_eq!(with_scope.to_string(), "2.0.0");
+    assert_eq!(without_scope.to_string(), "1.3.0");
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Pull request title bumps
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_pr_title_bump_reads_title_as_conventional_commit() {
+    assert_eq!(
+        pr_title_bump("feat!: drop v1 API", &[]),
+        Some(BumpKind::Major)
+    );
+    assert_eq!(
+        pr_title_bump("feat(ui): add button", &[]),
+        Some(BumpKind::Minor)
+    );
+    assert_eq!(
+        pr_title_bump("fix: handle empty input", &[]),
+        Some(BumpKind::Patch)
+    );
+    assert_eq!(pr_title_bump("chore: bump deps", &[]), None);
+    assert_eq!(pr_title_bump("Update README", &[]), None);
+    assert_eq!(pr_title_bump("", &[]), None);
+}
+
+#[test]
+fn test_pr_title_bump_honours_breaking_scopes() {
+    let breaking_scopes = vec!["api".to_string()];
+
+    assert_eq!(
+        pr_title_bump("fix(API): handle empty input", &breaking_scopes),
+        Some(BumpKind::Major)
+    );
+    assert_eq!(
+        pr_title_bump("fix(ui): handle empty input", &breaking_scopes),
+        Some(BumpKind::Patch)
+    );
+}
+
+#[test]
+fn test_breaking_pr_title_raises_fix_only_commits_to_major() {
+    let current = VersionCalculator::parse_version("1.2.0").unwrap();
+    let commits = parse(&[
+        ("sha1", "fix: handle empty input"),
+        ("sha2", "fix: correct typo"),
+    ]);
+    let calculated = VersionCalculator::new(Some(current.clone()))
+        .calculate_next_version(&commits)
+        .unwrap();
+    let title_bump = pr_title_bump("feat!: replace the config format", &[]).unwrap();
+
+    let effective = apply_bump_floor(&current, &calculated, &title_bump);
+
+    assert_eq!(calculated.to_string(), "1.2.1");
+    assert_eq!(effective.to_string(), "2.0.0");
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Bump explanation
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_explain_version_bump_names_breaking_commit() {
+    let commits = parse(&[
+        ("aaaaaaa1", "feat: add export"),
+        (
+            "bbbbbbb2",
+            "refactor: rework storage\n\nBREAKING CHANGE: new format",
+        ),
+    ]);
+
+    let reason = VersionCalculator::explain_version_bump(&commits);
+
+    assert_eq!(reason.bump, VersionBump::Major);
+    assert_eq!(reason.commits, vec!["bbbbbbb2"]);
+    assert_eq!(reason.to_string(), "major: BREAKING CHANGE in bbbbbbb");
+}
+
+#[test]
+fn test_explain_version_bump_names_earliest_feature() {
+    let commits = parse(&[
+        ("aaaaaaa1", "fix: handle empty input"),
+        ("bbbbbbb2", "feat: add export"),
+        ("ccccccc3", "feat: add import"),
+    ]);
+
+    let reason = VersionCalculator::explain_version_bump(&commits);
+
+    assert_eq!(reason.bump, VersionBump::Minor);
+    assert_eq!(reason.commits.first().map(String::as_str), Some("bbbbbbb2"));
+    assert_eq!(reason.to_string(), "minor: feat in bbbbbbb (and 1 more)");
+}
+
+#[test]
+fn test_explain_version_bump_skips_reverted_commits() {
+    let commits = parse(&[
+        ("aaaaaaa1", "feat: add export"),
+        ("ccccccc3", "fix: handle empty input"),
+        (
+            "bbbbbbb2",
+            "Revert \"feat: add export\"\n\nThis reverts commit aaaaaaa1.",
+        ),
+    ]);
+
+    let reason = VersionCalculator::explain_version_bump(&commits);
+
+    assert_eq!(reason.bump, VersionBump::Patch);
+    assert_eq!(reason.commits, vec!["ccccccc3"]);
+    assert_eq!(reason.rule, "fix");
+}
+
+#[test]
+fn test_is_conventional_message() {
+    assert!(is_conventional_message("fix: handle empty input"));
+    assert!(is_conventional_message("feat(api)!: drop v1 endpoints"));
+    assert!(is_conventional_message(
+        "Revert \"feat: add export\"\n\nThis reverts commit aaaaaaa1."
+    ));
+    assert!(!is_conventional_message("Update README"));
+    assert!(!is_conventional_message("Merge branch 'main' into dev"));
+}
+
+fn non_conventional_commits() -> Vec<(String, String)> {
+    vec![
+        ("aaaaaaa1".to_string(), "Update README".to_string()),
+        ("bbbbbbb2".to_string(), "tweak build script".to_string()),
+    ]
+}
+
+#[test]
+fn test_no_conventional_commits_policy_leaves_conventional_histories_alone() {
+    let mut commits = non_conventional_commits();
+    commits.push(("ccccccc3".to_string(), "fix: typo".to_string()));
+    let reason = BumpReason {
+        bump: VersionBump::Patch,
+        rule: "fix".to_string(),
+        commits: vec!["ccccccc3".to_string()],
+    };
+
+    let (bump, returned) = apply_no_conventional_commits_policy(
+        NoConventionalCommitsPolicy::Error,
+        &commits,
+        VersionBump::Patch,
+        reason.clone(),
+    )
+    .unwrap();
+
+    assert_eq!(bump, VersionBump::Patch);
+    assert_eq!(returned, reason);
+}
+
+#[test]
+fn test_no_conventional_commits_policy_patch() {
+    let (bump, reason) = apply_no_conventional_commits_policy(
+        NoConventionalCommitsPolicy::Patch,
+        &non_conventional_commits(),
+        VersionBump::None,
+        BumpReason::explain(&VersionBump::None, []),
+    )
+    .unwrap();
+
+    assert_eq!(bump, VersionBump::Patch);
+    assert_eq!(reason.commits, vec!["aaaaaaa1", "bbbbbbb2"]);
+    assert_eq!(
+        reason.rule,
+        "no conventional commits since the last release \
+         (2 commits; no_conventional_commits = \"patch\")"
+    );
+}
+
+#[test]
+fn test_no_conventional_commits_policy_none() {
+    let (bump, reason) = apply_no_conventional_commits_policy(
+        NoConventionalCommitsPolicy::None,
+        &non_conventional_commits(),
+        VersionBump::None,
+        BumpReason::explain(&VersionBump::None, []),
+    )
+    .unwrap();
+
+    assert_eq!(bump, VersionBump::None);
+    assert!(reason.commits.is_empty());
+    assert!(reason.rule.ends_with("no_conventional_commits = \"none\")"));
+}
+
+#[test]
+fn test_no_conventional_commits_policy_error() {
+    let result = apply_no_conventional_commits_policy(
+        NoConventionalCommitsPolicy::Error,
+        &non_conventional_commits(),
+        VersionBump::None,
+        BumpReason::explain(&VersionBump::None, []),
+    );
+
+    assert!(matches!(result, Err(CoreError::Versioning { .. })));
+}
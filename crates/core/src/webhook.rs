@@ -0,0 +1,93 @@
+//! Lightweight inspection of raw GitHub webhook payloads.
+//!
+//! Full event processing validates the signature, the event type and the whole
+//! payload. Callers that only need to know *which* repository a delivery is
+//! about — to apply an allow-list or pick a configuration before doing any
+//! real work — can use [`extract_repository`] instead. It deserializes only the
+//! `repository` object and ignores every other field of the payload.
+//!
+//! Callers that need the event itself use [`payload::parse_webhook`], which
+//! deserializes a delivery into a typed [`payload::WebhookEvent`]. The
+//! [`schema`] module describes those payloads as JSON Schemas and validates
+//! raw bodies against them.
+
+use crate::{traits::event_source::RepositoryInfo, CoreError, CoreResult};
+use serde::Deserialize;
+
+#[cfg(test)]
+#[path = "webhook_tests.rs"]
+mod tests;
+
+pub mod payload;
+pub mod schema;
+
+/// The subset of a webhook payload read by [`extract_repository`].
+#[derive(Deserialize)]
+struct PayloadRepository {
+    repository: Option<RepositoryFields>,
+}
+
+#[derive(Deserialize)]
+struct RepositoryFields {
+    name: Option<String>,
+    owner: Option<OwnerFields>,
+    default_branch: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct OwnerFields {
+    login: Option<String>,
+}
+
+/// Extract the repository a webhook payload refers to.
+///
+/// Reads `repository.owner.login`, `repository.name` and
+/// `repository.default_branch`. The rest of the payload is not validated, so a
+/// successful result does not mean the delivery is a well-formed event.
+///
+/// # Errors
+///
+/// Returns [`CoreError::InvalidInput`] when `payload` is not a JSON object, or
+/// when any of the three fields is missing, `null` or empty. The error names
+/// the missing field.
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+pub fn extract_repository(payload: &[u8]) -> CoreResult<RepositoryInfo> {
+    let parsed: PayloadRepository = serde_json::from_slice(payload).map_err(|e| {
+        CoreError::invalid_input("payload", format!("webhook payload is not valid JSON: {e}"))
+    })?;
+
+    parsed
+        .repository
+        .ok_or_else(|| missing_field("repository"))?
+        .into_repository_info()
+}
+
+impl RepositoryFields {
+    /// Convert to a [`RepositoryInfo`], requiring every field to be non-empty.
+    #[allow(clippy::result_large_err)] // CoreError is intentionally large
+    fn into_repository_info(self) -> CoreResult<RepositoryInfo> {
+        let owner = self
+            .owner
+            .and_then(|owner| owner.login)
+            .filter(|login| !login.is_empty())
+            .ok_or_else(|| missing_field("repository.owner.login"))?;
+        let name = self
+            .name
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| missing_field("repository.name"))?;
+        let default_branch = self
+            .default_branch
+            .filter(|branch| !branch.is_empty())
+            .ok_or_else(|| missing_field("repository.default_branch"))?;
+
+        Ok(RepositoryInfo {
+            owner,
+            name,
+            default_branch,
+        })
+    }
+}
+
+fn missing_field(field: &str) -> CoreError {
+    CoreError::invalid_input(field, format!("webhook payload is missing '{field}'"))
+}
@@ -0,0 +1,462 @@
+//! Typed GitHub webhook payloads.
+//!
+//! [`parse_webhook`] turns the `X-GitHub-Event` header value and the raw body
+//! of a delivery into a [`WebhookEvent`], so that the server and the CLI read
+//! webhook fields the same way instead of each walking a
+//! [`serde_json::Value`] by hand.
+//!
+//! Only the fields Release Regent uses are deserialized; everything else in
+//! the payload is ignored. The `repository` object of every supported event is
+//! converted to a [`RepositoryInfo`] with the same rules as
+//! [`extract_repository`](super::extract_repository).
+//!
+//! # Supported events
+//!
+//! | `X-GitHub-Event`              | Result                                                        |
+//! |-------------------------------|---------------------------------------------------------------|
+//! | `pull_request`                | [`WebhookEvent::PullRequestClosed`] for `action=closed`, otherwise [`WebhookEvent::PullRequest`] |
+//! | `issue_comment`               | [`WebhookEvent::IssueComment`]                                |
+//! | `pull_request_review_comment` | [`WebhookEvent::PullRequestReviewComment`]                    |
+//! | `push`                        | [`WebhookEvent::Push`]                                        |
+//! | `release`                     | [`WebhookEvent::Release`]                                     |
+//! | anything else                 | [`WebhookEvent::Other`]; the body is not parsed               |
+
+use super::RepositoryFields;
+use crate::{traits::event_source::RepositoryInfo, CoreError, CoreResult};
+use serde::{Deserialize, Deserializer};
+
+#[cfg(test)]
+#[path = "payload_tests.rs"]
+mod tests;
+
+/// GitHub webhook event kind, parsed from the `X-GitHub-Event` header.
+///
+/// The header names the event; the payload's `action` field only refines it
+/// and is absent for some events (for example `push`), so routing always
+/// starts from the kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookEventKind {
+    /// `issue_comment`
+    IssueComment,
+    /// `pull_request`
+    PullRequest,
+    /// `pull_request_review_comment`
+    PullRequestReviewComment,
+    /// `push`
+    Push,
+    /// `release`
+    Release,
+    /// Any other event, holding the raw header value
+    Other(String),
+}
+
+impl WebhookEventKind {
+    /// Parse an `X-GitHub-Event` header value.
+    #[must_use]
+    pub fn from_header(value: &str) -> Self {
+        match value.trim() {
+            "issue_comment" => Self::IssueComment,
+            "pull_request" => Self::PullRequest,
+            "pull_request_review_comment" => Self::PullRequestReviewComment,
+            "push" => Self::Push,
+            "release" => Self::Release,
+            other => Self::Other(other.to_string()),
+        }
+    }
+
+    /// The `X-GitHub-Event` header value for this kind.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::IssueComment => "issue_comment",
+            Self::PullRequest => "pull_request",
+            Self::PullRequestReviewComment => "pull_request_review_comment",
+            Self::Push => "push",
+            Self::Release => "release",
+            Self::Other(raw) => raw,
+        }
+    }
+}
+
+impl std::fmt::Display for WebhookEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A parsed webhook delivery.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookEvent {
+    /// A `pull_request` event with any action other than `closed`.
+    PullRequest(PullRequestPayload),
+    /// A `pull_request` event with `action=closed`, merged or not.
+    PullRequestClosed(PullRequestClosedPayload),
+    /// An `issue_comment` event, on an issue or a pull request.
+    IssueComment(CommentPayload),
+    /// A `pull_request_review_comment` event.
+    PullRequestReviewComment(CommentPayload),
+    /// A `push` event.
+    Push(PushPayload),
+    /// A `release` event.
+    Release(ReleasePayload),
+    /// Any other event, holding the raw `X-GitHub-Event` value.
+    Other(String),
+}
+
+impl WebhookEvent {
+    /// The repository the event is about, or `None` for [`WebhookEvent::Other`].
+    #[must_use]
+    pub fn repository(&self) -> Option<&RepositoryInfo> {
+        match self {
+            Self::PullRequest(payload) => Some(&payload.repository),
+            Self::PullRequestClosed(payload) => Some(&payload.repository),
+            Self::IssueComment(payload) | Self::PullRequestReviewComment(payload) => {
+                Some(&payload.repository)
+            }
+            Self::Push(payload) => Some(&payload.repository),
+            Self::Release(payload) => Some(&payload.repository),
+            Self::Other(_) => None,
+        }
+    }
+
+    /// The `installation.id` of the delivery, when present.
+    #[must_use]
+    pub fn installation_id(&self) -> Option<u64> {
+        match self {
+            Self::PullRequest(payload) => payload.installation_id,
+            Self::PullRequestClosed(payload) => payload.installation_id,
+            Self::IssueComment(payload) | Self::PullRequestReviewComment(payload) => {
+                payload.installation_id
+            }
+            Self::Push(payload) => payload.installation_id,
+            Self::Release(payload) => payload.installation_id,
+            Self::Other(_) => None,
+        }
+    }
+}
+
+/// A GitHub user, as embedded in webhook payloads.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct WebhookUser {
+    /// The user's login, e.g. `"octocat"`.
+    pub login: String,
+}
+
+/// The head or base of a pull request.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct WebhookBranch {
+    /// Branch name, without `refs/heads/`.
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    /// Commit SHA the branch pointed at when the event fired.
+    pub sha: String,
+}
+
+/// The `pull_request` object of a webhook payload.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct WebhookPullRequest {
+    /// Pull request number.
+    pub number: u64,
+    /// Pull request title.
+    pub title: String,
+    /// Pull request description, if any.
+    #[serde(default)]
+    pub body: Option<String>,
+    /// Whether the pull request is a draft.
+    #[serde(default)]
+    pub draft: bool,
+    /// Whether the pull request has been merged.
+    #[serde(default)]
+    pub merged: bool,
+    /// SHA of the merge commit, once GitHub has computed it.
+    #[serde(default)]
+    pub merge_commit_sha: Option<String>,
+    /// The branch being merged.
+    pub head: WebhookBranch,
+    /// The branch being merged into.
+    pub base: WebhookBranch,
+    /// The author of the pull request.
+    pub user: WebhookUser,
+}
+
+/// Payload of a `pull_request` event other than `closed`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct PullRequestPayload {
+    /// The event action, e.g. `"opened"` or `"synchronize"`.
+    pub action: String,
+    /// The pull request.
+    pub pull_request: WebhookPullRequest,
+    /// The repository the pull request belongs to.
+    #[serde(deserialize_with = "repository_info")]
+    pub repository: RepositoryInfo,
+    /// The `installation.id` of the delivery, when present.
+    #[serde(default, rename = "installation", deserialize_with = "installation_id")]
+    pub installation_id: Option<u64>,
+}
+
+/// Payload of a `pull_request` event with `action=closed`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PullRequestClosedPayload {
+    /// The pull request.
+    pub pull_request: WebhookPullRequest,
+    /// The repository the pull request belongs to.
+    pub repository: RepositoryInfo,
+    /// The `installation.id` of the delivery, when present.
+    pub installation_id: Option<u64>,
+}
+
+impl PullRequestClosedPayload {
+    /// Whether the pull request was merged rather than closed without merging.
+    #[must_use]
+    pub fn is_merged(&self) -> bool {
+        self.pull_request.merged
+    }
+}
+
+/// A comment on an issue or pull request.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct WebhookComment {
+    /// Comment ID.
+    pub id: u64,
+    /// Comment text.
+    #[serde(default)]
+    pub body: String,
+    /// The author of the comment.
+    pub user: WebhookUser,
+}
+
+/// Payload of an `issue_comment` or `pull_request_review_comment` event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommentPayload {
+    /// The event action, e.g. `"created"`.
+    pub action: String,
+    /// Number of the issue or pull request that was commented on.
+    pub number: u64,
+    /// Whether the comment is on a pull request rather than a plain issue.
+    pub is_pull_request: bool,
+    /// The comment.
+    pub comment: WebhookComment,
+    /// The repository the comment belongs to.
+    pub repository: RepositoryInfo,
+    /// The `installation.id` of the delivery, when present.
+    pub installation_id: Option<u64>,
+}
+
+/// A commit listed in a `push` event.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct PushCommit {
+    /// Commit SHA.
+    pub id: String,
+    /// Full commit message.
+    pub message: String,
+}
+
+/// Payload of a `push` event.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct PushPayload {
+    /// The full ref that was pushed, e.g. `"refs/heads/main"`.
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    /// SHA the ref pointed at before the push.
+    pub before: String,
+    /// SHA the ref points at after the push.
+    pub after: String,
+    /// Whether the push created the ref.
+    #[serde(default)]
+    pub created: bool,
+    /// Whether the push deleted the ref.
+    #[serde(default)]
+    pub deleted: bool,
+    /// Whether the push was a force push.
+    #[serde(default)]
+    pub forced: bool,
+    /// The pushed commits, oldest first.
+    #[serde(default)]
+    pub commits: Vec<PushCommit>,
+    /// The repository that was pushed to.
+    #[serde(deserialize_with = "repository_info")]
+    pub repository: RepositoryInfo,
+    /// The `installation.id` of the delivery, when present.
+    #[serde(default, rename = "installation", deserialize_with = "installation_id")]
+    pub installation_id: Option<u64>,
+}
+
+impl PushPayload {
+    /// The pushed branch, or `None` when the push was to a tag or other ref.
+    #[must_use]
+    pub fn branch(&self) -> Option<&str> {
+        self.ref_name.strip_prefix("refs/heads/")
+    }
+}
+
+/// The `release` object of a webhook payload.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct WebhookRelease {
+    /// Tag the release points at.
+    pub tag_name: String,
+    /// Release title, if any.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Release notes, if any.
+    #[serde(default)]
+    pub body: Option<String>,
+    /// Whether the release is a draft.
+    #[serde(default)]
+    pub draft: bool,
+    /// Whether the release is a prerelease.
+    #[serde(default)]
+    pub prerelease: bool,
+}
+
+/// Payload of a `release` event.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ReleasePayload {
+    /// The event action, e.g. `"published"`.
+    pub action: String,
+    /// The release.
+    pub release: WebhookRelease,
+    /// The repository the release belongs to.
+    #[serde(deserialize_with = "repository_info")]
+    pub repository: RepositoryInfo,
+    /// The `installation.id` of the delivery, when present.
+    #[serde(default, rename = "installation", deserialize_with = "installation_id")]
+    pub installation_id: Option<u64>,
+}
+
+/// Parse a webhook delivery.
+///
+/// `event_kind` is the `X-GitHub-Event` header value and `body` the raw
+/// request body. Events Release Regent does not handle are returned as
+/// [`WebhookEvent::Other`] without looking at the body.
+///
+/// # Errors
+///
+/// Returns [`CoreError::InvalidInput`] when `body` is not valid JSON, or a
+/// field the event needs is missing or has the wrong type. The message names
+/// the event kind and the offending field.
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+pub fn parse_webhook(event_kind: &str, body: &[u8]) -> CoreResult<WebhookEvent> {
+    let kind = WebhookEventKind::from_header(event_kind);
+    match kind {
+        WebhookEventKind::PullRequest => {
+            let payload: PullRequestPayload = from_body(&kind, body)?;
+            if payload.action == "closed" {
+                Ok(WebhookEvent::PullRequestClosed(PullRequestClosedPayload {
+                    pull_request: payload.pull_request,
+                    repository: payload.repository,
+                    installation_id: payload.installation_id,
+                }))
+            } else {
+                Ok(WebhookEvent::PullRequest(payload))
+            }
+        }
+        WebhookEventKind::IssueComment => {
+            let raw: RawIssueCommentPayload = from_body(&kind, body)?;
+            Ok(WebhookEvent::IssueComment(CommentPayload {
+                action: raw.action,
+                number: raw.issue.number,
+                is_pull_request: raw.issue.pull_request.is_some(),
+                comment: raw.comment,
+                repository: raw.repository,
+                installation_id: raw.installation_id,
+            }))
+        }
+        WebhookEventKind::PullRequestReviewComment => {
+            let raw: RawReviewCommentPayload = from_body(&kind, body)?;
+            Ok(WebhookEvent::PullRequestReviewComment(CommentPayload {
+                action: raw.action,
+                number: raw.pull_request.number,
+                is_pull_request: true,
+                comment: raw.comment,
+                repository: raw.repository,
+                installation_id: raw.installation_id,
+            }))
+        }
+        WebhookEventKind::Push => from_body(&kind, body).map(WebhookEvent::Push),
+        WebhookEventKind::Release => from_body(&kind, body).map(WebhookEvent::Release),
+        WebhookEventKind::Other(raw) => Ok(WebhookEvent::Other(raw)),
+    }
+}
+
+/// Parse a webhook delivery after validating it against its JSON Schema.
+///
+/// Like [`parse_webhook`], but the body is first checked with
+/// [`validate_webhook`](super::schema::validate_webhook), so a malformed
+/// delivery is rejected with every schema violation listed instead of only the
+/// first field serde trips over.
+///
+/// # Errors
+///
+/// Returns [`CoreError::InvalidInput`] when `body` is not valid JSON or does
+/// not match the schema of its event kind.
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+pub fn parse_validated_webhook(event_kind: &str, body: &[u8]) -> CoreResult<WebhookEvent> {
+    super::schema::validate_webhook(event_kind, body)?;
+    parse_webhook(event_kind, body)
+}
+
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+fn from_body<T: serde::de::DeserializeOwned>(
+    kind: &WebhookEventKind,
+    body: &[u8],
+) -> CoreResult<T> {
+    serde_json::from_slice(body).map_err(|e| {
+        CoreError::invalid_input("payload", format!("invalid {kind} webhook payload: {e}"))
+    })
+}
+
+/// The `issue` object of an `issue_comment` payload.
+#[derive(Deserialize)]
+struct RawIssue {
+    number: u64,
+    /// Present only when the issue is a pull request.
+    pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct RawIssueCommentPayload {
+    action: String,
+    issue: RawIssue,
+    comment: WebhookComment,
+    #[serde(deserialize_with = "repository_info")]
+    repository: RepositoryInfo,
+    #[serde(default, rename = "installation", deserialize_with = "installation_id")]
+    installation_id: Option<u64>,
+}
+
+/// The part of the `pull_request` object sent with review comments.
+#[derive(Deserialize)]
+struct RawReviewCommentPullRequest {
+    number: u64,
+}
+
+#[derive(Deserialize)]
+struct RawReviewCommentPayload {
+    action: String,
+    pull_request: RawReviewCommentPullRequest,
+    comment: WebhookComment,
+    #[serde(deserialize_with = "repository_info")]
+    repository: RepositoryInfo,
+    #[serde(default, rename = "installation", deserialize_with = "installation_id")]
+    installation_id: Option<u64>,
+}
+
+/// Deserialize a GitHub `repository` object into a [`RepositoryInfo`].
+fn repository_info<'de, D: Deserializer<'de>>(deserializer: D) -> Result<RepositoryInfo, D::Error> {
+    RepositoryFields::deserialize(deserializer)?
+        .into_repository_info()
+        .map_err(|e| match e {
+            CoreError::InvalidInput { message, .. } => serde::de::Error::custom(message),
+            other => serde::de::Error::custom(other),
+        })
+}
+
+/// Deserialize an optional `installation` object into its `id`.
+fn installation_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+    #[derive(Deserialize)]
+    struct Installation {
+        id: u64,
+    }
+
+    Ok(Option::<Installation>::deserialize(deserializer)?.map(|installation| installation.id))
+}
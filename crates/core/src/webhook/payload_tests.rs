@@ -0,0 +1,286 @@
+use super::*;
+use release_regent_testing::fixtures::webhook_fixtures::{
+    github_pull_request_merged, github_pull_request_opened,
+    github_push_event_with_conventional_commits, github_release_published, PullRequestEventBuilder,
+    PushEventBuilder, ReleaseEventBuilder,
+};
+use serde_json::{json, Value};
+
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+fn parse(event_kind: &str, payload: &Value) -> CoreResult<WebhookEvent> {
+    parse_webhook(event_kind, &serde_json::to_vec(payload).unwrap())
+}
+
+fn repository() -> Value {
+    json!({
+        "name": "widgets",
+        "owner": { "login": "acme" },
+        "default_branch": "main"
+    })
+}
+
+fn comment() -> Value {
+    json!({ "id": 7, "body": "!set-version 2.0.0", "user": { "login": "alice" } })
+}
+
+// ── WebhookEventKind ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_event_kind_round_trips_header_values() {
+    for header in [
+        "issue_comment",
+        "pull_request",
+        "pull_request_review_comment",
+        "push",
+        "release",
+        "workflow_run",
+    ] {
+        assert_eq!(WebhookEventKind::from_header(header).as_str(), header);
+    }
+}
+
+// ── pull_request ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_parse_pull_request_opened_fixture() {
+    let event = parse("pull_request", &github_pull_request_opened()).unwrap();
+
+    let WebhookEvent::PullRequest(payload) = event else {
+        panic!("expected PullRequest, got {event:?}");
+    };
+    assert_eq!(payload.action, "opened");
+    assert!(payload.pull_request.number > 0);
+    assert!(!payload.pull_request.merged);
+    assert!(!payload.pull_request.head.sha.is_empty());
+    assert_eq!(payload.repository.default_branch, "main");
+    assert_eq!(payload.installation_id, None);
+}
+
+#[test]
+fn test_parse_pull_request_merged_fixture() {
+    let payload = PullRequestEventBuilder::new()
+        .as_merged()
+        .with_number(42)
+        .with_title("feat: add widgets")
+        .with_branches("main", "feature/widgets")
+        .with_repository("acme", "widgets")
+        .with_user("alice")
+        .build();
+
+    let event = parse("pull_request", &payload).unwrap();
+
+    let WebhookEvent::PullRequestClosed(payload) = event else {
+        panic!("expected PullRequestClosed, got {event:?}");
+    };
+    assert!(payload.is_merged());
+    assert_eq!(payload.pull_request.number, 42);
+    assert_eq!(payload.pull_request.title, "feat: add widgets");
+    assert_eq!(payload.pull_request.head.ref_name, "feature/widgets");
+    assert_eq!(payload.pull_request.base.ref_name, "main");
+    assert_eq!(payload.pull_request.user.login, "alice");
+    assert_eq!(
+        payload.repository,
+        RepositoryInfo {
+            owner: "acme".to_string(),
+            name: "widgets".to_string(),
+            default_branch: "main".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_parse_pull_request_closed_without_merging() {
+    let mut payload = github_pull_request_merged();
+    payload["pull_request"]["merged"] = json!(false);
+
+    let event = parse("pull_request", &payload).unwrap();
+
+    let WebhookEvent::PullRequestClosed(payload) = event else {
+        panic!("expected PullRequestClosed, got {event:?}");
+    };
+    assert!(!payload.is_merged());
+}
+
+#[test]
+fn test_parse_reads_installation_id() {
+    let mut payload = github_pull_request_opened();
+    payload["installation"] = json!({ "id": 98765 });
+
+    let event = parse("pull_request", &payload).unwrap();
+
+    assert_eq!(event.installation_id(), Some(98765));
+}
+
+#[test]
+fn test_parse_pull_request_without_head_names_the_field() {
+    let mut payload = github_pull_request_opened();
+    payload["pull_request"]
+        .as_object_mut()
+        .unwrap()
+        .remove("head");
+
+    match parse("pull_request", &payload) {
+        Err(CoreError::InvalidInput { field, message, .. }) => {
+            assert_eq!(field, "payload");
+            assert!(message.contains("pull_request"), "{message}");
+            assert!(message.contains("head"), "{message}");
+        }
+        other => panic!("expected InvalidInput, got {other:?}"),
+    }
+}
+
+// ── comments ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_parse_issue_comment_on_pull_request() {
+    let payload = json!({
+        "action": "created",
+        "issue": { "number": 12, "pull_request": { "url": "https://api.github.com/x" } },
+        "comment": comment(),
+        "repository": repository(),
+        "installation": { "id": 5 }
+    });
+
+    let event = parse("issue_comment", &payload).unwrap();
+
+    let WebhookEvent::IssueComment(payload) = event else {
+        panic!("expected IssueComment, got {event:?}");
+    };
+    assert_eq!(payload.number, 12);
+    assert!(payload.is_pull_request);
+    assert_eq!(payload.comment.body, "!set-version 2.0.0");
+    assert_eq!(payload.comment.user.login, "alice");
+    assert_eq!(payload.installation_id, Some(5));
+}
+
+#[test]
+fn test_parse_issue_comment_on_plain_issue() {
+    let payload = json!({
+        "action": "created",
+        "issue": { "number": 3 },
+        "comment": comment(),
+        "repository": repository()
+    });
+
+    let event = parse("issue_comment", &payload).unwrap();
+
+    let WebhookEvent::IssueComment(payload) = event else {
+        panic!("expected IssueComment, got {event:?}");
+    };
+    assert!(!payload.is_pull_request);
+}
+
+#[test]
+fn test_parse_review_comment_uses_pull_request_number() {
+    let payload = json!({
+        "action": "created",
+        "pull_request": { "number": 21 },
+        "comment": comment(),
+        "repository": repository()
+    });
+
+    let event = parse("pull_request_review_comment", &payload).unwrap();
+
+    let WebhookEvent::PullRequestReviewComment(payload) = event else {
+        panic!("expected PullRequestReviewComment, got {event:?}");
+    };
+    assert_eq!(payload.number, 21);
+    assert!(payload.is_pull_request);
+}
+
+// ── push ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_parse_push_fixture() {
+    let event = parse("push", &github_push_event_with_conventional_commits()).unwrap();
+
+    let WebhookEvent::Push(payload) = event else {
+        panic!("expected Push, got {event:?}");
+    };
+    assert_eq!(payload.branch(), Some("main"));
+    assert!(!payload.commits.is_empty());
+    assert!(payload.commits.iter().all(|c| !c.id.is_empty()));
+    assert_eq!(payload.repository.default_branch, "main");
+}
+
+#[test]
+fn test_parse_push_to_tag_has_no_branch() {
+    let payload = PushEventBuilder::new()
+        .with_ref("refs/tags/v1.0.0")
+        .with_repository("acme", "widgets")
+        .build();
+
+    let event = parse("push", &payload).unwrap();
+
+    let WebhookEvent::Push(payload) = event else {
+        panic!("expected Push, got {event:?}");
+    };
+    assert_eq!(payload.branch(), None);
+    assert_eq!(payload.repository.owner, "acme");
+}
+
+// ── release ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_parse_release_fixture() {
+    let event = parse("release", &github_release_published()).unwrap();
+
+    let WebhookEvent::Release(payload) = event else {
+        panic!("expected Release, got {event:?}");
+    };
+    assert_eq!(payload.action, "published");
+    assert!(!payload.release.tag_name.is_empty());
+    assert!(!payload.release.draft);
+}
+
+#[test]
+fn test_parse_draft_release() {
+    let payload = ReleaseEventBuilder::new()
+        .as_created()
+        .as_draft()
+        .with_tag_name("v2.0.0")
+        .build();
+
+    let event = parse("release", &payload).unwrap();
+
+    let WebhookEvent::Release(payload) = event else {
+        panic!("expected Release, got {event:?}");
+    };
+    assert_eq!(payload.release.tag_name, "v2.0.0");
+    assert!(payload.release.draft);
+}
+
+// ── other events and errors ──────────────────────────────────────────────────
+
+#[test]
+fn test_parse_other_event_ignores_the_body() {
+    let event = parse_webhook("workflow_run", b"not json").unwrap();
+
+    assert_eq!(event, WebhookEvent::Other("workflow_run".to_string()));
+    assert_eq!(event.repository(), None);
+    assert_eq!(event.installation_id(), None);
+}
+
+#[test]
+fn test_parse_rejects_invalid_json() {
+    assert!(matches!(
+        parse_webhook("push", b"{"),
+        Err(CoreError::InvalidInput { .. })
+    ));
+}
+
+#[test]
+fn test_parse_missing_repository_field_names_the_field() {
+    let mut payload = github_push_event_with_conventional_commits();
+    payload["repository"]
+        .as_object_mut()
+        .unwrap()
+        .remove("default_branch");
+
+    match parse("push", &payload) {
+        Err(CoreError::InvalidInput { message, .. }) => {
+            assert!(message.contains("repository.default_branch"), "{message}");
+        }
+        other => panic!("expected InvalidInput, got {other:?}"),
+    }
+}
@@ -0,0 +1,264 @@
+//! JSON Schemas for the GitHub webhook payloads Release Regent reads.
+//!
+//! [`webhook_schema`] returns a JSON Schema (draft 2020-12) describing the
+//! fields [`parse_webhook`](super::payload::parse_webhook) needs for an event
+//! kind. Fields Release Regent ignores are not constrained, so real GitHub
+//! deliveries with extra fields validate.
+//!
+//! [`validate_webhook`] checks a raw body against that schema and reports every
+//! violation at once, which makes a truncated or unexpected delivery easy to
+//! diagnose before any deeper processing. The schemas can also be exported for
+//! use by tools outside Release Regent.
+
+use super::payload::WebhookEventKind;
+use crate::{CoreError, CoreResult};
+use serde_json::{json, Value};
+use std::sync::OnceLock;
+
+#[cfg(test)]
+#[path = "schema_tests.rs"]
+mod tests;
+
+/// URI of the JSON Schema dialect the webhook schemas are written in.
+pub const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// The JSON Schema for the payload of `event_kind` (the `X-GitHub-Event`
+/// header value).
+///
+/// Returns `None` for events Release Regent does not parse; their payloads are
+/// never validated.
+///
+/// # Examples
+///
+/// ```
+/// use release_regent_core::webhook::schema::webhook_schema;
+///
+/// let schema = webhook_schema("push").unwrap();
+/// assert!(schema["required"]
+///     .as_array()
+///     .unwrap()
+///     .contains(&"repository".into()));
+/// assert!(webhook_schema("workflow_run").is_none());
+/// ```
+#[must_use]
+pub fn webhook_schema(event_kind: &str) -> Option<Value> {
+    let kind = WebhookEventKind::from_header(event_kind);
+    let (required, properties) = match kind {
+        WebhookEventKind::PullRequest => (
+            json!(["action", "pull_request", "repository"]),
+            json!({
+                "action": { "type": "string" },
+                "pull_request": { "$ref": "#/$defs/pull_request" }
+            }),
+        ),
+        WebhookEventKind::IssueComment => (
+            json!(["action", "issue", "comment", "repository"]),
+            json!({
+                "action": { "type": "string" },
+                "issue": {
+                    "type": "object",
+                    "required": ["number"],
+                    "properties": { "number": { "$ref": "#/$defs/number" } }
+                },
+                "comment": { "$ref": "#/$defs/comment" }
+            }),
+        ),
+        WebhookEventKind::PullRequestReviewComment => (
+            json!(["action", "pull_request", "comment", "repository"]),
+            json!({
+                "action": { "type": "string" },
+                "pull_request": {
+                    "type": "object",
+                    "required": ["number"],
+                    "properties": { "number": { "$ref": "#/$defs/number" } }
+                },
+                "comment": { "$ref": "#/$defs/comment" }
+            }),
+        ),
+        WebhookEventKind::Push => (
+            json!(["ref", "before", "after", "repository"]),
+            json!({
+                "ref": { "type": "string" },
+                "before": { "type": "string" },
+                "after": { "type": "string" },
+                "created": { "type": "boolean" },
+                "deleted": { "type": "boolean" },
+                "forced": { "type": "boolean" },
+                "commits": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "required": ["id", "message"],
+                        "properties": {
+                            "id": { "type": "string" },
+                            "message": { "type": "string" }
+                        }
+                    }
+                }
+            }),
+        ),
+        WebhookEventKind::Release => (
+            json!(["action", "release", "repository"]),
+            json!({
+                "action": { "type": "string" },
+                "release": {
+                    "type": "object",
+                    "required": ["tag_name"],
+                    "properties": {
+                        "tag_name": { "type": "string" },
+                        "name": { "type": ["string", "null"] },
+                        "body": { "type": ["string", "null"] },
+                        "draft": { "type": "boolean" },
+                        "prerelease": { "type": "boolean" }
+                    }
+                }
+            }),
+        ),
+        WebhookEventKind::Other(_) => return None,
+    };
+
+    let mut properties = properties;
+    properties["repository"] = json!({ "$ref": "#/$defs/repository" });
+    properties["installation"] = json!({ "$ref": "#/$defs/installation" });
+
+    Some(json!({
+        "$schema": SCHEMA_DIALECT,
+        "title": format!("GitHub {} webhook payload", kind.as_str()),
+        "type": "object",
+        "required": required,
+        "properties": properties,
+        "$defs": definitions(),
+    }))
+}
+
+/// Validate a webhook body against the schema for `event_kind`.
+///
+/// Events without a schema (see [`webhook_schema`]) are accepted without
+/// looking at the body.
+///
+/// # Errors
+///
+/// Returns [`CoreError::InvalidInput`] when `body` is not valid JSON or does
+/// not match the schema. The message names the event kind and lists every
+/// violation with the JSON pointer of the offending value, e.g.
+/// `at /: "repository" is a required property`.
+#[allow(clippy::result_large_err)] // CoreError is intentionally large
+pub fn validate_webhook(event_kind: &str, body: &[u8]) -> CoreResult<()> {
+    let kind = WebhookEventKind::from_header(event_kind);
+    let Some(validator) = validator(&kind) else {
+        return Ok(());
+    };
+
+    let instance: Value = serde_json::from_slice(body).map_err(|e| {
+        CoreError::invalid_input("payload", format!("invalid {kind} webhook payload: {e}"))
+    })?;
+
+    let violations: Vec<String> = validator
+        .iter_errors(&instance)
+        .map(|error| {
+            let path = error.instance_path().to_string();
+            let path = if path.is_empty() {
+                "/".to_string()
+            } else {
+                path
+            };
+            format!("at {path}: {error}")
+        })
+        .collect();
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    Err(CoreError::invalid_input(
+        "payload",
+        format!(
+            "{kind} webhook payload does not match its schema: {}",
+            violations.join("; ")
+        ),
+    ))
+}
+
+/// The compiled validator for `kind`, built once per event kind.
+fn validator(kind: &WebhookEventKind) -> Option<&'static jsonschema::Validator> {
+    static PULL_REQUEST: OnceLock<jsonschema::Validator> = OnceLock::new();
+    static ISSUE_COMMENT: OnceLock<jsonschema::Validator> = OnceLock::new();
+    static REVIEW_COMMENT: OnceLock<jsonschema::Validator> = OnceLock::new();
+    static PUSH: OnceLock<jsonschema::Validator> = OnceLock::new();
+    static RELEASE: OnceLock<jsonschema::Validator> = OnceLock::new();
+
+    let cell = match kind {
+        WebhookEventKind::PullRequest => &PULL_REQUEST,
+        WebhookEventKind::IssueComment => &ISSUE_COMMENT,
+        WebhookEventKind::PullRequestReviewComment => &REVIEW_COMMENT,
+        WebhookEventKind::Push => &PUSH,
+        WebhookEventKind::Release => &RELEASE,
+        WebhookEventKind::Other(_) => return None,
+    };
+    Some(cell.get_or_init(|| {
+        let schema = webhook_schema(kind.as_str()).expect("supported events have a schema");
+        jsonschema::validator_for(&schema).expect("built-in webhook schemas are valid")
+    }))
+}
+
+/// Definitions shared by the schemas of every event kind.
+fn definitions() -> Value {
+    json!({
+        "number": { "type": "integer", "minimum": 0 },
+        "non_empty_string": { "type": "string", "minLength": 1 },
+        "user": {
+            "type": "object",
+            "required": ["login"],
+            "properties": { "login": { "type": "string" } }
+        },
+        "repository": {
+            "type": "object",
+            "required": ["name", "owner", "default_branch"],
+            "properties": {
+                "name": { "$ref": "#/$defs/non_empty_string" },
+                "owner": {
+                    "type": "object",
+                    "required": ["login"],
+                    "properties": { "login": { "$ref": "#/$defs/non_empty_string" } }
+                },
+                "default_branch": { "$ref": "#/$defs/non_empty_string" }
+            }
+        },
+        "installation": {
+            "type": ["object", "null"],
+            "required": ["id"],
+            "properties": { "id": { "$ref": "#/$defs/number" } }
+        },
+        "branch": {
+            "type": "object",
+            "required": ["ref", "sha"],
+            "properties": {
+                "ref": { "type": "string" },
+                "sha": { "type": "string" }
+            }
+        },
+        "pull_request": {
+            "type": "object",
+            "required": ["number", "title", "head", "base", "user"],
+            "properties": {
+                "number": { "$ref": "#/$defs/number" },
+                "title": { "type": "string" },
+                "body": { "type": ["string", "null"] },
+                "draft": { "type": "boolean" },
+                "merged": { "type": "boolean" },
+                "merge_commit_sha": { "type": ["string", "null"] },
+                "head": { "$ref": "#/$defs/branch" },
+                "base": { "$ref": "#/$defs/branch" },
+                "user": { "$ref": "#/$defs/user" }
+            }
+        },
+        "comment": {
+            "type": "object",
+            "required": ["id", "user"],
+            "properties": {
+                "id": { "$ref": "#/$defs/number" },
+                "body": { "type": "string" },
+                "user": { "$ref": "#/$defs/user" }
+            }
+        }
+    })
+}
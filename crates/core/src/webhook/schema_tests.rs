@@ -0,0 +1,124 @@
+use super::*;
+use crate::webhook::payload::{parse_validated_webhook, WebhookEvent};
+use release_regent_testing::fixtures::webhook_fixtures::{
+    github_pull_request_merged, github_pull_request_opened,
+    github_push_event_with_conventional_commits, github_release_published,
+};
+
+fn bytes(payload: &Value) -> Vec<u8> {
+    serde_json::to_vec(payload).unwrap()
+}
+
+fn invalid_input_message(result: CoreResult<()>) -> String {
+    match result {
+        Err(CoreError::InvalidInput { message, .. }) => message,
+        other => panic!("expected InvalidInput, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_every_parsed_event_kind_has_a_schema_in_the_2020_12_dialect() {
+    for kind in [
+        "issue_comment",
+        "pull_request",
+        "pull_request_review_comment",
+        "push",
+        "release",
+    ] {
+        let schema = webhook_schema(kind).unwrap();
+
+        assert_eq!(schema["$schema"], SCHEMA_DIALECT, "{kind}");
+        assert!(
+            jsonschema::draft202012::meta::is_valid(&schema),
+            "{kind} schema is not a valid JSON Schema"
+        );
+    }
+    assert!(webhook_schema("workflow_run").is_none());
+}
+
+#[test]
+fn test_github_fixtures_pass_validation() {
+    let fixtures = [
+        ("pull_request", github_pull_request_opened()),
+        ("pull_request", github_pull_request_merged()),
+        ("push", github_push_event_with_conventional_commits()),
+        ("release", github_release_published()),
+    ];
+
+    for (kind, payload) in fixtures {
+        validate_webhook(kind, &bytes(&payload))
+            .unwrap_or_else(|e| panic!("{kind} fixture should validate: {e}"));
+    }
+}
+
+#[test]
+fn test_payload_without_repository_fails_validation() {
+    let mut payload = github_pull_request_merged();
+    payload.as_object_mut().unwrap().remove("repository");
+
+    let message = invalid_input_message(validate_webhook("pull_request", &bytes(&payload)));
+
+    assert!(
+        message.contains("pull_request webhook payload"),
+        "{message}"
+    );
+    assert!(message.contains("\"repository\""), "{message}");
+}
+
+#[test]
+fn test_validation_lists_every_violation_with_its_location() {
+    let mut payload = github_push_event_with_conventional_commits();
+    payload["repository"]["default_branch"] = json!("");
+    payload["commits"][0]
+        .as_object_mut()
+        .unwrap()
+        .remove("message");
+
+    let message = invalid_input_message(validate_webhook("push", &bytes(&payload)));
+
+    assert!(
+        message.contains("at /repository/default_branch"),
+        "{message}"
+    );
+    assert!(message.contains("at /commits/0"), "{message}");
+}
+
+#[test]
+fn test_validation_rejects_invalid_json() {
+    let message = invalid_input_message(validate_webhook("push", b"{\"ref\": "));
+
+    assert!(
+        message.contains("invalid push webhook payload"),
+        "{message}"
+    );
+}
+
+#[test]
+fn test_events_without_a_schema_are_not_validated() {
+    assert!(validate_webhook("workflow_run", b"not json").is_ok());
+}
+
+#[test]
+fn test_parse_validated_webhook_parses_a_valid_delivery() {
+    let event =
+        parse_validated_webhook("pull_request", &bytes(&github_pull_request_merged())).unwrap();
+
+    assert!(
+        matches!(event, WebhookEvent::PullRequestClosed(_)),
+        "{event:?}"
+    );
+}
+
+#[test]
+fn test_parse_validated_webhook_reports_schema_violations() {
+    let mut payload = github_release_published();
+    payload["release"]["draft"] = json!("no");
+
+    match parse_validated_webhook("release", &bytes(&payload)) {
+        Err(CoreError::InvalidInput { message, .. }) => {
+            assert!(message.contains("does not match its schema"), "{message}");
+            assert!(message.contains("at /release/draft"), "{message}");
+        }
+        other => panic!("expected InvalidInput, got {other:?}"),
+    }
+}
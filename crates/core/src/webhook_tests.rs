@@ -0,0 +1,98 @@
+use super::*;
+use release_regent_testing::fixtures::webhook_fixtures::{
+    PullRequestEventBuilder, PushEventBuilder,
+};
+use serde_json::{json, Value};
+
+fn bytes(payload: &Value) -> Vec<u8> {
+    serde_json::to_vec(payload).unwrap()
+}
+
+fn assert_missing(payload: Value, expected_field: &str) {
+    match extract_repository(&bytes(&payload)) {
+        Err(CoreError::InvalidInput { field, message, .. }) => {
+            assert_eq!(field, expected_field);
+            assert!(message.contains(expected_field), "{message}");
+        }
+        other => panic!("expected InvalidInput for {expected_field}, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_extract_repository_from_push_fixture() {
+    let payload = PushEventBuilder::new()
+        .with_repository("acme", "widgets")
+        .build();
+
+    let repository = extract_repository(&bytes(&payload)).unwrap();
+
+    assert_eq!(
+        repository,
+        RepositoryInfo {
+            owner: "acme".to_string(),
+            name: "widgets".to_string(),
+            default_branch: "main".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_extract_repository_from_pull_request_fixture() {
+    let payload = PullRequestEventBuilder::new()
+        .with_repository("octocat", "hello-world")
+        .build();
+
+    let repository = extract_repository(&bytes(&payload)).unwrap();
+
+    assert_eq!(repository.owner, "octocat");
+    assert_eq!(repository.name, "hello-world");
+    assert_eq!(repository.default_branch, "main");
+}
+
+#[test]
+fn test_extract_repository_ignores_the_rest_of_the_payload() {
+    let payload = json!({
+        "action": 42,
+        "pull_request": "not an object",
+        "repository": {
+            "name": "app",
+            "owner": { "login": "acme" },
+            "default_branch": "trunk"
+        }
+    });
+
+    let repository = extract_repository(&bytes(&payload)).unwrap();
+
+    assert_eq!(repository.default_branch, "trunk");
+}
+
+#[test]
+fn test_extract_repository_reports_missing_fields() {
+    assert_missing(json!({ "action": "opened" }), "repository");
+    assert_missing(
+        json!({ "repository": { "name": "app", "default_branch": "main" } }),
+        "repository.owner.login",
+    );
+    assert_missing(
+        json!({ "repository": { "owner": { "login": "acme" }, "default_branch": "main" } }),
+        "repository.name",
+    );
+    assert_missing(
+        json!({ "repository": { "name": "app", "owner": { "login": "acme" } } }),
+        "repository.default_branch",
+    );
+    assert_missing(
+        json!({ "repository": { "name": "", "owner": { "login": "acme" }, "default_branch": "main" } }),
+        "repository.name",
+    );
+}
+
+#[test]
+fn test_extract_repository_rejects_invalid_json() {
+    let result = extract_repository(b"{ not json");
+
+    assert!(matches!(
+        result,
+        Err(CoreError::InvalidInput { ref field, .. }) if field == "payload"
+    ));
+}
@@ -69,6 +69,7 @@ async fn test_version_calculator_trait_object_safety() {
         target_branch: "main".to_string(),
         base_ref: None,
         head_ref: "HEAD".to_string(),
+        package_versions: HashMap::new(),
     };
     let strategy = version_calculator::VersioningStrategy::ConventionalCommits {
         custom_types: HashMap::new(),
@@ -146,6 +147,7 @@ async fn test_mock_error_handling() {
         owner: "owner".to_string(),
         repo: "repo".to_string(),
         target_branch: "main".to_string(),
+        package_versions: HashMap::new(),
     };
     let strategy = version_calculator::VersioningStrategy::ConventionalCommits {
         custom_types: HashMap::new(),
@@ -185,6 +187,7 @@ async fn test_async_trait_compatibility() {
             owner: "owner".to_string(),
             repo: "repo".to_string(),
             target_branch: "main".to_string(),
+            package_versions: HashMap::new(),
         };
         let strategy = version_calculator::VersioningStrategy::ConventionalCommits {
             custom_types: HashMap::new(),
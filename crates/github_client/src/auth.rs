@@ -1,20 +1,39 @@
 //! Authentication module for github-bot-sdk integration
 //!
-//! Provides [`SecretProvider`], [`JwtSigner`], and [`GitHubApiClient`] implementations
-//! for use with github-bot-sdk in production deployments.
+//! Provides [`SecretProvider`], [`JwtSigner`], [`GitHubApiClient`] and [`TokenCache`]
+//! implementations for use with github-bot-sdk in production deployments, plus
+//! [`JwtValidator`] for verifying app JWTs that are accepted internally.
 
+use crate::errors::{Error, GitHubResult};
 use async_trait::async_trait;
 use chrono::{DateTime, Duration, Utc};
 use github_bot_sdk::{
     auth::{
-        GitHubApiClient, GitHubAppId, Installation, InstallationId, InstallationPermissions,
-        InstallationToken, JsonWebToken, JwtClaims, JwtSigner, PrivateKey, RateLimitInfo,
-        Repository, RepositoryId, SecretProvider,
+        tokens::AuthConfig as SdkAuthConfig, GitHubApiClient, GitHubAppId, Installation,
+        InstallationId, InstallationPermissions, InstallationToken, JsonWebToken, JwtClaims,
+        JwtSigner, PrivateKey, RateLimitInfo, Repository, RepositoryId, SecretProvider, TokenCache,
     },
-    error::{ApiError, SecretError, SigningError, ValidationError},
+    error::{ApiError, CacheError, SecretError, SigningError, ValidationError},
 };
-use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
-use tracing::debug;
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use release_regent_core::clock::{system_clock, Clock};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+use tracing::{debug, warn};
+
+/// Default lifetime of app JWTs, in seconds.
+pub const DEFAULT_JWT_EXPIRATION_SECS: u64 = 600;
+
+/// Longest app JWT lifetime GitHub accepts, in seconds.
+///
+/// GitHub rejects JWTs whose `exp` lies more than 10 minutes in the future.
+pub const MAX_JWT_EXPIRATION_SECS: u64 = 600;
+
+/// Default timeout for a single GitHub API request, in seconds.
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 30;
 
 /// Configuration for GitHub App authentication
 #[derive(Clone)]
@@ -25,6 +44,59 @@ pub struct AuthConfig {
     pub private_key: String,
     /// Webhook secret for signature validation
     pub webhook_secret: String,
+    /// Lifetime of app JWTs in seconds; values above
+    /// [`MAX_JWT_EXPIRATION_SECS`] are clamped to it
+    pub jwt_expiration_seconds: u64,
+    /// Timeout for a single GitHub API request in seconds; see
+    /// [`DEFAULT_REQUEST_TIMEOUT_SECS`]
+    pub request_timeout_seconds: u64,
+}
+
+impl AuthConfig {
+    /// Lifetime of app JWTs, clamped to GitHub's 10-minute maximum.
+    ///
+    /// A warning is logged when the configured value had to be clamped.
+    #[must_use]
+    pub fn jwt_expiration(&self) -> Duration {
+        let seconds = if self.jwt_expiration_seconds > MAX_JWT_EXPIRATION_SECS {
+            warn!(
+                configured = self.jwt_expiration_seconds,
+                max = MAX_JWT_EXPIRATION_SECS,
+                "JWT expiration exceeds GitHub's maximum; clamping"
+            );
+            MAX_JWT_EXPIRATION_SECS
+        } else {
+            self.jwt_expiration_seconds
+        };
+        Duration::seconds(i64::try_from(seconds).unwrap_or(i64::MAX))
+    }
+
+    /// Timeout applied to every GitHub API request made by the client.
+    ///
+    /// A value of `0` falls back to [`DEFAULT_REQUEST_TIMEOUT_SECS`].
+    #[must_use]
+    pub fn request_timeout(&self) -> std::time::Duration {
+        let seconds = match self.request_timeout_seconds {
+            0 => DEFAULT_REQUEST_TIMEOUT_SECS,
+            seconds => seconds,
+        };
+        std::time::Duration::from_secs(seconds)
+    }
+
+    /// SDK authentication settings using this configuration's JWT lifetime.
+    ///
+    /// The JWT refresh margin is reduced for short lifetimes so that a cached
+    /// JWT is still reused for half its lifetime.
+    #[must_use]
+    pub fn sdk_auth_config(&self) -> SdkAuthConfig {
+        let jwt_expiration = self.jwt_expiration();
+        let defaults = SdkAuthConfig::default();
+        SdkAuthConfig {
+            jwt_expiration,
+            jwt_refresh_margin: defaults.jwt_refresh_margin.min(jwt_expiration / 2),
+            ..defaults
+        }
+    }
 }
 
 /// Environment-variable-based secret provider.
@@ -39,7 +111,7 @@ pub struct AuthConfig {
 /// AKS + CSI Driver, AWS ECS, etc.) injects secrets as environment variables before
 /// the process starts. This struct simply holds those pre-loaded values — there is no
 /// need for the application to call a secret-management API at runtime.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct EnvSecretProvider {
     app_id: GitHubAppId,
     private_key: PrivateKey,
@@ -70,6 +142,16 @@ impl EnvSecretProvider {
     }
 }
 
+impl std::fmt::Debug for EnvSecretProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EnvSecretProvider")
+            .field("app_id", &self.app_id)
+            .field("private_key", &"REDACTED")
+            .field("webhook_secret", &"REDACTED")
+            .finish()
+    }
+}
+
 #[async_trait]
 impl SecretProvider for EnvSecretProvider {
     async fn get_private_key(&self) -> Result<PrivateKey, SecretError> {
@@ -101,14 +183,35 @@ impl SecretProvider for EnvSecretProvider {
 /// Implements [`JwtSigner`] using the `jsonwebtoken` crate to produce RS256-signed
 /// JWTs suitable for GitHub App authentication.
 #[derive(Debug, Clone)]
-pub struct DefaultJwtSigner;
+pub struct DefaultJwtSigner {
+    audience: Option<String>,
+}
 
 impl DefaultJwtSigner {
     /// Create a new [`DefaultJwtSigner`].
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self { audience: None }
     }
+
+    /// Include an `aud` claim in every signed JWT.
+    ///
+    /// GitHub itself ignores `aud`; set this when tokens are also checked
+    /// internally with [`JwtValidator::with_audience`].
+    #[must_use]
+    pub fn with_audience(mut self, audience: impl Into<String>) -> Self {
+        self.audience = Some(audience.into());
+        self
+    }
+}
+
+/// Claims as encoded by [`DefaultJwtSigner`]: the SDK claims plus an optional `aud`.
+#[derive(Serialize)]
+struct SignedClaims<'a> {
+    #[serde(flatten)]
+    claims: &'a JwtClaims,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    aud: Option<&'a str>,
 }
 
 impl Default for DefaultJwtSigner {
@@ -134,10 +237,15 @@ impl JwtSigner for DefaultJwtSigner {
         let exp = claims.exp;
         let header = Header::new(Algorithm::RS256);
 
-        let token_string =
-            encode(&header, &claims, &encoding_key).map_err(|e| SigningError::EncodingFailed {
+        let signed_claims = SignedClaims {
+            claims: &claims,
+            aud: self.audience.as_deref(),
+        };
+        let token_string = encode(&header, &signed_claims, &encoding_key).map_err(|e| {
+            SigningError::EncodingFailed {
                 message: format!("Failed to encode JWT: {e}"),
-            })?;
+            }
+        })?;
 
         let expires_at =
             DateTime::from_timestamp(exp, 0).ok_or_else(|| SigningError::SigningFailed {
@@ -156,19 +264,330 @@ impl JwtSigner for DefaultJwtSigner {
     }
 }
 
+// ============================================================================
+// JwtValidator
+// ============================================================================
+
+/// Clock skew tolerated when checking `exp` and `iat`, in seconds.
+///
+/// Matches the 60 s back-dating GitHub recommends when issuing app JWTs.
+const JWT_LEEWAY_SECS: u64 = 60;
+
+/// Claims decoded from a JWT before validation of `iss`, `iat` and `aud`.
+#[derive(Deserialize)]
+struct DecodedClaims {
+    iss: u64,
+    iat: i64,
+    exp: i64,
+    #[serde(default)]
+    aud: Option<serde_json::Value>,
+}
+
+/// Validates GitHub App JWTs such as those produced by [`DefaultJwtSigner`].
+///
+/// Validation checks the RS256 signature, that the token has not expired
+/// (`exp`), that it was not issued in the future (`iat`), that the issuer is
+/// the expected GitHub App and, when an audience is configured, that `aud`
+/// matches it.
+#[derive(Clone)]
+pub struct JwtValidator {
+    decoding_key: DecodingKey,
+    app_id: GitHubAppId,
+    audience: Option<String>,
+}
+
+impl JwtValidator {
+    /// Create a validator from the app's PEM-encoded RSA public key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Auth`] if `public_key_pem` is not a valid RSA public key.
+    pub fn from_rsa_pem(public_key_pem: &[u8], app_id: u64) -> GitHubResult<Self> {
+        let decoding_key = DecodingKey::from_rsa_pem(public_key_pem).map_err(|e| Error::Auth {
+            message: format!("Invalid RSA public key: {e}"),
+            source: Some(Box::new(e)),
+        })?;
+
+        Ok(Self {
+            decoding_key,
+            app_id: GitHubAppId::new(app_id),
+            audience: None,
+        })
+    }
+
+    /// Require the token's `aud` claim to match `audience`.
+    ///
+    /// For GitHub Enterprise Server deployments this is typically the API base
+    /// URL (for example `https://github.example.com/api/v3`). Trailing slashes
+    /// are ignored when comparing.
+    #[must_use]
+    pub fn with_audience(mut self, audience: impl Into<String>) -> Self {
+        self.audience = Some(audience.into());
+        self
+    }
+
+    /// Validate `token` and return its claims.
+    ///
+    /// When no audience is configured, tokens carrying an `aud` claim are still
+    /// accepted; the claim is simply not checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Auth`] if the signature is invalid, the token has
+    /// expired, `iat` lies in the future, the issuer is not the expected app,
+    /// or the audience does not match.
+    pub fn validate_jwt(&self, token: &str) -> GitHubResult<JwtClaims> {
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.leeway = JWT_LEEWAY_SECS;
+        // `iss` is numeric for GitHub Apps, which `jsonwebtoken` cannot check;
+        // `iss` and `iat` presence is enforced by deserialising `DecodedClaims`.
+        validation.set_required_spec_claims(&["exp"]);
+        // `jsonwebtoken` rejects any token carrying `aud` unless an exact
+        // audience list is configured, and compares it verbatim. The audience
+        // is checked below instead so that enterprise URLs compare without
+        // trailing-slash sensitivity.
+        validation.validate_aud = false;
+
+        let data =
+            decode::<DecodedClaims>(token, &self.decoding_key, &validation).map_err(|e| {
+                Error::Auth {
+                    message: format!("JWT validation failed: {e}"),
+                    source: Some(Box::new(e)),
+                }
+            })?;
+        let claims = data.claims;
+
+        if claims.iss != self.app_id.as_u64() {
+            return Err(jwt_error(format!(
+                "JWT issuer {} does not match app ID {}",
+                claims.iss,
+                self.app_id.as_u64()
+            )));
+        }
+
+        let latest_iat = Utc::now().timestamp() + i64::try_from(JWT_LEEWAY_SECS).unwrap_or(0);
+        if claims.iat > latest_iat {
+            return Err(jwt_error(format!(
+                "JWT issued in the future (iat {})",
+                claims.iat
+            )));
+        }
+
+        if let Some(expected) = &self.audience {
+            if !audience_matches(claims.aud.as_ref(), expected) {
+                return Err(jwt_error(format!(
+                    "JWT audience does not match expected audience '{expected}'"
+                )));
+            }
+        }
+
+        Ok(JwtClaims {
+            iss: GitHubAppId::new(claims.iss),
+            iat: claims.iat,
+            exp: claims.exp,
+        })
+    }
+}
+
+impl std::fmt::Debug for JwtValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtValidator")
+            .field("app_id", &self.app_id)
+            .field("audience", &self.audience)
+            .finish_non_exhaustive()
+    }
+}
+
+fn jwt_error(message: String) -> Error {
+    Error::Auth {
+        message,
+        source: None,
+    }
+}
+
+/// Returns `true` if the `aud` claim (a string or an array of strings)
+/// contains `expected`, ignoring trailing slashes.
+fn audience_matches(aud: Option<&serde_json::Value>, expected: &str) -> bool {
+    let expected = expected.trim_end_matches('/');
+    let matches = |value: &serde_json::Value| {
+        value
+            .as_str()
+            .is_some_and(|a| a.trim_end_matches('/') == expected)
+    };
+
+    match aud {
+        Some(serde_json::Value::Array(values)) => values.iter().any(matches),
+        Some(value) => matches(value),
+        None => false,
+    }
+}
+
+// ============================================================================
+// SharedTokenCache
+// ============================================================================
+
+/// In-memory token cache that can be shared with a background cleanup task.
+///
+/// Expired tokens stay cached until [`TokenCache::cleanup_expired_tokens`]
+/// drops them; the SDK checks a cached token's expiry itself before using
+/// it. Expiry is judged against the cache's [`Clock`]. Clones share the same
+/// cache, so one clone can be handed to the SDK while another drives
+/// [`start_cleanup`](SharedTokenCache::start_cleanup).
+#[derive(Clone)]
+pub struct SharedTokenCache {
+    jwts: Arc<RwLock<HashMap<GitHubAppId, JsonWebToken>>>,
+    installation_tokens: Arc<RwLock<HashMap<InstallationId, InstallationToken>>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl Default for SharedTokenCache {
+    fn default() -> Self {
+        Self {
+            jwts: Arc::default(),
+            installation_tokens: Arc::default(),
+            clock: system_clock(),
+        }
+    }
+}
+
+impl SharedTokenCache {
+    /// Create an empty cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Judge token expiry against `clock` instead of the system clock.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns `true` if a token expiring at `expires_at` has expired.
+    fn is_expired(&self, expires_at: DateTime<Utc>) -> bool {
+        self.clock.now() >= expires_at
+    }
+
+    /// Remove every expired token.
+    fn remove_expired(&self) {
+        if let Ok(mut jwts) = self.jwts.write() {
+            jwts.retain(|_, jwt| !self.is_expired(jwt.expires_at()));
+        }
+        if let Ok(mut tokens) = self.installation_tokens.write() {
+            tokens.retain(|_, token| !self.is_expired(token.expires_at()));
+        }
+    }
+
+    /// Remove expired tokens every `interval` until the returned guard is
+    /// dropped.
+    ///
+    /// Must be called from within a Tokio runtime.
+    #[must_use = "the cleanup task stops when the guard is dropped"]
+    pub fn start_cleanup(&self, interval: std::time::Duration) -> CacheCleanupGuard {
+        let cache = self.clone();
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                // The cleanup is synchronous, so the cache locks are released
+                // before the task yields again.
+                cache.remove_expired();
+                debug!("Removed expired tokens from token cache");
+            }
+        });
+        CacheCleanupGuard { handle }
+    }
+}
+
+impl std::fmt::Debug for SharedTokenCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedTokenCache").finish_non_exhaustive()
+    }
+}
+
+/// Map a poisoned cache lock to a [`CacheError`].
+fn lock_error<T>(error: std::sync::PoisonError<T>) -> CacheError {
+    CacheError::OperationFailed {
+        message: format!("Token cache lock poisoned: {error}"),
+    }
+}
+
+#[async_trait]
+impl TokenCache for SharedTokenCache {
+    async fn get_jwt(&self, app_id: GitHubAppId) -> Result<Option<JsonWebToken>, CacheError> {
+        let jwts = self.jwts.read().map_err(lock_error)?;
+        Ok(jwts.get(&app_id).cloned())
+    }
+
+    async fn store_jwt(&self, jwt: JsonWebToken) -> Result<(), CacheError> {
+        let mut jwts = self.jwts.write().map_err(lock_error)?;
+        jwts.insert(jwt.app_id(), jwt);
+        Ok(())
+    }
+
+    async fn get_installation_token(
+        &self,
+        installation_id: InstallationId,
+    ) -> Result<Option<InstallationToken>, CacheError> {
+        let tokens = self.installation_tokens.read().map_err(lock_error)?;
+        Ok(tokens.get(&installation_id).cloned())
+    }
+
+    async fn store_installation_token(&self, token: InstallationToken) -> Result<(), CacheError> {
+        let mut tokens = self.installation_tokens.write().map_err(lock_error)?;
+        tokens.insert(token.installation_id(), token);
+        Ok(())
+    }
+
+    async fn invalidate_installation_token(
+        &self,
+        installation_id: InstallationId,
+    ) -> Result<(), CacheError> {
+        let mut tokens = self.installation_tokens.write().map_err(lock_error)?;
+        tokens.remove(&installation_id);
+        Ok(())
+    }
+
+    fn cleanup_expired_tokens(&self) {
+        self.remove_expired();
+    }
+}
+
+/// Stops the background cleanup task started by
+/// [`SharedTokenCache::start_cleanup`] when dropped.
+#[derive(Debug)]
+pub struct CacheCleanupGuard {
+    handle: tokio::task::JoinHandle<()>,
+}
+
+impl Drop for CacheCleanupGuard {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
 // ============================================================================
 // DefaultGitHubApiClient
 // ============================================================================
 
+/// Callback invoked when a fresh installation token is acquired.
+///
+/// Receives the installation ID and the new token's expiry. The token value
+/// itself is never passed to the callback.
+pub type TokenRefreshCallback = Arc<dyn Fn(u64, DateTime<Utc>) + Send + Sync>;
+
 /// HTTP-based GitHub API client for authentication operations.
 ///
 /// Implements [`GitHubApiClient`] using reqwest for the network calls needed
 /// to exchange JWT tokens for installation access tokens.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct DefaultGitHubApiClient {
     http_client: reqwest::Client,
     api_base_url: String,
     user_agent: String,
+    on_token_refresh: Option<TokenRefreshCallback>,
 }
 
 impl DefaultGitHubApiClient {
@@ -179,8 +598,40 @@ impl DefaultGitHubApiClient {
             http_client: reqwest::Client::new(),
             api_base_url: "https://api.github.com".to_string(),
             user_agent: "release-regent/0.1.0".to_string(),
+            on_token_refresh: None,
         }
     }
+
+    /// Point the client at a different API base URL (e.g. GitHub Enterprise Server).
+    #[must_use]
+    pub fn with_api_base_url(mut self, api_base_url: impl Into<String>) -> Self {
+        self.api_base_url = api_base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Register a callback fired whenever a fresh installation token is acquired.
+    ///
+    /// The SDK's token cache only calls through to this client on a cache miss
+    /// or explicit refresh, so the callback never fires for cached tokens. Use
+    /// it to emit audit logs or metrics for token refreshes.
+    #[must_use]
+    pub fn on_token_refresh(
+        mut self,
+        callback: impl Fn(u64, DateTime<Utc>) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_token_refresh = Some(Arc::new(callback));
+        self
+    }
+}
+
+impl std::fmt::Debug for DefaultGitHubApiClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DefaultGitHubApiClient")
+            .field("api_base_url", &self.api_base_url)
+            .field("user_agent", &self.user_agent)
+            .field("on_token_refresh", &self.on_token_refresh.is_some())
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for DefaultGitHubApiClient {
@@ -237,6 +688,15 @@ impl GitHubApiClient for DefaultGitHubApiClient {
                 message: format!("Failed to parse token response: {e}"),
             })?;
 
+        debug!(
+            installation_id = installation_id.as_u64(),
+            expires_at = %token_response.expires_at,
+            "Acquired installation access token"
+        );
+        if let Some(callback) = &self.on_token_refresh {
+            callback(installation_id.as_u64(), token_response.expires_at);
+        }
+
         Ok(InstallationToken::new(
             token_response.token,
             installation_id,
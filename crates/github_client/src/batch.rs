@@ -0,0 +1,98 @@
+//! Bounded-concurrency execution of many GitHub requests.
+//!
+//! Bulk work, such as creating release PRs across every repository of an
+//! installation, issues far more requests than a single event. [`GitHubClient::run_batched`]
+//! runs such a set of requests a few at a time, sends each one through the
+//! client's [`RateLimiter`](crate::RateLimiter) so transient failures and rate
+//! limits are retried, and reports the outcome of every request instead of
+//! stopping at the first failure.
+
+use crate::errors::{Error, GitHubResult};
+use crate::GitHubClient;
+use futures::stream::{self, StreamExt};
+use std::future::Future;
+use tracing::{debug, warn};
+
+/// Outcome of [`GitHubClient::run_batched`].
+///
+/// Entries are keyed by the position of the task in the input and sorted by it.
+#[derive(Debug)]
+pub struct BatchResult<T> {
+    /// Values returned by the tasks that succeeded.
+    pub succeeded: Vec<(usize, T)>,
+    /// Errors returned by the tasks that failed, after retries.
+    pub failed: Vec<(usize, Error)>,
+}
+
+impl<T> BatchResult<T> {
+    /// Total number of tasks that were run.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.succeeded.len() + self.failed.len()
+    }
+
+    /// Returns `true` if every task succeeded.
+    #[must_use]
+    pub fn is_complete_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+impl GitHubClient {
+    /// Run `tasks` with at most `concurrency` of them in flight at once.
+    ///
+    /// Each task is invoked once per attempt through the client's
+    /// [`RateLimiter`](crate::RateLimiter), so network failures and rate
+    /// limits are retried according to the [`RetryPolicy`](crate::RetryPolicy).
+    /// A task that still fails is recorded in [`BatchResult::failed`] and the
+    /// remaining tasks keep running. A `concurrency` of `0` is treated as `1`.
+    pub async fn run_batched<T, F, Fut>(&self, tasks: Vec<F>, concurrency: usize) -> BatchResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = GitHubResult<T>>,
+    {
+        let total = tasks.len();
+        debug!(total, concurrency, "Running batched GitHub requests");
+
+        let mut outcomes: Vec<(usize, GitHubResult<T>)> =
+            stream::iter(tasks.into_iter().enumerate())
+                .map(|(index, task)| async move {
+                    (
+                        index,
+                        self.rate_limiter
+                            .execute_with_timeout("run_batched", self.request_timeout, task)
+                            .await,
+                    )
+                })
+                .buffer_unordered(concurrency.max(1))
+                .collect()
+                .await;
+        outcomes.sort_by_key(|(index, _)| *index);
+
+        let mut result = BatchResult {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        };
+        for (index, outcome) in outcomes {
+            match outcome {
+                Ok(value) => result.succeeded.push((index, value)),
+                Err(error) => {
+                    warn!(task = index, error = %error, "Batched GitHub request failed");
+                    result.failed.push((index, error));
+                }
+            }
+        }
+
+        debug!(
+            total,
+            succeeded = result.succeeded.len(),
+            failed = result.failed.len(),
+            "Batched GitHub requests complete"
+        );
+        result
+    }
+}
+
+#[cfg(test)]
+#[path = "batch_tests.rs"]
+mod tests;
@@ -0,0 +1,198 @@
+// Tests for `GitHubClient::run_batched`.
+// The batched tasks do not touch the network; the client only supplies its
+// rate limiter, so it points at an address that is never contacted.
+
+use super::*;
+use crate::RetryPolicy;
+use github_bot_sdk::{
+    auth::{
+        AuthenticationProvider, Installation, InstallationId, InstallationPermissions,
+        InstallationToken, JsonWebToken, Repository as SdkRepository,
+    },
+    error::AuthError,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+// ---------------------------------------------------------------------------
+// Minimal mock auth provider (no real credentials needed)
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+struct MockAuthProvider;
+
+#[async_trait::async_trait]
+impl AuthenticationProvider for MockAuthProvider {
+    async fn app_token(&self) -> Result<JsonWebToken, AuthError> {
+        Err(AuthError::TokenGenerationFailed {
+            message: "not implemented for mock".into(),
+        })
+    }
+
+    async fn installation_token(
+        &self,
+        installation_id: InstallationId,
+    ) -> Result<InstallationToken, AuthError> {
+        let expires_at = chrono::Utc::now() + chrono::Duration::hours(1);
+        Ok(InstallationToken::new(
+            "fake-token".to_string(),
+            installation_id,
+            expires_at,
+            InstallationPermissions::default(),
+            Vec::new(),
+        ))
+    }
+
+    async fn refresh_installation_token(
+        &self,
+        installation_id: InstallationId,
+    ) -> Result<InstallationToken, AuthError> {
+        self.installation_token(installation_id).await
+    }
+
+    async fn list_installations(&self) -> Result<Vec<Installation>, AuthError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_installation_repositories(
+        &self,
+        _installation_id: InstallationId,
+    ) -> Result<Vec<SdkRepository>, AuthError> {
+        Ok(Vec::new())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn make_client() -> GitHubClient {
+    GitHubClient::new_for_testing(MockAuthProvider, 1, "http://127.0.0.1:9")
+        .expect("test client construction should not fail")
+        .with_retry_policy(RetryPolicy {
+            max_retries: 2,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(2),
+            secondary_rate_limit_base_delay: Duration::from_millis(1),
+            secondary_rate_limit_max_delay: Duration::from_millis(2),
+        })
+}
+
+// ---------------------------------------------------------------------------
+// run_batched
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_run_batched_collects_successes_and_failures() {
+    let client = make_client();
+    let tasks: Vec<_> = (0..5_u32)
+        .map(|i| {
+            move || async move {
+                if i % 2 == 0 {
+                    Ok(i * 10)
+                } else {
+                    Err(Error::NotFound {
+                        resource: format!("repo-{i}"),
+                    })
+                }
+            }
+        })
+        .collect();
+
+    let result = client.run_batched(tasks, 2).await;
+
+    assert_eq!(result.succeeded, vec![(0, 0), (2, 20), (4, 40)]);
+    let failed: Vec<usize> = result.failed.iter().map(|(i, _)| *i).collect();
+    assert_eq!(failed, vec![1, 3]);
+    assert!(matches!(
+        &result.failed[0].1,
+        Error::NotFound { resource } if resource == "repo-1"
+    ));
+    assert_eq!(result.total(), 5);
+    assert!(!result.is_complete_success());
+}
+
+#[tokio::test]
+async fn test_run_batched_retries_transient_failures_through_rate_limiter() {
+    let client = make_client();
+    let attempts: Vec<Arc<AtomicUsize>> = (0..2).map(|_| Arc::new(AtomicUsize::new(0))).collect();
+    let tasks: Vec<_> = attempts
+        .iter()
+        .enumerate()
+        .map(|(i, counter)| {
+            let counter = Arc::clone(counter);
+            move || {
+                let attempt = counter.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    match (i, attempt) {
+                        // Rate limited once, then succeeds.
+                        (0, 0) => Err(Error::RateLimit),
+                        (0, _) => Ok("created"),
+                        // Never recovers.
+                        _ => Err(Error::RateLimit),
+                    }
+                }
+            }
+        })
+        .collect();
+
+    let result = client.run_batched(tasks, 4).await;
+
+    assert_eq!(result.succeeded, vec![(0, "created")]);
+    assert_eq!(result.failed.len(), 1);
+    assert_eq!(result.failed[0].0, 1);
+    assert_eq!(attempts[0].load(Ordering::SeqCst), 2);
+    assert_eq!(
+        attempts[1].load(Ordering::SeqCst),
+        3,
+        "initial attempt + 2 retries"
+    );
+    assert_eq!(client.rate_limiter().metrics_snapshot().rate_limit_waits, 3);
+}
+
+#[tokio::test]
+async fn test_run_batched_limits_concurrency() {
+    let client = make_client();
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let tasks: Vec<_> = (0..8)
+        .map(|i| {
+            let in_flight = Arc::clone(&in_flight);
+            let peak = Arc::clone(&peak);
+            move || {
+                let in_flight = Arc::clone(&in_flight);
+                let peak = Arc::clone(&peak);
+                async move {
+                    let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(5)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    Ok::<_, Error>(i)
+                }
+            }
+        })
+        .collect();
+
+    let result = client.run_batched(tasks, 3).await;
+
+    assert!(result.is_complete_success());
+    assert_eq!(result.total(), 8);
+    assert!(
+        peak.load(Ordering::SeqCst) <= 3,
+        "peak = {}",
+        peak.load(Ordering::SeqCst)
+    );
+}
+
+#[tokio::test]
+async fn test_run_batched_with_zero_concurrency_runs_tasks_one_at_a_time() {
+    let client = make_client();
+    let tasks: Vec<_> = (0..3)
+        .map(|i| move || async move { Ok::<_, Error>(i) })
+        .collect();
+
+    let result = client.run_batched(tasks, 0).await;
+
+    assert_eq!(result.succeeded, vec![(0, 0), (1, 1), (2, 2)]);
+}
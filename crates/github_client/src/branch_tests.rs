@@ -0,0 +1,455 @@
+// Tests for branch, ref and repository operations (`upsert_branch`,
+// `resolve_ref`, `get_repository_info`, `create_commit_status`).
+// Uses wiremock to provide a local mock GitHub API server so no real credentials are needed.
+
+use super::*;
+use github_bot_sdk::{
+    auth::{
+        AuthenticationProvider, Installation, InstallationId, InstallationPermissions,
+        InstallationToken, JsonWebToken, Repository as SdkRepository,
+    },
+    error::AuthError,
+};
+use release_regent_testing::fixtures::{PushEventBuilder, RepositoryResponseBuilder};
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+// ---------------------------------------------------------------------------
+// Minimal mock auth provider (no real credentials needed)
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+struct MockAuthProvider;
+
+#[async_trait::async_trait]
+impl AuthenticationProvider for MockAuthProvider {
+    async fn app_token(&self) -> Result<JsonWebToken, AuthError> {
+        Err(AuthError::TokenGenerationFailed {
+            message: "not implemented for mock".into(),
+        })
+    }
+
+    async fn installation_token(
+        &self,
+        installation_id: InstallationId,
+    ) -> Result<InstallationToken, AuthError> {
+        let expires_at = chrono::Utc::now() + chrono::Duration::hours(1);
+        Ok(InstallationToken::new(
+            "fake-token".to_string(),
+            installation_id,
+            expires_at,
+            InstallationPermissions::default(),
+            Vec::new(),
+        ))
+    }
+
+    async fn refresh_installation_token(
+        &self,
+        installation_id: InstallationId,
+    ) -> Result<InstallationToken, AuthError> {
+        self.installation_token(installation_id).await
+    }
+
+    async fn list_installations(&self) -> Result<Vec<Installation>, AuthError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_installation_repositories(
+        &self,
+        _installation_id: InstallationId,
+    ) -> Result<Vec<SdkRepository>, AuthError> {
+        Ok(Vec::new())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn make_client(server: &MockServer) -> GitHubClient {
+    GitHubClient::new_for_testing(MockAuthProvider, 1, &server.uri())
+        .expect("test client construction should not fail")
+}
+
+fn ref_json(sha: &str) -> serde_json::Value {
+    serde_json::json!({
+        "ref": "refs/heads/release-regent/next",
+        "node_id": "REF_1",
+        "url": "https://api.github.com/repos/owner/repo/git/refs/heads/release-regent/next",
+        "object": {
+            "sha": sha,
+            "type": "commit",
+            "url": format!("https://api.github.com/repos/owner/repo/git/commits/{sha}")
+        }
+    })
+}
+
+async fn mount_existing_branch(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/git/refs"))
+        .respond_with(
+            ResponseTemplate::new(422)
+                .set_body_json(serde_json::json!({ "message": "Reference already exists" })),
+        )
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+// ---------------------------------------------------------------------------
+// upsert_branch
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_upsert_branch_creates_missing_branch() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/git/refs"))
+        .and(body_json(serde_json::json!({
+            "ref": "refs/heads/release-regent/next",
+            "sha": "abc123"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(ref_json("abc123")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let outcome = make_client(&server)
+        .upsert_branch("owner", "repo", "release-regent/next", "abc123")
+        .await
+        .unwrap();
+
+    assert_eq!(outcome, BranchUpsert::Created);
+}
+
+#[tokio::test]
+async fn test_upsert_branch_fast_forwards_existing_branch() {
+    let server = MockServer::start().await;
+    mount_existing_branch(&server).await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/owner/repo/git/refs/heads/release-regent/next"))
+        .and(body_json(
+            serde_json::json!({ "sha": "def456", "force": false }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ref_json("def456")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let outcome = make_client(&server)
+        .upsert_branch("owner", "repo", "release-regent/next", "def456")
+        .await
+        .unwrap();
+
+    assert_eq!(outcome, BranchUpsert::Updated);
+}
+
+#[tokio::test]
+async fn test_upsert_branch_rejects_non_fast_forward_update() {
+    let server = MockServer::start().await;
+    mount_existing_branch(&server).await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/owner/repo/git/refs/heads/release-regent/next"))
+        .respond_with(
+            ResponseTemplate::new(422)
+                .set_body_json(serde_json::json!({ "message": "Update is not a fast forward" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let err = make_client(&server)
+        .upsert_branch("owner", "repo", "release-regent/next", "def456")
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, CoreError::NotFastForward { ref branch, ref sha, .. }
+            if branch == "release-regent/next" && sha == "def456"),
+        "expected NotFastForward, got {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_upsert_branch_reports_concurrently_deleted_branch_as_conflict() {
+    let server = MockServer::start().await;
+    mount_existing_branch(&server).await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/owner/repo/git/refs/heads/release-regent/next"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(serde_json::json!({ "message": "Not Found" })),
+        )
+        .mount(&server)
+        .await;
+
+    let err = make_client(&server)
+        .upsert_branch("owner", "repo", "release-regent/next", "def456")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, CoreError::Conflict { .. }), "got {err:?}");
+}
+
+// ---------------------------------------------------------------------------
+// resolve_ref
+// ---------------------------------------------------------------------------
+
+fn object_ref_json(git_ref: &str, sha: &str, object_type: &str) -> serde_json::Value {
+    serde_json::json!({
+        "ref": format!("refs/{git_ref}"),
+        "node_id": "REF_1",
+        "url": format!("https://api.github.com/repos/owner/repo/git/refs/{git_ref}"),
+        "object": { "sha": sha, "type": object_type, "url": "https://api.github.com/x" }
+    })
+}
+
+async fn mount_ref(server: &MockServer, git_ref: &str, sha: &str, object_type: &str) {
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/owner/repo/git/ref/{git_ref}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(object_ref_json(
+            git_ref,
+            sha,
+            object_type,
+        )))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_resolve_ref_resolves_branch() {
+    let server = MockServer::start().await;
+    mount_ref(&server, "heads/main", "abc123", "commit").await;
+
+    let sha = make_client(&server)
+        .resolve_ref("owner", "repo", "refs/heads/main")
+        .await
+        .unwrap();
+
+    assert_eq!(sha, "abc123");
+}
+
+#[tokio::test]
+async fn test_resolve_ref_bare_name_falls_back_to_branch() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/git/ref/tags/develop"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(serde_json::json!({ "message": "Not Found" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_ref(&server, "heads/develop", "def456", "commit").await;
+
+    let sha = make_client(&server)
+        .resolve_ref("owner", "repo", "develop")
+        .await
+        .unwrap();
+
+    assert_eq!(sha, "def456");
+}
+
+#[tokio::test]
+async fn test_resolve_ref_resolves_lightweight_tag() {
+    let server = MockServer::start().await;
+    mount_ref(&server, "tags/v1.0.0", "abc123", "commit").await;
+
+    let sha = make_client(&server)
+        .resolve_ref("owner", "repo", "v1.0.0")
+        .await
+        .unwrap();
+
+    assert_eq!(sha, "abc123");
+}
+
+#[tokio::test]
+async fn test_resolve_ref_dereferences_annotated_tag() {
+    let server = MockServer::start().await;
+    mount_ref(&server, "tags/v2.0.0", "tag789", "tag").await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/git/tags/tag789"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "sha": "tag789",
+            "tag": "v2.0.0",
+            "message": "Release v2.0.0",
+            "object": { "sha": "commit321", "type": "commit", "url": "https://api.github.com/x" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let sha = make_client(&server)
+        .resolve_ref("owner", "repo", "tags/v2.0.0")
+        .await
+        .unwrap();
+
+    assert_eq!(sha, "commit321");
+}
+
+#[tokio::test]
+async fn test_resolve_ref_unknown_ref_returns_not_found() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(serde_json::json!({ "message": "Not Found" })),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let err = make_client(&server)
+        .resolve_ref("owner", "repo", "missing")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, CoreError::NotFound { .. }), "got {err:?}");
+}
+
+// ---------------------------------------------------------------------------
+// get_repository_info
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_get_repository_info_parses_topics_language_and_visibility() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(
+                RepositoryResponseBuilder::new()
+                    .with_owner("owner")
+                    .with_name("repo")
+                    .build_json(),
+            ),
+        )
+        .mount(&server)
+        .await;
+
+    let repository = make_client(&server)
+        .get_repository_info("owner", "repo")
+        .await
+        .expect("request should succeed");
+
+    assert_eq!(repository.full_name, "owner/repo");
+    assert_eq!(repository.owner, "owner");
+    assert_eq!(repository.topics, vec!["rust", "automation", "releases"]);
+    assert_eq!(repository.language.as_deref(), Some("Rust"));
+    assert_eq!(repository.visibility, RepositoryVisibility::Public);
+}
+
+#[tokio::test]
+async fn test_get_repository_info_without_visibility_falls_back_to_private_flag() {
+    let mut body = RepositoryResponseBuilder::new()
+        .with_owner("owner")
+        .with_name("repo")
+        .as_private()
+        .build_json();
+    let fields = body.as_object_mut().unwrap();
+    fields.remove("visibility");
+    fields.remove("topics");
+    fields.insert("language".to_string(), serde_json::Value::Null);
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&server)
+        .await;
+
+    let repository = make_client(&server)
+        .get_repository_info("owner", "repo")
+        .await
+        .expect("request should succeed");
+
+    assert_eq!(repository.visibility, RepositoryVisibility::Private);
+    assert!(repository.topics.is_empty());
+    assert_eq!(repository.language, None);
+}
+
+/// Push webhooks embed the same repository object as the REST API.
+#[test]
+fn test_push_webhook_repository_parses_topics_and_language() {
+    let payload = PushEventBuilder::new()
+        .with_repository("owner", "repo")
+        .build();
+
+    let response: RepositoryApiResponse =
+        serde_json::from_value(payload["repository"].clone()).unwrap();
+    let repository = repository_response_to_git_repository(response);
+
+    assert_eq!(repository.topics, vec!["rust", "automation", "releases"]);
+    assert_eq!(repository.language.as_deref(), Some("Rust"));
+    assert_eq!(repository.visibility, RepositoryVisibility::Public);
+}
+
+#[tokio::test]
+async fn test_get_repository_info_missing_repository_is_not_found() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(serde_json::json!({ "message": "Not Found" })),
+        )
+        .mount(&server)
+        .await;
+
+    let err = make_client(&server)
+        .get_repository_info("owner", "repo")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, CoreError::NotFound { .. }), "got {err:?}");
+}
+
+// ---------------------------------------------------------------------------
+// create_commit_status
+// ---------------------------------------------------------------------------
+
+fn version_status_params() -> CreateCommitStatusParams {
+    CreateCommitStatusParams {
+        context: "release-regent/version".to_string(),
+        description: "Next release: v1.3.0 (minor: feat in 1a2b3c4)".to_string(),
+        state: release_regent_core::traits::github_operations::CommitStatusState::Success,
+        target_url: None,
+    }
+}
+
+#[tokio::test]
+async fn test_create_commit_status_posts_state_context_and_description() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/statuses/abc123"))
+        .and(body_json(serde_json::json!({
+            "context": "release-regent/version",
+            "description": "Next release: v1.3.0 (minor: feat in 1a2b3c4)",
+            "state": "success"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({ "id": 1 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    make_client(&server)
+        .create_commit_status("owner", "repo", "abc123", version_status_params())
+        .await
+        .expect("request should succeed");
+}
+
+#[tokio::test]
+async fn test_create_commit_status_unknown_commit_returns_not_found() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/statuses/abc123"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let err = make_client(&server)
+        .create_commit_status("owner", "repo", "abc123", version_status_params())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, CoreError::NotFound { .. }), "got {err:?}");
+}
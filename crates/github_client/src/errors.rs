@@ -36,6 +36,14 @@ pub enum Error {
     #[error("Resource not found: {resource}")]
     NotFound { resource: String },
 
+    /// Resource already exists
+    ///
+    /// Returned when GitHub rejects a create request because a resource with
+    /// the same identity exists (for example a release asset with the same
+    /// name).
+    #[error("Resource already exists: {resource}")]
+    AlreadyExists { resource: String },
+
     /// Invalid input
     #[error("Invalid input: {message}")]
     InvalidInput { message: String },
@@ -44,6 +52,23 @@ pub enum Error {
     #[error("Rate limit exceeded")]
     RateLimit,
 
+    /// Secondary (abuse-detection) rate limit exceeded
+    ///
+    /// `retry_after` carries the `Retry-After` header value in seconds when
+    /// GitHub supplied one.
+    #[error("Secondary rate limit exceeded")]
+    SecondaryRateLimit { retry_after: Option<u64> },
+
+    /// Request timed out
+    ///
+    /// `timeout` is the limit that was exceeded, when known. Timeouts are
+    /// transient and retried by the [`RateLimiter`](crate::RateLimiter).
+    #[error("Request timed out: {operation}")]
+    Timeout {
+        operation: String,
+        timeout: Option<std::time::Duration>,
+    },
+
     /// Other error
     #[error("GitHub client error: {message}")]
     Other {
@@ -55,9 +80,24 @@ pub enum Error {
 
 impl From<github_bot_sdk::error::ApiError> for Error {
     fn from(err: github_bot_sdk::error::ApiError) -> Self {
-        Error::Api {
-            message: err.to_string(),
-            source: Some(Box::new(err)),
+        match err {
+            github_bot_sdk::error::ApiError::SecondaryRateLimit => {
+                Error::SecondaryRateLimit { retry_after: None }
+            }
+            github_bot_sdk::error::ApiError::Timeout => Error::Timeout {
+                operation: "GitHub API request".to_string(),
+                timeout: None,
+            },
+            github_bot_sdk::error::ApiError::HttpClientError(err) if err.is_timeout() => {
+                Error::Timeout {
+                    operation: "GitHub API request".to_string(),
+                    timeout: None,
+                }
+            }
+            err => Error::Api {
+                message: err.to_string(),
+                source: Some(Box::new(err)),
+            },
         }
     }
 }
@@ -74,6 +114,19 @@ impl From<github_bot_sdk::error::AuthError> for Error {
 impl From<Error> for release_regent_core::CoreError {
     fn from(err: Error) -> Self {
         match err {
+            // SDK errors keep the classification applied to direct SDK calls.
+            Error::Api {
+                source: Some(source),
+                ..
+            } if source.is::<github_bot_sdk::error::ApiError>() => {
+                match source.downcast::<github_bot_sdk::error::ApiError>() {
+                    Ok(api_error) => crate::map_sdk_error(*api_error),
+                    Err(source) => release_regent_core::CoreError::GitHub {
+                        source,
+                        context: None,
+                    },
+                }
+            }
             Error::Api { message, source } => release_regent_core::CoreError::GitHub {
                 source: source.unwrap_or_else(|| {
                     Box::new(std::io::Error::new(std::io::ErrorKind::Other, message))
@@ -98,6 +151,7 @@ impl From<Error> for release_regent_core::CoreError {
                 source: Box::new(std::io::Error::new(std::io::ErrorKind::NotFound, resource)),
                 context: None,
             },
+            Error::AlreadyExists { resource } => release_regent_core::CoreError::conflict(resource),
             Error::InvalidInput { message } => release_regent_core::CoreError::InvalidInput {
                 field: "unknown".to_string(),
                 message,
@@ -108,6 +162,16 @@ impl From<Error> for release_regent_core::CoreError {
                 retry_after_seconds: None,
                 context: None,
             },
+            Error::SecondaryRateLimit { retry_after } => {
+                release_regent_core::CoreError::rate_limit_with_retry(
+                    "GitHub secondary rate limit (abuse detection) exceeded",
+                    retry_after.unwrap_or(crate::SECONDARY_RATE_LIMIT_RETRY_SECS),
+                )
+            }
+            Error::Timeout { operation, timeout } => release_regent_core::CoreError::timeout(
+                operation,
+                timeout.map_or(0, |t| u64::try_from(t.as_millis()).unwrap_or(u64::MAX)),
+            ),
             Error::Other { message, source } => release_regent_core::CoreError::GitHub {
                 source: source.unwrap_or_else(|| {
                     Box::new(std::io::Error::new(std::io::ErrorKind::Other, message))
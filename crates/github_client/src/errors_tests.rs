@@ -70,6 +70,44 @@ fn test_error_other_variant() {
     );
 }
 
+#[test]
+fn test_error_timeout_variant() {
+    let error = Error::Timeout {
+        operation: "graphql".to_string(),
+        timeout: Some(std::time::Duration::from_millis(1_500)),
+    };
+
+    assert_eq!(error.to_string(), "Request timed out: graphql");
+}
+
+#[test]
+fn test_sdk_timeout_converts_to_timeout_error() {
+    let error: Error = github_bot_sdk::error::ApiError::Timeout.into();
+
+    assert!(matches!(error, Error::Timeout { timeout: None, .. }));
+}
+
+#[test]
+fn test_error_to_core_error_timeout() {
+    let error = Error::Timeout {
+        operation: "graphql".to_string(),
+        timeout: Some(std::time::Duration::from_millis(1_500)),
+    };
+
+    let core_error: release_regent_core::CoreError = error.into();
+    match core_error {
+        release_regent_core::CoreError::Timeout {
+            operation,
+            duration_ms,
+            ..
+        } => {
+            assert_eq!(operation, "graphql");
+            assert_eq!(duration_ms, 1_500);
+        }
+        other => panic!("Expected Timeout CoreError variant, got {other:?}"),
+    }
+}
+
 #[test]
 fn test_error_to_core_error_api() {
     let error = Error::Api {
@@ -135,3 +173,83 @@ fn test_error_to_core_error_rate_limit() {
         _ => panic!("Expected RateLimit CoreError variant"),
     }
 }
+
+#[test]
+fn test_error_secondary_rate_limit_variant() {
+    let error = Error::SecondaryRateLimit {
+        retry_after: Some(30),
+    };
+    assert_eq!(error.to_string(), "Secondary rate limit exceeded");
+}
+
+#[test]
+fn test_error_from_sdk_secondary_rate_limit() {
+    let error: Error = github_bot_sdk::error::ApiError::SecondaryRateLimit.into();
+
+    assert!(matches!(
+        error,
+        Error::SecondaryRateLimit { retry_after: None }
+    ));
+}
+
+#[test]
+fn test_error_to_core_error_secondary_rate_limit_uses_retry_after() {
+    let error = Error::SecondaryRateLimit {
+        retry_after: Some(120),
+    };
+
+    let core_error: release_regent_core::CoreError = error.into();
+    assert!(core_error.is_retryable());
+    assert_eq!(core_error.retry_delay_seconds(), Some(120));
+}
+
+#[test]
+fn test_error_to_core_error_secondary_rate_limit_defaults_to_sixty_seconds() {
+    let error = Error::SecondaryRateLimit { retry_after: None };
+
+    let core_error: release_regent_core::CoreError = error.into();
+    match core_error {
+        release_regent_core::CoreError::RateLimit {
+            retry_after_seconds,
+            ..
+        } => assert_eq!(retry_after_seconds, Some(60)),
+        other => panic!("Expected RateLimit CoreError variant, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_error_to_core_error_sdk_api_error_keeps_sdk_classification() {
+    let error: Error = github_bot_sdk::error::ApiError::NotFound.into();
+
+    let core_error: release_regent_core::CoreError = error.into();
+    assert!(matches!(
+        core_error,
+        release_regent_core::CoreError::NotFound { .. }
+    ));
+}
+
+#[test]
+fn test_error_already_exists_variant() {
+    let error = Error::AlreadyExists {
+        resource: "release asset 'app.zip'".to_string(),
+    };
+    assert_eq!(
+        error.to_string(),
+        "Resource already exists: release asset 'app.zip'"
+    );
+}
+
+#[test]
+fn test_error_to_core_error_already_exists_is_conflict() {
+    let error = Error::AlreadyExists {
+        resource: "release asset 'app.zip'".to_string(),
+    };
+
+    let core_error: release_regent_core::CoreError = error.into();
+    match core_error {
+        release_regent_core::CoreError::Conflict { resource, .. } => {
+            assert_eq!(resource, "release asset 'app.zip'");
+        }
+        other => panic!("Expected Conflict CoreError variant, got {other:?}"),
+    }
+}
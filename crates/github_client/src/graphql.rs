@@ -0,0 +1,270 @@
+//! GraphQL access to the GitHub API.
+//!
+//! Some lookups need several REST round trips but only one GraphQL query, for
+//! example listing the commits between two refs together with the GitHub
+//! login of each author. [`GitHubClient::graphql`] sends an arbitrary query
+//! through the installation client and [`RateLimiter`](crate::RateLimiter);
+//! the remaining methods in this module are typed queries built on top of it.
+
+use crate::errors::{Error, GitHubResult};
+use crate::{
+    compare_envelope_to_git_commit, CompareCommitDetails, CompareCommitEnvelope,
+    CompareCommitParent, CompareGitHubUser, CompareGitSignature, GitHubClient,
+};
+use release_regent_core::traits::git_operations::GitCommit;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, instrument, warn};
+
+/// Query listing the commits reachable from `head` but not from `base`,
+/// including author and committer logins, one page at a time.
+const COMPARE_COMMITS_QUERY: &str = r"
+query($owner: String!, $repo: String!, $base: String!, $head: String!, $after: String) {
+  repository(owner: $owner, name: $repo) {
+    ref(qualifiedName: $base) {
+      compare(headRef: $head) {
+        commits(first: 100, after: $after) {
+          pageInfo { hasNextPage endCursor }
+          nodes {
+            oid
+            message
+            authoredDate
+            committedDate
+            author { name email user { login } }
+            committer { name email user { login } }
+            parents(first: 10) { nodes { oid } }
+          }
+        }
+      }
+    }
+  }
+}
+";
+
+impl GitHubClient {
+    /// Execute a GraphQL query against the GitHub API.
+    ///
+    /// The request is authenticated as the installation this client is scoped
+    /// to and retried according to the client's [`RetryPolicy`](crate::RetryPolicy).
+    /// Returns the response's `data` object deserialised as `T`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotFound`] when GitHub reports a `NOT_FOUND` error
+    /// - [`Error::SecondaryRateLimit`] when retries are exhausted on a secondary rate limit
+    /// - [`Error::Api`] for GraphQL errors, HTTP failures and responses that do
+    ///   not match `T`
+    #[instrument(skip(self, query, variables))]
+    pub async fn graphql<T, V>(&self, query: &str, variables: V) -> GitHubResult<T>
+    where
+        T: DeserializeOwned,
+        V: Serialize,
+    {
+        let installation = self.installation().await.map_err(|e| Error::Auth {
+            message: format!("failed to get installation client: {e}"),
+            source: Some(Box::new(e)),
+        })?;
+
+        let data = self
+            .rate_limiter
+            .execute_with_timeout("graphql", self.request_timeout, || async {
+                installation
+                    .post_graphql(query, &variables)
+                    .await
+                    .map_err(|e| match e {
+                        github_bot_sdk::error::ApiError::NotFound => Error::NotFound {
+                            resource: "GraphQL query target".to_string(),
+                        },
+                        e => {
+                            warn!(error = %e, "GitHub GraphQL request failed");
+                            Error::from(e)
+                        }
+                    })
+            })
+            .await?;
+
+        serde_json::from_value(data).map_err(|e| Error::Api {
+            message: format!("unexpected GraphQL response shape: {e}"),
+            source: Some(Box::new(e)),
+        })
+    }
+
+    /// List the commits between `base` and `head` with author data using a
+    /// single GraphQL query per 100 commits.
+    ///
+    /// Equivalent to [`GitOperations::get_commits_between`](release_regent_core::traits::git_operations::GitOperations::get_commits_between)
+    /// but also resolves committer logins and is not capped at 250 commits.
+    /// `base` may be a branch or tag name; `head` may be any ref or SHA.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::NotFound`] when the repository or `base` ref does not exist
+    /// - Any error returned by [`graphql`](GitHubClient::graphql)
+    #[instrument(skip(self))]
+    pub async fn get_commits_between_graphql(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+    ) -> GitHubResult<Vec<GitCommit>> {
+        let mut commits = Vec::new();
+        let mut after: Option<String> = None;
+
+        loop {
+            let variables = serde_json::json!({
+                "owner": owner,
+                "repo": repo,
+                "base": base,
+                "head": head,
+                "after": after,
+            });
+            let data: CompareQueryData = self.graphql(COMPARE_COMMITS_QUERY, variables).await?;
+
+            let page = data
+                .repository
+                .and_then(|r| r.base_ref)
+                .and_then(|r| r.compare)
+                .ok_or_else(|| Error::NotFound {
+                    resource: format!("ref '{base}' in {owner}/{repo}"),
+                })?
+                .commits;
+
+            commits.extend(page.nodes.into_iter().map(graphql_commit_to_git_commit));
+
+            match page.page_info.end_cursor {
+                Some(cursor) if page.page_info.has_next_page => after = Some(cursor),
+                _ => break,
+            }
+        }
+
+        debug!(
+            owner,
+            repo,
+            base,
+            head,
+            count = commits.len(),
+            "get_commits_between_graphql complete"
+        );
+        Ok(commits)
+    }
+}
+
+// ── Compare query response types ────────────────────────────────────────────
+
+#[derive(Deserialize)]
+struct CompareQueryData {
+    repository: Option<CompareQueryRepository>,
+}
+
+#[derive(Deserialize)]
+struct CompareQueryRepository {
+    /// `null` when the base ref does not exist.
+    #[serde(rename = "ref")]
+    base_ref: Option<CompareQueryRef>,
+}
+
+#[derive(Deserialize)]
+struct CompareQueryRef {
+    compare: Option<CompareQueryComparison>,
+}
+
+#[derive(Deserialize)]
+struct CompareQueryComparison {
+    commits: CompareQueryCommits,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CompareQueryCommits {
+    page_info: PageInfo,
+    nodes: Vec<GraphQlCommit>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphQlCommit {
+    oid: String,
+    message: String,
+    authored_date: chrono::DateTime<chrono::Utc>,
+    committed_date: chrono::DateTime<chrono::Utc>,
+    author: Option<GraphQlGitActor>,
+    committer: Option<GraphQlGitActor>,
+    parents: GraphQlParents,
+}
+
+/// Git identity of an author or committer. `user` is `null` when the email
+/// does not match a GitHub account.
+#[derive(Deserialize)]
+struct GraphQlGitActor {
+    name: Option<String>,
+    email: Option<String>,
+    user: Option<CompareGitHubUser>,
+}
+
+#[derive(Deserialize)]
+struct GraphQlParents {
+    nodes: Vec<GraphQlParent>,
+}
+
+#[derive(Deserialize)]
+struct GraphQlParent {
+    oid: String,
+}
+
+/// Convert a GraphQL commit node into the core `GitCommit` type, reusing the
+/// REST compare conversion so both paths split messages identically.
+fn graphql_commit_to_git_commit(commit: GraphQlCommit) -> GitCommit {
+    let (author_name, author_email, author_user) = split_actor(commit.author);
+    let (committer_name, committer_email, committer_user) = split_actor(commit.committer);
+
+    compare_envelope_to_git_commit(CompareCommitEnvelope {
+        sha: commit.oid,
+        commit: CompareCommitDetails {
+            message: commit.message,
+            author: CompareGitSignature {
+                name: author_name,
+                email: author_email,
+                date: commit.authored_date,
+            },
+            committer: CompareGitSignature {
+                name: committer_name,
+                email: committer_email,
+                date: commit.committed_date,
+            },
+        },
+        author: author_user,
+        committer: committer_user,
+        parents: commit
+            .parents
+            .nodes
+            .into_iter()
+            .map(|p| CompareCommitParent { sha: p.oid })
+            .collect(),
+        files: Vec::new(),
+    })
+}
+
+fn split_actor(actor: Option<GraphQlGitActor>) -> (String, String, Option<CompareGitHubUser>) {
+    actor.map_or_else(
+        || (String::new(), String::new(), None),
+        |a| {
+            (
+                a.name.unwrap_or_default(),
+                a.email.unwrap_or_default(),
+                a.user,
+            )
+        },
+    )
+}
+
+#[cfg(test)]
+#[path = "graphql_tests.rs"]
+mod tests;
@@ -0,0 +1,389 @@
+// Tests for the GraphQL helper, the GraphQL commit comparison query and single
+// commit retrieval.
+// Uses wiremock to provide a local mock GitHub GraphQL endpoint.
+
+use super::*;
+use crate::RetryPolicy;
+use github_bot_sdk::{
+    auth::{
+        AuthenticationProvider, Installation, InstallationId, InstallationPermissions,
+        InstallationToken, JsonWebToken, Repository as SdkRepository,
+    },
+    error::AuthError,
+};
+use std::time::Duration;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const COMPARE_FIXTURE: &str = include_str!("../fixtures/graphql_compare_commits.json");
+
+// ---------------------------------------------------------------------------
+// Minimal mock auth provider (no real credentials needed)
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+struct MockAuthProvider;
+
+#[async_trait::async_trait]
+impl AuthenticationProvider for MockAuthProvider {
+    async fn app_token(&self) -> Result<JsonWebToken, AuthError> {
+        Err(AuthError::TokenGenerationFailed {
+            message: "not implemented for mock".into(),
+        })
+    }
+
+    async fn installation_token(
+        &self,
+        installation_id: InstallationId,
+    ) -> Result<InstallationToken, AuthError> {
+        let expires_at = chrono::Utc::now() + chrono::Duration::hours(1);
+        Ok(InstallationToken::new(
+            "fake-token".to_string(),
+            installation_id,
+            expires_at,
+            InstallationPermissions::default(),
+            Vec::new(),
+        ))
+    }
+
+    async fn refresh_installation_token(
+        &self,
+        installation_id: InstallationId,
+    ) -> Result<InstallationToken, AuthError> {
+        self.installation_token(installation_id).await
+    }
+
+    async fn list_installations(&self) -> Result<Vec<Installation>, AuthError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_installation_repositories(
+        &self,
+        _installation_id: InstallationId,
+    ) -> Result<Vec<SdkRepository>, AuthError> {
+        Ok(Vec::new())
+    }
+}
+
+fn make_client(server: &MockServer) -> GitHubClient {
+    GitHubClient::new_for_testing(MockAuthProvider, 1, &server.uri())
+        .expect("test client construction should not fail")
+}
+
+fn fixture() -> serde_json::Value {
+    serde_json::from_str(COMPARE_FIXTURE).expect("fixture should be valid JSON")
+}
+
+// ---------------------------------------------------------------------------
+// graphql
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_graphql_returns_deserialised_data() {
+    #[derive(serde::Deserialize)]
+    struct Viewer {
+        login: String,
+    }
+    #[derive(serde::Deserialize)]
+    struct Data {
+        viewer: Viewer,
+    }
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(serde_json::json!({
+            "variables": { "first": 1 }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": { "viewer": { "login": "release-regent[bot]" } }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let data: Data = make_client(&server)
+        .graphql(
+            "query { viewer { login } }",
+            serde_json::json!({ "first": 1 }),
+        )
+        .await
+        .expect("query should succeed");
+
+    assert_eq!(data.viewer.login, "release-regent[bot]");
+}
+
+#[tokio::test]
+async fn test_graphql_slow_endpoint_fails_with_retryable_timeout() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "data": {} }))
+                .set_delay(Duration::from_secs(2)),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = make_client(&server)
+        .with_retry_policy(RetryPolicy {
+            max_retries: 1,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        })
+        .with_request_timeout(Duration::from_millis(100));
+
+    let error = client
+        .graphql::<serde_json::Value, _>("query { viewer { login } }", serde_json::json!({}))
+        .await
+        .expect_err("slow response should time out");
+
+    assert!(
+        matches!(
+            error,
+            Error::Timeout {
+                timeout: Some(t),
+                ..
+            } if t == Duration::from_millis(100)
+        ),
+        "expected timeout error, got {error:?}"
+    );
+    assert!(client.rate_limiter().should_retry_error(&error));
+    assert_eq!(client.rate_limiter().metrics_snapshot().retries, 1);
+}
+
+#[tokio::test]
+async fn test_graphql_errors_array_maps_to_api_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "errors": [{ "message": "Field 'nope' doesn't exist on type 'Query'" }]
+        })))
+        .mount(&server)
+        .await;
+
+    let result: GitHubResult<serde_json::Value> = make_client(&server)
+        .graphql("query { nope }", serde_json::json!({}))
+        .await;
+
+    match result {
+        Err(Error::Api { message, .. }) => assert!(message.contains("nope")),
+        other => panic!("expected Api error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_graphql_not_found_error_maps_to_not_found() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": { "repository": null },
+            "errors": [{ "type": "NOT_FOUND", "message": "Could not resolve to a Repository" }]
+        })))
+        .mount(&server)
+        .await;
+
+    let result: GitHubResult<serde_json::Value> = make_client(&server)
+        .graphql("query { repository(owner: \"o\", name: \"r\") { id } }", ())
+        .await;
+
+    assert!(matches!(result, Err(Error::NotFound { .. })));
+}
+
+#[tokio::test]
+async fn test_graphql_retries_secondary_rate_limit_through_rate_limiter() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+            "message": "You have exceeded a secondary rate limit."
+        })))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "data": { "ok": true } })),
+        )
+        .mount(&server)
+        .await;
+
+    let client = make_client(&server).with_retry_policy(RetryPolicy {
+        max_retries: 1,
+        secondary_rate_limit_base_delay: Duration::from_millis(1),
+        secondary_rate_limit_max_delay: Duration::from_millis(1),
+        ..RetryPolicy::default()
+    });
+    let data: serde_json::Value = client
+        .graphql("query { ok }", ())
+        .await
+        .expect("query should succeed after retry");
+
+    assert_eq!(data["ok"], true);
+    assert_eq!(client.rate_limiter().metrics_snapshot().retries, 1);
+}
+
+// ---------------------------------------------------------------------------
+// get_commits_between_graphql
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_get_commits_between_graphql_maps_fixture_commits() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(serde_json::json!({
+            "variables": { "owner": "owner", "repo": "repo", "base": "v1.0.0", "head": "main" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let commits = make_client(&server)
+        .get_commits_between_graphql("owner", "repo", "v1.0.0", "main")
+        .await
+        .expect("query should succeed");
+
+    assert_eq!(commits.len(), 2);
+
+    let first = &commits[0];
+    assert_eq!(first.sha, "1111111111111111111111111111111111111111");
+    assert_eq!(first.subject, "feat(api): add release assets");
+    assert_eq!(
+        first.body.as_deref(),
+        Some("Uploads binaries to the release.")
+    );
+    assert_eq!(first.author.login.as_deref(), Some("octocat"));
+    assert_eq!(first.author.email, "octocat@example.com");
+    assert_eq!(first.committer.login.as_deref(), Some("web-flow"));
+    assert_eq!(
+        first.author_date.to_rfc3339(),
+        "2024-03-01T09:00:00+00:00",
+        "offsets should be normalised to UTC"
+    );
+    assert_eq!(
+        first.parents,
+        vec!["0000000000000000000000000000000000000000".to_string()]
+    );
+
+    let second = &commits[1];
+    assert_eq!(second.author.login, None);
+    assert_eq!(second.author.name, "External Contributor");
+    assert!(second.body.is_none());
+}
+
+#[tokio::test]
+async fn test_get_commits_between_graphql_follows_cursor() {
+    let server = MockServer::start().await;
+    let mut first_page = fixture();
+    first_page["data"]["repository"]["ref"]["compare"]["commits"]["pageInfo"] =
+        serde_json::json!({ "hasNextPage": true, "endCursor": "page-2" });
+    first_page["data"]["repository"]["ref"]["compare"]["commits"]["nodes"]
+        .as_array_mut()
+        .unwrap()
+        .truncate(1);
+    let mut second_page = fixture();
+    second_page["data"]["repository"]["ref"]["compare"]["commits"]["nodes"]
+        .as_array_mut()
+        .unwrap()
+        .remove(0);
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(serde_json::json!({
+            "variables": { "after": "page-2" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(second_page))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(serde_json::json!({
+            "variables": { "after": null }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(first_page))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let commits = make_client(&server)
+        .get_commits_between_graphql("owner", "repo", "v1.0.0", "main")
+        .await
+        .expect("query should succeed");
+
+    let shas: Vec<&str> = commits.iter().map(|c| &c.sha[..1]).collect();
+    assert_eq!(shas, vec!["1", "2"]);
+}
+
+#[tokio::test]
+async fn test_get_commits_between_graphql_missing_base_ref_returns_not_found() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": { "repository": { "ref": null } }
+        })))
+        .mount(&server)
+        .await;
+
+    let result = make_client(&server)
+        .get_commits_between_graphql("owner", "repo", "v9.9.9", "main")
+        .await;
+
+    match result {
+        Err(Error::NotFound { resource }) => assert!(resource.contains("v9.9.9")),
+        other => panic!("expected NotFound, got {other:?}"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// get_commit (REST)
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_get_commit_includes_changed_files() {
+    use release_regent_core::traits::GitOperations;
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/commits/abc123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "sha": "abc123",
+            "commit": {
+                "message": "feat(core): add parser\n\nDetails.",
+                "author": { "name": "Dev", "email": "dev@example.com", "date": "2024-01-01T00:00:00Z" },
+                "committer": { "name": "Dev", "email": "dev@example.com", "date": "2024-01-01T00:00:00Z" },
+                "comment_count": 0
+            },
+            "author": { "login": "dev" },
+            "committer": null,
+            "parents": [{ "sha": "parent1" }],
+            "files": [
+                { "filename": "crates/core/src/parser.rs", "status": "added" },
+                { "filename": "README.md", "status": "modified" }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let commit = make_client(&server)
+        .get_commit("owner", "repo", "abc123")
+        .await
+        .expect("request should succeed");
+
+    assert_eq!(commit.subject, "feat(core): add parser");
+    assert_eq!(commit.body.as_deref(), Some("Details."));
+    assert_eq!(commit.author.login.as_deref(), Some("dev"));
+    assert_eq!(commit.parents, vec!["parent1"]);
+    assert_eq!(commit.files, vec!["crates/core/src/parser.rs", "README.md"]);
+}
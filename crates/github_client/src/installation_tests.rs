@@ -1,4 +1,5 @@
-// Tests for `get_installation_id_for_repo` HTTP status mapping.
+// Tests for `get_repository_installation` HTTP status mapping and
+// `list_installations` pagination.
 //
 // Uses wiremock to provide a local mock GitHub API server so no real
 // credentials are needed.  The key correctness property is that a 401
@@ -15,7 +16,7 @@ use github_bot_sdk::{
 };
 use release_regent_core::CoreError;
 use wiremock::{
-    matchers::{method, path},
+    matchers::{method, path, query_param},
     Mock, MockServer, ResponseTemplate,
 };
 
@@ -86,6 +87,64 @@ fn make_app_client(mock_server: &MockServer) -> GitHubClient {
         .expect("test client construction should not fail")
 }
 
+/// JSON for an installation on the account `login`.
+fn installation_json(id: u64, login: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "account": {
+            "id": id + 1000,
+            "login": login,
+            "type": "Organization",
+            "avatar_url": null,
+            "html_url": format!("https://github.com/{login}")
+        },
+        "access_tokens_url": format!("https://api.github.com/app/installations/{id}/access_tokens"),
+        "repositories_url": "https://api.github.com/installation/repositories",
+        "html_url": format!("https://github.com/organizations/{login}/settings/installations/{id}"),
+        "app_id": 1,
+        "target_type": "Organization",
+        "repository_selection": "all",
+        "permissions": {},
+        "events": [],
+        "created_at": "2024-01-01T00:00:00Z",
+        "updated_at": "2024-01-01T00:00:00Z",
+        "suspended_at": null,
+        "suspended_by": null
+    })
+}
+
+/// Mount a two-page `/app/installations` listing: ids 1-2 on page 1 and
+/// id 3 on page 2.
+async fn mount_two_installation_pages(server: &MockServer) {
+    let next = format!(
+        "<{}/app/installations?per_page=100&page=2>; rel=\"next\", <{}/app/installations?per_page=100&page=2>; rel=\"last\"",
+        server.uri(),
+        server.uri()
+    );
+    Mock::given(method("GET"))
+        .and(path("/app/installations"))
+        .and(query_param("page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("link", next.as_str())
+                .set_body_json(serde_json::json!([
+                    installation_json(1, "acme"),
+                    installation_json(2, "other-org"),
+                ])),
+        )
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/app/installations"))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([installation_json(3, "Acme")])),
+        )
+        .mount(server)
+        .await;
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -107,6 +166,26 @@ async fn test_get_installation_id_success_returns_id() {
     assert_eq!(result.unwrap(), 42);
 }
 
+/// The inherent lookup returns the installation ID of an installed app.
+#[tokio::test]
+async fn test_get_repository_installation_returns_id() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/installation"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": 7 })))
+        .mount(&server)
+        .await;
+
+    let client = make_app_client(&server);
+    let id = client
+        .get_repository_installation("owner", "repo")
+        .await
+        .unwrap();
+
+    assert_eq!(id, 7);
+}
+
 /// A 401 response must map to `CoreError::Network` and be retryable.
 ///
 /// This is the inverse of `map_sdk_error`'s treatment of a general 401
@@ -140,9 +219,10 @@ async fn test_get_installation_id_401_returns_retryable_network_error() {
     );
 }
 
-/// A 404 response means the app is not installed — non-retryable `CoreError::NotFound`.
+/// A 404 response means the app is not installed — non-retryable
+/// `CoreError::AppNotInstalled` naming the repository.
 #[tokio::test]
-async fn test_get_installation_id_404_returns_not_found_non_retryable() {
+async fn test_get_installation_id_404_returns_app_not_installed_non_retryable() {
     let server = MockServer::start().await;
 
     Mock::given(method("GET"))
@@ -158,8 +238,12 @@ async fn test_get_installation_id_404_returns_not_found_non_retryable() {
         .unwrap_err();
 
     assert!(
-        matches!(err, CoreError::NotFound { .. }),
-        "404 should produce CoreError::NotFound, got: {:?}",
+        matches!(
+            err,
+            CoreError::AppNotInstalled { ref owner, ref repo, .. }
+                if owner == "owner" && repo == "repo"
+        ),
+        "404 should produce CoreError::AppNotInstalled, got: {:?}",
         err
     );
     assert!(
@@ -220,3 +304,86 @@ async fn test_get_installation_id_403_returns_github_error_non_retryable() {
         "403 (forbidden) should not be retryable"
     );
 }
+
+/// A 403 carrying GitHub's secondary rate limit message must surface as a
+/// retryable `CoreError::RateLimit` honouring the `Retry-After` header, not as
+/// the permanent error used for other 403 responses.
+#[tokio::test]
+async fn test_get_installation_id_secondary_rate_limit_returns_retryable_rate_limit() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/installation"))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .insert_header("retry-after", "90")
+                .set_body_json(serde_json::json!({
+                    "message": "You have exceeded a secondary rate limit. Please wait a few minutes before you try again."
+                })),
+        )
+        .mount(&server)
+        .await;
+
+    let client = make_app_client(&server);
+    let err = client
+        .get_installation_id_for_repo("owner", "repo")
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, CoreError::RateLimit { .. }),
+        "secondary rate limit should produce CoreError::RateLimit, got: {:?}",
+        err
+    );
+    assert!(err.is_retryable());
+    assert_eq!(err.retry_delay_seconds(), Some(90));
+}
+
+/// Every page of installations should be collected.
+#[tokio::test]
+async fn test_list_installations_follows_pagination() {
+    let server = MockServer::start().await;
+    mount_two_installation_pages(&server).await;
+
+    let client = make_app_client(&server);
+    let installations = client.list_installations(None).await.unwrap();
+
+    let ids: Vec<u64> = installations.iter().map(|i| i.id.as_u64()).collect();
+    assert_eq!(ids, vec![1, 2, 3]);
+}
+
+/// The account filter should keep only matching installations, ignoring case.
+#[tokio::test]
+async fn test_list_installations_filters_by_account_login() {
+    let server = MockServer::start().await;
+    mount_two_installation_pages(&server).await;
+
+    let client = make_app_client(&server);
+    let installations = client.list_installations(Some("ACME")).await.unwrap();
+
+    let ids: Vec<u64> = installations.iter().map(|i| i.id.as_u64()).collect();
+    assert_eq!(ids, vec![1, 3]);
+}
+
+/// A failed page should surface as an error rather than a partial list.
+#[tokio::test]
+async fn test_list_installations_error_status_returns_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/app/installations"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(serde_json::json!({ "message": "Not Found" })),
+        )
+        .mount(&server)
+        .await;
+
+    let client = make_app_client(&server);
+    let err = client.list_installations(None).await.unwrap_err();
+
+    assert!(
+        matches!(err, CoreError::NotFound { .. }),
+        "expected NotFound, got: {:?}",
+        err
+    );
+}
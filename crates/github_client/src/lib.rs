@@ -6,9 +6,7 @@
 use async_trait::async_trait;
 use base64::Engine as _;
 use github_bot_sdk::{
-    auth::{
-        cache::InMemoryTokenCache, tokens::GitHubAppAuth, AuthenticationProvider, InstallationId,
-    },
+    auth::{tokens::GitHubAppAuth, AuthenticationProvider, InstallationId},
     client::{
         ClientConfig, CreatePullRequestRequest, CreateReleaseRequest, GitHubClient as SdkClient,
         InstallationClient, UpdatePullRequestRequest, UpdateReleaseRequest,
@@ -19,16 +17,18 @@ use release_regent_core::{
     traits::{
         git_operations::{
             GetCommitsOptions, GitCommit, GitOperations, GitRepository, GitTag, GitTagType,
-            GitUser as GitOpsUser, ListTagsOptions, TagSortOrder,
+            GitUser as GitOpsUser, ListTagsOptions, RepositoryVisibility, TagSortOrder,
         },
         github_operations::{
-            CollaboratorPermission, CreatePullRequestParams, CreateReleaseParams, FileUpdate,
-            GitHubOperations, GitUser as GitHubUser, IssueComment, Label, PullRequest,
-            PullRequestBranch, Release, Repository, Tag, UpdateReleaseParams,
+            ref_candidates, BranchUpsert, CollaboratorPermission, CreateCommitStatusParams,
+            CreatePullRequestParams, CreateReleaseParams, FileUpdate, GitHubOperations,
+            GitUser as GitHubUser, IssueComment, Label, LabelMode, PullRequest, PullRequestBranch,
+            Release, Repository, SignedTagParams, Tag, UpdateReleaseParams,
         },
     },
     CoreError, CoreResult,
 };
+use std::sync::Arc;
 use std::time::Duration as StdDuration;
 use tracing::{debug, info, instrument, warn};
 
@@ -45,19 +45,52 @@ pub(crate) const MAX_RETRIES: u32 = 5;
 ///
 /// GitHub recommends waiting at least 60 s before retrying after a secondary
 /// rate limit response.
-const SECONDARY_RATE_LIMIT_RETRY_SECS: u64 = 60;
+pub(crate) const SECONDARY_RATE_LIMIT_RETRY_SECS: u64 = 60;
+
+/// Timeout for release asset uploads.
+///
+/// Uploads can be much larger than ordinary API requests, so they use their
+/// own HTTP client with a longer timeout than the SDK's 30 s default.
+const ASSET_UPLOAD_TIMEOUT: StdDuration = StdDuration::from_secs(10 * 60);
+
+/// Number of commits `list_commits` returns when no limit is given.
+const DEFAULT_HISTORY_LIMIT: usize = 1000;
+
+/// Maximum number of annotated tags `resolve_ref` follows to reach a commit.
+const MAX_TAG_PEEL_DEPTH: usize = 8;
 
 pub mod auth;
-pub use auth::{AuthConfig, EnvSecretProvider};
+pub use auth::{
+    AuthConfig, CacheCleanupGuard, DefaultGitHubApiClient, EnvSecretProvider, JwtValidator,
+    SharedTokenCache, TokenRefreshCallback, DEFAULT_JWT_EXPIRATION_SECS,
+    DEFAULT_REQUEST_TIMEOUT_SECS,
+};
+
+pub mod batch;
+pub use batch::BatchResult;
+
+pub mod graphql;
+
+pub mod rate_limiter;
+pub use rate_limiter::{RateLimitInfo, RateLimitMetrics, RateLimiter, RetryPolicy};
 
 // Re-export SDK types for convenience
-pub use github_bot_sdk::auth::{GitHubAppId, InstallationId as SdkInstallationId, PrivateKey};
+pub use github_bot_sdk::auth::{
+    GitHubAppId, Installation, InstallationId as SdkInstallationId, PrivateKey,
+};
 
 /// GitHub client that implements Release Regent's trait interfaces using github-bot-sdk
 #[derive(Clone)]
 pub struct GitHubClient {
     sdk_client: SdkClient,
     installation_id: InstallationId,
+    rate_limiter: Arc<RateLimiter>,
+    upload_client: reqwest::Client,
+    /// Token cache used by the SDK, when it was built by this crate
+    token_cache: Option<SharedTokenCache>,
+    /// Per-attempt limit for requests sent through the rate limiter; see
+    /// [`with_request_timeout`](GitHubClient::with_request_timeout)
+    request_timeout: Option<StdDuration>,
 }
 
 impl GitHubClient {
@@ -76,12 +109,34 @@ impl GitHubClient {
     pub fn new(
         auth_provider: impl AuthenticationProvider + 'static,
         installation_id: u64,
+    ) -> CoreResult<Self> {
+        Self::new_with_timeout(
+            auth_provider,
+            installation_id,
+            StdDuration::from_secs(auth::DEFAULT_REQUEST_TIMEOUT_SECS),
+        )
+    }
+
+    /// Create a new GitHub client whose HTTP requests time out after `timeout`.
+    ///
+    /// Behaves like [`new`](GitHubClient::new) otherwise. A request that
+    /// times out fails with a retryable timeout error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::GitHub`] if the underlying SDK client cannot be built.
+    #[allow(clippy::result_large_err)]
+    pub fn new_with_timeout(
+        auth_provider: impl AuthenticationProvider + 'static,
+        installation_id: u64,
+        timeout: StdDuration,
     ) -> CoreResult<Self> {
         let config = ClientConfig::default()
             .with_user_agent("release-regent/0.1.0")
-            .with_timeout(StdDuration::from_secs(30))
+            .with_timeout(timeout)
             .with_max_retries(MAX_RETRIES);
 
+        let upload_client = build_upload_client(&config.user_agent)?;
         let sdk_client = SdkClient::builder(auth_provider)
             .config(config)
             .build()
@@ -93,6 +148,10 @@ impl GitHubClient {
         Ok(Self {
             sdk_client,
             installation_id: InstallationId::new(installation_id),
+            rate_limiter: Arc::new(RateLimiter::default()),
+            upload_client,
+            token_cache: None,
+            request_timeout: None,
         })
     }
 
@@ -106,7 +165,8 @@ impl GitHubClient {
     /// - [`auth::EnvSecretProvider`] for secret retrieval
     /// - [`auth::DefaultJwtSigner`] for RS256 JWT signing
     /// - [`auth::DefaultGitHubApiClient`] for installation token exchange
-    /// - An in-memory token cache
+    /// - A [`SharedTokenCache`]; see
+    ///   [`start_cache_cleanup`](GitHubClient::start_cache_cleanup)
     ///
     /// # Errors
     ///
@@ -114,6 +174,34 @@ impl GitHubClient {
     /// if the underlying SDK client cannot be initialised.
     #[allow(clippy::result_large_err)]
     pub fn from_config(auth_config: AuthConfig) -> CoreResult<Self> {
+        Self::from_config_with_api_client(auth_config, auth::DefaultGitHubApiClient::new())
+    }
+
+    /// Create a new GitHub client from [`AuthConfig`] using a customised
+    /// token-exchange client.
+    ///
+    /// Behaves like [`from_config`](GitHubClient::from_config) but lets the
+    /// caller configure the [`auth::DefaultGitHubApiClient`], for example to
+    /// register a token refresh callback:
+    ///
+    /// ```rust,ignore
+    /// let api_client = DefaultGitHubApiClient::new().on_token_refresh(|id, expires_at| {
+    ///     tracing::info!(installation_id = id, %expires_at, "installation token refreshed");
+    /// });
+    /// let client = GitHubClient::from_config_with_api_client(auth_config, api_client)?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the private key in `auth_config` is malformed or
+    /// if the underlying SDK client cannot be initialised.
+    #[allow(clippy::result_large_err)]
+    pub fn from_config_with_api_client(
+        auth_config: AuthConfig,
+        api_client: auth::DefaultGitHubApiClient,
+    ) -> CoreResult<Self> {
+        let auth_config_sdk = auth_config.sdk_auth_config();
+        let request_timeout = auth_config.request_timeout();
         let secret_provider =
             auth::EnvSecretProvider::new(auth_config).map_err(|e| CoreError::GitHub {
                 source: Box::new(e),
@@ -121,21 +209,37 @@ impl GitHubClient {
             })?;
 
         let jwt_signer = auth::DefaultJwtSigner::new();
-        let api_client = auth::DefaultGitHubApiClient::new();
-        let token_cache = InMemoryTokenCache::default();
-        let auth_config_sdk = github_bot_sdk::auth::tokens::AuthConfig::default();
+        let token_cache = SharedTokenCache::new();
 
         let auth_provider = GitHubAppAuth::new(
             secret_provider,
             jwt_signer,
             api_client,
-            token_cache,
+            token_cache.clone(),
             auth_config_sdk,
         );
 
         // Installation ID 0 is a placeholder; the real ID is supplied per-request
         // via `scoped_to()` after extracting it from the webhook payload.
-        Self::new(auth_provider, 0)
+        let mut client = Self::new_with_timeout(auth_provider, 0, request_timeout)?;
+        client.token_cache = Some(token_cache);
+        Ok(client)
+    }
+
+    /// Periodically remove expired tokens from this client's token cache.
+    ///
+    /// Expired JWTs and installation tokens otherwise stay cached until they
+    /// are replaced, so a long-running server should call this once at
+    /// startup and keep the guard alive.  The task stops when the guard is
+    /// dropped.
+    ///
+    /// Returns `None` for clients built with [`new`](GitHubClient::new),
+    /// whose token cache belongs to the caller's authentication provider.
+    #[must_use = "the cleanup task stops when the guard is dropped"]
+    pub fn start_cache_cleanup(&self, interval: StdDuration) -> Option<CacheCleanupGuard> {
+        self.token_cache
+            .as_ref()
+            .map(|cache| cache.start_cleanup(interval))
     }
 
     /// Get the SDK client for direct access if needed
@@ -144,11 +248,237 @@ impl GitHubClient {
         &self.sdk_client
     }
 
+    /// Replace the retry policy used for requests that bypass the SDK's own
+    /// retry handling (see [`rate_limiter`]).
+    #[must_use]
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.rate_limiter = Arc::new(RateLimiter::new(policy));
+        self
+    }
+
+    /// Return a copy of this client that gives up on each request attempt
+    /// after `timeout`.
+    ///
+    /// Use this to bound an individual call more tightly than the timeout the
+    /// client was built with, e.g.
+    /// `client.with_request_timeout(Duration::from_secs(5)).graphql(...)`.
+    /// The override applies to requests this crate retries itself (GraphQL,
+    /// app-level lookups, asset uploads and [`run_batched`](GitHubClient::run_batched)
+    /// tasks); an attempt that exceeds it fails with [`Error::Timeout`] and
+    /// is retried according to the [`RetryPolicy`].
+    #[must_use]
+    pub fn with_request_timeout(&self, timeout: StdDuration) -> Self {
+        Self {
+            request_timeout: Some(timeout),
+            ..self.clone()
+        }
+    }
+
+    /// Get the rate limiter shared by this client and its scoped copies.
+    #[must_use]
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// Find the installation ID of the GitHub App for `owner/repo`.
+    ///
+    /// Calls `GET /repos/{owner}/{repo}/installation` with app-level JWT
+    /// authentication.  The returned ID can be passed to
+    /// [`GitHubOperations::scoped_to`] to act as that installation.
+    ///
+    /// # Errors
+    ///
+    /// - [`CoreError::AppNotInstalled`] — the App is not installed on the
+    ///   repository (HTTP 404).  Not retryable.
+    /// - [`CoreError::Network`] — the JWT was transiently rejected (HTTP 401),
+    ///   GitHub returned a server error, or the request failed.
+    /// - [`CoreError::RateLimit`] — GitHub kept rate limiting the request.
+    /// - [`CoreError::GitHub`] — any other failure status, or a response
+    ///   without an `id`.
+    #[instrument(skip(self))]
+    pub async fn get_repository_installation(&self, owner: &str, repo: &str) -> CoreResult<u64> {
+        info!(owner, repo, "Looking up installation ID for repository");
+        let path = format!("/repos/{owner}/{repo}/installation");
+
+        // App-level requests bypass the SDK's retry loop, so rate-limit
+        // responses (including secondary limits) are detected and retried here.
+        let (status, body) = self
+            .rate_limiter
+            .execute_with_timeout(
+                "get_installation_id_for_repo",
+                self.request_timeout,
+                || async {
+                    let response = self.sdk_client.get_as_app(&path).await?;
+                    let status = response.status();
+                    let headers = response.headers().clone();
+                    self.rate_limiter.record_response_headers(&headers);
+                    let body = response.text().await.map_err(|e| Error::Network {
+                        message: format!("failed to read installation lookup response: {e}"),
+                        source: Some(Box::new(e)),
+                    })?;
+                    match rate_limiter::detect_rate_limit(status.as_u16(), &headers, &body) {
+                        Some(error) => Err(error),
+                        None => Ok((status, body)),
+                    }
+                },
+            )
+            .await?;
+
+        if !status.is_success() {
+            let code = status.as_u16();
+            return Err(match code {
+                // A 401 from the installation endpoint means the JWT was
+                // rejected by GitHub.  This is treated as a transient auth
+                // token failure (clock skew, brief GitHub auth service hiccup)
+                // per the error-handling spec; the event will be retried and a
+                // fresh JWT will be used on the next attempt.
+                401 => {
+                    warn!(
+                        owner,
+                        repo,
+                        status = code,
+                        "Installation lookup returned 401 — transient JWT \
+                         rejection; event will be retried"
+                    );
+                    CoreError::network(format!(
+                        "installation lookup for {owner}/{repo} failed with 401 \
+                         (transient auth token rejection): {body}"
+                    ))
+                }
+                // 404 means the GitHub App is not installed on this repository.
+                // This is a permanent configuration error, not a transient one.
+                404 => {
+                    warn!(
+                        owner,
+                        repo,
+                        status = code,
+                        "Installation lookup returned 404 — app is not \
+                         installed on this repository"
+                    );
+                    CoreError::app_not_installed(owner, repo)
+                }
+                // 5xx responses are server-side errors — transient and retryable.
+                s if s >= 500 => {
+                    warn!(
+                        owner,
+                        repo,
+                        status = code,
+                        "Installation lookup returned server error — will retry"
+                    );
+                    CoreError::network(format!(
+                        "installation lookup for {owner}/{repo} failed with \
+                         server error {s}: {body}"
+                    ))
+                }
+                // All other failures (403, other 4xx) are permanent.
+                _ => CoreError::GitHub {
+                    source: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!(
+                            "installation lookup for {owner}/{repo} failed with \
+                             status {status}: {body}"
+                        ),
+                    )),
+                    context: None,
+                },
+            });
+        }
+
+        let body: serde_json::Value = serde_json::from_str(&body).map_err(CoreError::github)?;
+        body["id"].as_u64().ok_or_else(|| CoreError::GitHub {
+            source: Box::new(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("installation lookup for {owner}/{repo}: response missing 'id' field"),
+            )),
+            context: None,
+        })
+    }
+
+    /// List every installation of the GitHub App.
+    ///
+    /// Follows the `Link` header of `/app/installations` until the last page,
+    /// so apps installed on many accounts see all of them.  When
+    /// `account_login` is set, only installations on that account are
+    /// returned; logins are compared case-insensitively.
+    ///
+    /// # Errors
+    ///
+    /// - [`CoreError::RateLimit`] — GitHub kept rate limiting the request
+    ///   after all retries.
+    /// - [`CoreError::Authentication`] — the app JWT was rejected.
+    /// - [`CoreError::Network`] — the request failed or a page could not be
+    ///   parsed.
+    #[instrument(skip(self))]
+    pub async fn list_installations(
+        &self,
+        account_login: Option<&str>,
+    ) -> CoreResult<Vec<Installation>> {
+        info!(?account_login, "Listing app installations");
+
+        let mut installations: Vec<Installation> = Vec::new();
+        let mut current_page: u32 = 1;
+
+        loop {
+            let path = format!("/app/installations?per_page=100&page={current_page}");
+
+            // App-level requests bypass the SDK's retry loop, so rate-limit
+            // responses are detected and retried here.
+            let (status, next_page, body) = self
+                .rate_limiter
+                .execute_with_timeout("list_installations", self.request_timeout, || async {
+                    let response = self.sdk_client.get_as_app(&path).await?;
+                    let status = response.status();
+                    let headers = response.headers().clone();
+                    self.rate_limiter.record_response_headers(&headers);
+                    let next_page = headers
+                        .get("Link")
+                        .and_then(|h| h.to_str().ok())
+                        .and_then(parse_next_page_from_link_header);
+                    let body = response.text().await.map_err(|e| Error::Network {
+                        message: format!("failed to read installations response: {e}"),
+                        source: Some(Box::new(e)),
+                    })?;
+                    match rate_limiter::detect_rate_limit(status.as_u16(), &headers, &body) {
+                        Some(error) => Err(error),
+                        None => Ok((status, next_page, body)),
+                    }
+                })
+                .await?;
+
+            if !status.is_success() {
+                return Err(map_sdk_error(ApiError::HttpError {
+                    status: status.as_u16(),
+                    message: body,
+                }));
+            }
+
+            let page: Vec<Installation> = serde_json::from_str(&body).map_err(|e| {
+                CoreError::network(format!(
+                    "failed to parse installations page {current_page}: {e}"
+                ))
+            })?;
+            installations.extend(page.into_iter().filter(|installation| {
+                account_login.map_or(true, |login| {
+                    installation.account.login.eq_ignore_ascii_case(login)
+                })
+            }));
+
+            match next_page {
+                Some(next) if next > current_page => current_page = next,
+                _ => break,
+            }
+        }
+
+        debug!(count = installations.len(), "list_installations complete");
+        Ok(installations)
+    }
+
     /// Create a new GitHub client pointing at a custom API base URL.
     ///
     /// For use in tests only — points the SDK client at a `wiremock::MockServer`
     /// and disables retries so tests fail fast.
     #[cfg(test)]
+    #[allow(clippy::result_large_err)]
     pub(crate) fn new_for_testing(
         auth_provider: impl AuthenticationProvider + 'static,
         installation_id: u64,
@@ -158,6 +488,7 @@ impl GitHubClient {
             .with_github_api_url(api_base_url)
             .with_max_retries(0);
 
+        let upload_client = build_upload_client(&config.user_agent)?;
         let sdk_client = SdkClient::builder(auth_provider)
             .config(config)
             .build()
@@ -169,6 +500,13 @@ impl GitHubClient {
         Ok(Self {
             sdk_client,
             installation_id: InstallationId::new(installation_id),
+            rate_limiter: Arc::new(RateLimiter::new(RetryPolicy {
+                max_retries: 0,
+                ..RetryPolicy::default()
+            })),
+            upload_client,
+            token_cache: None,
+            request_timeout: None,
         })
     }
 
@@ -238,18 +576,83 @@ impl GitOperations for GitHubClient {
             .collect())
     }
 
+    #[instrument(skip(self))]
+    async fn list_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        head: &str,
+        options: GetCommitsOptions,
+    ) -> CoreResult<Vec<GitCommit>> {
+        info!(owner, repo, head, "Listing commit history");
+
+        // The list endpoint returns the same envelopes as the compare
+        // endpoint, newest first.
+        let limit = options.limit.unwrap_or(DEFAULT_HISTORY_LIMIT);
+        let installation = self.installation().await?;
+        let mut commits: Vec<GitCommit> = Vec::new();
+        let mut page: u32 = 1;
+        let mut more_pages = false;
+
+        while commits.len() < limit {
+            let path = format!("/repos/{owner}/{repo}/commits?sha={head}&per_page=100&page={page}");
+            let response = installation.get(&path).await.map_err(map_sdk_error)?;
+            if response.status().as_u16() == 404 {
+                return Err(CoreError::not_found(format!(
+                    "reference '{head}' not found in {owner}/{repo}"
+                )));
+            }
+
+            let next_page = response
+                .headers()
+                .get("Link")
+                .and_then(|h| h.to_str().ok())
+                .and_then(parse_next_page_from_link_header);
+
+            let items: Vec<CompareCommitEnvelope> = response
+                .json()
+                .await
+                .map_err(|e| CoreError::network(format!("GitHub HTTP client error: {e}")))?;
+            commits.extend(items.into_iter().map(compare_envelope_to_git_commit));
+
+            more_pages = next_page.is_some();
+            match next_page {
+                Some(next) => page = next,
+                None => break,
+            }
+        }
+
+        if commits.len() > limit || more_pages {
+            commits.truncate(limit);
+            warn!(
+                owner,
+                repo,
+                head,
+                limit,
+                "Commit history reached the limit of {limit} commits; older commits are left out"
+            );
+        }
+
+        commits.reverse();
+        Ok(commits)
+    }
+
     #[instrument(skip(self))]
     async fn get_commit(&self, owner: &str, repo: &str, commit_sha: &str) -> CoreResult<GitCommit> {
         info!(owner, repo, commit_sha, "Getting commit");
 
+        // Use the raw endpoint, like `get_commits_between`: the SDK's
+        // `FullCommit` has no `files` field, and the changed files are needed
+        // to assign commits to monorepo packages.
         let installation = self.installation().await?;
-        let sdk_commit = installation
-            .repositories()
-            .get_commit(owner, repo, commit_sha)
+        let path = format!("/repos/{owner}/{repo}/commits/{commit_sha}");
+        let response = installation.get(&path).await.map_err(map_sdk_error)?;
+        let commit: CompareCommitEnvelope = response
+            .json()
             .await
-            .map_err(map_sdk_error)?;
+            .map_err(|e| CoreError::network(format!("GitHub HTTP client error: {e}")))?;
 
-        Ok(convert_sdk_commit_to_git_commit(sdk_commit))
+        Ok(compare_envelope_to_git_commit(commit))
     }
 
     #[instrument(skip(self))]
@@ -360,23 +763,25 @@ impl GitOperations for GitHubClient {
     async fn get_repository_info(&self, owner: &str, repo: &str) -> CoreResult<GitRepository> {
         info!(owner, repo, "Getting repository info");
 
+        // Use the raw endpoint: the SDK's `Repository` has no `topics`,
+        // `language` or `visibility` fields.
         let installation = self.installation().await?;
-        let sdk_repo = installation
-            .repositories()
-            .get(owner, repo)
+        let path = format!("/repos/{owner}/{repo}");
+        let response = installation.get(&path).await.map_err(map_sdk_error)?;
+        let status = response.status().as_u16();
+        if status == 404 {
+            return Err(CoreError::not_found(format!("repository {owner}/{repo}")));
+        }
+        if status != 200 {
+            let message = response.text().await.unwrap_or_default();
+            return Err(map_sdk_error(ApiError::HttpError { status, message }));
+        }
+        let repository: RepositoryApiResponse = response
+            .json()
             .await
-            .map_err(map_sdk_error)?;
+            .map_err(|e| CoreError::network(format!("GitHub HTTP client error: {e}")))?;
 
-        Ok(GitRepository {
-            name: sdk_repo.name.clone(),
-            owner: owner.to_string(),
-            full_name: sdk_repo.full_name.clone(),
-            default_branch: sdk_repo.default_branch.clone(),
-            clone_url: sdk_repo.clone_url.clone(),
-            ssh_url: sdk_repo.ssh_url.clone(),
-            private: sdk_repo.private,
-            description: sdk_repo.description.clone(),
-        })
+        Ok(repository_response_to_git_repository(repository))
     }
 }
 
@@ -472,6 +877,96 @@ impl GitHubOperations for GitHubClient {
         })
     }
 
+    #[instrument(skip(self, params), fields(tag_name = %params.tag_name))]
+    async fn create_signed_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: SignedTagParams,
+    ) -> CoreResult<Tag> {
+        info!(
+            owner,
+            repo,
+            tag_name = %params.tag_name,
+            commit_sha = %params.commit_sha,
+            "Creating signed tag"
+        );
+
+        let installation = self.installation().await?;
+
+        // Step 1: Create the tag object. The signature is part of the message,
+        // exactly as `git tag -s` stores it.
+        let tag_body = serde_json::json!({
+            "tag": params.tag_name,
+            "message": params.signed_message(),
+            "object": params.commit_sha,
+            "type": "commit",
+            "tagger": {
+                "name": params.tagger.name,
+                "email": params.tagger.email,
+                "date": params
+                    .tagged_at
+                    .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            },
+        });
+        let tag_url = format!("/repos/{owner}/{repo}/git/tags");
+        let tag_resp = installation
+            .post(&tag_url, &tag_body)
+            .await
+            .map_err(map_sdk_error)?;
+        let tag_status = tag_resp.status().as_u16();
+        let tag_json: serde_json::Value = tag_resp.json().await.map_err(CoreError::github)?;
+        if tag_status != 201 {
+            return Err(CoreError::github(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("POST /git/tags failed with status {tag_status}"),
+            )));
+        }
+        let tag_object_sha = tag_json["sha"].as_str().ok_or_else(|| {
+            CoreError::github(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "tag response missing sha",
+            ))
+        })?;
+
+        // Step 2: Point the tag ref at the new tag object.
+        installation
+            .repositories()
+            .create_ref(
+                owner,
+                repo,
+                &format!("refs/tags/{}", params.tag_name),
+                tag_object_sha,
+            )
+            .await
+            .map_err(|e| {
+                // See `create_branch` for the two shapes of "already exists".
+                let is_already_exists = match &e {
+                    ApiError::HttpError { status: 422, .. } => true,
+                    ApiError::InvalidRequest { message } => {
+                        message.contains("Reference already exists")
+                    }
+                    _ => false,
+                };
+                if is_already_exists {
+                    CoreError::not_supported(
+                        "create_signed_tag",
+                        format!("tag '{}' already exists", params.tag_name),
+                    )
+                } else {
+                    map_sdk_error(e)
+                }
+            })?;
+
+        Ok(Tag {
+            name: params.tag_name,
+            commit_sha: params.commit_sha,
+            message: Some(params.message),
+            tagger: Some(params.tagger),
+            created_at: Some(params.tagged_at),
+        })
+    }
+
     #[instrument(skip(self))]
     async fn get_latest_release(&self, owner: &str, repo: &str) -> CoreResult<Option<Release>> {
         info!(owner, repo, "Getting latest release");
@@ -507,17 +1002,33 @@ impl GitHubOperations for GitHubClient {
     }
 
     #[instrument(skip(self))]
-    async fn get_release_by_tag(&self, owner: &str, repo: &str, tag: &str) -> CoreResult<Release> {
+    async fn get_release_by_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+    ) -> CoreResult<Option<Release>> {
         info!(owner, repo, tag, "Getting release by tag");
 
         let installation = self.installation().await?;
-        let sdk_release = installation
+        match installation
             .releases()
             .get_by_tag(owner, repo, tag)
             .await
-            .map_err(map_sdk_error)?;
-
-        Ok(convert_sdk_release_to_release_regent_release(sdk_release))
+            .map_err(map_sdk_error)
+        {
+            Ok(sdk_release) => Ok(Some(convert_sdk_release_to_release_regent_release(
+                sdk_release,
+            ))),
+            // A 404 means there is no release for the tag; every other error
+            // (auth, rate limit, server) is propagated so it is not mistaken
+            // for absence.
+            Err(CoreError::NotFound { .. }) => {
+                debug!(owner, repo, tag, "No release exists for tag");
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
     }
 
     #[instrument(skip(self))]
@@ -525,22 +1036,184 @@ impl GitHubOperations for GitHubClient {
         &self,
         owner: &str,
         repo: &str,
-        _per_page: Option<u8>,
-        _page: Option<u32>,
+        per_page: Option<u8>,
+        page: Option<u32>,
     ) -> CoreResult<Vec<Release>> {
-        info!(owner, repo, "Listing releases");
+        info!(owner, repo, ?per_page, ?page, "Listing releases");
+        validate_pagination(per_page, page)?;
+
+        // Use the raw HTTP endpoint so the requested page size and page are
+        // honoured; the SDK's `releases().list()` only returns GitHub's default
+        // first page.  An explicit `page` returns that page alone; otherwise
+        // every page is followed via the `Link` header.
+        let installation = self.installation().await?;
+        let per_page = per_page.unwrap_or(100);
+        let mut releases: Vec<Release> = Vec::new();
+        let mut current_page = page.unwrap_or(1);
+
+        loop {
+            let path =
+                format!("/repos/{owner}/{repo}/releases?per_page={per_page}&page={current_page}");
+            let response = installation.get(&path).await.map_err(map_sdk_error)?;
+
+            let next_page = response
+                .headers()
+                .get("Link")
+                .and_then(|h| h.to_str().ok())
+                .and_then(parse_next_page_from_link_header);
+
+            let items: Vec<github_bot_sdk::client::Release> = response
+                .json()
+                .await
+                .map_err(|e| CoreError::network(format!("GitHub HTTP client error: {e}")))?;
+            releases.extend(
+                items
+                    .into_iter()
+                    .map(convert_sdk_release_to_release_regent_release),
+            );
+
+            match next_page {
+                Some(next) if page.is_none() => current_page = next,
+                _ => break,
+            }
+        }
+
+        debug!(
+            owner,
+            repo,
+            count = releases.len(),
+            "list_releases complete"
+        );
+        Ok(releases)
+    }
+
+    #[instrument(skip(self, data), fields(size = data.len()))]
+    async fn upload_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: u64,
+        name: &str,
+        content_type: Option<&str>,
+        data: Vec<u8>,
+    ) -> CoreResult<String> {
+        info!(owner, repo, release_id, name, "Uploading release asset");
+
+        if name.trim().is_empty() {
+            return Err(CoreError::invalid_input(
+                "name",
+                "release asset name must not be empty",
+            ));
+        }
+        let content_type = content_type.unwrap_or_else(|| infer_asset_content_type(name));
+
+        // Assets are uploaded to a separate host which GitHub advertises per
+        // release as the `upload_url` URI template (`.../assets{?name,label}`).
+        let installation = self.installation().await?;
+        let response = installation
+            .get(&format!("/repos/{owner}/{repo}/releases/{release_id}"))
+            .await
+            .map_err(map_sdk_error)?;
+        let status = response.status().as_u16();
+        if status != 200 {
+            let message = response.text().await.unwrap_or_default();
+            return Err(map_sdk_error(ApiError::HttpError { status, message }));
+        }
+        let release: serde_json::Value = response.json().await.map_err(CoreError::github)?;
+        let upload_url = release["upload_url"]
+            .as_str()
+            .map(|template| template.split('{').next().unwrap_or(template))
+            .ok_or_else(|| {
+                CoreError::github(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    format!(
+                        "release {release_id} in {owner}/{repo}: response missing 'upload_url'"
+                    ),
+                ))
+            })?;
+        let url = format!("{upload_url}?name={}", urlencoding_encode_query_value(name));
+
+        // `Bytes` is reference counted, so each retry attempt re-sends the
+        // same buffer without copying it.
+        let body = bytes::Bytes::from(data);
+        let download_url = self
+            .rate_limiter
+            .execute_with_timeout("upload_release_asset", self.request_timeout, || {
+                let body = body.clone();
+                let url = url.as_str();
+                async move {
+                    let token = self
+                        .sdk_client
+                        .auth_provider()
+                        .installation_token(self.installation_id)
+                        .await?;
+                    let response = self
+                        .upload_client
+                        .post(url)
+                        .header(
+                            reqwest::header::AUTHORIZATION,
+                            format!("Bearer {}", token.token()),
+                        )
+                        .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+                        .header(reqwest::header::CONTENT_TYPE, content_type)
+                        .body(body)
+                        .send()
+                        .await
+                        .map_err(|e| Error::Network {
+                            message: format!("release asset upload failed: {e}"),
+                            source: Some(Box::new(e)),
+                        })?;
 
-        let installation = self.installation().await?;
-        let sdk_releases = installation
-            .releases()
-            .list(owner, repo)
-            .await
-            .map_err(map_sdk_error)?;
+                    let status = response.status().as_u16();
+                    let headers = response.headers().clone();
+                    self.rate_limiter.record_response_headers(&headers);
+                    let body = response.text().await.map_err(|e| Error::Network {
+                        message: format!("failed to read release asset upload response: {e}"),
+                        source: Some(Box::new(e)),
+                    })?;
 
-        Ok(sdk_releases
-            .into_iter()
-            .map(convert_sdk_release_to_release_regent_release)
-            .collect())
+                    if let Some(error) = rate_limiter::detect_rate_limit(status, &headers, &body) {
+                        return Err(error);
+                    }
+                    match status {
+                        200 | 201 => {
+                            let asset: serde_json::Value =
+                                serde_json::from_str(&body).map_err(|e| Error::Api {
+                                    message: format!("invalid release asset response: {e}"),
+                                    source: Some(Box::new(e)),
+                                })?;
+                            asset["browser_download_url"]
+                                .as_str()
+                                .map(str::to_owned)
+                                .ok_or_else(|| Error::Api {
+                                    message: "release asset response missing \
+                                              'browser_download_url'"
+                                        .to_string(),
+                                    source: None,
+                                })
+                        }
+                        // GitHub rejects a second asset with the same name on
+                        // a release with 422 and an `already_exists` error code.
+                        422 if body.contains("already_exists") => Err(Error::AlreadyExists {
+                            resource: format!("release asset '{name}' on release {release_id}"),
+                        }),
+                        s if s >= 500 => Err(Error::Network {
+                            message: format!(
+                                "GitHub server error {s} uploading release asset: {body}"
+                            ),
+                            source: None,
+                        }),
+                        _ => Err(Error::from(ApiError::HttpError {
+                            status,
+                            message: body,
+                        })),
+                    }
+                }
+            })
+            .await?;
+
+        debug!(owner, repo, release_id, name, %download_url, "Release asset uploaded");
+        Ok(download_url)
     }
 
     #[instrument(skip(self))]
@@ -830,6 +1503,35 @@ impl GitHubOperations for GitHubClient {
         Ok(())
     }
 
+    #[instrument(skip(self, params))]
+    async fn create_commit_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        params: CreateCommitStatusParams,
+    ) -> CoreResult<()> {
+        info!(owner, repo, sha, context = %params.context, "Creating commit status");
+
+        let installation = self.installation().await?;
+        let path = format!("/repos/{owner}/{repo}/statuses/{sha}");
+        let response = installation
+            .post(&path, &params)
+            .await
+            .map_err(map_sdk_error)?;
+        let status = response.status().as_u16();
+        if status == 404 {
+            return Err(CoreError::not_found(format!(
+                "commit {sha} in {owner}/{repo}"
+            )));
+        }
+        if status != 201 {
+            let message = response.text().await.unwrap_or_default();
+            return Err(map_sdk_error(ApiError::HttpError { status, message }));
+        }
+        Ok(())
+    }
+
     #[instrument(skip(self))]
     async fn delete_branch(&self, owner: &str, repo: &str, branch_name: &str) -> CoreResult<()> {
         info!(owner, repo, branch_name, "Deleting branch");
@@ -866,6 +1568,52 @@ impl GitHubOperations for GitHubClient {
         Ok(())
     }
 
+    #[instrument(skip(self))]
+    async fn upsert_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch_name: &str,
+        from_sha: &str,
+    ) -> CoreResult<BranchUpsert> {
+        match self.create_branch(owner, repo, branch_name, from_sha).await {
+            Ok(()) => return Ok(BranchUpsert::Created),
+            Err(CoreError::Conflict { .. }) => {}
+            Err(e) => return Err(e),
+        }
+
+        info!(owner, repo, branch_name, from_sha, "Fast-forwarding branch");
+
+        let installation = self.installation().await?;
+
+        installation
+            .repositories()
+            .update_ref(
+                owner,
+                repo,
+                &format!("heads/{branch_name}"),
+                from_sha,
+                false,
+            )
+            .await
+            .map_err(|e| match &e {
+                // GitHub answers 422 "Update is not a fast forward" when the
+                // branch holds commits that `from_sha` does not contain.
+                github_bot_sdk::error::ApiError::InvalidRequest { message }
+                    if message.contains("not a fast forward") =>
+                {
+                    CoreError::not_fast_forward(branch_name, from_sha)
+                }
+                // The branch existed a moment ago; it was deleted concurrently.
+                github_bot_sdk::error::ApiError::NotFound => {
+                    CoreError::conflict(format!("branch '{branch_name}' was deleted"))
+                }
+                _ => map_sdk_error(e),
+            })?;
+
+        Ok(BranchUpsert::Updated)
+    }
+
     #[instrument(skip(self, body))]
     async fn create_issue_comment(
         &self,
@@ -983,6 +1731,138 @@ impl GitHubOperations for GitHubClient {
         }
     }
 
+    #[instrument(skip(self))]
+    async fn set_pull_request_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        labels: &[&str],
+        mode: LabelMode,
+    ) -> CoreResult<()> {
+        if labels.is_empty() && mode == LabelMode::Add {
+            return Ok(());
+        }
+        info!(
+            owner,
+            repo,
+            pr_number,
+            ?labels,
+            ?mode,
+            "Setting pull request labels"
+        );
+
+        let installation = self.installation().await?;
+        let path = format!("/repos/{owner}/{repo}/issues/{pr_number}/labels");
+        let body = serde_json::json!({ "labels": labels });
+        let send = || async {
+            match mode {
+                LabelMode::Add => installation.post(&path, &body).await,
+                LabelMode::Replace => installation.put(&path, &body).await,
+            }
+        };
+
+        match send().await {
+            Ok(_) => Ok(()),
+            // GitHub creates unknown labels on this endpoint only when the
+            // token may manage labels; otherwise it rejects them with a 422.
+            // Create the missing labels explicitly and try once more.
+            Err(ApiError::InvalidRequest { message }) => {
+                debug!(
+                    owner,
+                    repo,
+                    pr_number,
+                    message = %message,
+                    "Label request rejected; creating missing labels"
+                );
+                create_missing_labels(&installation, owner, repo, labels).await?;
+                send().await.map(drop).map_err(map_sdk_error)
+            }
+            Err(e) => Err(map_sdk_error(e)),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn resolve_ref(&self, owner: &str, repo: &str, git_ref: &str) -> CoreResult<String> {
+        let candidates = ref_candidates(git_ref);
+        if candidates.is_empty() {
+            return Err(CoreError::invalid_input(
+                "git_ref",
+                "ref must not be empty".to_string(),
+            ));
+        }
+
+        debug!(owner, repo, git_ref, "Resolving ref");
+
+        let installation = self.installation().await?;
+
+        // Step 1: Find the first candidate ref that exists.
+        let mut object = None;
+        for candidate in &candidates {
+            let ref_url = format!("/repos/{owner}/{repo}/git/ref/{candidate}");
+            let ref_resp = match installation.get(&ref_url).await {
+                Ok(resp) if resp.status().as_u16() == 404 => continue,
+                Err(ApiError::NotFound | ApiError::HttpError { status: 404, .. }) => continue,
+                Ok(resp) => resp,
+                Err(e) => return Err(map_sdk_error(e)),
+            };
+            let ref_status = ref_resp.status().as_u16();
+            let ref_json: serde_json::Value = ref_resp.json().await.map_err(CoreError::github)?;
+            if ref_status != 200 {
+                return Err(CoreError::github(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    format!("GET /git/ref/{candidate} failed with status {ref_status}"),
+                )));
+            }
+            object = Some(ref_json["object"].clone());
+            break;
+        }
+        let mut object = object
+            .ok_or_else(|| CoreError::not_found(format!("ref '{git_ref}' in {owner}/{repo}")))?;
+
+        // Step 2: Peel annotated tags, which may themselves tag other tags,
+        // down to the commit.
+        for _ in 0..MAX_TAG_PEEL_DEPTH {
+            let sha = object["sha"].as_str().ok_or_else(|| {
+                CoreError::github(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    "ref response missing object.sha",
+                ))
+            })?;
+            match object["type"].as_str() {
+                Some("commit") => return Ok(sha.to_owned()),
+                Some("tag") => {
+                    let tag_url = format!("/repos/{owner}/{repo}/git/tags/{sha}");
+                    let tag_resp = installation.get(&tag_url).await.map_err(map_sdk_error)?;
+                    let tag_status = tag_resp.status().as_u16();
+                    let tag_json: serde_json::Value =
+                        tag_resp.json().await.map_err(CoreError::github)?;
+                    if tag_status != 200 {
+                        return Err(CoreError::github(std::io::Error::new(
+                            std::io::ErrorKind::Other,
+                            format!("GET /git/tags/{sha} failed with status {tag_status}"),
+                        )));
+                    }
+                    object = tag_json["object"].clone();
+                }
+                other => {
+                    return Err(CoreError::invalid_input(
+                        "git_ref",
+                        format!(
+                            "ref '{git_ref}' points to a {}, not a commit",
+                            other.unwrap_or("unknown object")
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Err(CoreError::invalid_input(
+            "git_ref",
+            format!("ref '{git_ref}' is nested more than {MAX_TAG_PEEL_DEPTH} tags deep"),
+        ))
+    }
+
     #[instrument(skip(self))]
     async fn list_pr_labels(
         &self,
@@ -1036,6 +1916,55 @@ impl GitHubOperations for GitHubClient {
         Ok(comments)
     }
 
+    /// Fetches every page of `/pulls/{number}/commits`. GitHub caps the list
+    /// at 250 commits.
+    #[instrument(skip(self))]
+    async fn list_pull_request_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> CoreResult<Vec<GitCommit>> {
+        info!(owner, repo, pr_number, "Listing pull request commits");
+
+        // The entries have the same shape as those of the compare endpoint,
+        // so the raw endpoint is used for the same reason as in
+        // `get_commits_between`.
+        let installation = self.installation().await?;
+        let mut commits: Vec<GitCommit> = Vec::new();
+        let mut page: u32 = 1;
+
+        loop {
+            let path =
+                format!("/repos/{owner}/{repo}/pulls/{pr_number}/commits?per_page=100&page={page}");
+            let response = installation.get(&path).await.map_err(map_sdk_error)?;
+            if response.status().as_u16() == 404 {
+                return Err(CoreError::not_found(format!(
+                    "pull request #{pr_number} not found in {owner}/{repo}"
+                )));
+            }
+
+            let next_page = response
+                .headers()
+                .get("Link")
+                .and_then(|h| h.to_str().ok())
+                .and_then(parse_next_page_from_link_header);
+
+            let items: Vec<CompareCommitEnvelope> = response
+                .json()
+                .await
+                .map_err(|e| CoreError::network(format!("GitHub HTTP client error: {e}")))?;
+            commits.extend(items.into_iter().map(compare_envelope_to_git_commit));
+
+            match next_page {
+                Some(next) => page = next,
+                None => break,
+            }
+        }
+
+        Ok(commits)
+    }
+
     #[instrument(skip(self, content, commit_message))]
     async fn upsert_file(
         &self,
@@ -1533,95 +2462,18 @@ impl GitHubOperations for GitHubClient {
         Ok(())
     }
 
-    #[instrument(skip(self))]
     async fn get_installation_id_for_repo(&self, owner: &str, repo: &str) -> CoreResult<u64> {
-        info!(owner, repo, "Looking up installation ID for repository");
-        let path = format!("/repos/{owner}/{repo}/installation");
-        let response = self
-            .sdk_client
-            .get_as_app(&path)
-            .await
-            .map_err(map_sdk_error)?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let body = response.text().await.unwrap_or_default();
-            let code = status.as_u16();
-            return Err(match code {
-                // A 401 from the installation endpoint means the JWT was
-                // rejected by GitHub.  This is treated as a transient auth
-                // token failure (clock skew, brief GitHub auth service hiccup)
-                // per the error-handling spec; the event will be retried and a
-                // fresh JWT will be used on the next attempt.
-                401 => {
-                    warn!(
-                        owner,
-                        repo,
-                        status = code,
-                        "Installation lookup returned 401 — transient JWT \
-                         rejection; event will be retried"
-                    );
-                    CoreError::network(format!(
-                        "installation lookup for {owner}/{repo} failed with 401 \
-                         (transient auth token rejection): {body}"
-                    ))
-                }
-                // 404 means the GitHub App is not installed on this repository.
-                // This is a permanent configuration error, not a transient one.
-                404 => {
-                    warn!(
-                        owner,
-                        repo,
-                        status = code,
-                        "Installation lookup returned 404 — app may not be \
-                         installed on this repository"
-                    );
-                    CoreError::not_found(format!(
-                        "installation lookup for {owner}/{repo} failed with 404 \
-                         (app not installed?): {body}"
-                    ))
-                }
-                // 5xx responses are server-side errors — transient and retryable.
-                s if s >= 500 => {
-                    warn!(
-                        owner,
-                        repo,
-                        status = code,
-                        "Installation lookup returned server error — will retry"
-                    );
-                    CoreError::network(format!(
-                        "installation lookup for {owner}/{repo} failed with \
-                         server error {s}: {body}"
-                    ))
-                }
-                // All other failures (403, other 4xx) are permanent.
-                _ => CoreError::GitHub {
-                    source: Box::new(std::io::Error::new(
-                        std::io::ErrorKind::Other,
-                        format!(
-                            "installation lookup for {owner}/{repo} failed with \
-                             status {status}: {body}"
-                        ),
-                    )),
-                    context: None,
-                },
-            });
-        }
-
-        let body: serde_json::Value = response.json().await.map_err(CoreError::github)?;
-        body["id"].as_u64().ok_or_else(|| CoreError::GitHub {
-            source: Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("installation lookup for {owner}/{repo}: response missing 'id' field"),
-            )),
-            context: None,
-        })
+        self.get_repository_installation(owner, repo).await
     }
 
     fn scoped_to(&self, installation_id: u64) -> Self {
         Self {
             sdk_client: self.sdk_client.clone(),
             installation_id: InstallationId::new(installation_id),
+            rate_limiter: Arc::clone(&self.rate_limiter),
+            upload_client: self.upload_client.clone(),
+            token_cache: self.token_cache.clone(),
+            request_timeout: self.request_timeout,
         }
     }
 }
@@ -1663,6 +2515,10 @@ struct CompareCommitEnvelope {
     committer: Option<CompareGitHubUser>,
     #[serde(default)]
     parents: Vec<CompareCommitParent>,
+    /// Files changed by the commit. Only the single-commit endpoint returns
+    /// these; the compare endpoint omits them.
+    #[serde(default)]
+    files: Vec<CompareCommitFile>,
 }
 
 #[derive(serde::Deserialize)]
@@ -1689,6 +2545,11 @@ struct CompareCommitParent {
     sha: String,
 }
 
+#[derive(serde::Deserialize)]
+struct CompareCommitFile {
+    filename: String,
+}
+
 // ── List-PRs API local types ────────────────────────────────────────────────
 //
 // The SDK's `PullRequest` type has `head.repo: PullRequestRepo` (required).
@@ -1736,6 +2597,30 @@ struct ListPrUser {
     login: String,
 }
 
+// ── Get-repository API local type ───────────────────────────────────────────
+//
+// The SDK's `Repository` type leaves out `topics`, `language` and
+// `visibility`, which callers use to pick configuration defaults.
+
+#[derive(serde::Deserialize)]
+struct RepositoryApiResponse {
+    name: String,
+    full_name: String,
+    owner: ListPrUser,
+    default_branch: String,
+    clone_url: String,
+    ssh_url: String,
+    private: bool,
+    description: Option<String>,
+    #[serde(default)]
+    topics: Vec<String>,
+    #[serde(default)]
+    language: Option<String>,
+    /// Absent on older GitHub Enterprise Server releases.
+    #[serde(default)]
+    visibility: Option<RepositoryVisibility>,
+}
+
 /// Percent-encode a file path for use in a GitHub Contents API URL.
 ///
 /// Only forward slashes are kept unencoded (they are valid path separators
@@ -1779,6 +2664,49 @@ fn urlencoding_encode_query_value(value: &str) -> String {
         .collect()
 }
 
+/// Build the HTTP client used for release asset uploads.
+#[allow(clippy::result_large_err)]
+fn build_upload_client(user_agent: &str) -> CoreResult<reqwest::Client> {
+    reqwest::Client::builder()
+        .user_agent(user_agent)
+        .timeout(ASSET_UPLOAD_TIMEOUT)
+        .build()
+        .map_err(CoreError::github)
+}
+
+/// Infer a release asset's MIME type from its file extension.
+///
+/// Falls back to `application/octet-stream`, which GitHub accepts for any
+/// asset.
+fn infer_asset_content_type(name: &str) -> &'static str {
+    let extension = name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "zip" => "application/zip",
+        "gz" | "tgz" => "application/gzip",
+        "tar" => "application/x-tar",
+        "xz" => "application/x-xz",
+        "bz2" => "application/x-bzip2",
+        "7z" => "application/x-7z-compressed",
+        "json" => "application/json",
+        "txt" | "sha256" | "sha512" => "text/plain",
+        "md" => "text/markdown",
+        "pdf" => "application/pdf",
+        "png" => "image/png",
+        "svg" => "image/svg+xml",
+        "asc" | "sig" => "application/pgp-signature",
+        "deb" => "application/vnd.debian.binary-package",
+        "rpm" => "application/x-rpm",
+        "msi" => "application/x-msi",
+        "dmg" => "application/x-apple-diskimage",
+        "exe" => "application/vnd.microsoft.portable-executable",
+        "wasm" => "application/wasm",
+        _ => "application/octet-stream",
+    }
+}
+
 /// Parse the `Link` response header and return the page number from the
 /// `rel="next"` URL, if present.
 fn parse_next_page_from_link_header(header_value: &str) -> Option<u32> {
@@ -1799,6 +2727,24 @@ fn parse_next_page_from_link_header(header_value: &str) -> Option<u32> {
 }
 
 /// Convert a raw GitHub list-PRs-API item into the core `PullRequest` type.
+fn repository_response_to_git_repository(repository: RepositoryApiResponse) -> GitRepository {
+    GitRepository {
+        name: repository.name,
+        owner: repository.owner.login,
+        full_name: repository.full_name,
+        default_branch: repository.default_branch,
+        clone_url: repository.clone_url,
+        ssh_url: repository.ssh_url,
+        private: repository.private,
+        description: repository.description,
+        topics: repository.topics,
+        language: repository.language,
+        visibility: repository
+            .visibility
+            .unwrap_or_else(|| RepositoryVisibility::from_private(repository.private)),
+    }
+}
+
 fn list_pr_item_to_release_regent_pr(pr: ListPrItem) -> PullRequest {
     let head_repo = pr.head.repo.as_ref();
     let base_repo = pr.base.repo.as_ref();
@@ -1891,7 +2837,7 @@ fn compare_envelope_to_git_commit(commit: CompareCommitEnvelope) -> GitCommit {
         subject,
         body,
         parents: commit.parents.into_iter().map(|p| p.sha).collect(),
-        files: vec![],
+        files: commit.files.into_iter().map(|f| f.filename).collect(),
     }
 }
 
@@ -2040,8 +2986,10 @@ fn apply_tag_sorting(tags: &mut [GitTag], sort: TagSortOrder) {
     match sort {
         TagSortOrder::NameAsc => tags.sort_by(|a, b| a.name.cmp(&b.name)),
         TagSortOrder::NameDesc => tags.sort_by(|a, b| b.name.cmp(&a.name)),
-        TagSortOrder::CreationDateAsc => tags.sort_by(|a, b| a.created_at.cmp(&b.created_at)),
-        TagSortOrder::CreationDateDesc => tags.sort_by(|a, b| b.created_at.cmp(&a.created_at)),
+        TagSortOrder::CreationDateAsc => tags.sort_by_key(|t| t.created_at),
+        TagSortOrder::CreationDateDesc => {
+            tags.sort_by_key(|t| std::cmp::Reverse(t.created_at));
+        }
         TagSortOrder::SemanticVersionAsc | TagSortOrder::SemanticVersionDesc => {
             // Semantic version sorting would require parsing
             // For now, fall back to name sorting
@@ -2059,7 +3007,7 @@ fn apply_tag_sorting(tags: &mut [GitTag], sort: TagSortOrder) {
 /// transient server faults (5xx, rate limits, timeouts, network) and `false` for
 /// permanent client errors (4xx auth/validation failures).
 #[allow(clippy::match_same_arms)] // explicit arms are intentional for clarity
-fn map_sdk_error(error: ApiError) -> CoreError {
+pub(crate) fn map_sdk_error(error: ApiError) -> CoreError {
     match error {
         // ── Permanent: resource not found ───────────────────────────────────
         ApiError::NotFound => CoreError::not_found("GitHub resource not found"),
@@ -2147,6 +3095,60 @@ fn map_sdk_error(error: ApiError) -> CoreError {
     }
 }
 
+/// Validate `per_page` / `page` arguments against GitHub's REST pagination limits.
+#[allow(clippy::result_large_err)]
+fn validate_pagination(per_page: Option<u8>, page: Option<u32>) -> CoreResult<()> {
+    if let Some(per_page) = per_page {
+        if per_page == 0 || per_page > 100 {
+            return Err(CoreError::invalid_input(
+                "per_page",
+                format!("per_page must be between 1 and 100, got {per_page}"),
+            ));
+        }
+    }
+    if page == Some(0) {
+        return Err(CoreError::invalid_input("page", "page numbers are 1-based"));
+    }
+    Ok(())
+}
+
+/// Create each label in `labels` that does not exist in `owner/repo` yet.
+///
+/// New labels get GitHub's default grey colour.
+async fn create_missing_labels(
+    installation: &InstallationClient,
+    owner: &str,
+    repo: &str,
+    labels: &[&str],
+) -> CoreResult<()> {
+    let existing = installation
+        .get(&format!("/repos/{owner}/{repo}/labels?per_page=100"))
+        .await
+        .map_err(map_sdk_error)?
+        .json::<Vec<serde_json::Value>>()
+        .await
+        .map_err(CoreError::github)?;
+
+    let path = format!("/repos/{owner}/{repo}/labels");
+    for label in labels {
+        let exists = existing
+            .iter()
+            .filter_map(|l| l["name"].as_str())
+            .any(|name| name.eq_ignore_ascii_case(label));
+        if exists {
+            continue;
+        }
+        info!(owner, repo, label, "Creating missing label");
+        let body = serde_json::json!({ "name": label, "color": "ededed" });
+        match installation.post(&path, &body).await {
+            // Created concurrently by someone else.
+            Ok(_) | Err(ApiError::InvalidRequest { .. }) => {}
+            Err(e) => return Err(map_sdk_error(e)),
+        }
+    }
+    Ok(())
+}
+
 fn is_not_found_error(error: &ApiError) -> bool {
     matches!(
         error,
@@ -2154,6 +3156,10 @@ fn is_not_found_error(error: &ApiError) -> bool {
     )
 }
 
+#[cfg(test)]
+#[path = "branch_tests.rs"]
+mod branch_tests;
+
 #[cfg(test)]
 #[path = "installation_tests.rs"]
 mod installation_tests;
@@ -251,3 +251,40 @@ fn test_sdk_types_reexported() {
     let installation_id = SdkInstallationId::new(67890);
     assert_eq!(installation_id.as_u64(), 67890);
 }
+
+// ============================================================================
+// Single-commit response conversion
+// ============================================================================
+
+/// The single-commit endpoint's response keeps the full message, including
+/// footers, along with both signatures and the changed files.
+#[test]
+fn test_single_commit_response_keeps_full_message_and_metadata() {
+    let json = serde_json::json!({
+        "sha": "abc123",
+        "commit": {
+            "message": "fix: tighten validation\n\nLonger explanation.\n\nBREAKING CHANGE: tokens need exp",
+            "author": {"name": "Ada", "email": "ada@example.com", "date": "2024-01-01T00:00:00Z"},
+            "committer": {"name": "GitHub", "email": "noreply@github.com", "date": "2024-01-02T00:00:00Z"}
+        },
+        "author": {"login": "ada"},
+        "committer": null,
+        "parents": [{"sha": "parent1"}],
+        "files": [{"filename": "src/auth.rs"}, {"filename": "CHANGELOG.md"}]
+    });
+
+    let envelope: CompareCommitEnvelope = serde_json::from_value(json).unwrap();
+    let commit = compare_envelope_to_git_commit(envelope);
+
+    assert_eq!(commit.subject, "fix: tighten validation");
+    assert_eq!(
+        commit.body.as_deref(),
+        Some("Longer explanation.\n\nBREAKING CHANGE: tokens need exp")
+    );
+    assert!(commit.message.ends_with("BREAKING CHANGE: tokens need exp"));
+    assert_eq!(commit.author.login.as_deref(), Some("ada"));
+    assert_eq!(commit.committer.name, "GitHub");
+    assert_eq!(commit.committer.login, None);
+    assert_eq!(commit.parents, vec!["parent1"]);
+    assert_eq!(commit.files, vec!["src/auth.rs", "CHANGELOG.md"]);
+}
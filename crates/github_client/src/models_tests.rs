@@ -6,5 +6,4 @@
 fn test_placeholder() {
     // Placeholder to maintain file structure
     // Model conversions are tested via integration tests with actual SDK types
-    assert!(true);
 }
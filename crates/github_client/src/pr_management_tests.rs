@@ -10,7 +10,7 @@ use github_bot_sdk::{
     error::AuthError,
 };
 use wiremock::{
-    matchers::{header, method, path, query_param},
+    matchers::{body_json, header, method, path, query_param},
     Mock, MockServer, ResponseTemplate,
 };
 
@@ -553,3 +553,289 @@ async fn test_search_pull_requests_null_repo_handled_gracefully() {
     assert_eq!(prs[0].number, 20);
     assert_eq!(prs[0].head.ref_name, "release/v1.0.0");
 }
+
+// ---------------------------------------------------------------------------
+// list_pull_request_commits
+// ---------------------------------------------------------------------------
+
+fn pr_commit_json(sha: &str, message: &str) -> serde_json::Value {
+    serde_json::json!({
+        "sha": sha,
+        "commit": {
+            "message": message,
+            "author": {"name": "Ada", "email": "ada@example.com", "date": "2024-01-01T00:00:00Z"},
+            "committer": {"name": "Ada", "email": "ada@example.com", "date": "2024-01-01T00:00:00Z"}
+        },
+        "author": {"login": "ada"},
+        "committer": {"login": "ada"},
+        "parents": [{"sha": format!("{sha}-parent")}]
+    })
+}
+
+/// The original commits of a squash-merged PR are returned across pages, in
+/// order, and parse as conventional commits.
+#[tokio::test]
+async fn test_list_pull_request_commits_returns_squashed_commits_from_all_pages() {
+    let mock_server = MockServer::start().await;
+
+    let link_header = format!(
+        r#"<{}repos/owner/repo/pulls/7/commits?per_page=100&page=2>; rel="next""#,
+        mock_server.uri()
+    );
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls/7/commits"))
+        .and(query_param("page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Link", link_header)
+                .set_body_json(serde_json::json!([
+                    pr_commit_json("c1", "feat(auth): add token refresh"),
+                    pr_commit_json("c2", "fix(auth): handle expired tokens"),
+                ])),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls/7/commits"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            pr_commit_json(
+                "c3",
+                "feat!: drop v1 tokens\n\nBREAKING CHANGE: v1 tokens are rejected"
+            ),
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let client = make_client(&mock_server, "test-token");
+    let commits = client
+        .list_pull_request_commits("owner", "repo", 7)
+        .await
+        .expect("list_pull_request_commits should succeed");
+
+    let shas: Vec<&str> = commits.iter().map(|c| c.sha.as_str()).collect();
+    assert_eq!(shas, vec!["c1", "c2", "c3"]);
+    assert_eq!(commits[0].author.login.as_deref(), Some("ada"));
+
+    let parsed = release_regent_core::versioning::VersionCalculator::parse_conventional_commits(
+        &commits
+            .iter()
+            .map(|c| (c.sha.clone(), c.message.clone()))
+            .collect::<Vec<_>>(),
+    );
+    let types: Vec<&str> = parsed.iter().map(|c| c.commit_type.as_str()).collect();
+    assert_eq!(types, vec!["feat", "fix", "feat"]);
+    assert_eq!(parsed[0].scope.as_deref(), Some("auth"));
+    assert!(parsed[2].breaking_change);
+}
+
+/// An unknown pull request is reported as `NotFound`.
+#[tokio::test]
+async fn test_list_pull_request_commits_unknown_pr_is_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls/404/commits"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(serde_json::json!({"message": "Not Found"})),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = make_client(&mock_server, "test-token");
+    let result = client.list_pull_request_commits("owner", "repo", 404).await;
+
+    assert!(
+        matches!(result, Err(CoreError::NotFound { .. })),
+        "{result:?}"
+    );
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// list_commits
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The history of a ref is fetched across pages and returned oldest first.
+#[tokio::test]
+async fn test_list_commits_returns_full_history_oldest_first() {
+    let mock_server = MockServer::start().await;
+
+    let link_header = format!(
+        r#"<{}repos/owner/repo/commits?sha=main&per_page=100&page=2>; rel="next""#,
+        mock_server.uri()
+    );
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/commits"))
+        .and(query_param("sha", "main"))
+        .and(query_param("page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Link", link_header)
+                .set_body_json(serde_json::json!([
+                    pr_commit_json("c3", "fix: handle empty input"),
+                    pr_commit_json("c2", "feat: add parser"),
+                ])),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/commits"))
+        .and(query_param("sha", "main"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            pr_commit_json("c1", "chore: initial commit"),
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let client = make_client(&mock_server, "test-token");
+    let commits = client
+        .list_commits("owner", "repo", "main", GetCommitsOptions::default())
+        .await
+        .expect("list_commits should succeed");
+
+    let shas: Vec<&str> = commits.iter().map(|c| c.sha.as_str()).collect();
+    assert_eq!(shas, vec!["c1", "c2", "c3"]);
+}
+
+/// `limit` keeps the most recent commits.
+#[tokio::test]
+async fn test_list_commits_limit_keeps_most_recent_commits() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/commits"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            pr_commit_json("c3", "fix: handle empty input"),
+            pr_commit_json("c2", "feat: add parser"),
+            pr_commit_json("c1", "chore: initial commit"),
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let client = make_client(&mock_server, "test-token");
+    let commits = client
+        .list_commits(
+            "owner",
+            "repo",
+            "main",
+            GetCommitsOptions {
+                limit: Some(2),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("list_commits should succeed");
+
+    let shas: Vec<&str> = commits.iter().map(|c| c.sha.as_str()).collect();
+    assert_eq!(shas, vec!["c2", "c3"]);
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// set_pull_request_labels
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// `LabelMode::Add` POSTs the labels, keeping the ones already on the PR.
+#[tokio::test]
+async fn test_set_pull_request_labels_add_posts_labels() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/issues/7/labels"))
+        .and(body_json(serde_json::json!({ "labels": ["release"] })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = make_client(&mock_server, "test-token");
+    client
+        .set_pull_request_labels("owner", "repo", 7, &["release"], LabelMode::Add)
+        .await
+        .expect("set_pull_request_labels should succeed");
+}
+
+/// `LabelMode::Replace` PUTs the labels, replacing the PR's label set.
+#[tokio::test]
+async fn test_set_pull_request_labels_replace_puts_labels() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/repos/owner/repo/issues/7/labels"))
+        .and(body_json(serde_json::json!({ "labels": ["release"] })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = make_client(&mock_server, "test-token");
+    client
+        .set_pull_request_labels("owner", "repo", 7, &["release"], LabelMode::Replace)
+        .await
+        .expect("set_pull_request_labels should succeed");
+}
+
+/// A label that does not exist yet is created and the request retried.
+#[tokio::test]
+async fn test_set_pull_request_labels_creates_missing_label_and_retries() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/issues/7/labels"))
+        .respond_with(
+            ResponseTemplate::new(422)
+                .set_body_json(serde_json::json!({ "message": "Validation Failed" })),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/issues/7/labels"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/labels"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([{ "name": "Release" }])),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/labels"))
+        .and(body_json(
+            serde_json::json!({ "name": "autorelease: pending", "color": "ededed" }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = make_client(&mock_server, "test-token");
+    client
+        .set_pull_request_labels(
+            "owner",
+            "repo",
+            7,
+            &["release", "autorelease: pending"],
+            LabelMode::Add,
+        )
+        .await
+        .expect("set_pull_request_labels should succeed after creating the label");
+}
+
+/// Adding no labels does not call GitHub at all.
+#[tokio::test]
+async fn test_set_pull_request_labels_add_nothing_is_a_no_op() {
+    let mock_server = MockServer::start().await;
+
+    let client = make_client(&mock_server, "test-token");
+    client
+        .set_pull_request_labels("owner", "repo", 7, &[], LabelMode::Add)
+        .await
+        .expect("set_pull_request_labels should succeed");
+
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
@@ -0,0 +1,356 @@
+//! Retry and rate-limit handling for requests issued directly by this crate.
+//!
+//! Requests sent through the SDK's installation client are retried by the SDK
+//! itself. Requests that bypass that path (for example app-level requests made
+//! with [`SdkClient::get_as_app`](github_bot_sdk::client::GitHubClient::get_as_app))
+//! receive raw responses, so rate-limit detection and backoff happen here.
+//!
+//! GitHub has two kinds of rate limit:
+//!
+//! - **Primary** limits are the per-hour request quota. They are handled with
+//!   the standard exponential backoff (`base_delay` .. `max_delay`).
+//! - **Secondary** (abuse-detection) limits are triggered by bursts of requests
+//!   or expensive operations. GitHub asks clients to wait at least a minute and
+//!   to back off further on repeated hits, so they use a separate, more
+//!   conservative policy starting at `secondary_rate_limit_base_delay`.
+//!
+//! [`RateLimiter::metrics_snapshot`] exposes the most recent quota reported by
+//! GitHub together with request, retry and wait counters for observability.
+
+use crate::errors::{Error, GitHubResult};
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+use std::time::Duration;
+use tracing::{debug, warn};
+
+/// Upper bound on the backoff applied after repeated secondary rate limit hits.
+const SECONDARY_RATE_LIMIT_MAX_DELAY_SECS: u64 = 15 * 60;
+
+/// Backoff configuration used by [`RateLimiter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the initial attempt.
+    pub max_retries: u32,
+    /// Initial delay for transient failures and primary rate limits.
+    pub base_delay: Duration,
+    /// Upper bound for the delay applied to transient failures and primary rate limits.
+    pub max_delay: Duration,
+    /// Initial delay after a secondary (abuse-detection) rate limit response.
+    pub secondary_rate_limit_base_delay: Duration,
+    /// Upper bound for the delay applied after secondary rate limit responses.
+    pub secondary_rate_limit_max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: crate::MAX_RETRIES,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(30),
+            secondary_rate_limit_base_delay: Duration::from_secs(
+                crate::SECONDARY_RATE_LIMIT_RETRY_SECS,
+            ),
+            secondary_rate_limit_max_delay: Duration::from_secs(
+                SECONDARY_RATE_LIMIT_MAX_DELAY_SECS,
+            ),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Compute the delay to wait before retrying after `error`.
+    ///
+    /// `attempt` is zero-based: `0` is the delay before the first retry.
+    /// Secondary rate limits honour the server's `Retry-After` hint but never
+    /// wait less than the exponential secondary backoff.
+    #[must_use]
+    pub fn delay_for(&self, error: &Error, attempt: u32) -> Duration {
+        match error {
+            Error::SecondaryRateLimit { retry_after } => {
+                let backoff = exponential(
+                    self.secondary_rate_limit_base_delay,
+                    attempt,
+                    self.secondary_rate_limit_max_delay,
+                );
+                retry_after
+                    .map(Duration::from_secs)
+                    .map_or(backoff, |hint| hint.max(backoff))
+            }
+            _ => exponential(self.base_delay, attempt, self.max_delay),
+        }
+    }
+}
+
+/// Quota information reported by GitHub in `X-RateLimit-*` response headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// Maximum number of requests allowed in the current window.
+    pub limit: u32,
+    /// Requests remaining in the current window.
+    pub remaining: u32,
+    /// Requests already used in the current window.
+    pub used: u32,
+    /// When the current window resets.
+    pub reset_at: DateTime<Utc>,
+}
+
+impl RateLimitInfo {
+    /// Parse quota information from GitHub response headers.
+    ///
+    /// Returns `None` when the limit, remaining or reset headers are missing or
+    /// malformed. `X-RateLimit-Used` is derived from the other values when absent.
+    #[must_use]
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let limit: u32 = header_value(headers, "x-ratelimit-limit")?;
+        let remaining: u32 = header_value(headers, "x-ratelimit-remaining")?;
+        let reset: i64 = header_value(headers, "x-ratelimit-reset")?;
+        let used = header_value(headers, "x-ratelimit-used")
+            .unwrap_or_else(|| limit.saturating_sub(remaining));
+
+        Some(Self {
+            limit,
+            remaining,
+            used,
+            reset_at: DateTime::from_timestamp(reset, 0)?,
+        })
+    }
+}
+
+/// Point-in-time view of rate limit state and request counters.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitMetrics {
+    /// Most recent quota reported by GitHub, if any response carried one.
+    pub rate_limit: Option<RateLimitInfo>,
+    /// Total request attempts made through [`RateLimiter::execute_with_retry`].
+    pub total_requests: u64,
+    /// Attempts that were retries of an earlier failed attempt.
+    pub retries: u64,
+    /// Retries that waited out a primary or secondary rate limit.
+    pub rate_limit_waits: u64,
+    /// Total time spent sleeping between attempts.
+    pub total_wait: Duration,
+}
+
+/// Executes GitHub requests with retry and rate-limit aware backoff.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    policy: RetryPolicy,
+    rate_limit: RwLock<Option<RateLimitInfo>>,
+    total_requests: AtomicU64,
+    retries: AtomicU64,
+    rate_limit_waits: AtomicU64,
+    total_wait_ms: AtomicU64,
+}
+
+impl RateLimiter {
+    /// Create a rate limiter using the given retry policy.
+    #[must_use]
+    pub fn new(policy: RetryPolicy) -> Self {
+        Self {
+            policy,
+            ..Self::default()
+        }
+    }
+
+    /// Take a snapshot of the current rate limit state and counters.
+    ///
+    /// Only takes a read lock, so it is cheap enough to call from a metrics
+    /// scrape or a health endpoint.
+    #[must_use]
+    pub fn metrics_snapshot(&self) -> RateLimitMetrics {
+        let rate_limit = *self
+            .rate_limit
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        RateLimitMetrics {
+            rate_limit,
+            total_requests: self.total_requests.load(Ordering::Relaxed),
+            retries: self.retries.load(Ordering::Relaxed),
+            rate_limit_waits: self.rate_limit_waits.load(Ordering::Relaxed),
+            total_wait: Duration::from_millis(self.total_wait_ms.load(Ordering::Relaxed)),
+        }
+    }
+
+    /// Record the quota reported in a GitHub response's headers.
+    ///
+    /// Responses without `X-RateLimit-*` headers leave the last known quota
+    /// unchanged.
+    pub fn record_response_headers(&self, headers: &HeaderMap) {
+        let Some(info) = RateLimitInfo::from_headers(headers) else {
+            return;
+        };
+
+        debug!(
+            limit = info.limit,
+            remaining = info.remaining,
+            used = info.used,
+            reset_at = %info.reset_at,
+            "GitHub rate limit updated"
+        );
+        *self
+            .rate_limit
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(info);
+    }
+
+    /// The retry policy applied by this rate limiter.
+    #[must_use]
+    pub fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+
+    /// Returns `true` if `error` is transient and the request may be retried.
+    ///
+    /// Network failures, timeouts and both primary and secondary rate limits
+    /// are retryable; everything else is treated as permanent.
+    #[must_use]
+    pub fn should_retry_error(&self, error: &Error) -> bool {
+        matches!(
+            error,
+            Error::Network { .. }
+                | Error::Timeout { .. }
+                | Error::RateLimit
+                | Error::SecondaryRateLimit { .. }
+        )
+    }
+
+    /// Run `operation`, retrying transient failures according to the policy.
+    ///
+    /// `operation` is invoked once per attempt. `name` is used for logging only.
+    ///
+    /// # Errors
+    ///
+    /// Returns the last error produced by `operation` when it is not retryable
+    /// or when `max_retries` retries have been exhausted.
+    pub async fn execute_with_retry<T, F, Fut>(&self, name: &str, operation: F) -> GitHubResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = GitHubResult<T>>,
+    {
+        self.execute_with_timeout(name, None, operation).await
+    }
+
+    /// Run `operation` like [`execute_with_retry`](Self::execute_with_retry),
+    /// failing each attempt that takes longer than `timeout`.
+    ///
+    /// An attempt that times out produces [`Error::Timeout`], which is retried
+    /// like any other transient failure. `None` leaves attempts unbounded, so
+    /// only the HTTP client's own timeout applies.
+    ///
+    /// # Errors
+    ///
+    /// Returns the last error produced by `operation` when it is not retryable
+    /// or when `max_retries` retries have been exhausted.
+    pub async fn execute_with_timeout<T, F, Fut>(
+        &self,
+        name: &str,
+        timeout: Option<Duration>,
+        mut operation: F,
+    ) -> GitHubResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = GitHubResult<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            self.total_requests.fetch_add(1, Ordering::Relaxed);
+            let outcome = match timeout {
+                Some(limit) => tokio::time::timeout(limit, operation())
+                    .await
+                    .unwrap_or_else(|_| {
+                        Err(Error::Timeout {
+                            operation: name.to_string(),
+                            timeout: Some(limit),
+                        })
+                    }),
+                None => operation().await,
+            };
+            match outcome {
+                Ok(value) => return Ok(value),
+                Err(error)
+                    if attempt < self.policy.max_retries && self.should_retry_error(&error) =>
+                {
+                    let delay = self.policy.delay_for(&error, attempt);
+                    warn!(
+                        operation = name,
+                        attempt = attempt + 1,
+                        max_retries = self.policy.max_retries,
+                        delay_ms = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX),
+                        error = %error,
+                        "GitHub request failed with a transient error; retrying"
+                    );
+                    if matches!(error, Error::RateLimit | Error::SecondaryRateLimit { .. }) {
+                        self.rate_limit_waits.fetch_add(1, Ordering::Relaxed);
+                    }
+                    self.retries.fetch_add(1, Ordering::Relaxed);
+                    self.total_wait_ms.fetch_add(
+                        u64::try_from(delay.as_millis()).unwrap_or(u64::MAX),
+                        Ordering::Relaxed,
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
+
+/// Classify a raw GitHub response as rate limited.
+///
+/// Returns `Some(Error::SecondaryRateLimit)` for abuse-detection responses and
+/// `Some(Error::RateLimit)` for exhausted primary quotas; `None` for any other
+/// response so the caller can apply its own status handling.
+#[must_use]
+pub fn detect_rate_limit(status: u16, headers: &HeaderMap, body: &str) -> Option<Error> {
+    if status != 403 && status != 429 {
+        return None;
+    }
+
+    let body_lower = body.to_lowercase();
+    // A 403 mentioning "rate limit" is a secondary limit unless the primary
+    // quota headers show the hourly allowance is exhausted.
+    if body_lower.contains("secondary rate limit")
+        || (github_bot_sdk::client::detect_secondary_rate_limit(status, body)
+            && !primary_quota_exhausted(headers))
+    {
+        return Some(Error::SecondaryRateLimit {
+            retry_after: retry_after_seconds(headers),
+        });
+    }
+
+    if status == 429 || primary_quota_exhausted(headers) {
+        return Some(Error::RateLimit);
+    }
+
+    None
+}
+
+fn primary_quota_exhausted(headers: &HeaderMap) -> bool {
+    headers
+        .get("x-ratelimit-remaining")
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.trim() == "0")
+}
+
+fn retry_after_seconds(headers: &HeaderMap) -> Option<u64> {
+    header_value(headers, reqwest::header::RETRY_AFTER.as_str())
+}
+
+fn header_value<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse().ok())
+}
+
+fn exponential(base: Duration, attempt: u32, max: Duration) -> Duration {
+    base.saturating_mul(2u32.saturating_pow(attempt)).min(max)
+}
+
+#[cfg(test)]
+#[path = "rate_limiter_tests.rs"]
+mod tests;
@@ -0,0 +1,421 @@
+use super::*;
+use reqwest::header::HeaderValue;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Instant;
+
+/// Policy with millisecond delays so retry tests run quickly.
+fn fast_policy() -> RetryPolicy {
+    RetryPolicy {
+        max_retries: 3,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(4),
+        secondary_rate_limit_base_delay: Duration::from_millis(25),
+        secondary_rate_limit_max_delay: Duration::from_millis(100),
+    }
+}
+
+fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+    let mut map = HeaderMap::new();
+    for (name, value) in pairs {
+        map.insert(*name, HeaderValue::from_static(value));
+    }
+    map
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// RetryPolicy
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_retry_policy_default_matches_spec() {
+    let policy = RetryPolicy::default();
+
+    assert_eq!(policy.max_retries, 5);
+    assert_eq!(policy.base_delay, Duration::from_millis(100));
+    assert_eq!(policy.max_delay, Duration::from_secs(30));
+    assert_eq!(
+        policy.secondary_rate_limit_base_delay,
+        Duration::from_secs(60)
+    );
+}
+
+#[test]
+fn test_delay_for_secondary_rate_limit_is_more_conservative_than_primary() {
+    let policy = RetryPolicy::default();
+
+    let primary = policy.delay_for(&Error::RateLimit, 0);
+    let secondary = policy.delay_for(&Error::SecondaryRateLimit { retry_after: None }, 0);
+
+    assert_eq!(secondary, Duration::from_secs(60));
+    assert!(secondary > primary);
+}
+
+#[test]
+fn test_delay_for_secondary_rate_limit_grows_and_is_capped() {
+    let policy = fast_policy();
+    let error = Error::SecondaryRateLimit { retry_after: None };
+
+    assert_eq!(policy.delay_for(&error, 0), Duration::from_millis(25));
+    assert_eq!(policy.delay_for(&error, 1), Duration::from_millis(50));
+    assert_eq!(policy.delay_for(&error, 5), Duration::from_millis(100));
+}
+
+#[test]
+fn test_delay_for_secondary_rate_limit_honours_longer_retry_after() {
+    let policy = RetryPolicy::default();
+
+    let delay = policy.delay_for(
+        &Error::SecondaryRateLimit {
+            retry_after: Some(120),
+        },
+        0,
+    );
+
+    assert_eq!(delay, Duration::from_secs(120));
+}
+
+#[test]
+fn test_delay_for_secondary_rate_limit_ignores_shorter_retry_after() {
+    let policy = RetryPolicy::default();
+
+    let delay = policy.delay_for(
+        &Error::SecondaryRateLimit {
+            retry_after: Some(5),
+        },
+        0,
+    );
+
+    assert_eq!(delay, Duration::from_secs(60));
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// RateLimiter::should_retry_error
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_should_retry_error_for_rate_limits_and_network_errors() {
+    let limiter = RateLimiter::default();
+
+    assert!(limiter.should_retry_error(&Error::RateLimit));
+    assert!(limiter.should_retry_error(&Error::SecondaryRateLimit { retry_after: None }));
+    assert!(limiter.should_retry_error(&Error::Network {
+        message: "connection reset".to_string(),
+        source: None,
+    }));
+    assert!(limiter.should_retry_error(&Error::Timeout {
+        operation: "graphql".to_string(),
+        timeout: Some(Duration::from_secs(5)),
+    }));
+}
+
+#[test]
+fn test_should_not_retry_permanent_errors() {
+    let limiter = RateLimiter::default();
+
+    assert!(!limiter.should_retry_error(&Error::NotFound {
+        resource: "repo".to_string(),
+    }));
+    assert!(!limiter.should_retry_error(&Error::Api {
+        message: "validation failed".to_string(),
+        source: None,
+    }));
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// RateLimiter::execute_with_retry
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_execute_with_retry_retries_secondary_rate_limit_with_secondary_backoff() {
+    let limiter = RateLimiter::new(fast_policy());
+    let attempts = AtomicU32::new(0);
+
+    let started = Instant::now();
+    let result = limiter
+        .execute_with_retry("test", || async {
+            if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(Error::SecondaryRateLimit { retry_after: None })
+            } else {
+                Ok(42)
+            }
+        })
+        .await;
+
+    assert_eq!(result.unwrap(), 42);
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    assert!(
+        started.elapsed() >= Duration::from_millis(25),
+        "secondary rate limit retry should wait for the secondary base delay"
+    );
+}
+
+#[tokio::test]
+async fn test_execute_with_retry_returns_secondary_rate_limit_after_exhausting_retries() {
+    let limiter = RateLimiter::new(RetryPolicy {
+        max_retries: 1,
+        ..fast_policy()
+    });
+    let attempts = AtomicU32::new(0);
+
+    let result: GitHubResult<()> = limiter
+        .execute_with_retry("test", || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(Error::SecondaryRateLimit {
+                retry_after: Some(0),
+            })
+        })
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(Error::SecondaryRateLimit {
+            retry_after: Some(0)
+        })
+    ));
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_execute_with_retry_does_not_retry_permanent_errors() {
+    let limiter = RateLimiter::new(fast_policy());
+    let attempts = AtomicU32::new(0);
+
+    let result: GitHubResult<()> = limiter
+        .execute_with_retry("test", || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(Error::NotFound {
+                resource: "repo".to_string(),
+            })
+        })
+        .await;
+
+    assert!(matches!(result, Err(Error::NotFound { .. })));
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_execute_with_timeout_times_out_slow_attempts_and_retries_them() {
+    let limiter = RateLimiter::new(RetryPolicy {
+        max_retries: 2,
+        ..fast_policy()
+    });
+    let attempts = AtomicU32::new(0);
+
+    let result = limiter
+        .execute_with_timeout("slow", Some(Duration::from_millis(20)), || async {
+            if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+            }
+            Ok(7)
+        })
+        .await;
+
+    assert_eq!(result.unwrap(), 7);
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    assert_eq!(limiter.metrics_snapshot().retries, 1);
+}
+
+#[tokio::test]
+async fn test_execute_with_timeout_returns_timeout_after_exhausting_retries() {
+    let limiter = RateLimiter::new(RetryPolicy {
+        max_retries: 1,
+        ..fast_policy()
+    });
+
+    let result: GitHubResult<()> = limiter
+        .execute_with_timeout("slow", Some(Duration::from_millis(10)), || async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok(())
+        })
+        .await;
+
+    match result {
+        Err(Error::Timeout { operation, timeout }) => {
+            assert_eq!(operation, "slow");
+            assert_eq!(timeout, Some(Duration::from_millis(10)));
+        }
+        other => panic!("expected a timeout, got {other:?}"),
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// detect_rate_limit
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_detect_rate_limit_secondary_message_with_retry_after() {
+    let error = detect_rate_limit(
+        403,
+        &headers(&[("retry-after", "90")]),
+        r#"{"message":"You have exceeded a secondary rate limit. Please wait a few minutes before you try again."}"#,
+    );
+
+    assert!(matches!(
+        error,
+        Some(Error::SecondaryRateLimit {
+            retry_after: Some(90)
+        })
+    ));
+}
+
+#[test]
+fn test_detect_rate_limit_abuse_message_without_retry_after() {
+    let error = detect_rate_limit(
+        403,
+        &HeaderMap::new(),
+        r#"{"message":"You have triggered an abuse detection mechanism."}"#,
+    );
+
+    assert!(matches!(
+        error,
+        Some(Error::SecondaryRateLimit { retry_after: None })
+    ));
+}
+
+#[test]
+fn test_detect_rate_limit_secondary_message_on_429() {
+    let error = detect_rate_limit(
+        429,
+        &HeaderMap::new(),
+        "You have exceeded a secondary rate limit",
+    );
+
+    assert!(matches!(error, Some(Error::SecondaryRateLimit { .. })));
+}
+
+#[test]
+fn test_detect_rate_limit_primary_quota_exhausted() {
+    let error = detect_rate_limit(
+        403,
+        &headers(&[("x-ratelimit-remaining", "0")]),
+        r#"{"message":"API rate limit exceeded for installation ID 1."}"#,
+    );
+
+    assert!(matches!(error, Some(Error::RateLimit)));
+}
+
+#[test]
+fn test_detect_rate_limit_plain_forbidden_is_not_rate_limited() {
+    let error = detect_rate_limit(403, &HeaderMap::new(), "Forbidden");
+
+    assert!(error.is_none());
+}
+
+#[test]
+fn test_detect_rate_limit_ignores_other_statuses() {
+    let error = detect_rate_limit(404, &HeaderMap::new(), "secondary rate limit");
+
+    assert!(error.is_none());
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Metrics
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_metrics_snapshot_starts_empty() {
+    let limiter = RateLimiter::default();
+
+    assert_eq!(limiter.metrics_snapshot(), RateLimitMetrics::default());
+}
+
+#[tokio::test]
+async fn test_metrics_snapshot_counts_requests_retries_and_waits_across_calls() {
+    let limiter = RateLimiter::new(fast_policy());
+    let attempts = AtomicU32::new(0);
+
+    // First call: one secondary rate limit, then success.
+    limiter
+        .execute_with_retry("first", || async {
+            if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(Error::SecondaryRateLimit { retry_after: None })
+            } else {
+                Ok(())
+            }
+        })
+        .await
+        .unwrap();
+
+    // Second call: one network failure, then success.
+    attempts.store(0, Ordering::SeqCst);
+    limiter
+        .execute_with_retry("second", || async {
+            if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(Error::Network {
+                    message: "connection reset".to_string(),
+                    source: None,
+                })
+            } else {
+                Ok(())
+            }
+        })
+        .await
+        .unwrap();
+
+    // Third call: immediate success.
+    limiter
+        .execute_with_retry("third", || async { Ok(()) })
+        .await
+        .unwrap();
+
+    let metrics = limiter.metrics_snapshot();
+    assert_eq!(metrics.total_requests, 5);
+    assert_eq!(metrics.retries, 2);
+    assert_eq!(metrics.rate_limit_waits, 1);
+    assert_eq!(metrics.total_wait, Duration::from_millis(26));
+}
+
+#[tokio::test]
+async fn test_metrics_snapshot_counts_failed_attempts_when_retries_exhausted() {
+    let limiter = RateLimiter::new(RetryPolicy {
+        max_retries: 2,
+        ..fast_policy()
+    });
+
+    let result: GitHubResult<()> = limiter
+        .execute_with_retry("test", || async { Err(Error::RateLimit) })
+        .await;
+
+    assert!(result.is_err());
+    let metrics = limiter.metrics_snapshot();
+    assert_eq!(metrics.total_requests, 3);
+    assert_eq!(metrics.retries, 2);
+    assert_eq!(metrics.rate_limit_waits, 2);
+}
+
+#[test]
+fn test_record_response_headers_updates_rate_limit_info() {
+    let limiter = RateLimiter::default();
+
+    limiter.record_response_headers(&headers(&[
+        ("x-ratelimit-limit", "5000"),
+        ("x-ratelimit-remaining", "4990"),
+        ("x-ratelimit-used", "10"),
+        ("x-ratelimit-reset", "1700000000"),
+    ]));
+
+    let info = limiter.metrics_snapshot().rate_limit.unwrap();
+    assert_eq!(info.limit, 5000);
+    assert_eq!(info.remaining, 4990);
+    assert_eq!(info.used, 10);
+    assert_eq!(info.reset_at.timestamp(), 1_700_000_000);
+}
+
+#[test]
+fn test_record_response_headers_without_rate_limit_headers_keeps_last_value() {
+    let limiter = RateLimiter::default();
+    limiter.record_response_headers(&headers(&[
+        ("x-ratelimit-limit", "5000"),
+        ("x-ratelimit-remaining", "4000"),
+        ("x-ratelimit-reset", "1700000000"),
+    ]));
+
+    limiter.record_response_headers(&HeaderMap::new());
+
+    let info = limiter.metrics_snapshot().rate_limit.unwrap();
+    assert_eq!(info.remaining, 4000);
+    assert_eq!(
+        info.used, 1000,
+        "used should be derived when header is absent"
+    );
+}
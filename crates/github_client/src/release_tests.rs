@@ -1,10 +1,562 @@
-// Note: Release operations use SDK's CreateReleaseRequest/UpdateReleaseRequest
-// These are tested via integration tests with the SDK.
-// Unit tests would require mocking the SDK client which is deferred to integration testing.
-
-#[test]
-fn test_placeholder() {
-    // Placeholder to maintain file structure
-    // Release operations tested via GitHubOperations trait integration tests
-    assert!(true);
+// Tests for release operations (`get_latest_release`, `list_releases`,
+// `upload_release_asset`, `create_signed_tag`).
+// Uses wiremock to provide a local mock GitHub API server so no real credentials are needed.
+
+use super::*;
+use github_bot_sdk::{
+    auth::{
+        AuthenticationProvider, Installation, InstallationId, InstallationPermissions,
+        InstallationToken, JsonWebToken, Repository as SdkRepository,
+    },
+    error::AuthError,
+};
+use wiremock::{
+    matchers::{body_bytes, body_json, header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+// ---------------------------------------------------------------------------
+// Minimal mock auth provider (no real credentials needed)
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+struct MockAuthProvider;
+
+#[async_trait::async_trait]
+impl AuthenticationProvider for MockAuthProvider {
+    async fn app_token(&self) -> Result<JsonWebToken, AuthError> {
+        Err(AuthError::TokenGenerationFailed {
+            message: "not implemented for mock".into(),
+        })
+    }
+
+    async fn installation_token(
+        &self,
+        installation_id: InstallationId,
+    ) -> Result<InstallationToken, AuthError> {
+        let expires_at = chrono::Utc::now() + chrono::Duration::hours(1);
+        Ok(InstallationToken::new(
+            "fake-token".to_string(),
+            installation_id,
+            expires_at,
+            InstallationPermissions::default(),
+            Vec::new(),
+        ))
+    }
+
+    async fn refresh_installation_token(
+        &self,
+        installation_id: InstallationId,
+    ) -> Result<InstallationToken, AuthError> {
+        self.installation_token(installation_id).await
+    }
+
+    async fn list_installations(&self) -> Result<Vec<Installation>, AuthError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_installation_repositories(
+        &self,
+        _installation_id: InstallationId,
+    ) -> Result<Vec<SdkRepository>, AuthError> {
+        Ok(Vec::new())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn make_client(server: &MockServer) -> GitHubClient {
+    GitHubClient::new_for_testing(MockAuthProvider, 1, &server.uri())
+        .expect("test client construction should not fail")
+}
+
+fn release_json(id: u64, tag: &str, draft: bool, prerelease: bool) -> serde_json::Value {
+    let published_at = if draft {
+        serde_json::Value::Null
+    } else {
+        serde_json::Value::from("2024-01-01T00:00:00Z")
+    };
+    serde_json::json!({
+        "id": id,
+        "node_id": format!("RE_{id}"),
+        "tag_name": tag,
+        "target_commitish": "main",
+        "name": tag,
+        "body": "notes",
+        "draft": draft,
+        "prerelease": prerelease,
+        "author": {
+            "login": "octocat",
+            "id": 1,
+            "node_id": "U_1",
+            "type": "User"
+        },
+        "created_at": "2024-01-01T00:00:00Z",
+        "published_at": published_at,
+        "url": format!("https://api.github.com/repos/owner/repo/releases/{id}"),
+        "html_url": format!("https://github.com/owner/repo/releases/tag/{tag}"),
+        "assets": []
+    })
+}
+
+fn not_found() -> ResponseTemplate {
+    ResponseTemplate::new(404).set_body_json(serde_json::json!({ "message": "Not Found" }))
+}
+
+// ---------------------------------------------------------------------------
+// get_latest_release
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_get_latest_release_returns_release() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/releases/latest"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(release_json(7, "v1.2.0", false, false)),
+        )
+        .mount(&server)
+        .await;
+
+    let release = make_client(&server)
+        .get_latest_release("owner", "repo")
+        .await
+        .expect("request should succeed")
+        .expect("a release should be returned");
+
+    assert_eq!(release.id, 7);
+    assert_eq!(release.tag_name, "v1.2.0");
+    assert!(!release.draft);
+}
+
+/// GitHub answers `/releases/latest` with 404 when the repository has no
+/// releases at all.
+#[tokio::test]
+async fn test_get_latest_release_with_zero_releases_returns_none() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/releases/latest"))
+        .respond_with(not_found())
+        .mount(&server)
+        .await;
+
+    let result = make_client(&server)
+        .get_latest_release("owner", "repo")
+        .await
+        .expect("404 should not be an error");
+
+    assert!(result.is_none());
+}
+
+/// Drafts are never reported by `/releases/latest`, so a repository holding
+/// only draft releases also yields 404 and therefore `None`.
+#[tokio::test]
+async fn test_get_latest_release_with_draft_only_releases_returns_none() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/releases"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([release_json(1, "v1.0.0", true, false)])),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/releases/latest"))
+        .respond_with(not_found())
+        .mount(&server)
+        .await;
+
+    let client = make_client(&server);
+    let latest = client
+        .get_latest_release("owner", "repo")
+        .await
+        .expect("404 should not be an error");
+    let all = client
+        .list_releases("owner", "repo", None, None)
+        .await
+        .expect("list should succeed");
+
+    assert!(latest.is_none());
+    assert_eq!(all.len(), 1);
+    assert!(all[0].draft);
+}
+
+// ---------------------------------------------------------------------------
+// list_releases
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_list_releases_with_zero_releases_returns_empty() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/releases"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .mount(&server)
+        .await;
+
+    let releases = make_client(&server)
+        .list_releases("owner", "repo", None, None)
+        .await
+        .expect("list should succeed");
+
+    assert!(releases.is_empty());
+}
+
+#[tokio::test]
+async fn test_list_releases_without_page_follows_link_header() {
+    let server = MockServer::start().await;
+    let next = format!(
+        "<{}/repos/owner/repo/releases?per_page=100&page=2>; rel=\"next\"",
+        server.uri()
+    );
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/releases"))
+        .and(query_param("page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Link", next.as_str())
+                .set_body_json(serde_json::json!([release_json(2, "v2.0.0", false, false)])),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/releases"))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([release_json(1, "v1.0.0", false, false)])),
+        )
+        .mount(&server)
+        .await;
+
+    let releases = make_client(&server)
+        .list_releases("owner", "repo", None, None)
+        .await
+        .expect("list should succeed");
+
+    let tags: Vec<&str> = releases.iter().map(|r| r.tag_name.as_str()).collect();
+    assert_eq!(tags, vec!["v2.0.0", "v1.0.0"]);
+}
+
+#[tokio::test]
+async fn test_list_releases_with_explicit_page_returns_only_that_page() {
+    let server = MockServer::start().await;
+    let next = format!(
+        "<{}/repos/owner/repo/releases?per_page=1&page=3>; rel=\"next\"",
+        server.uri()
+    );
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/releases"))
+        .and(query_param("per_page", "1"))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Link", next.as_str())
+                .set_body_json(serde_json::json!([release_json(5, "v0.5.0", false, true)])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let releases = make_client(&server)
+        .list_releases("owner", "repo", Some(1), Some(2))
+        .await
+        .expect("list should succeed");
+
+    assert_eq!(releases.len(), 1);
+    assert_eq!(releases[0].tag_name, "v0.5.0");
+    assert!(releases[0].prerelease);
+}
+
+#[tokio::test]
+async fn test_list_releases_rejects_invalid_pagination() {
+    let server = MockServer::start().await;
+    let client = make_client(&server);
+
+    let zero_per_page = client.list_releases("owner", "repo", Some(0), None).await;
+    let too_large = client.list_releases("owner", "repo", Some(101), None).await;
+    let zero_page = client.list_releases("owner", "repo", None, Some(0)).await;
+
+    for result in [zero_per_page, too_large, zero_page] {
+        assert!(
+            matches!(result, Err(CoreError::InvalidInput { .. })),
+            "expected InvalidInput, got {result:?}"
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// upload_release_asset
+// ---------------------------------------------------------------------------
+
+/// Mount the release lookup with an `upload_url` template pointing back at
+/// the mock server, as GitHub does with `uploads.github.com`.
+async fn mount_release_with_upload_url(server: &MockServer, id: u64) {
+    let mut release = release_json(id, "v1.0.0", false, false);
+    release["upload_url"] = serde_json::Value::from(format!(
+        "{}/uploads/repos/owner/repo/releases/{id}/assets{{?name,label}}",
+        server.uri()
+    ));
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/owner/repo/releases/{id}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(release))
+        .mount(server)
+        .await;
+}
+
+fn asset_json(name: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": 1,
+        "name": name,
+        "state": "uploaded",
+        "browser_download_url": format!("https://github.com/owner/repo/releases/download/v1.0.0/{name}")
+    })
+}
+
+#[tokio::test]
+async fn test_upload_release_asset_returns_download_url() {
+    let server = MockServer::start().await;
+    mount_release_with_upload_url(&server, 7).await;
+    Mock::given(method("POST"))
+        .and(path("/uploads/repos/owner/repo/releases/7/assets"))
+        .and(query_param("name", "app v1.zip"))
+        .and(header("content-type", "application/x-custom"))
+        .and(header("authorization", "Bearer fake-token"))
+        .and(body_bytes(b"payload".to_vec()))
+        .respond_with(ResponseTemplate::new(201).set_body_json(asset_json("app.v1.zip")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let url = make_client(&server)
+        .upload_release_asset(
+            "owner",
+            "repo",
+            7,
+            "app v1.zip",
+            Some("application/x-custom"),
+            b"payload".to_vec(),
+        )
+        .await
+        .expect("upload should succeed");
+
+    assert_eq!(
+        url,
+        "https://github.com/owner/repo/releases/download/v1.0.0/app.v1.zip"
+    );
+}
+
+#[tokio::test]
+async fn test_upload_release_asset_infers_content_type_from_extension() {
+    let server = MockServer::start().await;
+    mount_release_with_upload_url(&server, 7).await;
+    for (name, content_type) in [
+        ("app.tar.gz", "application/gzip"),
+        ("checksums.TXT", "text/plain"),
+        ("release-regent", "application/octet-stream"),
+    ] {
+        Mock::given(method("POST"))
+            .and(path("/uploads/repos/owner/repo/releases/7/assets"))
+            .and(query_param("name", name))
+            .and(header("content-type", content_type))
+            .respond_with(ResponseTemplate::new(201).set_body_json(asset_json(name)))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let client = make_client(&server);
+    for name in ["app.tar.gz", "checksums.TXT", "release-regent"] {
+        client
+            .upload_release_asset("owner", "repo", 7, name, None, vec![0u8; 16])
+            .await
+            .unwrap_or_else(|e| panic!("upload of {name} should succeed: {e:?}"));
+    }
+}
+
+#[tokio::test]
+async fn test_upload_release_asset_duplicate_name_returns_conflict() {
+    let server = MockServer::start().await;
+    mount_release_with_upload_url(&server, 7).await;
+    Mock::given(method("POST"))
+        .and(path("/uploads/repos/owner/repo/releases/7/assets"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+            "message": "Validation Failed",
+            "errors": [{ "resource": "ReleaseAsset", "code": "already_exists", "field": "name" }]
+        })))
+        .mount(&server)
+        .await;
+
+    let result = make_client(&server)
+        .upload_release_asset("owner", "repo", 7, "app.zip", None, vec![1, 2, 3])
+        .await;
+
+    assert!(
+        matches!(result, Err(CoreError::Conflict { .. })),
+        "expected Conflict, got {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_upload_release_asset_retries_secondary_rate_limit() {
+    let server = MockServer::start().await;
+    mount_release_with_upload_url(&server, 7).await;
+    Mock::given(method("POST"))
+        .and(path("/uploads/repos/owner/repo/releases/7/assets"))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .insert_header("retry-after", "0")
+                .set_body_json(serde_json::json!({
+                    "message": "You have exceeded a secondary rate limit."
+                })),
+        )
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/uploads/repos/owner/repo/releases/7/assets"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(asset_json("app.zip")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = make_client(&server).with_retry_policy(RetryPolicy {
+        max_retries: 1,
+        secondary_rate_limit_base_delay: StdDuration::from_millis(1),
+        secondary_rate_limit_max_delay: StdDuration::from_millis(1),
+        ..RetryPolicy::default()
+    });
+    let url = client
+        .upload_release_asset("owner", "repo", 7, "app.zip", None, vec![1])
+        .await
+        .expect("upload should succeed after retry");
+
+    assert!(url.ends_with("/app.zip"));
+    assert_eq!(client.rate_limiter().metrics_snapshot().retries, 1);
+}
+
+#[tokio::test]
+async fn test_upload_release_asset_unknown_release_returns_not_found() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/releases/99"))
+        .respond_with(not_found())
+        .mount(&server)
+        .await;
+
+    let result = make_client(&server)
+        .upload_release_asset("owner", "repo", 99, "app.zip", None, vec![1])
+        .await;
+
+    assert!(
+        matches!(result, Err(CoreError::NotFound { .. })),
+        "expected NotFound, got {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_upload_release_asset_rejects_empty_name() {
+    let server = MockServer::start().await;
+
+    let result = make_client(&server)
+        .upload_release_asset("owner", "repo", 7, "  ", None, vec![1])
+        .await;
+
+    assert!(matches!(result, Err(CoreError::InvalidInput { .. })));
+}
+
+// ---------------------------------------------------------------------------
+// create_signed_tag
+// ---------------------------------------------------------------------------
+
+fn signed_tag_params() -> SignedTagParams {
+    SignedTagParams {
+        commit_sha: "abc123".to_string(),
+        message: "Release v1.2.3".to_string(),
+        signature: "-----BEGIN SSH SIGNATURE-----\nAAAA\n-----END SSH SIGNATURE-----\n".to_string(),
+        tagged_at: chrono::DateTime::parse_from_rfc3339("2024-01-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc),
+        tagger: GitHubUser {
+            name: "Release Regent".to_string(),
+            email: "releases@example.com".to_string(),
+            login: None,
+        },
+        tag_name: "v1.2.3".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_create_signed_tag_creates_tag_object_then_ref() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/git/tags"))
+        .and(body_json(serde_json::json!({
+            "tag": "v1.2.3",
+            "message": "Release v1.2.3\n-----BEGIN SSH SIGNATURE-----\nAAAA\n-----END SSH SIGNATURE-----\n",
+            "object": "abc123",
+            "type": "commit",
+            "tagger": {
+                "name": "Release Regent",
+                "email": "releases@example.com",
+                "date": "2024-01-01T12:00:00Z"
+            }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({ "sha": "tag456" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/git/refs"))
+        .and(body_json(serde_json::json!({ "ref": "refs/tags/v1.2.3", "sha": "tag456" })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "ref": "refs/tags/v1.2.3",
+            "node_id": "REF_1",
+            "url": "https://api.github.com/repos/owner/repo/git/refs/tags/v1.2.3",
+            "object": { "sha": "tag456", "type": "tag", "url": "https://api.github.com/repos/owner/repo/git/tags/tag456" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let tag = make_client(&server)
+        .create_signed_tag("owner", "repo", signed_tag_params())
+        .await
+        .unwrap();
+
+    assert_eq!(tag.name, "v1.2.3");
+    assert_eq!(tag.commit_sha, "abc123");
+    assert_eq!(tag.message.as_deref(), Some("Release v1.2.3"));
+}
+
+#[tokio::test]
+async fn test_create_signed_tag_existing_ref_returns_not_supported() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/git/tags"))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(serde_json::json!({ "sha": "tag456" })),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/git/refs"))
+        .respond_with(
+            ResponseTemplate::new(422)
+                .set_body_json(serde_json::json!({ "message": "Reference already exists" })),
+        )
+        .mount(&server)
+        .await;
+
+    let result = make_client(&server)
+        .create_signed_tag("owner", "repo", signed_tag_params())
+        .await;
+
+    assert!(
+        matches!(result, Err(CoreError::NotSupported { .. })),
+        "expected NotSupported, got {result:?}"
+    );
 }
@@ -0,0 +1,151 @@
+//! Concurrency limit for webhook request intake.
+//!
+//! A burst of deliveries would otherwise all be read, validated and dispatched
+//! at once. [`IntakeLimiter`] lets at most `limit` webhook requests through
+//! this intake at a time. Requests beyond that wait in a short queue; once
+//! `queue_depth` requests are already waiting, or a request has waited for
+//! `queue_timeout`, further requests are turned away with HTTP 503 and a
+//! `Retry-After` header. GitHub neither retries failed deliveries nor honours
+//! `Retry-After`, so a rejected delivery is lost unless it is redelivered by
+//! hand or through the redeliver API.
+//!
+//! A slot is held only until the HTTP response is built. Dispatch hands the
+//! event to the event loop's bounded channel and returns, so the processing
+//! of accepted events is not limited here: the single event loop processes
+//! them one at a time. Backpressure from that channel is applied separately:
+//! a delivery that finds no room in it gets the same HTTP 503 (see
+//! [`EventChannel`](crate::handler::EventChannel)).
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+#[cfg(test)]
+#[path = "concurrency_limit_tests.rs"]
+mod tests;
+
+/// Default number of webhook requests taken in at the same time.
+pub const DEFAULT_CONCURRENCY_LIMIT: usize = 32;
+
+/// Default number of webhook requests that may wait for a free slot.
+pub const DEFAULT_QUEUE_DEPTH: usize = 64;
+
+/// How long a queued request waits for a free slot before it is turned away.
+pub const DEFAULT_QUEUE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Value of the `Retry-After` header sent with a rejected request.
+pub const RETRY_AFTER: Duration = Duration::from_secs(10);
+
+/// Why a request did not get a slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overloaded {
+    /// `queue_depth` requests were already waiting.
+    QueueFull,
+    /// The request waited `queue_timeout` without getting a slot.
+    QueueTimeout,
+}
+
+impl std::fmt::Display for Overloaded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::QueueFull => write!(f, "too many webhook requests are waiting"),
+            Self::QueueTimeout => write!(f, "timed out waiting for a free webhook slot"),
+        }
+    }
+}
+
+/// Limits the number of webhook requests taken in at the same time.
+///
+/// Taking a request in covers reading its body, verifying it and handing the
+/// event to the event loop; see the [module documentation](self).
+#[derive(Debug)]
+pub struct IntakeLimiter {
+    semaphore: Arc<Semaphore>,
+    limit: usize,
+    queue_depth: usize,
+    queue_timeout: Duration,
+    queued: AtomicUsize,
+}
+
+impl IntakeLimiter {
+    /// Allow `limit` concurrent requests, with up to `queue_depth` more
+    /// waiting for at most [`DEFAULT_QUEUE_TIMEOUT`].
+    ///
+    /// A `limit` of zero is treated as one.
+    #[must_use]
+    pub fn new(limit: usize, queue_depth: usize) -> Self {
+        let limit = limit.max(1);
+        Self {
+            semaphore: Arc::new(Semaphore::new(limit)),
+            limit,
+            queue_depth,
+            queue_timeout: DEFAULT_QUEUE_TIMEOUT,
+            queued: AtomicUsize::new(0),
+        }
+    }
+
+    /// Set how long a queued request waits for a free slot.
+    #[must_use]
+    pub fn with_queue_timeout(mut self, queue_timeout: Duration) -> Self {
+        self.queue_timeout = queue_timeout;
+        self
+    }
+
+    /// The number of requests taken in at the same time.
+    #[must_use]
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// The number of requests that may wait for a free slot.
+    #[must_use]
+    pub fn queue_depth(&self) -> usize {
+        self.queue_depth
+    }
+
+    /// How long a queued request waits for a free slot.
+    #[must_use]
+    pub fn queue_timeout(&self) -> Duration {
+        self.queue_timeout
+    }
+
+    /// Wait for a free slot.
+    ///
+    /// The slot is released when the returned permit is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Overloaded`] when the queue is full or the wait times out.
+    pub async fn acquire(&self) -> Result<OwnedSemaphorePermit, Overloaded> {
+        if let Ok(permit) = self.semaphore.clone().try_acquire_owned() {
+            return Ok(permit);
+        }
+
+        let queued = self.queued.fetch_add(1, Ordering::AcqRel);
+        let _queued = QueuedGuard(&self.queued);
+        if queued >= self.queue_depth {
+            return Err(Overloaded::QueueFull);
+        }
+
+        match tokio::time::timeout(self.queue_timeout, self.semaphore.clone().acquire_owned()).await
+        {
+            Ok(Ok(permit)) => Ok(permit),
+            // The semaphore is never closed, so only the timeout can fail.
+            Ok(Err(_)) | Err(_) => Err(Overloaded::QueueTimeout),
+        }
+    }
+}
+
+/// Decrements the queued request count when a request leaves the queue.
+struct QueuedGuard<'a>(&'a AtomicUsize);
+
+impl Drop for QueuedGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
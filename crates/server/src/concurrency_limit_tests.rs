@@ -0,0 +1,114 @@
+use super::*;
+use tokio::sync::watch;
+
+/// Yield until `condition` holds, letting spawned tasks run.
+async fn wait_until(condition: impl Fn() -> bool) {
+    while !condition() {
+        tokio::task::yield_now().await;
+    }
+}
+
+#[tokio::test]
+async fn test_requests_within_the_limit_are_not_queued() {
+    let limiter = IntakeLimiter::new(2, 0);
+
+    let first = limiter.acquire().await;
+    let second = limiter.acquire().await;
+
+    assert!(first.is_ok());
+    assert!(second.is_ok());
+}
+
+#[tokio::test]
+async fn test_zero_limit_is_treated_as_one() {
+    let limiter = IntakeLimiter::new(0, 0);
+
+    assert_eq!(limiter.limit(), 1);
+    assert!(limiter.acquire().await.is_ok());
+}
+
+#[tokio::test]
+async fn test_request_beyond_the_queue_depth_is_rejected() {
+    let limiter = Arc::new(IntakeLimiter::new(1, 1));
+    let held = limiter.acquire().await.unwrap();
+
+    let queued = tokio::spawn({
+        let limiter = limiter.clone();
+        async move { limiter.acquire().await.is_ok() }
+    });
+    wait_until(|| limiter.queued.load(Ordering::Acquire) == 1).await;
+
+    assert_eq!(limiter.acquire().await.unwrap_err(), Overloaded::QueueFull);
+
+    drop(held);
+    assert!(
+        queued.await.unwrap(),
+        "queued request gets the released slot"
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_queued_request_times_out() {
+    let limiter = IntakeLimiter::new(1, 4).with_queue_timeout(Duration::from_secs(1));
+    let _held = limiter.acquire().await.unwrap();
+
+    assert_eq!(
+        limiter.acquire().await.unwrap_err(),
+        Overloaded::QueueTimeout
+    );
+    // The timed-out request has left the queue.
+    assert_eq!(limiter.queued.load(Ordering::Acquire), 0);
+}
+
+#[tokio::test]
+async fn test_concurrent_burst_beyond_the_limit_is_throttled() {
+    const LIMIT: usize = 3;
+    const QUEUE_DEPTH: usize = 2;
+    const REQUESTS: usize = 10;
+
+    let limiter = Arc::new(IntakeLimiter::new(LIMIT, QUEUE_DEPTH));
+    let running = Arc::new(AtomicUsize::new(0));
+    let max_running = Arc::new(AtomicUsize::new(0));
+    let rejected = Arc::new(AtomicUsize::new(0));
+    // Admitted requests keep their slot until the whole burst has arrived.
+    let (release, released) = watch::channel(false);
+
+    let tasks: Vec<_> = (0..REQUESTS)
+        .map(|_| {
+            let (limiter, running, max_running, rejected, mut released) = (
+                limiter.clone(),
+                running.clone(),
+                max_running.clone(),
+                rejected.clone(),
+                released.clone(),
+            );
+            tokio::spawn(async move {
+                let Ok(_permit) = limiter.acquire().await else {
+                    rejected.fetch_add(1, Ordering::AcqRel);
+                    return;
+                };
+                let now = running.fetch_add(1, Ordering::AcqRel) + 1;
+                max_running.fetch_max(now, Ordering::AcqRel);
+                released.wait_for(|released| *released).await.unwrap();
+                running.fetch_sub(1, Ordering::AcqRel);
+            })
+        })
+        .collect();
+
+    wait_until(|| {
+        running.load(Ordering::Acquire) == LIMIT
+            && limiter.queued.load(Ordering::Acquire) == QUEUE_DEPTH
+            && rejected.load(Ordering::Acquire) == REQUESTS - LIMIT - QUEUE_DEPTH
+    })
+    .await;
+    release.send(true).unwrap();
+    for task in tasks {
+        task.await.unwrap();
+    }
+
+    assert_eq!(
+        rejected.load(Ordering::Acquire),
+        REQUESTS - LIMIT - QUEUE_DEPTH
+    );
+    assert_eq!(max_running.load(Ordering::Acquire), LIMIT);
+}
@@ -1,3 +1,10 @@
+use axum::{
+    http::{header::RETRY_AFTER, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use release_regent_core::CoreError;
+use std::time::Duration;
 use thiserror::Error;
 
 #[cfg(test)]
@@ -46,6 +53,11 @@ pub enum Error {
     #[error("Environment configuration error: {variable} - {message}")]
     Environment { variable: String, message: String },
 
+    /// The request was refused before its signature was checked, e.g. because
+    /// it does not come from GitHub.
+    #[error("Request forbidden: {message}")]
+    Forbidden { message: String },
+
     /// GitHub client errors returned directly from [`release_regent_github_client`].
     ///
     /// Note: most GitHub errors reach the server as [`Error::Core`] (wrapped in a
@@ -68,6 +80,28 @@ pub enum Error {
         #[from]
         source: serde_json::Error,
     },
+
+    /// The webhook request could not be read: missing headers, an unreadable
+    /// body or a payload that is not JSON.
+    #[error("Malformed webhook request: {message}")]
+    MalformedPayload { message: String },
+
+    /// The request body is larger than the configured limit of `limit` bytes.
+    #[error("Payload too large: the limit is {limit} bytes")]
+    PayloadTooLarge { limit: usize },
+
+    /// Too many webhook requests are being handled; the caller should retry
+    /// after `retry_after`.
+    #[error("Server overloaded: {message}")]
+    Overloaded {
+        message: String,
+        retry_after: Duration,
+    },
+
+    /// The webhook signature is missing or invalid, or the delivery was
+    /// rejected as a replay.
+    #[error("Webhook signature verification failed: {message}")]
+    Signature { message: String },
 }
 
 impl Error {
@@ -116,6 +150,13 @@ impl Error {
         }
     }
 
+    /// Create a new forbidden error
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::Forbidden {
+            message: message.into(),
+        }
+    }
+
     /// Create a new internal error
     pub fn internal(message: impl Into<String>) -> Self {
         Self::Internal {
@@ -123,7 +164,131 @@ impl Error {
         }
     }
 
+    /// Create a new malformed payload error
+    pub fn malformed_payload(message: impl Into<String>) -> Self {
+        Self::MalformedPayload {
+            message: message.into(),
+        }
+    }
+
+    /// Create a new payload too large error
+    pub fn payload_too_large(limit: usize) -> Self {
+        Self::PayloadTooLarge { limit }
+    }
+
+    /// Create a new overloaded error
+    pub fn overloaded(message: impl Into<String>, retry_after: Duration) -> Self {
+        Self::Overloaded {
+            message: message.into(),
+            retry_after,
+        }
+    }
+
+    /// Create a new signature verification error
+    pub fn signature(message: impl Into<String>) -> Self {
+        Self::Signature {
+            message: message.into(),
+        }
+    }
+
+    /// The HTTP status reported to the caller for this error.
+    ///
+    /// | Error | Status |
+    /// |-------|--------|
+    /// | [`Self::Signature`] | 401 |
+    /// | [`Self::MalformedPayload`], [`Self::Json`] | 400 |
+    /// | [`Self::Forbidden`] | 403 |
+    /// | [`Self::PayloadTooLarge`] | 413 |
+    /// | `Core(AppNotInstalled)` | 404 |
+    /// | `Core(InvalidInput \| Validation \| Webhook \| JsonParsing)` | 422 |
+    /// | [`Self::Overloaded`] | 503 |
+    /// | [`Self::GitHub`], `Core(GitHub \| Network \| RateLimit \| Timeout \| Authentication)` | 502 |
+    /// | everything else | 500 |
+    ///
+    /// A 5xx status suggests the delivery is worth redelivering, so only
+    /// failures that a redelivery could fix map to one.
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Signature { .. } => StatusCode::UNAUTHORIZED,
+            Self::MalformedPayload { .. } | Self::Json { .. } => StatusCode::BAD_REQUEST,
+            Self::Forbidden { .. } => StatusCode::FORBIDDEN,
+            Self::PayloadTooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::GitHub { .. } => StatusCode::BAD_GATEWAY,
+            Self::Overloaded { .. } => StatusCode::SERVICE_UNAVAILABLE,
+            Self::Core { source } => match source {
+                CoreError::AppNotInstalled { .. } => StatusCode::NOT_FOUND,
+                CoreError::InvalidInput { .. }
+                | CoreError::Validation { .. }
+                | CoreError::Webhook { .. }
+                | CoreError::JsonParsing { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+                CoreError::GitHub { .. }
+                | CoreError::Network { .. }
+                | CoreError::RateLimit { .. }
+                | CoreError::Timeout { .. }
+                | CoreError::Authentication { .. } => StatusCode::BAD_GATEWAY,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            Self::Authentication { .. }
+            | Self::AzureIdentity { .. }
+            | Self::AzureKeyVault { .. }
+            | Self::ConfigProvider { .. }
+            | Self::Environment { .. }
+            | Self::Internal { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// A stable, machine-readable code for the error, derived from its status.
+    pub fn code(&self) -> &'static str {
+        match self.status_code() {
+            StatusCode::UNAUTHORIZED => "invalid_signature",
+            StatusCode::BAD_REQUEST => "malformed_payload",
+            StatusCode::FORBIDDEN => "forbidden",
+            StatusCode::NOT_FOUND => "unknown_repository",
+            StatusCode::PAYLOAD_TOO_LARGE => "payload_too_large",
+            StatusCode::UNPROCESSABLE_ENTITY => "unprocessable_payload",
+            StatusCode::BAD_GATEWAY => "upstream_error",
+            StatusCode::SERVICE_UNAVAILABLE => "overloaded",
+            _ => "internal_error",
+        }
+    }
+
+    /// The message returned to the caller.
+    ///
+    /// Client errors (4xx) describe what was wrong with the request. Server
+    /// errors (5xx) get a fixed description so that internal details, such as
+    /// configuration values or upstream responses, never leave the server; the
+    /// full error is logged instead.
+    pub fn public_message(&self) -> String {
+        match self.status_code() {
+            StatusCode::BAD_GATEWAY => "A GitHub API request failed".to_string(),
+            StatusCode::SERVICE_UNAVAILABLE => "Server is busy; retry later".to_string(),
+            status if status.is_server_error() => "Internal server error".to_string(),
+            _ => self.to_string(),
+        }
+    }
+
     // Note: `parse` and `http_request` constructors removed — the `Parse` and
     // `HttpRequest` variants were unused in all production code paths and have
     // been deleted from the enum.
 }
+
+/// Renders the error as its HTTP status with a JSON body of the form
+/// `{"error":{"code":"invalid_signature","message":"..."}}`.
+/// [`Error::Overloaded`] also sets the `Retry-After` header, in seconds.
+impl IntoResponse for Error {
+    fn into_response(self) -> Response {
+        let body = serde_json::json!({
+            "error": {
+                "code": self.code(),
+                "message": self.public_message(),
+            }
+        });
+        let mut response = (self.status_code(), Json(body)).into_response();
+        if let Self::Overloaded { retry_after, .. } = self {
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, retry_after.as_secs().into());
+        }
+        response
+    }
+}
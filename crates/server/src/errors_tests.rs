@@ -67,3 +67,149 @@ fn test_json_error_conversion() {
         _ => panic!("Expected Json error from serde_json::Error"),
     }
 }
+
+#[test]
+fn test_signature_error_maps_to_401() {
+    let error = Error::signature("signature mismatch");
+
+    assert_eq!(error.status_code(), StatusCode::UNAUTHORIZED);
+    assert_eq!(error.code(), "invalid_signature");
+}
+
+#[test]
+fn test_malformed_payload_errors_map_to_400() {
+    let json_error = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+
+    for error in [
+        Error::malformed_payload("missing body"),
+        Error::from(json_error),
+    ] {
+        assert_eq!(error.status_code(), StatusCode::BAD_REQUEST, "{error}");
+        assert_eq!(error.code(), "malformed_payload");
+    }
+}
+
+#[test]
+fn test_invalid_core_input_maps_to_422() {
+    for source in [
+        CoreError::invalid_input("payload", "missing merge_commit_sha"),
+        CoreError::validation("repository", "missing owner"),
+        CoreError::webhook("parse", "unexpected action"),
+    ] {
+        let error = Error::from(source);
+        assert_eq!(
+            error.status_code(),
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "{error}"
+        );
+        assert_eq!(error.code(), "unprocessable_payload");
+    }
+}
+
+#[test]
+fn test_app_not_installed_maps_to_404() {
+    let error = Error::from(CoreError::app_not_installed("acme", "app"));
+
+    assert_eq!(error.status_code(), StatusCode::NOT_FOUND);
+    assert_eq!(error.code(), "unknown_repository");
+    assert_eq!(
+        error.public_message(),
+        "Core operation failed: GitHub App is not installed on acme/app"
+    );
+}
+
+#[test]
+fn test_github_failures_map_to_502() {
+    for source in [
+        CoreError::network("connection reset"),
+        CoreError::rate_limit("primary rate limit"),
+        CoreError::timeout("GitHub API request", 30_000),
+        CoreError::authentication("GitHub 401"),
+    ] {
+        let error = Error::from(source);
+        assert_eq!(error.status_code(), StatusCode::BAD_GATEWAY, "{error}");
+        assert_eq!(error.code(), "upstream_error");
+        assert_eq!(error.public_message(), "A GitHub API request failed");
+    }
+}
+
+#[test]
+fn test_server_side_failures_map_to_500_without_details() {
+    for error in [
+        Error::internal("queue closed"),
+        Error::environment("GITHUB_PRIVATE_KEY", "not a PEM key"),
+        Error::config_provider("failed to read /etc/release-regent.toml"),
+        Error::from(CoreError::config("bad section")),
+    ] {
+        assert_eq!(
+            error.status_code(),
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "{error}"
+        );
+        assert_eq!(error.code(), "internal_error");
+        assert_eq!(error.public_message(), "Internal server error");
+    }
+}
+
+#[tokio::test]
+async fn test_into_response_renders_status_and_json_body() {
+    let response = Error::signature("signature mismatch").into_response();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "error": {
+                "code": "invalid_signature",
+                "message": "Webhook signature verification failed: signature mismatch"
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_overloaded_maps_to_503_with_retry_after() {
+    let error = Error::overloaded("queue full", std::time::Duration::from_secs(10));
+
+    assert_eq!(error.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(error.code(), "overloaded");
+    assert_eq!(error.public_message(), "Server is busy; retry later");
+
+    let response = error.into_response();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(
+        response
+            .headers()
+            .get(axum::http::header::RETRY_AFTER)
+            .unwrap(),
+        "10"
+    );
+}
+
+#[test]
+fn test_payload_too_large_maps_to_413() {
+    let error = Error::payload_too_large(1024);
+
+    assert_eq!(error.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(error.code(), "payload_too_large");
+    assert_eq!(
+        error.public_message(),
+        "Payload too large: the limit is 1024 bytes"
+    );
+}
+
+#[test]
+fn test_forbidden_maps_to_403() {
+    let error = Error::forbidden("unexpected User-Agent");
+
+    assert_eq!(error.status_code(), StatusCode::FORBIDDEN);
+    assert_eq!(error.code(), "forbidden");
+    assert_eq!(
+        error.public_message(),
+        "Request forbidden: unexpected User-Agent"
+    );
+}
@@ -5,12 +5,15 @@
 //!
 //! - [`WebhookSecretProvider`] — thin `SecretProvider` wrapper over a pre-loaded
 //!   webhook secret string.
-//! - [`classify_event`] — classifies a raw GitHub event-type string + JSON payload
-//!   into a domain [`EventType`].
+//! - [`classify_event`] — classifies a [`WebhookEvent`] parsed by
+//!   [`parse_webhook`] into a domain [`EventType`].
 //! - [`convert_envelope`] — converts an SDK [`EventEnvelope`] into a domain
-//!   [`ProcessingEvent`].
+//!   [`ProcessingEvent`]; [`convert_validated_envelope`] also checks the payload
+//!   against its JSON Schema first.
 //! - [`ReleaseRegentWebhookHandler`] — implements the SDK's [`WebhookHandler`]
 //!   trait; performs allow-list filtering and forwards events on an `mpsc` channel.
+//! - [`EventChannel`] — the sender side of that channel, in which the HTTP
+//!   handler reserves room for an event before a delivery is acknowledged.
 //! - [`WebhookEventSource`] — implements the core [`EventSource`] trait by reading
 //!   from the same `mpsc` channel; consumed by `run_event_loop` (task 4.0).
 //! - [`create_webhook_components`] — convenience factory that creates a matched
@@ -44,9 +47,12 @@ use release_regent_core::{
     traits::event_source::{
         EventSource, EventSourceKind, EventType, ProcessingEvent, RepositoryInfo,
     },
-    CoreResult,
+    webhook::payload::{
+        parse_validated_webhook, parse_webhook, PullRequestClosedPayload, WebhookEvent,
+    },
+    CoreResult, RepositoryAllowList,
 };
-use std::sync::Arc;
+use std::sync::{Arc, PoisonError};
 use tokio::sync::{mpsc, Mutex};
 use tracing::{debug, warn};
 
@@ -126,71 +132,75 @@ fn release_v_prefix(branch_prefix: &str, version_prefix: &str) -> String {
     format!("{branch_prefix}/{version_prefix}")
 }
 
-/// Classify a raw GitHub webhook event into a domain [`EventType`].
+/// Classify a parsed GitHub webhook event into a domain [`EventType`].
 ///
 /// ## Routing table
 ///
-/// | `X-GitHub-Event`              | Conditions                                                      | Result                             |
+/// | Event                         | Conditions                                                      | Result                             |
 /// |-------------------------------|----------------------------------------------------------------|------------------------------------|
+/// | `pull_request`                | `action=opened`                                                 | `PullRequestOpened`                |
+/// | `pull_request`                | `action` is `edited` or `synchronize`                           | `PullRequestUpdated`               |
+/// | `pull_request`                | `action` is `reopened` or `ready_for_review`                    | `PullRequestReactivated`           |
 /// | `pull_request`                | `action=closed`, `merged=true`, non-release branch             | `PullRequestMerged`                |
 /// | `pull_request`                | `action=closed`, `merged=true`, `{release_branch_prefix}/{version_prefix}*` | `ReleasePrMerged`   |
 /// | `pull_request`                | any other action or not merged                                  | `Unknown("pull_request:<action>")` |
-/// | `issue_comment`               | `issue.pull_request` field present in payload                   | `PullRequestCommentReceived`       |
-/// | `issue_comment`               | no `issue.pull_request` field (plain issue)                     | `Unknown("issue_comment:issue")`   |
+/// | `issue_comment`               | the comment is on a pull request                                | `PullRequestCommentReceived`       |
+/// | `issue_comment`               | the comment is on a plain issue                                 | `Unknown("issue_comment:issue")`   |
 /// | `pull_request_review_comment` | always                                                          | `PullRequestCommentReceived`       |
+/// | `push`                        | always                                                          | `Unknown("push")`                  |
+/// | `release`                     | always                                                          | `Unknown("release:<action>")`      |
 /// | everything else               | always                                                          | `Unknown("<event_type>")`          |
 ///
 /// # Parameters
 ///
-/// - `event_type` — The raw `X-GitHub-Event` string (e.g. `"pull_request"`).
-/// - `payload` — The parsed JSON body of the webhook.
+/// - `event` — The delivery, parsed by [`parse_webhook`].
 /// - `release_branch_prefix` — The configured release branch prefix (e.g. `"release"`);
 ///   combined with `version_prefix` to form the expected branch head prefix (e.g. `"release/v"`).
 /// - `version_prefix` — The configured version prefix (e.g. `"v"` or `""`);
 ///   combined with `release_branch_prefix` to identify release PR branches.
 pub fn classify_event(
-    event_type: &str,
-    payload: &serde_json::Value,
+    event: &WebhookEvent,
     release_branch_prefix: &str,
     version_prefix: &str,
 ) -> EventType {
-    match event_type {
-        "pull_request" => {
-            classify_pull_request_event(payload, release_branch_prefix, version_prefix)
+    match event {
+        WebhookEvent::PullRequest(payload) => classify_pull_request_action(&payload.action),
+        WebhookEvent::PullRequestClosed(payload) => {
+            classify_closed_pull_request(payload, release_branch_prefix, version_prefix)
+        }
+        WebhookEvent::IssueComment(payload) if payload.is_pull_request => {
+            EventType::PullRequestCommentReceived
         }
-        "issue_comment" => classify_issue_comment_event(payload),
-        "pull_request_review_comment" => EventType::PullRequestCommentReceived,
-        other => EventType::Unknown(other.to_string()),
+        // GitHub fires `issue_comment` for comments on plain issues too; those
+        // are logged and dropped by the event loop.
+        WebhookEvent::IssueComment(_) => EventType::Unknown("issue_comment:issue".to_string()),
+        WebhookEvent::PullRequestReviewComment(_) => EventType::PullRequestCommentReceived,
+        WebhookEvent::Push(_) => EventType::Unknown("push".to_string()),
+        WebhookEvent::Release(payload) => EventType::Unknown(format!("release:{}", payload.action)),
+        WebhookEvent::Other(raw) => EventType::Unknown(raw.clone()),
     }
 }
 
-/// Classify an `issue_comment` payload.
+/// Classify a `pull_request` event that is not `closed`.
 ///
-/// GitHub fires `issue_comment` events for comments on both plain Issues and
-/// Pull Requests. Only comments where the `issue.pull_request` field is present
-/// are classified as [`EventType::PullRequestCommentReceived`]. Comments on
-/// plain issues are classified as `Unknown("issue_comment:issue")` and will be
-/// logged and dropped by the event loop.
-fn classify_issue_comment_event(payload: &serde_json::Value) -> EventType {
-    if payload
-        .get("issue")
-        .and_then(|i| i.get("pull_request"))
-        .is_some()
-    {
-        EventType::PullRequestCommentReceived
-    } else {
-        EventType::Unknown("issue_comment:issue".to_string())
+/// Actions Release Regent does not handle return
+/// `Unknown("pull_request:<action>")` so that the action is visible in logs
+/// when diagnosing which events are being discarded.
+fn classify_pull_request_action(action: &str) -> EventType {
+    match action {
+        "opened" => EventType::PullRequestOpened,
+        "edited" | "synchronize" => EventType::PullRequestUpdated,
+        "reopened" | "ready_for_review" => EventType::PullRequestReactivated,
+        other => EventType::Unknown(format!("pull_request:{other}")),
     }
 }
 
-/// Classify a `pull_request` payload into a specific [`EventType`].
+/// Classify a closed pull request.
 ///
-/// Non-closed and non-merged events return `Unknown("pull_request:<action>")`
-/// so that the action is visible in logs when diagnosing which events are being
-/// discarded.
-///
-/// A merged PR whose head branch starts with `{release_branch_prefix}/{version_prefix}` is
-/// classified as [`EventType::ReleasePrMerged`]; all others map to
+/// A pull request closed without merging returns
+/// `Unknown("pull_request:closed")`. A merged PR whose head branch starts with
+/// `{release_branch_prefix}/{version_prefix}` is classified as
+/// [`EventType::ReleasePrMerged`]; all others map to
 /// [`EventType::PullRequestMerged`].
 ///
 /// # Panics
@@ -199,31 +209,13 @@ fn classify_issue_comment_event(payload: &serde_json::Value) -> EventType {
 /// programming error: a `WARN` log is emitted and the event is classified as
 /// [`EventType::PullRequestMerged`] rather than silently matching any branch
 /// that starts with `"/{version_prefix}"`.
-fn classify_pull_request_event(
-    payload: &serde_json::Value,
+fn classify_closed_pull_request(
+    payload: &PullRequestClosedPayload,
     release_branch_prefix: &str,
     version_prefix: &str,
 ) -> EventType {
-    let action = payload
-        .get("action")
-        .and_then(serde_json::Value::as_str)
-        .unwrap_or("unknown");
-
-    let is_merged = payload
-        .pointer("/pull_request/merged")
-        .and_then(serde_json::Value::as_bool)
-        .unwrap_or(false);
-
-    // Route opened/updated actions before checking for the closed+merged path.
-    if action == "opened" {
-        return EventType::PullRequestOpened;
-    }
-    if action == "edited" || action == "synchronize" || action == "ready_for_review" {
-        return EventType::PullRequestUpdated;
-    }
-
-    if !(action == "closed" && is_merged) {
-        return EventType::Unknown(format!("pull_request:{action}"));
+    if !payload.is_merged() {
+        return EventType::Unknown("pull_request:closed".to_string());
     }
 
     if release_branch_prefix.is_empty() {
@@ -231,11 +223,7 @@ fn classify_pull_request_event(
         return EventType::PullRequestMerged;
     }
 
-    let head_ref = payload
-        .pointer("/pull_request/head/ref")
-        .and_then(serde_json::Value::as_str)
-        .unwrap_or("");
-
+    let head_ref = &payload.pull_request.head.ref_name;
     if head_ref.starts_with(release_v_prefix(release_branch_prefix, version_prefix).as_str()) {
         EventType::ReleasePrMerged
     } else {
@@ -249,13 +237,17 @@ fn classify_pull_request_event(
 
 /// Convert an SDK [`EventEnvelope`] into a domain [`ProcessingEvent`].
 ///
-/// The `repository.full_name` field (e.g. `"owner/repo"`) is split on `/` to
-/// populate [`RepositoryInfo::owner`] and [`RepositoryInfo::name`].
+/// The payload is parsed with [`parse_webhook`], the parser the CLI uses too,
+/// and the repository and installation ID are taken from the parsed event.
+/// Events Release Regent does not parse carry no repository of their own, so
+/// the envelope's `repository.full_name` (e.g. `"owner/repo"`) is split on `/`
+/// instead.
 ///
 /// # Errors
 ///
-/// Returns [`Error::Internal`] when `repository.full_name` does not contain
-/// a `/` separator.
+/// Returns [`Error::MalformedPayload`] when the payload does not parse as its
+/// event kind, and [`Error::Internal`] when the `repository.full_name` of an
+/// unparsed event does not contain a `/` separator.
 // `CoreError` is a large enum; boxing it here would complicate callers.
 // This is the established pattern across the codebase.
 #[allow(clippy::result_large_err)]
@@ -264,41 +256,79 @@ pub fn convert_envelope(
     release_branch_prefix: &str,
     version_prefix: &str,
 ) -> Result<ProcessingEvent, Error> {
-    let full_name = &envelope.repository.full_name;
+    convert_with(
+        envelope,
+        release_branch_prefix,
+        version_prefix,
+        parse_webhook,
+    )
+}
 
-    let (owner, name) = full_name.split_once('/').ok_or_else(|| Error::Internal {
-        message: format!("invalid repository full_name: {full_name}"),
-    })?;
+/// Convert an SDK [`EventEnvelope`] like [`convert_envelope`], after checking
+/// the payload against the JSON Schema of its event kind.
+///
+/// Used when `WEBHOOK_VALIDATE_SCHEMA` is enabled, so a truncated or
+/// unexpected payload is rejected with every schema violation listed.
+///
+/// # Errors
+///
+/// As [`convert_envelope`]; [`Error::MalformedPayload`] also covers schema
+/// violations.
+#[allow(clippy::result_large_err)]
+pub fn convert_validated_envelope(
+    envelope: &EventEnvelope,
+    release_branch_prefix: &str,
+    version_prefix: &str,
+) -> Result<ProcessingEvent, Error> {
+    convert_with(
+        envelope,
+        release_branch_prefix,
+        version_prefix,
+        parse_validated_webhook,
+    )
+}
 
-    let repository = RepositoryInfo {
-        owner: owner.to_string(),
-        name: name.to_string(),
-        default_branch: envelope.repository.default_branch.clone(),
+#[allow(clippy::result_large_err)]
+fn convert_with(
+    envelope: &EventEnvelope,
+    release_branch_prefix: &str,
+    version_prefix: &str,
+    parse: fn(&str, &[u8]) -> CoreResult<WebhookEvent>,
+) -> Result<ProcessingEvent, Error> {
+    let body = serde_json::to_vec(envelope.payload.raw())
+        .map_err(|e| Error::internal(format!("failed to re-encode webhook payload: {e}")))?;
+    let event = parse(envelope.event_type.as_str(), &body)
+        .map_err(|e| Error::malformed_payload(e.to_string()))?;
+
+    let repository = match event.repository() {
+        Some(repository) => repository.clone(),
+        None => {
+            let full_name = &envelope.repository.full_name;
+            let (owner, name) = full_name.split_once('/').ok_or_else(|| Error::Internal {
+                message: format!("invalid repository full_name: {full_name}"),
+            })?;
+            RepositoryInfo {
+                owner: owner.to_string(),
+                name: name.to_string(),
+                default_branch: envelope.repository.default_branch.clone(),
+            }
+        }
     };
 
-    let event_type = classify_event(
-        envelope.event_type.as_str(),
-        envelope.payload.raw(),
-        release_branch_prefix,
-        version_prefix,
-    );
-
-    let installation_id = envelope
-        .payload
-        .raw()
-        .get("installation")
-        .and_then(|i| i.get("id"))
-        .and_then(serde_json::Value::as_u64)
-        .unwrap_or_else(|| {
+    let event_type = classify_event(&event, release_branch_prefix, version_prefix);
+
+    let installation_id = event.installation_id().unwrap_or_else(|| {
+        if !matches!(event, WebhookEvent::Other(_)) {
             warn!(
                 event_id = %envelope.event_id,
                 event_type = %envelope.event_type,
                 "Webhook payload missing installation.id — \
-                 this may indicate an unsupported event type or a misconfigured webhook. \
+                 this may indicate a misconfigured webhook. \
                  API calls will fail with auth errors if this event requires an installation token.",
             );
-            0
-        });
+        }
+        0
+    });
 
     Ok(ProcessingEvent {
         event_id: envelope.event_id.to_string(),
@@ -312,6 +342,107 @@ pub fn convert_envelope(
     })
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// EventChannel
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Why no room could be reserved in the [`EventChannel`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelUnavailable {
+    /// The channel holds as many events as it can.
+    Full,
+    /// The event loop has stopped receiving events.
+    Closed,
+}
+
+impl std::fmt::Display for ChannelUnavailable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Full => write!(f, "the event queue is full"),
+            Self::Closed => write!(f, "the event queue is closed"),
+        }
+    }
+}
+
+/// Sender side of the event channel, with room reserved per delivery.
+///
+/// The SDK acknowledges a delivery before it calls the
+/// [`ReleaseRegentWebhookHandler`], so an event that finds the channel full
+/// could only be dropped after GitHub has been told it was received. Instead
+/// the HTTP handler calls [`reserve`](Self::reserve) before handing the
+/// delivery to the SDK and turns it away with HTTP 503 when there is no room.
+/// The reserved room is handed to the handler, which sends the event in it.
+///
+/// Reservations are interchangeable: every accepted delivery adds one and
+/// every call of the handler takes one, so each event finds room waiting.
+#[derive(Clone)]
+pub struct EventChannel {
+    tx: mpsc::Sender<ProcessingEvent>,
+    reserved: Arc<std::sync::Mutex<Vec<mpsc::OwnedPermit<ProcessingEvent>>>>,
+}
+
+impl EventChannel {
+    /// Wrap the sender side of the processing channel.
+    pub fn new(tx: mpsc::Sender<ProcessingEvent>) -> Self {
+        Self {
+            tx,
+            reserved: Arc::default(),
+        }
+    }
+
+    /// Reserve room in the channel for the event of one delivery.
+    ///
+    /// The room is released again when the returned [`Reservation`] is
+    /// dropped without being [accepted](Reservation::accept).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChannelUnavailable`] when the channel is full or closed.
+    pub fn reserve(&self) -> Result<Reservation, ChannelUnavailable> {
+        let permit = self.tx.clone().try_reserve_owned().map_err(|e| match e {
+            mpsc::error::TrySendError::Full(_) => ChannelUnavailable::Full,
+            mpsc::error::TrySendError::Closed(_) => ChannelUnavailable::Closed,
+        })?;
+        self.reserved
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(permit);
+        Ok(Reservation {
+            channel: self.clone(),
+            accepted: false,
+        })
+    }
+
+    /// Take the room reserved for one delivery, if any.
+    fn take_reserved(&self) -> Option<mpsc::OwnedPermit<ProcessingEvent>> {
+        self.reserved
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop()
+    }
+}
+
+/// Room reserved in the [`EventChannel`] for the event of one delivery.
+pub struct Reservation {
+    channel: EventChannel,
+    accepted: bool,
+}
+
+impl Reservation {
+    /// Keep the room for the handler once the SDK has accepted the delivery.
+    pub fn accept(mut self) {
+        self.accepted = true;
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        if !self.accepted {
+            drop(self.channel.take_reserved());
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // ReleaseRegentWebhookHandler
 // ─────────────────────────────────────────────────────────────────────────────
@@ -322,12 +453,15 @@ pub fn convert_envelope(
 /// The handler is registered with [`WebhookReceiver`](github_bot_sdk::webhook::WebhookReceiver)
 /// and invoked after signature validation succeeds. The HTTP response is
 /// already sent to GitHub before this method is called (fire-and-forget), so
-/// dropping an event here does not cause a GitHub delivery error.
+/// the event is sent in room the HTTP handler reserved beforehand through the
+/// [`event_channel`](Self::event_channel). Without a reservation the event is
+/// dropped when the channel is full.
 pub struct ReleaseRegentWebhookHandler {
-    tx: mpsc::Sender<ProcessingEvent>,
-    allowed_repos: Vec<String>,
+    channel: EventChannel,
+    allowed_repos: RepositoryAllowList,
     release_branch_prefix: String,
     version_prefix: String,
+    validate_schema: bool,
 }
 
 impl ReleaseRegentWebhookHandler {
@@ -339,7 +473,8 @@ impl ReleaseRegentWebhookHandler {
     /// - `allowed_repos` — Repository allow-list.
     ///   - Empty `Vec` → deny all repositories.
     ///   - `vec!["*"]` → allow all repositories.
-    ///   - Otherwise → exact `"owner/repo"` match.
+    ///   - Otherwise → `"owner/repo"` patterns where `*` matches within a
+    ///     segment, e.g. `"owner/*"`; see [`RepositoryAllowList`].
     /// - `release_branch_prefix` — The configured release branch prefix (e.g. `"release"`);
     ///   forwarded to [`classify_event`] during envelope conversion.
     /// - `version_prefix` — The configured version prefix (e.g. `"v"` or `""`);
@@ -351,24 +486,32 @@ impl ReleaseRegentWebhookHandler {
         version_prefix: String,
     ) -> Self {
         Self {
-            tx,
-            allowed_repos,
+            channel: EventChannel::new(tx),
+            allowed_repos: RepositoryAllowList::new(allowed_repos),
             release_branch_prefix,
             version_prefix,
+            validate_schema: false,
         }
     }
 
+    /// Check every payload against the JSON Schema of its event kind before
+    /// converting it (see [`convert_validated_envelope`]). Disabled by default.
+    #[must_use]
+    pub fn with_schema_validation(mut self, enabled: bool) -> Self {
+        self.validate_schema = enabled;
+        self
+    }
+
+    /// The channel this handler sends events on, for reserving room in it.
+    pub fn event_channel(&self) -> EventChannel {
+        self.channel.clone()
+    }
+
     /// Return `true` if `full_name` matches the allow-list policy.
     ///
     /// See [`new`](Self::new) for documentation on the allow-list semantics.
     pub fn is_allowed(&self, full_name: &str) -> bool {
-        if self.allowed_repos.is_empty() {
-            return false;
-        }
-        if self.allowed_repos.iter().any(|r| r == "*") {
-            return true;
-        }
-        self.allowed_repos.iter().any(|r| r == full_name)
+        self.allowed_repos.is_allowed_full_name(full_name)
     }
 }
 
@@ -378,6 +521,8 @@ impl WebhookHandler for ReleaseRegentWebhookHandler {
         &self,
         envelope: &EventEnvelope,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        // Taken even when the event is dropped below, which frees the room.
+        let reserved = self.channel.take_reserved();
         let full_name = &envelope.repository.full_name;
 
         if !self.is_allowed(full_name) {
@@ -388,8 +533,13 @@ impl WebhookHandler for ReleaseRegentWebhookHandler {
             return Ok(());
         }
 
+        let convert = if self.validate_schema {
+            convert_validated_envelope
+        } else {
+            convert_envelope
+        };
         let processing_event =
-            match convert_envelope(envelope, &self.release_branch_prefix, &self.version_prefix) {
+            match convert(envelope, &self.release_branch_prefix, &self.version_prefix) {
                 Ok(e) => e,
                 Err(e) => {
                     warn!(
@@ -404,7 +554,17 @@ impl WebhookHandler for ReleaseRegentWebhookHandler {
         let event_id = processing_event.event_id.clone();
         let event_type = processing_event.event_type.to_string();
 
-        match self.tx.try_send(processing_event) {
+        if let Some(permit) = reserved {
+            permit.send(processing_event);
+            debug!(
+                event_id = %event_id,
+                event_type = %event_type,
+                "Forwarded processing event to channel"
+            );
+            return Ok(());
+        }
+
+        match self.channel.tx.try_send(processing_event) {
             Ok(()) => {
                 debug!(
                     event_id = %event_id,
@@ -491,8 +651,10 @@ impl EventSource for WebhookEventSource {
 
 /// Create a matched [`ReleaseRegentWebhookHandler`] / [`WebhookEventSource`] pair.
 ///
-/// Both share a bounded `mpsc` channel. Events are dropped (with a `WARN`
-/// tracing event) when the channel reaches `channel_capacity`.
+/// Both share a bounded `mpsc` channel of `channel_capacity` events. Reserve
+/// room in it through [`ReleaseRegentWebhookHandler::event_channel`] before a
+/// delivery is acknowledged; events without a reservation are dropped (with a
+/// `WARN` tracing event) when the channel is full.
 ///
 /// # Parameters
 ///
@@ -6,6 +6,8 @@ use github_bot_sdk::{
     events::{EventPayload, EventProcessor, ProcessorConfig},
     webhook::{WebhookReceiver, WebhookRequest},
 };
+use release_regent_core::webhook::payload::WebhookEventKind;
+use release_regent_testing::fixtures::webhook_fixtures::PullRequestEventBuilder;
 use serde_json::json;
 use std::collections::HashMap;
 use tokio::sync::mpsc;
@@ -99,52 +101,77 @@ fn make_envelope(event_type: &str, payload: serde_json::Value) -> EventEnvelope
     )
 }
 
-/// Minimal GitHub `pull_request` payload for a merged non-release PR.
-fn merged_pr_payload() -> serde_json::Value {
+/// The `repository` object GitHub embeds in webhook payloads.
+fn repository_json() -> serde_json::Value {
     json!({
-        "action": "closed",
+        "id": 1,
+        "name": "test-repo",
+        "full_name": "owner/test-repo",
+        "owner": { "login": "owner", "id": 1, "avatar_url": "",
+                   "type": "Organization" },
+        "private": false,
+        "default_branch": "main",
+        "html_url": "", "clone_url": "", "ssh_url": "",
+        "created_at": "2024-01-01T00:00:00Z",
+        "updated_at": "2024-01-01T00:00:00Z"
+    })
+}
+
+/// GitHub `pull_request` payload for `action` on a PR whose head branch is
+/// `head_ref`.
+fn pull_request_payload(action: &str, merged: bool, head_ref: &str) -> serde_json::Value {
+    json!({
+        "action": action,
         "pull_request": {
-            "merged": true,
-            "head": { "ref": "feature/my-feature" }
+            "number": 42,
+            "title": "feat: add widget",
+            "merged": merged,
+            "head": { "ref": head_ref, "sha": "abc123" },
+            "base": { "ref": "main", "sha": "def456" },
+            "user": { "login": "octocat" }
         },
-        "repository": {
-            "id": 1,
-            "name": "test-repo",
-            "full_name": "owner/test-repo",
-            "owner": { "login": "owner", "id": 1, "avatar_url": "",
-                       "type": "Organization" },
-            "private": false,
-            "default_branch": "main",
-            "html_url": "", "clone_url": "", "ssh_url": "",
-            "created_at": "2024-01-01T00:00:00Z",
-            "updated_at": "2024-01-01T00:00:00Z"
-        }
+        "repository": repository_json()
     })
 }
 
-/// Minimal GitHub `pull_request` payload for a merged release PR.
+/// GitHub `pull_request` payload for a merged non-release PR.
+fn merged_pr_payload() -> serde_json::Value {
+    pull_request_payload("closed", true, "feature/my-feature")
+}
+
+/// GitHub `pull_request` payload for a merged release PR.
 fn merged_release_pr_payload() -> serde_json::Value {
+    pull_request_payload("closed", true, "release/v1.2.3")
+}
+
+/// GitHub `issue_comment` payload; `on_pull_request` adds the
+/// `issue.pull_request` object GitHub sends for comments on PRs.
+fn issue_comment_payload(on_pull_request: bool) -> serde_json::Value {
+    let mut issue = json!({ "number": 7, "title": "Bug report" });
+    if on_pull_request {
+        issue["pull_request"] = json!({ "url": "https://api.github.com/repos/owner/repo/pulls/7" });
+    }
     json!({
-        "action": "closed",
-        "pull_request": {
-            "merged": true,
-            "head": { "ref": "release/v1.2.3" }
-        },
-        "repository": {
-            "id": 1,
-            "name": "test-repo",
-            "full_name": "owner/test-repo",
-            "owner": { "login": "owner", "id": 1, "avatar_url": "",
-                       "type": "Organization" },
-            "private": false,
-            "default_branch": "main",
-            "html_url": "", "clone_url": "", "ssh_url": "",
-            "created_at": "2024-01-01T00:00:00Z",
-            "updated_at": "2024-01-01T00:00:00Z"
-        }
+        "action": "created",
+        "issue": issue,
+        "comment": { "id": 1, "body": "!release minor", "user": { "login": "octocat" } },
+        "repository": repository_json()
     })
 }
 
+/// Parse `payload` as an `event_type` delivery, as the server does, and
+/// classify it.
+fn classify(
+    event_type: &str,
+    payload: &serde_json::Value,
+    release_branch_prefix: &str,
+    version_prefix: &str,
+) -> EventType {
+    let body = serde_json::to_vec(payload).unwrap();
+    let event = parse_webhook(event_type, &body).expect("test payload should parse");
+    classify_event(&event, release_branch_prefix, version_prefix)
+}
+
 /// A minimal full webhook JSON payload suitable for `receive_webhook` integration tests.
 fn minimal_webhook_payload(action: &str) -> String {
     json!({
@@ -203,24 +230,21 @@ async fn test_webhook_secret_provider_get_app_id_returns_not_found() {
 #[test]
 fn test_classify_event_pull_request_closed_merged_regular_returns_pr_merged() {
     let payload = merged_pr_payload();
-    let result = classify_event("pull_request", &payload, "release", "v");
+    let result = classify("pull_request", &payload, "release", "v");
     assert_eq!(result, EventType::PullRequestMerged);
 }
 
 #[test]
 fn test_classify_event_pull_request_closed_merged_release_branch_returns_release_pr_merged() {
     let payload = merged_release_pr_payload();
-    let result = classify_event("pull_request", &payload, "release", "v");
+    let result = classify("pull_request", &payload, "release", "v");
     assert_eq!(result, EventType::ReleasePrMerged);
 }
 
 #[test]
 fn test_classify_event_pull_request_not_merged_returns_unknown_with_action() {
-    let payload = json!({
-        "action": "closed",
-        "pull_request": { "merged": false, "head": { "ref": "feature/x" } }
-    });
-    let result = classify_event("pull_request", &payload, "release", "v");
+    let payload = pull_request_payload("closed", false, "feature/x");
+    let result = classify("pull_request", &payload, "release", "v");
     assert!(
         matches!(result, EventType::Unknown(ref s) if s == "pull_request:closed"),
         "non-merged closed PR must return Unknown with action suffix"
@@ -229,11 +253,8 @@ fn test_classify_event_pull_request_not_merged_returns_unknown_with_action() {
 
 #[test]
 fn test_classify_event_pull_request_opened_returns_pull_request_opened() {
-    let payload = json!({
-        "action": "opened",
-        "pull_request": { "merged": false, "head": { "ref": "feature/x" } }
-    });
-    let result = classify_event("pull_request", &payload, "release", "v");
+    let payload = pull_request_payload("opened", false, "feature/x");
+    let result = classify("pull_request", &payload, "release", "v");
     assert_eq!(
         result,
         EventType::PullRequestOpened,
@@ -243,62 +264,69 @@ fn test_classify_event_pull_request_opened_returns_pull_request_opened() {
 
 #[test]
 fn test_classify_event_pull_request_synchronize_returns_pull_request_updated() {
-    let payload = json!({
-        "action": "synchronize",
-        "pull_request": { "merged": false, "head": { "ref": "feature/x" } }
-    });
-    let result = classify_event("pull_request", &payload, "release", "v");
+    let payload = pull_request_payload("synchronize", false, "feature/x");
+    let result = classify("pull_request", &payload, "release", "v");
     assert_eq!(result, EventType::PullRequestUpdated);
 }
 
 #[test]
-fn test_classify_event_pull_request_ready_for_review_returns_pull_request_updated() {
-    let payload = json!({
-        "action": "ready_for_review",
-        "pull_request": { "merged": false, "head": { "ref": "feature/x" } }
-    });
-    let result = classify_event("pull_request", &payload, "release", "v");
-    assert_eq!(result, EventType::PullRequestUpdated);
+fn test_classify_event_pull_request_ready_for_review_returns_pull_request_reactivated() {
+    let payload = PullRequestEventBuilder::new()
+        .with_branches("main", "release/v1.2.0")
+        .as_ready_for_review()
+        .build();
+    let result = classify("pull_request", &payload, "release", "v");
+    assert_eq!(result, EventType::PullRequestReactivated);
 }
 
 #[test]
 fn test_classify_event_pull_request_edited_returns_pull_request_updated() {
-    let payload = json!({
-        "action": "edited",
-        "pull_request": { "merged": false, "head": { "ref": "feature/x" } }
-    });
-    let result = classify_event("pull_request", &payload, "release", "v");
+    let payload = pull_request_payload("edited", false, "feature/x");
+    let result = classify("pull_request", &payload, "release", "v");
     assert_eq!(result, EventType::PullRequestUpdated);
 }
 
+#[test]
+fn test_classify_event_pull_request_reopened_returns_pull_request_reactivated() {
+    let payload = PullRequestEventBuilder::new()
+        .with_branches("main", "release/v1.2.0")
+        .as_reopened()
+        .build();
+    let result = classify("pull_request", &payload, "release", "v");
+    assert_eq!(result, EventType::PullRequestReactivated);
+}
+
+#[test]
+fn test_classify_event_pull_request_irrelevant_action_returns_unknown_with_action() {
+    for action in [
+        "labeled",
+        "assigned",
+        "review_requested",
+        "converted_to_draft",
+    ] {
+        let payload = pull_request_payload(action, false, "feature/x");
+        let result = classify("pull_request", &payload, "release", "v");
+        assert_eq!(
+            result,
+            EventType::Unknown(format!("pull_request:{action}")),
+            "action {action:?} must be ignored"
+        );
+    }
+}
+
 #[test]
 fn test_classify_event_issue_comment_on_pr_returns_pr_comment_received() {
     // Payload with "issue.pull_request" present — this is a PR comment.
-    let payload = json!({
-        "action": "created",
-        "issue": {
-            "number": 7,
-            "pull_request": {
-                "url": "https://api.github.com/repos/owner/repo/pulls/7"
-            }
-        }
-    });
-    let result = classify_event("issue_comment", &payload, "release", "v");
+    let payload = issue_comment_payload(true);
+    let result = classify("issue_comment", &payload, "release", "v");
     assert_eq!(result, EventType::PullRequestCommentReceived);
 }
 
 #[test]
 fn test_classify_event_issue_comment_on_regular_issue_returns_unknown() {
     // Payload without "issue.pull_request" — this is a plain issue comment.
-    let payload = json!({
-        "action": "created",
-        "issue": {
-            "number": 42,
-            "title": "Bug report"
-            // no "pull_request" key
-        }
-    });
-    let result = classify_event("issue_comment", &payload, "release", "v");
+    let payload = issue_comment_payload(false);
+    let result = classify("issue_comment", &payload, "release", "v");
     assert!(
         matches!(result, EventType::Unknown(ref s) if s == "issue_comment:issue"),
         "issue_comment on a plain issue must not be classified as PullRequestCommentReceived"
@@ -307,19 +335,77 @@ fn test_classify_event_issue_comment_on_regular_issue_returns_unknown() {
 
 #[test]
 fn test_classify_event_pull_request_review_comment_returns_pr_comment_received() {
-    let result = classify_event("pull_request_review_comment", &json!({}), "release", "v");
+    let payload = json!({
+        "action": "created",
+        "pull_request": { "number": 7 },
+        "comment": { "id": 1, "body": "looks good", "user": { "login": "octocat" } },
+        "repository": repository_json()
+    });
+    let result = classify("pull_request_review_comment", &payload, "release", "v");
     assert_eq!(result, EventType::PullRequestCommentReceived);
 }
 
 #[test]
 fn test_classify_event_push_returns_unknown() {
-    let result = classify_event("push", &json!({}), "release", "v");
+    let payload = json!({
+        "ref": "refs/heads/main",
+        "before": "abc123",
+        "after": "def456",
+        "repository": repository_json()
+    });
+    let result = classify("push", &payload, "release", "v");
     assert!(matches!(result, EventType::Unknown(s) if s == "push"));
 }
 
+#[test]
+fn test_classify_event_push_ignores_action_field() {
+    // A push is identified by the header alone, even if the payload happens
+    // to carry fields that look like a merged pull request.
+    let mut payload = merged_pr_payload();
+    payload["ref"] = json!("refs/heads/main");
+    payload["before"] = json!("abc123");
+    payload["after"] = json!("def456");
+    let result = classify("push", &payload, "release", "v");
+    assert_eq!(result, EventType::Unknown("push".to_string()));
+}
+
+#[test]
+fn test_classify_event_release_published_returns_unknown_with_action() {
+    let payload = json!({
+        "action": "published",
+        "release": { "tag_name": "v1.0.0" },
+        "repository": repository_json()
+    });
+    let result = classify("release", &payload, "release", "v");
+    assert_eq!(result, EventType::Unknown("release:published".to_string()));
+}
+
+#[test]
+fn test_webhook_event_kind_from_header_parses_known_kinds() {
+    let cases = [
+        ("issue_comment", WebhookEventKind::IssueComment),
+        ("pull_request", WebhookEventKind::PullRequest),
+        (
+            "pull_request_review_comment",
+            WebhookEventKind::PullRequestReviewComment,
+        ),
+        ("push", WebhookEventKind::Push),
+        ("release", WebhookEventKind::Release),
+        (
+            "workflow_run",
+            WebhookEventKind::Other("workflow_run".to_string()),
+        ),
+    ];
+    for (header, expected) in cases {
+        let kind = WebhookEventKind::from_header(header);
+        assert_eq!(kind, expected, "header {header:?}");
+        assert_eq!(kind.as_str(), header);
+    }
+}
+
 #[test]
 fn test_classify_event_empty_string_returns_unknown() {
-    let result = classify_event("", &json!({}), "release", "v");
+    let result = classify("", &json!({}), "release", "v");
     assert!(matches!(result, EventType::Unknown(_)));
 }
 
@@ -327,14 +413,8 @@ fn test_classify_event_empty_string_returns_unknown() {
 fn test_classify_event_custom_prefix_matching_branch_returns_release_pr_merged() {
     // A deployment that uses "custom" as the branch prefix should have
     // "custom/v1.2.3" branches classified as ReleasePrMerged.
-    let payload = json!({
-        "action": "closed",
-        "pull_request": {
-            "merged": true,
-            "head": { "ref": "custom/v1.2.3" }
-        }
-    });
-    let result = classify_event("pull_request", &payload, "custom", "v");
+    let payload = pull_request_payload("closed", true, "custom/v1.2.3");
+    let result = classify("pull_request", &payload, "custom", "v");
     assert_eq!(
         result,
         EventType::ReleasePrMerged,
@@ -345,14 +425,8 @@ fn test_classify_event_custom_prefix_matching_branch_returns_release_pr_merged()
 #[test]
 fn test_classify_event_custom_prefix_non_matching_branch_returns_pr_merged() {
     // With prefix "custom", the standard "release/v*" branch is NOT a release PR.
-    let payload = json!({
-        "action": "closed",
-        "pull_request": {
-            "merged": true,
-            "head": { "ref": "release/v1.2.3" }
-        }
-    });
-    let result = classify_event("pull_request", &payload, "custom", "v");
+    let payload = pull_request_payload("closed", true, "release/v1.2.3");
+    let result = classify("pull_request", &payload, "custom", "v");
     assert_eq!(
         result,
         EventType::PullRequestMerged,
@@ -363,14 +437,8 @@ fn test_classify_event_custom_prefix_non_matching_branch_returns_pr_merged() {
 #[test]
 fn test_classify_event_default_prefix_unchanged_behavior_for_release_branch() {
     // Regression guard: default "release" prefix keeps existing behavior.
-    let payload = json!({
-        "action": "closed",
-        "pull_request": {
-            "merged": true,
-            "head": { "ref": "release/v2.0.0" }
-        }
-    });
-    let result = classify_event("pull_request", &payload, "release", "v");
+    let payload = pull_request_payload("closed", true, "release/v2.0.0");
+    let result = classify("pull_request", &payload, "release", "v");
     assert_eq!(
         result,
         EventType::ReleasePrMerged,
@@ -382,14 +450,8 @@ fn test_classify_event_default_prefix_unchanged_behavior_for_release_branch() {
 fn test_classify_event_empty_prefix_merged_pr_returns_pr_merged() {
     // An empty prefix is a programming error. Rather than matching any "/v*" branch,
     // the classifier must fall back to PullRequestMerged and emit a warning.
-    let payload = json!({
-        "action": "closed",
-        "pull_request": {
-            "merged": true,
-            "head": { "ref": "/v1.0.0" }
-        }
-    });
-    let result = classify_event("pull_request", &payload, "", "v");
+    let payload = pull_request_payload("closed", true, "/v1.0.0");
+    let result = classify("pull_request", &payload, "", "v");
     assert_eq!(
         result,
         EventType::PullRequestMerged,
@@ -443,6 +505,63 @@ fn test_convert_envelope_invalid_full_name_returns_error() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_convert_envelope_rejects_incomplete_pull_request() {
+    let mut payload = merged_pr_payload();
+    payload["pull_request"]
+        .as_object_mut()
+        .unwrap()
+        .remove("head");
+    let envelope = make_envelope("pull_request", payload);
+
+    let result = convert_envelope(&envelope, "release", "v");
+
+    assert!(
+        matches!(result, Err(Error::MalformedPayload { ref message }) if message.contains("head")),
+        "{result:?}"
+    );
+}
+
+#[test]
+fn test_convert_envelope_reads_installation_id_from_parsed_event() {
+    let mut payload = merged_pr_payload();
+    payload["installation"] = json!({ "id": 4242 });
+    let envelope = make_envelope("pull_request", payload);
+
+    let event = convert_envelope(&envelope, "release", "v").expect("conversion must succeed");
+
+    assert_eq!(event.installation_id, 4242);
+}
+
+#[test]
+fn test_convert_validated_envelope_lists_schema_violations() {
+    let mut payload = merged_pr_payload();
+    payload.as_object_mut().unwrap().remove("repository");
+    let envelope = make_envelope("pull_request", payload);
+
+    let result = convert_validated_envelope(&envelope, "release", "v");
+
+    assert!(
+        matches!(
+            result,
+            Err(Error::MalformedPayload { ref message })
+                if message.contains("does not match its schema")
+                    && message.contains("\"repository\" is a required property")
+        ),
+        "{result:?}"
+    );
+}
+
+#[test]
+fn test_convert_validated_envelope_accepts_a_valid_payload() {
+    let envelope = make_envelope("pull_request", merged_pr_payload());
+
+    let event =
+        convert_validated_envelope(&envelope, "release", "v").expect("conversion must succeed");
+
+    assert_eq!(event.event_type, EventType::PullRequestMerged);
+}
+
 #[test]
 fn test_convert_envelope_payload_is_preserved() {
     let payload = merged_pr_payload();
@@ -500,6 +619,19 @@ fn test_is_allowed_explicit_match_denies_unlisted_repo() {
     assert!(!handler.is_allowed("owner/other-repo"));
 }
 
+#[test]
+fn test_is_allowed_owner_wildcard_allows_repos_of_that_owner() {
+    let (tx, _rx) = mpsc::channel(1);
+    let handler = ReleaseRegentWebhookHandler::new(
+        tx,
+        vec!["owner/*".to_string()],
+        "release".to_string(),
+        "v".to_string(),
+    );
+    assert!(handler.is_allowed("owner/any-repo"));
+    assert!(!handler.is_allowed("other/any-repo"));
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // ReleaseRegentWebhookHandler::handle_event tests
 // ─────────────────────────────────────────────────────────────────────────────
@@ -566,6 +698,38 @@ async fn test_handle_event_release_pr_sends_release_pr_merged_event() {
     assert_eq!(event.event_type, EventType::ReleasePrMerged);
 }
 
+/// With schema validation enabled, valid deliveries are still forwarded and
+/// deliveries that break the schema are dropped.
+#[tokio::test]
+async fn test_handle_event_with_schema_validation_drops_schema_violations() {
+    let (tx, mut rx) = mpsc::channel(4);
+    let handler = ReleaseRegentWebhookHandler::new(
+        tx,
+        vec!["*".to_string()],
+        "release".to_string(),
+        "v".to_string(),
+    )
+    .with_schema_validation(true);
+
+    handler
+        .handle_event(&make_envelope("pull_request", merged_pr_payload()))
+        .await
+        .expect("handle_event must succeed");
+    let event = rx.try_recv().expect("expected event on channel");
+    assert_eq!(event.event_type, EventType::PullRequestMerged);
+
+    let mut payload = merged_pr_payload();
+    payload["pull_request"]["number"] = json!("forty-two");
+    handler
+        .handle_event(&make_envelope("pull_request", payload))
+        .await
+        .expect("handle_event must succeed");
+    assert!(
+        rx.try_recv().is_err(),
+        "channel must be empty — event should have been dropped"
+    );
+}
+
 #[tokio::test]
 async fn test_handle_event_full_channel_drops_event_without_error() {
     // Channel with capacity 0 is impossible; use capacity 1 and fill it first.
@@ -608,6 +772,87 @@ async fn test_handle_event_full_channel_drops_event_without_error() {
     assert!(rx.try_recv().is_err(), "no second event should be present");
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// EventChannel tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn handler_with_capacity(
+    capacity: usize,
+    allowed_repos: Vec<String>,
+) -> (ReleaseRegentWebhookHandler, mpsc::Receiver<ProcessingEvent>) {
+    let (tx, rx) = mpsc::channel::<ProcessingEvent>(capacity);
+    let handler =
+        ReleaseRegentWebhookHandler::new(tx, allowed_repos, "release".to_string(), "v".to_string());
+    (handler, rx)
+}
+
+#[tokio::test]
+async fn test_reserve_fails_when_the_channel_is_full() {
+    let (handler, _rx) = handler_with_capacity(1, vec!["*".to_string()]);
+    let channel = handler.event_channel();
+
+    let _held = channel.reserve().expect("first reservation must succeed");
+
+    assert_eq!(channel.reserve().err(), Some(ChannelUnavailable::Full));
+}
+
+#[tokio::test]
+async fn test_reserve_fails_when_the_channel_is_closed() {
+    let (handler, rx) = handler_with_capacity(1, vec!["*".to_string()]);
+    drop(rx);
+
+    assert_eq!(
+        handler.event_channel().reserve().err(),
+        Some(ChannelUnavailable::Closed)
+    );
+}
+
+#[tokio::test]
+async fn test_reservation_not_accepted_frees_its_room() {
+    let (handler, _rx) = handler_with_capacity(1, vec!["*".to_string()]);
+    let channel = handler.event_channel();
+
+    drop(channel.reserve().expect("reservation must succeed"));
+
+    assert!(channel.reserve().is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_event_sends_the_event_in_the_reserved_room() {
+    let (handler, mut rx) = handler_with_capacity(1, vec!["*".to_string()]);
+    let channel = handler.event_channel();
+    channel
+        .reserve()
+        .expect("reservation must succeed")
+        .accept();
+
+    handler
+        .handle_event(&make_envelope("pull_request", merged_pr_payload()))
+        .await
+        .expect("handle_event must succeed");
+
+    let event = rx.try_recv().expect("event must be in the channel");
+    assert_eq!(event.event_type, EventType::PullRequestMerged);
+}
+
+#[tokio::test]
+async fn test_handle_event_dropping_an_event_frees_the_reserved_room() {
+    let (handler, mut rx) = handler_with_capacity(1, vec![]);
+    let channel = handler.event_channel();
+    channel
+        .reserve()
+        .expect("reservation must succeed")
+        .accept();
+
+    handler
+        .handle_event(&make_envelope("pull_request", merged_pr_payload()))
+        .await
+        .expect("handle_event must succeed");
+
+    assert!(rx.try_recv().is_err(), "denied event must not be forwarded");
+    assert!(channel.reserve().is_ok(), "the room must be free again");
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // WebhookEventSource tests
 // ─────────────────────────────────────────────────────────────────────────────
@@ -817,32 +1062,7 @@ async fn test_receive_webhook_valid_request_invokes_handler_and_sends_event() {
     receiver.add_handler(handler).await;
 
     // Use a standard closed+merged PR payload so event type is PullRequestMerged.
-    let payload = json!({
-        "action": "closed",
-        "pull_request": {
-            "merged": true,
-            "head": { "ref": "feature/my-feature" }
-        },
-        "repository": {
-            "id": 1,
-            "name": "test-repo",
-            "full_name": "owner/test-repo",
-            "owner": {
-                "login": "owner",
-                "id": 1,
-                "avatar_url": "https://github.com/avatars/u/1",
-                "type": "Organization"
-            },
-            "private": false,
-            "default_branch": "main",
-            "html_url": "https://github.com/owner/test-repo",
-            "clone_url": "https://github.com/owner/test-repo.git",
-            "ssh_url": "git@github.com:owner/test-repo.git",
-            "created_at": "2024-01-01T00:00:00Z",
-            "updated_at": "2024-01-01T00:00:00Z"
-        }
-    })
-    .to_string();
+    let payload = merged_pr_payload().to_string();
 
     let request = signed_webhook_request("pull_request", &payload, SECRET);
     let response = receiver.receive_webhook(request).await;
@@ -9,14 +9,87 @@
 //! | Env var                  | Description                                          | Default |
 //! |--------------------------|------------------------------------------------------|---------|
 //! | `GITHUB_WEBHOOK_SECRET`  | HMAC-SHA256 secret shared with GitHub (**required**) | —                  |
+//! | `GITHUB_WEBHOOK_SECRETS` | Comma-separated secrets also accepted while rotating; see [`webhook_secrets`] | unset |
 //! | `GITHUB_APP_ID`          | Numeric GitHub App ID (**required**)                 | —                  |
 //! | `GITHUB_PRIVATE_KEY`     | PEM-encoded GitHub App private key (**required**)    | —                  |
 //! | `CONFIG_DIR`             | Directory to search for `.release-regent.toml`       | current directory  |
-//! | `ALLOWED_REPOS`          | Comma-separated `owner/repo` values, or `*`          | `*`                |
+//! | `ALLOWED_REPOS`          | Comma-separated `owner/repo` patterns (`owner/*`), or `*` | `*`           |
 //! | `EVENT_CHANNEL_CAPACITY` | Bounded channel depth for in-flight events           | `1024`             |
 //! | `PORT`                   | TCP port the server listens on                       | `8080`             |
+//! | `SHUTDOWN_DRAIN_TIMEOUT_SECS` | Seconds in-flight work may take after a shutdown signal | `30`        |
+//! | `LOOKUP_DEFAULT_BRANCH`  | Always read the default branch from the GitHub API   | `false`            |
 //! | `RELEASE_BRANCH_PREFIX`  | Release branch prefix for webhook routing            | `"release"`        |
 //! | `VERSION_PREFIX`         | Version prefix for webhook routing (e.g. `""` or `"v"`) | `"v"`           |
+//! | `WEBHOOK_MAX_AGE_SECS`   | Reject deliveries older than this; unset disables the check | unset       |
+//! | `WEBHOOK_CLOCK_SKEW_SECS` | Clock skew tolerated by the delivery age check      | `30`               |
+//! | `WEBHOOK_TIMESTAMP_HEADER` | Header set by a trusted proxy that dates a delivery; the payload is used when unset | unset |
+//! | `WEBHOOK_CONCURRENCY_LIMIT` | Webhook requests taken in at the same time      | `32`               |
+//! | `WEBHOOK_QUEUE_DEPTH`    | Webhook requests that may wait for a free slot       | `64`               |
+//! | `WEBHOOK_QUEUE_TIMEOUT_SECS` | Seconds a request may wait for a free slot       | `5`                |
+//! | `WEBHOOK_MAX_PAYLOAD_BYTES` | Largest webhook body accepted, in bytes          | `1048576` (1 MiB)  |
+//! | `WEBHOOK_REQUIRE_GITHUB_USER_AGENT` | Reject requests whose `User-Agent` is not GitHub's | `false` |
+//! | `RELEASE_REGENT_LOG_FORMAT` | Log output format: `pretty` or `json`              | `pretty`           |
+//!
+//! # Replay protection
+//!
+//! When `WEBHOOK_MAX_AGE_SECS` is set, deliveries older than that are rejected
+//! with HTTP 401 before their signature is checked. The signature does not
+//! cover headers, so `WEBHOOK_TIMESTAMP_HEADER` is only safe when a trusted
+//! proxy sets it. See [`replay_protection`].
+//!
+//! # Concurrency limit
+//!
+//! At most `WEBHOOK_CONCURRENCY_LIMIT` webhook requests are taken in at once:
+//! read, verified and queued for processing. Up to `WEBHOOK_QUEUE_DEPTH` more
+//! wait up to `WEBHOOK_QUEUE_TIMEOUT_SECS` for a free slot; the rest, and
+//! those that time out, get HTTP 503 with a `Retry-After` header. See
+//! [`concurrency_limit`].
+//!
+//! GitHub does not retry a delivery that fails, whatever the status, so a
+//! delivery turned away with 503 is lost unless it is redelivered by hand or
+//! through the redeliver API.
+//!
+//! The limit does not cover event processing. Accepted events wait in a
+//! channel of `EVENT_CHANNEL_CAPACITY` events and are processed one at a time
+//! by the event loop. Every delivery reserves room in that channel before it
+//! is acknowledged; while the channel is full, deliveries get HTTP 503 with a
+//! `Retry-After` header instead of being acknowledged and then dropped, so
+//! that the failure shows in GitHub's delivery log.
+//!
+//! # Payload size limit
+//!
+//! Webhook bodies larger than `WEBHOOK_MAX_PAYLOAD_BYTES` are rejected with
+//! HTTP 413 and a JSON error while the body is being read, so an oversized
+//! payload is never buffered in full.
+//!
+//! # User-Agent check
+//!
+//! GitHub sends every delivery with a `User-Agent` starting with
+//! `GitHub-Hookshot/`. When `WEBHOOK_REQUIRE_GITHUB_USER_AGENT` is `true`,
+//! requests with any other or no `User-Agent` are rejected with HTTP 403
+//! before their signature is checked. The header is easily forged, so the
+//! check only turns away obviously foreign traffic early; the signature check
+//! still applies to every request. Leave it off behind proxies that rewrite
+//! the header.
+//!
+//! # Request logging
+//!
+//! Every request is logged once on completion by [`request_log::log_requests`]
+//! with its status, duration, payload size and GitHub delivery ID. Signature
+//! and secret headers are redacted.
+//!
+//! # Metrics
+//!
+//! `GET /metrics` returns Prometheus metrics: webhooks received, events
+//! processed, ignored or failed, and latency histograms for event processing,
+//! the key processing stages and GitHub API calls. See [`metrics`].
+//!
+//! # Health endpoints
+//!
+//! - `GET /` is a liveness check that always returns HTTP 200.
+//! - `GET /health/ready` is a readiness check that loads the configuration and
+//!   lists the GitHub App's installations, returning HTTP 503 with a JSON
+//!   breakdown when either fails. Results are cached for 30 seconds.
 //!
 //! # Architecture
 //!
@@ -38,34 +111,58 @@
 //! When `SIGINT` (Ctrl-C) is received (or `SIGTERM` on Unix), the token is cancelled:
 //! - Axum stops accepting new connections after completing in-flight requests.
 //! - The event loop finishes processing the current event and then exits.
+//!
+//! Both get `SHUTDOWN_DRAIN_TIMEOUT_SECS` to finish. Work still running after
+//! that is abandoned so that the process exits before the orchestrator's own
+//! kill deadline; keep the timeout below the container's termination grace
+//! period.
 
 use axum::{
-    extract::{DefaultBodyLimit, State},
+    extract::{rejection::BytesRejection, DefaultBodyLimit, State},
     http::{HeaderMap, StatusCode},
-    response::Json,
+    response::{IntoResponse, Json, Response},
     routing::{get, post},
     Router,
 };
 use bytes::Bytes;
-use github_bot_sdk::{
-    events::{EventProcessor, ProcessorConfig},
-    webhook::{WebhookReceiver, WebhookRequest, WebhookResponse},
+use github_bot_sdk::webhook::{WebhookRequest, WebhookResponse};
+use release_regent_core::telemetry::{LogFormat, LOG_FORMAT_ENV};
+use release_regent_core::{
+    run_event_loop, telemetry, GitHubVersionCalculator, RepositoryAllowList, VersionCalculator,
+};
+use std::{
+    collections::HashMap,
+    future::IntoFuture,
+    sync::Arc,
+    time::{Duration, Instant},
 };
-use release_regent_core::{run_event_loop, GitHubVersionCalculator, VersionCalculator};
-use std::{collections::HashMap, sync::Arc};
 use tokio::net::TcpListener;
 use tokio_util::sync::CancellationToken;
-use tracing::{error, info, warn};
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use tracing::{error, info, warn, Subscriber};
+use tracing_subscriber::{
+    fmt::MakeWriter, layer::SubscriberExt, registry::LookupSpan, util::SubscriberInitExt, Layer,
+};
 
+mod concurrency_limit;
 mod errors;
 mod handler;
+mod metrics;
+mod readiness;
+mod replay_protection;
+mod request_log;
+mod webhook_secrets;
 
 #[cfg(test)]
 #[path = "main_tests.rs"]
 mod tests;
 
-use handler::WebhookSecretProvider;
+use concurrency_limit::{
+    IntakeLimiter, DEFAULT_CONCURRENCY_LIMIT, DEFAULT_QUEUE_DEPTH, DEFAULT_QUEUE_TIMEOUT,
+};
+use handler::EventChannel;
+use readiness::{ConfigLoadCheck, GitHubAppCheck, ReadinessChecker, READINESS_CACHE_TTL};
+use replay_protection::{ReplayProtection, DEFAULT_CLOCK_SKEW};
+use webhook_secrets::{RotatingWebhookReceiver, WebhookSecrets};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Type aliases
@@ -83,11 +180,21 @@ type ServerProcessor = release_regent_core::ReleaseRegentProcessor<
     Arc<dyn VersionCalculator + Send + Sync>,
 >;
 
-/// Maximum allowed webhook payload size (10 MiB).
+/// Default maximum webhook payload size (1 MiB).
 ///
-/// Requests larger than this limit are rejected by the `DefaultBodyLimit` Axum
+/// Requests larger than the limit are rejected by the `DefaultBodyLimit` Axum
 /// layer before the signature validator even runs.
-const MAX_BODY_BYTES: usize = 10 * 1024 * 1024;
+const DEFAULT_MAX_PAYLOAD_BYTES: usize = 1024 * 1024;
+
+/// Default time in-flight requests and events get to finish after a shutdown
+/// signal.
+const DEFAULT_SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Prefix of the `User-Agent` GitHub sends with every webhook delivery.
+const GITHUB_USER_AGENT_PREFIX: &str = "GitHub-Hookshot/";
+
+/// How often expired GitHub tokens are removed from the processor's token cache.
+const TOKEN_CACHE_CLEANUP_INTERVAL: Duration = Duration::from_secs(5 * 60);
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Processor construction helpers
@@ -145,16 +252,13 @@ async fn build_server_processor(webhook_secret: String) -> Result<ServerProcesso
         app_id,
         private_key,
         webhook_secret,
+        jwt_expiration_seconds: release_regent_github_client::DEFAULT_JWT_EXPIRATION_SECS,
+        request_timeout_seconds: release_regent_github_client::DEFAULT_REQUEST_TIMEOUT_SECS,
     };
 
     let github_client = release_regent_github_client::GitHubClient::from_config(auth_config)?;
 
-    let config_dir = match std::env::var("CONFIG_DIR") {
-        Ok(dir) => std::path::PathBuf::from(dir),
-        Err(_) => std::env::current_dir().map_err(|e| {
-            errors::Error::internal(format!("Failed to determine working directory: {e}"))
-        })?,
-    };
+    let config_dir = config_dir_from_env()?;
     info!(config_dir = %config_dir.display(), "Using configuration directory");
 
     let config_provider = release_regent_config_provider::GitHubConfigurationProvider::new(
@@ -178,15 +282,97 @@ async fn build_server_processor(webhook_secret: String) -> Result<ServerProcesso
     ))
 }
 
+/// Directory named by `CONFIG_DIR`, or the current working directory.
+///
+/// # Errors
+///
+/// Returns [`errors::Error::Internal`] if `CONFIG_DIR` is absent and the
+/// working directory cannot be determined.
+#[allow(clippy::result_large_err)] // errors::Error is intentionally large
+fn config_dir_from_env() -> Result<std::path::PathBuf, errors::Error> {
+    match std::env::var("CONFIG_DIR") {
+        Ok(dir) => Ok(std::path::PathBuf::from(dir)),
+        Err(_) => std::env::current_dir().map_err(|e| {
+            errors::Error::internal(format!("Failed to determine working directory: {e}"))
+        }),
+    }
+}
+
+/// Construct the [`ReadinessChecker`] behind `/health/ready`.
+///
+/// The checks use their own GitHub client and file configuration provider,
+/// built from the same environment variables as the processor, so a failing
+/// check reflects what the processor would run into.
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`build_server_processor`].
+async fn build_readiness_checker(
+    webhook_secret: String,
+) -> Result<ReadinessChecker, errors::Error> {
+    let (app_id, private_key) = read_github_credentials_from_env()?;
+    let github_client = release_regent_github_client::GitHubClient::from_config(
+        release_regent_github_client::AuthConfig {
+            app_id,
+            private_key,
+            webhook_secret,
+            jwt_expiration_seconds: release_regent_github_client::DEFAULT_JWT_EXPIRATION_SECS,
+            request_timeout_seconds: release_regent_github_client::DEFAULT_REQUEST_TIMEOUT_SECS,
+        },
+    )?;
+
+    let config_provider =
+        release_regent_config_provider::FileConfigurationProvider::new(config_dir_from_env()?)
+            .await
+            .map_err(|e| errors::Error::config_provider(e.to_string()))?;
+
+    Ok(ReadinessChecker::new(
+        vec![
+            Box::new(ConfigLoadCheck::new(config_provider)),
+            Box::new(GitHubAppCheck::new(github_client)),
+        ],
+        READINESS_CACHE_TTL,
+    ))
+}
+
 /// Application state cloned into every Axum request handler.
 #[derive(Clone)]
 struct AppState {
-    receiver: Arc<WebhookReceiver>,
+    receiver: Arc<RotatingWebhookReceiver>,
+    readiness: Arc<ReadinessChecker>,
+    /// Delivery age check; `None` when replay protection is disabled.
+    replay_protection: Option<Arc<ReplayProtection>>,
+    /// Caps the number of webhook requests read, verified and queued at the
+    /// same time; event processing is not covered.
+    intake_limiter: Arc<IntakeLimiter>,
+    /// Channel to the event loop, in which every delivery reserves room for
+    /// its event before it is acknowledged.
+    event_channel: EventChannel,
+    /// Largest webhook body accepted, in bytes.
+    max_payload_bytes: usize,
+    /// Reject requests whose `User-Agent` is not GitHub's.
+    require_github_user_agent: bool,
 }
 
-/// Health check endpoint.
+/// Build the HTTP router serving the health, metrics and webhook endpoints.
 ///
-/// Returns `{"status":"healthy","service":"release-regent-webhook"}` with HTTP 200.
+/// Request bodies are limited to `state.max_payload_bytes`.
+fn build_router(state: AppState) -> Router {
+    let max_payload_bytes = state.max_payload_bytes;
+    Router::new()
+        .route("/", get(health_check))
+        .route("/health/ready", get(readiness_check))
+        .route("/metrics", get(metrics::metrics_handler))
+        .route("/webhook", post(webhook_handler))
+        .layer(DefaultBodyLimit::max(max_payload_bytes))
+        .layer(axum::middleware::from_fn(request_log::log_requests))
+        .with_state(state)
+}
+
+/// Liveness endpoint.
+///
+/// Returns `{"status":"healthy","service":"release-regent-webhook"}` with HTTP 200
+/// without checking any dependencies; see [`readiness_check`] for that.
 async fn health_check() -> Json<serde_json::Value> {
     Json(serde_json::json!({
         "status": "healthy",
@@ -194,14 +380,37 @@ async fn health_check() -> Json<serde_json::Value> {
     }))
 }
 
+/// Readiness endpoint.
+///
+/// Returns HTTP 200 when the configuration loads and the GitHub App can reach
+/// the GitHub API, and HTTP 503 otherwise. The body lists the outcome of each
+/// check, e.g. `{"status":"degraded","checks":[{"name":"config","status":"ok"},
+/// {"name":"github","status":"failed","error":"..."}]}`. Results are cached
+/// for [`READINESS_CACHE_TTL`].
+async fn readiness_check(
+    State(state): State<AppState>,
+) -> (StatusCode, Json<readiness::ReadinessReport>) {
+    state.readiness.respond().await
+}
+
 /// Receive an incoming GitHub webhook HTTP request.
 ///
 /// Converts the raw Axum headers and body into a [`WebhookRequest`] and
-/// delegates signature validation and dispatch to the SDK's
-/// [`WebhookReceiver`]. The HTTP response is returned as soon as validation
+/// delegates signature validation and dispatch to a
+/// [`RotatingWebhookReceiver`], which accepts every configured webhook secret.
+/// The HTTP response is returned as soon as validation
 /// completes; the actual event processing happens asynchronously in the
 /// registered [`ReleaseRegentWebhookHandler`] (fire-and-forget).
 ///
+/// When [`AppState::require_github_user_agent`] is set, requests without a
+/// GitHub `User-Agent` get HTTP 403. Bodies larger than
+/// [`AppState::max_payload_bytes`] get HTTP 413 as soon as the limit is
+/// exceeded. Requests that do not get a slot from the
+/// [`IntakeLimiter`], or find no room for their event in the
+/// [`EventChannel`], get HTTP 503 with a `Retry-After` header. Deliveries rejected by the [`ReplayProtection`]
+/// check, when it is enabled, get HTTP 401 without reaching the SDK. Rejections carry a JSON error body
+/// (see [`errors::Error::into_response`]).
+///
 /// | SDK response    | HTTP status |
 /// |-----------------|-------------|
 /// | `Ok`            | 200         |
@@ -211,8 +420,8 @@ async fn health_check() -> Json<serde_json::Value> {
 async fn webhook_handler(
     State(state): State<AppState>,
     headers: HeaderMap,
-    body: Bytes,
-) -> StatusCode {
+    body: Result<Bytes, BytesRejection>,
+) -> Response {
     let headers_map: HashMap<String, String> = headers
         .iter()
         .filter_map(|(name, value)| {
@@ -225,45 +434,408 @@ async fn webhook_handler(
         })
         .collect();
 
+    // Only used as a label once the signature has been verified; until then
+    // the header is untrusted and rejections are labelled "unverified".
+    let event_type =
+        metrics::event_type_label(headers_map.get("x-github-event").map_or("", String::as_str));
+
+    if state.require_github_user_agent {
+        if let Err(error) = check_github_user_agent(&headers_map) {
+            warn!(
+                delivery_id = headers_map
+                    .get("x-github-delivery")
+                    .map_or("", String::as_str),
+                user_agent = headers_map.get("user-agent").map_or("", String::as_str),
+                "Webhook rejected: not from GitHub"
+            );
+            metrics::record_webhook(metrics::UNVERIFIED_EVENT_TYPE, "rejected");
+            return error.into_response();
+        }
+    }
+
+    let body = match body {
+        Ok(body) => body,
+        Err(rejection) => {
+            let error = if rejection.status() == StatusCode::PAYLOAD_TOO_LARGE {
+                errors::Error::payload_too_large(state.max_payload_bytes)
+            } else {
+                errors::Error::malformed_payload(rejection.body_text())
+            };
+            warn!(
+                delivery_id = headers_map.get("x-github-delivery").map_or("", String::as_str),
+                error = %error,
+                "Webhook rejected: unreadable body"
+            );
+            metrics::record_webhook(metrics::UNVERIFIED_EVENT_TYPE, "rejected");
+            return error.into_response();
+        }
+    };
+
+    // Held until the response is built; dropping it frees the slot. The
+    // event itself is processed later by the event loop, outside the slot.
+    let _intake_slot = match state.intake_limiter.acquire().await {
+        Ok(permit) => permit,
+        Err(overloaded) => {
+            warn!(
+                delivery_id = headers_map.get("x-github-delivery").map_or("", String::as_str),
+                reason = %overloaded,
+                "Webhook rejected: server busy"
+            );
+            metrics::record_webhook(metrics::UNVERIFIED_EVENT_TYPE, "throttled");
+            return errors::Error::overloaded(
+                overloaded.to_string(),
+                concurrency_limit::RETRY_AFTER,
+            )
+            .into_response();
+        }
+    };
+
+    let started = Instant::now();
+    if let Some(replay_protection) = &state.replay_protection {
+        if let Err(rejection) = replay_protection.check(&headers_map, &body, chrono::Utc::now()) {
+            warn!(
+                delivery_id = headers_map.get("x-github-delivery").map_or("", String::as_str),
+                reason = %rejection,
+                "Webhook rejected: possible replay"
+            );
+            telemetry::record_stage(
+                metrics::STAGE_SIGNATURE_VERIFICATION,
+                false,
+                started.elapsed(),
+            );
+            metrics::record_webhook(metrics::UNVERIFIED_EVENT_TYPE, "rejected");
+            return errors::Error::signature(rejection.to_string()).into_response();
+        }
+    }
+
+    // The SDK acknowledges the delivery before the event is queued, so room
+    // is reserved first: GitHub must not get a 200 for an event that is then
+    // dropped. The room is released again unless the delivery is accepted.
+    let reservation = match state.event_channel.reserve() {
+        Ok(reservation) => reservation,
+        Err(unavailable) => {
+            warn!(
+                delivery_id = headers_map.get("x-github-delivery").map_or("", String::as_str),
+                reason = %unavailable,
+                "Webhook rejected: event queue unavailable"
+            );
+            metrics::record_webhook(metrics::UNVERIFIED_EVENT_TYPE, "throttled");
+            return errors::Error::overloaded(
+                unavailable.to_string(),
+                concurrency_limit::RETRY_AFTER,
+            )
+            .into_response();
+        }
+    };
+
     let request = WebhookRequest::new(headers_map, body);
     let response = state.receiver.receive_webhook(request).await;
 
-    match response {
+    let (result, outcome, event_type) = match response {
         WebhookResponse::Ok { ref event_id, .. } => {
+            reservation.accept();
             info!(event_id = %event_id, "Webhook accepted");
-            StatusCode::OK
+            (Ok(()), "accepted", event_type)
         }
-        WebhookResponse::BadRequest { ref message } => {
+        WebhookResponse::BadRequest { message } => {
             warn!(details = %message, "Webhook rejected: bad request");
-            StatusCode::BAD_REQUEST
+            (
+                Err(errors::Error::malformed_payload(message)),
+                "rejected",
+                metrics::UNVERIFIED_EVENT_TYPE,
+            )
         }
-        WebhookResponse::Unauthorized { ref message } => {
+        WebhookResponse::Unauthorized { message } => {
             warn!(details = %message, "Webhook rejected: unauthorized");
-            StatusCode::UNAUTHORIZED
+            (
+                Err(errors::Error::signature(message)),
+                "rejected",
+                metrics::UNVERIFIED_EVENT_TYPE,
+            )
         }
-        WebhookResponse::InternalError { ref message } => {
+        WebhookResponse::InternalError { message } => {
             error!(details = %message, "Webhook processing error");
-            StatusCode::INTERNAL_SERVER_ERROR
+            (Err(errors::Error::internal(message)), "error", event_type)
+        }
+    };
+    telemetry::record_stage(
+        metrics::STAGE_SIGNATURE_VERIFICATION,
+        result.is_ok(),
+        started.elapsed(),
+    );
+    metrics::record_webhook(event_type, outcome);
+    match result {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+/// Check that the `User-Agent` header is the one GitHub sends with webhook
+/// deliveries, i.e. starts with [`GITHUB_USER_AGENT_PREFIX`].
+///
+/// # Errors
+///
+/// Returns [`errors::Error::Forbidden`] when the header is missing or has any
+/// other value.
+#[allow(clippy::result_large_err)] // errors::Error is intentionally large
+fn check_github_user_agent(headers: &HashMap<String, String>) -> Result<(), errors::Error> {
+    match headers.get("user-agent") {
+        Some(user_agent) if user_agent.starts_with(GITHUB_USER_AGENT_PREFIX) => Ok(()),
+        Some(_) => Err(errors::Error::forbidden(format!(
+            "User-Agent does not start with '{GITHUB_USER_AGENT_PREFIX}'"
+        ))),
+        None => Err(errors::Error::forbidden("User-Agent header is missing")),
+    }
+}
+
+/// Read `WEBHOOK_REQUIRE_GITHUB_USER_AGENT`: whether to reject webhook
+/// requests whose `User-Agent` is not GitHub's.
+///
+/// Only `true` and `1` enable the check; any other value, or none, leaves it
+/// disabled.
+fn require_github_user_agent_from_env() -> bool {
+    std::env::var("WEBHOOK_REQUIRE_GITHUB_USER_AGENT")
+        .is_ok_and(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "true" | "1"))
+}
+
+/// Read `WEBHOOK_VALIDATE_SCHEMA`: whether to check every webhook payload
+/// against the JSON Schema of its event kind before processing it.
+///
+/// Only `true` and `1` enable the check; any other value, or none, leaves it
+/// disabled.
+fn validate_webhook_schema_from_env() -> bool {
+    std::env::var("WEBHOOK_VALIDATE_SCHEMA")
+        .is_ok_and(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "true" | "1"))
+}
+
+/// Read `LOOKUP_DEFAULT_BRANCH`: whether to always read a repository's
+/// default branch from the GitHub API rather than from the webhook payload.
+///
+/// Only `true` and `1` enable the lookup; any other value, or none, leaves it
+/// disabled.
+fn lookup_default_branch_from_env() -> bool {
+    std::env::var("LOOKUP_DEFAULT_BRANCH")
+        .is_ok_and(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "true" | "1"))
+}
+
+/// Build the webhook replay protection from `WEBHOOK_MAX_AGE_SECS`,
+/// `WEBHOOK_CLOCK_SKEW_SECS` and `WEBHOOK_TIMESTAMP_HEADER`.
+///
+/// Returns `None`, disabling the check, when `WEBHOOK_MAX_AGE_SECS` is absent,
+/// zero or not a whole number of seconds. An invalid skew falls back to
+/// [`DEFAULT_CLOCK_SKEW`].
+fn replay_protection_from_env() -> Option<ReplayProtection> {
+    let max_age = std::env::var("WEBHOOK_MAX_AGE_SECS").ok()?;
+    let max_age = match max_age.trim().parse::<u64>() {
+        Ok(0) => return None,
+        Ok(secs) => Duration::from_secs(secs),
+        Err(_) => {
+            warn!(
+                value = %max_age,
+                variable = "WEBHOOK_MAX_AGE_SECS",
+                "Invalid value; webhook replay protection is disabled"
+            );
+            return None;
         }
+    };
+
+    let clock_skew = match std::env::var("WEBHOOK_CLOCK_SKEW_SECS") {
+        Ok(s) => s
+            .trim()
+            .parse::<u64>()
+            .map(Duration::from_secs)
+            .unwrap_or_else(|_| {
+                warn!(
+                    value = %s,
+                    variable = "WEBHOOK_CLOCK_SKEW_SECS",
+                    "Invalid value; using default {}s",
+                    DEFAULT_CLOCK_SKEW.as_secs()
+                );
+                DEFAULT_CLOCK_SKEW
+            }),
+        Err(_) => DEFAULT_CLOCK_SKEW,
+    };
+
+    let protection = ReplayProtection::new(max_age).with_clock_skew(clock_skew);
+    match std::env::var("WEBHOOK_TIMESTAMP_HEADER") {
+        Ok(header) if !header.trim().is_empty() => {
+            Some(protection.with_timestamp_header(header.trim()))
+        }
+        _ => Some(protection),
+    }
+}
+
+/// Build the webhook [`IntakeLimiter`] from `WEBHOOK_CONCURRENCY_LIMIT`,
+/// `WEBHOOK_QUEUE_DEPTH` and `WEBHOOK_QUEUE_TIMEOUT_SECS`.
+///
+/// Each variable falls back to its default when absent or not a whole number;
+/// a limit of zero is also replaced by the default.
+fn intake_limiter_from_env() -> IntakeLimiter {
+    fn read(variable: &str, default: u64) -> Option<u64> {
+        let value = std::env::var(variable).ok()?;
+        match value.trim().parse::<u64>() {
+            Ok(parsed) => Some(parsed),
+            Err(_) => {
+                warn!(
+                    value = %value,
+                    variable,
+                    "Invalid value; using default {default}"
+                );
+                None
+            }
+        }
+    }
+
+    let limit = read(
+        "WEBHOOK_CONCURRENCY_LIMIT",
+        DEFAULT_CONCURRENCY_LIMIT as u64,
+    )
+    .filter(|&limit| limit > 0)
+    .map_or(DEFAULT_CONCURRENCY_LIMIT, |limit| limit as usize);
+    let queue_depth = read("WEBHOOK_QUEUE_DEPTH", DEFAULT_QUEUE_DEPTH as u64)
+        .map_or(DEFAULT_QUEUE_DEPTH, |depth| depth as usize);
+    let queue_timeout = read(
+        "WEBHOOK_QUEUE_TIMEOUT_SECS",
+        DEFAULT_QUEUE_TIMEOUT.as_secs(),
+    )
+    .map_or(DEFAULT_QUEUE_TIMEOUT, Duration::from_secs);
+    IntakeLimiter::new(limit, queue_depth).with_queue_timeout(queue_timeout)
+}
+
+/// Read the largest accepted webhook body from `WEBHOOK_MAX_PAYLOAD_BYTES`.
+///
+/// Falls back to [`DEFAULT_MAX_PAYLOAD_BYTES`] when the variable is absent,
+/// zero or not a whole number of bytes.
+fn max_payload_bytes_from_env() -> usize {
+    match std::env::var("WEBHOOK_MAX_PAYLOAD_BYTES") {
+        Ok(s) => match s.trim().parse::<usize>() {
+            Ok(bytes) if bytes > 0 => bytes,
+            _ => {
+                warn!(
+                    value = %s,
+                    variable = "WEBHOOK_MAX_PAYLOAD_BYTES",
+                    "Invalid value; using default {DEFAULT_MAX_PAYLOAD_BYTES} bytes"
+                );
+                DEFAULT_MAX_PAYLOAD_BYTES
+            }
+        },
+        Err(_) => DEFAULT_MAX_PAYLOAD_BYTES,
+    }
+}
+
+/// Read the shutdown drain timeout from `SHUTDOWN_DRAIN_TIMEOUT_SECS`.
+///
+/// Falls back to [`DEFAULT_SHUTDOWN_DRAIN_TIMEOUT`] when the variable is
+/// absent or not a whole number of seconds.
+fn shutdown_drain_timeout_from_env() -> Duration {
+    match std::env::var("SHUTDOWN_DRAIN_TIMEOUT_SECS") {
+        Ok(s) => s
+            .parse::<u64>()
+            .map(Duration::from_secs)
+            .unwrap_or_else(|_| {
+                warn!(
+                    value = %s,
+                    variable = "SHUTDOWN_DRAIN_TIMEOUT_SECS",
+                    "Invalid value; using default {}s",
+                    DEFAULT_SHUTDOWN_DRAIN_TIMEOUT.as_secs()
+                );
+                DEFAULT_SHUTDOWN_DRAIN_TIMEOUT
+            }),
+        Err(_) => DEFAULT_SHUTDOWN_DRAIN_TIMEOUT,
+    }
+}
+
+/// Serve `app` until `shutdown_token` is cancelled, then drain.
+///
+/// Once the token is cancelled the listener stops accepting connections and
+/// in-flight requests get `drain_timeout` to complete. Requests still running
+/// after that are dropped and the function returns.
+///
+/// # Errors
+///
+/// Returns an error if the server fails while serving.
+async fn serve_with_graceful_shutdown(
+    listener: TcpListener,
+    app: Router,
+    shutdown_token: CancellationToken,
+    drain_timeout: Duration,
+) -> std::io::Result<()> {
+    let server_token = shutdown_token.clone();
+    let server = axum::serve(listener, app)
+        .with_graceful_shutdown(async move { server_token.cancelled().await })
+        .into_future();
+    tokio::pin!(server);
+
+    tokio::select! {
+        result = &mut server => return result,
+        () = shutdown_token.cancelled() => {}
+    }
+
+    info!(
+        timeout_secs = drain_timeout.as_secs(),
+        "Draining in-flight requests"
+    );
+    if let Ok(result) = tokio::time::timeout(drain_timeout, server).await {
+        result
+    } else {
+        warn!(
+            timeout_secs = drain_timeout.as_secs(),
+            "In-flight requests did not finish before the drain timeout; abandoning them"
+        );
+        Ok(())
     }
 }
 
 /// Initialise structured logging from `RUST_LOG` or a sensible default filter.
-fn setup_logging() {
+///
+/// The output format is read from `RELEASE_REGENT_LOG_FORMAT`. The filter only
+/// applies to the log output; GitHub call latency is measured from spans
+/// regardless of the log level.
+///
+/// # Errors
+///
+/// Returns [`errors::Error::Environment`] if `RELEASE_REGENT_LOG_FORMAT` is
+/// not `json` or `pretty`.
+#[allow(clippy::result_large_err)] // errors::Error is intentionally large
+fn setup_logging() -> Result<(), errors::Error> {
+    let format =
+        LogFormat::from_env().map_err(|e| errors::Error::environment(LOG_FORMAT_ENV, e))?;
     let filter = tracing_subscriber::filter::EnvFilter::try_from_default_env()
         .unwrap_or_else(|_| {
             "release_regent_server=debug,release_regent_core=debug,release_regent_github_client=debug,info".into()
         });
 
     tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::fmt::layer()
-                .with_target(false)
-                .with_timer(tracing_subscriber::fmt::time::UtcTime::rfc_3339())
-                .with_level(true),
-        )
-        .with(filter)
+        .with(log_layer(format, std::io::stdout).with_filter(filter))
+        .with(metrics::GitHubCallMetricsLayer)
         .init();
+    Ok(())
+}
+
+/// Log layer rendering events as `format` to `writer`.
+///
+/// JSON lines carry the event fields at the top level and the fields of the
+/// enclosing spans, including the request's `correlation_id`, under `span`
+/// and `spans`.
+fn log_layer<S, W>(format: LogFormat, writer: W) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    let layer = tracing_subscriber::fmt::layer()
+        .with_writer(writer)
+        .with_target(false)
+        .with_timer(tracing_subscriber::fmt::time::UtcTime::rfc_3339())
+        .with_level(true);
+    match format {
+        LogFormat::Pretty => layer.boxed(),
+        LogFormat::Json => layer
+            .json()
+            .flatten_event(true)
+            .with_current_span(true)
+            .with_span_list(true)
+            .boxed(),
+    }
 }
 
 /// Main entry point for the Release Regent webhook server.
@@ -275,31 +847,33 @@ fn setup_logging() {
 /// # Errors
 ///
 /// Returns an error if:
-/// - Any of `GITHUB_WEBHOOK_SECRET`, `GITHUB_APP_ID`, or `GITHUB_PRIVATE_KEY` is absent from the
-///   environment. (`GITHUB_INSTALLATION_ID` is resolved per-event from the webhook payload.)
+/// - Neither `GITHUB_WEBHOOK_SECRET` nor `GITHUB_WEBHOOK_SECRETS` is set, or `GITHUB_APP_ID` or
+///   `GITHUB_PRIVATE_KEY` is absent from the environment. (`GITHUB_INSTALLATION_ID` is resolved
+///   per-event from the webhook payload.)
 /// - The TCP listener cannot bind to the configured address.
 /// - The Axum server exits with an error.
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    setup_logging();
+    setup_logging()?;
+    metrics::install_recorder();
 
     info!("Starting Release Regent webhook server");
 
     // ── Secret / configuration loading ────────────────────────────────────
 
-    // Load webhook secret.
-    let github_secret = std::env::var("GITHUB_WEBHOOK_SECRET")
-        .map_err(|e| errors::Error::environment("GITHUB_WEBHOOK_SECRET", e.to_string()))?;
+    // Load the webhook secrets; more than one is accepted during a rotation.
+    let webhook_secrets = WebhookSecrets::from_env()?;
+    info!(
+        secret_count = webhook_secrets.count(),
+        "Loaded webhook secrets"
+    );
 
     // ── Build production processor ─────────────────────────────────────────
 
     // Construct the real ReleaseRegentProcessor from GitHub App credentials.
     // Fails fast with a clear error message when any required variable is absent.
     info!("Building production processor from environment credentials");
-    let processor = Arc::new(build_server_processor(github_secret.clone()).await?);
-    info!("Production processor constructed successfully");
-
-    // Allowed repositories: comma-separated "owner/repo" values, or "*" for all.
+    // Allowed repositories: comma-separated "owner/repo" patterns, or "*" for all.
     let allowed_repos: Vec<String> = std::env::var("ALLOWED_REPOS").map_or_else(
         |_| vec!["*".to_string()],
         |s| {
@@ -311,6 +885,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         },
     );
 
+    // The webhook handler drops deliveries for other repositories early; the
+    // processor enforces the same list for events from any source.
+    let processor = Arc::new(
+        build_server_processor(webhook_secrets.primary().to_string())
+            .await?
+            .with_allowed_repos(RepositoryAllowList::new(&allowed_repos))
+            .with_default_branch_lookup(lookup_default_branch_from_env()),
+    );
+    info!("Production processor constructed successfully");
+
+    // Held until `main` returns; dropping it stops the cleanup task.
+    let _token_cache_cleanup = processor
+        .github_operations()
+        .start_cache_cleanup(TOKEN_CACHE_CLEANUP_INTERVAL);
+
+    let readiness = Arc::new(build_readiness_checker(webhook_secrets.primary().to_string()).await?);
+
     // Bounded channel capacity for in-flight events.
     let channel_capacity: usize = match std::env::var("EVENT_CHANNEL_CAPACITY") {
         Ok(s) => s.parse::<usize>().unwrap_or_else(|_| {
@@ -383,15 +974,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     // repositories served by this instance must use the same prefix.
     let default_orch = release_regent_core::release_orchestrator::OrchestratorConfig::default();
     let release_branch_prefix =
-        std::env::var("RELEASE_BRANCH_PREFIX").unwrap_or_else(|_| default_orch.branch_prefix);
-    let version_prefix =
-        std::env::var("VERSION_PREFIX").unwrap_or_else(|_| default_orch.version_prefix);
+        std::env::var("RELEASE_BRANCH_PREFIX").unwrap_or(default_orch.branch_prefix);
+    let version_prefix = std::env::var("VERSION_PREFIX").unwrap_or(default_orch.version_prefix);
     let (webhook_event_handler, event_source) = handler::create_webhook_components(
         allowed_repos,
         channel_capacity,
         release_branch_prefix,
         version_prefix,
     );
+    let validate_schema = validate_webhook_schema_from_env();
+    if validate_schema {
+        info!("Webhook payload schema validation enabled");
+    }
+    let webhook_event_handler = webhook_event_handler.with_schema_validation(validate_schema);
+    let event_channel = webhook_event_handler.event_channel();
 
     // Spawn the event processing loop.  It runs until the shutdown token is
     // cancelled, processing each `ProcessingEvent` from the mpsc channel.
@@ -405,37 +1001,70 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 
     // ── HTTP server ────────────────────────────────────────────────────────
 
-    // Build the SDK WebhookReceiver (validates signatures, dispatches to handlers).
-    let secret_provider = Arc::new(WebhookSecretProvider::new(github_secret));
-    let sdk_event_processor = EventProcessor::new(ProcessorConfig::default());
-    let mut receiver = WebhookReceiver::new(secret_provider, sdk_event_processor);
+    // Build the webhook receiver (validates signatures against every accepted
+    // secret, dispatches to handlers).
+    let mut receiver = RotatingWebhookReceiver::new(&webhook_secrets);
     receiver.add_handler(Arc::new(webhook_event_handler)).await;
 
+    let replay_protection = replay_protection_from_env();
+    if let Some(protection) = &replay_protection {
+        info!(
+            max_age_secs = protection.max_age().as_secs(),
+            clock_skew_secs = protection.clock_skew().as_secs(),
+            "Webhook replay protection enabled"
+        );
+    }
+
+    let intake_limiter = intake_limiter_from_env();
+    info!(
+        limit = intake_limiter.limit(),
+        queue_depth = intake_limiter.queue_depth(),
+        queue_timeout_secs = intake_limiter.queue_timeout().as_secs(),
+        "Webhook intake limit configured"
+    );
+
+    let max_payload_bytes = max_payload_bytes_from_env();
+    info!(max_payload_bytes, "Webhook payload size limit configured");
+
+    let require_github_user_agent = require_github_user_agent_from_env();
+    if require_github_user_agent {
+        info!("Webhook User-Agent check enabled");
+    }
+
     let state = AppState {
         receiver: Arc::new(receiver),
+        readiness,
+        replay_protection: replay_protection.map(Arc::new),
+        intake_limiter: Arc::new(intake_limiter),
+        event_channel,
+        max_payload_bytes,
+        require_github_user_agent,
     };
 
-    let app = Router::new()
-        .route("/", get(health_check))
-        .route("/webhook", post(webhook_handler))
-        .layer(DefaultBodyLimit::max(MAX_BODY_BYTES))
-        .with_state(state);
+    let app = build_router(state);
 
     let port = std::env::var("PORT").unwrap_or_else(|_| "8080".to_string());
     let addr = format!("0.0.0.0:{port}");
     let listener = TcpListener::bind(&addr).await?;
+    let drain_timeout = shutdown_drain_timeout_from_env();
 
     info!(address = %addr, "Server listening");
 
-    // `with_graceful_shutdown` waits for the token to be cancelled before
-    // closing the listener and draining in-flight connections.
-    let server_token = shutdown_token.clone();
-    axum::serve(listener, app)
-        .with_graceful_shutdown(async move { server_token.cancelled().await })
-        .await?;
+    // Waits for the token to be cancelled, then closes the listener and gives
+    // in-flight connections `drain_timeout` to finish.
+    serve_with_graceful_shutdown(listener, app, shutdown_token.clone(), drain_timeout).await?;
 
-    // Wait for the event loop to drain any in-flight events before exiting.
-    let _ = event_loop_handle.await;
+    // Wait for the event loop to finish the event it is processing, which may
+    // be a multi-step release, before exiting.
+    if tokio::time::timeout(drain_timeout, event_loop_handle)
+        .await
+        .is_err()
+    {
+        warn!(
+            timeout_secs = drain_timeout.as_secs(),
+            "Event loop did not stop before the drain timeout; exiting anyway"
+        );
+    }
 
     info!("Shutdown complete");
     Ok(())
@@ -141,6 +141,7 @@ const TEST_RSA_PRIVATE_KEY: &str = include_str!("../../github_client/test_key.pe
 /// The processor is constructed but never contacts the GitHub API during
 /// construction — token exchange only happens on the first API call.
 #[tokio::test]
+#[allow(clippy::await_holding_lock)] // ENV_LOCK must span the whole test
 async fn test_build_server_processor_with_valid_credentials_succeeds() {
     let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
     clear_github_app_env_vars();
@@ -163,6 +164,7 @@ async fn test_build_server_processor_with_valid_credentials_succeeds() {
 /// not valid PEM — the error originates from key parsing, before any network
 /// call is made.
 #[tokio::test]
+#[allow(clippy::await_holding_lock)] // ENV_LOCK must span the whole test
 async fn test_build_server_processor_with_invalid_pem_returns_github_error() {
     let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
     clear_github_app_env_vars();
@@ -191,3 +193,573 @@ async fn test_build_server_processor_with_invalid_pem_returns_github_error() {
         Ok(_) => panic!("Expected Err but got Ok"),
     }
 }
+
+// ──────────────────────────────────────────────────────────────────────────────
+// shutdown_drain_timeout_from_env
+// ──────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_shutdown_drain_timeout_reads_seconds_and_falls_back_on_invalid_value() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    std::env::remove_var("SHUTDOWN_DRAIN_TIMEOUT_SECS");
+    let default = shutdown_drain_timeout_from_env();
+    std::env::set_var("SHUTDOWN_DRAIN_TIMEOUT_SECS", "5");
+    let configured = shutdown_drain_timeout_from_env();
+    std::env::set_var("SHUTDOWN_DRAIN_TIMEOUT_SECS", "soon");
+    let invalid = shutdown_drain_timeout_from_env();
+    std::env::remove_var("SHUTDOWN_DRAIN_TIMEOUT_SECS");
+
+    assert_eq!(default, DEFAULT_SHUTDOWN_DRAIN_TIMEOUT);
+    assert_eq!(configured, Duration::from_secs(5));
+    assert_eq!(invalid, DEFAULT_SHUTDOWN_DRAIN_TIMEOUT);
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// lookup_default_branch_from_env
+// ──────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_lookup_default_branch_is_enabled_only_by_true_or_one() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    std::env::remove_var("LOOKUP_DEFAULT_BRANCH");
+    let unset = lookup_default_branch_from_env();
+    let mut enabled = Vec::new();
+    for value in ["true", "TRUE", "1", "false", "0", "yes"] {
+        std::env::set_var("LOOKUP_DEFAULT_BRANCH", value);
+        enabled.push(lookup_default_branch_from_env());
+    }
+    std::env::remove_var("LOOKUP_DEFAULT_BRANCH");
+
+    assert!(!unset);
+    assert_eq!(enabled, vec![true, true, true, false, false, false]);
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// validate_webhook_schema_from_env
+// ──────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_webhook_schema_validation_is_enabled_only_by_true_or_one() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    std::env::remove_var("WEBHOOK_VALIDATE_SCHEMA");
+    let unset = validate_webhook_schema_from_env();
+    let mut enabled = Vec::new();
+    for value in ["true", "1", "false", "no"] {
+        std::env::set_var("WEBHOOK_VALIDATE_SCHEMA", value);
+        enabled.push(validate_webhook_schema_from_env());
+    }
+    std::env::remove_var("WEBHOOK_VALIDATE_SCHEMA");
+
+    assert!(!unset);
+    assert_eq!(enabled, vec![true, true, false, false]);
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// replay_protection_from_env
+// ──────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_replay_protection_is_enabled_only_by_a_positive_max_age() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    std::env::remove_var("WEBHOOK_CLOCK_SKEW_SECS");
+    std::env::remove_var("WEBHOOK_TIMESTAMP_HEADER");
+
+    std::env::remove_var("WEBHOOK_MAX_AGE_SECS");
+    let unset = replay_protection_from_env();
+    std::env::set_var("WEBHOOK_MAX_AGE_SECS", "0");
+    let zero = replay_protection_from_env();
+    std::env::set_var("WEBHOOK_MAX_AGE_SECS", "five minutes");
+    let invalid = replay_protection_from_env();
+    std::env::set_var("WEBHOOK_MAX_AGE_SECS", "300");
+    let configured = replay_protection_from_env();
+    std::env::remove_var("WEBHOOK_MAX_AGE_SECS");
+
+    assert_eq!(unset, None);
+    assert_eq!(zero, None);
+    assert_eq!(invalid, None);
+    assert_eq!(
+        configured,
+        Some(ReplayProtection::new(Duration::from_secs(300)))
+    );
+}
+
+#[test]
+fn test_replay_protection_reads_clock_skew_and_timestamp_header() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    std::env::set_var("WEBHOOK_MAX_AGE_SECS", "300");
+    std::env::set_var("WEBHOOK_CLOCK_SKEW_SECS", "60");
+    std::env::set_var("WEBHOOK_TIMESTAMP_HEADER", "X-Delivery-Timestamp");
+    let configured = replay_protection_from_env();
+    std::env::set_var("WEBHOOK_CLOCK_SKEW_SECS", "a minute");
+    std::env::remove_var("WEBHOOK_TIMESTAMP_HEADER");
+    let invalid_skew = replay_protection_from_env();
+    std::env::remove_var("WEBHOOK_MAX_AGE_SECS");
+    std::env::remove_var("WEBHOOK_CLOCK_SKEW_SECS");
+
+    assert_eq!(
+        configured,
+        Some(
+            ReplayProtection::new(Duration::from_secs(300))
+                .with_clock_skew(Duration::from_secs(60))
+                .with_timestamp_header("x-delivery-timestamp")
+        )
+    );
+    assert_eq!(
+        invalid_skew.map(|p| p.clock_skew()),
+        Some(DEFAULT_CLOCK_SKEW)
+    );
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// serve_with_graceful_shutdown
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// Starts a server whose `/slow` route takes `request_duration` to respond.
+async fn start_slow_server(
+    request_duration: Duration,
+    drain_timeout: Duration,
+) -> (
+    std::net::SocketAddr,
+    CancellationToken,
+    tokio::task::JoinHandle<std::io::Result<()>>,
+) {
+    let app = Router::new().route(
+        "/slow",
+        get(move || async move {
+            tokio::time::sleep(request_duration).await;
+            "done"
+        }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let token = CancellationToken::new();
+    let server = tokio::spawn(serve_with_graceful_shutdown(
+        listener,
+        app,
+        token.clone(),
+        drain_timeout,
+    ));
+    (addr, token, server)
+}
+
+/// Sends `GET /slow` and returns the raw response, or an empty string when
+/// the connection is closed without one.
+async fn get_slow(addr: std::net::SocketAddr) -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+    stream
+        .write_all(b"GET /slow HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+        .await
+        .unwrap();
+    let mut response = String::new();
+    let _ = stream.read_to_string(&mut response).await;
+    response
+}
+
+#[tokio::test]
+async fn test_in_flight_request_completes_after_shutdown_signal() {
+    let (addr, token, server) =
+        start_slow_server(Duration::from_millis(300), Duration::from_secs(5)).await;
+
+    let request = tokio::spawn(get_slow(addr));
+    // Let the request reach the handler before signalling shutdown.
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    token.cancel();
+
+    let response = request.await.unwrap();
+    assert!(
+        response.starts_with("HTTP/1.1 200"),
+        "in-flight request should complete, got: {response:?}"
+    );
+    assert!(response.ends_with("done"));
+    server.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn test_shutdown_stops_waiting_after_drain_timeout() {
+    let (addr, token, server) =
+        start_slow_server(Duration::from_secs(30), Duration::from_millis(200)).await;
+
+    let _request = tokio::spawn(get_slow(addr));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let cancelled_at = std::time::Instant::now();
+    token.cancel();
+
+    server.await.unwrap().unwrap();
+    assert!(
+        cancelled_at.elapsed() < Duration::from_secs(5),
+        "server should stop draining after the timeout"
+    );
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// log_layer
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// `io::Write` sink shared between the log layer and the test.
+#[derive(Clone, Default)]
+struct CapturedLogs(std::sync::Arc<Mutex<Vec<u8>>>);
+
+impl std::io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl CapturedLogs {
+    fn text(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+/// Log a request-scoped event through a layer writing `format`.
+fn log_request_event(format: LogFormat) -> String {
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::registry().with(log_layer(format, move || writer.clone()));
+
+    tracing::subscriber::with_default(subscriber, || {
+        let span = tracing::info_span!("webhook_request", correlation_id = "corr-123");
+        let _entered = span.enter();
+        info!(event_type = "pull_request", "Webhook accepted");
+    });
+    logs.text()
+}
+
+#[test]
+fn test_log_layer_json_emits_one_object_per_event_with_span_fields() {
+    let output = log_request_event(LogFormat::Json);
+
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 1, "{output}");
+    let line: serde_json::Value = serde_json::from_str(lines[0]).expect("line should be JSON");
+    assert_eq!(line["message"], "Webhook accepted");
+    assert_eq!(line["event_type"], "pull_request");
+    assert_eq!(line["level"], "INFO");
+    assert_eq!(line["span"]["correlation_id"], "corr-123");
+    assert_eq!(line["spans"][0]["name"], "webhook_request");
+}
+
+#[test]
+fn test_log_layer_pretty_is_not_json() {
+    let output = log_request_event(LogFormat::Pretty);
+
+    assert!(output.contains("Webhook accepted"), "{output}");
+    assert!(output.contains("correlation_id"), "{output}");
+    assert!(serde_json::from_str::<serde_json::Value>(output.trim()).is_err());
+}
+
+#[test]
+fn test_log_format_from_env_rejects_unknown_format() {
+    let _guard = ENV_LOCK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    std::env::set_var(LOG_FORMAT_ENV, "xml");
+
+    let result = LogFormat::from_env();
+    std::env::remove_var(LOG_FORMAT_ENV);
+
+    let error = result.unwrap_err();
+    assert!(error.starts_with(LOG_FORMAT_ENV), "{error}");
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// intake_limiter_from_env
+// ──────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_concurrency_limiter_reads_limit_and_queue_depth() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    std::env::remove_var("WEBHOOK_CONCURRENCY_LIMIT");
+    std::env::remove_var("WEBHOOK_QUEUE_DEPTH");
+    std::env::remove_var("WEBHOOK_QUEUE_TIMEOUT_SECS");
+    let defaults = intake_limiter_from_env();
+    std::env::set_var("WEBHOOK_CONCURRENCY_LIMIT", "4");
+    std::env::set_var("WEBHOOK_QUEUE_DEPTH", "0");
+    std::env::set_var("WEBHOOK_QUEUE_TIMEOUT_SECS", "2");
+    let configured = intake_limiter_from_env();
+    std::env::set_var("WEBHOOK_CONCURRENCY_LIMIT", "0");
+    std::env::set_var("WEBHOOK_QUEUE_DEPTH", "many");
+    std::env::set_var("WEBHOOK_QUEUE_TIMEOUT_SECS", "soon");
+    let invalid = intake_limiter_from_env();
+    std::env::remove_var("WEBHOOK_CONCURRENCY_LIMIT");
+    std::env::remove_var("WEBHOOK_QUEUE_DEPTH");
+    std::env::remove_var("WEBHOOK_QUEUE_TIMEOUT_SECS");
+
+    assert_eq!(
+        (defaults.limit(), defaults.queue_depth()),
+        (DEFAULT_CONCURRENCY_LIMIT, DEFAULT_QUEUE_DEPTH)
+    );
+    assert_eq!((configured.limit(), configured.queue_depth()), (4, 0));
+    assert_eq!(configured.queue_timeout(), Duration::from_secs(2));
+    assert_eq!(invalid.queue_timeout(), DEFAULT_QUEUE_TIMEOUT);
+    assert_eq!(
+        (invalid.limit(), invalid.queue_depth()),
+        (DEFAULT_CONCURRENCY_LIMIT, DEFAULT_QUEUE_DEPTH)
+    );
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Payload size limit
+// ──────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_max_payload_bytes_reads_bytes_and_falls_back_on_invalid_value() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    std::env::remove_var("WEBHOOK_MAX_PAYLOAD_BYTES");
+    let default = max_payload_bytes_from_env();
+    std::env::set_var("WEBHOOK_MAX_PAYLOAD_BYTES", "4096");
+    let configured = max_payload_bytes_from_env();
+    std::env::set_var("WEBHOOK_MAX_PAYLOAD_BYTES", "0");
+    let zero = max_payload_bytes_from_env();
+    std::env::set_var("WEBHOOK_MAX_PAYLOAD_BYTES", "1MB");
+    let invalid = max_payload_bytes_from_env();
+    std::env::remove_var("WEBHOOK_MAX_PAYLOAD_BYTES");
+
+    assert_eq!(default, DEFAULT_MAX_PAYLOAD_BYTES);
+    assert_eq!(configured, 4096);
+    assert_eq!(zero, DEFAULT_MAX_PAYLOAD_BYTES);
+    assert_eq!(invalid, DEFAULT_MAX_PAYLOAD_BYTES);
+}
+
+/// State with no replay protection, no User-Agent check and a
+/// `max_payload_bytes` body limit.
+fn test_state(max_payload_bytes: usize) -> AppState {
+    let receiver = RotatingWebhookReceiver::new(&WebhookSecrets::new(["secret"]).unwrap());
+    let (tx, rx) = tokio::sync::mpsc::channel(8);
+    // No event loop reads the channel; it only has to stay open.
+    std::mem::forget(rx);
+    AppState {
+        receiver: Arc::new(receiver),
+        readiness: Arc::new(ReadinessChecker::new(Vec::new(), READINESS_CACHE_TTL)),
+        replay_protection: None,
+        intake_limiter: Arc::new(IntakeLimiter::new(4, 4)),
+        event_channel: EventChannel::new(tx),
+        max_payload_bytes,
+        require_github_user_agent: false,
+    }
+}
+
+/// Serves the full router with `state`.
+async fn start_router(state: AppState) -> std::net::SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(axum::serve(listener, build_router(state)).into_future());
+    addr
+}
+
+/// Serves the full router with a `max_payload_bytes` body limit.
+async fn start_router_with_payload_limit(max_payload_bytes: usize) -> std::net::SocketAddr {
+    start_router(test_state(max_payload_bytes)).await
+}
+
+/// Posts a webhook announcing `content_length` bytes but sends only `sent`
+/// bytes of it, then reads until `expected` appears in the response or the
+/// server stops answering.
+async fn post_partial_webhook(
+    addr: std::net::SocketAddr,
+    content_length: usize,
+    sent: usize,
+    expected: &str,
+) -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+    let head = format!(
+        "POST /webhook HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\n\
+         X-GitHub-Event: ping\r\nX-GitHub-Delivery: delivery-1\r\n\
+         Content-Length: {content_length}\r\n\r\n"
+    );
+    stream.write_all(head.as_bytes()).await.unwrap();
+    stream.write_all(&vec![b' '; sent]).await.unwrap();
+
+    let mut response = Vec::new();
+    let mut buf = [0u8; 4096];
+    while !String::from_utf8_lossy(&response).contains(expected) {
+        match tokio::time::timeout(Duration::from_secs(5), stream.read(&mut buf)).await {
+            Ok(Ok(n)) if n > 0 => response.extend_from_slice(&buf[..n]),
+            _ => break,
+        }
+    }
+    String::from_utf8_lossy(&response).into_owned()
+}
+
+#[tokio::test]
+async fn test_oversized_webhook_gets_413_before_the_body_is_buffered() {
+    let addr = start_router_with_payload_limit(1024).await;
+
+    // 10 MiB are announced but only 2 KiB are sent: the server must answer
+    // without waiting for the rest of the body.
+    let response = post_partial_webhook(addr, 10 * 1024 * 1024, 2048, "payload_too_large").await;
+
+    assert!(response.starts_with("HTTP/1.1 413"), "{response}");
+    assert!(response.contains("application/json"), "{response}");
+    assert!(
+        response.contains(r#""code":"payload_too_large""#),
+        "{response}"
+    );
+    assert!(response.contains("1024 bytes"), "{response}");
+}
+
+#[tokio::test]
+async fn test_webhook_within_the_payload_limit_is_not_rejected_as_too_large() {
+    let addr = start_router_with_payload_limit(1024).await;
+
+    // An unsigned body within the limit reaches the signature check.
+    let response = post_partial_webhook(addr, 512, 512, "invalid_signature").await;
+
+    assert!(response.starts_with("HTTP/1.1 401"), "{response}");
+}
+
+#[tokio::test]
+async fn test_webhook_gets_503_while_the_event_channel_is_full() {
+    let (tx, _rx) = tokio::sync::mpsc::channel(1);
+    let event_channel = EventChannel::new(tx);
+    let _full = event_channel.reserve().unwrap();
+    let addr = start_router(AppState {
+        event_channel,
+        ..test_state(DEFAULT_MAX_PAYLOAD_BYTES)
+    })
+    .await;
+
+    let response = post_webhook_with_user_agent(addr, None).await;
+
+    // Turned away before the SDK could acknowledge it, so GitHub records a
+    // failed delivery that can be redelivered.
+    assert!(response.starts_with("HTTP/1.1 503"), "{response}");
+    assert!(
+        response.to_ascii_lowercase().contains("retry-after: 10"),
+        "{response}"
+    );
+    assert!(response.contains(r#""code":"overloaded""#), "{response}");
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// User-Agent check
+// ──────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_require_github_user_agent_is_enabled_only_by_true_or_one() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    std::env::remove_var("WEBHOOK_REQUIRE_GITHUB_USER_AGENT");
+    let unset = require_github_user_agent_from_env();
+    std::env::set_var("WEBHOOK_REQUIRE_GITHUB_USER_AGENT", "TRUE");
+    let upper = require_github_user_agent_from_env();
+    std::env::set_var("WEBHOOK_REQUIRE_GITHUB_USER_AGENT", "1");
+    let one = require_github_user_agent_from_env();
+    std::env::set_var("WEBHOOK_REQUIRE_GITHUB_USER_AGENT", "yes");
+    let other = require_github_user_agent_from_env();
+    std::env::remove_var("WEBHOOK_REQUIRE_GITHUB_USER_AGENT");
+
+    assert!(!unset);
+    assert!(upper);
+    assert!(one);
+    assert!(!other);
+}
+
+/// Serves the full router with the User-Agent check `enabled` or not.
+async fn start_router_with_user_agent_check(enabled: bool) -> std::net::SocketAddr {
+    start_router(AppState {
+        require_github_user_agent: enabled,
+        ..test_state(DEFAULT_MAX_PAYLOAD_BYTES)
+    })
+    .await
+}
+
+/// Posts an unsigned `ping` webhook with the given `User-Agent`, if any, and
+/// returns the raw response.
+async fn post_webhook_with_user_agent(
+    addr: std::net::SocketAddr,
+    user_agent: Option<&str>,
+) -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let body = "{}";
+    let user_agent = user_agent
+        .map(|value| format!("User-Agent: {value}\r\n"))
+        .unwrap_or_default();
+    let request = format!(
+        "POST /webhook HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\n\
+         X-GitHub-Event: ping\r\nX-GitHub-Delivery: delivery-1\r\n{user_agent}\
+         Connection: close\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    );
+
+    let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = Vec::new();
+    tokio::time::timeout(Duration::from_secs(5), stream.read_to_end(&mut response))
+        .await
+        .unwrap()
+        .unwrap();
+    String::from_utf8_lossy(&response).into_owned()
+}
+
+#[tokio::test]
+async fn test_hookshot_user_agent_passes_on_to_the_signature_check() {
+    let addr = start_router_with_user_agent_check(true).await;
+
+    let response = post_webhook_with_user_agent(addr, Some("GitHub-Hookshot/044aadd")).await;
+
+    // The unsigned body is still rejected: the User-Agent check does not
+    // replace the signature check.
+    assert!(response.starts_with("HTTP/1.1 401"), "{response}");
+    assert!(
+        response.contains(r#""code":"invalid_signature""#),
+        "{response}"
+    );
+}
+
+#[tokio::test]
+async fn test_missing_user_agent_gets_403_when_the_check_is_enabled() {
+    let addr = start_router_with_user_agent_check(true).await;
+
+    let response = post_webhook_with_user_agent(addr, None).await;
+
+    assert!(response.starts_with("HTTP/1.1 403"), "{response}");
+    assert!(response.contains(r#""code":"forbidden""#), "{response}");
+    assert!(
+        response.contains("User-Agent header is missing"),
+        "{response}"
+    );
+}
+
+#[tokio::test]
+async fn test_spoofed_user_agent_gets_403_when_the_check_is_enabled() {
+    let addr = start_router_with_user_agent_check(true).await;
+
+    for user_agent in [
+        "curl/8.5.0",
+        "github-hookshot/044aadd",
+        "Mozilla GitHub-Hookshot/1",
+    ] {
+        let response = post_webhook_with_user_agent(addr, Some(user_agent)).await;
+
+        assert!(
+            response.starts_with("HTTP/1.1 403"),
+            "{user_agent}: {response}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_user_agent_is_not_checked_when_the_check_is_disabled() {
+    let addr = start_router_with_user_agent_check(false).await;
+
+    let response = post_webhook_with_user_agent(addr, None).await;
+
+    assert!(response.starts_with("HTTP/1.1 401"), "{response}");
+}
@@ -0,0 +1,151 @@
+//! Prometheus metrics for the `/metrics` endpoint.
+//!
+//! The core crate emits event and stage metrics through the [`metrics`]
+//! facade (see [`release_regent_core::telemetry`]). This module installs the
+//! Prometheus recorder that collects them, adds the server's own webhook
+//! metrics, and measures GitHub API latency from the spans of the
+//! `#[instrument]`ed GitHub client methods.
+//!
+//! Labels are limited to event types, outcomes, stage names and GitHub client
+//! operation names. Repository names, delivery IDs and header values are never
+//! used as labels: the `X-GitHub-Event` header is mapped to a fixed set of
+//! event types by [`event_type_label`], and deliveries rejected before their
+//! signature is verified are labelled [`UNVERIFIED_EVENT_TYPE`].
+
+use axum::http::header;
+use axum::response::IntoResponse;
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+use release_regent_core::webhook::payload::WebhookEventKind;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+use tracing::{span, Subscriber};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+#[cfg(test)]
+#[path = "metrics_tests.rs"]
+mod tests;
+
+/// Counter of webhook deliveries received, labelled with `event_type` and
+/// `outcome` (`accepted`, `rejected`, `throttled` or `error`).
+///
+/// `event_type` is one of the events Release Regent parses, `other`, or
+/// [`UNVERIFIED_EVENT_TYPE`].
+pub const WEBHOOKS_RECEIVED_TOTAL: &str = "release_regent_webhooks_received_total";
+
+/// Histogram of the time spent in a GitHub client call, in seconds, labelled
+/// with `operation`.
+pub const GITHUB_CALL_DURATION_SECONDS: &str = "release_regent_github_call_duration_seconds";
+
+/// `event_type` label of deliveries rejected before their signature was
+/// verified, whose `X-GitHub-Event` header cannot be trusted.
+pub const UNVERIFIED_EVENT_TYPE: &str = "unverified";
+
+/// `event_type` label of verified deliveries of events Release Regent does
+/// not parse.
+pub const OTHER_EVENT_TYPE: &str = "other";
+
+/// Stage label for validating the webhook signature and dispatching the event.
+pub const STAGE_SIGNATURE_VERIFICATION: &str = "signature_verification";
+
+/// Content type of the Prometheus text exposition format.
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Span target prefix of the GitHub client's instrumented methods.
+const GITHUB_CLIENT_TARGET: &str = "release_regent_github_client";
+
+static HANDLE: OnceLock<PrometheusHandle> = OnceLock::new();
+
+/// Install the global Prometheus recorder, once, and return its handle.
+///
+/// # Panics
+///
+/// Panics if a different metrics recorder has already been installed.
+pub fn install_recorder() -> PrometheusHandle {
+    HANDLE
+        .get_or_init(|| {
+            PrometheusBuilder::new()
+                .install_recorder()
+                .expect("no other metrics recorder is installed")
+        })
+        .clone()
+}
+
+/// The `event_type` label for an `X-GitHub-Event` header value.
+///
+/// Events Release Regent parses keep their name; every other value becomes
+/// [`OTHER_EVENT_TYPE`], so callers cannot create new label values.
+#[must_use]
+pub fn event_type_label(header: &str) -> &'static str {
+    match WebhookEventKind::from_header(header) {
+        WebhookEventKind::IssueComment => "issue_comment",
+        WebhookEventKind::PullRequest => "pull_request",
+        WebhookEventKind::PullRequestReviewComment => "pull_request_review_comment",
+        WebhookEventKind::Push => "push",
+        WebhookEventKind::Release => "release",
+        WebhookEventKind::Other(_) => OTHER_EVENT_TYPE,
+    }
+}
+
+/// Record a webhook delivery.
+///
+/// `event_type` is a label from [`event_type_label`] or
+/// [`UNVERIFIED_EVENT_TYPE`].
+pub fn record_webhook(event_type: &'static str, outcome: &'static str) {
+    metrics::counter!(
+        WEBHOOKS_RECEIVED_TOTAL,
+        "event_type" => event_type,
+        "outcome" => outcome
+    )
+    .increment(1);
+}
+
+/// Metrics endpoint.
+///
+/// Returns every recorded metric in the Prometheus text exposition format.
+pub async fn metrics_handler() -> impl IntoResponse {
+    let handle = install_recorder();
+    handle.run_upkeep();
+    (
+        [(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)],
+        handle.render(),
+    )
+}
+
+/// Start time of a GitHub client span, kept in the span's extensions.
+struct CallStarted(Instant);
+
+/// Tracing layer that records the duration of GitHub client calls.
+///
+/// Every public method of the GitHub client is `#[instrument]`ed, so the
+/// lifetime of its span is the duration of the call, including retries. The
+/// span name, which is the method name, is used as the `operation` label.
+pub struct GitHubCallMetricsLayer;
+
+impl<S> Layer<S> for GitHubCallMetricsLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        if !attrs.metadata().target().starts_with(GITHUB_CLIENT_TARGET) {
+            return;
+        }
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(CallStarted(Instant::now()));
+        }
+    }
+
+    fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(started) = span.extensions().get::<CallStarted>().map(|s| s.0) else {
+            return;
+        };
+        record_github_call(span.name(), started.elapsed());
+    }
+}
+
+fn record_github_call(operation: &'static str, duration: Duration) {
+    metrics::histogram!(GITHUB_CALL_DURATION_SECONDS, "operation" => operation)
+        .record(duration.as_secs_f64());
+}
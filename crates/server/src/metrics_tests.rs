@@ -0,0 +1,230 @@
+use super::*;
+use crate::{
+    concurrency_limit::IntakeLimiter,
+    readiness::{ReadinessChecker, READINESS_CACHE_TTL},
+    webhook_handler,
+    webhook_secrets::{RotatingWebhookReceiver, WebhookSecrets},
+    AppState,
+};
+use axum::{
+    routing::{get, post},
+    Router,
+};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tracing_subscriber::layer::SubscriberExt;
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Helpers
+// ──────────────────────────────────────────────────────────────────────────────
+
+const SECRET: &str = "test-webhook-secret";
+
+fn compute_signature(payload: &[u8]) -> String {
+    use hmac::{Hmac, KeyInit, Mac};
+    use sha2::Sha256;
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(SECRET.as_bytes()).unwrap();
+    mac.update(payload);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+fn app() -> Router {
+    let receiver = RotatingWebhookReceiver::new(&WebhookSecrets::new([SECRET]).unwrap());
+    let (tx, rx) = tokio::sync::mpsc::channel(8);
+    // No event loop reads the channel; it only has to stay open.
+    std::mem::forget(rx);
+    let state = AppState {
+        receiver: Arc::new(receiver),
+        readiness: Arc::new(ReadinessChecker::new(Vec::new(), READINESS_CACHE_TTL)),
+        replay_protection: None,
+        intake_limiter: Arc::new(IntakeLimiter::new(4, 4)),
+        event_channel: crate::handler::EventChannel::new(tx),
+        max_payload_bytes: 1024 * 1024,
+        require_github_user_agent: false,
+    };
+    Router::new()
+        .route("/metrics", get(metrics_handler))
+        .route("/webhook", post(webhook_handler))
+        .with_state(state)
+}
+
+/// Sends a raw HTTP/1.1 request to the app and returns the full response.
+async fn send(request: String) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let app = app();
+    tokio::spawn(async move { axum::serve(listener, app).await });
+
+    let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    response
+}
+
+async fn scrape() -> String {
+    send("GET /metrics HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n".to_string()).await
+}
+
+async fn send_webhook(event_type: &str, signature: Option<&str>) -> String {
+    let payload = serde_json::json!({
+        "action": "opened",
+        "repository": {
+            "id": 123,
+            "name": "test-repo",
+            "full_name": "owner/test-repo",
+            "owner": {
+                "login": "owner",
+                "id": 1,
+                "avatar_url": "https://github.com/avatars/u/1",
+                "type": "Organization"
+            },
+            "private": false,
+            "default_branch": "main",
+            "html_url": "https://github.com/owner/test-repo",
+            "clone_url": "https://github.com/owner/test-repo.git",
+            "ssh_url": "git@github.com:owner/test-repo.git",
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        }
+    })
+    .to_string();
+    let signature = signature.map_or_else(|| compute_signature(payload.as_bytes()), String::from);
+
+    send(format!(
+        "POST /webhook HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\
+         Content-Type: application/json\r\nX-GitHub-Event: {event_type}\r\n\
+         X-GitHub-Delivery: aaaaaaaa-bbbb-cccc-dddd-eeeeeeeeeeee\r\n\
+         X-Hub-Signature-256: {signature}\r\nContent-Length: {}\r\n\r\n{payload}",
+        payload.len()
+    ))
+    .await
+}
+
+/// Value of the sample of `name` whose labels include every entry of `labels`,
+/// or 0 when there is none yet.
+fn sample(body: &str, name: &str, labels: &[&str]) -> f64 {
+    body.lines()
+        .filter(|line| line.starts_with(&format!("{name}{{")))
+        .find(|line| labels.iter().all(|label| line.contains(label)))
+        .and_then(|line| line.rsplit(' ').next())
+        .map_or(0.0, |value| value.parse().unwrap())
+}
+
+fn response_has_prometheus_content_type(response: &str) -> bool {
+    response
+        .to_ascii_lowercase()
+        .contains(&format!("content-type: {PROMETHEUS_CONTENT_TYPE}"))
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// /metrics
+// ──────────────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_metrics_webhook_counter_increments_after_accepted_webhook() {
+    install_recorder();
+    let labels = [r#"event_type="pull_request""#, r#"outcome="accepted""#];
+    let before = sample(&scrape().await, WEBHOOKS_RECEIVED_TOTAL, &labels);
+
+    let response = send_webhook("pull_request", None).await;
+    assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+
+    let after = scrape().await;
+    assert!(sample(&after, WEBHOOKS_RECEIVED_TOTAL, &labels) >= before + 1.0);
+    assert!(
+        sample(
+            &after,
+            "release_regent_stage_duration_seconds_count",
+            &[r#"stage="signature_verification""#, r#"outcome="success""#]
+        ) >= 1.0,
+        "{after}"
+    );
+}
+
+#[tokio::test]
+async fn test_metrics_records_rejected_webhook_without_sensitive_labels() {
+    install_recorder();
+    let signature = "sha256=0000000000000000000000000000000000000000000000000000000000000000";
+
+    let response = send_webhook("push", Some(signature)).await;
+    assert!(response.starts_with("HTTP/1.1 401"), "{response}");
+
+    let body = scrape().await;
+    assert!(response_has_prometheus_content_type(&body), "{body}");
+    assert!(
+        sample(
+            &body,
+            WEBHOOKS_RECEIVED_TOTAL,
+            &[r#"event_type="unverified""#, r#"outcome="rejected""#]
+        ) >= 1.0
+    );
+    assert!(!body.contains("owner/test-repo"));
+    assert!(!body.contains(signature));
+    assert!(!body.contains("aaaaaaaa-bbbb"));
+}
+
+#[tokio::test]
+async fn test_metrics_unverified_webhook_does_not_label_its_event_header() {
+    install_recorder();
+    let signature = "sha256=0000000000000000000000000000000000000000000000000000000000000000";
+
+    let response = send_webhook("made_up_event_1817", Some(signature)).await;
+    assert!(response.starts_with("HTTP/1.1 401"), "{response}");
+
+    let body = scrape().await;
+    assert!(!body.contains("made_up_event_1817"), "{body}");
+}
+
+#[tokio::test]
+async fn test_metrics_verified_webhook_of_unparsed_event_is_labelled_other() {
+    install_recorder();
+
+    let response = send_webhook("made_up_event_1818", None).await;
+    assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+
+    let body = scrape().await;
+    assert!(!body.contains("made_up_event_1818"), "{body}");
+    assert!(
+        sample(
+            &body,
+            WEBHOOKS_RECEIVED_TOTAL,
+            &[r#"event_type="other""#, r#"outcome="accepted""#]
+        ) >= 1.0,
+        "{body}"
+    );
+}
+
+#[test]
+fn test_event_type_label_is_limited_to_known_events() {
+    assert_eq!(event_type_label("pull_request"), "pull_request");
+    assert_eq!(event_type_label("issue_comment"), "issue_comment");
+    assert_eq!(event_type_label("push"), "push");
+    assert_eq!(event_type_label("workflow_run"), OTHER_EVENT_TYPE);
+    assert_eq!(event_type_label(""), OTHER_EVENT_TYPE);
+    assert_eq!(event_type_label(&"x".repeat(4096)), OTHER_EVENT_TYPE);
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// GitHubCallMetricsLayer
+// ──────────────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_github_call_layer_records_client_spans_only() {
+    install_recorder();
+    let subscriber = tracing_subscriber::registry().with(GitHubCallMetricsLayer);
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::info_span!(target: "release_regent_github_client", "metrics_test_operation")
+            .in_scope(|| std::thread::sleep(Duration::from_millis(5)));
+        tracing::info_span!(target: "release_regent_core", "metrics_test_other").in_scope(|| {});
+    });
+
+    let body = scrape().await;
+    let count = format!("{GITHUB_CALL_DURATION_SECONDS}_count");
+    assert_eq!(
+        sample(&body, &count, &[r#"operation="metrics_test_operation""#]),
+        1.0
+    );
+    assert!(!body.contains("metrics_test_other"));
+}
@@ -0,0 +1,206 @@
+//! Readiness checks for the `/health/ready` endpoint.
+//!
+//! The liveness endpoint (`/`) only reports that the process is running. The
+//! readiness endpoint additionally verifies that the server's dependencies
+//! work: that the configuration can be loaded and that the GitHub App can
+//! authenticate against the GitHub API. Orchestrators use it to keep traffic
+//! away from an instance that would fail every webhook.
+//!
+//! Checking GitHub costs an API call, so [`ReadinessChecker`] caches each
+//! report for a short time instead of running the checks on every probe.
+
+use async_trait::async_trait;
+use axum::{http::StatusCode, response::Json};
+use release_regent_core::traits::{configuration_provider::LoadOptions, ConfigurationProvider};
+use serde::Serialize;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+#[cfg(test)]
+#[path = "readiness_tests.rs"]
+mod tests;
+
+/// How long a readiness report is reused before the checks run again.
+pub const READINESS_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// A dependency the server needs in order to process webhooks.
+#[async_trait]
+pub trait DependencyCheck: Send + Sync {
+    /// Name of the check as reported by the readiness endpoint.
+    fn name(&self) -> &str;
+
+    /// Verify the dependency.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the problem when the dependency is unavailable.
+    async fn check(&self) -> Result<(), String>;
+}
+
+/// Verifies that the global configuration can be loaded.
+pub struct ConfigLoadCheck<P> {
+    provider: P,
+}
+
+impl<P: ConfigurationProvider> ConfigLoadCheck<P> {
+    /// Create a check that loads the global configuration from `provider`.
+    pub fn new(provider: P) -> Self {
+        Self { provider }
+    }
+}
+
+#[async_trait]
+impl<P: ConfigurationProvider> DependencyCheck for ConfigLoadCheck<P> {
+    fn name(&self) -> &str {
+        "config"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        self.provider
+            .load_global_config(LoadOptions::default())
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Verifies that the GitHub App can sign a JWT and call the GitHub API.
+///
+/// Listing the app's installations is the cheapest request that exercises
+/// both the private key and the connection to GitHub.
+pub struct GitHubAppCheck {
+    client: release_regent_github_client::GitHubClient,
+}
+
+impl GitHubAppCheck {
+    /// Create a check that lists the installations of the app behind `client`.
+    pub fn new(client: release_regent_github_client::GitHubClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl DependencyCheck for GitHubAppCheck {
+    fn name(&self) -> &str {
+        "github"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        self.client
+            .sdk_client()
+            .list_installations()
+            .await
+            .map(|installations| {
+                debug!(
+                    installations = installations.len(),
+                    "GitHub readiness check succeeded"
+                );
+            })
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Outcome of a single dependency check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CheckResult {
+    /// Name of the check
+    pub name: String,
+    /// `"ok"` or `"failed"`
+    pub status: &'static str,
+    /// Why the check failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Outcome of all dependency checks, returned as the endpoint's JSON body.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReadinessReport {
+    /// `"ready"` when every check passed, `"degraded"` otherwise
+    pub status: &'static str,
+    /// Result of each check, in registration order
+    pub checks: Vec<CheckResult>,
+}
+
+impl ReadinessReport {
+    /// Whether every check passed.
+    pub fn is_ready(&self) -> bool {
+        self.status == "ready"
+    }
+}
+
+/// Runs the dependency checks and caches the resulting report.
+pub struct ReadinessChecker {
+    checks: Vec<Box<dyn DependencyCheck>>,
+    ttl: Duration,
+    cached: Mutex<Option<(Instant, ReadinessReport)>>,
+}
+
+impl ReadinessChecker {
+    /// Create a checker that reuses each report for `ttl`.
+    pub fn new(checks: Vec<Box<dyn DependencyCheck>>, ttl: Duration) -> Self {
+        Self {
+            checks,
+            ttl,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Current readiness report, running the checks when the cached report
+    /// has expired.
+    ///
+    /// The cache lock is held while the checks run, so concurrent probes wait
+    /// for one set of checks instead of each calling GitHub.
+    pub async fn report(&self) -> ReadinessReport {
+        let mut cached = self.cached.lock().await;
+        if let Some((checked_at, report)) = cached.as_ref() {
+            if checked_at.elapsed() < self.ttl {
+                return report.clone();
+            }
+        }
+
+        let mut results = Vec::with_capacity(self.checks.len());
+        for check in &self.checks {
+            let result = match check.check().await {
+                Ok(()) => CheckResult {
+                    name: check.name().to_string(),
+                    status: "ok",
+                    error: None,
+                },
+                Err(error) => {
+                    warn!(check = check.name(), error = %error, "Readiness check failed");
+                    CheckResult {
+                        name: check.name().to_string(),
+                        status: "failed",
+                        error: Some(error),
+                    }
+                }
+            };
+            results.push(result);
+        }
+
+        let status = if results.iter().all(|r| r.error.is_none()) {
+            "ready"
+        } else {
+            "degraded"
+        };
+        let report = ReadinessReport {
+            status,
+            checks: results,
+        };
+        *cached = Some((Instant::now(), report.clone()));
+        report
+    }
+
+    /// HTTP response for the readiness endpoint: `200` when ready, `503`
+    /// otherwise, with the report as the body.
+    pub async fn respond(&self) -> (StatusCode, Json<ReadinessReport>) {
+        let report = self.report().await;
+        let status = if report.is_ready() {
+            StatusCode::OK
+        } else {
+            StatusCode::SERVICE_UNAVAILABLE
+        };
+        (status, Json(report))
+    }
+}
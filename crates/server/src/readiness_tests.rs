@@ -0,0 +1,150 @@
+use super::*;
+use release_regent_testing::mocks::{MockConfig, MockConfigurationProvider};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Helpers
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// Stand-in for the GitHub check that counts how often it runs.
+struct StubCheck {
+    name: &'static str,
+    error: Option<&'static str>,
+    runs: Arc<AtomicUsize>,
+}
+
+impl StubCheck {
+    fn healthy(name: &'static str) -> Self {
+        Self {
+            name,
+            error: None,
+            runs: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    fn failing(name: &'static str, error: &'static str) -> Self {
+        Self {
+            name,
+            error: Some(error),
+            runs: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+}
+
+#[async_trait]
+impl DependencyCheck for StubCheck {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        self.runs.fetch_add(1, Ordering::SeqCst);
+        match self.error {
+            Some(error) => Err(error.to_string()),
+            None => Ok(()),
+        }
+    }
+}
+
+fn failing_config_check() -> ConfigLoadCheck<MockConfigurationProvider> {
+    ConfigLoadCheck::new(MockConfigurationProvider::with_config(
+        MockConfig::default().with_method_failure_rate("load_global_config", 1.0),
+    ))
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// ReadinessChecker
+// ──────────────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_respond_returns_ok_when_all_checks_pass() {
+    let checker = ReadinessChecker::new(
+        vec![
+            Box::new(ConfigLoadCheck::new(MockConfigurationProvider::new())),
+            Box::new(StubCheck::healthy("github")),
+        ],
+        READINESS_CACHE_TTL,
+    );
+
+    let (status, Json(report)) = checker.respond().await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report.status, "ready");
+    assert!(report.checks.iter().all(|c| c.status == "ok"));
+}
+
+#[tokio::test]
+async fn test_respond_returns_503_when_config_fails_to_load() {
+    let checker = ReadinessChecker::new(
+        vec![
+            Box::new(failing_config_check()),
+            Box::new(StubCheck::healthy("github")),
+        ],
+        READINESS_CACHE_TTL,
+    );
+
+    let (status, Json(report)) = checker.respond().await;
+
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(report.status, "degraded");
+    assert_eq!(report.checks[0].name, "config");
+    assert_eq!(report.checks[0].status, "failed");
+    assert!(report.checks[0]
+        .error
+        .as_deref()
+        .is_some_and(|e| e.contains("Simulated global configuration loading error")));
+    assert_eq!(report.checks[1].status, "ok");
+}
+
+#[tokio::test]
+async fn test_degraded_report_serializes_failed_check_breakdown() {
+    let checker = ReadinessChecker::new(
+        vec![
+            Box::new(ConfigLoadCheck::new(MockConfigurationProvider::new())),
+            Box::new(StubCheck::failing("github", "401 Unauthorized")),
+        ],
+        READINESS_CACHE_TTL,
+    );
+
+    let (_, Json(report)) = checker.respond().await;
+    let body = serde_json::to_value(&report).unwrap();
+
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "status": "degraded",
+            "checks": [
+                {"name": "config", "status": "ok"},
+                {"name": "github", "status": "failed", "error": "401 Unauthorized"}
+            ]
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_report_is_cached_within_ttl() {
+    let check = StubCheck::failing("github", "timed out");
+    let runs = Arc::clone(&check.runs);
+    let checker = ReadinessChecker::new(vec![Box::new(check)], Duration::from_secs(60));
+
+    let first = checker.report().await;
+    let second = checker.report().await;
+
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+    assert_eq!(first, second);
+}
+
+#[tokio::test]
+async fn test_report_reruns_checks_after_ttl_expires() {
+    let check = StubCheck::healthy("github");
+    let runs = Arc::clone(&check.runs);
+    let checker = ReadinessChecker::new(vec![Box::new(check)], Duration::ZERO);
+
+    checker.report().await;
+    checker.report().await;
+
+    assert_eq!(runs.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,210 @@
+//! Replay protection for webhook deliveries.
+//!
+//! A valid signature proves that GitHub sent a delivery, not that it was sent
+//! recently: a captured delivery can be replayed verbatim and its signature
+//! still verifies. [`ReplayProtection`] narrows that window by rejecting
+//! deliveries whose timestamp is older than a configured maximum age.
+//!
+//! The timestamp is read from a configured header when one is set (for
+//! example a header added by a proxy in front of the server), and otherwise
+//! from the payload, using the first of [`PAYLOAD_TIMESTAMP_POINTERS`] that is
+//! present. Only fields that change when the delivery is sent are used: the
+//! time a commit was authored or a branch was last pushed says nothing about
+//! when a push event was delivered. Deliveries whose payload carries none of
+//! those fields, such as pushes, cannot be dated and are accepted.
+//!
+//! The check runs before the signature is verified, and the signature covers
+//! only the body. A timestamp header is therefore only as trustworthy as
+//! whatever sets it: configure one only when a trusted proxy in front of the
+//! server sets it and strips any value sent by the client, otherwise anyone
+//! replaying a delivery can send a fresh timestamp with it.
+//!
+//! Timestamps may be RFC 3339 strings or Unix seconds. A clock skew tolerance
+//! is applied in both directions so that small differences between GitHub's
+//! clock and the server's do not cause rejections.
+
+use chrono::{DateTime, Utc};
+use std::{collections::HashMap, time::Duration};
+
+#[cfg(test)]
+#[path = "replay_protection_tests.rs"]
+mod tests;
+
+/// Default clock skew tolerance.
+pub const DEFAULT_CLOCK_SKEW: Duration = Duration::from_secs(30);
+
+/// Payload fields, as JSON pointers, that date a delivery, in order of
+/// preference. Each is updated by the change that triggers the delivery.
+pub const PAYLOAD_TIMESTAMP_POINTERS: &[&str] = &[
+    "/pull_request/updated_at",
+    "/comment/updated_at",
+    "/issue/updated_at",
+    "/release/updated_at",
+];
+
+/// Why a delivery was rejected by [`ReplayProtection::check`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ReplayRejection {
+    /// The delivery is older than the maximum age plus the skew tolerance.
+    #[error("delivery is {age_secs}s old; the maximum age is {max_age_secs}s")]
+    Expired {
+        /// Age of the delivery in seconds.
+        age_secs: i64,
+        /// Configured maximum age in seconds.
+        max_age_secs: u64,
+    },
+
+    /// The delivery is dated further in the future than the skew tolerance.
+    #[error("delivery is dated {ahead_secs}s in the future")]
+    FromFuture {
+        /// How far ahead of the server's clock the delivery is, in seconds.
+        ahead_secs: i64,
+    },
+
+    /// The configured timestamp header is absent.
+    #[error("timestamp header '{header}' is missing")]
+    MissingTimestamp {
+        /// Name of the configured header.
+        header: String,
+    },
+
+    /// The timestamp could not be parsed.
+    #[error("timestamp '{value}' is neither RFC 3339 nor Unix seconds")]
+    InvalidTimestamp {
+        /// The unparseable value.
+        value: String,
+    },
+}
+
+/// Rejects webhook deliveries that are too old to be fresh.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayProtection {
+    max_age: Duration,
+    clock_skew: Duration,
+    timestamp_header: Option<String>,
+}
+
+impl ReplayProtection {
+    /// Reject deliveries older than `max_age`, reading the timestamp from the
+    /// payload and tolerating [`DEFAULT_CLOCK_SKEW`].
+    #[must_use]
+    pub fn new(max_age: Duration) -> Self {
+        Self {
+            max_age,
+            clock_skew: DEFAULT_CLOCK_SKEW,
+            timestamp_header: None,
+        }
+    }
+
+    /// Set the clock skew tolerance.
+    #[must_use]
+    pub fn with_clock_skew(mut self, clock_skew: Duration) -> Self {
+        self.clock_skew = clock_skew;
+        self
+    }
+
+    /// Read the timestamp from `header` instead of the payload.
+    ///
+    /// Deliveries without the header are rejected. The header is not covered
+    /// by the webhook signature, so only use one that a trusted proxy sets;
+    /// see the [module documentation](self).
+    #[must_use]
+    pub fn with_timestamp_header(mut self, header: impl Into<String>) -> Self {
+        self.timestamp_header = Some(header.into().to_ascii_lowercase());
+        self
+    }
+
+    /// The maximum age of an accepted delivery.
+    #[must_use]
+    pub fn max_age(&self) -> Duration {
+        self.max_age
+    }
+
+    /// The clock skew tolerance.
+    #[must_use]
+    pub fn clock_skew(&self) -> Duration {
+        self.clock_skew
+    }
+
+    /// Check that a delivery is fresh at `now`.
+    ///
+    /// `headers` must be keyed by lower-case header name.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReplayRejection`] describing why the delivery is not fresh.
+    pub fn check(
+        &self,
+        headers: &HashMap<String, String>,
+        body: &[u8],
+        now: DateTime<Utc>,
+    ) -> Result<(), ReplayRejection> {
+        let Some(timestamp) = self.delivery_timestamp(headers, body)? else {
+            tracing::debug!("Delivery carries no timestamp; skipping replay check");
+            return Ok(());
+        };
+
+        let skew = chrono::Duration::from_std(self.clock_skew).unwrap_or(chrono::Duration::MAX);
+        let max_age = chrono::Duration::from_std(self.max_age).unwrap_or(chrono::Duration::MAX);
+        let age = now.signed_duration_since(timestamp);
+
+        if age > max_age.checked_add(&skew).unwrap_or(chrono::Duration::MAX) {
+            return Err(ReplayRejection::Expired {
+                age_secs: age.num_seconds(),
+                max_age_secs: self.max_age.as_secs(),
+            });
+        }
+        if -age > skew {
+            return Err(ReplayRejection::FromFuture {
+                ahead_secs: (-age).num_seconds(),
+            });
+        }
+        Ok(())
+    }
+
+    fn delivery_timestamp(
+        &self,
+        headers: &HashMap<String, String>,
+        body: &[u8],
+    ) -> Result<Option<DateTime<Utc>>, ReplayRejection> {
+        if let Some(header) = &self.timestamp_header {
+            let value = headers
+                .get(header)
+                .ok_or_else(|| ReplayRejection::MissingTimestamp {
+                    header: header.clone(),
+                })?;
+            return parse_timestamp(&serde_json::Value::String(value.clone())).map(Some);
+        }
+
+        let Ok(payload) = serde_json::from_slice::<serde_json::Value>(body) else {
+            return Ok(None);
+        };
+        PAYLOAD_TIMESTAMP_POINTERS
+            .iter()
+            .find_map(|pointer| payload.pointer(pointer).filter(|v| !v.is_null()))
+            .map(parse_timestamp)
+            .transpose()
+    }
+}
+
+/// Parse an RFC 3339 string or a Unix-seconds number or string.
+fn parse_timestamp(value: &serde_json::Value) -> Result<DateTime<Utc>, ReplayRejection> {
+    let seconds = match value {
+        serde_json::Value::String(s) => {
+            let s = s.trim();
+            if let Ok(timestamp) = DateTime::parse_from_rfc3339(s) {
+                return Ok(timestamp.with_timezone(&Utc));
+            }
+            s.parse::<i64>().ok()
+        }
+        serde_json::Value::Number(n) => n.as_i64(),
+        _ => None,
+    };
+    seconds
+        .and_then(|s| DateTime::from_timestamp(s, 0))
+        .ok_or_else(|| ReplayRejection::InvalidTimestamp {
+            value: value
+                .as_str()
+                .map_or_else(|| value.to_string(), String::from),
+        })
+}
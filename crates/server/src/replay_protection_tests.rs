@@ -0,0 +1,135 @@
+use super::*;
+
+fn now() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2026-03-01T12:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+fn payload_updated_at(updated_at: &str) -> Vec<u8> {
+    serde_json::json!({
+        "action": "closed",
+        "pull_request": { "number": 1, "updated_at": updated_at }
+    })
+    .to_string()
+    .into_bytes()
+}
+
+fn five_minutes() -> ReplayProtection {
+    ReplayProtection::new(Duration::from_secs(300)).with_clock_skew(Duration::from_secs(30))
+}
+
+#[test]
+fn test_fresh_delivery_is_accepted() {
+    let body = payload_updated_at("2026-03-01T11:59:00Z");
+
+    assert_eq!(five_minutes().check(&HashMap::new(), &body, now()), Ok(()));
+}
+
+#[test]
+fn test_old_delivery_is_rejected() {
+    let body = payload_updated_at("2026-03-01T11:50:00Z");
+
+    assert_eq!(
+        five_minutes().check(&HashMap::new(), &body, now()),
+        Err(ReplayRejection::Expired {
+            age_secs: 600,
+            max_age_secs: 300
+        })
+    );
+}
+
+#[test]
+fn test_skew_within_tolerance_is_accepted() {
+    let protection = five_minutes();
+
+    // 20 seconds past the maximum age, within the 30 second tolerance.
+    let late = payload_updated_at("2026-03-01T11:54:40Z");
+    assert_eq!(protection.check(&HashMap::new(), &late, now()), Ok(()));
+
+    // 20 seconds ahead of the server's clock.
+    let ahead = payload_updated_at("2026-03-01T12:00:20Z");
+    assert_eq!(protection.check(&HashMap::new(), &ahead, now()), Ok(()));
+}
+
+#[test]
+fn test_delivery_beyond_skew_in_the_future_is_rejected() {
+    let body = payload_updated_at("2026-03-01T12:05:00Z");
+
+    assert_eq!(
+        five_minutes().check(&HashMap::new(), &body, now()),
+        Err(ReplayRejection::FromFuture { ahead_secs: 300 })
+    );
+}
+
+#[test]
+fn test_payload_without_timestamp_is_accepted() {
+    let body = br#"{"zen":"Keep it logically awesome."}"#;
+
+    assert_eq!(five_minutes().check(&HashMap::new(), body, now()), Ok(()));
+}
+
+#[test]
+fn test_push_of_old_commits_is_accepted() {
+    // A push of commits authored long ago, for example of a rebased branch.
+    // Neither field says when the delivery was sent, so it cannot be dated.
+    let body = serde_json::json!({
+        "ref": "refs/heads/main",
+        "head_commit": { "timestamp": "2026-01-01T00:00:00Z" },
+        "repository": { "pushed_at": now().timestamp() - 86_400 }
+    })
+    .to_string();
+
+    assert_eq!(
+        five_minutes().check(&HashMap::new(), body.as_bytes(), now()),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_unix_seconds_payload_timestamp_is_supported() {
+    let old = now().timestamp() - 3600;
+    let body = serde_json::json!({ "release": { "updated_at": old } }).to_string();
+
+    assert!(matches!(
+        five_minutes().check(&HashMap::new(), body.as_bytes(), now()),
+        Err(ReplayRejection::Expired { .. })
+    ));
+}
+
+#[test]
+fn test_configured_header_takes_precedence_over_payload() {
+    let protection = five_minutes().with_timestamp_header("X-Delivery-Timestamp");
+    let body = payload_updated_at("2026-03-01T11:00:00Z");
+    let headers = HashMap::from([(
+        "x-delivery-timestamp".to_string(),
+        now().timestamp().to_string(),
+    )]);
+
+    assert_eq!(protection.check(&headers, &body, now()), Ok(()));
+}
+
+#[test]
+fn test_missing_configured_header_is_rejected() {
+    let protection = five_minutes().with_timestamp_header("x-delivery-timestamp");
+    let body = payload_updated_at("2026-03-01T11:59:00Z");
+
+    assert_eq!(
+        protection.check(&HashMap::new(), &body, now()),
+        Err(ReplayRejection::MissingTimestamp {
+            header: "x-delivery-timestamp".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_unparseable_timestamp_is_rejected() {
+    let body = payload_updated_at("yesterday");
+
+    assert_eq!(
+        five_minutes().check(&HashMap::new(), &body, now()),
+        Err(ReplayRejection::InvalidTimestamp {
+            value: "yesterday".to_string()
+        })
+    );
+}
@@ -0,0 +1,122 @@
+//! Structured per-request logging.
+//!
+//! [`log_requests`] wraps every request in a `http_request` span and emits a
+//! single completion event with the method, path, status, duration, payload
+//! size and, for webhooks, the GitHub delivery ID and event type.
+//!
+//! The span's `correlation_id` matches the correlation ID of the
+//! `ProcessingEvent` created for the delivery, so the request log can be joined
+//! with the `process_event` logs written by the event loop. Webhooks are
+//! processed after the response is sent; the request log therefore reports
+//! whether the delivery was accepted, and the event loop's "Event processing
+//! finished" log reports whether it was processed, ignored or failed.
+
+use axum::{
+    extract::Request,
+    http::{HeaderMap, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use std::time::Instant;
+use tracing::{debug, info, Instrument};
+
+#[cfg(test)]
+#[path = "request_log_tests.rs"]
+mod tests;
+
+/// Value logged in place of a sensitive header.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Headers whose values are never logged.
+const SENSITIVE_HEADERS: &[&str] = &[
+    "authorization",
+    "cookie",
+    "proxy-authorization",
+    "set-cookie",
+    "x-hub-signature",
+    "x-hub-signature-256",
+];
+
+/// Whether the value of header `name` must be redacted.
+///
+/// Besides the well-known credential headers, any header whose name mentions
+/// a secret or token is treated as sensitive.
+fn is_sensitive(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    SENSITIVE_HEADERS.contains(&name.as_str()) || name.contains("secret") || name.contains("token")
+}
+
+/// Header names and values with sensitive values replaced by [`REDACTED`].
+pub fn redact_headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if is_sensitive(name.as_str()) {
+                REDACTED.to_string()
+            } else {
+                value.to_str().unwrap_or("<non-UTF-8>").to_string()
+            };
+            (name.as_str().to_string(), value)
+        })
+        .collect()
+}
+
+/// Outcome of a request as reported in the completion log.
+///
+/// For webhooks, `accepted` means the signature was valid and the delivery was
+/// handed to the event loop.
+fn outcome(status: StatusCode) -> &'static str {
+    if status.is_server_error() {
+        "error"
+    } else if status.is_client_error() {
+        "rejected"
+    } else {
+        "accepted"
+    }
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+/// Axum middleware that logs one structured span per request.
+pub async fn log_requests(request: Request, next: Next) -> Response {
+    let started = Instant::now();
+    let headers = request.headers();
+    let delivery_id = header(headers, "x-github-delivery").unwrap_or_default();
+    // The SDK derives the event ID, which doubles as the correlation ID, from
+    // the delivery ID in the same way.
+    let correlation_id = if delivery_id.is_empty() {
+        String::new()
+    } else {
+        format!("gh-{delivery_id}")
+    };
+    let payload_bytes = header(headers, "content-length")
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(0);
+
+    let span = tracing::info_span!(
+        "http_request",
+        method = %request.method(),
+        path = %request.uri().path(),
+        delivery_id = %delivery_id,
+        correlation_id = %correlation_id,
+        event_type = %header(headers, "x-github-event").unwrap_or_default(),
+        payload_bytes,
+    );
+    span.in_scope(|| debug!(headers = ?redact_headers(request.headers()), "Request received"));
+
+    let response = next.run(request).instrument(span.clone()).await;
+
+    let status = response.status();
+    let duration_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+    span.in_scope(|| {
+        info!(
+            status = status.as_u16(),
+            duration_ms,
+            outcome = outcome(status),
+            "Request completed"
+        );
+    });
+    response
+}
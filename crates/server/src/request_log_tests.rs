@@ -0,0 +1,182 @@
+use super::*;
+use axum::{middleware, routing::post, Router};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Subscriber,
+};
+use tracing_subscriber::{layer::Context, layer::SubscriberExt, registry::LookupSpan, Layer};
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Helpers
+// ──────────────────────────────────────────────────────────────────────────────
+
+type Fields = HashMap<String, String>;
+
+#[derive(Default)]
+struct FieldVisitor(Fields);
+
+impl Visit for FieldVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.insert(
+            field.name().to_string(),
+            format!("{value:?}").trim_matches('"').to_string(),
+        );
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+}
+
+/// Records the fields of every span and event, events merged with the fields
+/// of the span they were emitted in.
+#[derive(Clone, Default)]
+struct CaptureLayer {
+    spans: Arc<Mutex<HashMap<u64, Fields>>>,
+    events: Arc<Mutex<Vec<Fields>>>,
+}
+
+impl<S> Layer<S> for CaptureLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, _ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        self.spans.lock().unwrap().insert(id.into_u64(), visitor.0);
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let mut fields = ctx
+            .event_span(event)
+            .and_then(|s| self.spans.lock().unwrap().get(&s.id().into_u64()).cloned())
+            .unwrap_or_default();
+        fields.extend(visitor.0);
+        self.events.lock().unwrap().push(fields);
+    }
+}
+
+impl CaptureLayer {
+    fn completion_events(&self) -> Vec<Fields> {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|e| e.get("message").map(String::as_str) == Some("Request completed"))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Sends a raw HTTP/1.1 request to the app and waits for the response.
+async fn send(app: Router, request: &str) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await });
+
+    let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    response
+}
+
+fn app() -> Router {
+    Router::new()
+        .route(
+            "/webhook",
+            post(|| async {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                StatusCode::OK
+            }),
+        )
+        .route(
+            "/broken",
+            post(|| async { StatusCode::INTERNAL_SERVER_ERROR }),
+        )
+        .layer(middleware::from_fn(log_requests))
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// log_requests
+// ──────────────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_log_requests_records_status_duration_and_delivery() {
+    let capture = CaptureLayer::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+
+    let response = send(
+        app(),
+        "POST /webhook HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\
+         X-GitHub-Delivery: 72d3162e\r\nX-GitHub-Event: pull_request\r\n\
+         X-Hub-Signature-256: sha256=abc\r\nContent-Length: 2\r\n\r\n{}",
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 200"));
+
+    let events = capture.completion_events();
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(event["status"], "200");
+    assert!(event["duration_ms"].parse::<u64>().unwrap() >= 50);
+    assert_eq!(event["outcome"], "accepted");
+    assert_eq!(event["method"], "POST");
+    assert_eq!(event["path"], "/webhook");
+    assert_eq!(event["delivery_id"], "72d3162e");
+    assert_eq!(event["correlation_id"], "gh-72d3162e");
+    assert_eq!(event["event_type"], "pull_request");
+    assert_eq!(event["payload_bytes"], "2");
+}
+
+#[tokio::test]
+async fn test_log_requests_reports_server_errors() {
+    let capture = CaptureLayer::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+
+    send(
+        app(),
+        "POST /broken HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: 0\r\n\r\n",
+    )
+    .await;
+
+    let events = capture.completion_events();
+    assert_eq!(events[0]["status"], "500");
+    assert_eq!(events[0]["outcome"], "error");
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// redact_headers
+// ──────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_redact_headers_hides_signatures_and_secrets() {
+    let mut headers = HeaderMap::new();
+    headers.insert("x-hub-signature-256", "sha256=abc".parse().unwrap());
+    headers.insert("x-hub-signature", "sha1=abc".parse().unwrap());
+    headers.insert("authorization", "Bearer token".parse().unwrap());
+    headers.insert("x-webhook-secret", "hunter2".parse().unwrap());
+    headers.insert("x-github-event", "push".parse().unwrap());
+
+    let redacted: HashMap<String, String> = redact_headers(&headers).into_iter().collect();
+
+    assert_eq!(redacted["x-hub-signature-256"], REDACTED);
+    assert_eq!(redacted["x-hub-signature"], REDACTED);
+    assert_eq!(redacted["authorization"], REDACTED);
+    assert_eq!(redacted["x-webhook-secret"], REDACTED);
+    assert_eq!(redacted["x-github-event"], "push");
+}
@@ -0,0 +1,203 @@
+//! Webhook secret rotation.
+//!
+//! GitHub signs every delivery with a single secret, but while that secret is
+//! being rotated deliveries may be signed with either the old or the new one.
+//! [`WebhookSecrets`] holds every accepted secret, and
+//! [`RotatingWebhookReceiver`] checks the signature of a delivery against each
+//! of them with the SDK's constant-time [`SignatureValidator`] before handing
+//! it to an SDK [`WebhookReceiver`] configured with the secret that matched.
+//!
+//! Secrets are identified in logs by their index, never by their value.
+//!
+//! # Rotating the secret
+//!
+//! 1. Set `GITHUB_WEBHOOK_SECRETS` to `new,old` and restart the server.
+//! 2. Change the secret of the GitHub App webhook to `new`.
+//! 3. Once no delivery has matched secret 1 for a while, drop `old`.
+
+use crate::{errors::Error, handler::WebhookSecretProvider};
+use github_bot_sdk::{
+    events::{EventProcessor, ProcessorConfig},
+    webhook::{
+        SignatureValidator, WebhookHandler, WebhookReceiver, WebhookRequest, WebhookResponse,
+    },
+};
+use std::sync::Arc;
+use tracing::debug;
+
+#[cfg(test)]
+#[path = "webhook_secrets_tests.rs"]
+mod tests;
+
+/// Environment variable holding the single webhook secret.
+pub const WEBHOOK_SECRET_ENV: &str = "GITHUB_WEBHOOK_SECRET";
+
+/// Environment variable holding a comma-separated list of accepted webhook
+/// secrets.
+pub const WEBHOOK_SECRETS_ENV: &str = "GITHUB_WEBHOOK_SECRETS";
+
+/// The webhook secrets a delivery may be signed with, in order of preference.
+///
+/// There is always at least one secret. The first one is the primary secret.
+#[derive(Clone)]
+pub struct WebhookSecrets {
+    secrets: Vec<String>,
+}
+
+impl WebhookSecrets {
+    /// Accept `secrets`, in order. Duplicates are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Environment`] when `secrets` is empty or contains an
+    /// empty secret.
+    #[allow(clippy::result_large_err)] // errors::Error is intentionally large
+    pub fn new<I, S>(secrets: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut accepted: Vec<String> = Vec::new();
+        for secret in secrets {
+            let secret = secret.into();
+            if secret.is_empty() {
+                return Err(Error::environment(
+                    WEBHOOK_SECRETS_ENV,
+                    "webhook secrets must not be empty",
+                ));
+            }
+            if !accepted.contains(&secret) {
+                accepted.push(secret);
+            }
+        }
+        if accepted.is_empty() {
+            return Err(Error::environment(
+                WEBHOOK_SECRET_ENV,
+                format!("set {WEBHOOK_SECRET_ENV} or {WEBHOOK_SECRETS_ENV}"),
+            ));
+        }
+        Ok(Self { secrets: accepted })
+    }
+
+    /// Read the secrets from [`WEBHOOK_SECRET_ENV`] and
+    /// [`WEBHOOK_SECRETS_ENV`].
+    ///
+    /// # Errors
+    ///
+    /// See [`from_values`](Self::from_values).
+    #[allow(clippy::result_large_err)] // errors::Error is intentionally large
+    pub fn from_env() -> Result<Self, Error> {
+        Self::from_values(
+            std::env::var(WEBHOOK_SECRET_ENV).ok().as_deref(),
+            std::env::var(WEBHOOK_SECRETS_ENV).ok().as_deref(),
+        )
+    }
+
+    /// Combine the single `secret` and the comma-separated `secrets` list.
+    ///
+    /// The single secret, when set, comes first, followed by the entries of
+    /// the list. Whitespace around list entries is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Environment`] when neither is set, or the list has an
+    /// empty entry.
+    #[allow(clippy::result_large_err)] // errors::Error is intentionally large
+    pub fn from_values(secret: Option<&str>, secrets: Option<&str>) -> Result<Self, Error> {
+        let list = secrets
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| s.split(',').map(str::trim).collect::<Vec<_>>())
+            .unwrap_or_default();
+        Self::new(secret.into_iter().chain(list))
+    }
+
+    /// The primary secret.
+    #[must_use]
+    pub fn primary(&self) -> &str {
+        &self.secrets[0]
+    }
+
+    /// The number of accepted secrets.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.secrets.len()
+    }
+}
+
+impl std::fmt::Debug for WebhookSecrets {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookSecrets")
+            .field("count", &self.secrets.len())
+            .finish()
+    }
+}
+
+/// A [`WebhookReceiver`] that accepts deliveries signed with any of several
+/// [`WebhookSecrets`].
+pub struct RotatingWebhookReceiver {
+    validators: Vec<SignatureValidator>,
+    receivers: Vec<WebhookReceiver>,
+}
+
+impl RotatingWebhookReceiver {
+    /// Create a receiver accepting every secret in `secrets`.
+    #[must_use]
+    pub fn new(secrets: &WebhookSecrets) -> Self {
+        let providers: Vec<Arc<WebhookSecretProvider>> = secrets
+            .secrets
+            .iter()
+            .map(|secret| Arc::new(WebhookSecretProvider::new(secret.clone())))
+            .collect();
+        Self {
+            validators: providers
+                .iter()
+                .map(|provider| SignatureValidator::new(provider.clone()))
+                .collect(),
+            receivers: providers
+                .into_iter()
+                .map(|provider| {
+                    WebhookReceiver::new(provider, EventProcessor::new(ProcessorConfig::default()))
+                })
+                .collect(),
+        }
+    }
+
+    /// Register `handler` for deliveries signed with any of the secrets.
+    pub async fn add_handler(&mut self, handler: Arc<dyn WebhookHandler>) {
+        for receiver in &mut self.receivers {
+            receiver.add_handler(handler.clone()).await;
+        }
+    }
+
+    /// Validate and dispatch `request`.
+    ///
+    /// A delivery whose signature matches none of the secrets is handed to
+    /// the receiver of the primary secret, which rejects it with the SDK's
+    /// usual response.
+    pub async fn receive_webhook(&self, request: WebhookRequest) -> WebhookResponse {
+        let index = match request.signature() {
+            Some(signature) => self.matching_secret(request.payload(), signature).await,
+            None => None,
+        };
+        if let Some(index) = index {
+            debug!(
+                secret_index = index,
+                delivery_id = request.delivery_id().unwrap_or_default(),
+                "Webhook signature matched"
+            );
+        }
+        self.receivers[index.unwrap_or(0)]
+            .receive_webhook(request)
+            .await
+    }
+
+    /// Index of the first secret that `signature` was made with, if any.
+    pub(crate) async fn matching_secret(&self, payload: &[u8], signature: &str) -> Option<usize> {
+        for (index, validator) in self.validators.iter().enumerate() {
+            if let Ok(true) = validator.validate(payload, signature).await {
+                return Some(index);
+            }
+        }
+        None
+    }
+}
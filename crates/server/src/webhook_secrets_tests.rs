@@ -0,0 +1,185 @@
+use super::*;
+use bytes::Bytes;
+use std::collections::HashMap;
+
+const OLD_SECRET: &str = "old-webhook-secret";
+const NEW_SECRET: &str = "new-webhook-secret";
+
+fn sign(payload: &str, secret: &str) -> String {
+    use hmac::{Hmac, KeyInit, Mac};
+    use sha2::Sha256;
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(payload.as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+fn signed_request(payload: &str, secret: &str) -> WebhookRequest {
+    let headers = HashMap::from([
+        ("x-github-event".to_string(), "ping".to_string()),
+        ("x-github-delivery".to_string(), "delivery-1".to_string()),
+        ("x-hub-signature-256".to_string(), sign(payload, secret)),
+    ]);
+    WebhookRequest::new(headers, Bytes::from(payload.to_string()))
+}
+
+/// A `ping` delivery payload the SDK accepts.
+fn ping_payload() -> String {
+    serde_json::json!({
+        "zen": "Keep it logically awesome.",
+        "repository": {
+            "id": 123,
+            "name": "test-repo",
+            "full_name": "owner/test-repo",
+            "owner": {
+                "login": "owner",
+                "id": 1,
+                "avatar_url": "https://github.com/avatars/u/1",
+                "type": "Organization"
+            },
+            "private": false,
+            "default_branch": "main",
+            "html_url": "https://github.com/owner/test-repo",
+            "clone_url": "https://github.com/owner/test-repo.git",
+            "ssh_url": "git@github.com:owner/test-repo.git",
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        }
+    })
+    .to_string()
+}
+
+fn rotating_receiver() -> RotatingWebhookReceiver {
+    RotatingWebhookReceiver::new(&WebhookSecrets::new([NEW_SECRET, OLD_SECRET]).unwrap())
+}
+
+// ── WebhookSecrets ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_from_values_puts_single_secret_first() {
+    let secrets = WebhookSecrets::from_values(Some("a"), Some("b, c")).unwrap();
+
+    assert_eq!(secrets.secrets, vec!["a", "b", "c"]);
+    assert_eq!(secrets.primary(), "a");
+    assert_eq!(secrets.count(), 3);
+}
+
+#[test]
+fn test_from_values_accepts_either_variable_alone() {
+    assert_eq!(
+        WebhookSecrets::from_values(Some("a"), None)
+            .unwrap()
+            .secrets,
+        vec!["a"]
+    );
+    assert_eq!(
+        WebhookSecrets::from_values(None, Some("b,c"))
+            .unwrap()
+            .secrets,
+        vec!["b", "c"]
+    );
+}
+
+#[test]
+fn test_from_values_drops_duplicates() {
+    let secrets = WebhookSecrets::from_values(Some("a"), Some("a,b")).unwrap();
+
+    assert_eq!(secrets.secrets, vec!["a", "b"]);
+}
+
+#[test]
+fn test_from_values_requires_a_secret() {
+    assert!(matches!(
+        WebhookSecrets::from_values(None, None),
+        Err(Error::Environment { .. })
+    ));
+    assert!(matches!(
+        WebhookSecrets::from_values(None, Some("  ")),
+        Err(Error::Environment { .. })
+    ));
+}
+
+#[test]
+fn test_from_values_rejects_empty_list_entries() {
+    assert!(matches!(
+        WebhookSecrets::from_values(None, Some("a,,b")),
+        Err(Error::Environment { .. })
+    ));
+}
+
+#[test]
+fn test_debug_does_not_reveal_secrets() {
+    let secrets = WebhookSecrets::new([NEW_SECRET, OLD_SECRET]).unwrap();
+
+    let debug = format!("{secrets:?}");
+
+    assert!(!debug.contains(NEW_SECRET), "{debug}");
+    assert!(!debug.contains(OLD_SECRET), "{debug}");
+    assert!(debug.contains("count: 2"), "{debug}");
+}
+
+// ── RotatingWebhookReceiver ──────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_matching_secret_reports_the_index_of_the_signing_secret() {
+    let receiver = rotating_receiver();
+    let payload = &ping_payload();
+
+    assert_eq!(
+        receiver
+            .matching_secret(payload.as_bytes(), &sign(payload, NEW_SECRET))
+            .await,
+        Some(0)
+    );
+    assert_eq!(
+        receiver
+            .matching_secret(payload.as_bytes(), &sign(payload, OLD_SECRET))
+            .await,
+        Some(1)
+    );
+    assert_eq!(
+        receiver
+            .matching_secret(payload.as_bytes(), &sign(payload, "other"))
+            .await,
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_payload_signed_with_second_secret_is_accepted() {
+    let receiver = rotating_receiver();
+    let payload = &ping_payload();
+
+    let response = receiver
+        .receive_webhook(signed_request(payload, OLD_SECRET))
+        .await;
+
+    assert!(response.is_success(), "{response:?}");
+}
+
+#[tokio::test]
+async fn test_payload_signed_with_primary_secret_is_accepted() {
+    let receiver = rotating_receiver();
+    let payload = &ping_payload();
+
+    let response = receiver
+        .receive_webhook(signed_request(payload, NEW_SECRET))
+        .await;
+
+    assert!(response.is_success(), "{response:?}");
+}
+
+#[tokio::test]
+async fn test_payload_signed_with_unknown_secret_is_rejected() {
+    let receiver = rotating_receiver();
+    let payload = &ping_payload();
+
+    let response = receiver
+        .receive_webhook(signed_request(payload, "retired-secret"))
+        .await;
+
+    assert!(
+        matches!(response, WebhookResponse::Unauthorized { .. }),
+        "{response:?}"
+    );
+}
@@ -0,0 +1,369 @@
+//! Assertions over the calls recorded by the mocks
+//!
+//! [`CallVerifier`] wraps a mock's call history and checks how often, in
+//! which order and with which arguments methods were called:
+//!
+//! ```rust,no_run
+//! use release_regent_testing::assertions::CallVerifier;
+//! use release_regent_testing::mocks::MockGitHubOperations;
+//!
+//! # async fn example(mock: MockGitHubOperations) -> Result<(), Box<dyn std::error::Error>> {
+//! let verifier = CallVerifier::new(mock.call_history().await);
+//! verifier.called_in_order(&["get_commits_between", "create_release"])?;
+//! verifier.called_once("create_release")?;
+//! verifier.called_with("create_release", |params| params["tag"] == "v1.2.0")?;
+//! verifier.never_called("delete_branch")?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::mocks::{CallInfo, CallResult};
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// A call verification that did not hold
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallVerificationError {
+    /// What was expected and what was recorded instead
+    pub message: String,
+}
+
+impl fmt::Display for CallVerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "call verification failed: {}", self.message)
+    }
+}
+
+impl std::error::Error for CallVerificationError {}
+
+/// Verifies the call history recorded by a mock
+#[derive(Debug, Clone)]
+pub struct CallVerifier {
+    /// Recorded calls, oldest first
+    calls: Vec<CallInfo>,
+}
+
+impl CallVerifier {
+    /// Create a verifier for a recorded call history
+    ///
+    /// # Parameters
+    /// - `calls`: Calls as returned by a mock's `call_history()`, oldest first
+    ///
+    /// # Returns
+    /// New verifier
+    #[must_use]
+    pub fn new(calls: Vec<CallInfo>) -> Self {
+        Self { calls }
+    }
+
+    /// Recorded calls to `method`, oldest first
+    ///
+    /// # Parameters
+    /// - `method`: Method name
+    ///
+    /// # Returns
+    /// The matching calls
+    #[must_use]
+    pub fn calls_to(&self, method: &str) -> Vec<&CallInfo> {
+        self.calls.iter().filter(|c| c.method == method).collect()
+    }
+
+    /// Check that `method` was called exactly once
+    ///
+    /// # Parameters
+    /// - `method`: Method name
+    ///
+    /// # Errors
+    /// Returns a [`CallVerificationError`] with the number of calls otherwise.
+    pub fn called_once(&self, method: &str) -> Result<(), CallVerificationError> {
+        match self.calls_to(method).len() {
+            1 => Ok(()),
+            count => Err(self.failure(format!(
+                "expected '{method}' to be called once, but it was called {count} times"
+            ))),
+        }
+    }
+
+    /// Check that `method` was never called
+    ///
+    /// # Parameters
+    /// - `method`: Method name
+    ///
+    /// # Errors
+    /// Returns a [`CallVerificationError`] with the number of calls otherwise.
+    pub fn never_called(&self, method: &str) -> Result<(), CallVerificationError> {
+        match self.calls_to(method).len() {
+            0 => Ok(()),
+            count => Err(self.failure(format!(
+                "expected '{method}' never to be called, but it was called {count} times"
+            ))),
+        }
+    }
+
+    /// Check that at least one call to `method` has matching arguments
+    ///
+    /// The predicate receives the arguments parsed by [`parse_parameters`].
+    ///
+    /// # Parameters
+    /// - `method`: Method name
+    /// - `predicate`: Returns `true` for matching arguments
+    ///
+    /// # Errors
+    /// Returns a [`CallVerificationError`] listing the recorded arguments of
+    /// every call to `method` when none of them match.
+    pub fn called_with<F>(&self, method: &str, predicate: F) -> Result<(), CallVerificationError>
+    where
+        F: Fn(&Value) -> bool,
+    {
+        let calls = self.calls_to(method);
+        if calls
+            .iter()
+            .any(|call| predicate(&parse_parameters(&call.parameters)))
+        {
+            return Ok(());
+        }
+
+        let message = if calls.is_empty() {
+            format!(
+                "expected '{method}' to be called with matching arguments, but it was never called"
+            )
+        } else {
+            let arguments: Vec<String> = calls
+                .iter()
+                .map(|call| format!("  {}", call.parameters))
+                .collect();
+            format!(
+                "no call to '{method}' had matching arguments; recorded arguments:\n{}",
+                arguments.join("\n")
+            )
+        };
+        Err(self.failure(message))
+    }
+
+    /// Check that `methods` were called in the given order
+    ///
+    /// Other calls may happen before, between and after them.
+    ///
+    /// # Parameters
+    /// - `methods`: Method names in the expected order
+    ///
+    /// # Errors
+    /// Returns a [`CallVerificationError`] naming the first method that was
+    /// not called after the previous one.
+    pub fn called_in_order(&self, methods: &[&str]) -> Result<(), CallVerificationError> {
+        let mut remaining = self.calls.iter();
+        for (index, method) in methods.iter().enumerate() {
+            if !remaining.any(|call| call.method == *method) {
+                let message = if index == 0 {
+                    format!("expected '{method}' to be called, but it was never called")
+                } else {
+                    format!(
+                        "expected '{method}' to be called after '{}', but it was not",
+                        methods[index - 1]
+                    )
+                };
+                return Err(self.failure(message));
+            }
+        }
+        Ok(())
+    }
+
+    fn failure(&self, message: String) -> CallVerificationError {
+        let recorded: Vec<String> = self
+            .calls
+            .iter()
+            .map(|call| match &call.result {
+                CallResult::Success => call.method.clone(),
+                CallResult::Error(_) => format!("{} (error)", call.method),
+                CallResult::Cancelled => format!("{} (cancelled)", call.method),
+            })
+            .collect();
+        CallVerificationError {
+            message: format!("{message}\nrecorded calls: [{}]", recorded.join(", ")),
+        }
+    }
+}
+
+/// Parse recorded call parameters into a JSON value
+///
+/// Parameters that are valid JSON are returned as is. The mocks record most
+/// parameters as `key=value` pairs separated by `, `; these become an object
+/// with one member per key. Values that are JSON numbers or booleans keep
+/// their type and everything else becomes a string, so
+/// `owner=acme, pr=42` becomes `{"owner": "acme", "pr": 42}`.
+///
+/// # Parameters
+/// - `raw`: Parameters as recorded in [`CallInfo::parameters`]
+///
+/// # Returns
+/// The parsed parameters; a string value when they are neither JSON nor
+/// `key=value` pairs
+#[must_use]
+pub fn parse_parameters(raw: &str) -> Value {
+    if let Ok(value) = serde_json::from_str::<Value>(raw) {
+        return value;
+    }
+
+    let mut pairs: Vec<(String, String)> = Vec::new();
+    for segment in raw.split(", ") {
+        match segment.split_once('=') {
+            Some((key, value)) if is_key(key) => pairs.push((key.to_string(), value.to_string())),
+            // A value that itself contains ", " continues the previous pair
+            _ => match pairs.last_mut() {
+                Some((_, value)) => {
+                    value.push_str(", ");
+                    value.push_str(segment);
+                }
+                None => return Value::String(raw.to_string()),
+            },
+        }
+    }
+
+    let object: Map<String, Value> = pairs
+        .into_iter()
+        .map(|(key, value)| {
+            let parsed = match serde_json::from_str::<Value>(&value) {
+                Ok(scalar @ (Value::Number(_) | Value::Bool(_))) => scalar,
+                _ => Value::String(value),
+            };
+            (key, parsed)
+        })
+        .collect();
+    Value::Object(object)
+}
+
+fn is_key(key: &str) -> bool {
+    !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::MockGitHubOperations;
+    use release_regent_core::traits::git_operations::GitOperations;
+
+    fn call(method: &str, parameters: &str) -> CallInfo {
+        CallInfo {
+            method: method.to_string(),
+            parameters: parameters.to_string(),
+            timestamp: chrono::Utc::now(),
+            result: CallResult::Success,
+        }
+    }
+
+    #[test]
+    fn test_called_in_order_allows_interleaved_calls() {
+        let verifier = CallVerifier::new(vec![
+            call("get_latest_release", "owner=o, repo=r"),
+            call("get_commits_between", "owner=o, repo=r"),
+            call("list_pr_labels", "owner=o, repo=r, pr=7"),
+            call("create_release", "owner=o, repo=r, tag=v1.1.0"),
+        ]);
+
+        assert!(verifier
+            .called_in_order(&["get_commits_between", "create_release"])
+            .is_ok());
+    }
+
+    #[test]
+    fn test_called_in_order_reports_out_of_order_call() {
+        let verifier = CallVerifier::new(vec![
+            call("create_release", "owner=o, repo=r, tag=v1.1.0"),
+            call("get_commits_between", "owner=o, repo=r"),
+        ]);
+
+        let error = verifier
+            .called_in_order(&["get_commits_between", "create_release"])
+            .unwrap_err();
+
+        assert!(error
+            .message
+            .contains("expected 'create_release' to be called after 'get_commits_between'"));
+        assert!(error
+            .message
+            .contains("recorded calls: [create_release, get_commits_between]"));
+    }
+
+    #[test]
+    fn test_called_once_and_never_called() {
+        let verifier = CallVerifier::new(vec![
+            call("list_releases", "owner=o, repo=r"),
+            call("list_releases", "owner=o, repo=r"),
+            call("create_release", "owner=o, repo=r, tag=v1.1.0"),
+        ]);
+
+        assert!(verifier.called_once("create_release").is_ok());
+        assert!(verifier.never_called("delete_branch").is_ok());
+        assert!(verifier
+            .called_once("list_releases")
+            .unwrap_err()
+            .message
+            .contains("called 2 times"));
+        assert!(verifier.never_called("create_release").is_err());
+    }
+
+    #[test]
+    fn test_called_with_matches_parsed_arguments() {
+        let verifier = CallVerifier::new(vec![
+            call("get_pull_request", "owner=o, repo=r, pr=7"),
+            call(
+                "create_pull_request",
+                "owner=o, repo=r, title=chore: release 1.1.0, again",
+            ),
+        ]);
+
+        assert!(verifier
+            .called_with("get_pull_request", |p| p["pr"] == 7 && p["owner"] == "o")
+            .is_ok());
+        assert!(verifier
+            .called_with("create_pull_request", |p| {
+                p["title"] == "chore: release 1.1.0, again"
+            })
+            .is_ok());
+
+        let error = verifier
+            .called_with("get_pull_request", |p| p["pr"] == 8)
+            .unwrap_err();
+        assert!(error
+            .message
+            .contains("recorded arguments:\n  owner=o, repo=r, pr=7"));
+    }
+
+    #[test]
+    fn test_called_with_reports_missing_method() {
+        let verifier = CallVerifier::new(Vec::new());
+
+        let error = verifier.called_with("create_tag", |_| true).unwrap_err();
+
+        assert!(error.message.contains("it was never called"));
+    }
+
+    #[test]
+    fn test_parse_parameters_accepts_json() {
+        assert_eq!(
+            parse_parameters(r#"{"owner":"o","pr":7}"#),
+            serde_json::json!({"owner": "o", "pr": 7})
+        );
+        assert_eq!(
+            parse_parameters("no pairs here"),
+            Value::String("no pairs here".into())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verifies_calls_recorded_by_mock() {
+        let mock = MockGitHubOperations::new().with_repository_exists(true);
+        let _ = mock.get_repository_info("test", "repo").await;
+        let _ = mock.tag_exists("test", "repo", "v1.0.0").await;
+
+        let verifier = CallVerifier::new(mock.call_history().await);
+
+        verifier
+            .called_in_order(&["get_repository_info", "tag_exists"])
+            .unwrap();
+        verifier
+            .called_with("tag_exists", |p| p["tag"] == "v1.0.0")
+            .unwrap();
+        verifier.never_called("create_release").unwrap();
+    }
+}
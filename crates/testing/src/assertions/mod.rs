@@ -4,17 +4,32 @@
 //! and verifying behavioral compliance.
 
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
 
 pub mod behavior_verifier;
+pub mod call_verifier;
 pub mod compliance_checker;
 pub mod examples;
+pub mod snapshot;
 pub mod spec_runner;
 
 pub use behavior_verifier::*;
+pub use call_verifier::{parse_parameters, CallVerificationError, CallVerifier};
 pub use compliance_checker::*;
+pub use snapshot::{
+    assert_changelog_matches, assert_snapshot_matches, compare_snapshot, NormalizeOptions,
+    SnapshotMismatch,
+};
+
+/// Metadata key under which a failed evaluation records why it failed
+pub const FAILURE_REASON_KEY: &str = "failure_reason";
+
+/// Custom comparison of expected and actual behavior, see
+/// [`SpecAssertion::with_matcher`]
+pub type SpecMatcher = Arc<dyn Fn(&str, &str) -> bool + Send + Sync>;
 
 /// Behavioral assertion for spec testing
-#[derive(Debug)]
 pub struct SpecAssertion {
     /// Test subject description
     pub subject: String,
@@ -28,6 +43,22 @@ pub struct SpecAssertion {
     pub passed: bool,
     /// Additional metadata for the assertion
     pub metadata: HashMap<String, String>,
+    /// Matcher used by [`evaluate`](Self::evaluate) instead of exact comparison
+    matcher: Option<SpecMatcher>,
+}
+
+impl fmt::Debug for SpecAssertion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpecAssertion")
+            .field("subject", &self.subject)
+            .field("specification", &self.specification)
+            .field("expected_behavior", &self.expected_behavior)
+            .field("actual_behavior", &self.actual_behavior)
+            .field("passed", &self.passed)
+            .field("metadata", &self.metadata)
+            .field("has_matcher", &self.matcher.is_some())
+            .finish()
+    }
 }
 
 impl SpecAssertion {
@@ -49,6 +80,7 @@ impl SpecAssertion {
             actual_behavior: None,
             passed: false,
             metadata: HashMap::new(),
+            matcher: None,
         }
     }
 
@@ -79,16 +111,71 @@ impl SpecAssertion {
         self
     }
 
+    /// Compare behavior with a custom matcher instead of exact matching
+    ///
+    /// # Parameters
+    /// - `matcher`: Function receiving the expected and actual behavior,
+    ///   returning whether they match
+    ///
+    /// # Returns
+    /// Self for method chaining
+    #[must_use]
+    pub fn with_matcher<F>(mut self, matcher: F) -> Self
+    where
+        F: Fn(&str, &str) -> bool + Send + Sync + 'static,
+    {
+        self.matcher = Some(Arc::new(matcher));
+        self
+    }
+
     /// Evaluate the assertion
     ///
+    /// Compares the expected and actual behavior with the matcher set by
+    /// [`with_matcher`](Self::with_matcher), or exactly (ignoring surrounding
+    /// whitespace) when there is none. An assertion without actual behavior
+    /// fails. The reason for a failure is recorded in the metadata under
+    /// [`FAILURE_REASON_KEY`].
+    ///
     /// # Returns
     /// Whether the assertion passed
     pub fn evaluate(&mut self) -> bool {
-        if let Some(actual) = &self.actual_behavior {
-            // Simple comparison - can be enhanced with more sophisticated matching
-            self.passed = actual.trim() == self.expected_behavior.trim();
-        } else {
-            self.passed = false;
+        let outcome = match &self.actual_behavior {
+            None => Err("no actual behavior was recorded".to_string()),
+            Some(actual) => match &self.matcher {
+                Some(matcher) if matcher(&self.expected_behavior, actual) => Ok(()),
+                Some(_) => Err(format!(
+                    "matcher rejected actual behavior '{actual}' for expected '{}'",
+                    self.expected_behavior
+                )),
+                None if actual.trim() == self.expected_behavior.trim() => Ok(()),
+                None => Err(format!(
+                    "expected '{}' but got '{actual}'",
+                    self.expected_behavior
+                )),
+            },
+        };
+        self.record(outcome)
+    }
+
+    /// Reason the last evaluation failed, if it did
+    ///
+    /// # Returns
+    /// Failure reason recorded by the last evaluation
+    #[must_use]
+    pub fn failure_reason(&self) -> Option<&str> {
+        self.metadata.get(FAILURE_REASON_KEY).map(String::as_str)
+    }
+
+    fn record(&mut self, outcome: Result<(), String>) -> bool {
+        match outcome {
+            Ok(()) => {
+                self.metadata.remove(FAILURE_REASON_KEY);
+                self.passed = true;
+            }
+            Err(reason) => {
+                self.metadata.insert(FAILURE_REASON_KEY.to_string(), reason);
+                self.passed = false;
+            }
         }
         self.passed
     }
@@ -104,8 +191,15 @@ impl SpecAssertion {
     where
         F: FnOnce(&str, &Option<String>) -> bool,
     {
-        self.passed = predicate(&self.expected_behavior, &self.actual_behavior);
-        self.passed
+        let outcome = if predicate(&self.expected_behavior, &self.actual_behavior) {
+            Ok(())
+        } else {
+            Err(format!(
+                "predicate rejected actual behavior {:?} for expected '{}'",
+                self.actual_behavior, self.expected_behavior
+            ))
+        };
+        self.record(outcome)
     }
 
     /// Evaluate using contains matching
@@ -113,12 +207,15 @@ impl SpecAssertion {
     /// # Returns
     /// Whether the assertion passed
     pub fn evaluate_contains(&mut self) -> bool {
-        if let Some(actual) = &self.actual_behavior {
-            self.passed = actual.contains(&self.expected_behavior);
-        } else {
-            self.passed = false;
-        }
-        self.passed
+        let outcome = match &self.actual_behavior {
+            None => Err("no actual behavior was recorded".to_string()),
+            Some(actual) if actual.contains(&self.expected_behavior) => Ok(()),
+            Some(actual) => Err(format!(
+                "expected '{actual}' to contain '{}'",
+                self.expected_behavior
+            )),
+        };
+        self.record(outcome)
     }
 
     /// Get assertion result
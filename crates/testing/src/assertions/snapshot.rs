@@ -0,0 +1,343 @@
+//! Snapshot assertions for multi-line output
+//!
+//! This module compares generated text, such as changelogs and version
+//! results, against an expected snapshot. Both sides are normalized first so
+//! that volatile data like commit SHAs and timestamps does not make the
+//! comparison fail, and a mismatch is reported as a line diff.
+
+use regex::Regex;
+use std::fmt;
+
+/// Placeholder substituted for commit SHAs by [`NormalizeOptions::default`]
+pub const SHA_PLACEHOLDER: &str = "<SHA>";
+
+/// Placeholder substituted for timestamps by [`NormalizeOptions::default`]
+pub const TIMESTAMP_PLACEHOLDER: &str = "<TIMESTAMP>";
+
+/// Placeholder substituted for dates by [`NormalizeOptions::default`]
+pub const DATE_PLACEHOLDER: &str = "<DATE>";
+
+/// How text is normalized before it is compared with a snapshot
+///
+/// Line endings are always converted to `\n`, trailing whitespace is removed
+/// from every line and leading and trailing blank lines are ignored. The
+/// replacements are then applied in the order they were added.
+#[derive(Debug, Clone)]
+pub struct NormalizeOptions {
+    /// Patterns and the text that replaces their matches
+    replacements: Vec<(Regex, String)>,
+}
+
+impl NormalizeOptions {
+    /// Create options without any replacements
+    ///
+    /// # Returns
+    /// Options that only normalize line endings and whitespace
+    #[must_use]
+    pub fn none() -> Self {
+        Self {
+            replacements: Vec::new(),
+        }
+    }
+
+    /// Replace every match of `pattern` with `replacement`
+    ///
+    /// `replacement` may refer to capture groups, e.g. `$1`.
+    ///
+    /// # Parameters
+    /// - `pattern`: Regular expression matching the volatile text
+    /// - `replacement`: Text to put in its place
+    ///
+    /// # Returns
+    /// Self for method chaining
+    ///
+    /// # Panics
+    /// Panics if `pattern` is not a valid regular expression.
+    #[must_use]
+    pub fn with_replacement(mut self, pattern: &str, replacement: &str) -> Self {
+        let regex = Regex::new(pattern)
+            .unwrap_or_else(|e| panic!("invalid normalization pattern '{pattern}': {e}"));
+        self.replacements.push((regex, replacement.to_string()));
+        self
+    }
+
+    /// Replace full and abbreviated commit SHAs (7 to 40 lowercase hex digits)
+    ///
+    /// # Returns
+    /// Self for method chaining
+    #[must_use]
+    pub fn with_shas(self) -> Self {
+        self.with_replacement(r"\b[0-9a-f]{7,40}\b", SHA_PLACEHOLDER)
+    }
+
+    /// Replace RFC 3339 timestamps such as `2024-05-01T12:30:00Z`
+    ///
+    /// # Returns
+    /// Self for method chaining
+    #[must_use]
+    pub fn with_timestamps(self) -> Self {
+        self.with_replacement(
+            r"\b\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}(?:\.\d+)?(?:Z|[+-]\d{2}:?\d{2})?",
+            TIMESTAMP_PLACEHOLDER,
+        )
+    }
+
+    /// Replace ISO 8601 dates such as `2024-05-01`
+    ///
+    /// Add this after [`with_timestamps`](Self::with_timestamps) so that
+    /// full timestamps are replaced as a whole.
+    ///
+    /// # Returns
+    /// Self for method chaining
+    #[must_use]
+    pub fn with_dates(self) -> Self {
+        self.with_replacement(r"\b\d{4}-\d{2}-\d{2}\b", DATE_PLACEHOLDER)
+    }
+
+    /// Normalize `text` for comparison
+    ///
+    /// # Parameters
+    /// - `text`: Text to normalize
+    ///
+    /// # Returns
+    /// Normalized text
+    #[must_use]
+    pub fn normalize(&self, text: &str) -> String {
+        let mut normalized = text.replace("\r\n", "\n");
+        for (regex, replacement) in &self.replacements {
+            normalized = regex
+                .replace_all(&normalized, replacement.as_str())
+                .into_owned();
+        }
+
+        let lines: Vec<&str> = normalized.lines().map(str::trim_end).collect();
+        lines.join("\n").trim_matches('\n').to_string()
+    }
+}
+
+impl Default for NormalizeOptions {
+    /// Replace SHAs, timestamps and dates
+    fn default() -> Self {
+        Self::none().with_timestamps().with_dates().with_shas()
+    }
+}
+
+/// A normalized snapshot that did not match the actual output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotMismatch {
+    /// Normalized expected text
+    pub expected: String,
+    /// Normalized actual text
+    pub actual: String,
+}
+
+impl SnapshotMismatch {
+    /// Line diff from the expected to the actual text
+    ///
+    /// Lines only in the expected text start with `-`, lines only in the
+    /// actual text start with `+` and unchanged lines start with a space.
+    ///
+    /// # Returns
+    /// The diff, one line per output line
+    #[must_use]
+    pub fn diff(&self) -> String {
+        line_diff(&self.expected, &self.actual)
+    }
+}
+
+impl fmt::Display for SnapshotMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "snapshot mismatch (- expected, + actual):")?;
+        write!(f, "{}", self.diff())
+    }
+}
+
+impl std::error::Error for SnapshotMismatch {}
+
+/// Compare output with a snapshot after normalizing both
+///
+/// # Parameters
+/// - `actual`: Output produced by the code under test
+/// - `expected`: Expected snapshot
+/// - `options`: How to normalize both texts
+///
+/// # Returns
+/// `Ok(())` when the normalized texts are equal
+///
+/// # Errors
+/// Returns a [`SnapshotMismatch`] holding both normalized texts otherwise.
+pub fn compare_snapshot(
+    actual: &str,
+    expected: &str,
+    options: &NormalizeOptions,
+) -> Result<(), SnapshotMismatch> {
+    let actual = options.normalize(actual);
+    let expected = options.normalize(expected);
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(SnapshotMismatch { expected, actual })
+    }
+}
+
+/// Assert that output matches a snapshot after normalizing both
+///
+/// # Parameters
+/// - `actual`: Output produced by the code under test
+/// - `expected`: Expected snapshot
+/// - `options`: How to normalize both texts
+///
+/// # Panics
+/// Panics with a line diff when the normalized texts differ.
+#[track_caller]
+pub fn assert_snapshot_matches(actual: &str, expected: &str, options: &NormalizeOptions) {
+    if let Err(mismatch) = compare_snapshot(actual, expected, options) {
+        panic!("{mismatch}");
+    }
+}
+
+/// Assert that a generated changelog matches a snapshot
+///
+/// # Parameters
+/// - `actual`: Generated changelog
+/// - `expected`: Expected changelog
+/// - `options`: How to normalize both texts, usually [`NormalizeOptions::default`]
+///
+/// # Panics
+/// Panics with a line diff when the normalized changelogs differ.
+#[track_caller]
+pub fn assert_changelog_matches(actual: &str, expected: &str, options: &NormalizeOptions) {
+    if let Err(mismatch) = compare_snapshot(actual, expected, options) {
+        panic!("changelog {mismatch}");
+    }
+}
+
+/// Line diff based on the longest common subsequence of lines
+fn line_diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // lcs[i][j] is the length of the longest common subsequence of
+    // old[i..] and new[j..].
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut output = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            output.push(format!("  {}", old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            output.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            output.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+    output.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builders::helpers::generate_git_sha;
+
+    fn changelog(shas: &[String], date: &str) -> String {
+        format!(
+            "## [1.3.0] - {date}\r\n\
+             \r\n\
+             ### Features\r\n\
+             \r\n\
+             - add login ({})   \r\n\
+             \r\n\
+             ### Bug Fixes\r\n\
+             \r\n\
+             - handle empty input ({})\r\n",
+            &shas[0][..7],
+            shas[1]
+        )
+    }
+
+    #[test]
+    fn test_changelog_with_random_shas_matches_after_normalization() {
+        let expected = "\
+## [1.3.0] - <DATE>
+
+### Features
+
+- add login (<SHA>)
+
+### Bug Fixes
+
+- handle empty input (<SHA>)
+";
+        for _ in 0..5 {
+            let shas = vec![generate_git_sha(), generate_git_sha()];
+            let actual = changelog(&shas, "2024-05-01");
+
+            assert_changelog_matches(&actual, expected, &NormalizeOptions::default());
+        }
+    }
+
+    #[test]
+    fn test_normalize_replaces_timestamps_before_dates() {
+        let options = NormalizeOptions::default();
+
+        assert_eq!(
+            options.normalize("released 2024-05-01T12:30:00Z on 2024-05-01"),
+            "released <TIMESTAMP> on <DATE>"
+        );
+    }
+
+    #[test]
+    fn test_custom_replacement_with_capture_group() {
+        let options = NormalizeOptions::none().with_replacement(r"#(\d+)", "#<PR>");
+
+        assert_eq!(
+            options.normalize("fix: crash (#1234)"),
+            "fix: crash (#<PR>)"
+        );
+    }
+
+    #[test]
+    fn test_mismatch_reports_line_diff() {
+        let mismatch = compare_snapshot(
+            "## 1.0.0\n- feat: a\n- fix: c",
+            "## 1.0.0\n- feat: a\n- fix: b",
+            &NormalizeOptions::none(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            mismatch.diff(),
+            "  ## 1.0.0\n  - feat: a\n- - fix: b\n+ - fix: c"
+        );
+        assert!(mismatch.to_string().starts_with("snapshot mismatch"));
+    }
+
+    #[test]
+    #[should_panic(expected = "changelog snapshot mismatch")]
+    fn test_assert_changelog_matches_panics_on_mismatch() {
+        assert_changelog_matches("- feat: a", "- feat: b", &NormalizeOptions::default());
+    }
+
+    #[test]
+    fn test_version_output_matches_without_normalization() {
+        assert_snapshot_matches(
+            "next: 1.3.0\r\nbump: minor\n\n",
+            "next: 1.3.0\nbump: minor",
+            &NormalizeOptions::none(),
+        );
+    }
+}
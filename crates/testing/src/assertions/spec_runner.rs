@@ -134,7 +134,7 @@ mod tests {
         let result = SpecRunner::new("Test Suite")
             .with_assertion(assertion)
             .run_with_evaluator(|_expected, actual| {
-                actual.as_ref().map_or(false, |a| a.contains("Different"))
+                actual.as_ref().is_some_and(|a| a.contains("Different"))
             });
 
         assert_eq!(result.total_assertions, 1);
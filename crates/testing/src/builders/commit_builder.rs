@@ -21,6 +21,7 @@ pub struct CommitBuilder {
     #[allow(dead_code)] // retained for realistic test data structure
     tree_sha: String,
     parents: Vec<String>,
+    footers: Vec<(String, String)>,
 }
 
 impl CommitBuilder {
@@ -41,6 +42,7 @@ impl CommitBuilder {
             committer_date: timestamp,
             tree_sha: generate_git_sha(),
             parents: vec![],
+            footers: vec![],
         }
     }
 
@@ -65,6 +67,24 @@ impl CommitBuilder {
         self
     }
 
+    /// Append a `key: value` footer to the message
+    ///
+    /// Values starting with `#` (issue references) use the `key #value`
+    /// form instead, e.g. `with_footer("Closes", "#123")` produces
+    /// `Closes #123`. Footers are appended after a blank line when the
+    /// commit is built, so they survive later calls to `with_message`.
+    #[must_use]
+    pub fn with_footer(mut self, key: &str, value: &str) -> Self {
+        self.footers.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Append a `BREAKING CHANGE: <description>` footer to the message
+    #[must_use]
+    pub fn with_breaking_change(self, description: &str) -> Self {
+        self.with_footer("BREAKING CHANGE", description)
+    }
+
     /// Set `GitCommit` author
     #[must_use]
     pub fn with_author(mut self, name: &str, email: &str) -> Self {
@@ -82,7 +102,21 @@ impl Default for CommitBuilder {
 
 impl TestDataBuilder<GitCommit> for CommitBuilder {
     fn build(self) -> GitCommit {
-        let message = self.message;
+        let mut message = self.message;
+        if !self.footers.is_empty() {
+            let footers: Vec<String> = self
+                .footers
+                .iter()
+                .map(|(key, value)| {
+                    if value.starts_with('#') {
+                        format!("{key} {value}")
+                    } else {
+                        format!("{key}: {value}")
+                    }
+                })
+                .collect();
+            message = format!("{}\n\n{}", message.trim_end(), footers.join("\n"));
+        }
         let subject = GitCommit::extract_subject(&message);
         let body = GitCommit::extract_body(&message);
 
@@ -7,10 +7,12 @@ use crate::builders::{
 use release_regent_core::{
     changelog::ChangelogConfig,
     config::{
-        BranchConfig, CoreConfig, ErrorHandlingConfig, NotificationConfig, NotificationStrategy,
-        ReleasePrConfig, ReleaseRegentConfig, ReleasesConfig, VersioningConfig, VersioningStrategy,
+        BranchConfig, CommitExclusionConfig, CoreConfig, ErrorHandlingConfig,
+        NoConventionalCommitsPolicy, NotificationConfig, NotificationStrategy, ReleasePrConfig,
+        ReleaseRegentConfig, ReleasesConfig, ScopeFilterConfig, VersioningConfig,
+        VersioningStrategy, CURRENT_CONFIG_VERSION,
     },
-    traits::configuration_provider::RepositoryConfig,
+    traits::{configuration_provider::RepositoryConfig, github_operations::LabelMode},
 };
 
 /// Builder for creating test repository configuration data
@@ -83,6 +85,7 @@ impl Default for ConfigurationBuilder {
 /// Create a default `ReleaseRegentConfig` for testing
 fn create_default_config() -> ReleaseRegentConfig {
     ReleaseRegentConfig {
+        config_version: CURRENT_CONFIG_VERSION,
         core: CoreConfig {
             version_prefix: "v".to_string(),
             branches: BranchConfig {
@@ -97,11 +100,16 @@ fn create_default_config() -> ReleaseRegentConfig {
             draft: false,
             manifest_files: Vec::new(),
             auto_detect_manifests: true,
+            branch_template: None,
+            labels: Vec::new(),
+            label_mode: LabelMode::default(),
         },
         releases: ReleasesConfig {
             draft: false,
             prerelease: false,
             generate_notes: true,
+            target_branch: None,
+            tag_signing: None,
         },
         error_handling: ErrorHandlingConfig {
             max_retries: 3,
@@ -119,6 +127,14 @@ fn create_default_config() -> ReleaseRegentConfig {
             strategy: VersioningStrategy::Conventional,
             allow_override: false,
             excluded_pr_authors: Vec::new(),
+            exclusions: CommitExclusionConfig::default(),
+            scopes: ScopeFilterConfig::default(),
+            minimum_version: None,
+            breaking_scopes: Vec::new(),
+            use_pr_title: false,
+            release_on_no_bump: false,
+            no_conventional_commits: NoConventionalCommitsPolicy::default(),
+            initial_version: None,
         },
         changelog: ChangelogConfig::default(),
     }
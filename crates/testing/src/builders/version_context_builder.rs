@@ -7,6 +7,7 @@ use crate::builders::{
 use release_regent_core::{
     traits::version_calculator::VersionContext, versioning::SemanticVersion,
 };
+use std::collections::HashMap;
 
 /// Builder for creating test version context data
 #[derive(Debug, Clone)]
@@ -17,6 +18,7 @@ pub struct VersionContextBuilder {
     target_branch: String,
     base_ref: Option<String>,
     head_ref: String,
+    package_versions: HashMap<String, SemanticVersion>,
 }
 
 impl VersionContextBuilder {
@@ -36,6 +38,7 @@ impl VersionContextBuilder {
             target_branch: "main".to_string(),
             base_ref: Some("v1.0.0".to_string()),
             head_ref: "HEAD".to_string(),
+            package_versions: HashMap::new(),
         }
     }
 
@@ -138,6 +141,13 @@ impl VersionContextBuilder {
         self
     }
 
+    /// Set the current version of a monorepo package
+    #[must_use]
+    pub fn with_package_version(mut self, package: &str, version: SemanticVersion) -> Self {
+        self.package_versions.insert(package.to_string(), version);
+        self
+    }
+
     /// Set version range for analysis
     #[must_use]
     pub fn with_version_range(mut self, from_version: &str, to_ref: &str) -> Self {
@@ -186,6 +196,7 @@ impl TestDataBuilder<VersionContext> for VersionContextBuilder {
             target_branch: self.target_branch,
             base_ref: self.base_ref,
             head_ref: self.head_ref,
+            package_versions: self.package_versions,
         }
     }
 
@@ -189,7 +189,10 @@ impl RepositoryResponseBuilder {
             "topics".to_string(),
             json!(["rust", "automation", "releases"]),
         );
-        result.insert("visibility".to_string(), json!("public"));
+        result.insert(
+            "visibility".to_string(),
+            json!(if self.private { "private" } else { "public" }),
+        );
         result.insert("forks".to_string(), json!(5));
         result.insert("open_issues".to_string(), json!(2));
         result.insert("watchers".to_string(), json!(42));
@@ -1,10 +1,14 @@
 //! Test fixtures for webhooks and API responses
 //!
 //! This module provides pre-built test data for common scenarios
-//! including webhook payloads and GitHub API responses.
+//! including webhook payloads and GitHub API responses. Captured payloads
+//! can be added from a directory of JSON files with
+//! [`FixtureProvider::load_from_dir`].
 
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::path::{Path, PathBuf};
 
 pub mod github_api_fixtures;
 pub mod webhook_fixtures;
@@ -12,6 +16,19 @@ pub mod webhook_fixtures;
 pub use github_api_fixtures::*;
 pub use webhook_fixtures::*;
 
+#[cfg(test)]
+mod tests;
+
+/// What to do when a fixture loaded from disk has the same name as one that
+/// is already present
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixtureConflict {
+    /// Fail without loading any fixture from the directory
+    Error,
+    /// Replace the existing fixture with the loaded one
+    Replace,
+}
+
 /// Common fixture data provider
 #[derive(Debug, Clone)]
 pub struct FixtureProvider {
@@ -171,6 +188,132 @@ impl FixtureProvider {
     pub fn add_fixture(&mut self, name: &str, data: Value) {
         self.fixtures.insert(name.to_string(), data);
     }
+
+    /// Load every `*.json` file below a directory as a fixture
+    ///
+    /// Fixture names are the file paths relative to `dir`, without the
+    /// extension and with `.` as separator, so `webhook/push/simple.json`
+    /// becomes `webhook.push.simple`. A name that is already present,
+    /// including a built-in fixture, is an error; use
+    /// [`load_from_dir_with`](Self::load_from_dir_with) to replace instead.
+    ///
+    /// # Parameters
+    /// - `dir`: Directory to load fixtures from, searched recursively
+    ///
+    /// # Returns
+    /// Number of fixtures loaded
+    ///
+    /// # Errors
+    /// See [`load_from_dir_with`](Self::load_from_dir_with).
+    pub fn load_from_dir(&mut self, dir: impl AsRef<Path>) -> io::Result<usize> {
+        self.load_from_dir_with(dir, FixtureConflict::Error)
+    }
+
+    /// Load every `*.json` file below a directory as a fixture, resolving
+    /// name conflicts according to `on_conflict`
+    ///
+    /// Nothing is loaded unless every file can be read and parsed, and
+    /// conflicts are resolved in path order, so the result never depends on
+    /// the order the file system lists entries in.
+    ///
+    /// # Parameters
+    /// - `dir`: Directory to load fixtures from, searched recursively
+    /// - `on_conflict`: What to do when a fixture name is already taken
+    ///
+    /// # Returns
+    /// Number of fixtures loaded
+    ///
+    /// # Errors
+    /// - `io::ErrorKind::InvalidData` — a file is not valid JSON or its path is not valid UTF-8
+    /// - `io::ErrorKind::AlreadyExists` — a name is already taken and `on_conflict` is
+    ///   [`FixtureConflict::Error`]
+    /// - Any other error from reading the directory or a file
+    ///
+    /// Every error message names the offending file.
+    pub fn load_from_dir_with(
+        &mut self,
+        dir: impl AsRef<Path>,
+        on_conflict: FixtureConflict,
+    ) -> io::Result<usize> {
+        let dir = dir.as_ref();
+        let mut files = Vec::new();
+        collect_json_files(dir, &mut files)?;
+        files.sort();
+
+        let mut loaded: BTreeMap<String, (PathBuf, Value)> = BTreeMap::new();
+        for path in files {
+            let name = fixture_name(dir, &path)?;
+            let content = std::fs::read_to_string(&path)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+            let value: Value = serde_json::from_str(&content).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: invalid JSON: {e}", path.display()),
+                )
+            })?;
+
+            if on_conflict == FixtureConflict::Error {
+                if let Some((other, _)) = loaded.get(&name) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::AlreadyExists,
+                        format!(
+                            "{}: fixture '{name}' is also defined by {}",
+                            path.display(),
+                            other.display()
+                        ),
+                    ));
+                }
+                if self.fixtures.contains_key(&name) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::AlreadyExists,
+                        format!("{}: fixture '{name}' already exists", path.display()),
+                    ));
+                }
+            }
+            loaded.insert(name, (path, value));
+        }
+
+        let count = loaded.len();
+        for (name, (_, value)) in loaded {
+            self.fixtures.insert(name, value);
+        }
+        Ok(count)
+    }
+}
+
+/// Recursively collect the `*.json` files below `dir`.
+fn collect_json_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", dir.display())))?;
+    for entry in entries {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_json_files(&path, files)?;
+        } else if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+        {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Fixture name for `path`: its path relative to `dir`, without the
+/// extension, with components joined by `.`.
+fn fixture_name(dir: &Path, path: &Path) -> io::Result<String> {
+    let invalid = || {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: path is not valid UTF-8", path.display()),
+        )
+    };
+    let relative = path.strip_prefix(dir).unwrap_or(path).with_extension("");
+    let components = relative
+        .components()
+        .map(|c| c.as_os_str().to_str().ok_or_else(invalid))
+        .collect::<io::Result<Vec<_>>>()?;
+    Ok(components.join("."))
 }
 
 impl Default for FixtureProvider {
@@ -0,0 +1,125 @@
+//! Tests for loading fixtures from disk.
+
+use super::*;
+use serde_json::json;
+use tempfile::TempDir;
+
+fn write(dir: &Path, relative: &str, content: &str) {
+    let path = dir.join(relative);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+fn empty_provider() -> FixtureProvider {
+    let mut provider = FixtureProvider::new();
+    provider.clear();
+    provider
+}
+
+#[test]
+fn test_load_from_dir_names_fixtures_by_relative_path() {
+    let dir = TempDir::new().unwrap();
+    write(
+        dir.path(),
+        "webhook/push/captured.json",
+        r#"{"ref": "refs/heads/main"}"#,
+    );
+    write(dir.path(), "api/tags.json", r#"[{"name": "v1.0.0"}]"#);
+    write(dir.path(), "notes.txt", "not a fixture");
+
+    let mut provider = empty_provider();
+    let loaded = provider.load_from_dir(dir.path()).unwrap();
+
+    assert_eq!(loaded, 2);
+    assert_eq!(
+        provider.get_webhook_fixture("push", "captured"),
+        Some(&json!({"ref": "refs/heads/main"}))
+    );
+    assert_eq!(
+        provider.get_fixture("api.tags"),
+        Some(&json!([{"name": "v1.0.0"}]))
+    );
+    assert_eq!(provider.list_fixtures().len(), 2);
+}
+
+#[test]
+fn test_load_from_dir_malformed_json_names_the_file() {
+    let dir = TempDir::new().unwrap();
+    write(dir.path(), "webhook/good.json", "{}");
+    write(dir.path(), "webhook/broken.json", r#"{"action": "#);
+
+    let mut provider = empty_provider();
+    let err = provider.load_from_dir(dir.path()).unwrap_err();
+
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert!(err.to_string().contains("broken.json"), "got: {err}");
+    // Nothing is loaded when any file fails.
+    assert!(provider.list_fixtures().is_empty());
+}
+
+#[test]
+fn test_load_from_dir_collision_with_builtin_is_an_error() {
+    let dir = TempDir::new().unwrap();
+    write(
+        dir.path(),
+        "webhook/push/simple.json",
+        r#"{"captured": true}"#,
+    );
+
+    let mut provider = FixtureProvider::new();
+    let builtin = provider.get_fixture("webhook.push.simple").cloned();
+    let err = provider.load_from_dir(dir.path()).unwrap_err();
+
+    assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+    assert!(
+        err.to_string().contains("webhook.push.simple"),
+        "got: {err}"
+    );
+    assert_eq!(
+        provider.get_fixture("webhook.push.simple").cloned(),
+        builtin
+    );
+}
+
+#[test]
+fn test_load_from_dir_with_replace_overrides_builtin() {
+    let dir = TempDir::new().unwrap();
+    write(
+        dir.path(),
+        "webhook/push/simple.json",
+        r#"{"captured": true}"#,
+    );
+
+    let mut provider = FixtureProvider::new();
+    let before = provider.list_fixtures().len();
+    provider
+        .load_from_dir_with(dir.path(), FixtureConflict::Replace)
+        .unwrap();
+
+    assert_eq!(
+        provider.get_fixture("webhook.push.simple"),
+        Some(&json!({"captured": true}))
+    );
+    assert_eq!(provider.list_fixtures().len(), before);
+}
+
+#[test]
+fn test_load_from_dir_collision_within_directory() {
+    let dir = TempDir::new().unwrap();
+    write(dir.path(), "webhook/push.json", r#"{"from": "nested"}"#);
+    write(dir.path(), "webhook.push.json", r#"{"from": "flat"}"#);
+
+    let mut provider = empty_provider();
+    let err = provider.load_from_dir(dir.path()).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+
+    // With Replace, the path that sorts last wins every time: `webhook`
+    // sorts before `webhook.push.json`.
+    provider
+        .load_from_dir_with(dir.path(), FixtureConflict::Replace)
+        .unwrap();
+    assert_eq!(
+        provider.get_fixture("webhook.push"),
+        Some(&json!({"from": "flat"}))
+    );
+}
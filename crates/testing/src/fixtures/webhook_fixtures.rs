@@ -505,6 +505,22 @@ impl PullRequestEventBuilder {
         self
     }
 
+    /// Create as reopened PR
+    #[must_use]
+    pub fn as_reopened(mut self) -> Self {
+        self.action = "reopened".to_string();
+        self.state = "open".to_string();
+        self
+    }
+
+    /// Create as draft PR marked ready for review
+    #[must_use]
+    pub fn as_ready_for_review(mut self) -> Self {
+        self.action = "ready_for_review".to_string();
+        self.draft = false;
+        self
+    }
+
     /// Build the webhook payload
     #[must_use]
     #[allow(clippy::too_many_lines)] // complex JSON construction requires many fields
@@ -138,6 +138,7 @@ mod mock_tests {
             target_branch: "main".to_string(),
             base_ref: Some("v1.1.0".to_string()),
             head_ref: "HEAD".to_string(),
+            package_versions: HashMap::new(),
         };
 
         let strategy = VersioningStrategy::ConventionalCommits {
@@ -194,6 +195,35 @@ mod builder_tests {
         assert!(!commit.sha.is_empty());
     }
 
+    #[test]
+    fn test_commit_builder_footers_parse_as_breaking_change() {
+        let commit = CommitBuilder::new()
+            .with_conventional("feat(api)", "drop v1 endpoints")
+            .with_breaking_change("the v1 endpoints are removed")
+            .with_footer("Closes", "#123")
+            .build();
+
+        assert_eq!(
+            commit.message,
+            "feat(api): drop v1 endpoints\n\n\
+             BREAKING CHANGE: the v1 endpoints are removed\n\
+             Closes #123"
+        );
+        assert_eq!(commit.subject, "feat(api): drop v1 endpoints");
+
+        let parsed = release_regent_core::versioning::VersionCalculator::parse_conventional_commits(
+            &[(commit.sha.clone(), commit.message.clone())],
+        );
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].commit_type, "feat");
+        assert_eq!(parsed[0].scope.as_deref(), Some("api"));
+        assert_eq!(parsed[0].description, "drop v1 endpoints");
+        assert!(
+            parsed[0].breaking_change,
+            "footer should mark the commit as breaking"
+        );
+    }
+
     #[test]
     fn test_repository_builder_creates_valid_repository() {
         // Test that RepositoryBuilder can create a valid repository
@@ -337,6 +367,74 @@ mod assertion_tests {
         assert!(assertion.passed());
     }
 
+    #[test]
+    fn test_spec_assertion_mismatch_fails_with_reason() {
+        let mut assertion =
+            SpecAssertion::new("subject", "spec", "1.3.0").with_actual_behavior("1.2.4");
+
+        assert!(!assertion.evaluate());
+        assert!(!assertion.passed());
+        let reason = assertion.failure_reason().unwrap();
+        assert!(
+            reason.contains("1.3.0") && reason.contains("1.2.4"),
+            "got: {reason}"
+        );
+    }
+
+    #[test]
+    fn test_spec_assertion_without_actual_behavior_fails() {
+        let mut assertion = SpecAssertion::new("subject", "spec", "anything");
+
+        assert!(!assertion.evaluate());
+        assert_eq!(
+            assertion.failure_reason(),
+            Some("no actual behavior was recorded")
+        );
+    }
+
+    #[test]
+    fn test_spec_assertion_with_matcher() {
+        let case_insensitive = |expected: &str, actual: &str| expected.eq_ignore_ascii_case(actual);
+
+        let mut matching = SpecAssertion::new("subject", "spec", "Minor Bump")
+            .with_actual_behavior("minor bump")
+            .with_matcher(case_insensitive);
+        assert!(matching.evaluate());
+        assert!(matching.failure_reason().is_none());
+
+        let mut mismatching = SpecAssertion::new("subject", "spec", "Minor Bump")
+            .with_actual_behavior("major bump")
+            .with_matcher(case_insensitive);
+        assert!(!mismatching.evaluate());
+        assert!(mismatching
+            .failure_reason()
+            .unwrap()
+            .contains("matcher rejected"));
+    }
+
+    #[test]
+    fn test_spec_assertion_reevaluation_clears_failure_reason() {
+        let mut assertion = SpecAssertion::new("subject", "spec", "done");
+        assert!(!assertion.evaluate());
+
+        assertion = assertion.with_actual_behavior("done");
+        assert!(assertion.evaluate());
+        assert!(assertion.failure_reason().is_none());
+    }
+
+    #[test]
+    fn test_spec_runner_counts_failed_evaluations() {
+        let result = crate::assertions::spec_runner::SpecRunner::new("suite")
+            .with_assertion(SpecAssertion::new("a", "spec", "x").with_actual_behavior("x"))
+            .with_assertion(SpecAssertion::new("b", "spec", "x").with_actual_behavior("y"))
+            .with_assertion(SpecAssertion::new("c", "spec", "x"))
+            .run();
+
+        assert_eq!(result.passed_assertions, 1);
+        assert_eq!(result.failed_assertions, 2);
+        assert!(!result.success);
+    }
+
     #[test]
     fn test_spec_test_result_aggregation() {
         // Test that SpecTestResult can aggregate multiple assertions
@@ -429,6 +527,7 @@ mod integration_tests {
             target_branch: "main".to_string(),
             base_ref: Some("v1.0.0".to_string()),
             head_ref: "HEAD".to_string(),
+            package_versions: HashMap::new(),
         };
 
         let version_result = version_mock
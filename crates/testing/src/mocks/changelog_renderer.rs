@@ -0,0 +1,131 @@
+//! Mock implementation of the [`ChangelogRenderer`] trait.
+//!
+//! Returns fixed changelog text and records every render request so tests can
+//! verify which commits the processor handed to the renderer.
+//!
+//! # Usage
+//!
+//! ```rust
+//! use release_regent_testing::mocks::changelog_renderer::MockChangelogRenderer;
+//!
+//! let mock = MockChangelogRenderer::new().with_output("## Changes");
+//! let processor_renderer = Box::new(mock.clone());
+//! # let _ = processor_renderer;
+//! ```
+
+use release_regent_core::{
+    traits::changelog_renderer::{ChangelogContext, ChangelogRenderer},
+    versioning::ConventionalCommit,
+    CoreError, CoreResult,
+};
+use std::sync::{Arc, Mutex};
+
+/// A render request received by [`MockChangelogRenderer`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderCall {
+    /// Repository owner from the context
+    pub owner: String,
+    /// Repository name from the context
+    pub repo: String,
+    /// SHAs of the commits to render, in the order received
+    pub commit_shas: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+struct MockChangelogRendererState {
+    /// Requests received by `generate`, in call order.
+    calls: Vec<RenderCall>,
+    /// Error returned by the next `generate` call.
+    next_error: Option<CoreError>,
+}
+
+/// Deterministic [`ChangelogRenderer`] for tests.
+///
+/// Every call returns the configured output (`"Mock changelog"` by default)
+/// unless an error was injected with
+/// [`inject_next_error`](MockChangelogRenderer::inject_next_error).
+/// Clones share their recorded calls, so a clone can be boxed into the
+/// processor while the original is kept for assertions.
+#[derive(Debug, Clone)]
+pub struct MockChangelogRenderer {
+    output: String,
+    state: Arc<Mutex<MockChangelogRendererState>>,
+}
+
+impl MockChangelogRenderer {
+    /// Create a mock that renders `"Mock changelog"`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            output: "Mock changelog".to_string(),
+            state: Arc::new(Mutex::new(MockChangelogRendererState::default())),
+        }
+    }
+
+    /// Render `output` for every request.
+    #[must_use]
+    pub fn with_output(mut self, output: impl Into<String>) -> Self {
+        self.output = output.into();
+        self
+    }
+
+    /// Inject an error that will be returned by the **next** `generate` call.
+    ///
+    /// # Panics
+    /// Panics if the internal mutex is poisoned (indicates a prior thread panic).
+    #[allow(clippy::expect_used)] // mutex poison means a prior thread panicked; re-panic is correct
+    pub fn inject_next_error(&self, error: CoreError) {
+        self.state
+            .lock()
+            .expect("renderer state mutex should not be poisoned")
+            .next_error = Some(error);
+    }
+
+    /// Returns the render requests received so far, in call order.
+    ///
+    /// # Panics
+    /// Panics if the internal mutex is poisoned (indicates a prior thread panic).
+    #[allow(clippy::expect_used)] // mutex poison means a prior thread panicked; re-panic is correct
+    #[must_use]
+    pub fn calls(&self) -> Vec<RenderCall> {
+        self.state
+            .lock()
+            .expect("renderer state mutex should not be poisoned")
+            .calls
+            .clone()
+    }
+}
+
+impl Default for MockChangelogRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChangelogRenderer for MockChangelogRenderer {
+    /// Record the request and return the configured output or injected error.
+    #[allow(clippy::expect_used)] // mutex poison means a prior thread panicked; re-panic is correct
+    fn generate(
+        &self,
+        commits: &[ConventionalCommit],
+        context: &ChangelogContext<'_>,
+    ) -> CoreResult<String> {
+        let mut state = self
+            .state
+            .lock()
+            .expect("renderer state mutex should not be poisoned");
+        state.calls.push(RenderCall {
+            owner: context.owner.to_string(),
+            repo: context.repo.to_string(),
+            commit_shas: commits.iter().map(|c| c.sha.clone()).collect(),
+        });
+        match state.next_error.take() {
+            Some(error) => Err(error),
+            None => Ok(self.output.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "changelog_renderer_tests.rs"]
+mod tests;
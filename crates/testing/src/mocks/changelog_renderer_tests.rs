@@ -0,0 +1,61 @@
+//! Self-tests for [`MockChangelogRenderer`].
+
+use super::*;
+use release_regent_core::changelog::ChangelogConfig;
+
+fn commit(sha: &str) -> ConventionalCommit {
+    ConventionalCommit {
+        commit_type: "feat".to_string(),
+        scope: None,
+        description: "add thing".to_string(),
+        breaking_change: false,
+        message: "feat: add thing".to_string(),
+        body: None,
+        sha: sha.to_string(),
+        author: None,
+    }
+}
+
+#[test]
+fn test_generate_returns_configured_output_and_records_call() {
+    let mock = MockChangelogRenderer::new().with_output("## Changes");
+    let config = ChangelogConfig::default();
+    let context = ChangelogContext {
+        owner: "acme",
+        repo: "app",
+        config: &config,
+        initial_release: false,
+    };
+
+    let output = mock
+        .generate(&[commit("abc"), commit("def")], &context)
+        .unwrap();
+
+    assert_eq!(output, "## Changes");
+    assert_eq!(
+        mock.calls(),
+        vec![RenderCall {
+            owner: "acme".to_string(),
+            repo: "app".to_string(),
+            commit_shas: vec!["abc".to_string(), "def".to_string()],
+        }]
+    );
+}
+
+#[test]
+fn test_injected_error_is_returned_once() {
+    let mock = MockChangelogRenderer::new();
+    let clone = mock.clone();
+    mock.inject_next_error(CoreError::changelog_generation("boom"));
+    let config = ChangelogConfig::default();
+    let context = ChangelogContext {
+        owner: "acme",
+        repo: "app",
+        config: &config,
+        initial_release: false,
+    };
+
+    assert!(clone.generate(&[], &context).is_err());
+    assert_eq!(clone.generate(&[], &context).unwrap(), "Mock changelog");
+    assert_eq!(mock.calls().len(), 2, "clones share recorded calls");
+}
@@ -0,0 +1,67 @@
+//! Mock implementation of the [`Clock`] trait.
+//!
+//! [`MockClock`] reports a time set by the test and only moves when the test
+//! moves it, so date-based versions and token expiry can be tested without
+//! sleeping or racing the system clock.
+//!
+//! # Usage
+//!
+//! ```rust
+//! use chrono::{Duration, TimeZone, Utc};
+//! use release_regent_core::clock::Clock;
+//! use release_regent_testing::mocks::clock::MockClock;
+//!
+//! let clock = MockClock::new(Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap());
+//! clock.advance(Duration::hours(1));
+//!
+//! assert_eq!(clock.now(), Utc.with_ymd_and_hms(2024, 5, 1, 13, 0, 0).unwrap());
+//! ```
+
+use chrono::{DateTime, Duration, Utc};
+use release_regent_core::clock::Clock;
+use std::sync::{Arc, Mutex};
+
+/// A [`Clock`] whose time is controlled by the test.
+///
+/// Clones share the same time, so a test can keep one clone and hand another
+/// to the component under test.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl MockClock {
+    /// Create a clock that reports `now` until it is moved.
+    #[must_use]
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    /// Move the clock forward by `duration`. A negative duration moves it back.
+    pub fn advance(&self, duration: Duration) {
+        *self.lock() += duration;
+    }
+
+    /// Set the clock to `now`.
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.lock() = now;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, DateTime<Utc>> {
+        self.now
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.lock()
+    }
+}
+
+#[cfg(test)]
+#[path = "clock_tests.rs"]
+mod tests;
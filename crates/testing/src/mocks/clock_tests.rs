@@ -0,0 +1,35 @@
+use super::*;
+use chrono::TimeZone;
+
+fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+}
+
+#[test]
+fn test_mock_clock_stands_still_until_moved() {
+    let clock = MockClock::new(start());
+
+    assert_eq!(clock.now(), start());
+    assert_eq!(clock.now(), start());
+}
+
+#[test]
+fn test_mock_clock_advance_and_set() {
+    let clock = MockClock::new(start());
+
+    clock.advance(Duration::minutes(90));
+    assert_eq!(clock.now(), start() + Duration::minutes(90));
+
+    clock.set(start() - Duration::days(1));
+    assert_eq!(clock.now(), start() - Duration::days(1));
+}
+
+#[test]
+fn test_mock_clock_clones_share_the_time() {
+    let clock = MockClock::new(start());
+    let shared: Arc<dyn Clock> = Arc::new(clock.clone());
+
+    clock.advance(Duration::seconds(30));
+
+    assert_eq!(shared.now(), start() + Duration::seconds(30));
+}
@@ -183,13 +183,13 @@ impl MockConfigurationProvider {
     }
 
     /// Simulate latency if configured
-    async fn simulate_latency(&self) {
-        self.state.read().await.simulate_latency().await;
+    async fn simulate_latency(&self, method: &str) {
+        self.state.read().await.simulate_latency(method).await;
     }
 
     /// Check if should simulate failure
-    async fn should_simulate_failure(&self) -> bool {
-        self.state.read().await.should_simulate_failure()
+    async fn should_simulate_failure(&self, method: &str) -> bool {
+        self.state.read().await.should_simulate_failure(method)
     }
 
     /// Create a default validation result
@@ -236,10 +236,10 @@ impl ConfigurationProvider for MockConfigurationProvider {
 
         // Check quota and simulate latency
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
         // Simulate failure if configured
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::config("Simulated global configuration loading error");
             self.record_call(method, &params, CallResult::Error(error.to_string()))
                 .await;
@@ -277,10 +277,10 @@ impl ConfigurationProvider for MockConfigurationProvider {
 
         // Check quota and simulate latency
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
         // Simulate failure if configured
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::config("Simulated repository config error");
             self.record_call(method, &params, CallResult::Error(error.to_string()))
                 .await;
@@ -319,10 +319,10 @@ impl ConfigurationProvider for MockConfigurationProvider {
 
         // Check quota and simulate latency
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
         // Simulate failure if configured
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::config("Simulated merged config error");
             self.record_call(method, &params, CallResult::Error(error.to_string()))
                 .await;
@@ -360,10 +360,10 @@ impl ConfigurationProvider for MockConfigurationProvider {
 
         // Check quota and simulate latency
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
         // Simulate failure if configured
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::config("Simulated validation error");
             self.record_call(method, &params, CallResult::Error(error.to_string()))
                 .await;
@@ -402,7 +402,7 @@ impl ConfigurationProvider for MockConfigurationProvider {
 
         // Check quota and simulate latency
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
         // Mock implementation doesn't support saving
         let error =
@@ -433,10 +433,10 @@ impl ConfigurationProvider for MockConfigurationProvider {
 
         // Check quota and simulate latency
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
         // Simulate failure if configured
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::config("Simulated list repository configs error");
             self.record_call(method, &params, CallResult::Error(error.to_string()))
                 .await;
@@ -471,10 +471,10 @@ impl ConfigurationProvider for MockConfigurationProvider {
 
         // Check quota and simulate latency
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
         // Simulate failure if configured
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::config("Simulated source not found error");
             self.record_call(method, &params, CallResult::Error(error.to_string()))
                 .await;
@@ -516,10 +516,10 @@ impl ConfigurationProvider for MockConfigurationProvider {
 
         // Check quota and simulate latency
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
         // Simulate failure if configured
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::config("Simulated reload error");
             self.record_call(method, &params, CallResult::Error(error.to_string()))
                 .await;
@@ -549,10 +549,10 @@ impl ConfigurationProvider for MockConfigurationProvider {
 
         // Check quota and simulate latency
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
         // Simulate failure if configured
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::config("Simulated config exists check error");
             self.record_call(method, &params, CallResult::Error(error.to_string()))
                 .await;
@@ -596,10 +596,10 @@ impl ConfigurationProvider for MockConfigurationProvider {
 
         // Check quota and simulate latency
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
         // Simulate failure if configured
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::config("Simulated default config error");
             self.record_call(method, &params, CallResult::Error(error.to_string()))
                 .await;
@@ -3,16 +3,18 @@
 //! Provides a comprehensive mock implementation that supports all GitHub API
 //! operations required by Release Regent without making actual API calls.
 
-use crate::mocks::{CallResult, MockConfig, MockState, SharedMockState};
+use crate::mocks::{CallResult, MethodBehavior, MockConfig, MockState, SharedMockState};
 use async_trait::async_trait;
 use release_regent_core::{
     traits::{
         git_operations::{
             GetCommitsOptions, GitCommit, GitRepository, GitTag, GitTagType, ListTagsOptions,
+            RepositoryVisibility,
         },
         github_operations::{
-            CollaboratorPermission, CreatePullRequestParams, CreateReleaseParams, IssueComment,
-            Label, PullRequest, PullRequestBranch, Release, Repository, Tag, UpdateReleaseParams,
+            ref_candidates, BranchUpsert, CollaboratorPermission, CreateCommitStatusParams,
+            CreatePullRequestParams, CreateReleaseParams, IssueComment, Label, LabelMode,
+            PullRequest, PullRequestBranch, Release, Repository, Tag, UpdateReleaseParams,
         },
     },
     CoreError, CoreResult, GitHubOperations, GitOperations,
@@ -31,6 +33,10 @@ type BatchCommitFilesCalls = Arc<RwLock<Vec<(String, String, String, Vec<String>
 type BatchCommitFilesRebasedCalls =
     Arc<RwLock<Vec<(String, String, String, Vec<String>, String, String)>>>;
 type UpsertFileCalls = Arc<RwLock<Vec<(String, String, String, String, String, String)>>>;
+type UpdateIssueCommentCalls = Arc<RwLock<Vec<(String, String, u64, String)>>>;
+type UploadReleaseAssetCalls =
+    Arc<RwLock<Vec<(String, String, u64, String, Option<String>, Vec<u8>)>>>;
+type CommitStatusCalls = Arc<RwLock<Vec<(String, String, String, CreateCommitStatusParams)>>>;
 
 /// Mock implementation of `GitHubOperations` trait
 ///
@@ -64,6 +70,8 @@ pub struct MockGitHubOperations {
     commits: HashMap<String, Vec<GitCommit>>,
     /// Pre-configured pull request data
     pull_requests: HashMap<String, Vec<PullRequest>>,
+    /// Pre-configured pull request commits, keyed `"owner/repo/pr_number"`
+    pull_request_commits: HashMap<String, Vec<GitCommit>>,
     /// Pre-configured tag data
     tags: HashMap<String, Vec<Tag>>,
     /// Pre-configured release data
@@ -74,6 +82,12 @@ pub struct MockGitHubOperations {
     /// A name already present causes `create_branch` to return
     /// `CoreError::Conflict`, matching real GitHub 422 behaviour.
     branches: HashMap<String, Vec<String>>,
+    /// Existing branch names (keyed `owner/repo`) whose tip holds commits of
+    /// its own, so `upsert_branch` rejects them as not a fast-forward.
+    diverged_branches: HashMap<String, Vec<String>>,
+    /// Branch head commits (keyed `owner/repo` → branch → SHA) returned by
+    /// `resolve_ref`.
+    branch_heads: HashMap<String, HashMap<String, String>>,
     /// Labels keyed `"owner/repo/issue_number"`.  Used by `list_pr_labels`,
     /// `add_labels` (appends), and `remove_label` (removes by name).
     /// The `search_pull_requests` label-filter also reads from this map.
@@ -108,7 +122,15 @@ pub struct MockGitHubOperations {
     /// Recorded `list_issue_comments` calls: (owner, repo, issue_number).
     list_issue_comments_calls: Arc<RwLock<Vec<(String, String, u64)>>>,
     /// Recorded `update_issue_comment` calls: (owner, repo, comment_id, body).
-    update_issue_comment_calls: Arc<RwLock<Vec<(String, String, u64, String)>>>,
+    update_issue_comment_calls: UpdateIssueCommentCalls,
+    /// Recorded successful `upload_release_asset` calls:
+    /// (owner, repo, release_id, name, content_type, data).
+    ///
+    /// Also used to reject a second upload of the same asset name to the same
+    /// release with `CoreError::Conflict`, matching GitHub's 422 response.
+    upload_release_asset_calls: UploadReleaseAssetCalls,
+    /// Recorded successful `create_commit_status` calls: (owner, repo, sha, params).
+    commit_status_calls: CommitStatusCalls,
 }
 
 impl MockGitHubOperations {
@@ -152,9 +174,12 @@ impl MockGitHubOperations {
             repositories: HashMap::new(),
             commits: HashMap::new(),
             pull_requests: HashMap::new(),
+            pull_request_commits: HashMap::new(),
             tags: HashMap::new(),
             releases: HashMap::new(),
             branches: HashMap::new(),
+            diverged_branches: HashMap::new(),
+            branch_heads: HashMap::new(),
             pr_labels: Arc::new(RwLock::new(HashMap::new())),
             collaborator_permission: None,
             method_errors: HashMap::new(),
@@ -166,6 +191,8 @@ impl MockGitHubOperations {
             issue_comments: Arc::new(RwLock::new(HashMap::new())),
             list_issue_comments_calls: Arc::new(RwLock::new(Vec::new())),
             update_issue_comment_calls: Arc::new(RwLock::new(Vec::new())),
+            upload_release_asset_calls: Arc::new(RwLock::new(Vec::new())),
+            commit_status_calls: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
@@ -230,13 +257,13 @@ impl MockGitHubOperations {
     }
 
     /// Check if should simulate failure
-    async fn should_simulate_failure(&self) -> bool {
-        self.state.read().await.should_simulate_failure()
+    async fn should_simulate_failure(&self, method: &str) -> bool {
+        self.state.read().await.should_simulate_failure(method)
     }
 
     /// Simulate latency if configured
-    async fn simulate_latency(&self) {
-        self.state.read().await.simulate_latency().await;
+    async fn simulate_latency(&self, method: &str) {
+        self.state.read().await.simulate_latency(method).await;
     }
 
     /// Configure the mock with `GitCommit` data for a repository
@@ -255,6 +282,30 @@ impl MockGitHubOperations {
         self
     }
 
+    /// Configure the commits returned by `list_pull_request_commits` for a
+    /// pull request
+    ///
+    /// # Parameters
+    /// - `owner`: Repository owner
+    /// - `name`: Repository name
+    /// - `pr_number`: Pull request number
+    /// - `commits`: The pull request's commits, oldest first
+    ///
+    /// # Returns
+    /// Self for method chaining
+    #[must_use]
+    pub fn with_pull_request_commits(
+        mut self,
+        owner: &str,
+        name: &str,
+        pr_number: u64,
+        commits: Vec<GitCommit>,
+    ) -> Self {
+        let key = format!("{owner}/{name}/{pr_number}");
+        self.pull_request_commits.insert(key, commits);
+        self
+    }
+
     /// Create a new mock with custom configuration
     ///
     /// # Parameters
@@ -271,9 +322,12 @@ impl MockGitHubOperations {
             repositories: HashMap::new(),
             commits: HashMap::new(),
             pull_requests: HashMap::new(),
+            pull_request_commits: HashMap::new(),
             tags: HashMap::new(),
             releases: HashMap::new(),
             branches: HashMap::new(),
+            diverged_branches: HashMap::new(),
+            branch_heads: HashMap::new(),
             pr_labels: Arc::new(RwLock::new(HashMap::new())),
             collaborator_permission: None,
             method_errors: HashMap::new(),
@@ -285,6 +339,8 @@ impl MockGitHubOperations {
             issue_comments: Arc::new(RwLock::new(HashMap::new())),
             list_issue_comments_calls: Arc::new(RwLock::new(Vec::new())),
             update_issue_comment_calls: Arc::new(RwLock::new(Vec::new())),
+            upload_release_asset_calls: Arc::new(RwLock::new(Vec::new())),
+            commit_status_calls: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
@@ -419,6 +475,53 @@ impl MockGitHubOperations {
         self
     }
 
+    /// Mark existing branches of a repository as diverged.
+    ///
+    /// `upsert_branch` answers `CoreError::NotFastForward` for these names,
+    /// mirroring GitHub's HTTP 422 "Update is not a fast forward" response.
+    ///
+    /// # Parameters
+    /// - `owner`: Repository owner
+    /// - `name`: Repository name
+    /// - `branches`: List of diverged branch names
+    ///
+    /// # Returns
+    /// Self for method chaining
+    #[must_use]
+    pub fn with_diverged_branches(
+        mut self,
+        owner: &str,
+        name: &str,
+        branches: Vec<String>,
+    ) -> Self {
+        let key = format!("{owner}/{name}");
+        self.diverged_branches.insert(key, branches);
+        self
+    }
+
+    /// Set the commit a branch points to, as returned by `resolve_ref`.
+    ///
+    /// Tags resolve to the `commit_sha` of the tags set with
+    /// [`with_tags`](Self::with_tags).
+    ///
+    /// # Parameters
+    /// - `owner`: Repository owner
+    /// - `name`: Repository name
+    /// - `branch`: Branch name, without `refs/heads/`
+    /// - `sha`: Commit SHA the branch points to
+    ///
+    /// # Returns
+    /// Self for method chaining
+    #[must_use]
+    pub fn with_branch_head(mut self, owner: &str, name: &str, branch: &str, sha: &str) -> Self {
+        let key = format!("{owner}/{name}");
+        self.branch_heads
+            .entry(key)
+            .or_default()
+            .insert(branch.to_string(), sha.to_string());
+        self
+    }
+
     /// Pre-populate the mock with labels for a specific PR/issue number.
     ///
     /// These labels are returned by `list_pr_labels` and are also used by
@@ -464,6 +567,49 @@ impl MockGitHubOperations {
         self
     }
 
+    /// Delay every call to a specific method by `latency_ms`.
+    ///
+    /// This replaces the global `response_latency_ms` for that method only,
+    /// e.g. to test a slow `get_commits_between` while `get_repository_info`
+    /// stays fast.
+    ///
+    /// # Panics
+    /// Panics if the mock state is locked, which cannot happen while the mock
+    /// is still being built.
+    #[must_use]
+    pub fn with_method_latency(self, method_name: &str, latency_ms: u64) -> Self {
+        self.update_method_behavior(method_name, |b| b.latency_ms = Some(latency_ms));
+        self
+    }
+
+    /// Fail calls to a specific method at `failure_rate`.
+    ///
+    /// This replaces the global failure settings for that method only and
+    /// applies even when `simulate_failures` is off; a rate of `1.0` makes
+    /// every call fail with a simulated network error.
+    ///
+    /// # Panics
+    /// Panics if the mock state is locked, which cannot happen while the mock
+    /// is still being built.
+    #[must_use]
+    pub fn with_method_failure_rate(self, method_name: &str, failure_rate: f64) -> Self {
+        self.update_method_behavior(method_name, |b| b.failure_rate = Some(failure_rate));
+        self
+    }
+
+    fn update_method_behavior(&self, method_name: &str, update: impl FnOnce(&mut MethodBehavior)) {
+        let mut state = self
+            .state
+            .try_write()
+            .expect("mock state must not be locked while configuring the mock");
+        let mut behavior = state
+            .method_behavior(method_name)
+            .cloned()
+            .unwrap_or_default();
+        update(&mut behavior);
+        state.set_method_behavior(method_name, behavior);
+    }
+
     /// Pre-populate the mock with comments for a specific issue/PR.
     ///
     /// These comments are returned by `list_issue_comments`.
@@ -494,6 +640,25 @@ impl MockGitHubOperations {
     pub async fn update_issue_comment_calls(&self) -> Vec<(String, String, u64, String)> {
         self.update_issue_comment_calls.read().await.clone()
     }
+
+    /// Return all recorded successful `upload_release_asset` calls.
+    ///
+    /// Each element is `(owner, repo, release_id, name, content_type, data)`,
+    /// where `content_type` is the value passed by the caller.
+    pub async fn upload_release_asset_calls(
+        &self,
+    ) -> Vec<(String, String, u64, String, Option<String>, Vec<u8>)> {
+        self.upload_release_asset_calls.read().await.clone()
+    }
+
+    /// Return all recorded successful `create_commit_status` calls.
+    ///
+    /// Each element is `(owner, repo, sha, params)`.
+    pub async fn commit_status_calls(
+        &self,
+    ) -> Vec<(String, String, String, CreateCommitStatusParams)> {
+        self.commit_status_calls.read().await.clone()
+    }
 }
 
 impl Default for MockGitHubOperations {
@@ -515,9 +680,12 @@ impl Clone for MockGitHubOperations {
             repositories: self.repositories.clone(),
             commits: self.commits.clone(),
             pull_requests: self.pull_requests.clone(),
+            pull_request_commits: self.pull_request_commits.clone(),
             tags: self.tags.clone(),
             releases: self.releases.clone(),
             branches: self.branches.clone(),
+            diverged_branches: self.diverged_branches.clone(),
+            branch_heads: self.branch_heads.clone(),
             pr_labels: Arc::clone(&self.pr_labels),
             collaborator_permission: self.collaborator_permission.clone(),
             method_errors: self.method_errors.clone(),
@@ -529,6 +697,8 @@ impl Clone for MockGitHubOperations {
             issue_comments: Arc::clone(&self.issue_comments),
             list_issue_comments_calls: Arc::clone(&self.list_issue_comments_calls),
             update_issue_comment_calls: Arc::clone(&self.update_issue_comment_calls),
+            upload_release_asset_calls: Arc::clone(&self.upload_release_asset_calls),
+            commit_status_calls: Arc::clone(&self.commit_status_calls),
         }
     }
 }
@@ -588,9 +758,9 @@ impl GitHubOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, title={}", params.title);
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -655,9 +825,9 @@ impl GitHubOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, tag={}", params.tag_name);
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -702,9 +872,9 @@ impl GitHubOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, tag={tag_name}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -724,14 +894,46 @@ impl GitHubOperations for MockGitHubOperations {
         Ok(tag)
     }
 
+    async fn create_signed_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: release_regent_core::traits::github_operations::SignedTagParams,
+    ) -> CoreResult<Tag> {
+        let method = "create_signed_tag";
+        let params_str = format!("owner={owner}, repo={repo}, tag={}", params.tag_name);
+
+        self.check_quota().await?;
+        self.simulate_latency(method).await;
+
+        if self.should_simulate_failure(method).await {
+            let error = CoreError::network("Simulated GitHub API error");
+            self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                .await;
+            return Err(error);
+        }
+
+        let tag = Tag {
+            name: params.tag_name,
+            commit_sha: params.commit_sha,
+            message: Some(params.message),
+            tagger: Some(params.tagger),
+            created_at: Some(params.tagged_at),
+        };
+
+        self.record_call(method, &params_str, CallResult::Success)
+            .await;
+        Ok(tag)
+    }
+
     async fn get_latest_release(&self, owner: &str, repo: &str) -> CoreResult<Option<Release>> {
         let method = "get_latest_release";
         let params_str = format!("owner={owner}, repo={repo}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -762,9 +964,9 @@ impl GitHubOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, pr={pr_number}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -792,14 +994,19 @@ impl GitHubOperations for MockGitHubOperations {
         Ok(pr)
     }
 
-    async fn get_release_by_tag(&self, owner: &str, repo: &str, tag: &str) -> CoreResult<Release> {
+    async fn get_release_by_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+    ) -> CoreResult<Option<Release>> {
         let method = "get_release_by_tag";
         let params_str = format!("owner={owner}, repo={repo}, tag={tag}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -810,19 +1017,13 @@ impl GitHubOperations for MockGitHubOperations {
         let release = self
             .releases
             .get(&key)
-            .and_then(|releases| releases.iter().find(|r| r.tag_name == tag).cloned());
-
-        let Some(release) = release else {
-            self.record_call(
-                method,
-                &params_str,
-                CallResult::Error(format!("release for tag '{tag}' not found")),
-            )
-            .await;
-            return Err(CoreError::not_found(format!(
-                "release for tag '{tag}' not found"
-            )));
-        };
+            // Like GitHub, the tag lookup never returns draft releases.
+            .and_then(|releases| {
+                releases
+                    .iter()
+                    .find(|r| r.tag_name == tag && !r.draft)
+                    .cloned()
+            });
 
         self.record_call(method, &params_str, CallResult::Success)
             .await;
@@ -833,30 +1034,129 @@ impl GitHubOperations for MockGitHubOperations {
         &self,
         owner: &str,
         repo: &str,
-        _per_page: Option<u8>,
-        _page: Option<u32>,
+        per_page: Option<u8>,
+        page: Option<u32>,
     ) -> CoreResult<Vec<Release>> {
         let method = "list_releases";
-        let params_str = format!("owner={owner}, repo={repo}");
+        let params_str =
+            format!("owner={owner}, repo={repo}, per_page={per_page:?}, page={page:?}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
             return Err(error);
         }
 
+        if per_page.is_some_and(|p| p == 0 || p > 100) || page == Some(0) {
+            let error = CoreError::invalid_input(
+                "per_page",
+                "per_page must be between 1 and 100 and page must be 1-based",
+            );
+            self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                .await;
+            return Err(error);
+        }
+
         let key = format!("{owner}/{repo}");
-        let releases = self.releases.get(&key).cloned().unwrap_or_default();
+        let mut releases = self.releases.get(&key).cloned().unwrap_or_default();
+
+        // Mirror the GitHub client: an explicit page returns only that page;
+        // without one, all releases are returned.
+        if let Some(page) = page {
+            let size = usize::from(per_page.unwrap_or(30));
+            let start = usize::try_from(page - 1)
+                .unwrap_or(usize::MAX)
+                .saturating_mul(size);
+            releases = releases.into_iter().skip(start).take(size).collect();
+        }
 
         self.record_call(method, &params_str, CallResult::Success)
             .await;
         Ok(releases)
     }
 
+    async fn upload_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: u64,
+        name: &str,
+        content_type: Option<&str>,
+        data: Vec<u8>,
+    ) -> CoreResult<String> {
+        let method = "upload_release_asset";
+        let params_str = format!(
+            "owner={owner}, repo={repo}, release_id={release_id}, name={name}, \
+             content_type={content_type:?}, size={}",
+            data.len()
+        );
+
+        self.check_quota().await?;
+        self.simulate_latency(method).await;
+
+        if self.should_simulate_failure(method).await {
+            let error = CoreError::network("Simulated GitHub API error");
+            self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                .await;
+            return Err(error);
+        }
+
+        if let Some(msg) = self.method_errors.get(method) {
+            let error = CoreError::network(msg.clone());
+            self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                .await;
+            return Err(error);
+        }
+
+        if name.trim().is_empty() {
+            let error = CoreError::invalid_input("name", "release asset name must not be empty");
+            self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                .await;
+            return Err(error);
+        }
+
+        let mut calls = self.upload_release_asset_calls.write().await;
+        let duplicate = calls
+            .iter()
+            .any(|(o, r, id, n, _, _)| o == owner && r == repo && *id == release_id && n == name);
+        if duplicate {
+            drop(calls);
+            let error =
+                CoreError::conflict(format!("release asset '{name}' on release {release_id}"));
+            self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                .await;
+            return Err(error);
+        }
+        calls.push((
+            owner.to_string(),
+            repo.to_string(),
+            release_id,
+            name.to_string(),
+            content_type.map(str::to_string),
+            data,
+        ));
+        drop(calls);
+
+        // Use the release's tag when it is known so the URL matches the shape
+        // GitHub returns; otherwise fall back to the numeric release ID.
+        let key = format!("{owner}/{repo}");
+        let tag = self
+            .releases
+            .get(&key)
+            .and_then(|releases| releases.iter().find(|r| r.id == release_id))
+            .map_or_else(|| release_id.to_string(), |r| r.tag_name.clone());
+
+        self.record_call(method, &params_str, CallResult::Success)
+            .await;
+        Ok(format!(
+            "https://github.com/{owner}/{repo}/releases/download/{tag}/{name}"
+        ))
+    }
+
     async fn update_pull_request(
         &self,
         owner: &str,
@@ -870,9 +1170,9 @@ impl GitHubOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, pr={pr_number}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -922,9 +1222,9 @@ impl GitHubOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, id={release_id}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -981,9 +1281,9 @@ impl GitHubOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1027,9 +1327,9 @@ impl GitHubOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, query={query}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1116,9 +1416,9 @@ impl GitHubOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, branch={branch_name}, sha={sha}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1147,14 +1447,48 @@ impl GitHubOperations for MockGitHubOperations {
         Ok(())
     }
 
+    async fn create_commit_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        params: CreateCommitStatusParams,
+    ) -> CoreResult<()> {
+        let method = "create_commit_status";
+        let params_str = format!(
+            "owner={owner}, repo={repo}, sha={sha}, context={}, state={:?}",
+            params.context, params.state
+        );
+
+        self.check_quota().await?;
+        self.simulate_latency(method).await;
+
+        if self.should_simulate_failure(method).await {
+            let error = CoreError::network("Simulated GitHub API error");
+            self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                .await;
+            return Err(error);
+        }
+
+        self.commit_status_calls.write().await.push((
+            owner.to_string(),
+            repo.to_string(),
+            sha.to_string(),
+            params,
+        ));
+        self.record_call(method, &params_str, CallResult::Success)
+            .await;
+        Ok(())
+    }
+
     async fn delete_branch(&self, owner: &str, repo: &str, branch_name: &str) -> CoreResult<()> {
         let method = "delete_branch";
         let params_str = format!("owner={owner}, repo={repo}, branch={branch_name}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1177,9 +1511,9 @@ impl GitHubOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, branch={branch_name}, sha={sha}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1191,6 +1525,51 @@ impl GitHubOperations for MockGitHubOperations {
         Ok(())
     }
 
+    async fn upsert_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch_name: &str,
+        from_sha: &str,
+    ) -> CoreResult<BranchUpsert> {
+        let method = "upsert_branch";
+        let params_str =
+            format!("owner={owner}, repo={repo}, branch={branch_name}, sha={from_sha}");
+
+        self.check_quota().await?;
+        self.simulate_latency(method).await;
+
+        if self.should_simulate_failure(method).await {
+            let error = CoreError::network("Simulated GitHub API error");
+            self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                .await;
+            return Err(error);
+        }
+
+        let key = format!("{owner}/{repo}");
+        let listed = |registry: &HashMap<String, Vec<String>>| {
+            registry
+                .get(&key)
+                .is_some_and(|bs| bs.iter().any(|b| b == branch_name))
+        };
+
+        if listed(&self.diverged_branches) {
+            let err = CoreError::not_fast_forward(branch_name, from_sha);
+            self.record_call(method, &params_str, CallResult::Error(err.to_string()))
+                .await;
+            return Err(err);
+        }
+
+        let outcome = if listed(&self.branches) {
+            BranchUpsert::Updated
+        } else {
+            BranchUpsert::Created
+        };
+        self.record_call(method, &params_str, CallResult::Success)
+            .await;
+        Ok(outcome)
+    }
+
     async fn create_issue_comment(
         &self,
         owner: &str,
@@ -1205,9 +1584,9 @@ impl GitHubOperations for MockGitHubOperations {
         );
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1229,9 +1608,9 @@ impl GitHubOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, username={username}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1265,9 +1644,9 @@ impl GitHubOperations for MockGitHubOperations {
             format!("owner={owner}, repo={repo}, issue={issue_number}, labels={labels:?}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1315,9 +1694,9 @@ impl GitHubOperations for MockGitHubOperations {
             format!("owner={owner}, repo={repo}, issue={issue_number}, label={label_name}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1344,6 +1723,119 @@ impl GitHubOperations for MockGitHubOperations {
         Ok(())
     }
 
+    async fn set_pull_request_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        labels: &[&str],
+        mode: LabelMode,
+    ) -> CoreResult<()> {
+        let method = "set_pull_request_labels";
+        let params_str =
+            format!("owner={owner}, repo={repo}, pr={pr_number}, labels={labels:?}, mode={mode:?}");
+
+        self.check_quota().await?;
+        self.simulate_latency(method).await;
+
+        if self.should_simulate_failure(method).await {
+            let error = CoreError::network("Simulated GitHub API error");
+            self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                .await;
+            return Err(error);
+        }
+
+        if let Some(msg) = self.method_errors.get(method) {
+            let error = CoreError::network(msg.clone());
+            self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                .await;
+            return Err(error);
+        }
+
+        let key = format!("{owner}/{repo}/{pr_number}");
+        {
+            let mut map = self.pr_labels.write().await;
+            let entry = map.entry(key).or_default();
+            if mode == LabelMode::Replace {
+                entry.retain(|l| labels.contains(&l.name.as_str()));
+            }
+            for label_name in labels {
+                if !entry.iter().any(|l| l.name == *label_name) {
+                    entry.push(Label {
+                        id: 0,
+                        name: (*label_name).to_string(),
+                        color: String::new(),
+                        description: None,
+                    });
+                }
+            }
+        }
+
+        self.record_call(method, &params_str, CallResult::Success)
+            .await;
+        Ok(())
+    }
+
+    async fn resolve_ref(&self, owner: &str, repo: &str, git_ref: &str) -> CoreResult<String> {
+        let method = "resolve_ref";
+        let params_str = format!("owner={owner}, repo={repo}, ref={git_ref}");
+
+        self.check_quota().await?;
+        self.simulate_latency(method).await;
+
+        if self.should_simulate_failure(method).await {
+            let error = CoreError::network("Simulated GitHub API error");
+            self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                .await;
+            return Err(error);
+        }
+
+        if let Some(msg) = self.method_errors.get(method) {
+            let error = CoreError::network(msg.clone());
+            self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                .await;
+            return Err(error);
+        }
+
+        let candidates = ref_candidates(git_ref);
+        if candidates.is_empty() {
+            let error = CoreError::invalid_input("git_ref", "ref must not be empty");
+            self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                .await;
+            return Err(error);
+        }
+
+        let key = format!("{owner}/{repo}");
+        let sha = candidates.iter().find_map(|candidate| {
+            if let Some(tag) = candidate.strip_prefix("tags/") {
+                self.tags
+                    .get(&key)
+                    .and_then(|tags| tags.iter().find(|t| t.name == tag))
+                    .map(|t| t.commit_sha.clone())
+            } else {
+                let branch = candidate.strip_prefix("heads/")?;
+                self.branch_heads
+                    .get(&key)
+                    .and_then(|heads| heads.get(branch))
+                    .cloned()
+            }
+        });
+
+        match sha {
+            Some(sha) => {
+                self.record_call(method, &params_str, CallResult::Success)
+                    .await;
+                Ok(sha)
+            }
+            None => {
+                let error = CoreError::not_found(format!("ref '{git_ref}' in {owner}/{repo}"));
+                self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                    .await;
+                Err(error)
+            }
+        }
+    }
+
     async fn list_pr_labels(
         &self,
         owner: &str,
@@ -1354,9 +1846,9 @@ impl GitHubOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, issue={issue_number}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1394,9 +1886,9 @@ impl GitHubOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, issue={issue_number}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1429,6 +1921,48 @@ impl GitHubOperations for MockGitHubOperations {
         Ok(comments)
     }
 
+    async fn list_pull_request_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> CoreResult<Vec<GitCommit>> {
+        let method = "list_pull_request_commits";
+        let params_str = format!("owner={owner}, repo={repo}, pr={pr_number}");
+
+        self.check_quota().await?;
+        self.simulate_latency(method).await;
+
+        if self.should_simulate_failure(method).await {
+            let error = CoreError::network("Simulated GitHub API error");
+            self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                .await;
+            return Err(error);
+        }
+
+        if let Some(msg) = self.method_errors.get(method) {
+            let error = CoreError::network(msg.clone());
+            self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                .await;
+            return Err(error);
+        }
+
+        let key = format!("{owner}/{repo}/{pr_number}");
+        let Some(commits) = self.pull_request_commits.get(&key).cloned() else {
+            self.record_call(
+                method,
+                &params_str,
+                CallResult::Error(format!("PR #{pr_number} not found")),
+            )
+            .await;
+            return Err(CoreError::not_found(format!("PR #{pr_number} not found")));
+        };
+
+        self.record_call(method, &params_str, CallResult::Success)
+            .await;
+        Ok(commits)
+    }
+
     async fn update_issue_comment(
         &self,
         owner: &str,
@@ -1443,9 +1977,9 @@ impl GitHubOperations for MockGitHubOperations {
         );
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1487,9 +2021,12 @@ impl GitHubOperations for MockGitHubOperations {
             repositories: self.repositories.clone(),
             commits: self.commits.clone(),
             pull_requests: self.pull_requests.clone(),
+            pull_request_commits: self.pull_request_commits.clone(),
             tags: self.tags.clone(),
             releases: self.releases.clone(),
             branches: self.branches.clone(),
+            diverged_branches: self.diverged_branches.clone(),
+            branch_heads: self.branch_heads.clone(),
             pr_labels: Arc::clone(&self.pr_labels),
             collaborator_permission: self.collaborator_permission.clone(),
             method_errors: self.method_errors.clone(),
@@ -1501,6 +2038,8 @@ impl GitHubOperations for MockGitHubOperations {
             issue_comments: Arc::clone(&self.issue_comments),
             list_issue_comments_calls: Arc::clone(&self.list_issue_comments_calls),
             update_issue_comment_calls: Arc::clone(&self.update_issue_comment_calls),
+            upload_release_asset_calls: Arc::clone(&self.upload_release_asset_calls),
+            commit_status_calls: Arc::clone(&self.commit_status_calls),
         }
     }
 
@@ -1517,9 +2056,9 @@ impl GitHubOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, path={path}, branch={branch}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1558,9 +2097,9 @@ impl GitHubOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, path={path}, branch={branch}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1614,9 +2153,9 @@ impl GitHubOperations for MockGitHubOperations {
         );
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1660,9 +2199,9 @@ impl GitHubOperations for MockGitHubOperations {
         );
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1707,9 +2246,36 @@ impl GitOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
+
+        if self.should_simulate_failure(method).await {
+            let error = CoreError::network("Simulated GitHub API error");
+            self.record_call(method, &params_str, CallResult::Error(error.to_string()))
+                .await;
+            return Err(error);
+        }
+
+        let key = format!("{owner}/{repo}");
+        let commits = self.commits.get(&key).cloned().unwrap_or_default();
+        self.record_call(method, &params_str, CallResult::Success)
+            .await;
+        Ok(commits)
+    }
+
+    async fn list_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        head: &str,
+        _options: GetCommitsOptions,
+    ) -> CoreResult<Vec<GitCommit>> {
+        let method = "list_commits";
+        let params_str = format!("owner={owner}, repo={repo}, head={head}");
+
+        self.check_quota().await?;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1728,9 +2294,9 @@ impl GitOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, sha={commit_sha}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1770,9 +2336,9 @@ impl GitOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1796,9 +2362,9 @@ impl GitOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, tag={tag_name}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1832,9 +2398,9 @@ impl GitOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}, tag={tag_name}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1862,9 +2428,9 @@ impl GitOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1897,9 +2463,9 @@ impl GitOperations for MockGitHubOperations {
         let params_str = format!("owner={owner}, repo={repo}");
 
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::network("Simulated GitHub API error");
             self.record_call(method, &params_str, CallResult::Error(error.to_string()))
                 .await;
@@ -1928,6 +2494,9 @@ impl GitOperations for MockGitHubOperations {
             ssh_url: repository.ssh_url.clone(),
             private: repository.private,
             description: repository.description.clone(),
+            topics: Vec::new(),
+            language: None,
+            visibility: RepositoryVisibility::from_private(repository.private),
         })
     }
 }
@@ -4,13 +4,14 @@
 //! promotions for [`GitHubOperations`] and [`GitOperations`] methods.
 
 use super::*;
-use crate::builders::{
-    CommitBuilder, PullRequestBuilder, ReleaseBuilder, TagBuilder, TestDataBuilder,
-};
+use crate::builders::{PullRequestBuilder, ReleaseBuilder, TagBuilder, TestDataBuilder};
 use chrono::Utc;
 use release_regent_core::traits::{
     git_operations::{GitTagType, ListTagsOptions},
-    github_operations::{CreatePullRequestParams, CreateReleaseParams, UpdateReleaseParams},
+    github_operations::{
+        CommitStatusState, CreatePullRequestParams, CreateReleaseParams, LabelMode,
+        UpdateReleaseParams,
+    },
 };
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -250,19 +251,49 @@ async fn test_get_release_by_tag_returns_matching_release() {
 
     let result = mock.get_release_by_tag("o", "r", "v1.2.0").await.unwrap();
 
-    assert_eq!(result.tag_name, "v1.2.0");
+    assert_eq!(result.map(|r| r.tag_name), Some("v1.2.0".to_string()));
 }
 
-/// Verify that querying for a release with a non-existent tag returns an error.
+/// Verify that querying for a release with a non-existent tag returns `None`.
 #[tokio::test]
-async fn test_get_release_by_tag_returns_error_when_not_found() {
+async fn test_get_release_by_tag_returns_none_when_not_found() {
     let mock = make_mock().with_releases(
         "o",
         "r",
         vec![ReleaseBuilder::new().with_tag_name("v1.0.0").build()],
     );
 
-    let result = mock.get_release_by_tag("o", "r", "v9.9.9").await;
+    let result = mock.get_release_by_tag("o", "r", "v9.9.9").await.unwrap();
+
+    assert!(result.is_none());
+}
+
+/// Verify that, like GitHub, the tag lookup does not return a draft release.
+#[tokio::test]
+async fn test_get_release_by_tag_does_not_return_drafts() {
+    let draft = ReleaseBuilder::new()
+        .with_tag_name("v1.2.0")
+        .as_draft()
+        .build();
+    let mock = make_mock().with_releases("o", "r", vec![draft]);
+
+    let result = mock.get_release_by_tag("o", "r", "v1.2.0").await.unwrap();
+
+    assert!(result.is_none());
+}
+
+/// Verify that an API failure is reported as an error rather than as `None`.
+#[tokio::test]
+async fn test_get_release_by_tag_returns_error_on_api_failure() {
+    let mock = make_mock()
+        .with_releases(
+            "o",
+            "r",
+            vec![ReleaseBuilder::new().with_tag_name("v1.0.0").build()],
+        )
+        .with_method_failure_rate("get_release_by_tag", 1.0);
+
+    let result = mock.get_release_by_tag("o", "r", "v1.0.0").await;
 
     assert!(result.is_err());
 }
@@ -285,6 +316,39 @@ async fn test_list_releases_returns_all_stored_releases() {
     assert_eq!(result.len(), 2);
 }
 
+/// Verify that `list_releases` with an explicit page returns only that page.
+#[tokio::test]
+async fn test_list_releases_with_page_returns_requested_slice() {
+    let releases = vec![
+        ReleaseBuilder::new().with_tag_name("v3.0.0").build(),
+        ReleaseBuilder::new().with_tag_name("v2.0.0").build(),
+        ReleaseBuilder::new().with_tag_name("v1.0.0").build(),
+    ];
+    let mock = make_mock().with_releases("o", "r", releases);
+
+    let second = mock
+        .list_releases("o", "r", Some(2), Some(2))
+        .await
+        .unwrap();
+    let beyond = mock
+        .list_releases("o", "r", Some(2), Some(3))
+        .await
+        .unwrap();
+
+    assert_eq!(second.len(), 1);
+    assert_eq!(second[0].tag_name, "v1.0.0");
+    assert!(beyond.is_empty());
+}
+
+/// Verify that `list_releases` rejects out-of-range pagination arguments.
+#[tokio::test]
+async fn test_list_releases_rejects_invalid_pagination() {
+    let mock = make_mock();
+
+    assert!(mock.list_releases("o", "r", Some(0), None).await.is_err());
+    assert!(mock.list_releases("o", "r", None, Some(0)).await.is_err());
+}
+
 /// Verify that `list_releases` returns an empty list when no releases have been configured.
 #[tokio::test]
 async fn test_list_releases_returns_empty_when_no_releases_configured() {
@@ -295,6 +359,55 @@ async fn test_list_releases_returns_empty_when_no_releases_configured() {
     assert!(result.is_empty());
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// upload_release_asset
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Verify that `upload_release_asset` records the call and returns a download URL
+/// built from the release's tag.
+#[tokio::test]
+async fn test_upload_release_asset_records_call_and_returns_download_url() {
+    let mut release = ReleaseBuilder::new().with_tag_name("v1.0.0").build();
+    release.id = 42;
+    let mock = make_mock().with_releases("o", "r", vec![release]);
+
+    let url = mock
+        .upload_release_asset("o", "r", 42, "app.zip", None, b"zip".to_vec())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        url,
+        "https://github.com/o/r/releases/download/v1.0.0/app.zip"
+    );
+    let calls = mock.upload_release_asset_calls().await;
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].2, 42);
+    assert_eq!(calls[0].3, "app.zip");
+    assert_eq!(calls[0].4, None);
+    assert_eq!(calls[0].5, b"zip".to_vec());
+}
+
+/// Verify that uploading the same asset name to the same release twice is a conflict.
+#[tokio::test]
+async fn test_upload_release_asset_duplicate_name_returns_conflict() {
+    let mock = make_mock();
+    mock.upload_release_asset("o", "r", 1, "app.zip", Some("application/zip"), vec![1])
+        .await
+        .unwrap();
+
+    let duplicate = mock
+        .upload_release_asset("o", "r", 1, "app.zip", Some("application/zip"), vec![2])
+        .await;
+    let other_release = mock
+        .upload_release_asset("o", "r", 2, "app.zip", Some("application/zip"), vec![3])
+        .await;
+
+    assert!(matches!(duplicate, Err(CoreError::Conflict { .. })));
+    assert!(other_release.is_ok());
+    assert_eq!(mock.upload_release_asset_calls().await.len(), 2);
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // get_latest_release
 // ─────────────────────────────────────────────────────────────────────────────
@@ -424,6 +537,55 @@ async fn test_create_tag_records_call_and_returns_tag_from_params() {
     assert_eq!(mock.call_count().await, 1);
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// upsert_branch
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Verify that `upsert_branch` creates a branch that is not registered yet.
+#[tokio::test]
+async fn test_upsert_branch_creates_missing_branch() {
+    let mock = make_mock();
+
+    let outcome = mock
+        .upsert_branch("o", "r", "release-regent/next", "abc123")
+        .await
+        .unwrap();
+
+    assert_eq!(outcome, BranchUpsert::Created);
+    assert_eq!(mock.call_count().await, 1);
+}
+
+/// Verify that `upsert_branch` fast-forwards a registered branch.
+#[tokio::test]
+async fn test_upsert_branch_updates_existing_branch() {
+    let mock = make_mock().with_branches("o", "r", vec!["release-regent/next".to_string()]);
+
+    let outcome = mock
+        .upsert_branch("o", "r", "release-regent/next", "abc123")
+        .await
+        .unwrap();
+
+    assert_eq!(outcome, BranchUpsert::Updated);
+}
+
+/// Verify that `upsert_branch` refuses to move a diverged branch.
+#[tokio::test]
+async fn test_upsert_branch_rejects_diverged_branch() {
+    let mock = make_mock()
+        .with_branches("o", "r", vec!["release-regent/next".to_string()])
+        .with_diverged_branches("o", "r", vec!["release-regent/next".to_string()]);
+
+    let err = mock
+        .upsert_branch("o", "r", "release-regent/next", "abc123")
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, CoreError::NotFastForward { .. }),
+        "got {err:?}"
+    );
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // update_pull_request
 // ─────────────────────────────────────────────────────────────────────────────
@@ -613,6 +775,130 @@ async fn test_tag_exists_returns_false_when_tag_absent() {
     assert!(!result);
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// set_pull_request_labels
+// ─────────────────────────────────────────────────────────────────────────────
+
+async fn label_names(mock: &MockGitHubOperations, number: u64) -> Vec<String> {
+    mock.list_pr_labels("o", "r", number)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|l| l.name)
+        .collect()
+}
+
+/// Verify that `LabelMode::Add` keeps the labels already on the PR.
+#[tokio::test]
+async fn test_set_pull_request_labels_add_keeps_existing_labels() {
+    let mock = make_mock();
+    mock.add_labels("o", "r", 7, &["bug"]).await.unwrap();
+
+    mock.set_pull_request_labels(
+        "o",
+        "r",
+        7,
+        &["autorelease: pending", "bug"],
+        LabelMode::Add,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        label_names(&mock, 7).await,
+        vec!["bug", "autorelease: pending"]
+    );
+}
+
+/// Verify that `LabelMode::Replace` drops labels that are not in the new set.
+#[tokio::test]
+async fn test_set_pull_request_labels_replace_drops_other_labels() {
+    let mock = make_mock();
+    mock.add_labels("o", "r", 7, &["bug", "release"])
+        .await
+        .unwrap();
+
+    mock.set_pull_request_labels(
+        "o",
+        "r",
+        7,
+        &["release", "autorelease: pending"],
+        LabelMode::Replace,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        label_names(&mock, 7).await,
+        vec!["release", "autorelease: pending"]
+    );
+}
+
+/// Verify that a configured method error is returned and recorded.
+#[tokio::test]
+async fn test_set_pull_request_labels_returns_configured_error() {
+    let mock = make_mock().with_method_error("set_pull_request_labels", "labels unavailable");
+
+    let result = mock
+        .set_pull_request_labels("o", "r", 7, &["release"], LabelMode::Add)
+        .await;
+
+    assert!(result.is_err());
+    assert!(label_names(&mock, 7).await.is_empty());
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// resolve_ref
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Verify that `resolve_ref` returns the configured head of a branch.
+#[tokio::test]
+async fn test_resolve_ref_returns_branch_head() {
+    let mock = make_mock().with_branch_head("o", "r", "main", "abc123");
+
+    assert_eq!(mock.resolve_ref("o", "r", "main").await.unwrap(), "abc123");
+    assert_eq!(
+        mock.resolve_ref("o", "r", "refs/heads/main").await.unwrap(),
+        "abc123"
+    );
+}
+
+/// Verify that a bare name resolves to the tag before a branch of the same name.
+#[tokio::test]
+async fn test_resolve_ref_prefers_tag_over_branch() {
+    let mock = make_mock()
+        .with_branch_head("o", "r", "v1.0.0", "branch-sha")
+        .with_tags(
+            "o",
+            "r",
+            vec![TagBuilder::new()
+                .with_name("v1.0.0")
+                .with_commit_sha("tag-sha")
+                .annotated()
+                .build()],
+        );
+
+    assert_eq!(
+        mock.resolve_ref("o", "r", "v1.0.0").await.unwrap(),
+        "tag-sha"
+    );
+    assert_eq!(
+        mock.resolve_ref("o", "r", "heads/v1.0.0").await.unwrap(),
+        "branch-sha"
+    );
+}
+
+/// Verify that `resolve_ref` returns `NotFound` for an unknown ref.
+#[tokio::test]
+async fn test_resolve_ref_returns_not_found_for_unknown_ref() {
+    let mock = make_mock().with_branch_head("o", "r", "main", "abc123");
+
+    let result = mock.resolve_ref("o", "r", "develop").await;
+
+    assert!(matches!(result, Err(CoreError::NotFound { .. })));
+    assert_eq!(mock.call_count().await, 1);
+}
+
 /// Verify that `tag_exists` returns `false` when no tags have been configured.
 #[tokio::test]
 async fn test_tag_exists_returns_false_when_no_tags_configured() {
@@ -653,6 +939,41 @@ async fn test_get_commit_returns_error_when_sha_not_found() {
     assert!(result.is_err());
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// list_pull_request_commits
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Verify that `list_pull_request_commits` returns the commits configured for the PR, in order.
+#[tokio::test]
+async fn test_list_pull_request_commits_returns_configured_commits() {
+    use crate::builders::{CommitBuilder, TestDataBuilder};
+    let first = CommitBuilder::new()
+        .with_conventional("feat", "add login")
+        .build();
+    let second = CommitBuilder::new()
+        .with_conventional("fix", "handle empty password")
+        .build();
+    let mock =
+        make_mock().with_pull_request_commits("o", "r", 7, vec![first.clone(), second.clone()]);
+
+    let commits = mock.list_pull_request_commits("o", "r", 7).await.unwrap();
+
+    assert_eq!(commits.len(), 2);
+    assert_eq!(commits[0].subject, first.subject);
+    assert_eq!(commits[1].subject, second.subject);
+    assert_eq!(mock.call_count().await, 1);
+}
+
+/// Verify that `list_pull_request_commits` returns an error for a PR with no configured commits.
+#[tokio::test]
+async fn test_list_pull_request_commits_returns_error_when_pr_not_configured() {
+    let mock = make_mock();
+
+    let result = mock.list_pull_request_commits("o", "r", 99).await;
+
+    assert!(result.is_err());
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Error injection (failure simulation)
 // ─────────────────────────────────────────────────────────────────────────────
@@ -677,6 +998,110 @@ async fn test_failure_simulation_returns_error_for_list_releases() {
     assert!(result.is_err());
 }
 
+/// Verify that two deterministic mocks with the same seed fail the same calls.
+#[tokio::test]
+async fn test_deterministic_failure_simulation_is_reproducible_for_seed() {
+    use crate::mocks::MockConfig;
+    async fn failure_pattern(seed: u64) -> Vec<bool> {
+        let config = MockConfig {
+            deterministic: true,
+            simulate_failures: true,
+            failure_rate: 0.5,
+            ..MockConfig::default()
+        }
+        .with_seed(seed);
+        let mock = MockGitHubOperations::with_config(config).with_releases(
+            "o",
+            "r",
+            vec![ReleaseBuilder::new().build()],
+        );
+        let mut pattern = Vec::new();
+        for _ in 0..32 {
+            pattern.push(mock.list_releases("o", "r", None, None).await.is_err());
+        }
+        pattern
+    }
+
+    let first = failure_pattern(42).await;
+    let second = failure_pattern(42).await;
+
+    assert_eq!(first, second);
+    assert!(first.contains(&true) && first.contains(&false));
+}
+
+/// Verify that a per-method failure rate fails only the targeted method, even with global failure simulation off.
+#[tokio::test]
+async fn test_method_failure_rate_fails_only_targeted_method() {
+    let mock = make_mock()
+        .with_releases("o", "r", vec![ReleaseBuilder::new().build()])
+        .with_method_failure_rate("create_release", 1.0);
+    let params = CreateReleaseParams {
+        tag_name: "v1.0.0".to_string(),
+        name: None,
+        body: None,
+        draft: false,
+        prerelease: false,
+        target_commitish: None,
+        generate_release_notes: false,
+    };
+
+    let failed = mock.create_release("o", "r", params).await;
+    let listed = mock.list_releases("o", "r", None, None).await;
+
+    assert!(failed.is_err());
+    assert!(listed.is_ok());
+}
+
+/// Verify that a per-method failure rate of zero overrides a global failure rate of one.
+#[tokio::test]
+async fn test_method_failure_rate_overrides_global_failure_rate() {
+    use crate::mocks::MockConfig;
+    let config = MockConfig {
+        simulate_failures: true,
+        failure_rate: 1.0,
+        ..MockConfig::default()
+    }
+    .with_method_failure_rate("list_releases", 0.0);
+    let mock = MockGitHubOperations::with_config(config).with_releases(
+        "o",
+        "r",
+        vec![ReleaseBuilder::new().build()],
+    );
+
+    assert!(mock.list_releases("o", "r", None, None).await.is_ok());
+    assert!(mock.get_latest_release("o", "r").await.is_err());
+}
+
+/// Verify that a per-method latency delays only the targeted method.
+#[tokio::test]
+async fn test_method_latency_delays_only_targeted_method() {
+    use crate::builders::{CommitBuilder, TestDataBuilder};
+    use release_regent_core::traits::git_operations::GetCommitsOptions;
+    let mock = make_mock()
+        .with_repository_exists(true)
+        .with_commits("test", "repo", vec![CommitBuilder::new().build()])
+        .with_method_latency("get_commits_between", 200);
+
+    let start = std::time::Instant::now();
+    let _ = mock.get_repository_info("test", "repo").await;
+    let fast = start.elapsed();
+
+    let start = std::time::Instant::now();
+    let _ = mock
+        .get_commits_between(
+            "test",
+            "repo",
+            "v1.0.0",
+            "main",
+            GetCommitsOptions::default(),
+        )
+        .await;
+    let slow = start.elapsed();
+
+    assert!(fast.as_millis() < 100, "untargeted call took {fast:?}");
+    assert!(slow.as_millis() >= 190, "targeted call took {slow:?}");
+}
+
 /// Verify that each method call increments the total call counter by one.
 #[tokio::test]
 async fn test_call_count_increments_for_each_method_call() {
@@ -733,3 +1158,28 @@ fn test_tag_builder_with_created_at_sets_timestamp() {
     let tag = TagBuilder::new().with_created_at(ts).build();
     assert_eq!(tag.created_at, Some(ts));
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// create_commit_status
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Verify that `create_commit_status` records the target commit and params.
+#[tokio::test]
+async fn test_create_commit_status_records_call() {
+    let mock = make_mock();
+    let params = CreateCommitStatusParams {
+        context: "release-regent/version".to_string(),
+        description: "Next release: v1.0.0 (feat)".to_string(),
+        state: CommitStatusState::Success,
+        target_url: None,
+    };
+
+    mock.create_commit_status("o", "r", "abc123", params.clone())
+        .await
+        .unwrap();
+
+    let calls = mock.commit_status_calls().await;
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].2, "abc123");
+    assert_eq!(calls[0].3, params);
+}
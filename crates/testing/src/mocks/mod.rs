@@ -30,8 +30,9 @@
 //! - Invalid input and validation errors
 //! - Service unavailable and maintenance modes
 
+use rand::{rngs::StdRng, SeedableRng};
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use tokio::sync::RwLock;
 
 // Re-export core traits for type identity
@@ -39,11 +40,15 @@ pub use release_regent_core::traits::configuration_provider::ConfigurationProvid
 pub use release_regent_core::traits::github_operations::GitHubOperations;
 pub use release_regent_core::traits::version_calculator::VersionCalculator;
 
+pub mod changelog_renderer;
+pub mod clock;
 pub mod configuration_provider;
 pub mod event_source;
 pub mod github_operations;
 pub mod version_calculator;
 
+pub use changelog_renderer::MockChangelogRenderer;
+pub use clock::MockClock;
 pub use configuration_provider::MockConfigurationProvider;
 pub use event_source::MockEventSource;
 pub use github_operations::MockGitHubOperations;
@@ -53,7 +58,13 @@ pub use version_calculator::MockVersionCalculator;
 #[derive(Debug, Clone)]
 pub struct MockConfig {
     /// Whether to enable deterministic behavior (same inputs = same outputs)
+    ///
+    /// Simulated failures are then drawn from a random number generator
+    /// seeded with `seed`, so the same sequence of calls fails the same way
+    /// on every run.
     pub deterministic: bool,
+    /// Seed for simulated failures when `deterministic` is enabled
+    pub seed: u64,
     /// Default response latency in milliseconds
     pub response_latency_ms: u64,
     /// Whether to track all method calls for verification
@@ -64,27 +75,98 @@ pub struct MockConfig {
     pub simulate_failures: bool,
     /// Failure rate (0.0 = no failures, 1.0 = always fail)
     pub failure_rate: f64,
+    /// Per-method overrides of the latency and failure settings, keyed by
+    /// method name (e.g. `"get_commits_between"`)
+    pub method_overrides: HashMap<String, MethodBehavior>,
+}
+
+impl MockConfig {
+    /// Seed simulated failures with `seed`
+    ///
+    /// Only used when `deterministic` is enabled.
+    ///
+    /// # Parameters
+    /// - `seed`: Seed for the random number generator
+    ///
+    /// # Returns
+    /// Self for method chaining
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Delay calls to `method` by `latency_ms`, replacing `response_latency_ms`
+    ///
+    /// # Parameters
+    /// - `method`: Method name, e.g. `"get_commits_between"`
+    /// - `latency_ms`: Latency in milliseconds
+    ///
+    /// # Returns
+    /// Self for method chaining
+    #[must_use]
+    pub fn with_method_latency(mut self, method: &str, latency_ms: u64) -> Self {
+        self.method_overrides
+            .entry(method.to_string())
+            .or_default()
+            .latency_ms = Some(latency_ms);
+        self
+    }
+
+    /// Fail calls to `method` at `failure_rate`, replacing `failure_rate`
+    ///
+    /// The override applies even when `simulate_failures` is `false`.
+    ///
+    /// # Parameters
+    /// - `method`: Method name, e.g. `"create_release"`
+    /// - `failure_rate`: Failure rate (0.0 = never fail, 1.0 = always fail)
+    ///
+    /// # Returns
+    /// Self for method chaining
+    #[must_use]
+    pub fn with_method_failure_rate(mut self, method: &str, failure_rate: f64) -> Self {
+        self.method_overrides
+            .entry(method.to_string())
+            .or_default()
+            .failure_rate = Some(failure_rate);
+        self
+    }
 }
 
 impl Default for MockConfig {
     fn default() -> Self {
         Self {
             deterministic: true,
+            seed: 0,
             response_latency_ms: 0,
             track_calls: true,
             max_calls: None,
             simulate_failures: false,
             failure_rate: 0.0,
+            method_overrides: HashMap::new(),
         }
     }
 }
 
+/// Latency and failure settings for a single mock method
+///
+/// Unset values fall back to the global [`MockConfig`] settings.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MethodBehavior {
+    /// Response latency in milliseconds
+    pub latency_ms: Option<u64>,
+    /// Failure rate (0.0 = no failures, 1.0 = always fail)
+    pub failure_rate: Option<f64>,
+}
+
 /// Call tracking information for mock verification
 #[derive(Debug, Clone)]
 pub struct CallInfo {
     /// Method name that was called
     pub method: String,
-    /// Parameters passed to the method (serialized as JSON)
+    /// Parameters passed to the method, as `key=value` pairs or JSON
+    ///
+    /// See [`parse_parameters`](crate::assertions::parse_parameters).
     pub parameters: String,
     /// Timestamp when the call was made
     pub timestamp: chrono::DateTime<chrono::Utc>,
@@ -104,7 +186,7 @@ pub enum CallResult {
 }
 
 /// Shared state for tracking mock behavior and calls
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct MockState {
     /// Configuration for mock behavior
     config: MockConfig,
@@ -114,6 +196,14 @@ pub struct MockState {
     call_count: u64,
     /// Custom state data for specific test scenarios
     custom_data: HashMap<String, serde_json::Value>,
+    /// Random number generator for simulated failures in deterministic mode
+    rng: Mutex<StdRng>,
+}
+
+impl Default for MockState {
+    fn default() -> Self {
+        Self::with_config(MockConfig::default())
+    }
 }
 
 impl MockState {
@@ -127,8 +217,11 @@ impl MockState {
     #[must_use]
     pub fn with_config(config: MockConfig) -> Self {
         Self {
+            rng: Mutex::new(StdRng::seed_from_u64(config.seed)),
             config,
-            ..Default::default()
+            call_history: Vec::new(),
+            call_count: 0,
+            custom_data: HashMap::new(),
         }
     }
 
@@ -167,29 +260,62 @@ impl MockState {
         }
     }
 
-    /// Determine if this call should simulate a failure
+    /// Determine if a call to `method` should simulate a failure
+    ///
+    /// A failure rate configured for `method` takes precedence over the
+    /// global settings. In deterministic mode the outcome is drawn from the
+    /// seeded generator, so it only depends on the seed and the order of calls.
+    ///
+    /// # Panics
+    /// Panics if the random number generator lock is poisoned.
     #[must_use]
-    pub fn should_simulate_failure(&self) -> bool {
+    pub fn should_simulate_failure(&self, method: &str) -> bool {
         use rand::RngExt;
 
-        if !self.config.simulate_failures {
-            return false;
-        }
+        let failure_rate = match self.method_behavior(method).and_then(|b| b.failure_rate) {
+            Some(rate) => rate,
+            None if self.config.simulate_failures => self.config.failure_rate,
+            None => return false,
+        };
 
-        let mut rng = rand::rng();
-        rng.random::<f64>() < self.config.failure_rate
+        let sample = if self.config.deterministic {
+            self.rng
+                .lock()
+                .expect("mock random number generator lock poisoned")
+                .random::<f64>()
+        } else {
+            rand::rng().random::<f64>()
+        };
+        sample < failure_rate
     }
 
-    /// Add latency simulation if configured
-    pub async fn simulate_latency(&self) {
-        if self.config.response_latency_ms > 0 {
-            tokio::time::sleep(tokio::time::Duration::from_millis(
-                self.config.response_latency_ms,
-            ))
-            .await;
+    /// Add latency simulation for a call to `method` if configured
+    ///
+    /// A latency configured for `method` takes precedence over the global
+    /// `response_latency_ms`.
+    pub async fn simulate_latency(&self, method: &str) {
+        let latency_ms = self
+            .method_behavior(method)
+            .and_then(|b| b.latency_ms)
+            .unwrap_or(self.config.response_latency_ms);
+        if latency_ms > 0 {
+            tokio::time::sleep(tokio::time::Duration::from_millis(latency_ms)).await;
         }
     }
 
+    /// Set the latency and failure overrides for `method`
+    pub fn set_method_behavior(&mut self, method: &str, behavior: MethodBehavior) {
+        self.config
+            .method_overrides
+            .insert(method.to_string(), behavior);
+    }
+
+    /// Get the latency and failure overrides for `method`
+    #[must_use]
+    pub fn method_behavior(&self, method: &str) -> Option<&MethodBehavior> {
+        self.config.method_overrides.get(method)
+    }
+
     /// Set custom data for test scenarios
     pub fn set_custom_data(&mut self, key: &str, value: serde_json::Value) {
         self.custom_data.insert(key.to_string(), value);
@@ -233,13 +233,13 @@ impl MockVersionCalculator {
     }
 
     /// Simulate latency if configured
-    async fn simulate_latency(&self) {
-        self.state.read().await.simulate_latency().await;
+    async fn simulate_latency(&self, method: &str) {
+        self.state.read().await.simulate_latency(method).await;
     }
 
     /// Check if should simulate failure
-    async fn should_simulate_failure(&self) -> bool {
-        self.state.read().await.should_simulate_failure()
+    async fn should_simulate_failure(&self, method: &str) -> bool {
+        self.state.read().await.should_simulate_failure(method)
     }
 
     /// Create a context key from version context
@@ -260,12 +260,14 @@ impl MockVersionCalculator {
             next_version: self.default_next_version.clone(),
             current_version: context.current_version.clone(),
             version_bump: self.default_version_bump.clone(),
+            bump_reason: None,
             strategy: strategy.clone(),
             analyzed_commits: vec![],
             changelog_entries: vec![],
             is_prerelease: false,
             build_metadata: None,
             metadata: HashMap::new(),
+            packages: std::collections::BTreeMap::new(),
         }
     }
 }
@@ -306,10 +308,10 @@ impl VersionCalculator for MockVersionCalculator {
 
         // Check quota and simulate latency
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
         // Simulate failure if configured
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::versioning("Simulated version calculation error");
             self.record_call(method, &params, CallResult::Error(error.to_string()))
                 .await;
@@ -357,10 +359,10 @@ impl VersionCalculator for MockVersionCalculator {
 
         // Check quota and simulate latency
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
         // Simulate failure if configured
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::versioning("Simulated commit analysis error");
             self.record_call(method, &params, CallResult::Error(error.to_string()))
                 .await;
@@ -406,10 +408,10 @@ impl VersionCalculator for MockVersionCalculator {
 
         // Check quota and simulate latency
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
         // Simulate failure if configured
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::versioning("Simulated version validation error");
             self.record_call(method, &params, CallResult::Error(error.to_string()))
                 .await;
@@ -447,10 +449,10 @@ impl VersionCalculator for MockVersionCalculator {
 
         // Check quota and simulate latency
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
         // Simulate failure if configured
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::versioning("Simulated version bump error");
             self.record_call(method, &params, CallResult::Error(error.to_string()))
                 .await;
@@ -498,10 +500,10 @@ impl VersionCalculator for MockVersionCalculator {
 
         // Check quota and simulate latency
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
         // Simulate failure if configured
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::changelog_generation("Simulated changelog generation error");
             self.record_call(method, &params, CallResult::Error(error.to_string()))
                 .await;
@@ -550,10 +552,10 @@ impl VersionCalculator for MockVersionCalculator {
 
         // Check quota and simulate latency
         self.check_quota().await?;
-        self.simulate_latency().await;
+        self.simulate_latency(method).await;
 
         // Simulate failure if configured
-        if self.should_simulate_failure().await {
+        if self.should_simulate_failure(method).await {
             let error = CoreError::versioning("Simulated preview calculation error");
             self.record_call(method, &params, CallResult::Error(error.to_string()))
                 .await;